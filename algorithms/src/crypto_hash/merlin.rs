@@ -0,0 +1,324 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AlgebraicSponge;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+
+use smallvec::SmallVec;
+use std::marker::PhantomData;
+
+/// The number of bits in a short nonnative field element.
+const SHORT_NONNATIVE_BITS: usize = 168;
+
+/// A Fiat-Shamir transcript that follows the [Merlin][merlin] transcript construction,
+/// for interoperability with external verifiers that do not implement the Poseidon sponge.
+///
+/// Every call on the sponge maps onto a single Merlin operation:
+/// - `absorb_native_field_elements` appends the little-endian bytes of each element under `b"native"`,
+/// - `absorb_nonnative_field_elements` appends the little-endian bytes of each element under `b"nonnative"`,
+/// - `absorb_bytes` appends the bytes under `b"bytes"`,
+/// - `squeeze_native_field_elements` and `squeeze_nonnative_field_elements` extract, per element,
+///   `(size_in_bits + 128) / 8` challenge bytes under `b"native"` or `b"nonnative"`, reduced modulo the field order,
+/// - `squeeze_short_nonnative_field_elements` extracts, per element, 21 challenge bytes under `b"short"`.
+///
+/// [merlin]: https://merlin.cool
+#[derive(Clone, Debug)]
+pub struct MerlinSponge<F: PrimeField, const RATE: usize> {
+    /// The underlying Merlin transcript.
+    transcript: MerlinTranscript,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, const RATE: usize> MerlinSponge<F, RATE> {
+    /// The default domain separation label of the transcript.
+    pub const DEFAULT_LABEL: &'static [u8] = b"snarkVM";
+
+    /// Squeezes `num` field elements, using `num_bytes` challenge bytes per element.
+    fn squeeze_field_elements<Target: PrimeField>(
+        &mut self,
+        label: &'static [u8],
+        num: usize,
+        num_bytes: usize,
+    ) -> SmallVec<[Target; 10]> {
+        let mut bytes = vec![0u8; num_bytes];
+        (0..num)
+            .map(|_| {
+                self.transcript.challenge_bytes(label, &mut bytes);
+                Target::from_bytes_le_mod_order(&bytes)
+            })
+            .collect()
+    }
+}
+
+impl<F: PrimeField, const RATE: usize> AlgebraicSponge<F, RATE> for MerlinSponge<F, RATE> {
+    /// The domain separation label of the transcript.
+    type Parameters = &'static [u8];
+
+    fn sample_parameters() -> Self::Parameters {
+        Self::DEFAULT_LABEL
+    }
+
+    fn new_with_parameters(label: &Self::Parameters) -> Self {
+        Self { transcript: MerlinTranscript::new(label), _field: PhantomData }
+    }
+
+    /// Takes in field elements.
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        let mut bytes = Vec::new();
+        for element in elements.iter().flat_map(|e| e.to_field_elements().unwrap()) {
+            element.write_le(&mut bytes).unwrap();
+        }
+        if !bytes.is_empty() {
+            self.transcript.append_message(b"native", &bytes);
+        }
+    }
+
+    /// Takes in field elements.
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        let mut bytes = Vec::new();
+        for element in elements {
+            element.write_le(&mut bytes).unwrap();
+        }
+        if !bytes.is_empty() {
+            self.transcript.append_message(b"nonnative", &bytes);
+        }
+    }
+
+    /// Takes in bytes.
+    fn absorb_bytes(&mut self, elements: &[u8]) {
+        self.transcript.append_message(b"bytes", elements);
+    }
+
+    /// Takes out field elements.
+    fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]> {
+        self.squeeze_field_elements(b"native", num, (F::size_in_bits() + 128 + 7) / 8)
+    }
+
+    /// Takes out field elements.
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(b"nonnative", num, (Target::size_in_bits() + 128 + 7) / 8)
+    }
+
+    /// Takes out field elements of 168 bits.
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(b"short", num, SHORT_NONNATIVE_BITS / 8)
+    }
+}
+
+/// A Merlin transcript, as specified in <https://merlin.cool>.
+#[derive(Clone, Debug)]
+pub(crate) struct MerlinTranscript {
+    strobe: Strobe128,
+}
+
+impl MerlinTranscript {
+    /// Initializes a new transcript with the given domain separation label.
+    pub(crate) fn new(label: &[u8]) -> Self {
+        let mut transcript = Self { strobe: Strobe128::new(b"Merlin v1.0") };
+        transcript.append_message(b"dom-sep", label);
+        transcript
+    }
+
+    /// Appends a labeled message to the transcript.
+    pub(crate) fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        let length = u32::try_from(message.len()).expect("Merlin messages must be shorter than 2^32 bytes");
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&length.to_le_bytes(), true);
+        self.strobe.ad(message, false);
+    }
+
+    /// Fills `dest` with labeled challenge bytes derived from the transcript.
+    pub(crate) fn challenge_bytes(&mut self, label: &[u8], dest: &mut [u8]) {
+        let length = u32::try_from(dest.len()).expect("Merlin challenges must be shorter than 2^32 bytes");
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&length.to_le_bytes(), true);
+        self.strobe.prf(dest, false);
+    }
+}
+
+/// The rate of STROBE-128, in bytes.
+const STROBE_R: u8 = 166;
+
+const FLAG_I: u8 = 1;
+const FLAG_A: u8 = 1 << 1;
+const FLAG_C: u8 = 1 << 2;
+const FLAG_T: u8 = 1 << 3;
+const FLAG_M: u8 = 1 << 4;
+const FLAG_K: u8 = 1 << 5;
+
+/// The subset of STROBE-128 (v1.0.2) operations used by Merlin.
+#[derive(Clone, Debug)]
+struct Strobe128 {
+    state: [u8; 200],
+    pos: u8,
+    pos_begin: u8,
+    cur_flags: u8,
+}
+
+impl Strobe128 {
+    fn new(protocol_label: &[u8]) -> Self {
+        let mut state = [0u8; 200];
+        state[0..6].copy_from_slice(&[1, STROBE_R + 2, 1, 0, 1, 96]);
+        state[6..18].copy_from_slice(b"STROBEv1.0.2");
+        keccak_f1600(&mut state);
+
+        let mut strobe = Self { state, pos: 0, pos_begin: 0, cur_flags: 0 };
+        strobe.meta_ad(protocol_label, false);
+        strobe
+    }
+
+    fn meta_ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_M | FLAG_A, more);
+        self.absorb(data);
+    }
+
+    fn ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_A, more);
+        self.absorb(data);
+    }
+
+    fn prf(&mut self, data: &mut [u8], more: bool) {
+        self.begin_op(FLAG_I | FLAG_A | FLAG_C, more);
+        self.squeeze(data);
+    }
+
+    fn run_f(&mut self) {
+        self.state[self.pos as usize] ^= self.pos_begin;
+        self.state[self.pos as usize + 1] ^= 0x04;
+        self.state[STROBE_R as usize + 1] ^= 0x80;
+        keccak_f1600(&mut self.state);
+        self.pos = 0;
+        self.pos_begin = 0;
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        for byte in data {
+            self.state[self.pos as usize] ^= byte;
+            self.pos += 1;
+            if self.pos == STROBE_R {
+                self.run_f();
+            }
+        }
+    }
+
+    fn squeeze(&mut self, data: &mut [u8]) {
+        for byte in data {
+            *byte = self.state[self.pos as usize];
+            self.state[self.pos as usize] = 0;
+            self.pos += 1;
+            if self.pos == STROBE_R {
+                self.run_f();
+            }
+        }
+    }
+
+    fn begin_op(&mut self, flags: u8, more: bool) {
+        // Check if we're continuing an operation.
+        if more {
+            assert_eq!(self.cur_flags, flags, "STROBE cannot continue an operation with different flags");
+            return;
+        }
+        // Transport operations are not used by Merlin.
+        assert_eq!(flags & FLAG_T, 0, "STROBE transport operations are not supported");
+
+        let old_begin = self.pos_begin;
+        self.pos_begin = self.pos + 1;
+        self.cur_flags = flags;
+        self.absorb(&[old_begin, flags]);
+
+        // Force a permutation if the operation is a cipher or key operation.
+        if flags & (FLAG_C | FLAG_K) != 0 && self.pos != 0 {
+            self.run_f();
+        }
+    }
+}
+
+const KECCAK_RHO: [u32; 24] =
+    [1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44];
+
+const KECCAK_PI: [usize; 24] = [10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1];
+
+const KECCAK_RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Applies the Keccak-f[1600] permutation to the given state, in place.
+pub(crate) fn keccak_f1600(bytes: &mut [u8; 200]) {
+    // Load the state as little-endian lanes.
+    let mut state = [0u64; 25];
+    for (lane, chunk) in state.iter_mut().zip(bytes.chunks_exact(8)) {
+        let mut lane_bytes = [0u8; 8];
+        lane_bytes.copy_from_slice(chunk);
+        *lane = u64::from_le_bytes(lane_bytes);
+    }
+
+    for round_constant in KECCAK_RC {
+        // Theta
+        let mut columns = [0u64; 5];
+        for (x, column) in columns.iter_mut().enumerate() {
+            *column = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+        // Rho and Pi
+        let mut last = state[1];
+        for (rho, pi) in KECCAK_RHO.iter().zip(KECCAK_PI) {
+            let next = state[pi];
+            state[pi] = last.rotate_left(*rho);
+            last = next;
+        }
+        // Chi
+        for y in 0..5 {
+            let mut row = [0u64; 5];
+            row.copy_from_slice(&state[5 * y..5 * y + 5]);
+            for x in 0..5 {
+                state[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+        // Iota
+        state[0] ^= round_constant;
+    }
+
+    // Store the lanes back as bytes.
+    for (lane, chunk) in state.iter().zip(bytes.chunks_exact_mut(8)) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod merlin;
+pub use merlin::*;
+
 pub mod poseidon;
 pub use poseidon::*;
 
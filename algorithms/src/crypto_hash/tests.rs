@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
-use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
+use crate::{
+    crypto_hash::{keccak_f1600, MerlinSponge, MerlinTranscript, PoseidonSponge},
+    AlgebraicSponge,
+    DuplexSpongeMode,
+};
+use snarkvm_curves::bls12_377::{Fq, Fr};
+use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR, PrimeField};
+use snarkvm_utilities::BigInteger;
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -86,3 +91,46 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>();
     single_rate_test::<8>();
 }
+
+#[test]
+fn test_keccak_f1600_zero_state() {
+    let mut state = [0u8; 200];
+    keccak_f1600(&mut state);
+    assert_eq!(&state[..8], &0xF1258F7940E1DDE7u64.to_le_bytes());
+}
+
+#[test]
+fn test_merlin_transcript_conformance() {
+    // The test vector shared by the reference Merlin implementations.
+    let mut transcript = MerlinTranscript::new(b"test protocol");
+    transcript.append_message(b"some label", b"some data");
+    let mut challenge = [0u8; 32];
+    transcript.challenge_bytes(b"challenge", &mut challenge);
+    let challenge = challenge.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+    assert_eq!(challenge, "d5a21972d0d5fe320c0d263fac7fffb8145aa640af6e9bca177c03c7efcf0615");
+}
+
+#[test]
+fn test_merlin_sponge_consistency() {
+    let parameters = MerlinSponge::<Fq, 2>::sample_parameters();
+
+    let mut sponge = MerlinSponge::<Fq, 2>::new_with_parameters(&parameters);
+    sponge.absorb_native_field_elements(&[Fq::from(1237812u64)]);
+    sponge.absorb_nonnative_field_elements([Fr::from(1237812u64)]);
+    let challenges = sponge.squeeze_nonnative_field_elements::<Fr>(3);
+    assert_eq!(challenges.len(), 3);
+    assert_ne!(challenges[0], challenges[1]);
+    assert!(sponge.squeeze_short_nonnative_field_element::<Fr>().to_bigint().to_biguint().bits() <= 168);
+
+    // Ensure the same transcript yields the same challenges.
+    let mut expected = MerlinSponge::<Fq, 2>::new_with_parameters(&parameters);
+    expected.absorb_native_field_elements(&[Fq::from(1237812u64)]);
+    expected.absorb_nonnative_field_elements([Fr::from(1237812u64)]);
+    assert_eq!(challenges, expected.squeeze_nonnative_field_elements::<Fr>(3));
+
+    // Ensure a different transcript yields different challenges.
+    let mut candidate = MerlinSponge::<Fq, 2>::new_with_parameters(&parameters);
+    candidate.absorb_native_field_elements(&[Fq::from(1237813u64)]);
+    candidate.absorb_nonnative_field_elements([Fr::from(1237812u64)]);
+    assert_ne!(challenges, candidate.squeeze_nonnative_field_elements::<Fr>(3));
+}
@@ -25,6 +25,7 @@
 //! by performing an O(n log n) FFT over such a domain.
 
 use crate::{
+    cfg_chunks,
     cfg_chunks_mut,
     cfg_into_iter,
     cfg_iter,
@@ -345,9 +346,14 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Returns the evaluations of the product over the domain.
     #[must_use]
     pub fn mul_polynomials_in_evaluation_domain(&self, self_evals: Vec<F>, other_evals: &[F]) -> Vec<F> {
+        const CHUNK_SIZE: usize = 1 << 10;
+
         let mut result = self_evals;
 
-        cfg_iter_mut!(result).zip_eq(other_evals).for_each(|(a, b)| *a *= b);
+        // Multiply in chunks, so that each thread can use a vectorized field backend, if one is available.
+        cfg_chunks_mut!(result, CHUNK_SIZE)
+            .zip_eq(cfg_chunks!(other_evals, CHUNK_SIZE))
+            .for_each(|(a, b)| F::batch_product_in_place(a, b));
 
         result
     }
@@ -15,6 +15,9 @@
 pub mod batched;
 pub mod standard;
 
+mod preprocessed;
+pub use preprocessed::PreprocessedBases;
+
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
@@ -27,6 +30,9 @@ pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("msm", size = scalars.len()).entered();
+
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -105,6 +111,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_preprocessed() {
+        use snarkvm_curves::ProjectiveCurve;
+        for msm_size in [1, 5, 10, 50, 100, 500, 1000] {
+            let mut rng = TestRng::default();
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            let preprocessed = PreprocessedBases::new(&bases);
+
+            let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+            let candidate = preprocessed.msm(0, scalars.as_slice()).to_affine();
+            assert_eq!(naive, candidate, "MSM size: {msm_size}");
+
+            // Ensure the MSM over a suffix of the bases is correct.
+            let offset = msm_size / 2;
+            let naive = VariableBase::msm_naive(&bases[offset..], &scalars[offset..]).to_affine();
+            let candidate = preprocessed.msm(offset, &scalars[offset..]).to_affine();
+            assert_eq!(naive, candidate, "MSM size: {msm_size}, offset: {offset}");
+        }
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
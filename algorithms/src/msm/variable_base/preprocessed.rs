@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::batched::{batch_add, BucketPosition};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks_mut, cfg_iter_mut, BigInteger};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Window-decomposed bases for a fixed set of MSM bases.
+///
+/// For every window `j` and base `B_i`, the table stores `2^{c * j} B_i`, where `c` is the window size.
+/// An MSM over these bases then accumulates the digits of all windows into a single set of buckets,
+/// which removes the per-window bucket reductions and doublings of `VariableBase::msm`,
+/// at the cost of storing `num_windows` copies of the bases.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PreprocessedBases<G: AffineCurve> {
+    /// The window size, in bits.
+    window_size: usize,
+    /// The number of original bases.
+    num_bases: usize,
+    /// The shifted bases, where `tables[j * num_bases + i] = 2^{c * j} B_i`.
+    tables: Vec<G>,
+}
+
+impl<G: AffineCurve> PreprocessedBases<G> {
+    /// Initializes the window tables for the given bases.
+    pub fn new(bases: &[G]) -> Self {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("msm_preprocess", size = bases.len()).entered();
+
+        // Determine the window size `c` (chosen empirically).
+        // As the buckets are only reduced once, the windows are wider than in `VariableBase::msm`.
+        let window_size = match bases.len() < 32 {
+            true => 3,
+            false => crate::msm::ln_without_floats(bases.len()) + 4,
+        };
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (num_bits + window_size - 1) / window_size;
+        assert!(bases.len() * num_windows <= u32::MAX as usize, "Too many bases to preprocess");
+
+        // Compute the shifted bases, one window at a time.
+        let mut tables = Vec::with_capacity(bases.len() * num_windows);
+        let mut current = bases.iter().map(|base| base.to_projective()).collect::<Vec<_>>();
+        for window in 0..num_windows {
+            if window > 0 {
+                cfg_iter_mut!(current).for_each(|base| {
+                    for _ in 0..window_size {
+                        base.double_in_place();
+                    }
+                });
+            }
+            tables.extend(G::Projective::batch_normalization_into_affine(current.clone()));
+        }
+
+        Self { window_size, num_bases: bases.len(), tables }
+    }
+
+    /// Returns the number of bases that were preprocessed.
+    pub const fn len(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns `true` if there are no preprocessed bases.
+    pub const fn is_empty(&self) -> bool {
+        self.num_bases == 0
+    }
+
+    /// Returns the MSM of `scalars` with the bases `offset..offset + scalars.len()`.
+    pub fn msm(&self, offset: usize, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        assert!(offset + scalars.len() <= self.num_bases, "Insufficient number of preprocessed bases");
+        if scalars.is_empty() {
+            return G::Projective::zero();
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("msm_preprocessed", size = scalars.len()).entered();
+
+        // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
+        let c = self.window_size;
+        let num_buckets = (1 << c) - 1;
+        let num_windows = self.tables.len() / self.num_bases;
+
+        // Assign every digit of every scalar to a bucket, pointing at the base shifted for its window.
+        let mut bucket_positions: Vec<_> = (0..num_windows)
+            .flat_map(|window| {
+                scalars.iter().enumerate().map(move |(i, &scalar)| {
+                    let mut scalar = scalar;
+
+                    // We right-shift by the window start, thus getting rid of the lower bits.
+                    scalar.divn((window * c) as u32);
+
+                    // We mod the remaining bits by the window size.
+                    let digit = (scalar.as_ref()[0] % (1 << c)) as u32;
+
+                    BucketPosition {
+                        bucket_index: digit.wrapping_sub(1),
+                        scalar_index: (window * self.num_bases + offset + i) as u32,
+                    }
+                })
+            })
+            .collect();
+
+        // Accumulate the buckets in chunks, and reduce each chunk's buckets.
+        #[cfg(not(feature = "serial"))]
+        let num_chunks = rayon::current_num_threads();
+        #[cfg(feature = "serial")]
+        let num_chunks = 1;
+        let chunk_size = (bucket_positions.len() + num_chunks - 1) / num_chunks;
+
+        cfg_chunks_mut!(bucket_positions, chunk_size)
+            .map(|bucket_positions| {
+                let buckets = batch_add(num_buckets, &self.tables, bucket_positions);
+
+                let mut res = G::Projective::zero();
+                let mut running_sum = G::Projective::zero();
+                for b in buckets.into_iter().rev() {
+                    running_sum.add_assign_mixed(&b);
+                    res += &running_sum;
+                }
+                res
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    FromBytes,
+    ToBytes,
+};
+
+use std::io;
+
+/// `CommitterKey` contains the generators used to commit to and open polynomials.
+/// As the scheme is transparent, the same generators are used to check evaluation proofs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey<G: AffineCurve> {
+    /// The generators for the coefficients of a polynomial.
+    pub generators: Vec<G>,
+    /// The generator that is used for making a commitment hiding.
+    pub h: G,
+    /// The generator that is used to bind the evaluation in the inner-product argument.
+    pub u: G,
+}
+
+impl<G: AffineCurve> CommitterKey<G> {
+    /// The number of coefficients supported by `self`.
+    pub fn size(&self) -> usize {
+        self.generators.len()
+    }
+}
+
+/// `UniversalParams` are the universal parameters for the IPA scheme.
+pub type UniversalParams<G> = CommitterKey<G>;
+
+/// `VerifierKey` is used to check evaluation proofs for a given commitment.
+pub type VerifierKey<G> = CommitterKey<G>;
+
+/// `IPACommitment` commits to a polynomial. It is output by `IPA::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPACommitment<G: AffineCurve>(
+    /// The commitment is a group element.
+    pub G,
+);
+
+impl<G: AffineCurve> FromBytes for IPACommitment<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize IPACommitment"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for IPACommitment<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize IPACommitment"))
+    }
+}
+
+/// `IPARandomness` hides the polynomial inside a commitment. It is output by `IPA::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IPARandomness<G: AffineCurve> {
+    /// The blinding factor of the commitment, if the commitment is hiding.
+    pub rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> IPARandomness<G> {
+    /// Does `self` provide any hiding properties to the corresponding commitment?
+    pub fn is_hiding(&self) -> bool {
+        self.rand.is_some()
+    }
+}
+
+/// `IPAProof` is an evaluation proof that is output by `IPA::open`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IPAProof<G: AffineCurve> {
+    /// The left cross-term commitments, one for each round of the inner-product argument.
+    pub l_vec: Vec<G>,
+    /// The right cross-term commitments, one for each round of the inner-product argument.
+    pub r_vec: Vec<G>,
+    /// The last coefficient of the polynomial, after it is folded in every round.
+    pub final_coefficient: G::ScalarField,
+    /// The commitment to the masking polynomial, if the commitment is hiding.
+    pub hiding_commitment: Option<G>,
+    /// The combined blinding factor of the commitment and the masking polynomial, if the commitment is hiding.
+    pub random_v: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> IPAProof<G> {
+    pub fn is_hiding(&self) -> bool {
+        self.hiding_commitment.is_some()
+    }
+}
+
+impl<G: AffineCurve> FromBytes for IPAProof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize IPA proof"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for IPAProof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize IPA proof"))
+    }
+}
@@ -0,0 +1,493 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Here we construct a transparent polynomial commitment scheme from the inner-product argument
+//! of [Bulletproofs](https://eprint.iacr.org/2017/1066), in the form used by [Halo](https://eprint.iacr.org/2019/1021).
+//! The generators are obtained by hashing to the curve, so the scheme requires no trusted setup,
+//! at the cost of logarithmic-size evaluation proofs and a linear-time verifier.
+//!
+//! The scheme is standalone, as Marlin commits with `SonicKZG10` and does not use `PolynomialCommitment`.
+
+use crate::{
+    crypto_hash::sha256,
+    fft::DensePolynomial,
+    msm::VariableBase,
+    polycommit::{PCError, PolynomialCommitment},
+};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, rand::Uniform, ToBytes};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+use rand_chacha::ChaChaRng;
+use rand_core::{RngCore, SeedableRng};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// The domain separator for the generators of the IPA scheme.
+const GENERATOR_DOMAIN: &str = "AleoIPAGenerator0";
+/// The domain separator for the Fiat-Shamir transcript of the IPA scheme.
+const TRANSCRIPT_DOMAIN: &str = "AleoIPATranscript0";
+
+/// `IPA` is an implementation of the polynomial commitment scheme from the inner-product argument
+/// of [Bünz et al.][bulletproofs], as adapted to polynomial commitments by [Bowe, Grigg and Hopwood][halo].
+///
+/// [bulletproofs]: https://eprint.iacr.org/2017/1066
+/// [halo]: https://eprint.iacr.org/2019/1021
+#[derive(Clone, Debug)]
+pub struct IPA<G: AffineCurve>(PhantomData<G>);
+
+impl<G: AffineCurve> IPA<G> {
+    /// Constructs public parameters for polynomials up to the given maximum degree.
+    pub fn setup(max_degree: usize) -> Result<UniversalParams<G>, PCError> {
+        let setup_time = start_timer!(|| format!("Sampling IPA generators for degree {max_degree}"));
+        let size = Self::num_coefficients(max_degree);
+        let generators = cfg_into_iter!(0..size).map(|i| Self::hash_to_generator("G", i)).collect::<Result<_, _>>()?;
+        let h = Self::hash_to_generator("H", 0)?;
+        let u = Self::hash_to_generator("U", 0)?;
+        end_timer!(setup_time);
+        Ok(UniversalParams { generators, h, u })
+    }
+
+    /// Specializes the public parameters for polynomials up to the given supported degree.
+    pub fn trim(
+        pp: &UniversalParams<G>,
+        supported_degree: usize,
+    ) -> Result<(CommitterKey<G>, VerifierKey<G>), PCError> {
+        let size = Self::num_coefficients(supported_degree);
+        if size > pp.size() {
+            return Err(PCError::TrimmingDegreeTooLarge);
+        }
+        let ck = CommitterKey { generators: pp.generators[..size].to_vec(), h: pp.h, u: pp.u };
+        let vk = ck.clone();
+        Ok((ck, vk))
+    }
+
+    /// Outputs a commitment to `polynomial`, which is hiding if `hiding` is `true`.
+    pub fn commit(
+        ck: &CommitterKey<G>,
+        polynomial: &DensePolynomial<G::ScalarField>,
+        hiding: bool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(IPACommitment<G>, IPARandomness<G>), PCError> {
+        Self::check_degree_is_too_large(polynomial, ck.size())?;
+        let commit_time = start_timer!(|| format!("Committing to polynomial of degree {}", polynomial.degree()));
+
+        let randomness = match hiding {
+            true => IPARandomness { rand: Some(G::ScalarField::rand(rng.ok_or(PCError::MissingRng)?)) },
+            false => IPARandomness { rand: None },
+        };
+        let commitment = Self::commit_with_randomness(ck, &polynomial.coeffs, randomness.rand);
+
+        end_timer!(commit_time);
+        Ok((IPACommitment(commitment.to_affine()), randomness))
+    }
+
+    /// On input a polynomial `p` and a point `point`, outputs a proof for the same.
+    ///
+    /// If the commitment is hiding, the polynomial is masked by a random polynomial that vanishes at `point`,
+    /// which is derived deterministically from the commitment randomness and the point.
+    pub fn open(
+        ck: &CommitterKey<G>,
+        polynomial: &DensePolynomial<G::ScalarField>,
+        point: G::ScalarField,
+        randomness: &IPARandomness<G>,
+    ) -> Result<IPAProof<G>, PCError> {
+        Self::check_degree_is_too_large(polynomial, ck.size())?;
+        let open_time = start_timer!(|| format!("Opening polynomial of degree {}", polynomial.degree()));
+
+        let size = ck.size();
+        let value = polynomial.evaluate(point);
+        let commitment = Self::commit_with_randomness(ck, &polynomial.coeffs, randomness.rand).to_affine();
+
+        // Initialize the transcript with the statement.
+        let mut transcript = Transcript::new();
+        transcript.absorb(&commitment)?;
+        transcript.absorb(&point)?;
+        transcript.absorb(&value)?;
+
+        let mut coeffs = polynomial.coeffs.clone();
+        coeffs.resize(size, G::ScalarField::zero());
+
+        // If the commitment is hiding, mask the polynomial with a random polynomial that vanishes at `point`.
+        let (hiding_commitment, random_v) = match randomness.rand {
+            Some(rand) => {
+                let mut rng = ChaChaRng::from_seed(sha256(&(rand, point).to_bytes_le()?));
+                // Compute the masking polynomial `(X - point) * q(X)`, for a random `q` of degree `size - 2`.
+                let q = (0..size - 1).map(|_| G::ScalarField::rand(&mut rng)).collect::<Vec<_>>();
+                let mut mask = vec![G::ScalarField::zero(); size];
+                for (i, q_i) in q.iter().enumerate() {
+                    mask[i] -= point * q_i;
+                    mask[i + 1] += q_i;
+                }
+                let mask_rand = G::ScalarField::rand(&mut rng);
+                let hiding_commitment = Self::commit_with_randomness(ck, &mask, Some(mask_rand)).to_affine();
+
+                transcript.absorb(&hiding_commitment)?;
+                let alpha = transcript.challenge::<G::ScalarField>()?;
+                cfg_iter_mut!(coeffs).zip(&mask).for_each(|(c, m)| *c += alpha * m);
+                (Some(hiding_commitment), Some(rand + alpha * mask_rand))
+            }
+            None => (None, None),
+        };
+
+        // Bind the evaluation to the inner-product argument.
+        let xi = transcript.challenge::<G::ScalarField>()?;
+        let u = (ck.u * xi).to_affine();
+
+        let mut a = coeffs;
+        let mut b = Self::powers_of(point, size);
+        let mut generators = ck.generators.clone();
+        let mut l_vec = Vec::with_capacity(size.trailing_zeros() as usize);
+        let mut r_vec = Vec::with_capacity(size.trailing_zeros() as usize);
+
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_l, a_r) = a.split_at(half);
+            let (b_l, b_r) = b.split_at(half);
+            let (g_l, g_r) = generators.split_at(half);
+
+            // Compute the cross-term commitments for this round.
+            let l = VariableBase::msm(g_r, &Self::to_bigints(a_l)) + u * Self::inner_product(a_l, b_r);
+            let r = VariableBase::msm(g_l, &Self::to_bigints(a_r)) + u * Self::inner_product(a_r, b_l);
+            let [l, r]: [G; 2] = G::Projective::batch_normalization_into_affine(vec![l, r]).try_into().unwrap();
+
+            transcript.absorb(&l)?;
+            transcript.absorb(&r)?;
+            let x = transcript.challenge::<G::ScalarField>()?;
+            let x_inv = x.inverse().ok_or_else(|| anyhow!("Found a zero challenge in the IPA transcript"))?;
+
+            // Fold the coefficients, powers of the point, and generators.
+            let next_a = cfg_iter!(a_l).zip(a_r).map(|(a_l, a_r)| *a_l * x + *a_r * x_inv).collect();
+            let next_b = cfg_iter!(b_l).zip(b_r).map(|(b_l, b_r)| *b_l * x_inv + *b_r * x).collect();
+            let next_generators = cfg_iter!(g_l).zip(g_r).map(|(g_l, g_r)| *g_l * x_inv + *g_r * x).collect();
+
+            a = next_a;
+            b = next_b;
+            generators = G::Projective::batch_normalization_into_affine(next_generators);
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+
+        end_timer!(open_time);
+        Ok(IPAProof { l_vec, r_vec, final_coefficient: a[0], hiding_commitment, random_v })
+    }
+
+    /// Verifies that `value` is the evaluation at `point` of the polynomial
+    /// committed inside `commitment`.
+    pub fn check(
+        vk: &VerifierKey<G>,
+        commitment: &IPACommitment<G>,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        proof: &IPAProof<G>,
+    ) -> Result<bool, PCError> {
+        let check_time = start_timer!(|| "Checking evaluation");
+        let size = vk.size();
+        let num_rounds = size.trailing_zeros() as usize;
+        if proof.l_vec.len() != num_rounds || proof.r_vec.len() != num_rounds {
+            end_timer!(check_time, || "Result: false");
+            return Ok(false);
+        }
+
+        // Initialize the transcript with the statement.
+        let mut transcript = Transcript::new();
+        transcript.absorb(&commitment.0)?;
+        transcript.absorb(&point)?;
+        transcript.absorb(&value)?;
+
+        // If the commitment is hiding, remove the blinding factor and add the masking polynomial.
+        let mut combined_commitment = commitment.0.to_projective();
+        match (proof.hiding_commitment, proof.random_v) {
+            (Some(hiding_commitment), Some(random_v)) => {
+                transcript.absorb(&hiding_commitment)?;
+                let alpha = transcript.challenge::<G::ScalarField>()?;
+                combined_commitment += hiding_commitment * alpha;
+                combined_commitment -= vk.h * random_v;
+            }
+            (None, None) => (),
+            _ => {
+                end_timer!(check_time, || "Result: false");
+                return Ok(false);
+            }
+        }
+
+        // Bind the evaluation to the inner-product argument.
+        let xi = transcript.challenge::<G::ScalarField>()?;
+        let u = vk.u * xi;
+        let mut lhs = combined_commitment + u * value;
+
+        // Compute the coefficients of the folded generator, and the folded powers of the point.
+        let mut scalars = vec![G::ScalarField::one()];
+        let mut final_b = G::ScalarField::one();
+        // The powers of the point are `point^(size / 2^(i + 1))`, which are computed in reverse order.
+        let mut powers = Vec::with_capacity(num_rounds);
+        let mut power = point;
+        for _ in 0..num_rounds {
+            powers.push(power);
+            power.square_in_place();
+        }
+        for ((l, r), power) in proof.l_vec.iter().zip(&proof.r_vec).zip(powers.iter().rev()) {
+            transcript.absorb(l)?;
+            transcript.absorb(r)?;
+            let x = transcript.challenge::<G::ScalarField>()?;
+            let x_inv = x.inverse().ok_or_else(|| anyhow!("Found a zero challenge in the IPA transcript"))?;
+
+            lhs += *l * x.square();
+            lhs += *r * x_inv.square();
+            scalars = scalars.iter().flat_map(|s| [*s * x_inv, *s * x]).collect();
+            final_b *= x_inv + x * power;
+        }
+        let final_generator = VariableBase::msm(&vk.generators, &Self::to_bigints(&scalars));
+        let rhs = final_generator * proof.final_coefficient + u * (proof.final_coefficient * final_b);
+
+        end_timer!(check_time, || format!("Result: {}", lhs == rhs));
+        Ok(lhs == rhs)
+    }
+
+    /// Returns the commitment to the given coefficients, with the given blinding factor.
+    fn commit_with_randomness(
+        ck: &CommitterKey<G>,
+        coeffs: &[G::ScalarField],
+        rand: Option<G::ScalarField>,
+    ) -> G::Projective {
+        let mut commitment = VariableBase::msm(&ck.generators[..coeffs.len()], &Self::to_bigints(coeffs));
+        if let Some(rand) = rand {
+            commitment += ck.h * rand;
+        }
+        commitment
+    }
+
+    /// Samples the generator with the given label and index, by hashing to the curve.
+    fn hash_to_generator(label: &str, index: usize) -> Result<G, PCError> {
+        let serialized_size = G::prime_subgroup_generator().compressed_size();
+        // Attempt to increment the counter at most 256 times.
+        for counter in 0..256 {
+            // Expand the digest to the serialized size of the generator.
+            let mut bytes = Vec::with_capacity(serialized_size + 32);
+            for block in 0.. {
+                if bytes.len() >= serialized_size {
+                    break;
+                }
+                let message = format!("{GENERATOR_DOMAIN} {label} {index} in {counter} block {block}");
+                bytes.extend_from_slice(&sha256(message.as_bytes()));
+            }
+            bytes.truncate(serialized_size);
+            // Output the generator if a valid generator was found.
+            if let Some(g) = G::from_random_bytes(&bytes).map(|g| g.mul_by_cofactor()) {
+                if !g.is_zero() {
+                    return Ok(g);
+                }
+            }
+        }
+        Err(anyhow!("Unable to hash to curve on {label} {index}").into())
+    }
+
+    /// Returns the number of coefficients (a power of two) needed to support the given degree.
+    fn num_coefficients(degree: usize) -> usize {
+        core::cmp::max(degree + 1, 2).next_power_of_two()
+    }
+
+    /// Returns the first `size` powers of `point`.
+    fn powers_of(point: G::ScalarField, size: usize) -> Vec<G::ScalarField> {
+        let mut powers = Vec::with_capacity(size);
+        let mut power = G::ScalarField::one();
+        for _ in 0..size {
+            powers.push(power);
+            power *= point;
+        }
+        powers
+    }
+
+    /// Returns the inner product of the given vectors.
+    fn inner_product(a: &[G::ScalarField], b: &[G::ScalarField]) -> G::ScalarField {
+        a.iter().zip(b).map(|(a, b)| *a * b).sum()
+    }
+
+    fn to_bigints(scalars: &[G::ScalarField]) -> Vec<<G::ScalarField as PrimeField>::BigInteger> {
+        cfg_iter!(scalars).map(|s| s.to_bigint()).collect()
+    }
+
+    fn check_degree_is_too_large(polynomial: &DensePolynomial<G::ScalarField>, size: usize) -> Result<(), PCError> {
+        if polynomial.coeffs.len() > size {
+            Err(PCError::TooManyCoefficients { num_coefficients: polynomial.coeffs.len(), num_powers: size })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<G: AffineCurve> PolynomialCommitment<G::ScalarField> for IPA<G> {
+    type Commitment = IPACommitment<G>;
+    type CommitterKey = CommitterKey<G>;
+    type Proof = IPAProof<G>;
+    type Randomness = IPARandomness<G>;
+    type UniversalParams = UniversalParams<G>;
+    type VerifierKey = VerifierKey<G>;
+
+    fn setup(max_degree: usize) -> Result<Self::UniversalParams, PCError> {
+        Self::setup(max_degree)
+    }
+
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+        _hiding_bound: Option<usize>,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), PCError> {
+        Self::trim(pp, supported_degree)
+    }
+
+    fn commit(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<G::ScalarField>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Self::Commitment, Self::Randomness), PCError> {
+        Self::commit(ck, polynomial, hiding_bound.is_some(), rng)
+    }
+
+    fn open(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<G::ScalarField>,
+        point: G::ScalarField,
+        randomness: &Self::Randomness,
+    ) -> Result<Self::Proof, PCError> {
+        Self::open(ck, polynomial, point, randomness)
+    }
+
+    fn check(
+        vk: &Self::VerifierKey,
+        commitment: &Self::Commitment,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        proof: &Self::Proof,
+    ) -> Result<bool, PCError> {
+        Self::check(vk, commitment, point, value, proof)
+    }
+}
+
+/// A Fiat-Shamir transcript for the IPA scheme, which hashes every message into its state.
+struct Transcript([u8; 32]);
+
+impl Transcript {
+    /// Initializes a new transcript.
+    fn new() -> Self {
+        Self(sha256(TRANSCRIPT_DOMAIN.as_bytes()))
+    }
+
+    /// Absorbs the given message into the transcript.
+    fn absorb(&mut self, message: &impl ToBytes) -> Result<(), PCError> {
+        let mut bytes = self.0.to_vec();
+        message.write_le(&mut bytes).map_err(anyhow::Error::from)?;
+        self.0 = sha256(&bytes);
+        Ok(())
+    }
+
+    /// Squeezes a nonzero challenge from the transcript.
+    fn challenge<F: PrimeField>(&mut self) -> Result<F, PCError> {
+        self.0 = sha256(&self.0);
+        let challenge = F::from_bytes_le_mod_order(&self.0);
+        match challenge.is_zero() {
+            true => Err(anyhow!("Found a zero challenge in the IPA transcript").into()),
+            false => Ok(challenge),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+    use super::*;
+    use crate::polycommit::test_templates::polynomial_commitment_test;
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    use snarkvm_utilities::rand::TestRng;
+
+    type IPA_Bls12_377 = IPA<G1Affine>;
+
+    #[test]
+    fn test_polynomial_commitment() {
+        polynomial_commitment_test::<Fr, IPA_Bls12_377>(None).expect("test failed for bls12-377");
+        polynomial_commitment_test::<Fr, IPA_Bls12_377>(Some(1)).expect("test failed for bls12-377");
+    }
+
+    #[test]
+    fn test_setup_is_deterministic() {
+        let pp = IPA_Bls12_377::setup(15).unwrap();
+        assert_eq!(16, pp.size());
+        assert_eq!(pp, IPA_Bls12_377::setup(15).unwrap());
+        // Ensure the generators are distinct.
+        let mut generators = pp.generators.clone();
+        generators.extend([pp.h, pp.u]);
+        generators.sort_by_key(|g| g.to_bytes_le().unwrap());
+        generators.dedup();
+        assert_eq!(18, generators.len());
+        // Ensure a smaller setup is a prefix of a larger setup.
+        let (ck, _) = IPA_Bls12_377::trim(&pp, 7).unwrap();
+        assert_eq!(IPA_Bls12_377::setup(7).unwrap(), ck);
+        assert!(IPA_Bls12_377::trim(&pp, 16).is_err());
+    }
+
+    #[test]
+    fn test_hiding_proof() {
+        let rng = &mut TestRng::default();
+
+        let pp = IPA_Bls12_377::setup(31).unwrap();
+        let (ck, vk) = IPA_Bls12_377::trim(&pp, 31).unwrap();
+
+        let polynomial = DensePolynomial::rand(31, rng);
+        let (commitment, randomness) = IPA_Bls12_377::commit(&ck, &polynomial, true, Some(rng)).unwrap();
+        assert!(randomness.is_hiding());
+        // Ensure the hiding commitment differs from the non-hiding commitment.
+        let (plain_commitment, _) = IPA_Bls12_377::commit(&ck, &polynomial, false, None).unwrap();
+        assert_ne!(commitment, plain_commitment);
+
+        let point = Fr::rand(rng);
+        let value = polynomial.evaluate(point);
+        let proof = IPA_Bls12_377::open(&ck, &polynomial, point, &randomness).unwrap();
+        assert!(proof.is_hiding());
+        assert!(IPA_Bls12_377::check(&vk, &commitment, point, value, &proof).unwrap());
+        // Ensure the proof does not verify against the non-hiding commitment.
+        assert!(!IPA_Bls12_377::check(&vk, &plain_commitment, point, value, &proof).unwrap());
+
+        // Ensure a proof with a missing masking commitment is rejected.
+        let mut bad_proof = proof.clone();
+        bad_proof.hiding_commitment = None;
+        assert!(!IPA_Bls12_377::check(&vk, &commitment, point, value, &bad_proof).unwrap());
+        // Ensure a proof with a truncated argument is rejected.
+        let mut bad_proof = proof.clone();
+        bad_proof.l_vec.pop();
+        assert!(!IPA_Bls12_377::check(&vk, &commitment, point, value, &bad_proof).unwrap());
+        // Ensure a proof with a modified final coefficient is rejected.
+        let mut bad_proof = proof;
+        bad_proof.final_coefficient += Fr::one();
+        assert!(!IPA_Bls12_377::check(&vk, &commitment, point, value, &bad_proof).unwrap());
+    }
+
+    #[test]
+    fn test_degree_is_too_large() {
+        let rng = &mut TestRng::default();
+
+        let pp = IPA_Bls12_377::setup(7).unwrap();
+        let (ck, _) = IPA_Bls12_377::trim(&pp, 7).unwrap();
+
+        let polynomial = DensePolynomial::<Fr>::rand(8, rng);
+        assert!(IPA_Bls12_377::commit(&ck, &polynomial, false, None).is_err());
+        assert!(IPA_Bls12_377::open(&ck, &polynomial, Fr::rand(rng), &IPARandomness::default()).is_err());
+    }
+}
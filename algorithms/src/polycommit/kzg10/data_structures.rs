@@ -14,6 +14,7 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    msm::PreprocessedBases,
     AlgebraicSponge,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -33,6 +34,7 @@ use crate::srs::{UniversalProver, UniversalVerifier};
 use anyhow::Result;
 use core::ops::{Add, AddAssign};
 use parking_lot::RwLock;
+use rand::{CryptoRng, Rng};
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
 
@@ -62,6 +64,17 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(Self { powers, h, prepared_h, prepared_beta_h })
     }
 
+    /// Samples fresh universal parameters with `num_powers` powers of beta G, without downloading any powers.
+    /// This setup is **insecure**, and must only be used for development and testing.
+    pub fn insecure_setup<R: Rng + CryptoRng>(num_powers: usize, rng: &mut R) -> Result<Self> {
+        let powers = Arc::new(RwLock::new(PowersOfG::<E>::insecure_setup(num_powers, rng)?));
+        let h = E::G2Affine::prime_subgroup_generator();
+        let prepared_h = h.prepare();
+        let prepared_beta_h = powers.read().beta_h().prepare();
+
+        Ok(Self { powers, h, prepared_h, prepared_beta_h })
+    }
+
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
         self.powers.write().download_powers_for(range)
     }
@@ -154,6 +167,8 @@ pub struct Powers<'a, E: PairingEngine> {
     pub powers_of_beta_g: Cow<'a, [E::G1Affine]>,
     /// Group elements of the form `β^i γG`, for different values of `i`.
     pub powers_of_beta_times_gamma_g: Cow<'a, [E::G1Affine]>,
+    /// The window tables of `powers_of_beta_g`, if they have been precomputed.
+    pub preprocessed_powers_of_beta_g: Option<&'a PreprocessedBases<E::G1Affine>>,
 }
 
 impl<E: PairingEngine> Powers<'_, E> {
@@ -172,6 +187,8 @@ pub struct LagrangeBasis<'a, E: PairingEngine> {
     /// Domain representing the multiplicative subgroup the powers
     /// in `self.lagrange_basis_at_beta_g` are defined over.
     pub domain: EvaluationDomain<E::Fr>,
+    /// The window tables of `lagrange_basis_at_beta_g`, if they have been precomputed.
+    pub preprocessed_lagrange_basis_at_beta_g: Option<&'a PreprocessedBases<E::G1Affine>>,
 }
 
 impl<E: PairingEngine> LagrangeBasis<'_, E> {
@@ -21,8 +21,8 @@
 
 use crate::{
     fft::{DensePolynomial, Polynomial},
-    msm::VariableBase,
-    polycommit::PCError,
+    msm::{PreprocessedBases, VariableBase},
+    polycommit::{PCError, PolynomialCommitment},
 };
 use anyhow::anyhow;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -32,6 +32,7 @@ use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
 use core::{marker::PhantomData, ops::Mul};
 use itertools::Itertools;
 use rand_core::RngCore;
+use std::borrow::Cow;
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -93,6 +94,46 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(params)
     }
 
+    /// Specializes the public parameters for polynomials up to the given supported degree,
+    /// which should be less than `pp.max_degree()`.
+    pub fn trim(
+        pp: &UniversalParams<E>,
+        mut supported_degree: usize,
+        hiding_bound: Option<usize>,
+    ) -> Result<(Powers<'static, E>, VerifierKey<E>), PCError> {
+        if supported_degree == 1 {
+            supported_degree += 1;
+        }
+        let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1)?;
+
+        let powers_of_beta_times_gamma_g = match hiding_bound {
+            Some(hiding_bound) => (0..=(hiding_bound + 1))
+                .map(|i| {
+                    pp.powers_of_beta_times_gamma_g().get(&i).copied().ok_or(PCError::HidingBoundToolarge {
+                        hiding_poly_degree: hiding_bound,
+                        num_powers: pp.powers_of_beta_times_gamma_g().len(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => vec![],
+        };
+
+        let powers = Powers {
+            powers_of_beta_g: Cow::Owned(powers_of_beta_g),
+            powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
+            preprocessed_powers_of_beta_g: None,
+        };
+        let vk = VerifierKey {
+            g: pp.power_of_beta_g(0)?,
+            gamma_g: pp.powers_of_beta_times_gamma_g()[&0],
+            h: pp.h,
+            beta_h: pp.beta_h(),
+            prepared_h: pp.prepared_h.clone(),
+            prepared_beta_h: pp.prepared_beta_h.clone(),
+        };
+        Ok((powers, vk))
+    }
+
     /// Outputs a commitment to `polynomial`.
     pub fn commit(
         powers: &Powers<E>,
@@ -112,10 +153,13 @@ impl<E: PairingEngine> KZG10<E> {
             Polynomial::Dense(polynomial) => {
                 let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
 
-                let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + plain_coeffs.len())];
-
                 let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-                let commitment = VariableBase::msm(bases, &plain_coeffs);
+                let commitment = Self::msm_with_offset(
+                    &powers.powers_of_beta_g,
+                    powers.preprocessed_powers_of_beta_g,
+                    num_leading_zeros,
+                    &plain_coeffs,
+                );
                 end_timer!(msm_time);
 
                 commitment
@@ -175,7 +219,12 @@ impl<E: PairingEngine> KZG10<E> {
 
         let evaluations = evaluations.iter().map(|e| e.to_bigint()).collect::<Vec<_>>();
         let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-        let mut commitment = VariableBase::msm(&lagrange_basis.lagrange_basis_at_beta_g, &evaluations);
+        let mut commitment = Self::msm_with_offset(
+            &lagrange_basis.lagrange_basis_at_beta_g,
+            lagrange_basis.preprocessed_lagrange_basis_at_beta_g,
+            0,
+            &evaluations,
+        );
         end_timer!(msm_time);
 
         let mut randomness = KZGRandomness::empty();
@@ -245,10 +294,13 @@ impl<E: PairingEngine> KZG10<E> {
         Self::check_degree_is_too_large(witness_polynomial.degree(), powers.size())?;
         let (num_leading_zeros, witness_coeffs) = skip_leading_zeros_and_convert_to_bigints(witness_polynomial);
 
-        let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + witness_coeffs.len())];
-
         let witness_comm_time = start_timer!(|| "Computing commitment to witness polynomial");
-        let mut w = VariableBase::msm(bases, &witness_coeffs);
+        let mut w = Self::msm_with_offset(
+            &powers.powers_of_beta_g,
+            powers.preprocessed_powers_of_beta_g,
+            num_leading_zeros,
+            &witness_coeffs,
+        );
         end_timer!(witness_comm_time);
 
         let random_v = if let Some(hiding_witness_polynomial) = hiding_witness_polynomial {
@@ -400,6 +452,22 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(result)
     }
 
+    /// Returns the MSM of `scalars` with the bases `offset..offset + scalars.len()`,
+    /// using the precomputed window tables of the bases if they are available.
+    fn msm_with_offset(
+        bases: &[E::G1Affine],
+        preprocessed_bases: Option<&PreprocessedBases<E::G1Affine>>,
+        offset: usize,
+        scalars: &[<E::Fr as PrimeField>::BigInteger],
+    ) -> E::G1Projective {
+        match preprocessed_bases {
+            Some(preprocessed_bases) if offset + scalars.len() <= preprocessed_bases.len() => {
+                preprocessed_bases.msm(offset, scalars)
+            }
+            _ => VariableBase::msm(&bases[offset..(offset + scalars.len())], scalars),
+        }
+    }
+
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
         let num_coefficients = degree + 1;
         if num_coefficients > num_powers {
@@ -448,6 +516,55 @@ impl<E: PairingEngine> KZG10<E> {
     }
 }
 
+impl<E: PairingEngine> PolynomialCommitment<E::Fr> for KZG10<E> {
+    type Commitment = KZGCommitment<E>;
+    type CommitterKey = Powers<'static, E>;
+    type Proof = KZGProof<E>;
+    type Randomness = KZGRandomness<E>;
+    type UniversalParams = UniversalParams<E>;
+    type VerifierKey = VerifierKey<E>;
+
+    fn setup(max_degree: usize) -> Result<Self::UniversalParams, PCError> {
+        Self::load_srs(max_degree)
+    }
+
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+        hiding_bound: Option<usize>,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), PCError> {
+        Self::trim(pp, supported_degree, hiding_bound)
+    }
+
+    fn commit(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<E::Fr>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Self::Commitment, Self::Randomness), PCError> {
+        Self::commit(ck, &polynomial.into(), hiding_bound, rng)
+    }
+
+    fn open(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<E::Fr>,
+        point: E::Fr,
+        randomness: &Self::Randomness,
+    ) -> Result<Self::Proof, PCError> {
+        Self::open(ck, polynomial, point, randomness)
+    }
+
+    fn check(
+        vk: &Self::VerifierKey,
+        commitment: &Self::Commitment,
+        point: E::Fr,
+        value: E::Fr,
+        proof: &Self::Proof,
+    ) -> Result<bool, PCError> {
+        Self::check(vk, commitment, point, value, proof)
+    }
+}
+
 fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<F>) -> (usize, Vec<F::BigInteger>) {
     if p.coeffs.is_empty() {
         (0, vec![])
@@ -476,45 +593,8 @@ mod tests {
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
 
-    use std::borrow::Cow;
-
     type KZG_Bls12_377 = KZG10<Bls12_377>;
 
-    impl<E: PairingEngine> KZG10<E> {
-        /// Specializes the public parameters for a given maximum degree `d` for polynomials
-        /// `d` should be less that `pp.max_degree()`.
-        pub(crate) fn trim(
-            pp: &UniversalParams<E>,
-            mut supported_degree: usize,
-            hiding_bound: Option<usize>,
-        ) -> (Powers<E>, VerifierKey<E>) {
-            if supported_degree == 1 {
-                supported_degree += 1;
-            }
-            let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1).unwrap().to_vec();
-
-            let powers_of_beta_times_gamma_g = if let Some(hiding_bound) = hiding_bound {
-                (0..=(hiding_bound + 1)).map(|i| pp.powers_of_beta_times_gamma_g()[&i]).collect()
-            } else {
-                vec![]
-            };
-
-            let powers = Powers {
-                powers_of_beta_g: Cow::Owned(powers_of_beta_g),
-                powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
-            };
-            let vk = VerifierKey {
-                g: pp.power_of_beta_g(0).unwrap(),
-                gamma_g: pp.powers_of_beta_times_gamma_g()[&0],
-                h: pp.h,
-                beta_h: pp.beta_h(),
-                prepared_h: pp.prepared_h.clone(),
-                prepared_beta_h: pp.prepared_beta_h.clone(),
-            };
-            (powers, vk)
-        }
-    }
-
     #[test]
     fn test_kzg10_universal_params_serialization() {
         let degree = 4;
@@ -536,7 +616,7 @@ mod tests {
             }
             let pp = KZG10::<E>::load_srs(degree)?;
             let hiding_bound = Some(1);
-            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound)?;
             let p = DensePolynomial::rand(degree, rng);
             let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
             let point = E::Fr::rand(rng);
@@ -559,7 +639,7 @@ mod tests {
             let degree = 50;
             let pp = KZG10::<E>::load_srs(degree)?;
             let hiding_bound = Some(1);
-            let (ck, vk) = KZG10::trim(&pp, 2, hiding_bound);
+            let (ck, vk) = KZG10::trim(&pp, 2, hiding_bound)?;
             let p = DensePolynomial::rand(1, rng);
             let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
             let point = E::Fr::rand(rng);
@@ -585,7 +665,7 @@ mod tests {
                 degree = usize::rand(rng) % 20;
             }
             let pp = KZG10::<E>::load_srs(degree)?;
-            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound)?;
 
             let mut comms = Vec::new();
             let mut values = Vec::new();
@@ -625,13 +705,21 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_polynomial_commitment() {
+        use crate::polycommit::test_templates::polynomial_commitment_test;
+
+        polynomial_commitment_test::<Fr, KZG_Bls12_377>(None).expect("test failed for bls12-377");
+        polynomial_commitment_test::<Fr, KZG_Bls12_377>(Some(1)).expect("test failed for bls12-377");
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
 
         let max_degree = 123;
         let pp = KZG_Bls12_377::load_srs(max_degree).unwrap();
-        let (powers, _) = KZG_Bls12_377::trim(&pp, max_degree, None);
+        let (powers, _) = KZG_Bls12_377::trim(&pp, max_degree, None).unwrap();
 
         let p = DensePolynomial::<Fr>::rand(max_degree + 1, rng);
         assert!(p.degree() > max_degree);
@@ -23,6 +23,13 @@
 /// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
 pub mod kzg10;
 
+/// A transparent polynomial commitment scheme based on the inner-product argument
+/// of [\[BBBPWM18, “Bulletproofs”\]][bulletproofs], as used in [\[BGH19, “Halo”\]][halo].
+///
+/// [bulletproofs]: https://eprint.iacr.org/2017/1066
+/// [halo]: https://eprint.iacr.org/2019/1021
+pub mod ipa_pc;
+
 /// Polynomial commitment scheme based on the construction in [\[KZG10\]][kzg],
 /// modified to obtain batching and to enforce strict
 /// degree bounds by following the approach outlined in [[MBKM19,
@@ -39,6 +46,10 @@ pub mod sonic_pc;
 pub mod error;
 pub use error::*;
 
+/// The interface for polynomial commitment backends.
+pub mod traits;
+pub use traits::*;
+
 /// A random number generator that bypasses some limitations of the Rust borrow
 /// checker.
 pub mod optional_rng;
@@ -13,9 +13,9 @@
 // limitations under the License.
 
 use super::{LabeledPolynomial, PolynomialInfo};
-use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
+use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, msm::PreprocessedBases, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -23,7 +23,7 @@ use std::{
     borrow::{Borrow, Cow},
     collections::{BTreeMap, BTreeSet},
     fmt,
-    ops::{AddAssign, MulAssign, SubAssign},
+    ops::{AddAssign, Mul, MulAssign, SubAssign},
 };
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
@@ -285,6 +285,31 @@ impl<E: PairingEngine> CommitterKey<E> {
     }
 }
 
+/// `PreprocessedCommitterKey` holds the window tables of the bases of a `CommitterKey`,
+/// so that repeated commitments with the same key skip the preprocessing of the bases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreprocessedCommitterKey<E: PairingEngine> {
+    /// The window tables of the key used to commit to polynomials.
+    pub powers_of_beta_g: PreprocessedBases<E::G1Affine>,
+
+    /// The window tables of the keys used to commit to polynomials in Lagrange basis.
+    pub lagrange_bases_at_beta_g: BTreeMap<usize, PreprocessedBases<E::G1Affine>>,
+}
+
+impl<E: PairingEngine> PreprocessedCommitterKey<E> {
+    /// Computes the window tables for the bases of the given committer key.
+    pub fn new(committer_key: &CommitterKey<E>) -> Self {
+        Self {
+            powers_of_beta_g: PreprocessedBases::new(&committer_key.powers_of_beta_g),
+            lagrange_bases_at_beta_g: committer_key
+                .lagrange_bases_at_beta_g
+                .iter()
+                .map(|(size, bases)| (*size, PreprocessedBases::new(bases)))
+                .collect(),
+        }
+    }
+}
+
 /// `CommitterUnionKey` is a union of `CommitterKey`s, useful for multi-circuit batch proofs.
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct CommitterUnionKey<'a, E: PairingEngine> {
@@ -309,6 +334,12 @@ pub struct CommitterUnionKey<'a, E: PairingEngine> {
     /// Sorted in ascending order from smallest bound to largest bound.
     /// This is `None` if `self` does not support enforcing any degree bounds.
     pub enforced_degree_bounds: Option<Vec<usize>>,
+
+    /// The window tables of `powers_of_beta_g`, if they have been precomputed.
+    pub preprocessed_powers_of_beta_g: Option<&'a PreprocessedBases<E::G1Affine>>,
+
+    /// The window tables of `lagrange_bases_at_beta_g`, for the bases that have been precomputed.
+    pub preprocessed_lagrange_bases_at_beta_g: BTreeMap<usize, &'a PreprocessedBases<E::G1Affine>>,
 }
 
 impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
@@ -317,6 +348,7 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
         kzg10::Powers {
             powers_of_beta_g: self.powers_of_beta_g.unwrap().as_slice().into(),
             powers_of_beta_times_gamma_g: self.powers_of_beta_times_gamma_g.unwrap().as_slice().into(),
+            preprocessed_powers_of_beta_g: self.preprocessed_powers_of_beta_g,
         }
     }
 
@@ -335,6 +367,7 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
                 let ck = kzg10::Powers {
                     powers_of_beta_g: shifted_powers_of_beta_g[powers_range].into(),
                     powers_of_beta_times_gamma_g: shifted_powers_of_beta_times_gamma_g[&bound].clone().into(),
+                    preprocessed_powers_of_beta_g: None,
                 };
 
                 Some(ck)
@@ -351,6 +384,10 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
             lagrange_basis_at_beta_g: Cow::Borrowed(basis),
             powers_of_beta_times_gamma_g: Cow::Borrowed(self.powers_of_beta_times_gamma_g.unwrap()),
             domain,
+            preprocessed_lagrange_basis_at_beta_g: self
+                .preprocessed_lagrange_bases_at_beta_g
+                .get(&domain.size())
+                .copied(),
         })
     }
 
@@ -362,6 +399,8 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
             shifted_powers_of_beta_g: None,
             shifted_powers_of_beta_times_gamma_g: None,
             enforced_degree_bounds: None,
+            preprocessed_powers_of_beta_g: None,
+            preprocessed_lagrange_bases_at_beta_g: BTreeMap::new(),
         };
         let mut enforced_degree_bounds = vec![];
         let mut biggest_ck: Option<&CommitterKey<E>> = None;
@@ -398,6 +437,27 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
 
         ck_union
     }
+
+    /// Attaches the window tables of the given preprocessed committer keys.
+    /// The keys must be trimmed from the same SRS as the keys in the union.
+    pub fn with_preprocessed<T: IntoIterator<Item = &'a PreprocessedCommitterKey<E>>>(
+        mut self,
+        preprocessed_keys: T,
+    ) -> Self {
+        for preprocessed_key in preprocessed_keys {
+            // As the powers are all prefixes of the same SRS powers, the largest table serves every key.
+            if self
+                .preprocessed_powers_of_beta_g
+                .map_or(true, |bases| bases.len() < preprocessed_key.powers_of_beta_g.len())
+            {
+                self.preprocessed_powers_of_beta_g = Some(&preprocessed_key.powers_of_beta_g);
+            }
+            for (size, bases) in preprocessed_key.lagrange_bases_at_beta_g.iter() {
+                self.preprocessed_lagrange_bases_at_beta_g.entry(*size).or_insert(bases);
+            }
+        }
+        self
+    }
 }
 
 /// Evaluation proof at a query set.
@@ -670,6 +730,38 @@ pub fn evaluate_query_set<'a, F: PrimeField>(
     evaluations
 }
 
+/// A pairing check that has been accumulated from an evaluation proof, but not yet performed.
+#[derive(Clone, Debug)]
+pub struct PendingCheck<E: PairingEngine> {
+    /// The combined commitments, grouped by degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined witness, adjusted by the evaluations and the query points.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> Default for PendingCheck<E> {
+    fn default() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+}
+
+impl<E: PairingEngine> PendingCheck<E> {
+    /// Adds the given pending check, scaled by `randomizer`, to this pending check.
+    pub(crate) fn add_scaled(&mut self, other: &Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in &other.combined_comms {
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += comm.mul(randomizer);
+        }
+        self.combined_witness += other.combined_witness.mul(randomizer);
+        self.combined_adjusted_witness += other.combined_adjusted_witness.mul(randomizer);
+    }
+}
+
 /// A proof of satisfaction of linear combinations.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchLCProof<E: PairingEngine> {
@@ -23,6 +23,7 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::rand::Uniform;
 
 use anyhow::{bail, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
@@ -104,7 +105,13 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                     for i in 0..=supported_hiding_bound + 1 {
                         // We have an additional degree in `powers_of_beta_times_gamma_g` beyond `powers_of_beta_g`.
                         if shift_degree + i < max_degree + 2 {
-                            powers_for_degree_bound.push(pp.powers_of_beta_times_gamma_g()[&(shift_degree + i)]);
+                            let power = pp.powers_of_beta_times_gamma_g().get(&(shift_degree + i)).copied().ok_or(
+                                PCError::HidingBoundToolarge {
+                                    hiding_poly_degree: supported_hiding_bound,
+                                    num_powers: powers_for_degree_bound.len(),
+                                },
+                            )?;
+                            powers_for_degree_bound.push(power);
                         }
                     }
                     shifted_powers_of_beta_times_gamma_g.insert(*degree_bound, powers_for_degree_bound);
@@ -181,6 +188,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
         let rng = &mut OptionalRng(rng);
         let commit_time = start_timer!(|| "Committing to polynomials");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("commit").entered();
         let mut labeled_comms: Vec<LabeledCommitment<Commitment<E>>> = Vec::new();
         let mut randomness: Vec<Randomness<E>> = Vec::new();
 
@@ -359,6 +368,23 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let pending_check = Self::batch_accumulate(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_elems(vk, pending_check)
+    }
+
+    /// Accumulates the evaluation proof into a pending pairing check, without performing the pairings.
+    /// The pending check can be combined with others, and performed once via `check_pending`.
+    pub fn batch_accumulate<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PendingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -379,9 +405,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         let mut randomizer = E::Fr::one();
 
-        let mut combined_comms = BTreeMap::new();
-        let mut combined_witness = E::G1Projective::zero();
-        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut pending_check = PendingCheck::default();
 
         for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
             let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
@@ -399,9 +423,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             }
 
             Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
+                &mut pending_check,
                 vk,
                 comms_to_combine.into_iter(),
                 *query,
@@ -414,9 +436,30 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result
+        Ok(pending_check)
+    }
+
+    /// Performs the given pending pairing checks with a single product of pairings.
+    /// Each check is scaled by a randomizer sampled from `rng`, so that the combined
+    /// check passes only if every individual check passes (except with negligible probability).
+    pub fn check_pending<R: RngCore>(
+        vk: &UniversalVerifier<E>,
+        pending_checks: impl IntoIterator<Item = PendingCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool, PCError> {
+        let combination_time = start_timer!(|| "Combining pending checks");
+        let mut combined_check = PendingCheck::default();
+        let mut randomizer = E::Fr::one();
+        for pending_check in pending_checks {
+            combined_check.add_scaled(&pending_check, randomizer);
+            // We don't need to sample randomizers from the full field,
+            // only from 128-bit strings.
+            randomizer = u128::rand(rng).into();
+        }
+        end_timer!(combination_time);
+
+        Self::check_elems(vk, combined_check)
     }
 
     pub fn open_combinations<'a>(
@@ -433,6 +476,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Randomness<E>: 'a,
         Commitment<E>: 'a,
     {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("open").entered();
+
         let label_map = polynomials
             .into_iter()
             .zip_eq(rands)
@@ -510,6 +556,25 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let pending_check =
+            Self::accumulate_combinations(vk, linear_combinations, commitments, query_set, evaluations, proof, fs_rng)?;
+        Self::check_elems(vk, pending_check)
+    }
+
+    /// Accumulates the proof for the linear combinations into a pending pairing check,
+    /// without performing the pairings.
+    pub fn accumulate_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PendingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -566,7 +631,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_accumulate(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -606,9 +671,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     #[allow(clippy::too_many_arguments)]
     fn accumulate_elems<'a>(
-        combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: &mut E::G1Projective,
-        combined_adjusted_witness: &mut E::G1Projective,
+        pending_check: &mut PendingCheck<E>,
         vk: &UniversalVerifier<E>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
         point: E::Fr,
@@ -636,7 +699,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             let comm_with_challenge: E::G1Projective = comm.0.mul(coeff);
 
             // Accumulate values in the BTreeMap
-            *combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += &comm_with_challenge;
+            *pending_check.combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) +=
+                &comm_with_challenge;
             end_timer!(acc_timer);
         }
 
@@ -647,24 +711,19 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             bases.push(vk.vk.gamma_g);
             coeffs.push(random_v);
         }
-        *combined_witness += if let Some(randomizer) = randomizer {
+        pending_check.combined_witness += if let Some(randomizer) = randomizer {
             coeffs.iter_mut().for_each(|c| *c *= randomizer);
             proof.w.mul(randomizer)
         } else {
             proof.w.to_projective()
         };
         let coeffs = coeffs.into_iter().map(|c| c.into()).collect::<Vec<_>>();
-        *combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
+        pending_check.combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
         end_timer!(acc_time);
     }
 
-    #[allow(clippy::type_complexity)]
-    fn check_elems(
-        vk: &UniversalVerifier<E>,
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-    ) -> Result<bool, PCError> {
+    pub(crate) fn check_elems(vk: &UniversalVerifier<E>, pending_check: PendingCheck<E>) -> Result<bool, PCError> {
+        let PendingCheck { combined_comms, combined_witness, combined_adjusted_witness } = pending_check;
         let check_time = start_timer!(|| "Checking elems");
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
@@ -28,13 +28,18 @@ use crate::{
     polycommit::{
         sonic_pc::{LabeledPolynomial, LabeledPolynomialWithBasis, LinearCombination},
         PCError,
+        PolynomialCommitment,
     },
     srs::UniversalVerifier,
     AlgebraicSponge,
 };
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::{TestRng, Uniform};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    FromBytes,
+    ToBytes,
+};
 
 use itertools::Itertools;
 use rand::{
@@ -733,3 +738,35 @@ where
     };
     equation_test_template::<E, S>(info)
 }
+
+/// Checks that a polynomial commitment backend opens commitments correctly,
+/// and rejects evaluation proofs for the wrong value or point.
+pub fn polynomial_commitment_test<F, PC>(hiding_bound: Option<usize>) -> Result<(), PCError>
+where
+    F: PrimeField,
+    PC: PolynomialCommitment<F>,
+{
+    let rng = &mut TestRng::default();
+    for _ in 0..10 {
+        let max_degree = rng.gen_range(2..64);
+        let pp = PC::setup(max_degree)?;
+        let (ck, vk) = PC::trim(&pp, max_degree, hiding_bound)?;
+
+        let degree = rng.gen_range(1..=max_degree);
+        let polynomial = DensePolynomial::rand(degree, rng);
+        let (commitment, randomness) = PC::commit(&ck, &polynomial, hiding_bound, Some(rng))?;
+        // Ensure the commitment round-trips through bytes.
+        assert_eq!(commitment, PC::Commitment::from_bytes_le(&commitment.to_bytes_le()?)?);
+
+        let point = F::rand(rng);
+        let value = polynomial.evaluate(point);
+        let proof = PC::open(&ck, &polynomial, point, &randomness)?;
+        // Ensure the proof round-trips through bytes.
+        assert_eq!(proof, PC::Proof::from_bytes_le(&proof.to_bytes_le()?)?);
+
+        assert!(PC::check(&vk, &commitment, point, value, &proof)?);
+        assert!(!PC::check(&vk, &commitment, point, value + F::one(), &proof)?);
+        assert!(!PC::check(&vk, &commitment, point + F::one(), value, &proof)?);
+    }
+    Ok(())
+}
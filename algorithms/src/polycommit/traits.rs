@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{fft::DensePolynomial, polycommit::PCError};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt::Debug;
+use rand_core::RngCore;
+
+/// A polynomial commitment scheme over the field `F`, which commits to a single polynomial
+/// and later proves the evaluation of the polynomial at a chosen point.
+pub trait PolynomialCommitment<F: PrimeField>: 'static + Clone + Debug + Send + Sync {
+    /// The universal parameters of the scheme, for polynomials up to some maximum degree.
+    type UniversalParams: Clone + Debug + Send + Sync;
+    /// The key used to commit to and open polynomials.
+    type CommitterKey: Clone + Debug + Send + Sync;
+    /// The key used to check evaluation proofs.
+    type VerifierKey: Clone + Debug + Send + Sync;
+    /// The commitment to a polynomial.
+    type Commitment: Clone + Debug + PartialEq + Eq + ToBytes + FromBytes + Send + Sync;
+    /// The randomness used to make a commitment hiding.
+    type Randomness: Clone + Debug + Send + Sync;
+    /// The evaluation proof for a polynomial at a point.
+    type Proof: Clone + Debug + PartialEq + Eq + ToBytes + FromBytes + Send + Sync;
+
+    /// Returns the universal parameters for polynomials up to the given maximum degree.
+    fn setup(max_degree: usize) -> Result<Self::UniversalParams, PCError>;
+
+    /// Specializes the universal parameters for polynomials up to the given supported degree,
+    /// and for hiding commitments up to the given hiding bound.
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+        hiding_bound: Option<usize>,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), PCError>;
+
+    /// Outputs a commitment to `polynomial`, which is hiding if `hiding_bound` is set.
+    ///
+    /// `rng` should not be `None` if `hiding_bound` is set.
+    fn commit(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<F>,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Self::Commitment, Self::Randomness), PCError>;
+
+    /// Outputs a proof of the evaluation of `polynomial` at `point`.
+    fn open(
+        ck: &Self::CommitterKey,
+        polynomial: &DensePolynomial<F>,
+        point: F,
+        randomness: &Self::Randomness,
+    ) -> Result<Self::Proof, PCError>;
+
+    /// Returns `true` if `value` is the evaluation at `point` of the polynomial committed in `commitment`.
+    fn check(
+        vk: &Self::VerifierKey,
+        commitment: &Self::Commitment,
+        point: F,
+        value: F,
+        proof: &Self::Proof,
+    ) -> Result<bool, PCError>;
+}
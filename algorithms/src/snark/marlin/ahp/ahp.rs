@@ -62,6 +62,26 @@ pub(crate) struct NonZeroDomains<F: PrimeField> {
     pub(crate) domain_c: EvaluationDomain<F>,
 }
 
+/// The evaluation domains of a circuit, which only depend on its circuit info.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CircuitDomains<F: PrimeField> {
+    pub(crate) input_domain: EvaluationDomain<F>,
+    pub(crate) constraint_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_a_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_b_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_c_domain: EvaluationDomain<F>,
+}
+
+impl<F: PrimeField> CircuitDomains<F> {
+    /// Returns the largest of the non-zero domains.
+    pub(crate) fn max_non_zero_domain(&self) -> EvaluationDomain<F> {
+        [self.non_zero_a_domain, self.non_zero_b_domain, self.non_zero_c_domain]
+            .into_iter()
+            .max_by_key(|domain| domain.size())
+            .unwrap()
+    }
+}
+
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// The linear combinations that are statically known to evaluate to zero.
     /// These correspond to the virtual commitments as noted in the Aleo marlin protocol docs
@@ -69,7 +89,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     pub const LC_WITH_ZERO_EVAL: [&'static str; 2] = ["matrix_sumcheck", "lincheck_sumcheck"];
 
     pub fn zk_bound() -> Option<usize> {
-        MM::ZK.then_some(1)
+        MM::ZK.then_some(MM::HIDING_BOUND)
     }
 
     /// Check that the (formatted) public input is of the form 2^n for some integer n.
@@ -151,6 +171,41 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         Ok(NonZeroDomains { max_non_zero_domain, domain_a, domain_b, domain_c })
     }
 
+    /// Returns the evaluation domains of the circuit with the given circuit info.
+    pub(crate) fn circuit_domains(circuit_info: &CircuitInfo) -> Result<CircuitDomains<F>, AHPError> {
+        // Check that the R1CS is a square matrix.
+        if circuit_info.num_constraints != circuit_info.num_variables {
+            return Err(AHPError::NonSquareMatrix);
+        }
+
+        let constraint_domain_time = start_timer!(|| "Constructing constraint domain");
+        let constraint_domain =
+            EvaluationDomain::new(circuit_info.num_constraints).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(constraint_domain_time);
+
+        let non_zero_a_time = start_timer!(|| "Constructing non-zero-a domain");
+        let non_zero_a_domain =
+            EvaluationDomain::new(circuit_info.num_non_zero_a).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(non_zero_a_time);
+
+        let non_zero_b_time = start_timer!(|| "Constructing non-zero-b domain");
+        let non_zero_b_domain =
+            EvaluationDomain::new(circuit_info.num_non_zero_b).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(non_zero_b_time);
+
+        let non_zero_c_time = start_timer!(|| "Constructing non-zero-c domain");
+        let non_zero_c_domain =
+            EvaluationDomain::new(circuit_info.num_non_zero_c).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(non_zero_c_time);
+
+        let input_domain_time = start_timer!(|| "Constructing input domain");
+        let input_domain =
+            EvaluationDomain::new(circuit_info.num_public_inputs).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(input_domain_time);
+
+        Ok(CircuitDomains { input_domain, constraint_domain, non_zero_a_domain, non_zero_b_domain, non_zero_c_domain })
+    }
+
     pub fn fft_precomputation(
         constraint_domain_size: usize,
         non_zero_a_domain_size: usize,
@@ -112,6 +112,23 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
         Ok(CircuitId(blake2.finalize().into()))
     }
 
+    /// Returns a copy of this circuit, for use in the given Marlin mode.
+    pub(crate) fn to_mode<MM2: MarlinMode>(&self) -> Circuit<F, MM2> {
+        Circuit {
+            index_info: self.index_info,
+            a: self.a.clone(),
+            b: self.b.clone(),
+            c: self.c.clone(),
+            a_arith: self.a_arith.clone(),
+            b_arith: self.b_arith.clone(),
+            c_arith: self.c_arith.clone(),
+            fft_precomputation: self.fft_precomputation.clone(),
+            ifft_precomputation: self.ifft_precomputation.clone(),
+            _mode: PhantomData,
+            id: self.id,
+        }
+    }
+
     /// The maximum degree required to represent polynomials of this index.
     pub fn max_degree(&self) -> usize {
         self.index_info.max_degree::<F, MM>()
@@ -14,7 +14,7 @@
 
 use crate::{
     polycommit::sonic_pc,
-    snark::marlin::{ahp::indexer::*, CircuitVerifyingKey, MarlinMode},
+    snark::marlin::{ahp::indexer::*, CircuitVerifyingKey, MarlinHidingMode, MarlinMode, MarlinNonHidingMode},
 };
 use snarkvm_curves::PairingEngine;
 use snarkvm_utilities::{
@@ -24,10 +24,11 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
+use once_cell::sync::OnceCell;
 use std::{cmp::Ordering, sync::Arc};
 
 /// Proving key for a specific circuit (i.e., R1CS matrices).
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct CircuitProvingKey<E: PairingEngine, MM: MarlinMode> {
     /// The circuit verifying key.
     pub circuit_verifying_key: CircuitVerifyingKey<E>,
@@ -38,6 +39,38 @@ pub struct CircuitProvingKey<E: PairingEngine, MM: MarlinMode> {
     pub circuit: Arc<Circuit<E::Fr, MM>>,
     /// The committer key for this index, trimmed from the universal SRS.
     pub committer_key: Arc<sonic_pc::CommitterKey<E>>,
+    /// The window tables of the committer key, if they have been computed with `Self::preprocess`.
+    pub(crate) preprocessed_committer_key: Arc<OnceCell<sonic_pc::PreprocessedCommitterKey<E>>>,
+}
+
+impl<E: PairingEngine, MM: MarlinMode> CircuitProvingKey<E, MM> {
+    /// Computes the window tables of the committer key, if they have not been computed yet.
+    ///
+    /// The tables hold one shifted copy of the bases of the committer key per MSM window,
+    /// which is roughly 20 times the memory of the committer key, in exchange for faster commitments.
+    /// As such, they are opt-in: the prover only uses them after this is called. The tables are shared between clones.
+    pub fn preprocess(&self) -> &sonic_pc::PreprocessedCommitterKey<E> {
+        self.preprocessed_committer_key.get_or_init(|| sonic_pc::PreprocessedCommitterKey::new(&self.committer_key))
+    }
+
+    /// Returns the window tables of the committer key, if they have been computed with `Self::preprocess`.
+    pub fn preprocessed_committer_key(&self) -> Option<&sonic_pc::PreprocessedCommitterKey<E>> {
+        self.preprocessed_committer_key.get()
+    }
+}
+
+impl<E: PairingEngine> CircuitProvingKey<E, MarlinHidingMode> {
+    /// Returns the proving key for non-hiding proofs of the same circuit.
+    /// The committer key is shared, as the non-hiding mode requires no more powers than the hiding mode.
+    pub fn to_non_hiding(&self) -> CircuitProvingKey<E, MarlinNonHidingMode> {
+        CircuitProvingKey {
+            circuit_verifying_key: self.circuit_verifying_key.clone(),
+            circuit_commitment_randomness: self.circuit_commitment_randomness.clone(),
+            circuit: Arc::new(self.circuit.to_mode()),
+            committer_key: self.committer_key.clone(),
+            preprocessed_committer_key: self.preprocessed_committer_key.clone(),
+        }
+    }
 }
 
 impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
@@ -58,10 +91,28 @@ impl<E: PairingEngine, MM: MarlinMode> FromBytes for CircuitProvingKey<E, MM> {
         let circuit = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
         let committer_key = Arc::new(FromBytes::read_le(&mut reader)?);
 
-        Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
+        Ok(Self {
+            circuit_verifying_key,
+            circuit_commitment_randomness,
+            circuit,
+            committer_key,
+            preprocessed_committer_key: Default::default(),
+        })
+    }
+}
+
+impl<E: PairingEngine, MM: MarlinMode> PartialEq for CircuitProvingKey<E, MM> {
+    fn eq(&self, other: &Self) -> bool {
+        // The window tables are derived from the committer key, and are not compared.
+        self.circuit_verifying_key == other.circuit_verifying_key
+            && self.circuit_commitment_randomness == other.circuit_commitment_randomness
+            && self.circuit == other.circuit
+            && self.committer_key == other.committer_key
     }
 }
 
+impl<E: PairingEngine, MM: MarlinMode> Eq for CircuitProvingKey<E, MM> {}
+
 impl<E: PairingEngine, MM: MarlinMode> Ord for CircuitProvingKey<E, MM> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.circuit.id.cmp(&other.circuit.id)
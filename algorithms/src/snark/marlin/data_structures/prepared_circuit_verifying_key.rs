@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    polycommit::sonic_pc::{self, LabeledCommitment},
+    snark::marlin::{
+        ahp::{AHPError, AHPForR1CS, CircuitDomains},
+        CircuitVerifyingKey,
+        MarlinMode,
+    },
+};
+use snarkvm_curves::PairingEngine;
+
+use core::cmp::Ordering;
+use itertools::Itertools;
+use std::sync::Arc;
+
+/// A circuit verifying key, with the values that only depend on the key precomputed,
+/// so that verifying many proofs for the same circuit does not recompute them for each proof.
+#[derive(Clone, Debug)]
+pub struct PreparedCircuitVerifyingKey<E: PairingEngine> {
+    /// The circuit verifying key.
+    pub orig_vk: Arc<CircuitVerifyingKey<E>>,
+    /// The evaluation domains of the circuit.
+    pub(in crate::snark::marlin) domains: CircuitDomains<E::Fr>,
+    /// The commitments to the indexed polynomials, labeled with their polynomial info.
+    pub(in crate::snark::marlin) labeled_circuit_commitments: Vec<LabeledCommitment<sonic_pc::Commitment<E>>>,
+}
+
+impl<E: PairingEngine> PreparedCircuitVerifyingKey<E> {
+    /// Prepares the given circuit verifying key.
+    pub fn prepare<MM: MarlinMode>(orig_vk: Arc<CircuitVerifyingKey<E>>) -> Result<Self, AHPError> {
+        let domains = AHPForR1CS::<E::Fr, MM>::circuit_domains(&orig_vk.circuit_info)?;
+        let labeled_circuit_commitments = orig_vk
+            .circuit_commitments
+            .iter()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info([&orig_vk.id].into_iter()).values())
+            .map(|(commitment, info)| LabeledCommitment::new_with_info(info, *commitment))
+            .collect();
+        Ok(Self { orig_vk, domains, labeled_circuit_commitments })
+    }
+}
+
+impl<E: PairingEngine> PartialEq for PreparedCircuitVerifyingKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.orig_vk == other.orig_vk
+    }
+}
+
+impl<E: PairingEngine> Eq for PreparedCircuitVerifyingKey<E> {}
+
+impl<E: PairingEngine> Ord for PreparedCircuitVerifyingKey<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.orig_vk.cmp(&other.orig_vk)
+    }
+}
+
+impl<E: PairingEngine> PartialOrd for PreparedCircuitVerifyingKey<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -20,6 +20,7 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PendingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -33,11 +34,14 @@ use crate::{
         CircuitProvingKey,
         CircuitVerifyingKey,
         MarlinMode,
+        PreparedCircuitVerifyingKey,
         Proof,
         UniversalSRS,
     },
     srs::UniversalVerifier,
     AlgebraicSponge,
+    ProvingJob,
+    ProvingProgress,
     SNARKError,
     SNARK,
 };
@@ -66,6 +70,21 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
     /// Used to personalize the Fiat-Shamir RNG.
     pub const PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019";
 
+    /// Samples a fresh universal SRS supporting circuits up to `max_degree`, without downloading any powers.
+    /// This setup is **insecure**, as the trapdoor is derived from `rng`, and must only be used for development and testing.
+    pub fn insecure_universal_setup<R: Rng + CryptoRng>(
+        max_degree: usize,
+        rng: &mut R,
+    ) -> Result<UniversalSRS<E>, SNARKError> {
+        let setup_time = start_timer!(|| format!("Marlin::InsecureUniversalSetup with max_degree {max_degree}"));
+        let num_powers = (max_degree + 1)
+            .checked_next_power_of_two()
+            .ok_or_else(|| SNARKError::Message(format!("The maximum degree ({max_degree}) is too large")))?;
+        let srs = UniversalSRS::<E>::insecure_setup(num_powers, rng).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
     // TODO: implement optimizations resulting from batching
     //       (e.g. computing a common set of Lagrange powers, FFT precomputations, etc)
     pub fn batch_circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
@@ -86,8 +105,8 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
                 .map_err(|e| anyhow!("Failed to download powers for degree {}: {e}", indexed_circuit.max_degree()))?;
             let coefficient_support = AHPForR1CS::<E::Fr, MM>::get_degree_bounds(&indexed_circuit.index_info);
 
-            // Marlin only needs degree 2 random polynomials.
-            let supported_hiding_bound = 1;
+            // Marlin only needs degree `MM::HIDING_BOUND + 1` random polynomials.
+            let supported_hiding_bound = MM::HIDING_BOUND;
             let (committer_key, _) = SonicKZG10::<E, FS>::trim(
                 universal_srs,
                 indexed_circuit.max_degree(),
@@ -115,6 +134,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
                 circuit_commitment_randomness,
                 circuit_verifying_key: circuit_verifying_key.clone(),
                 committer_key: Arc::new(committer_key),
+                preprocessed_committer_key: Default::default(),
             };
             circuit_keys.push((circuit_proving_key, circuit_verifying_key));
         }
@@ -278,47 +298,9 @@ where
         verifying_key: &Self::VerifyingKey,
         certificate: &Self::Certificate,
     ) -> Result<bool, SNARKError> {
-        let circuit_id = &verifying_key.id;
-        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info(std::iter::once(circuit_id));
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-
-        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, circuit_id, point)?;
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        let mut evaluation = E::Fr::zero();
-        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
-            lc.add(c, label.as_str());
-            evaluation += c * eval;
-        }
-
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(info.values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            universal_verifier,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
-        )
-        .map_err(Into::into)
+        let pending_check =
+            Self::accumulate_vk(universal_verifier, fs_parameters, circuit, verifying_key, certificate)?;
+        SonicKZG10::<E, FS>::check_elems(universal_verifier, pending_check).map_err(Into::into)
     }
 
     #[allow(clippy::only_used_in_recursion)]
@@ -331,16 +313,40 @@ where
         keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
         zk_rng: &mut R,
     ) -> Result<Self::Proof, SNARKError> {
+        Self::prove_batch_with_job(universal_prover, fs_parameters, keys_to_constraints, &ProvingJob::default(), zk_rng)
+    }
+
+    /// The prover checks for cancellation after synthesis and after each of the four commitment rounds.
+    fn prove_batch_with_job<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+        job: &ProvingJob,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        const NUM_ROUNDS: usize = 4;
+
+        if job.is_cancelled() {
+            return Err(SNARKError::Terminated);
+        }
         let prover_time = start_timer!(|| "Marlin::Prover");
         if keys_to_constraints.is_empty() {
             return Err(SNARKError::EmptyBatch);
         }
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("prove", circuits = keys_to_constraints.len()).entered();
+
         let mut circuits_to_constraints = BTreeMap::new();
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuits_to_constraints)?;
+        let prover_state = {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("synthesize_constraints").entered();
+            AHPForR1CS::<_, MM>::init_prover(&circuits_to_constraints)?
+        };
+        job.report(ProvingProgress::Synthesized)?;
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -364,7 +370,8 @@ where
         }
         assert_eq!(prover_state.total_instances, total_instances);
 
-        let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
+        let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()))
+            .with_preprocessed(keys_to_constraints.keys().filter_map(|pk| pk.preprocessed_committer_key()));
 
         let circuit_commitments =
             keys_to_constraints.keys().map(|pk| pk.circuit_verifying_key.circuit_commitments.as_slice());
@@ -387,12 +394,17 @@ where
             )?
         };
         end_timer!(first_round_comm_time);
+        job.report(ProvingProgress::Committed { round: 1, num_rounds: NUM_ROUNDS })?;
 
         Self::absorb_labeled(&first_commitments, &mut sponge);
 
+        let circuit_domains = circuit_infos
+            .iter()
+            .map(|(circuit_id, circuit_info)| Ok((*circuit_id, AHPForR1CS::<_, MM>::circuit_domains(circuit_info)?)))
+            .collect::<Result<BTreeMap<_, _>, AHPError>>()?;
         let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
             &batch_sizes,
-            &circuit_infos,
+            &circuit_domains,
             prover_state.max_constraint_domain,
             prover_state.max_non_zero_domain,
             &mut sponge,
@@ -413,6 +425,7 @@ where
             Some(zk_rng),
         )?;
         end_timer!(second_round_comm_time);
+        job.report(ProvingProgress::Committed { round: 2, num_rounds: NUM_ROUNDS })?;
 
         Self::absorb_labeled(&second_commitments, &mut sponge);
 
@@ -434,6 +447,7 @@ where
             Some(zk_rng),
         )?;
         end_timer!(third_round_comm_time);
+        job.report(ProvingProgress::Committed { round: 3, num_rounds: NUM_ROUNDS })?;
 
         Self::absorb_labeled_with_msg(&third_commitments, &prover_third_message, &mut sponge);
 
@@ -455,6 +469,7 @@ where
             Some(zk_rng),
         )?;
         end_timer!(fourth_round_comm_time);
+        job.report(ProvingProgress::Committed { round: 4, num_rounds: NUM_ROUNDS })?;
 
         Self::absorb_labeled(&fourth_commitments, &mut sponge);
 
@@ -569,6 +584,7 @@ where
 
         let proof = Proof::<E>::new(batch_sizes, commitments, evaluations, prover_third_message, pc_proof)?;
         assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
+        job.report(ProvingProgress::Opened)?;
 
         end_timer!(prover_time);
         Ok(proof)
@@ -577,20 +593,52 @@ where
     /// This is the main entrypoint for verifying proofs.
     /// You can find a specification of the verifier algorithm in:
     /// https://github.com/AleoHQ/protocol-docs/tree/main/marlin
+    ///
+    /// To verify many proofs for the same circuits, prepare the verifying keys once,
+    /// and use `verify_batch_prepared` instead.
     fn verify_batch<B: Borrow<Self::VerifierInput>>(
         universal_verifier: &Self::UniversalVerifier,
         fs_parameters: &Self::FSParameters,
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        // Prepare the verifying keys.
+        let prepared_keys = keys_to_inputs
+            .iter()
+            .map(|(vk, inputs)| Ok((PreparedCircuitVerifyingKey::prepare::<MM>(Arc::new((*vk).clone()))?, *inputs)))
+            .collect::<Result<Vec<_>, SNARKError>>()?;
+        let keys_to_inputs = prepared_keys.iter().map(|(vk, inputs)| (vk, *inputs)).collect();
+        Self::verify_batch_prepared(universal_verifier, fs_parameters, &keys_to_inputs, proof)
+    }
+}
+
+impl<E: PairingEngine, FS, MM> MarlinSNARK<E, FS, MM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: MarlinMode,
+{
+    /// Verifies the given proof for the given prepared verifying keys and their public inputs.
+    /// You can find a specification of the verifier algorithm in:
+    /// https://github.com/AleoHQ/protocol-docs/tree/main/marlin
+    pub fn verify_batch_prepared<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&PreparedCircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
     ) -> Result<bool, SNARKError> {
         if keys_to_inputs.is_empty() {
             return Err(SNARKError::EmptyBatch);
         }
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("verify", circuits = keys_to_inputs.len()).entered();
+
         let batch_sizes_vec = proof.batch_sizes()?;
         let mut batch_sizes = BTreeMap::new();
         for (i, (vk, public_inputs_i)) in keys_to_inputs.iter().enumerate() {
-            batch_sizes.insert(vk.id, batch_sizes_vec[i]);
+            batch_sizes.insert(vk.orig_vk.id, batch_sizes_vec[i]);
 
             if public_inputs_i.is_empty() {
                 return Err(SNARKError::EmptyBatch);
@@ -602,23 +650,26 @@ where
         }
 
         // collect values into structures for our calculations
-        let mut max_constraint_domain = None;
-        let mut max_non_zero_domain = None;
+        let mut max_constraint_domain: Option<EvaluationDomain<E::Fr>> = None;
+        let mut max_non_zero_domain: Option<EvaluationDomain<E::Fr>> = None;
         let mut public_inputs = BTreeMap::new();
         let mut padded_public_vec = Vec::with_capacity(keys_to_inputs.len());
         let mut inputs_and_batch_sizes = BTreeMap::new();
-        let mut input_domains = BTreeMap::new();
+        let mut circuit_domains = BTreeMap::new();
         let mut circuit_infos = BTreeMap::new();
         let mut circuit_ids = Vec::with_capacity(keys_to_inputs.len());
         for (vk, public_inputs_i) in keys_to_inputs.iter() {
-            let constraint_domains =
-                AHPForR1CS::<_, MM>::max_constraint_domain(&vk.circuit_info, max_constraint_domain)?;
-            max_constraint_domain = constraint_domains.max_constraint_domain;
-            let non_zero_domains = AHPForR1CS::<_, MM>::max_non_zero_domain(&vk.circuit_info, max_non_zero_domain)?;
-            max_non_zero_domain = non_zero_domains.max_non_zero_domain;
+            let constraint_domain = vk.domains.constraint_domain;
+            if max_constraint_domain.map_or(true, |max| max.size() < constraint_domain.size()) {
+                max_constraint_domain = Some(constraint_domain);
+            }
+            let non_zero_domain = vk.domains.max_non_zero_domain();
+            if max_non_zero_domain.map_or(true, |max| max.size() < non_zero_domain.size()) {
+                max_non_zero_domain = Some(non_zero_domain);
+            }
 
-            let input_domain = EvaluationDomain::<E::Fr>::new(vk.circuit_info.num_public_inputs).unwrap();
-            input_domains.insert(vk.id, input_domain);
+            let input_domain = vk.domains.input_domain;
+            circuit_domains.insert(vk.orig_vk.id, vk.domains);
 
             let (padded_public_inputs_i, parsed_public_inputs_i): (Vec<_>, Vec<_>) = {
                 public_inputs_i
@@ -636,14 +687,14 @@ where
                     })
                     .unzip()
             };
-            let circuit_id = vk.id;
+            let circuit_id = vk.orig_vk.id;
             public_inputs.insert(circuit_id, parsed_public_inputs_i);
             padded_public_vec.push(padded_public_inputs_i);
-            circuit_infos.insert(circuit_id, &vk.circuit_info);
+            circuit_infos.insert(circuit_id, &vk.orig_vk.circuit_info);
             circuit_ids.push(circuit_id);
         }
         for (i, (vk, &batch_size)) in keys_to_inputs.keys().zip(batch_sizes.values()).enumerate() {
-            inputs_and_batch_sizes.insert(vk.id, (batch_size, padded_public_vec[i].as_slice()));
+            inputs_and_batch_sizes.insert(vk.orig_vk.id, (batch_size, padded_public_vec[i].as_slice()));
         }
 
         let comms = &proof.commitments;
@@ -726,8 +777,8 @@ where
         let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
         let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
 
-        let circuit_commitments = keys_to_inputs.keys().map(|vk| vk.circuit_commitments.as_slice());
-        let mut sponge = Self::init_sponge(fs_parameters, &inputs_and_batch_sizes, circuit_commitments.clone());
+        let circuit_commitments = keys_to_inputs.keys().map(|vk| vk.orig_vk.circuit_commitments.as_slice());
+        let mut sponge = Self::init_sponge(fs_parameters, &inputs_and_batch_sizes, circuit_commitments);
 
         // --------------------------------------------------------------------
         // First round
@@ -735,7 +786,7 @@ where
         Self::absorb_labeled(&first_commitments, &mut sponge);
         let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
             &batch_sizes,
-            &circuit_infos,
+            &circuit_domains,
             max_constraint_domain.unwrap(),
             max_non_zero_domain.unwrap(),
             &mut sponge,
@@ -774,11 +825,9 @@ where
         // correct degree.
 
         // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_commitments
-            .into_iter()
-            .flatten()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(circuit_ids.iter()).values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, *c))
+        let commitments: Vec<_> = keys_to_inputs
+            .keys()
+            .flat_map(|vk| vk.labeled_circuit_commitments.iter().cloned())
             .chain(first_commitments)
             .chain(second_commitments)
             .chain(third_commitments)
@@ -845,4 +894,77 @@ where
         ));
         Ok(evaluations_are_correct & proof_has_correct_zk_mode)
     }
+
+    /// Verifies the given verifying keys against their circuits and certificates, using a single pairing check.
+    /// This is equivalent to calling `verify_vk` for each verifying key, but amortizes the pairings across all of them.
+    pub fn verify_vk_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        circuits: &[(&C, &CircuitVerifyingKey<E>, &Certificate<E>)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        if circuits.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+
+        let pending_checks = circuits
+            .iter()
+            .map(|(circuit, verifying_key, certificate)| {
+                Self::accumulate_vk(universal_verifier, fs_parameters, *circuit, verifying_key, certificate)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        SonicKZG10::<E, FS>::check_pending(universal_verifier, pending_checks, rng).map_err(Into::into)
+    }
+
+    /// Accumulates the check of the verifying key against the circuit and certificate,
+    /// without performing the final pairing check.
+    fn accumulate_vk<C: ConstraintSynthesizer<E::Fr>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        circuit: &C,
+        verifying_key: &CircuitVerifyingKey<E>,
+        certificate: &Certificate<E>,
+    ) -> Result<PendingCheck<E>, SNARKError> {
+        let circuit_id = &verifying_key.id;
+        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info(std::iter::once(circuit_id));
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+
+        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, circuit_id, point)?;
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        let mut evaluation = E::Fr::zero();
+        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
+            lc.add(c, label.as_str());
+            evaluation += c * eval;
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(info.values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+
+        SonicKZG10::<E, FS>::accumulate_combinations(
+            universal_verifier,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
 }
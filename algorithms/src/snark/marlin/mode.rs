@@ -17,6 +17,16 @@ use core::fmt::Debug;
 /// A trait to specify the Marlin mode.
 pub trait MarlinMode: 'static + Copy + Clone + Debug + PartialEq + Eq + Sync + Send {
     const ZK: bool;
+
+    /// The number of queries each hiding commitment remains hiding against.
+    /// This is only used when `ZK` is set.
+    ///
+    /// The bound trades the zero-knowledge margin against prover time: a larger bound samples
+    /// higher-degree hiding polynomials, which costs more prover time and requires an SRS with
+    /// as many additional powers of `gamma_g`. The prover opens each committed polynomial at one
+    /// point, so the default of 1 is the smallest bound that keeps the proof zero knowledge.
+    /// The degree bounds are derived from the circuit by the AHP and are not configurable.
+    const HIDING_BOUND: usize = 1;
 }
 
 /// The Marlin hiding mode produces a hiding Marlin proof.
@@ -27,6 +37,17 @@ impl MarlinMode for MarlinHidingMode {
     const ZK: bool = true;
 }
 
+/// The Marlin mode with a configurable hiding bound for the polynomial commitments,
+/// see `MarlinMode::HIDING_BOUND`. A bound of 0 drops zero knowledge for the fastest prover,
+/// and is equivalent to `MarlinNonHidingMode`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MarlinModeWithHidingBound<const HIDING_BOUND: usize>;
+
+impl<const HIDING_BOUND: usize> MarlinMode for MarlinModeWithHidingBound<HIDING_BOUND> {
+    const HIDING_BOUND: usize = HIDING_BOUND;
+    const ZK: bool = HIDING_BOUND > 0;
+}
+
 /// The Marlin non-hiding mode produces a non-hiding Marlin proof.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct MarlinNonHidingMode;
@@ -20,9 +20,17 @@ use std::collections::BTreeMap;
 
 mod marlin {
     use super::*;
-    use crate::snark::marlin::{AHPForR1CS, CircuitVerifyingKey, MarlinHidingMode, MarlinNonHidingMode, MarlinSNARK};
+    use crate::snark::marlin::{
+        AHPForR1CS,
+        CircuitVerifyingKey,
+        MarlinHidingMode,
+        MarlinNonHidingMode,
+        MarlinSNARK,
+        PreparedCircuitVerifyingKey,
+    };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::rand::{TestRng, Uniform};
+    use std::sync::Arc;
 
     type MarlinSonicInst = MarlinSNARK<Bls12_377, FS, MarlinHidingMode>;
 
@@ -86,6 +94,24 @@ mod marlin {
                                 $marlin_inst::batch_circuit_setup(&universal_srs, unique_instances.as_slice()).unwrap();
                             println!("Called circuit setup");
 
+                            // Ensure the certificates verify in a single batch.
+                            let certificates = index_keys
+                                .iter()
+                                .map(|(index_pk, index_vk)| $marlin_inst::prove_vk(universal_prover, &fs_parameters, index_vk, index_pk).unwrap())
+                                .collect::<Vec<_>>();
+                            let circuits = index_keys
+                                .iter()
+                                .zip(&certificates)
+                                .map(|((index_pk, index_vk), certificate)| (&constraints[&index_pk.circuit.id][0], index_vk, certificate))
+                                .collect::<Vec<_>>();
+                            assert!($marlin_inst::verify_vk_batch(universal_verifier, &fs_parameters, &circuits, rng).unwrap());
+                            // Ensure the batch fails if any certificate is for another circuit.
+                            if circuit_batch_size > 1 {
+                                let mut circuits = circuits;
+                                circuits[0].2 = &certificates[1];
+                                assert!(!$marlin_inst::verify_vk_batch(universal_verifier, &fs_parameters, &circuits, rng).unwrap());
+                            }
+
                             let mut pks_to_constraints = BTreeMap::new();
                             let mut vks_to_inputs = BTreeMap::new();
 
@@ -103,6 +129,17 @@ mod marlin {
                                 "Batch verification failed with {instance_batch_size} instances and {circuit_batch_size} circuits for circuits: {constraints:?}"
                             );
                             println!("Called verifier");
+
+                            // Ensure the proof verifies with the prepared verifying keys.
+                            let prepared_vks = vks_to_inputs
+                                .keys()
+                                .map(|vk| PreparedCircuitVerifyingKey::prepare::<$marlin_mode>(Arc::new((*vk).clone())).unwrap())
+                                .collect::<Vec<_>>();
+                            let prepared_vks_to_inputs =
+                                prepared_vks.iter().zip(vks_to_inputs.values()).map(|(vk, inputs)| (vk, *inputs)).collect();
+                            assert!(
+                                $marlin_inst::verify_batch_prepared(universal_verifier, &fs_parameters, &prepared_vks_to_inputs, &proof).unwrap()
+                            );
                             println!("\nShould not verify (i.e. verifier messages should print below):");
                             let mut fake_instance_inputs = Vec::with_capacity(vks_to_inputs.len());
                             for instance_input in vks_to_inputs.values() {
@@ -267,8 +304,15 @@ mod marlin {
 mod marlin_hiding {
     use super::*;
     use crate::{
-        crypto_hash::PoseidonSponge,
-        snark::marlin::{ahp::AHPForR1CS, CircuitVerifyingKey, MarlinHidingMode, MarlinSNARK},
+        crypto_hash::{MerlinSponge, PoseidonSponge},
+        snark::marlin::{
+            ahp::AHPForR1CS,
+            CircuitProvingKey,
+            CircuitVerifyingKey,
+            MarlinHidingMode,
+            MarlinModeWithHidingBound,
+            MarlinSNARK,
+        },
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::{
@@ -454,6 +498,191 @@ mod marlin_hiding {
         assert!(MarlinInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn test_insecure_universal_setup() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::insecure_universal_setup(max_degree, rng).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure the certificate and the proof verify with the locally generated SRS.
+        let certificate = MarlinInst::prove_vk(universal_prover, &fs_parameters, &index_vk, &index_pk).unwrap();
+        assert!(MarlinInst::verify_vk(universal_verifier, &fs_parameters, &circuit, &index_vk, &certificate).unwrap());
+        let proof = MarlinInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(
+            MarlinInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.clone(), &proof).unwrap()
+        );
+
+        // Ensure the proof does not verify with the production SRS.
+        let production_verifier = &MarlinInst::universal_setup(max_degree).unwrap().to_universal_verifier().unwrap();
+        assert!(!MarlinInst::verify(production_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+
+        // Ensure the locally generated SRS does not download powers beyond its degree.
+        assert!(universal_srs.download_powers_for(0..(max_degree + 1).next_power_of_two() + 1).is_err());
+    }
+
+    #[test]
+    fn test_preprocessed_committer_key() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure a proof does not compute the window tables, unless they are requested.
+        let proof = MarlinInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(
+            MarlinInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.clone(), &proof).unwrap()
+        );
+        assert!(index_pk.preprocessed_committer_key().is_none());
+
+        // Ensure the window tables are shared between clones.
+        let cloned_pk = index_pk.clone();
+        index_pk.preprocess();
+        assert!(cloned_pk.preprocessed_committer_key().is_some());
+
+        // Ensure the subsequent proofs with the window tables verify.
+        let proof = MarlinInst::prove(universal_prover, &fs_parameters, &cloned_pk, &circuit, rng).unwrap();
+        assert!(MarlinInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+        assert_eq!(index_pk, CircuitProvingKey::from_bytes_le(&index_pk.to_bytes_le().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_merlin_transcript() {
+        type MerlinInst = MarlinSNARK<Bls12_377, MerlinSponge<Fq, 2>, MarlinHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MerlinInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = MerlinSponge::<Fq, 2>::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (index_pk, index_vk) = MerlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure the certificate and the proof verify with the Merlin transcript.
+        let certificate = MerlinInst::prove_vk(universal_prover, &fs_parameters, &index_vk, &index_pk).unwrap();
+        assert!(MerlinInst::verify_vk(universal_verifier, &fs_parameters, &circuit, &index_vk, &certificate).unwrap());
+        let proof = MerlinInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(
+            MerlinInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.clone(), &proof).unwrap()
+        );
+        assert!(!MerlinInst::verify(universal_verifier, &fs_parameters, &index_vk, [Fr::rand(rng)], &proof).unwrap());
+
+        // Ensure the proof does not verify with the Poseidon transcript.
+        let poseidon_parameters = FS::sample_parameters();
+        assert!(
+            !MarlinInst::verify(universal_verifier, &poseidon_parameters, &index_vk, public_inputs, &proof).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_proving_job() {
+        use crate::{ProvingJob, ProvingProgress, SNARKError};
+        use std::sync::{Arc, Mutex};
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let keys_to_constraints = [(&index_pk, std::slice::from_ref(&circuit))].into_iter().collect();
+
+        // Ensure the job reports every stage of the prover.
+        let stages = Arc::new(Mutex::new(Vec::new()));
+        let job = ProvingJob::new().with_progress({
+            let stages = stages.clone();
+            move |progress| stages.lock().unwrap().push(progress)
+        });
+        let proof = MarlinInst::prove_batch_with_job(universal_prover, &fs_parameters, &keys_to_constraints, &job, rng)
+            .unwrap();
+        assert!(MarlinInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+        let mut expected = vec![ProvingProgress::Synthesized];
+        expected.extend((1..=4).map(|round| ProvingProgress::Committed { round, num_rounds: 4 }));
+        expected.push(ProvingProgress::Opened);
+        assert_eq!(*stages.lock().unwrap(), expected);
+
+        // Ensure the job stops at the next stage once it is cancelled.
+        let stages = Arc::new(Mutex::new(Vec::new()));
+        let job = ProvingJob::new();
+        let job = job.clone().with_progress({
+            let stages = stages.clone();
+            move |progress| {
+                stages.lock().unwrap().push(progress);
+                if progress == (ProvingProgress::Committed { round: 2, num_rounds: 4 }) {
+                    job.cancel();
+                }
+            }
+        });
+        let result =
+            MarlinInst::prove_batch_with_job(universal_prover, &fs_parameters, &keys_to_constraints, &job, rng);
+        assert!(matches!(result, Err(SNARKError::Terminated)));
+        assert_eq!(stages.lock().unwrap().len(), 3);
+
+        // Ensure a cancelled job does not start proving.
+        assert!(job.is_cancelled());
+        let result =
+            MarlinInst::prove_batch_with_job(universal_prover, &fs_parameters, &keys_to_constraints, &job, rng);
+        assert!(matches!(result, Err(SNARKError::Terminated)));
+        assert_eq!(stages.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_custom_hiding_bound() {
+        type BoundedInst = MarlinSNARK<Bls12_377, FS, MarlinModeWithHidingBound<2>>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinModeWithHidingBound<2>>::max_degree(100, 25, 300).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+
+        // Ensure the production SRS rejects a hiding bound beyond its powers of gamma.
+        let production_srs = BoundedInst::universal_setup(max_degree).unwrap();
+        assert!(BoundedInst::circuit_setup(&production_srs, &circuit).is_err());
+
+        // Ensure proofs with a larger hiding bound verify with an SRS that supports it.
+        let universal_srs = BoundedInst::insecure_universal_setup(max_degree, rng).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = BoundedInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = BoundedInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(proof.pc_proof.is_hiding());
+        assert!(
+            BoundedInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.clone(), &proof).unwrap()
+        );
+        assert!(!BoundedInst::verify(universal_verifier, &fs_parameters, &index_vk, [Fr::rand(rng)], &proof).unwrap());
+
+        // Ensure a zero hiding bound trades zero knowledge for prover time, and produces non-hiding proofs that verify.
+        type UnboundedInst = MarlinSNARK<Bls12_377, FS, MarlinModeWithHidingBound<0>>;
+        let (index_pk, index_vk) = UnboundedInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = UnboundedInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(!proof.pc_proof.is_hiding());
+        assert!(UnboundedInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+
+        // Ensure the local SRS rejects a hiding bound beyond its powers of gamma.
+        type OverboundInst = MarlinSNARK<Bls12_377, FS, MarlinModeWithHidingBound<3>>;
+        assert!(OverboundInst::circuit_setup(&universal_srs, &circuit).is_err());
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
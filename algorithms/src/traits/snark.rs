@@ -18,7 +18,15 @@ use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToB
 
 use anyhow::Result;
 use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, fmt::Debug};
+use std::{
+    borrow::Borrow,
+    collections::BTreeMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 /// Defines trait that describes preparing from an unprepared version to a prepare version.
 pub trait Prepare {
@@ -26,6 +34,70 @@ pub trait Prepare {
     fn prepare(&self) -> Self::Prepared;
 }
 
+/// The stages of a proving job, as reported to its progress callback.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProvingProgress {
+    /// The constraints of every circuit in the batch are synthesized.
+    Synthesized,
+    /// The prover committed to the polynomials of round `round` (1-indexed) of `num_rounds`.
+    Committed { round: usize, num_rounds: usize },
+    /// The prover opened the commitments, and the proof is complete.
+    Opened,
+}
+
+/// A handle to a proving job, which reports the progress of the prover,
+/// and lets another thread cancel the job at the next stage.
+///
+/// Clones of the job share the cancellation flag, so one clone can be given to the prover
+/// while another is kept to cancel it.
+#[derive(Clone, Default)]
+pub struct ProvingJob {
+    /// Whether the job is cancelled.
+    cancelled: Arc<AtomicBool>,
+    /// The callback that is invoked at each stage of the job.
+    on_progress: Option<Arc<dyn Fn(ProvingProgress) + Send + Sync>>,
+}
+
+impl ProvingJob {
+    /// Initializes a new proving job.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the job with the given progress callback.
+    pub fn with_progress(mut self, on_progress: impl Fn(ProvingProgress) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Cancels the job. The prover stops with `SNARKError::Terminated` at its next stage.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the job is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Reports the given progress, if the job is not cancelled.
+    pub fn report(&self, progress: ProvingProgress) -> Result<(), SNARKError> {
+        if self.is_cancelled() {
+            return Err(SNARKError::Terminated);
+        }
+        if let Some(on_progress) = &self.on_progress {
+            on_progress(progress);
+        }
+        Ok(())
+    }
+}
+
+impl Debug for ProvingJob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProvingJob").field("cancelled", &self.is_cancelled()).finish()
+    }
+}
+
 pub trait SNARK {
     type ScalarField: Clone + PrimeField;
     type BaseField: Clone + PrimeField;
@@ -94,6 +166,23 @@ pub trait SNARK {
         rng: &mut R,
     ) -> Result<Self::Proof, SNARKError>;
 
+    /// Returns a proof for the given batch, reporting its progress to the given job,
+    /// and stopping with `SNARKError::Terminated` if the job is cancelled.
+    fn prove_batch_with_job<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&Self::ProvingKey, &[C]>,
+        job: &ProvingJob,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        if job.is_cancelled() {
+            return Err(SNARKError::Terminated);
+        }
+        let proof = Self::prove_batch(universal_prover, fs_parameters, keys_to_constraints, rng)?;
+        job.report(ProvingProgress::Opened)?;
+        Ok(proof)
+    }
+
     fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
         universal_verifier: &Self::UniversalVerifier,
         fs_parameters: &Self::FSParameters,
@@ -19,7 +19,7 @@ use snarkvm_circuit_types::environment::assert_scope;
 
 use crate::ComputeKey;
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U8};
 
 pub struct Signature<A: Aleo> {
     /// The verifier challenge to check against.
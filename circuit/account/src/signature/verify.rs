@@ -39,6 +39,25 @@ impl<A: Aleo> Signature<A> {
         // Return `true` if the challenge and address is valid.
         self.challenge.is_equal(&candidate_challenge) & address.is_equal(&candidate_address)
     }
+
+    /// Returns `true` if the signature is valid for the given `address` and `message` (as bytes).
+    pub fn verify_bytes(&self, address: &Address<A>, message: &[U8<A>]) -> Boolean<A> {
+        // Convert the message into bits, and verify the signature.
+        self.verify_bits(address, &message.to_bits_le())
+    }
+
+    /// Returns `true` if the signature is valid for the given `address` and `message` (as bits).
+    /// The bits are packed into field elements in the same way as `console::Signature::sign_bits`.
+    pub fn verify_bits(&self, address: &Address<A>, message: &[Boolean<A>]) -> Boolean<A> {
+        // Pack the bits into field elements.
+        let fields: Vec<_> = message.chunks(A::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect();
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if fields.len() > A::MAX_DATA_SIZE_IN_FIELDS as usize {
+            A::halt("Cannot verify the signature: the signed message exceeds maximum allowed size")
+        }
+        // Verify the signature.
+        self.verify(address, &fields)
+    }
 }
 
 #[cfg(all(test, console))]
@@ -126,6 +145,72 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    fn check_verify_bits(
+        mode: Mode,
+        num_bits: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (private_key, _compute_key, _view_key, address) = generate_account()?;
+
+            // Generate a signature over a message of the given number of bits.
+            let message = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let signature = console::Signature::sign_bits(&private_key, &message, rng)?;
+
+            // Initialize the signature, address, and message.
+            let signature = Signature::<Circuit>::new(mode, signature);
+            let address = Address::new(mode, address);
+            let message = message.iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = signature.verify_bits(&address, &message);
+                assert!(candidate.eject_value());
+                // TODO (howardwu): Resolve skipping the cost count checks for the burn-in round.
+                if i > 0 {
+                    assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+                }
+            });
+
+            // Ensure the signature does not verify for a different message.
+            let mut tampered = message.clone();
+            tampered[0] = !&tampered[0];
+            assert!(!signature.verify_bits(&address, &tampered).eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_verify_bytes(mode: Mode, num_bytes: usize) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (private_key, _compute_key, _view_key, address) = generate_account()?;
+
+            // Generate a signature over a message of the given number of bytes.
+            let message = (0..num_bytes).map(|_| U8::new(mode, Uniform::rand(rng))).collect::<Vec<_>>();
+            let bytes = message.iter().map(|byte| *byte.eject_value()).collect::<Vec<u8>>();
+            let signature = console::Signature::sign_bytes(&private_key, &bytes, rng)?;
+
+            // Initialize the signature and address.
+            let signature = Signature::<Circuit>::new(mode, signature);
+            let address = Address::new(mode, address);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = signature.verify_bytes(&address, &message);
+                assert!(candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_verify_constant() -> Result<()> {
         check_verify(Mode::Constant, 4514, 0, 0, 0)
@@ -155,4 +240,24 @@ pub(crate) mod tests {
     fn test_verify_large_private() -> Result<()> {
         check_verify_large(Mode::Private, 1757, 0, 8308, 8317)
     }
+
+    #[test]
+    fn test_verify_bits_constant() -> Result<()> {
+        check_verify_bits(Mode::Constant, 1000, 4514, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_verify_bits_public() -> Result<()> {
+        check_verify_bits(Mode::Public, 1000, 1757, 0, 7793, 7802)
+    }
+
+    #[test]
+    fn test_verify_bits_private() -> Result<()> {
+        check_verify_bits(Mode::Private, 1000, 1757, 0, 7793, 7802)
+    }
+
+    #[test]
+    fn test_verify_bytes() -> Result<()> {
+        check_verify_bytes(Mode::Private, 100)
+    }
 }
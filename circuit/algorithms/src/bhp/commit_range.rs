@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> CommitRange for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    type Boolean = Boolean<E>;
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+    type Value = Field<E>;
+
+    /// Returns `true` if the given BHP commitment opens to the `num_bits`-bit little-endian encoding
+    /// of the given value and randomizer, and the value lies in the range `[0, 2^num_bits)`.
+    fn is_committed_in_range(
+        &self,
+        commitment: &Self::Output,
+        value: &Self::Value,
+        randomizer: &Self::Randomizer,
+        num_bits: usize,
+    ) -> Self::Boolean {
+        // Ensure the range is strictly smaller than the field modulus.
+        let max_bits = E::BaseField::size_in_data_bits();
+        if num_bits > max_bits {
+            E::halt(format!("The BHP range cannot exceed {max_bits} bits, found {num_bits} bits"))
+        }
+        // Split the value into the lower `num_bits` bits and the remaining upper bits.
+        let bits = value.to_bits_le();
+        let (lower_bits, upper_bits) = bits.split_at(num_bits);
+        // Determine whether the value lies in the range `[0, 2^num_bits)`.
+        let is_in_range = upper_bits.iter().fold(Boolean::constant(true), |is_in_range, bit| is_in_range & !bit);
+        // Determine whether the commitment opens to the lower bits of the value.
+        let is_opening = self.commit(lower_bits, randomizer).is_equal(commitment);
+        // Return `true` if both checks pass.
+        is_in_range & is_opening
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_is_committed_in_range(
+        mode: Mode,
+        num_bits: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::CommitRange as C;
+
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::new(Mode::Constant, native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a value in the range `[0, 2^num_bits)`.
+            let value = console::Field::from_u64(u64::rand(&mut rng) >> (64 - num_bits));
+            // Sample a randomizer.
+            let randomizer = Uniform::rand(&mut rng);
+            // Compute the expected commitment.
+            let commitment = native.commit_range(&value, &randomizer, num_bits)?;
+
+            // Prepare the circuit inputs.
+            let circuit_commitment = Field::<Circuit>::new(Mode::Public, commitment);
+            let circuit_value = Field::new(mode, value);
+            let circuit_randomizer = Scalar::new(mode, randomizer);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate =
+                    circuit.is_committed_in_range(&circuit_commitment, &circuit_value, &circuit_randomizer, num_bits);
+                assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+                assert!(candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_committed_in_range_constant() -> Result<()> {
+        check_is_committed_in_range(Mode::Constant, 64, 1593, 0, 2, 3)
+    }
+
+    #[test]
+    fn test_is_committed_in_range_public() -> Result<()> {
+        check_is_committed_in_range(Mode::Public, 64, 903, 0, 2817, 2822)
+    }
+
+    #[test]
+    fn test_is_committed_in_range_private() -> Result<()> {
+        check_is_committed_in_range(Mode::Private, 64, 903, 0, 2817, 2822)
+    }
+
+    #[test]
+    fn test_is_committed_in_range_fails() -> Result<()> {
+        use console::Commit as C;
+
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::new(Mode::Constant, native.clone());
+
+        let mut rng = TestRng::default();
+
+        let num_bits = 32;
+        // Sample a value outside of the range `[0, 2^num_bits)`.
+        let value = console::Field::<<Circuit as Environment>::Network>::from_u64(
+            (u64::rand(&mut rng) >> num_bits) << num_bits | 1 << num_bits,
+        );
+        let randomizer = Uniform::rand(&mut rng);
+        // Commit to the lower `num_bits` bits of the value, ignoring the upper bits.
+        let commitment = native.commit(&value.to_bits_le()[..num_bits], &randomizer)?;
+
+        let circuit_commitment = Field::<Circuit>::new(Mode::Public, commitment);
+        let circuit_value = Field::new(Mode::Private, value);
+        let circuit_randomizer = Scalar::new(Mode::Private, randomizer);
+
+        // Ensure a value outside of the range is rejected.
+        let candidate =
+            circuit.is_committed_in_range(&circuit_commitment, &circuit_value, &circuit_randomizer, num_bits);
+        assert!(!candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure an incorrect randomizer is rejected.
+        let value =
+            console::Field::<<Circuit as Environment>::Network>::from_u64(u64::rand(&mut rng) >> (64 - num_bits));
+        let commitment = native.commit(&value.to_bits_le()[..num_bits], &randomizer)?;
+
+        let circuit_commitment = Field::<Circuit>::new(Mode::Public, commitment);
+        let circuit_value = Field::new(Mode::Private, value);
+        let circuit_randomizer = Scalar::new(Mode::Private, Uniform::rand(&mut rng));
+
+        let candidate =
+            circuit.is_committed_in_range(&circuit_commitment, &circuit_value, &circuit_randomizer, num_bits);
+        assert!(!candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ mod hasher;
 use hasher::BHPHasher;
 
 mod commit;
+mod commit_range;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
@@ -23,7 +24,7 @@ mod hash_uncompressed;
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
 
-use crate::{Commit, CommitUncompressed, Hash, HashUncompressed};
+use crate::{Commit, CommitRange, CommitUncompressed, Hash, HashUncompressed};
 use snarkvm_circuit_types::prelude::*;
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -21,6 +21,11 @@ pub use bhp::*;
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+#[cfg(console)]
+pub mod p256;
+#[cfg(console)]
+pub use p256::P256;
+
 pub mod pedersen;
 pub use pedersen::*;
 
@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+#[cfg(test)]
+use snarkvm_utilities::TestRng;
+
+use snarkvm_circuit_types::{
+    emulated::{EmulatedParameters, Secp256r1Base, Secp256r1Scalar, LIMB_BITS},
+    environment::prelude::*,
+    Boolean,
+    Emulated,
+};
+
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+/// An element of the base field of the P-256 curve.
+type BaseField<E> = Emulated<E, Secp256r1Base>;
+/// An element of the scalar field of the P-256 curve.
+type ScalarField<E> = Emulated<E, Secp256r1Scalar>;
+/// A point on the P-256 curve in affine coordinates.
+type Point<E> = (BaseField<E>, BaseField<E>);
+
+/// The scalar of the offset point, which is the initial accumulator of the scalar multiplication.
+/// The offset point keeps the accumulator away from the point at infinity, which has no affine coordinates.
+const OFFSET_SCALAR: u64 = u64::from_le_bytes(*b"p256offs");
+
+/// The NIST P-256 (secp256r1) curve, whose arithmetic is emulated over the base field of the environment.
+///
+/// The addition formulas are incomplete, i.e. adding two points with the same x-coordinate is unsatisfiable.
+/// This does not affect soundness, and only occurs for honest inputs with negligible probability.
+pub struct P256<E: Environment>(PhantomData<E>);
+
+impl<E: Environment> P256<E> {
+    /// Returns the given console point as a constant.
+    fn constant_point((x, y): &(BigUint, BigUint)) -> Point<E> {
+        (BaseField::constant(x.clone()), BaseField::constant(y.clone()))
+    }
+
+    /// Returns `numerator / denominator`, which is unsatisfiable if the denominator is zero.
+    fn divide(numerator: &BaseField<E>, denominator: &BaseField<E>) -> BaseField<E> {
+        let modulus = Secp256r1Base::modulus();
+        let value =
+            (numerator.eject_value() * denominator.eject_value().modpow(&(&modulus - 2u64), &modulus)) % modulus;
+
+        match numerator.is_constant() && denominator.is_constant() {
+            true => BaseField::constant(value),
+            false => {
+                // Ensure the denominator is nonzero, as otherwise any quotient satisfies the check below.
+                E::assert(denominator.is_not_equal(&BaseField::zero()));
+                // Witness the quotient.
+                let quotient = BaseField::new(Mode::Private, value);
+                // Ensure `quotient * denominator == numerator`.
+                Self::assert_equal(&(&quotient * denominator), numerator);
+                quotient
+            }
+        }
+    }
+
+    /// Enforces that the given elements are equal.
+    fn assert_equal(first: &BaseField<E>, second: &BaseField<E>) {
+        // As both elements are canonical, this compares their limbs.
+        for (a, b) in first.limbs().iter().zip_eq(second.limbs()) {
+            E::assert_eq(a, b);
+        }
+    }
+
+    /// Enforces that the given coordinates are a point on the curve, i.e. `y^2 == x^3 - 3x + b`.
+    fn assert_on_curve((x, y): &Point<E>) {
+        let b = BaseField::constant(console::P256::b());
+        let rhs = x * x * x - (x + x + x) + b;
+        Self::assert_equal(&(y * y), &rhs);
+    }
+
+    /// Returns `point + point`.
+    fn double((x, y): &Point<E>) -> Point<E> {
+        // Compute the slope of the tangent, i.e. `(3x^2 - 3) / 2y`.
+        let x_squared = x * x;
+        let lambda = Self::divide(&(&x_squared + &x_squared + &x_squared - BaseField::constant(3u64.into())), &(y + y));
+        // Compute the sum.
+        let x3 = &lambda * &lambda - x - x;
+        let y3 = &lambda * &(x - &x3) - y;
+        (x3, y3)
+    }
+
+    /// Returns `first + second`, which is unsatisfiable if the points have the same x-coordinate.
+    fn add((x1, y1): &Point<E>, (x2, y2): &Point<E>) -> Point<E> {
+        // Compute the slope of the line through the points, i.e. `(y2 - y1) / (x2 - x1)`.
+        let lambda = Self::divide(&(y2 - y1), &(x2 - x1));
+        // Compute the sum.
+        let x3 = &lambda * &lambda - x1 - x2;
+        let y3 = &lambda * &(x1 - &x3) - y1;
+        (x3, y3)
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Boolean<E>, (x1, y1): &Point<E>, (x2, y2): &Point<E>) -> Point<E> {
+        (BaseField::ternary(condition, x1, x2), BaseField::ternary(condition, y1, y2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_add_and_double() {
+        let generator = Some(console::P256::generator());
+        let double = console::P256::add(&generator, &generator);
+        let triple = console::P256::add(&double, &generator);
+
+        for mode in [Mode::Constant, Mode::Private] {
+            let point = generator.clone().unwrap();
+            let point = (BaseField::<Circuit>::new(mode, point.0), BaseField::<Circuit>::new(mode, point.1));
+
+            Circuit::scope(format!("Add {mode}"), || {
+                P256::assert_on_curve(&point);
+                let candidate = P256::double(&point);
+                assert_eq!(double, Some((candidate.0.eject_value(), candidate.1.eject_value())));
+                let candidate = P256::add(&candidate, &point);
+                assert_eq!(triple, Some((candidate.0.eject_value(), candidate.1.eject_value())));
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_add_fails_on_same_x() {
+        let generator = console::P256::generator();
+        let point = (BaseField::<Circuit>::new(Mode::Private, generator.0), BaseField::new(Mode::Private, generator.1));
+        let _candidate = P256::add(&point, &point);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> P256<E> {
+    /// Returns `true` if `(r, s)` is a valid ECDSA signature of the given 256-bit digest, for the given public key.
+    /// Each input is given as little-endian bits, i.e. the 256 bits of the big-endian integer in reverse order.
+    ///
+    /// This method is unsatisfiable if the public key is not a point on the curve, or if `r` or `s` is not in `[1, n)`.
+    /// It is also unsatisfiable if the public key is the generator or its inverse,
+    /// or if the signature recovers the point at infinity.
+    pub fn verify(
+        public_key_x: &[Boolean<E>],
+        public_key_y: &[Boolean<E>],
+        digest: &[Boolean<E>],
+        r: &[Boolean<E>],
+        s: &[Boolean<E>],
+    ) -> Boolean<E> {
+        // Ensure the public key is a point on the curve.
+        let public_key = (BaseField::from_bits_le(public_key_x), BaseField::from_bits_le(public_key_y));
+        Self::assert_on_curve(&public_key);
+
+        // Ensure `r` and `s` are in `[1, n)`.
+        let r = ScalarField::from_bits_le(r);
+        let s = ScalarField::from_bits_le(s);
+        E::assert(r.is_not_equal(&ScalarField::zero()));
+        // Note: `s` is nonzero, as the inverse is unsatisfiable for zero.
+        let s_inverse = s.inverse();
+
+        // Compute `u1 := digest / s mod n`, and `u2 := r / s mod n`.
+        let u1 = ScalarField::from_bits_le_reduced(digest) * &s_inverse;
+        let u2 = &r * &s_inverse;
+
+        // Prepare the points to add, for each pair of bits of `u1` and `u2`.
+        let generator = Self::constant_point(&console::P256::generator());
+        let generator_plus_public_key = Self::add(&generator, &public_key);
+
+        // Compute `2^256 * offset + u1 * G + u2 * Q`, with Shamir's trick.
+        let offset = Some(console::P256::generator());
+        let offset = console::P256::scalar_multiply(&offset, &BigUint::from(OFFSET_SCALAR));
+        let mut accumulator = Self::constant_point(&offset.clone().expect("The offset point is not the identity"));
+        let (u1_bits, u2_bits) = (u1.to_bits_le(), u2.to_bits_le());
+        for (bit_1, bit_2) in u1_bits.iter().rev().zip_eq(u2_bits.iter().rev()) {
+            accumulator = Self::double(&accumulator);
+            // Select `G`, `Q`, or `G + Q`, where `G` is also selected (and discarded) if both bits are zero.
+            let point =
+                Self::ternary(bit_2, &Self::ternary(bit_1, &generator_plus_public_key, &public_key), &generator);
+            let sum = Self::add(&accumulator, &point);
+            accumulator = Self::ternary(&(bit_1 | bit_2), &sum, &accumulator);
+        }
+
+        // Compute `R := u1 * G + u2 * Q`, by subtracting `2^256 * offset`.
+        let num_bits = Secp256r1Scalar::num_limbs() * LIMB_BITS;
+        let offset = console::P256::scalar_multiply(&offset, &(BigUint::from(1u64) << num_bits));
+        let offset = console::P256::negate(&offset).expect("The offset point is not the identity");
+        let (x, _) = Self::add(&accumulator, &Self::constant_point(&offset));
+
+        // Return `R.x mod n == r`.
+        ScalarField::from_bits_le_reduced(&x.to_bits_le()).is_equal(&r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::Uniform;
+
+    /// Returns the 256 little-endian bits of the given integer, with the given mode.
+    fn to_bits_le(mode: Mode, value: &BigUint) -> Vec<Boolean<Circuit>> {
+        (0..256).map(|i| Boolean::new(mode, value.bit(i))).collect()
+    }
+
+    /// Samples a random integer in `[1, n)`.
+    fn sample_scalar(rng: &mut TestRng) -> BigUint {
+        let bytes = (0..40).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+        BigUint::from_bytes_le(&bytes) % (console::P256::order() - 1u64) + 1u64
+    }
+
+    fn check_verify(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        // Sign a digest.
+        let private_key = sample_scalar(&mut rng);
+        let (x, y) = console::P256::public_key(&private_key).unwrap();
+        let digest = BigUint::from_bytes_le(&(0..32).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>());
+        let (r, s) = console::P256::sign(&private_key, &digest, &sample_scalar(&mut rng)).unwrap();
+
+        for (digest, expected) in [(digest.clone(), true), (&digest ^ BigUint::from(1u64), false)] {
+            assert_eq!(expected, console::P256::verify(&(x.clone(), y.clone()), &digest, &r, &s).unwrap());
+
+            let (x, y) = (to_bits_le(mode, &x), to_bits_le(mode, &y));
+            let (digest, r, s) = (to_bits_le(mode, &digest), to_bits_le(mode, &r), to_bits_le(mode, &s));
+
+            Circuit::scope(format!("Verify {mode}"), || {
+                let candidate = P256::verify(&x, &y, &digest, &r, &s);
+                assert_eq!(expected, candidate.eject_value());
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        check_verify(Mode::Constant);
+    }
+
+    #[test]
+    #[ignore = "synthesizes millions of constraints, and requires over 10 GB of memory"]
+    fn test_verify_private() {
+        check_verify(Mode::Private);
+    }
+
+    #[test]
+    fn test_verify_fails_on_malformed_inputs() {
+        let mut rng = TestRng::default();
+        let (x, y) = console::P256::public_key(&sample_scalar(&mut rng)).unwrap();
+        let s = sample_scalar(&mut rng);
+
+        // Ensure `r` must be nonzero.
+        let result = std::panic::catch_unwind(|| {
+            let (x, y) = (to_bits_le(Mode::Constant, &x), to_bits_le(Mode::Constant, &y));
+            let zero = to_bits_le(Mode::Constant, &BigUint::default());
+            P256::verify(&x, &y, &zero, &zero, &to_bits_le(Mode::Constant, &s))
+        });
+        assert!(result.is_err());
+        Circuit::reset();
+
+        // Ensure the public key must be on the curve.
+        let result = std::panic::catch_unwind(|| {
+            let (x, y) = (to_bits_le(Mode::Constant, &x), to_bits_le(Mode::Constant, &(&y + 1u64)));
+            let s = to_bits_le(Mode::Constant, &s);
+            P256::verify(&x, &y, &s, &s, &s)
+        });
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod commit;
+mod commit_range;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
@@ -20,7 +21,7 @@ mod hash_uncompressed;
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
 
-use crate::{Commit, CommitUncompressed, Hash, HashUncompressed};
+use crate::{Commit, CommitRange, CommitUncompressed, Hash, HashUncompressed};
 use snarkvm_circuit_types::prelude::*;
 
 /// Pedersen64 is an *additively-homomorphic* collision-resistant hash function that takes up to a 64-bit input.
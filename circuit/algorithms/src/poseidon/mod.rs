@@ -17,6 +17,9 @@ mod hash_many;
 mod hash_to_group;
 mod hash_to_scalar;
 mod prf;
+mod sponge;
+
+pub use sponge::PoseidonSponge;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
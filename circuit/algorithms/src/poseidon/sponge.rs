@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A duplex sponge for streaming `absorb` and `squeeze` calls, using the Poseidon permutation.
+#[derive(Clone)]
+pub struct PoseidonSponge<'a, E: Environment, const RATE: usize> {
+    /// The Poseidon instance for the permutation.
+    poseidon: &'a Poseidon<E, RATE>,
+    /// The current state of the sponge.
+    state: Vec<Field<E>>,
+    /// The current mode of the sponge (whether it is absorbing or squeezing).
+    mode: DuplexSpongeMode,
+}
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns a new sponge for streaming `absorb` and `squeeze` calls, domain-separated by this instance.
+    ///
+    /// The sponge is initialized with the preimage header `[ DOMAIN || -1 || [0; RATE-2] ]`.
+    /// As no input has a length of `-1`, its outputs are distinct from those of `hash_many`.
+    pub fn sponge(&self) -> PoseidonSponge<'_, E, RATE> {
+        // Construct the header: [ DOMAIN || -1 || [0; RATE-2] ].
+        let mut header = Vec::with_capacity(RATE);
+        header.push(self.domain.clone());
+        header.push(-Field::one());
+        header.resize(RATE, Field::zero()); // Pad up to RATE.
+
+        let mut sponge = PoseidonSponge {
+            poseidon: self,
+            state: vec![Field::zero(); RATE + CAPACITY],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        };
+        sponge.absorb(&header);
+        sponge
+    }
+}
+
+impl<'a, E: Environment, const RATE: usize> PoseidonSponge<'a, E, RATE> {
+    /// Absorbs the given input elements into the sponge.
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        self.poseidon.absorb(&mut self.state, &mut self.mode, input)
+    }
+
+    /// Squeezes the specified number of elements from the sponge.
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        self.poseidon.squeeze(&mut self.state, &mut self.mode, num_outputs)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonSponge0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    fn check_sponge(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::AlgebraicSponge;
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the input.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Stream the input into the native sponge, interleaving squeezes of increasing length.
+            let mut native_sponge = native.sponge();
+            let mut expected = Vec::new();
+            for (j, element) in native_input.iter().enumerate() {
+                native_sponge.absorb(&[*element]);
+                expected.extend(native_sponge.squeeze(j as u16));
+            }
+            expected.extend(native_sponge.squeeze(RATE as u16 + 1));
+
+            // Stream the input into the circuit sponge.
+            Circuit::scope(format!("PoseidonSponge {mode} {i}"), || {
+                let mut sponge = poseidon.sponge();
+                let mut candidate = Vec::new();
+                for (j, element) in input.iter().enumerate() {
+                    sponge.absorb(std::slice::from_ref(element));
+                    candidate.extend(sponge.squeeze(j as u16));
+                }
+                candidate.extend(sponge.squeeze(RATE as u16 + 1));
+
+                for (expected_element, candidate_element) in expected.iter().zip_eq(&candidate) {
+                    assert_eq!(*expected_element, candidate_element.eject_value());
+                }
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=(2 * RATE) {
+            check_sponge(Mode::Constant, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=(2 * RATE) {
+            check_sponge(Mode::Public, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=(2 * RATE) {
+            check_sponge(Mode::Private, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+}
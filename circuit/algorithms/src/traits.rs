@@ -27,6 +27,24 @@ pub trait Commit {
     fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Self::Output;
 }
 
+/// A trait for a commitment scheme that proves a committed value lies in the range `[0, 2^num_bits)`.
+pub trait CommitRange {
+    type Boolean;
+    type Value;
+    type Output;
+    type Randomizer;
+
+    /// Returns `true` if the given commitment opens to the `num_bits`-bit little-endian encoding
+    /// of the given value and randomizer, and the value lies in the range `[0, 2^num_bits)`.
+    fn is_committed_in_range(
+        &self,
+        commitment: &Self::Output,
+        value: &Self::Value,
+        randomizer: &Self::Randomizer,
+        num_bits: usize,
+    ) -> Self::Boolean;
+}
+
 /// A trait for a commitment scheme.
 pub trait CommitUncompressed {
     type Input;
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use super::*;
+
+/// A Merkle multi-path, which proves the membership of many leaves in a single proof.
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the path.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains the sibling hashes that are not computed from the leaves,
+    /// ordered by level from the leaf to the root, and by index within each level.
+    siblings: Vec<Field<E>>,
+    /// The `merges` contains, for each level from the leaf to the root, whether each node is merged with the next node.
+    /// Note: The shape of the multi-path is fixed when the circuit is synthesized, and is not part of the witness.
+    merges: Vec<Vec<bool>>,
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Inject for MerkleMultiPath<E, DEPTH> {
+    type Primitive = console::merkle_tree::MerkleMultiPath<E::Network, DEPTH>;
+
+    /// Initializes a Merkle multi-path from the given mode and native Merkle multi-path.
+    fn new(mode: Mode, multi_path: Self::Primitive) -> Self {
+        // Initialize the shape of the Merkle multi-path.
+        let merges = Self::Primitive::merges(multi_path.leaf_indices());
+        // Initialize the leaf indices.
+        let leaf_indices = multi_path.leaf_indices().iter().map(|index| U64::new(mode, *index)).collect();
+        // Initialize the Merkle multi-path siblings.
+        let siblings = multi_path.siblings().iter().map(|node| Field::new(mode, *node)).collect();
+        // Return the Merkle multi-path.
+        Self { leaf_indices, siblings, merges }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Eject for MerkleMultiPath<E, DEPTH> {
+    type Primitive = console::merkle_tree::MerkleMultiPath<E::Network, DEPTH>;
+
+    /// Ejects the mode of the Merkle multi-path.
+    fn eject_mode(&self) -> Mode {
+        (&self.leaf_indices, &self.siblings).eject_mode()
+    }
+
+    /// Ejects the Merkle multi-path.
+    fn eject_value(&self) -> Self::Primitive {
+        match Self::Primitive::try_from((&self.leaf_indices, &self.siblings).eject_value()) {
+            Ok(multi_path) => multi_path,
+            Err(error) => E::halt(format!("Failed to eject the Merkle multi-path: {error}")),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_network::AleoV0 as Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+    use std::collections::BTreeMap;
+
+    fn check_new<const DEPTH: u8>(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Compute the leaves.
+        let leaves = (0..10)
+            .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng).to_bits_le())
+            .collect::<Vec<_>>();
+        // Compute the Merkle tree.
+        let merkle_tree =
+            <<Circuit as Environment>::Network as snarkvm_console_network::Network>::merkle_tree_bhp::<DEPTH>(&leaves)?;
+
+        // Compute the Merkle multi-path.
+        let subset = [1, 2, 3, 7].into_iter().map(|index| (index, leaves[index].clone())).collect::<BTreeMap<_, _>>();
+        let multi_path = merkle_tree.prove_many(&subset)?;
+
+        Circuit::scope(format!("New {mode}"), || {
+            let candidate = MerkleMultiPath::<Circuit, DEPTH>::new(mode, multi_path.clone());
+            assert_eq!(multi_path, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_constant() -> Result<()> {
+        check_new::<32>(Mode::Constant, 288, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_new_public() -> Result<()> {
+        check_new::<32>(Mode::Public, 0, 288, 0, 256)
+    }
+
+    #[test]
+    fn test_new_private() -> Result<()> {
+        check_new::<32>(Mode::Private, 0, 0, 288, 256)
+    }
+}
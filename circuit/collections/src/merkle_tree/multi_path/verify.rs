@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    /// The leaves must be given in the same order as the leaf indices.
+    pub fn verify<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> Boolean<E> {
+        // Ensure the number of leaves matches the number of leaf indices.
+        if leaves.len() != self.leaf_indices.len() {
+            E::halt("Found an incorrect number of Merkle leaves")
+        }
+        // Ensure the shape of the Merkle multi-path matches the depth.
+        if self.merges.len() != DEPTH as usize {
+            E::halt("Found an incorrect Merkle multi-path depth")
+        }
+
+        // Initialize a tracker for the validity of the Merkle multi-path.
+        let mut is_valid = Boolean::constant(true);
+
+        // Initialize a tracker for the current nodes, given as the leaf index bits of the first leaf
+        // below each node, and the current hash, by computing the leaf hashes to start.
+        let mut current_nodes = Vec::with_capacity(leaves.len());
+        for (leaf_index, leaf) in self.leaf_indices.iter().zip_eq(leaves) {
+            // Retrieve the bits of the leaf index.
+            let leaf_index_bits = leaf_index.to_bits_le();
+            // Ensure the leaf index is within the tree depth.
+            for bit in leaf_index_bits.iter().skip(DEPTH as usize) {
+                is_valid &= !bit;
+            }
+            current_nodes.push((leaf_index_bits, leaf_hasher.hash_leaf(leaf)));
+        }
+
+        // Initialize an iterator over the sibling hashes.
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between leaf level and root.
+        for (level, merges) in self.merges.iter().enumerate() {
+            let mut next_nodes = Vec::with_capacity(merges.len());
+            let mut i = 0;
+            for is_merged in merges {
+                // Retrieve the current node.
+                let (bits, current_hash) = match current_nodes.get(i) {
+                    Some(node) => node,
+                    None => E::halt("Found an incorrect Merkle multi-path shape"),
+                };
+                // Construct the ordering of the left & right child hash for this node.
+                let (left, right) = match is_merged {
+                    // If the node is merged, then the next node is its sibling.
+                    true => {
+                        let (sibling_bits, sibling_hash) = match current_nodes.get(i + 1) {
+                            Some(node) => node,
+                            None => E::halt("Found an incorrect Merkle multi-path shape"),
+                        };
+                        // Ensure the current node is a left child, and the next node is a right child.
+                        is_valid &= !&bits[level] & &sibling_bits[level];
+                        // Ensure both nodes share the same parent.
+                        for (bit, sibling_bit) in
+                            bits[level + 1..DEPTH as usize].iter().zip_eq(&sibling_bits[level + 1..DEPTH as usize])
+                        {
+                            is_valid &= bit.is_equal(sibling_bit);
+                        }
+                        (current_hash.clone(), sibling_hash.clone())
+                    }
+                    // Otherwise, order the current hash and sibling hash by the leaf index bit.
+                    false => {
+                        let sibling_hash = match siblings.next() {
+                            Some(sibling_hash) => sibling_hash,
+                            None => E::halt("Found an incorrect number of Merkle multi-path siblings"),
+                        };
+                        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+                        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+                        let indicator = !&bits[level];
+                        (
+                            Field::ternary(&indicator, current_hash, sibling_hash),
+                            Field::ternary(&indicator, sibling_hash, current_hash),
+                        )
+                    }
+                };
+                // Compute the hash for the next level.
+                next_nodes.push((bits.clone(), path_hasher.hash_children(&left, &right)));
+                i += 1 + *is_merged as usize;
+            }
+            // Ensure every node on this level was consumed.
+            if i != current_nodes.len() {
+                E::halt("Found an incorrect Merkle multi-path shape")
+            }
+            current_nodes = next_nodes;
+        }
+
+        // Ensure all of the sibling hashes were used, and a single node remains.
+        if siblings.next().is_some() || current_nodes.len() != 1 {
+            E::halt("Found an incorrect Merkle multi-path shape")
+        }
+
+        // Ensure the final hash matches the given root.
+        is_valid & root.is_equal(&current_nodes[0].1)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+    use std::collections::BTreeMap;
+
+    const DOMAIN: &str = "MerkleTreeCircuit0";
+
+    macro_rules! check_verify {
+        ($lh:ident, $ph:ident, $mode:ident, $depth:expr, $num_inputs:expr, ($num_constants:expr, $num_public:expr, $num_private:expr, $num_constraints:expr)) => {{
+            // Initialize the leaf hasher.
+            let native_leaf_hasher =
+                snarkvm_console_algorithms::$lh::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_leaf_hasher = $lh::<Circuit>::constant(native_leaf_hasher.clone());
+
+            let mut rng = TestRng::default();
+
+            // Initialize the path hasher.
+            let native_path_hasher =
+                snarkvm_console_algorithms::$ph::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_path_hasher = $ph::<Circuit>::constant(native_path_hasher.clone());
+
+            // Compute the leaves.
+            let leaves = (0..10)
+                .map(|_| (0..$num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            // Compute the Merkle tree.
+            let merkle_tree = console::merkle_tree::MerkleTree::<_, _, _, $depth>::new(
+                &native_leaf_hasher,
+                &native_path_hasher,
+                &leaves,
+            )?;
+
+            // Compute the Merkle multi-path.
+            let subset =
+                [1, 2, 3, 7].into_iter().map(|index| (index, leaves[index].clone())).collect::<BTreeMap<_, _>>();
+            let multi_path = merkle_tree.prove_many(&subset)?;
+
+            // Initialize the Merkle multi-path.
+            let path = MerkleMultiPath::<Circuit, $depth>::new(Mode::$mode, multi_path.clone());
+            assert_eq!(multi_path, path.eject_value());
+            // Initialize the Merkle root.
+            let root = Field::new(Mode::$mode, *merkle_tree.root());
+            // Initialize the Merkle leaves.
+            let leaves: Vec<Vec<_>> = subset.values().map(|leaf| Inject::new(Mode::$mode, leaf.clone())).collect();
+
+            Circuit::scope(format!("Verify {}", Mode::$mode), || {
+                let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaves);
+                assert!(candidate.eject_value());
+                assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+            });
+            Circuit::reset();
+
+            // Initialize an incorrect Merkle root.
+            let incorrect_root = root.clone() + Field::one();
+
+            Circuit::scope(format!("Verify (Incorrect Root) {}", Mode::$mode), || {
+                let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &incorrect_root, &leaves);
+                assert!(!candidate.eject_value());
+                assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+            });
+            Circuit::reset();
+
+            // Initialize the Merkle leaves in the wrong order.
+            let mut incorrect_leaves = leaves.clone();
+            incorrect_leaves.swap(0, 3);
+
+            Circuit::scope(format!("Verify (Incorrect Leaves) {}", Mode::$mode), || {
+                let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &incorrect_leaves);
+                assert!(!candidate.eject_value());
+                assert_scope!($num_constants, $num_public, $num_private, $num_constraints);
+            });
+            Circuit::reset();
+            Ok(())
+        }};
+    }
+
+    #[test]
+    fn test_verify_bhp512_constant() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Constant, 32, 1024, (63130, 0, 0, 0))
+    }
+
+    #[test]
+    fn test_verify_bhp512_public() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Public, 32, 1024, (15967, 0, 73121, 73262))
+    }
+
+    #[test]
+    fn test_verify_bhp512_private() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Private, 32, 1024, (15967, 0, 73121, 73262))
+    }
+
+    #[test]
+    fn test_verify_poseidon2_constant() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Constant, 32, 4, (40, 0, 0, 0))
+    }
+
+    #[test]
+    fn test_verify_poseidon2_public() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Public, 32, 4, (39, 0, 22080, 22081))
+    }
+
+    #[test]
+    fn test_verify_poseidon2_private() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Private, 32, 4, (39, 0, 22080, 22081))
+    }
+}
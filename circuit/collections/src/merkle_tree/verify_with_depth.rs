@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Returns `true` if the Merkle path is valid for the given root and leaf, in a tree of the given depth.
+    /// The depth may be private, and must lie in `[1, DEPTH]`, where siblings beyond the depth are ignored.
+    pub fn verify_with_depth<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+        depth: &U8<E>,
+    ) -> Boolean<E> {
+        // Ensure the path length matches the maximum depth.
+        if self.siblings.len() != DEPTH as usize {
+            E::halt("Found an incorrect Merkle path length")
+        }
+
+        // Initialize a tracker for the current level, starting from the leaf level.
+        let mut level = U8::zero();
+        // Ensure the depth is greater than 0.
+        let mut is_valid = depth.is_greater_than(&level);
+
+        // Initialize a tracker for the current hash, by computing the leaf hash to start.
+        let mut current_hash = leaf_hasher.hash_leaf(leaf);
+
+        // Retrieve the bits of the leaf index.
+        let leaf_index_bits = self.leaf_index.to_bits_le();
+        // Ensure the leaf index is within the maximum depth.
+        for bit in leaf_index_bits.iter().skip(DEPTH as usize) {
+            is_valid &= !bit;
+        }
+
+        // Check levels between leaf level and the maximum depth.
+        for (bit, sibling_hash) in leaf_index_bits.iter().take(DEPTH as usize).zip_eq(&self.siblings) {
+            // Determine whether this level lies within the given depth.
+            let is_active = depth.is_greater_than(&level);
+            // Ensure the leaf index is within the given depth.
+            is_valid &= &is_active | &!bit;
+
+            // Compute the ordering of the current hash and sibling hash on this level.
+            // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+            // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+            let indicator = !bit;
+            // Construct the ordering of the left & right child hash for this level.
+            let left = Field::ternary(&indicator, &current_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &current_hash);
+
+            // Update the current hash for the next level, if the level lies within the given depth.
+            let next_hash = path_hasher.hash_children(&left, &right);
+            current_hash = Field::ternary(&is_active, &next_hash, &current_hash);
+
+            // Increment the level.
+            level = &level + &U8::one();
+        }
+
+        // Ensure the depth does not exceed the maximum depth.
+        is_valid &= !depth.is_greater_than(&level);
+
+        // Ensure the final hash matches the given root.
+        is_valid & root.is_equal(&current_hash)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u128 = 4;
+    const DOMAIN: &str = "MerkleTreeCircuit0";
+    const MAX_DEPTH: u8 = 8;
+
+    fn check_verify_with_depth<const TREE_DEPTH: u8>(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize the leaf hasher.
+        let native_leaf_hasher = snarkvm_console_algorithms::Poseidon4::<CurrentNetwork>::setup(DOMAIN)?;
+        let circuit_leaf_hasher = Poseidon4::<Circuit>::constant(native_leaf_hasher.clone());
+
+        // Initialize the path hasher.
+        let native_path_hasher = snarkvm_console_algorithms::Poseidon2::<CurrentNetwork>::setup(DOMAIN)?;
+        let circuit_path_hasher = Poseidon2::<Circuit>::constant(native_path_hasher.clone());
+
+        let mut rng = TestRng::default();
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(TREE_DEPTH as u32), ITERATIONS);
+        // Compute the leaves.
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        // Compute the Merkle tree.
+        let merkle_tree = console::merkle_tree::MerkleTree::<_, _, _, TREE_DEPTH>::new(
+            &native_leaf_hasher,
+            &native_path_hasher,
+            &leaves,
+        )?;
+
+        for (index, merkle_leaf) in leaves.iter().enumerate() {
+            // Compute the Merkle path, and pad it to the maximum depth.
+            let merkle_path = merkle_tree.prove(index, merkle_leaf)?.pad::<MAX_DEPTH>()?;
+
+            // Initialize the Merkle path.
+            let path = MerklePath::<Circuit, MAX_DEPTH>::new(mode, merkle_path.clone());
+            // Initialize the Merkle root.
+            let root = Field::new(mode, *merkle_tree.root());
+            // Initialize the Merkle leaf.
+            let leaf: Vec<_> = Inject::new(mode, merkle_leaf.clone());
+
+            // Verify the Merkle path against each candidate depth.
+            for candidate_depth in 0..=MAX_DEPTH + 1 {
+                let depth = U8::new(mode, console::U8::new(candidate_depth));
+
+                Circuit::scope(format!("Verify {mode} {candidate_depth}"), || {
+                    let candidate =
+                        path.verify_with_depth(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf, &depth);
+                    // Ensure the Merkle path is only valid for the depth of the tree.
+                    assert_eq!(candidate_depth == TREE_DEPTH, candidate.eject_value());
+                    assert_scope!(num_constants, num_public, num_private, num_constraints);
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+
+            // Initialize an incorrect Merkle root.
+            let incorrect_root = root.clone() + Field::one();
+            let depth = U8::new(mode, console::U8::new(TREE_DEPTH));
+
+            Circuit::scope(format!("Verify (Incorrect Root) {mode}"), || {
+                let candidate =
+                    path.verify_with_depth(&circuit_leaf_hasher, &circuit_path_hasher, &incorrect_root, &leaf, &depth);
+                assert!(!candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_depth_constant() -> Result<()> {
+        check_verify_with_depth::<1>(Mode::Constant, 156, 0, 0, 0)?;
+        check_verify_with_depth::<4>(Mode::Constant, 156, 0, 0, 0)?;
+        check_verify_with_depth::<MAX_DEPTH>(Mode::Constant, 156, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_verify_with_depth_public() -> Result<()> {
+        check_verify_with_depth::<1>(Mode::Public, 225, 0, 4845, 4856)?;
+        check_verify_with_depth::<4>(Mode::Public, 225, 0, 4845, 4856)?;
+        check_verify_with_depth::<MAX_DEPTH>(Mode::Public, 225, 0, 4845, 4856)
+    }
+
+    #[test]
+    fn test_verify_with_depth_private() -> Result<()> {
+        check_verify_with_depth::<1>(Mode::Private, 225, 0, 4845, 4856)?;
+        check_verify_with_depth::<4>(Mode::Private, 225, 0, 4845, 4856)?;
+        check_verify_with_depth::<MAX_DEPTH>(Mode::Private, 225, 0, 4845, 4856)
+    }
+}
@@ -132,6 +132,56 @@ impl Environment for Circuit {
         })
     }
 
+    /// Runs the given logic on the given circuit, in place of the current circuit.
+    /// The current circuit is suspended, and restored once the logic returns or halts.
+    fn with_r1cs<Fn, Output>(r1cs: &mut R1CS<Self::BaseField>, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        /// Restores the suspended circuit when dropped, so that a halt does not leak the given circuit.
+        struct Suspended<'a> {
+            r1cs: &'a mut R1CS<Field>,
+            in_witness: bool,
+        }
+
+        impl Drop for Suspended<'_> {
+            fn drop(&mut self) {
+                CIRCUIT.with(|circuit| core::mem::swap(&mut *(**circuit).borrow_mut(), self.r1cs));
+                IN_WITNESS.with(|in_witness| *(**in_witness).borrow_mut() = self.in_witness);
+            }
+        }
+
+        // Swap in the given circuit, suspending the current circuit.
+        CIRCUIT.with(|circuit| core::mem::swap(&mut *(**circuit).borrow_mut(), r1cs));
+        // Suspend witness mode, as the given circuit starts outside of it.
+        let in_witness = IN_WITNESS.with(|in_witness| core::mem::replace(&mut *(**in_witness).borrow_mut(), false));
+        let _suspended = Suspended { r1cs, in_witness };
+
+        // Run the logic.
+        logic()
+    }
+
+    /// Runs the given logic, halting synthesis if it adds more than `max_constraints` constraints.
+    fn with_budget<Fn, Output>(max_constraints: u64, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Start the constraint budget.
+        CIRCUIT.with(|circuit| (**circuit).borrow_mut().push_budget(max_constraints));
+
+        // Run the logic.
+        let output = logic();
+
+        // Remove the constraint budget.
+        CIRCUIT.with(|circuit| {
+            if let Err(error) = (**circuit).borrow_mut().pop_budget() {
+                Self::halt(error)
+            }
+        });
+
+        output
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -169,7 +219,11 @@ impl Environment for Circuit {
                             // Construct the constraint object.
                             let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
                             // Append the constraint.
-                            (**circuit).borrow_mut().enforce(constraint)
+                            (**circuit).borrow_mut().enforce(constraint);
+                            // Ensure no constraint budget has been exceeded.
+                            if let Err(error) = (**circuit).borrow().check_budgets() {
+                                Self::halt(error)
+                            }
                         }
                     }
                 });
@@ -189,6 +243,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
     }
 
+    /// Returns the first constraint in the environment that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().first_unsatisfied_constraint())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
@@ -352,6 +411,45 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_circuit_budget() {
+        let candidate = Circuit::with_budget(64, || Circuit::scope("example", create_example_circuit::<Circuit>));
+        assert_eq!(64, Circuit::num_constraints());
+        assert!(!candidate.eject_value().is_zero());
+
+        // Ensure nested budgets are released.
+        Circuit::with_budget(1, || Circuit::with_budget(1, || ()));
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic(expected = "Exceeded the constraint budget of 63 in scope 'example'")]
+    fn test_circuit_budget_exceeded() {
+        Circuit::with_budget(63, || Circuit::scope("example", create_example_circuit::<Circuit>));
+    }
+
+    #[test]
+    fn test_circuit_isolated() {
+        let _candidate = create_example_circuit::<Circuit>();
+
+        // Ensure the isolated circuit starts empty, and leaves the current circuit untouched.
+        let num_constraints = Circuit::isolated(|| {
+            assert_eq!(0, Circuit::num_constraints());
+            let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+            let _candidate = Field::<Circuit>::new(Mode::Private, one);
+            Circuit::num_private()
+        });
+        assert_eq!(1, num_constraints);
+        assert_eq!(64, Circuit::num_constraints());
+        assert_eq!(129, Circuit::num_private());
+
+        // Ensure the current circuit is restored when the isolated circuit halts.
+        let result = std::panic::catch_unwind(|| Circuit::isolated(|| Circuit::halt::<_, ()>("Halted")));
+        assert!(result.is_err());
+        assert_eq!(64, Circuit::num_constraints());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{witness_mode, Assignment, CustomGate, Inject, LinearCombination, Mode, UnsatisfiedConstraint, Variable, R1CS};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -59,6 +59,25 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     where
         Fn: FnOnce() -> Output;
 
+    /// Runs the given logic on the given circuit, in place of the current circuit.
+    /// The current circuit is suspended, and restored once the logic returns or halts.
+    fn with_r1cs<Fn, Output>(r1cs: &mut R1CS<Self::BaseField>, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Runs the given logic on a new, empty circuit, restoring the current circuit afterwards.
+    fn isolated<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        Self::with_r1cs(&mut R1CS::new(), logic)
+    }
+
+    /// Runs the given logic, halting synthesis if it adds more than `max_constraints` constraints.
+    fn with_budget<Fn, Output>(max_constraints: u64, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -103,12 +122,32 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         Self::enforce(|| (a_minus_b, multiplier, Self::one()));
     }
 
+    /// Returns the output of the given custom gate on the given inputs.
+    fn apply_gate(
+        gate: &CustomGate<Self::BaseField>,
+        inputs: &[LinearCombination<Self::BaseField>],
+    ) -> LinearCombination<Self::BaseField> {
+        gate.apply::<Self>(inputs)
+    }
+
+    /// Adds the constraints enforcing that the output of the given custom gate on the given inputs is `output`.
+    fn enforce_gate<Output: Into<LinearCombination<Self::BaseField>>>(
+        gate: &CustomGate<Self::BaseField>,
+        inputs: &[LinearCombination<Self::BaseField>],
+        output: Output,
+    ) {
+        gate.enforce::<Self>(inputs, output.into())
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns the first constraint in the environment that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>>;
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Assignment, Environment, R1CS};
+
+use core::marker::PhantomData;
+
+/// A circuit builder that owns its own circuit, independent of the current circuit in the environment.
+/// Multiple builders may exist on the same thread, and each may be entered any number of times.
+#[derive(Debug)]
+pub struct CircuitBuilder<E: Environment> {
+    r1cs: R1CS<E::BaseField>,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> CircuitBuilder<E> {
+    /// Initializes a new circuit builder with an empty circuit.
+    pub fn new() -> Self {
+        Self { r1cs: R1CS::new(), _phantom: PhantomData }
+    }
+
+    /// Runs the given logic on the circuit of this builder.
+    /// The current circuit in the environment is suspended, and restored once the logic returns or halts.
+    pub fn enter<Fn, Output>(&mut self, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::with_r1cs(&mut self.r1cs, logic)
+    }
+
+    /// Returns the number of public variables in the circuit.
+    pub fn num_public(&self) -> u64 {
+        self.r1cs.num_public()
+    }
+
+    /// Returns the number of private variables in the circuit.
+    pub fn num_private(&self) -> u64 {
+        self.r1cs.num_private()
+    }
+
+    /// Returns the number of constraints in the circuit.
+    pub fn num_constraints(&self) -> u64 {
+        self.r1cs.num_constraints()
+    }
+
+    /// Returns the R1CS circuit of this builder.
+    pub fn into_r1cs(self) -> R1CS<E::BaseField> {
+        self.r1cs
+    }
+
+    /// Returns the R1CS assignment of the circuit of this builder.
+    pub fn into_assignment(self) -> Assignment<E::BaseField> {
+        Assignment::from(self.r1cs)
+    }
+}
+
+impl<E: Environment> Default for CircuitBuilder<E> {
+    /// Initializes a new circuit builder with an empty circuit.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::{environment::CircuitBuilder, prelude::*};
+
+    type CurrentEnvironment = Circuit;
+
+    fn sample_square(value: u64) -> Field<CurrentEnvironment> {
+        let value = snarkvm_console_types::Field::from_u64(value);
+        let candidate = Field::<CurrentEnvironment>::new(Mode::Private, value);
+        candidate.square()
+    }
+
+    #[test]
+    fn test_interleaved_builders() {
+        let mut first = CircuitBuilder::<CurrentEnvironment>::new();
+        let mut second = CircuitBuilder::<CurrentEnvironment>::new();
+
+        // Interleave synthesis across both builders.
+        first.enter(|| sample_square(2));
+        second.enter(|| sample_square(3));
+        first.enter(|| sample_square(4));
+
+        // Ensure each builder only contains its own constraints.
+        assert_eq!(2, first.num_constraints());
+        assert_eq!(4, first.num_private());
+        assert_eq!(1, second.num_constraints());
+        assert_eq!(2, second.num_private());
+
+        // Ensure the current circuit is left untouched.
+        assert_eq!(0, CurrentEnvironment::num_constraints());
+        assert_eq!(1, CurrentEnvironment::num_public());
+        assert_eq!(0, CurrentEnvironment::num_private());
+
+        // Ensure the assignment contains the constraints of the builder.
+        let assignment = first.into_assignment();
+        assert_eq!(2, assignment.num_constraints());
+    }
+
+    #[test]
+    fn test_nested_builders() {
+        let mut outer = CircuitBuilder::<CurrentEnvironment>::new();
+        let mut inner = CircuitBuilder::<CurrentEnvironment>::new();
+
+        outer.enter(|| {
+            sample_square(2);
+            inner.enter(|| sample_square(3));
+            sample_square(4);
+            assert_eq!(2, CurrentEnvironment::num_constraints());
+            assert!(CurrentEnvironment::is_satisfied());
+        });
+        assert_eq!(2, outer.num_constraints());
+        assert_eq!(1, inner.num_constraints());
+    }
+}
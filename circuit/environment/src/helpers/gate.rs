@@ -0,0 +1,323 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, Environment, LinearCombination};
+use snarkvm_fields::PrimeField;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// A monomial in the inputs of a custom gate, given as `(input index, exponent)` pairs in increasing order of index.
+type Monomial = Vec<(usize, u32)>;
+
+/// A custom gate, which computes a polynomial of arbitrary degree in its inputs.
+///
+/// A custom gate is lowered to R1CS by computing each monomial with a short chain of multiplications,
+/// where partial products are shared between monomials, and linear terms are absorbed for free.
+/// The last multiplication is folded into the output, so enforcing the output of a gate
+/// saves one constraint over a gadget that first computes the output and then asserts it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomGate<F: PrimeField> {
+    /// The name of the gate.
+    name: String,
+    /// The number of inputs to the gate.
+    num_inputs: usize,
+    /// The terms of the gate, as pairs of a coefficient and a monomial.
+    terms: Vec<(F, Monomial)>,
+}
+
+impl<F: PrimeField> CustomGate<F> {
+    /// Initializes a new custom gate with the given name and number of inputs, and no terms.
+    pub fn new<S: Into<String>>(name: S, num_inputs: usize) -> Self {
+        Self { name: name.into(), num_inputs, terms: Vec::new() }
+    }
+
+    /// Adds the term `coefficient * inputs[i_0]^e_0 * ... * inputs[i_n]^e_n` to the gate,
+    /// where the monomial is given as the `(i_j, e_j)` pairs. An empty monomial adds a constant term.
+    pub fn with_term(mut self, coefficient: F, monomial: &[(usize, u32)]) -> Result<Self> {
+        // Normalize the monomial, by merging repeated inputs and removing zero exponents.
+        let mut normalized = BTreeMap::new();
+        for (index, exponent) in monomial {
+            ensure!(*index < self.num_inputs, "Custom gate '{}' has no input at index {index}", self.name);
+            let entry = normalized.entry(*index).or_insert(0u32);
+            *entry = match entry.checked_add(*exponent) {
+                Some(exponent) => exponent,
+                None => bail!("Custom gate '{}' has an exponent that overflows", self.name),
+            };
+        }
+        let monomial = normalized.into_iter().filter(|(_, exponent)| *exponent > 0).collect::<Monomial>();
+
+        // Merge the term with an existing term of the same monomial, if one exists.
+        match self.terms.iter_mut().find(|(_, existing)| *existing == monomial) {
+            Some((existing, _)) => *existing += coefficient,
+            None => self.terms.push((coefficient, monomial)),
+        }
+        Ok(self)
+    }
+
+    /// Returns the name of the gate.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of inputs to the gate.
+    pub const fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the degree of the gate.
+    pub fn degree(&self) -> u32 {
+        self.terms.iter().map(|(_, monomial)| Self::degree_of(monomial)).max().unwrap_or(0)
+    }
+
+    /// Returns the output of the gate on the given inputs.
+    pub fn evaluate(&self, inputs: &[F]) -> Result<F> {
+        ensure!(inputs.len() == self.num_inputs, "Custom gate '{}' expects {} inputs", self.name, self.num_inputs);
+        Ok(self
+            .terms
+            .iter()
+            .map(|(coefficient, monomial)| {
+                monomial
+                    .iter()
+                    .fold(*coefficient, |acc, (index, exponent)| acc * inputs[*index].pow([u64::from(*exponent)]))
+            })
+            .sum())
+    }
+
+    /// Returns the output of the gate on the given inputs, as a linear combination.
+    pub(crate) fn apply<E: Environment<BaseField = F>>(&self, inputs: &[LinearCombination<F>]) -> LinearCombination<F> {
+        let (linear, last) = self.lower::<E>(inputs);
+        match last {
+            // If the last multiplication is not over variables, the output is a linear combination.
+            Some((left, right)) if left.is_constant() || right.is_constant() => linear + Self::mul::<E>(left, right),
+            // Otherwise, allocate the output, and fold the last multiplication into it.
+            Some((left, right)) => {
+                let output: LinearCombination<F> =
+                    E::new_variable(Mode::Private, linear.value() + left.value() * right.value()).into();
+                E::enforce(|| (left, right, &output - &linear));
+                output
+            }
+            None => linear,
+        }
+    }
+
+    /// Adds the constraints enforcing that the output of the gate on the given inputs is `output`.
+    pub(crate) fn enforce<E: Environment<BaseField = F>>(
+        &self,
+        inputs: &[LinearCombination<F>],
+        output: LinearCombination<F>,
+    ) {
+        let (linear, last) = self.lower::<E>(inputs);
+        match last {
+            // Fold the last multiplication into the equality check.
+            Some((left, right)) => E::enforce(|| (left, right, output - linear)),
+            None => E::assert_eq(linear, output),
+        }
+    }
+
+    /// Lowers the gate on the given inputs, returning the sum of every term except the last term of degree
+    /// greater than one, and the operands of the last multiplication of that term, if one exists.
+    fn lower<E: Environment<BaseField = F>>(
+        &self,
+        inputs: &[LinearCombination<F>],
+    ) -> (LinearCombination<F>, Option<(LinearCombination<F>, LinearCombination<F>)>) {
+        // Ensure the number of inputs matches the gate.
+        if inputs.len() != self.num_inputs {
+            E::halt(format!("Custom gate '{}' expects {} inputs, found {}", self.name, self.num_inputs, inputs.len()))
+        }
+
+        // Initialize a cache of the products computed so far, to share them between monomials.
+        let mut products = HashMap::new();
+        // Determine the last term that requires a multiplication, which is folded into the output.
+        let last_index = self.terms.iter().rposition(|(_, monomial)| Self::degree_of(monomial) > 1);
+
+        let mut linear = E::zero();
+        let mut last = None;
+        for (i, (coefficient, monomial)) in self.terms.iter().enumerate() {
+            match Some(i) == last_index {
+                true => {
+                    let (left, right) = Self::split(monomial);
+                    let left = Self::product::<E>(&left, inputs, &mut products);
+                    let right = Self::product::<E>(&right, inputs, &mut products);
+                    last = Some((left * coefficient, right));
+                }
+                false => linear += Self::product::<E>(monomial, inputs, &mut products) * coefficient,
+            }
+        }
+        (linear, last)
+    }
+
+    /// Returns the product of the given monomial on the given inputs, using the cache of computed products.
+    fn product<E: Environment<BaseField = F>>(
+        monomial: &Monomial,
+        inputs: &[LinearCombination<F>],
+        products: &mut HashMap<Monomial, LinearCombination<F>>,
+    ) -> LinearCombination<F> {
+        match monomial.as_slice() {
+            [] => E::one(),
+            [(index, 1)] => inputs[*index].clone(),
+            _ => {
+                if let Some(product) = products.get(monomial) {
+                    return product.clone();
+                }
+                let (left, right) = Self::split(monomial);
+                let left = Self::product::<E>(&left, inputs, products);
+                let right = Self::product::<E>(&right, inputs, products);
+                let product = Self::mul::<E>(left, right);
+                products.insert(monomial.clone(), product.clone());
+                product
+            }
+        }
+    }
+
+    /// Splits the given monomial of degree greater than one into two monomials, whose product is the monomial.
+    /// Powers of a single input are split by repeated squaring.
+    fn split(monomial: &Monomial) -> (Monomial, Monomial) {
+        match monomial.as_slice() {
+            [(index, exponent)] if exponent % 2 == 0 => (vec![(*index, exponent / 2)], vec![(*index, exponent / 2)]),
+            [(index, exponent)] => (vec![(*index, exponent - 1)], vec![(*index, 1)]),
+            [rest @ .., last] => (rest.to_vec(), vec![*last]),
+            [] => unreachable!("The monomial must have a degree greater than one"),
+        }
+    }
+
+    /// Returns the product of the given linear combinations, adding a constraint if both are variables.
+    fn mul<E: Environment<BaseField = F>>(a: LinearCombination<F>, b: LinearCombination<F>) -> LinearCombination<F> {
+        if a.is_constant() {
+            b * a.value()
+        } else if b.is_constant() {
+            a * b.value()
+        } else {
+            let product: LinearCombination<F> = E::new_variable(Mode::Private, a.value() * b.value()).into();
+            E::enforce(|| (a, b, product.clone()));
+            product
+        }
+    }
+
+    /// Returns the degree of the given monomial.
+    fn degree_of(monomial: &Monomial) -> u32 {
+        monomial.iter().map(|(_, exponent)| exponent).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    type F = <Circuit as Environment>::BaseField;
+
+    /// Returns the gate `x^5 + 3xy + 2y + 7`.
+    fn sample_gate() -> CustomGate<F> {
+        CustomGate::new("sample", 2)
+            .with_term(F::one(), &[(0, 5)])
+            .and_then(|gate| gate.with_term(F::from(3u64), &[(0, 1), (1, 1)]))
+            .and_then(|gate| gate.with_term(F::from(2u64), &[(1, 1)]))
+            .and_then(|gate| gate.with_term(F::from(7u64), &[]))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_custom_gate_definition() {
+        let gate = sample_gate();
+        assert_eq!("sample", gate.name());
+        assert_eq!(2, gate.num_inputs());
+        assert_eq!(5, gate.degree());
+
+        // Ensure repeated inputs and terms are merged.
+        let merged = CustomGate::new("merged", 1)
+            .with_term(F::one(), &[(0, 2), (0, 3)])
+            .and_then(|gate| gate.with_term(F::one(), &[(0, 5)]))
+            .unwrap();
+        assert_eq!(merged, CustomGate::new("merged", 1).with_term(F::from(2u64), &[(0, 5)]).unwrap());
+
+        // Ensure out of bounds inputs are rejected.
+        assert!(CustomGate::<F>::new("invalid", 1).with_term(F::one(), &[(1, 1)]).is_err());
+        assert!(gate.evaluate(&[F::one()]).is_err());
+
+        // Check the evaluation.
+        assert_eq!(F::from(32u64 + 18 + 6 + 7), gate.evaluate(&[F::from(2u64), F::from(3u64)]).unwrap());
+    }
+
+    #[test]
+    fn test_apply_gate() {
+        let gate = sample_gate();
+        let (x, y) = (snarkvm_console_types::Field::from_u64(2), snarkvm_console_types::Field::from_u64(3));
+        let expected = gate.evaluate(&[*x, *y]).unwrap();
+
+        // Ensure a gate on constants is evaluated without constraints.
+        Circuit::scope("Constant", || {
+            let inputs = [Field::<Circuit>::constant(x), Field::constant(y)].map(LinearCombination::from);
+            let output = Circuit::apply_gate(&gate, &inputs);
+            assert!(output.is_constant());
+            assert_eq!(expected, output.value());
+            assert_eq!(0, Circuit::num_constraints_in_scope());
+        });
+        Circuit::reset();
+
+        // Ensure a gate on variables uses one constraint per multiplication.
+        Circuit::scope("Private", || {
+            let inputs =
+                [Field::<Circuit>::new(Mode::Private, x), Field::new(Mode::Private, y)].map(LinearCombination::from);
+            let output = Circuit::apply_gate(&gate, &inputs);
+            assert_eq!(expected, output.value());
+            // The constraints are `x^2`, `x^4`, `x * y`, and `x^4 * x` folded into the output.
+            assert_eq!(4, Circuit::num_constraints_in_scope());
+            // The private variables are the two inputs, and the output of each constraint.
+            assert_eq!(6, Circuit::num_private_in_scope());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_enforce_gate() {
+        let gate = sample_gate();
+        let (x, y) = (snarkvm_console_types::Field::from_u64(2), snarkvm_console_types::Field::from_u64(3));
+        let expected = snarkvm_console_types::Field::new(gate.evaluate(&[*x, *y]).unwrap());
+
+        // Compute the gate using field operations, and enforce the output.
+        let naive_count = Circuit::scope("Naive", || {
+            let (x, y) = (Field::<Circuit>::new(Mode::Private, x), Field::new(Mode::Private, y));
+            let output = Field::<Circuit>::new(Mode::Private, expected);
+            let candidate = x.square().square() * &x
+                + &x * &y * Field::constant(snarkvm_console_types::Field::from_u64(3))
+                + y.double()
+                + Field::constant(snarkvm_console_types::Field::from_u64(7));
+            Circuit::assert_eq(&candidate, &output);
+            assert!(Circuit::is_satisfied_in_scope());
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+
+        // Enforce the output of the gate.
+        Circuit::scope("Gate", || {
+            let inputs =
+                [Field::<Circuit>::new(Mode::Private, x), Field::new(Mode::Private, y)].map(LinearCombination::from);
+            let output = Field::<Circuit>::new(Mode::Private, expected);
+            Circuit::enforce_gate(&gate, &inputs, &output);
+            assert!(Circuit::is_satisfied_in_scope());
+            assert_eq!(naive_count - 1, Circuit::num_constraints_in_scope());
+        });
+        Circuit::reset();
+
+        // Ensure an incorrect output is not satisfied.
+        Circuit::scope("Incorrect", || {
+            let inputs =
+                [Field::<Circuit>::new(Mode::Private, x), Field::new(Mode::Private, y)].map(LinearCombination::from);
+            let output = Field::<Circuit>::new(Mode::Private, expected + snarkvm_console_types::Field::one());
+            Circuit::enforce_gate(&gate, &inputs, &output);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+}
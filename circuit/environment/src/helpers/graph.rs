@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Index, Mode, Scope, R1CS};
+use snarkvm_fields::PrimeField;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A (non-constant) variable in the constraint graph, identified by its mode and index.
+pub type GraphVariable = (Mode, Index);
+
+/// A scope (gadget) in the constraint graph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScopeNode {
+    /// The parent scope of this scope, if one exists.
+    parent: Option<Scope>,
+    /// The number of constraints enforced directly in this scope.
+    num_constraints: u64,
+    /// The variables referenced by the constraints enforced directly in this scope.
+    variables: IndexSet<GraphVariable>,
+}
+
+impl ScopeNode {
+    /// Returns the parent scope of this scope, if one exists.
+    pub const fn parent(&self) -> Option<&Scope> {
+        self.parent.as_ref()
+    }
+
+    /// Returns the number of constraints enforced directly in this scope.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the variables referenced by the constraints enforced directly in this scope.
+    pub const fn variables(&self) -> &IndexSet<GraphVariable> {
+        &self.variables
+    }
+}
+
+/// The structure of a constraint system, given as the gadget hierarchy of its scopes
+/// and the variables shared between them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintGraph {
+    /// The scopes in the constraint system, in the order they are first entered.
+    scopes: IndexMap<Scope, ScopeNode>,
+    /// The variables shared between each pair of scopes.
+    shared: IndexMap<(Scope, Scope), IndexSet<GraphVariable>>,
+}
+
+impl<F: PrimeField> From<&R1CS<F>> for ConstraintGraph {
+    /// Converts an R1CS to a constraint graph.
+    fn from(r1cs: &R1CS<F>) -> Self {
+        let mut graph = Self::default();
+        // Ensure the root scope is always present.
+        graph.insert_scope("");
+
+        // Collect the variables referenced by each scope.
+        for constraint in r1cs.to_constraints() {
+            let scope = &constraint.0;
+            graph.insert_scope(scope);
+
+            let node = graph.scopes.get_mut(scope).expect("The scope was just inserted");
+            node.num_constraints += 1;
+
+            let (a, b, c) = constraint.to_terms();
+            for variable in a.to_terms().keys().chain(b.to_terms().keys()).chain(c.to_terms().keys()) {
+                if !variable.is_constant() {
+                    node.variables.insert((variable.mode(), variable.index()));
+                }
+            }
+        }
+
+        // Determine the scopes that reference each variable.
+        let mut owners = IndexMap::<GraphVariable, Vec<&Scope>>::new();
+        for (scope, node) in &graph.scopes {
+            for variable in &node.variables {
+                owners.entry(*variable).or_default().push(scope);
+            }
+        }
+
+        // Record the variables shared between each pair of scopes.
+        let mut shared = IndexMap::<(Scope, Scope), IndexSet<GraphVariable>>::new();
+        for (variable, scopes) in owners {
+            for (i, first) in scopes.iter().enumerate() {
+                for second in &scopes[i + 1..] {
+                    shared.entry(((*first).clone(), (*second).clone())).or_default().insert(variable);
+                }
+            }
+        }
+        graph.shared = shared;
+
+        graph
+    }
+}
+
+impl ConstraintGraph {
+    /// Returns the scopes in the constraint graph.
+    pub const fn scopes(&self) -> &IndexMap<Scope, ScopeNode> {
+        &self.scopes
+    }
+
+    /// Returns the variables shared between each pair of scopes.
+    pub const fn shared(&self) -> &IndexMap<(Scope, Scope), IndexSet<GraphVariable>> {
+        &self.shared
+    }
+
+    /// Returns the constraint graph in the Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph circuit {\n    node [shape=box];\n");
+        // Write the scopes.
+        for (scope, node) in &self.scopes {
+            output += &format!(
+                "    \"{scope}\" [label=\"{}\\n{} constraints, {} variables\"];\n",
+                Self::label(scope),
+                node.num_constraints,
+                node.variables.len()
+            );
+        }
+        // Write the gadget hierarchy.
+        for (scope, node) in &self.scopes {
+            if let Some(parent) = &node.parent {
+                output += &format!("    \"{parent}\" -> \"{scope}\";\n");
+            }
+        }
+        // Write the shared variables.
+        for ((first, second), variables) in &self.shared {
+            output += &format!(
+                "    \"{first}\" -> \"{second}\" [dir=none, style=dashed, color=red, label=\"{} shared\"];\n",
+                variables.len()
+            );
+        }
+        output += "}\n";
+        output
+    }
+
+    /// Returns the constraint graph in the JSON format.
+    pub fn to_json(&self) -> serde_json::Value {
+        let variable = |(mode, index): &GraphVariable| serde_json::Value::String(format!("{mode}:{index}"));
+
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|(scope, node)| {
+                serde_json::json!({
+                    "name": Self::label(scope),
+                    "parent": node.parent.as_deref().map(Self::label),
+                    "num_constraints": node.num_constraints,
+                    "variables": node.variables.iter().map(variable).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let shared = self
+            .shared
+            .iter()
+            .map(|((first, second), variables)| {
+                serde_json::json!({
+                    "scopes": [Self::label(first), Self::label(second)],
+                    "variables": variables.iter().map(variable).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({ "scopes": scopes, "shared": shared })
+    }
+
+    /// Inserts the given scope and all of its ancestors into the graph, if they do not already exist.
+    fn insert_scope(&mut self, scope: &str) {
+        if self.scopes.contains_key(scope) {
+            return;
+        }
+        // Determine the parent of the scope, where the root scope has no parent.
+        let parent = match scope.is_empty() {
+            true => None,
+            false => Some(scope.rsplit_once('.').map(|(parent, _)| parent.to_string()).unwrap_or_default()),
+        };
+        if let Some(parent) = &parent {
+            self.insert_scope(parent);
+        }
+        self.scopes.insert(scope.to_string(), ScopeNode { parent, ..Default::default() });
+    }
+
+    /// Returns the display label for the given scope.
+    fn label(scope: &str) -> &str {
+        match scope.is_empty() {
+            true => "(root)",
+            false => scope,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    #[test]
+    fn test_constraint_graph() {
+        Circuit::reset();
+
+        let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(2));
+        let b = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(3));
+        let c = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(4));
+        let d = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(6));
+
+        // Enforce two logically independent operations that share the variable `a`.
+        Circuit::scope("outer", || {
+            Circuit::scope("left", || Circuit::enforce(|| (&a, &a, &c)));
+            Circuit::scope("right", || Circuit::enforce(|| (&a, &b, &d)));
+        });
+
+        let r1cs = Circuit::eject_r1cs_and_reset();
+        let graph = ConstraintGraph::from(&r1cs);
+
+        // Check the gadget hierarchy.
+        let scopes = graph.scopes();
+        assert_eq!(
+            vec!["", "outer", "outer.left", "outer.right"],
+            scopes.keys().map(String::as_str).collect::<Vec<_>>()
+        );
+        assert_eq!(None, scopes[""].parent());
+        assert_eq!(Some(&"".to_string()), scopes["outer"].parent());
+        assert_eq!(Some(&"outer".to_string()), scopes["outer.left"].parent());
+        assert_eq!(0, scopes["outer"].num_constraints());
+
+        // Check the shared variables.
+        let shared = graph.shared();
+        assert_eq!(1, shared.len());
+        assert_eq!(1, shared[&("outer.left".to_string(), "outer.right".to_string())].len());
+
+        // Check the exports.
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph circuit {"));
+        assert!(dot.contains("\"outer\" -> \"outer.left\";"));
+        assert!(
+            dot.contains("\"outer.left\" -> \"outer.right\" [dir=none, style=dashed, color=red, label=\"1 shared\"];")
+        );
+
+        let json = graph.to_json();
+        assert_eq!(4, json["scopes"].as_array().unwrap().len());
+        assert_eq!("(root)", json["scopes"][0]["name"]);
+        assert_eq!(serde_json::json!(["outer.left", "outer.right"]), json["shared"][0]["scopes"]);
+    }
+}
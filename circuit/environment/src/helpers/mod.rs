@@ -15,6 +15,9 @@
 mod assignment;
 pub use assignment::*;
 
+mod builder;
+pub use builder::*;
+
 pub mod circuit_type;
 pub use circuit_type::*;
 
@@ -29,12 +32,21 @@ pub use count::*;
 pub(super) mod counter;
 pub(super) use counter::*;
 
+pub mod gate;
+pub use gate::*;
+
+pub mod graph;
+pub use graph::*;
+
 pub mod linear_combination;
 pub use linear_combination::*;
 
 mod mode;
 pub use mode::*;
 
+pub mod unsatisfied;
+pub use unsatisfied::*;
+
 pub mod variable;
 pub use variable::*;
 
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{Constraint, Counter, UnsatisfiedConstraint},
     prelude::*,
 };
 use snarkvm_fields::PrimeField;
@@ -30,6 +30,7 @@ pub struct R1CS<F: PrimeField> {
     constraints: Vec<Constraint<F>>,
     counter: Counter<F>,
     nonzeros: (u64, u64, u64),
+    budgets: Vec<(u64, u64)>,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -42,6 +43,7 @@ impl<F: PrimeField> R1CS<F> {
             constraints: Default::default(),
             counter: Default::default(),
             nonzeros: (0, 0, 0),
+            budgets: Default::default(),
         }
     }
 
@@ -55,6 +57,36 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.pop(name)
     }
 
+    /// Starts a new constraint budget, allowing at most `max_constraints` further constraints.
+    pub(crate) fn push_budget(&mut self, max_constraints: u64) {
+        self.budgets.push((self.num_constraints(), max_constraints));
+    }
+
+    /// Removes the most recent constraint budget.
+    pub(crate) fn pop_budget(&mut self) -> Result<(), String> {
+        match self.budgets.pop() {
+            Some(_) => Ok(()),
+            None => Err("Tried to remove a constraint budget, but none is active".to_string()),
+        }
+    }
+
+    /// Returns an error if any active constraint budget has been exceeded.
+    pub(crate) fn check_budgets(&self) -> Result<(), String> {
+        for (start, max_constraints) in self.budgets.iter().rev() {
+            let num_constraints = self.num_constraints().saturating_sub(*start);
+            if num_constraints > *max_constraints {
+                let scope = match self.scope() {
+                    scope if scope.is_empty() => "the root scope".to_string(),
+                    scope => format!("scope '{scope}'"),
+                };
+                return Err(format!(
+                    "Exceeded the constraint budget of {max_constraints} in {scope} ({num_constraints} constraints)"
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Returns a new constant with the given value and scope.
     pub(crate) fn new_constant(&mut self, value: F) -> Variable<F> {
         let variable = Variable::Constant(Rc::new(value));
@@ -95,6 +127,17 @@ impl<F: PrimeField> R1CS<F> {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
     }
 
+    /// Returns the first constraint in the environment that is not satisfied, if one exists.
+    pub(crate) fn first_unsatisfied_constraint(&self) -> Option<UnsatisfiedConstraint<F>> {
+        self.constraints.iter().enumerate().find_map(|(index, Constraint(scope, a, b, c))| {
+            let (a, b, c) = (a.value(), b.value(), c.value());
+            match a * b == c {
+                true => None,
+                false => Some(UnsatisfiedConstraint::new(index as u64, scope.clone(), a, b, c)),
+            }
+        })
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
         self.counter.is_satisfied_in_scope()
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, Scope};
+use snarkvm_fields::PrimeField;
+
+/// A report describing a constraint `(A * B) == C` that is not satisfied by its assignment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint<F: PrimeField> {
+    /// The index of the constraint in the constraint system.
+    index: u64,
+    /// The scope path in which the constraint was enforced.
+    scope: Scope,
+    /// The assigned value of `A`.
+    a: F,
+    /// The assigned value of `B`.
+    b: F,
+    /// The assigned value of `C`.
+    c: F,
+}
+
+impl<F: PrimeField> UnsatisfiedConstraint<F> {
+    /// Initializes a new unsatisfied constraint report.
+    pub(crate) const fn new(index: u64, scope: Scope, a: F, b: F, c: F) -> Self {
+        Self { index, scope, a, b, c }
+    }
+
+    /// Returns the index of the constraint in the constraint system.
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the scope path in which the constraint was enforced.
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    /// Returns the assigned values of `(A, B, C)`.
+    pub const fn values(&self) -> (F, F, F) {
+        (self.a, self.b, self.c)
+    }
+
+    /// Returns the assigned value of the left-hand side, `A * B`.
+    pub fn lhs(&self) -> F {
+        self.a * self.b
+    }
+
+    /// Returns the assigned value of the right-hand side, `C`.
+    pub const fn rhs(&self) -> F {
+        self.c
+    }
+}
+
+impl<F: PrimeField> Display for UnsatisfiedConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scope = match self.scope.is_empty() {
+            true => "(root)",
+            false => self.scope.as_str(),
+        };
+        write!(
+            f,
+            "Constraint {} at {scope} is unsatisfied: ({} * {}) = {} != {}",
+            self.index,
+            self.a,
+            self.b,
+            self.lhs(),
+            self.c
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    type F = <Circuit as Environment>::BaseField;
+
+    #[test]
+    fn test_first_unsatisfied_constraint() {
+        Circuit::reset();
+
+        let two = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(2));
+        let three = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(3));
+        let six = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(6));
+        let seven = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(7));
+
+        // Enforce a satisfied constraint.
+        Circuit::scope("satisfied", || Circuit::enforce(|| (&two, &three, &six)));
+        assert!(Circuit::is_satisfied());
+        assert!(Circuit::first_unsatisfied_constraint().is_none());
+
+        // Enforce an unsatisfied constraint.
+        Circuit::scope("outer", || Circuit::scope("inner", || Circuit::enforce(|| (&two, &three, &seven))));
+        assert!(!Circuit::is_satisfied());
+
+        let report = Circuit::first_unsatisfied_constraint().unwrap();
+        assert_eq!(1, report.index());
+        assert_eq!("outer.inner", report.scope());
+        assert_eq!((F::from(2u64), F::from(3u64), F::from(7u64)), report.values());
+        assert_eq!(F::from(6u64), report.lhs());
+        assert_eq!(F::from(7u64), report.rhs());
+        assert_eq!("Constraint 1 at outer.inner is unsatisfied: (2 * 3) = 6 != 7", report.to_string());
+
+        Circuit::reset();
+    }
+}
@@ -76,6 +76,7 @@ pub mod prelude {
             ToBits as _,
         },
         Parser,
+        ParserDiagnostic,
         ParserResult,
         TypeName,
     };
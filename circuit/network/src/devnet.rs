@@ -0,0 +1,564 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Aleo;
+use snarkvm_circuit_algorithms::{
+    Commit,
+    CommitUncompressed,
+    Hash,
+    HashMany,
+    HashToGroup,
+    HashToScalar,
+    HashUncompressed,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+use snarkvm_circuit_collections::merkle_tree::MerklePath;
+use snarkvm_circuit_types::{
+    environment::{prelude::*, Assignment, Circuit, UnsatisfiedConstraint, R1CS},
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+};
+
+use console::{Devnet, DevnetConfig};
+use snarkvm_console_algorithms as hashers;
+
+use core::{
+    any::{Any, TypeId},
+    fmt,
+    marker::PhantomData,
+};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+type E = Circuit;
+
+thread_local! {
+    /// The constants of every devnet config, which are initialized on first use.
+    ///
+    /// Thread-locals cannot be generic over the config, so the constants of every config are kept in one map.
+    static CONSTANTS: RefCell<HashMap<TypeId, Rc<dyn Any>>> = Default::default();
+}
+
+/// The constants of the Aleo circuit for a development network.
+struct Constants<Config: DevnetConfig> {
+    /// The group bases for the Aleo signature and encryption schemes.
+    generator_g: Vec<Group<AleoDevnet<Config>>>,
+
+    /// The encryption domain as a constant field element.
+    encryption_domain: Field<AleoDevnet<Config>>,
+    /// The graph key domain as a constant field element.
+    graph_key_domain: Field<AleoDevnet<Config>>,
+    /// The serial number domain as a constant field element.
+    serial_number_domain: Field<AleoDevnet<Config>>,
+
+    /// The BHP hash function, which can take an input of up to 256 bits.
+    bhp_256: BHP256<AleoDevnet<Config>>,
+    /// The BHP hash function, which can take an input of up to 512 bits.
+    bhp_512: BHP512<AleoDevnet<Config>>,
+    /// The BHP hash function, which can take an input of up to 768 bits.
+    bhp_768: BHP768<AleoDevnet<Config>>,
+    /// The BHP hash function, which can take an input of up to 1024 bits.
+    bhp_1024: BHP1024<AleoDevnet<Config>>,
+
+    /// The Pedersen hash function, which can take an input of up to 64 bits.
+    pedersen_64: Pedersen64<AleoDevnet<Config>>,
+    /// The Pedersen hash function, which can take an input of up to 128 bits.
+    pedersen_128: Pedersen128<AleoDevnet<Config>>,
+
+    /// The Poseidon hash function, using a rate of 2.
+    poseidon_2: Poseidon2<AleoDevnet<Config>>,
+    /// The Poseidon hash function, using a rate of 4.
+    poseidon_4: Poseidon4<AleoDevnet<Config>>,
+    /// The Poseidon hash function, using a rate of 8.
+    poseidon_8: Poseidon8<AleoDevnet<Config>>,
+}
+
+/// The Aleo circuit of a development network, which shares the circuit environment of `AleoV0`,
+/// so that a `Devnet` can synthesize, prove, and verify its own executions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AleoDevnet<Config: DevnetConfig>(PhantomData<Config>);
+
+impl<Config: DevnetConfig> AleoDevnet<Config> {
+    /// Returns the constants of this config, initializing them on first use.
+    fn constants() -> Rc<Constants<Config>> {
+        // Retrieve the constants, if they are initialized.
+        let constants = CONSTANTS.with(|constants| constants.borrow().get(&TypeId::of::<Config>()).cloned());
+        let constants = match constants {
+            Some(constants) => constants,
+            None => {
+                // Initialize the constants outside of the borrow, as injecting them uses the circuit environment.
+                // The hashers are set up with the same messages as the hashers of the console `Devnet`.
+                let constants: Rc<dyn Any> = Rc::new(Constants::<Config> {
+                    generator_g: Vec::constant(<Devnet<Config> as console::Network>::g_powers().to_vec()),
+                    encryption_domain: Field::constant(<Devnet<Config> as console::Network>::encryption_domain()),
+                    graph_key_domain: Field::constant(<Devnet<Config> as console::Network>::graph_key_domain()),
+                    serial_number_domain: Field::constant(<Devnet<Config> as console::Network>::serial_number_domain()),
+                    bhp_256: BHP256::constant(hashers::BHP256::setup("AleoBHP256").expect("Failed to setup BHP256")),
+                    bhp_512: BHP512::constant(hashers::BHP512::setup("AleoBHP512").expect("Failed to setup BHP512")),
+                    bhp_768: BHP768::constant(hashers::BHP768::setup("AleoBHP768").expect("Failed to setup BHP768")),
+                    bhp_1024: BHP1024::constant(
+                        hashers::BHP1024::setup("AleoBHP1024").expect("Failed to setup BHP1024"),
+                    ),
+                    pedersen_64: Pedersen64::constant(hashers::Pedersen64::setup("AleoPedersen64")),
+                    pedersen_128: Pedersen128::constant(hashers::Pedersen128::setup("AleoPedersen128")),
+                    poseidon_2: Poseidon2::constant(
+                        hashers::Poseidon2::setup("AleoPoseidon2").expect("Failed to setup Poseidon2"),
+                    ),
+                    poseidon_4: Poseidon4::constant(
+                        hashers::Poseidon4::setup("AleoPoseidon4").expect("Failed to setup Poseidon4"),
+                    ),
+                    poseidon_8: Poseidon8::constant(
+                        hashers::Poseidon8::setup("AleoPoseidon8").expect("Failed to setup Poseidon8"),
+                    ),
+                });
+                CONSTANTS.with(|constants_map| {
+                    constants_map.borrow_mut().entry(TypeId::of::<Config>()).or_insert(constants).clone()
+                })
+            }
+        };
+        constants
+            .downcast::<Constants<Config>>()
+            .unwrap_or_else(|_| E::halt("Mismatching type of the devnet constants"))
+    }
+}
+
+impl<Config: DevnetConfig> Aleo for AleoDevnet<Config> {
+    /// Returns the encryption domain as a constant field element.
+    fn encryption_domain() -> Field<Self> {
+        Self::constants().encryption_domain.clone()
+    }
+
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self> {
+        Self::constants().graph_key_domain.clone()
+    }
+
+    /// Returns the serial number domain as a constant field element.
+    fn serial_number_domain() -> Field<Self> {
+        Self::constants().serial_number_domain.clone()
+    }
+
+    /// Returns the scalar multiplication on the generator `G`.
+    #[inline]
+    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+        Self::constants()
+            .generator_g
+            .iter()
+            .zip_eq(&scalar.to_bits_le())
+            .fold(Group::zero(), |output, (base, bit)| Group::ternary(bit, &(&output + base), &output))
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits.
+    fn commit_bhp256(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+        Self::constants().bhp_256.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits.
+    fn commit_bhp512(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+        Self::constants().bhp_512.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits.
+    fn commit_bhp768(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+        Self::constants().bhp_768.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits.
+    fn commit_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+        Self::constants().bhp_1024.commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_ped64(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+        Self::constants().pedersen_64.commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_ped128(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+        Self::constants().pedersen_128.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits.
+    fn commit_to_group_bhp256(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        Self::constants().bhp_256.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits.
+    fn commit_to_group_bhp512(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        Self::constants().bhp_512.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits.
+    fn commit_to_group_bhp768(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        Self::constants().bhp_768.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits.
+    fn commit_to_group_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        Self::constants().bhp_1024.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_to_group_ped64(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        Self::constants().pedersen_64.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_to_group_ped128(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Group<Self> {
+        Self::constants().pedersen_128.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_bhp256(input: &[Boolean<Self>]) -> Field<Self> {
+        Self::constants().bhp_256.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_bhp512(input: &[Boolean<Self>]) -> Field<Self> {
+        Self::constants().bhp_512.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_bhp768(input: &[Boolean<Self>]) -> Field<Self> {
+        Self::constants().bhp_768.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self> {
+        Self::constants().bhp_1024.hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_ped64(input: &[Boolean<Self>]) -> Field<Self> {
+        Self::constants().pedersen_64.hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_ped128(input: &[Boolean<Self>]) -> Field<Self> {
+        Self::constants().pedersen_128.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2.
+    fn hash_psd2(input: &[Field<Self>]) -> Field<Self> {
+        Self::constants().poseidon_2.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4.
+    fn hash_psd4(input: &[Field<Self>]) -> Field<Self> {
+        Self::constants().poseidon_4.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8.
+    fn hash_psd8(input: &[Field<Self>]) -> Field<Self> {
+        Self::constants().poseidon_8.hash(input)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 2.
+    fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        Self::constants().poseidon_2.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 4.
+    fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        Self::constants().poseidon_4.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 8.
+    fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        Self::constants().poseidon_8.hash_many(input, num_outputs)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_to_group_bhp256(input: &[Boolean<Self>]) -> Group<Self> {
+        Self::constants().bhp_256.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_to_group_bhp512(input: &[Boolean<Self>]) -> Group<Self> {
+        Self::constants().bhp_512.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_to_group_bhp768(input: &[Boolean<Self>]) -> Group<Self> {
+        Self::constants().bhp_768.hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_to_group_bhp1024(input: &[Boolean<Self>]) -> Group<Self> {
+        Self::constants().bhp_1024.hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_to_group_ped64(input: &[Boolean<Self>]) -> Group<Self> {
+        Self::constants().pedersen_64.hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_to_group_ped128(input: &[Boolean<Self>]) -> Group<Self> {
+        Self::constants().pedersen_128.hash_uncompressed(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_psd2(input: &[Field<Self>]) -> Group<Self> {
+        Self::constants().poseidon_2.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_psd4(input: &[Field<Self>]) -> Group<Self> {
+        Self::constants().poseidon_4.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_psd8(input: &[Field<Self>]) -> Group<Self> {
+        Self::constants().poseidon_8.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+    fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Scalar<Self> {
+        Self::constants().poseidon_2.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+    fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Scalar<Self> {
+        Self::constants().poseidon_4.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+    fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Scalar<Self> {
+        Self::constants().poseidon_8.hash_to_scalar(input)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_bhp<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Boolean<Self>>,
+    ) -> Boolean<Self> {
+        let constants = Self::constants();
+        path.verify(&constants.bhp_1024, &constants.bhp_512, root, leaf)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_psd<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> Boolean<Self> {
+        let constants = Self::constants();
+        path.verify(&constants.poseidon_4, &constants.poseidon_2, root, leaf)
+    }
+}
+
+impl<Config: DevnetConfig> Environment for AleoDevnet<Config> {
+    type Affine = <E as Environment>::Affine;
+    type BaseField = <E as Environment>::BaseField;
+    type Network = Devnet<Config>;
+    type ScalarField = <E as Environment>::ScalarField;
+
+    /// Returns the `zero` constant.
+    fn zero() -> LinearCombination<Self::BaseField> {
+        E::zero()
+    }
+
+    /// Returns the `one` constant.
+    fn one() -> LinearCombination<Self::BaseField> {
+        E::one()
+    }
+
+    /// Returns a new variable of the given mode and value.
+    fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
+        E::new_variable(mode, value)
+    }
+
+    /// Returns a new witness of the given mode and value.
+    fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
+        E::new_witness(mode, logic)
+    }
+
+    /// Enters a new scope for the environment.
+    fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::scope(name, logic)
+    }
+
+    /// Runs the given logic on the given circuit, in place of the current circuit.
+    /// The current circuit is suspended, and restored once the logic returns or halts.
+    fn with_r1cs<Fn, Output>(r1cs: &mut R1CS<Self::BaseField>, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::with_r1cs(r1cs, logic)
+    }
+
+    /// Runs the given logic, halting synthesis if it adds more than `max_constraints` constraints.
+    fn with_budget<Fn, Output>(max_constraints: u64, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::with_budget(max_constraints, logic)
+    }
+
+    /// Adds one constraint enforcing that `(A * B) == C`.
+    fn enforce<Fn, A, B, C>(constraint: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        E::enforce(constraint)
+    }
+
+    /// Returns `true` if all constraints in the environment are satisfied.
+    fn is_satisfied() -> bool {
+        E::is_satisfied()
+    }
+
+    /// Returns `true` if all constraints in the current scope are satisfied.
+    fn is_satisfied_in_scope() -> bool {
+        E::is_satisfied_in_scope()
+    }
+
+    /// Returns the first constraint in the environment that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        E::first_unsatisfied_constraint()
+    }
+
+    /// Returns the number of constants in the entire circuit.
+    fn num_constants() -> u64 {
+        E::num_constants()
+    }
+
+    /// Returns the number of public variables in the entire circuit.
+    fn num_public() -> u64 {
+        E::num_public()
+    }
+
+    /// Returns the number of private variables in the entire circuit.
+    fn num_private() -> u64 {
+        E::num_private()
+    }
+
+    /// Returns the number of constraints in the entire circuit.
+    fn num_constraints() -> u64 {
+        E::num_constraints()
+    }
+
+    /// Returns the number of nonzeros in the entire circuit.
+    fn num_nonzeros() -> (u64, u64, u64) {
+        E::num_nonzeros()
+    }
+
+    /// Returns the number of constants for the current scope.
+    fn num_constants_in_scope() -> u64 {
+        E::num_constants_in_scope()
+    }
+
+    /// Returns the number of public variables for the current scope.
+    fn num_public_in_scope() -> u64 {
+        E::num_public_in_scope()
+    }
+
+    /// Returns the number of private variables for the current scope.
+    fn num_private_in_scope() -> u64 {
+        E::num_private_in_scope()
+    }
+
+    /// Returns the number of constraints for the current scope.
+    fn num_constraints_in_scope() -> u64 {
+        E::num_constraints_in_scope()
+    }
+
+    /// Returns the number of nonzeros for the current scope.
+    fn num_nonzeros_in_scope() -> (u64, u64, u64) {
+        E::num_nonzeros_in_scope()
+    }
+
+    /// Halts the program from further synthesis, evaluation, and execution in the current environment.
+    fn halt<S: Into<String>, T>(message: S) -> T {
+        E::halt(message)
+    }
+
+    /// Returns the R1CS circuit, resetting the circuit.
+    fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
+        E::inject_r1cs(r1cs)
+    }
+
+    /// Returns the R1CS circuit, resetting the circuit.
+    fn eject_r1cs_and_reset() -> R1CS<Self::BaseField> {
+        E::eject_r1cs_and_reset()
+    }
+
+    /// Returns the R1CS assignment of the circuit, resetting the circuit.
+    fn eject_assignment_and_reset() -> Assignment<<Self::Network as console::Environment>::Field> {
+        E::eject_assignment_and_reset()
+    }
+
+    /// Clears the circuit and initializes an empty environment.
+    fn reset() {
+        E::reset()
+    }
+}
+
+impl<Config: DevnetConfig> Display for AleoDevnet<Config> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // TODO (howardwu): Find a better way to print the circuit.
+        fmt::Display::fmt(&Circuit, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AleoV0;
+    use snarkvm_console_types::Field as ConsoleField;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestConfig;
+
+    impl DevnetConfig for TestConfig {}
+
+    type CurrentAleo = AleoDevnet<TestConfig>;
+
+    #[test]
+    fn test_hashes_match_aleo_v0() {
+        // Note: The devnet uses the parameters of `Testnet3`, so its hashes match those of `AleoV0`.
+        let one = ConsoleField::<Devnet<TestConfig>>::one();
+        let input = vec![Field::<CurrentAleo>::new(Mode::Private, one); 3];
+        let expected = vec![Field::<AleoV0>::new(Mode::Private, ConsoleField::one()); 3];
+
+        assert_eq!(*CurrentAleo::hash_psd2(&input).eject_value(), *AleoV0::hash_psd2(&expected).eject_value());
+        assert_eq!(
+            *CurrentAleo::hash_bhp256(&input[0].to_bits_le()).eject_value(),
+            *AleoV0::hash_bhp256(&expected[0].to_bits_le()).eject_value()
+        );
+        assert_eq!(
+            CurrentAleo::serial_number_domain().eject_value().to_string(),
+            AleoV0::serial_number_domain().eject_value().to_string()
+        );
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+    }
+}
@@ -33,7 +33,7 @@ use snarkvm_circuit_algorithms::{
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
-    environment::{prelude::*, Assignment, Circuit, R1CS},
+    environment::{prelude::*, Assignment, Circuit, UnsatisfiedConstraint, R1CS},
     Boolean,
     Field,
     Group,
@@ -340,6 +340,23 @@ impl Environment for AleoV0 {
         E::scope(name, logic)
     }
 
+    /// Runs the given logic on the given circuit, in place of the current circuit.
+    /// The current circuit is suspended, and restored once the logic returns or halts.
+    fn with_r1cs<Fn, Output>(r1cs: &mut R1CS<Self::BaseField>, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::with_r1cs(r1cs, logic)
+    }
+
+    /// Runs the given logic, halting synthesis if it adds more than `max_constraints` constraints.
+    fn with_budget<Fn, Output>(max_constraints: u64, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::with_budget(max_constraints, logic)
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -361,6 +378,11 @@ impl Environment for AleoV0 {
         E::is_satisfied_in_scope()
     }
 
+    /// Returns the first constraint in the environment that is not satisfied, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        E::first_unsatisfied_constraint()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         E::num_constants()
@@ -122,6 +122,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_encrypt_dual_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Generate the owner and auditor accounts.
+            let private_key = snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+            let address = snarkvm_console_account::Address::try_from(private_key)?;
+            let auditor_private_key =
+                snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+            let auditor_view_key = snarkvm_console_account::ViewKey::try_from(auditor_private_key)?;
+            let auditor = snarkvm_console_account::Address::try_from(auditor_private_key)?;
+
+            // Prepare the record, with a private owner.
+            let randomizer = Scalar::new(Mode::Private, Uniform::rand(&mut rng));
+            let record = Record {
+                owner: Owner::Private(Plaintext::from(Literal::Address(Address::<Circuit>::new(
+                    Mode::Private,
+                    address,
+                )))),
+                data: IndexMap::from_iter([(
+                    Identifier::from_str("a")?,
+                    Entry::Private(Plaintext::from(Literal::Field(Field::new(Mode::Private, Uniform::rand(&mut rng))))),
+                )]),
+                nonce: <Circuit as Aleo>::g_scalar_multiply(&randomizer),
+            };
+
+            // Encrypt the record for the owner and the auditor, where the auditor is fixed by the program or an input.
+            let mode = if i % 2 == 0 { Mode::Constant } else { Mode::Public };
+            let (owner_ciphertext, auditor_ciphertext) =
+                record.encrypt_dual(&Address::<Circuit>::new(mode, auditor), &randomizer);
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the owner ciphertext matches the single-recipient encryption.
+            assert_eq!(record.encrypt(&randomizer).eject(), owner_ciphertext.eject());
+            // Ensure the auditor ciphertext decrypts to the record.
+            assert_eq!(record.eject_value(), auditor_ciphertext.eject_value().decrypt(&auditor_view_key)?);
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_encrypt_and_decrypt() -> Result<()> {
         let mut rng = TestRng::default();
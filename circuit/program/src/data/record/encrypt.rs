@@ -25,6 +25,23 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
         self.encrypt_symmetric(record_view_key)
     }
 
+    /// Encrypts `self` for the record owner and for the given auditor under the given randomizer,
+    /// returning the owner ciphertext and the auditor ciphertext.
+    ///
+    /// Both ciphertexts are derived from `self` in the same circuit, which enforces that they encode the same record.
+    pub fn encrypt_dual(
+        &self,
+        auditor: &Address<A>,
+        randomizer: &Scalar<A>,
+    ) -> (Record<A, Ciphertext<A>>, Record<A, Ciphertext<A>>) {
+        // Encrypt the record for the owner, which ensures the randomizer corresponds to the record nonce.
+        let owner_ciphertext = self.encrypt(randomizer);
+        // Compute the auditor record view key.
+        let auditor_record_view_key = (auditor.to_group() * randomizer).to_x_coordinate();
+        // Encrypt the record for the auditor.
+        (owner_ciphertext, self.encrypt_symmetric(auditor_record_view_key))
+    }
+
     /// Encrypts `self` under the given record view key.
     pub fn encrypt_symmetric(&self, record_view_key: Field<A>) -> Record<A, Ciphertext<A>> {
         // Determine the number of randomizers needed to encrypt the record.
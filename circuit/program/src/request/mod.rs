@@ -138,6 +138,8 @@ pub struct Request<A: Aleo> {
     tsk: Scalar<A>,
     /// The transition commitment.
     tcm: Field<A>,
+    /// The expiration height as a field element, if the request expires (see `console::Request::expiration_field`).
+    expiration: Option<Field<A>>,
 }
 
 #[cfg(console)]
@@ -148,6 +150,9 @@ impl<A: Aleo> Inject for Request<A> {
     fn new(mode: Mode, request: Self::Primitive) -> Self {
         // Inject the transition commitment `tcm` as `Mode::Public`.
         let tcm = Field::new(Mode::Public, *request.tcm());
+        // Inject the expiration height as `Mode::Public`, if the request expires.
+        let expiration = console::Request::expiration_field(request.valid_until())
+            .map(|expiration| Field::new(Mode::Public, expiration));
 
         // Inject the inputs.
         let inputs = match request
@@ -221,6 +226,7 @@ impl<A: Aleo> Inject for Request<A> {
             tvk: Field::new(mode, *request.tvk()),
             tsk: Scalar::new(mode, *request.tsk()),
             tcm,
+            expiration,
         }
     }
 }
@@ -280,6 +286,11 @@ impl<A: Aleo> Request<A> {
     pub const fn tcm(&self) -> &Field<A> {
         &self.tcm
     }
+
+    /// Returns the expiration height as a field element, if the request expires.
+    pub const fn expiration(&self) -> Option<&Field<A>> {
+        self.expiration.as_ref()
+    }
 }
 
 #[cfg(console)]
@@ -288,7 +299,7 @@ impl<A: Aleo> Eject for Request<A> {
 
     /// Ejects the mode of the request.
     fn eject_mode(&self) -> Mode {
-        Mode::combine(self.caller.eject_mode(), [
+        let mode = Mode::combine(self.caller.eject_mode(), [
             self.network_id.eject_mode(),
             self.program_id.eject_mode(),
             self.function_name.eject_mode(),
@@ -299,7 +310,11 @@ impl<A: Aleo> Eject for Request<A> {
             self.tvk.eject_mode(),
             self.tsk.eject_mode(),
             self.tcm.eject_mode(),
-        ])
+        ]);
+        match &self.expiration {
+            Some(expiration) => Mode::combine(mode, [expiration.eject_mode()]),
+            None => mode,
+        }
     }
 
     /// Ejects the request as a primitive.
@@ -317,5 +332,14 @@ impl<A: Aleo> Eject for Request<A> {
             self.tsk.eject_value(),
             self.tcm.eject_value(),
         ))
+        .with_valid_until(self.expiration.as_ref().map(|expiration| {
+            // Recover the expiration height from the field element.
+            let valid_until: Result<console::U32<A::Network>> =
+                console::FromField::from_field(&expiration.eject_value());
+            match valid_until {
+                Ok(valid_until) => *valid_until,
+                Err(error) => A::halt(format!("Invalid expiration height in the request - {error}")),
+            }
+        }))
     }
 }
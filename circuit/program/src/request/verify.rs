@@ -20,6 +20,8 @@ impl<A: Aleo> Request<A> {
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, caller, \[tvk, tcm, function ID, input IDs\])
+    ///
+    /// If the request expires, the expiration is signed after the function ID.
     pub fn verify(&self, input_types: &[console::ValueType<A::Network>], tpk: &Group<A>) -> Boolean<A> {
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
         let function_id = A::hash_bhp1024(
@@ -31,6 +33,8 @@ impl<A: Aleo> Request<A> {
         message.push(self.tvk.clone());
         message.push(self.tcm.clone());
         message.push(function_id);
+        // Extend the signature message with the expiration, if the request expires.
+        message.extend(self.expiration.clone());
 
         // Check the input IDs and construct the rest of the signature message.
         let (input_checks, append_to_message) = Self::check_input_ids::<true>(
@@ -350,14 +354,27 @@ mod tests {
                 console::ValueType::from_str("token.aleo/token.record").unwrap(),
             ];
 
+            // Sample an expiration height, for every other request.
+            let valid_until = (i % 2 == 1).then_some(u32::try_from(i)?);
+
             // Compute the signed request.
-            let request =
-                console::Request::sign(&private_key, program_id, function_name, inputs.iter(), &input_types, rng)?;
+            let request = console::Request::sign_with_expiration(
+                &private_key,
+                program_id,
+                function_name,
+                inputs.iter(),
+                &input_types,
+                valid_until,
+                rng,
+            )?;
             assert!(request.verify(&input_types));
 
             // Inject the request into a circuit.
             let tpk = Group::<Circuit>::new(mode, request.to_tpk());
+            let expected = request.clone();
             let request = Request::<Circuit>::new(mode, request);
+            // Ensure the expiration height is carried by the circuit request.
+            assert_eq!(expected, request.eject_value());
 
             Circuit::scope(format!("Request {i}"), || {
                 let candidate = request.verify(&input_types, &tpk);
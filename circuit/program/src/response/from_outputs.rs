@@ -16,6 +16,9 @@ use super::*;
 
 impl<A: Aleo> Response<A> {
     /// Initializes a response, given the number of inputs, caller, tvk, tcm, outputs, output types, and output registers.
+    ///
+    /// If the program declares an auditor, each record output is additionally encrypted to the auditor,
+    /// and the record checksum commits to both ciphertexts.
     pub fn from_outputs(
         network_id: &U16<A>,
         program_id: &ProgramID<A>,
@@ -26,6 +29,7 @@ impl<A: Aleo> Response<A> {
         outputs: Vec<Value<A>>,
         output_types: &[console::ValueType<A::Network>], // Note: Console type
         output_registers: &[Option<console::Register<A::Network>>], // Note: Console type
+        auditor: Option<&console::Address<A::Network>>,  // Note: Console type
     ) -> Self {
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
         let function_id =
@@ -113,10 +117,22 @@ impl<A: Aleo> Response<A> {
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
                         let randomizer = A::hash_to_scalar_psd2(&[tvk.clone(), output_index]);
 
-                        // Encrypt the record, using the randomizer.
-                        let encrypted_record = record.encrypt(&randomizer);
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = A::hash_bhp1024(&encrypted_record.to_bits_le());
+                        let checksum = match auditor {
+                            Some(auditor) => {
+                                // Encrypt the record for the owner and for the auditor, using the randomizer.
+                                // Both ciphertexts are derived from the same record, which enforces that they encode the same record.
+                                let (owner_record, auditor_record) =
+                                    record.encrypt_dual(&Address::constant(*auditor), &randomizer);
+                                // Compute the record checksum, as the hash of the owner and auditor encrypted records.
+                                A::hash_bhp1024(&[owner_record.to_bits_le(), auditor_record.to_bits_le()].concat())
+                            }
+                            None => {
+                                // Encrypt the record, using the randomizer.
+                                let encrypted_record = record.encrypt(&randomizer);
+                                // Compute the record checksum, as the hash of the encrypted record.
+                                A::hash_bhp1024(&encrypted_record.to_bits_le())
+                            }
+                        };
 
                         // Return the output ID.
                         OutputID::record(commitment, checksum)
@@ -160,6 +176,7 @@ mod tests {
 
     fn check_from_outputs(
         mode: Mode,
+        auditor: Option<console::Address<<Circuit as Environment>::Network>>,
         num_constants: u64,
         num_public: u64,
         num_private: u64,
@@ -230,6 +247,7 @@ mod tests {
                 outputs.clone(),
                 &output_types,
                 &output_registers,
+                auditor.as_ref(),
             )?;
 
             // Inject the network ID, program ID, function name, `tvk`, `tcm`, and outputs.
@@ -252,6 +270,7 @@ mod tests {
                     outputs,
                     &output_types,
                     &output_registers,
+                    auditor.as_ref(),
                 );
                 assert_eq!(response, candidate.eject_value());
                 match mode.is_constant() {
@@ -269,16 +288,22 @@ mod tests {
 
     #[test]
     fn test_from_outputs_constant() -> Result<()> {
-        check_from_outputs(Mode::Constant, 26000, 6, 9500, 9500)
+        check_from_outputs(Mode::Constant, None, 26000, 6, 9500, 9500)
     }
 
     #[test]
     fn test_from_outputs_public() -> Result<()> {
-        check_from_outputs(Mode::Public, 24793, 6, 13962, 13983)
+        check_from_outputs(Mode::Public, None, 24793, 6, 13962, 13983)
     }
 
     #[test]
     fn test_from_outputs_private() -> Result<()> {
-        check_from_outputs(Mode::Private, 24793, 6, 13962, 13983)
+        check_from_outputs(Mode::Private, None, 24793, 6, 13962, 13983)
+    }
+
+    #[test]
+    fn test_from_outputs_with_auditor() -> Result<()> {
+        let auditor = console::Address::from_str("aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px")?;
+        check_from_outputs(Mode::Private, Some(auditor), 25640, 6, 20289, 20316)
     }
 }
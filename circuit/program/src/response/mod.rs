@@ -20,7 +20,7 @@ mod process_outputs_from_callback;
 
 use crate::{Identifier, ProgramID, Value};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Field, U16};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Field, U16};
 
 pub enum OutputID<A: Aleo> {
     /// The hash of the constant output.
@@ -239,6 +239,7 @@ mod tests {
                 outputs.clone(),
                 &output_types,
                 &output_registers,
+                None,
             )?;
             // assert!(response.verify());
 
@@ -279,6 +280,7 @@ mod tests {
                 outputs,
                 &output_types,
                 &output_registers,
+                None,
             );
             assert_eq!(response, candidate_b.eject_value());
 
@@ -16,6 +16,7 @@ mod helpers;
 pub use helpers::*;
 
 mod verify;
+mod verify_batch;
 
 #[cfg(test)]
 use snarkvm_circuit_types::environment::assert_scope;
@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> StatePath<A> {
+    /// Returns `true` if all of the given state paths are valid.
+    ///
+    /// Every global state path must be for the same block as the first state path, so that
+    /// the header path, block hash, and block path are only verified once, from the first state path.
+    /// The transactions path of each global state path is then verified against the shared header leaf.
+    ///
+    /// # Parameters
+    ///  - `state_paths` are the state paths, each with a boolean indicating whether it is for a global or local state root.
+    ///  - `local_state_root` is the local transaction root for the current execution.
+    pub fn verify_batch(state_paths: &[(Self, Boolean<A>)], local_state_root: &Field<A>) -> Boolean<A> {
+        // Retrieve the first state path, which provides the shared block.
+        let Some((first, _)) = state_paths.first() else {
+            return Boolean::constant(true);
+        };
+
+        // Ensure the header path is valid.
+        let check_header_path =
+            A::verify_merkle_path_bhp(&first.header_path, &first.header_root, &first.header_leaf.to_bits_le())
+                & first.header_leaf.index().is_equal(&U8::one()); // Index = 1 (Header::transactions_root)
+
+        // Construct the block hash preimage.
+        let block_hash_preimage = first
+            .previous_block_hash
+            .to_bits_le()
+            .into_iter()
+            .chain(first.header_root.to_bits_le())
+            .collect::<Vec<_>>();
+
+        // Ensure the block path is valid.
+        let check_block_hash = A::hash_bhp1024(&block_hash_preimage).is_equal(&first.block_hash);
+
+        // Ensure the global state root is correct.
+        let check_state_root =
+            A::verify_merkle_path_bhp(&first.block_path, &first.global_state_root, &first.block_hash.to_bits_le());
+
+        // Combine the shared block checks.
+        let check_block = check_header_path & check_block_hash & check_state_root;
+
+        state_paths.iter().fold(Boolean::constant(true), |is_valid, (state_path, is_global)| {
+            // Ensure the transition path is valid.
+            let check_transition_path =
+                A::verify_merkle_path_bhp(
+                    &state_path.transition_path,
+                    state_path.transaction_leaf.id(),
+                    &state_path.transition_leaf.to_bits_le(),
+                ) & state_path.transition_leaf.variant().is_equal(&U8::constant(console::U8::new(3))); // Variant = 3 (Input::Record)
+
+            // Ensure the transaction path is valid.
+            let check_transaction_path = A::verify_merkle_path_bhp(
+                &state_path.transaction_path,
+                &state_path.transaction_id,
+                &state_path.transaction_leaf.to_bits_le(),
+            ) & state_path.transaction_leaf.variant().is_equal(&U8::one()); // Variant = 1 (Transaction::Execution)
+
+            // Ensure the transactions path is valid, with respect to the shared header leaf.
+            let check_transactions_path = A::verify_merkle_path_bhp(
+                &state_path.transactions_path,
+                first.header_leaf.id(),
+                &state_path.transaction_id.to_bits_le(),
+            );
+
+            // Ensure the global state root matches the shared global state root.
+            let check_global_state_root = state_path.global_state_root.is_equal(&first.global_state_root);
+
+            // Combine the transition and transaction path checks.
+            let check_transition_and_transaction_path = check_transition_path & check_transaction_path;
+
+            // Check the state path.
+            let check_local =
+                &check_transition_and_transaction_path & local_state_root.is_equal(&state_path.transaction_id);
+            let check_global = check_transition_and_transaction_path
+                & check_transactions_path
+                & check_global_state_root
+                & &check_block;
+
+            // If the state path is for a global root, use 'check_global'. Else, use 'check_local'.
+            is_valid & Boolean::ternary(is_global, &check_global, &check_local)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: usize = 5;
+
+    fn check_verify_batch(mode: Mode, num_paths: usize) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the console state paths.
+            let console_state_paths =
+                console::state_path::test_helpers::sample_global_state_paths::<CurrentNetwork>(num_paths, rng)?;
+            // Sample the console local state path.
+            let console_local_state_path =
+                console::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, rng)?;
+            // Retrieve the local state root.
+            let local_state_root = **console_local_state_path.transaction_id();
+
+            Circuit::scope(format!("Verify batch of {num_paths} state paths {mode}"), || {
+                // Inject the local state root.
+                let circuit_local_state_root = Field::new(mode, local_state_root);
+                // Inject the state paths.
+                let mut circuit_state_paths = console_state_paths
+                    .iter()
+                    .map(|state_path| (StatePath::<Circuit>::new(mode, state_path.clone()), Boolean::new(mode, true)))
+                    .collect::<Vec<_>>();
+                circuit_state_paths.push((
+                    StatePath::<Circuit>::new(mode, console_local_state_path.clone()),
+                    Boolean::new(mode, false),
+                ));
+
+                // Ensure the batch of state paths is valid.
+                let is_valid = StatePath::verify_batch(&circuit_state_paths, &circuit_local_state_root);
+                assert!(is_valid.eject_value());
+                assert!(Circuit::is_satisfied());
+
+                // Ensure the batch is invalid if a global state path is treated as local.
+                circuit_state_paths[0].1 = Boolean::new(mode, false);
+                let is_valid = StatePath::verify_batch(&circuit_state_paths, &circuit_local_state_root);
+                assert!(!is_valid.eject_value());
+            });
+            Circuit::reset();
+
+            // Ensure the batch is invalid if a global state path is from a different block.
+            let console_other_state_path =
+                console::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng)?;
+            Circuit::scope(format!("Verify batch with a different block {mode}"), || {
+                let circuit_local_state_root = Field::new(mode, console::Field::rand(rng));
+                let mut circuit_state_paths = console_state_paths
+                    .iter()
+                    .map(|state_path| (StatePath::<Circuit>::new(mode, state_path.clone()), Boolean::new(mode, true)))
+                    .collect::<Vec<_>>();
+                circuit_state_paths.push((
+                    StatePath::<Circuit>::new(mode, console_other_state_path.clone()),
+                    Boolean::new(mode, true),
+                ));
+
+                let is_valid = StatePath::verify_batch(&circuit_state_paths, &circuit_local_state_root);
+                assert!(!is_valid.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_path_verify_batch_constant() -> Result<()> {
+        check_verify_batch(Mode::Constant, 3)
+    }
+
+    #[test]
+    fn test_state_path_verify_batch_public() -> Result<()> {
+        check_verify_batch(Mode::Public, 3)
+    }
+
+    #[test]
+    fn test_state_path_verify_batch_private() -> Result<()> {
+        check_verify_batch(Mode::Private, 1)?;
+        check_verify_batch(Mode::Private, 3)
+    }
+
+    #[test]
+    fn test_state_path_verify_batch_is_cheaper() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the console state paths.
+        let console_state_paths =
+            console::state_path::test_helpers::sample_global_state_paths::<CurrentNetwork>(4, rng)?;
+        let local_state_root = console::Field::rand(rng);
+
+        // Count the constraints to verify each state path individually.
+        Circuit::scope("Verify individually", || {
+            let circuit_local_state_root = Field::new(Mode::Private, local_state_root);
+            for state_path in &console_state_paths {
+                let circuit_state_path = StatePath::<Circuit>::new(Mode::Private, state_path.clone());
+                let is_valid = circuit_state_path.verify(&Boolean::new(Mode::Private, true), &circuit_local_state_root);
+                assert!(is_valid.eject_value());
+            }
+        });
+        let individual_constraints = Circuit::num_constraints();
+        Circuit::reset();
+
+        // Count the constraints to verify the state paths as a batch.
+        Circuit::scope("Verify batch", || {
+            let circuit_local_state_root = Field::new(Mode::Private, local_state_root);
+            let circuit_state_paths = console_state_paths
+                .iter()
+                .map(|state_path| {
+                    (StatePath::<Circuit>::new(Mode::Private, state_path.clone()), Boolean::new(Mode::Private, true))
+                })
+                .collect::<Vec<_>>();
+            let is_valid = StatePath::verify_batch(&circuit_state_paths, &circuit_local_state_root);
+            assert!(is_valid.eject_value());
+        });
+        let batch_constraints = Circuit::num_constraints();
+        Circuit::reset();
+
+        assert!(batch_constraints < individual_constraints);
+        Ok(())
+    }
+}
@@ -114,11 +114,15 @@ impl<E: Environment> FromStr for Boolean<E> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Equal<Self> for Emulated<E, P> {
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are equal.
+    ///
+    /// As both elements are canonical, this compares their limbs.
+    ///
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        self.limbs
+            .iter()
+            .zip_eq(&other.limbs)
+            .map(|(a, b)| a.is_equal(b))
+            .fold(Boolean::constant(true), |accumulator, is_equal| accumulator & is_equal)
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* equal.
+    ///
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    type Element = Emulated<Circuit, Secp256k1Base>;
+
+    fn check_is_equal(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Element::sample(&mut rng);
+            let second = Element::sample(&mut rng);
+
+            let a = Element::new(mode_a, first.clone());
+            let b = Element::new(mode_b, second.clone());
+            let c = Element::new(mode_b, first.clone());
+
+            Circuit::scope(format!("IsEqual {i}"), || {
+                assert_eq!(first == second, a.is_equal(&b).eject_value());
+                assert_eq!(first != second, a.is_not_equal(&b).eject_value());
+                assert!(a.is_equal(&c).eject_value());
+                assert!(!a.is_not_equal(&c).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_is_equal() {
+        check_is_equal(Mode::Constant, Mode::Constant);
+        check_is_equal(Mode::Constant, Mode::Private);
+        check_is_equal(Mode::Public, Mode::Private);
+        check_is_equal(Mode::Private, Mode::Private);
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> FromBits for Emulated<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes an emulated field element from a list of **little-endian** bits.
+    ///   - If `bits_le` is longer than `P::num_limbs() * LIMB_BITS`, the excess bits are enforced to be `0`s.
+    ///   - The integer represented by `bits_le` is enforced to be less than the modulus.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let element = Self::from_limbs_unchecked(Self::limbs_from_bits_le(bits_le));
+        // Ensure the element is canonical.
+        element.enforce_canonical();
+        element
+    }
+
+    /// Initializes an emulated field element from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Emulated<E, P> {
+    /// Initializes an emulated field element from a list of **little-endian** bits, reduced modulo the modulus.
+    ///   - If `bits_le` is longer than `P::num_limbs() * LIMB_BITS`, the excess bits are enforced to be `0`s.
+    pub fn from_bits_le_reduced(bits_le: &[Boolean<E>]) -> Self {
+        let limbs = Self::limbs_from_bits_le(bits_le);
+        let value = Self::to_integer(&limbs);
+
+        if limbs.iter().all(|limb| limb.is_constant()) {
+            return Self::constant(value % P::modulus());
+        }
+
+        // Witness the quotient and remainder, such that `value == quotient * modulus + remainder`.
+        // As the most-significant limb of the modulus is nonzero, the quotient fits in a single limb.
+        let quotient_value = &value / P::modulus();
+        let quotient = Self::witness_limbs(Mode::Private, &quotient_value, 1).remove(0);
+        let remainder = Self::new(Mode::Private, value % P::modulus());
+
+        let limb_values = Self::to_limb_values(&Self::to_integer(&limbs), P::num_limbs());
+        let quotient_value = BigInt::from(quotient_value);
+        let remainder_values = remainder.signed_limbs();
+
+        // Compute the limbs of `value - quotient * modulus - remainder`, before carrying.
+        let terms = (0..P::num_limbs())
+            .map(|i| {
+                let modulus_limb = Field::constant(console::Field::from_u64(P::MODULUS[i]));
+                let term = &limbs[i] - &quotient * modulus_limb - &remainder.limbs[i];
+                let value = BigInt::from(limb_values[i]) - &quotient_value * P::MODULUS[i] - &remainder_values[i];
+                (term, value)
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure `value == quotient * modulus + remainder`.
+        Self::enforce_zero(&terms, 2 * LIMB_BITS + 1);
+
+        remainder
+    }
+
+    /// Returns the `P::num_limbs()` limbs for the given list of **little-endian** bits.
+    /// If `bits_le` is longer than `P::num_limbs() * LIMB_BITS`, the excess bits are enforced to be `0`s.
+    fn limbs_from_bits_le(bits_le: &[Boolean<E>]) -> Vec<Field<E>> {
+        let num_bits = P::num_limbs() * LIMB_BITS;
+        // Ensure the excess bits are zero.
+        if bits_le.len() > num_bits {
+            for bit in &bits_le[num_bits..] {
+                E::assert_eq(E::zero(), bit);
+            }
+        }
+        let bits_le = &bits_le[..bits_le.len().min(num_bits)];
+        let mut limbs = bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect::<Vec<_>>();
+        limbs.resize(P::num_limbs(), Field::zero());
+        limbs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    type Element = Emulated<Circuit, Secp256r1Scalar>;
+
+    /// Returns the little-endian bits of the given integer, padded to the given number of bits.
+    fn to_bits_le(value: &BigUint, num_bits: usize) -> Vec<bool> {
+        (0..num_bits).map(|i| value.bit(i as u64)).collect()
+    }
+
+    #[test]
+    fn test_from_bits_le() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let expected = Element::sample(&mut rng);
+                let bits =
+                    to_bits_le(&expected, 256).into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("FromBits {mode} {i}"), || {
+                    let candidate = Element::from_bits_le(&bits);
+                    assert_eq!(expected, candidate.eject_value());
+                    let mut bits_be = bits.clone();
+                    bits_be.reverse();
+                    assert_eq!(expected, Element::from_bits_be(&bits_be).eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_fails_on_noncanonical() {
+        let result = std::panic::catch_unwind(|| {
+            let bits = to_bits_le(&Secp256r1Scalar::modulus(), 256);
+            let bits = bits.into_iter().map(|bit| Boolean::new(Mode::Private, bit)).collect::<Vec<_>>();
+            Element::from_bits_le(&bits)
+        });
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_reduced() {
+        let mut rng = TestRng::default();
+        let maximum = (BigUint::from(1u64) << 256u32) - 1u64;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                for given in [Element::sample(&mut rng) + Secp256r1Scalar::modulus(), maximum.clone()] {
+                    let given = given.min(maximum.clone());
+                    let bits =
+                        to_bits_le(&given, 256).into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>();
+
+                    Circuit::scope(format!("FromBitsReduced {mode} {i}"), || {
+                        let candidate = Element::from_bits_le_reduced(&bits);
+                        assert_eq!(&given % Secp256r1Scalar::modulus(), candidate.eject_value());
+                        assert!(Circuit::is_satisfied_in_scope());
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+}
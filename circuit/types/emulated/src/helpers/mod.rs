@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+pub mod from_bits;
+pub mod one;
+pub mod to_bits;
+pub mod zero;
+
+use num_bigint::Sign;
+
+impl<E: Environment, P: EmulatedParameters> Emulated<E, P> {
+    /// Initializes an emulated field element from the given limbs, without checking they are canonical.
+    pub(crate) fn from_limbs_unchecked(limbs: Vec<Field<E>>) -> Self {
+        Self { limbs, _parameters: PhantomData }
+    }
+
+    /// Initializes an emulated field element as the constant reduction of the given integer.
+    pub(crate) fn constant_reduced(value: &BigInt) -> Self {
+        let modulus = BigInt::from(P::modulus());
+        let reduced = ((value % &modulus) + &modulus) % &modulus;
+        Self::constant(reduced.to_biguint().expect("The reduced value is non-negative"))
+    }
+
+    /// Returns the integer represented by the given little-endian limbs.
+    pub(crate) fn to_integer(limbs: &[Field<E>]) -> BigUint {
+        limbs.iter().rev().fold(BigUint::default(), |accumulator, limb| {
+            // Recover the limb value from its lower bits.
+            let limb = limb
+                .eject_value()
+                .to_bits_le()
+                .into_iter()
+                .take(LIMB_BITS)
+                .rev()
+                .fold(0u64, |acc, bit| (acc << 1) | bit as u64);
+            (accumulator << LIMB_BITS) + limb
+        })
+    }
+
+    /// Returns the given integer as `num_limbs` little-endian limbs.
+    pub(crate) fn to_limb_values(value: &BigUint, num_limbs: usize) -> Vec<u64> {
+        let mut limbs = value.to_u64_digits();
+        limbs.resize(num_limbs, 0);
+        limbs
+    }
+
+    /// Returns `num_limbs` limbs of the given mode for the given integer.
+    /// If the mode is not constant, each limb is enforced to be at most `LIMB_BITS` bits.
+    pub(crate) fn witness_limbs(mode: Mode, value: &BigUint, num_limbs: usize) -> Vec<Field<E>> {
+        Self::to_limb_values(value, num_limbs)
+            .into_iter()
+            .map(|limb| {
+                let limb = Field::new(mode, console::Field::from_u64(limb));
+                // Ensure the limb is within range.
+                if !mode.is_constant() {
+                    limb.to_lower_bits_le(LIMB_BITS);
+                }
+                limb
+            })
+            .collect()
+    }
+
+    /// Enforces that the element is strictly less than the modulus.
+    ///
+    /// This is done by witnessing `slack := (modulus - 1) - self`, and enforcing that
+    /// `self + slack == modulus - 1` holds over the integers, with each limb of `slack` in range.
+    pub(crate) fn enforce_canonical(&self) {
+        if self.is_constant() {
+            return;
+        }
+        let maximum = P::modulus() - 1u64;
+        let slack_value = &maximum - self.eject_value();
+        let slack = Self::witness_limbs(Mode::Private, &slack_value, P::num_limbs());
+
+        let maximum = Self::to_limb_values(&maximum, P::num_limbs());
+        let self_values = Self::to_limb_values(&self.eject_value(), P::num_limbs());
+        let slack_values = Self::to_limb_values(&slack_value, P::num_limbs());
+
+        let terms = (0..P::num_limbs())
+            .map(|i| {
+                let term = &self.limbs[i] + &slack[i] - Field::constant(console::Field::from_u64(maximum[i]));
+                let value = BigInt::from(self_values[i]) + slack_values[i] - maximum[i];
+                (term, value)
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure `self + slack == modulus - 1`.
+        Self::enforce_zero(&terms, LIMB_BITS + 1);
+    }
+
+    /// Enforces that the integer `sum_i terms[i] * 2^(LIMB_BITS * i)` is zero.
+    ///
+    /// Each term is given as a base field element along with its signed integer value,
+    /// where each value must be strictly less than `2^max_bits` in absolute value.
+    /// The check propagates a range-checked carry from each limb into the next,
+    /// and requires `max_bits` to be small enough that no term wraps around the base field.
+    pub(crate) fn enforce_zero(terms: &[(Field<E>, BigInt)], max_bits: usize) {
+        // Ensure the terms cannot overflow the base field.
+        if max_bits < LIMB_BITS || max_bits + 2 >= E::BaseField::size_in_data_bits() {
+            E::halt(format!("Cannot enforce a zero integer with {max_bits}-bit limbs"))
+        }
+
+        // Each carry lies within `(-2^(carry_bits - 1), 2^(carry_bits - 1))`.
+        let carry_bits = max_bits + 2 - LIMB_BITS;
+        let offset = Field::<E>::constant(Self::to_console_field(&(BigInt::from(1u64) << (carry_bits - 1))));
+        let shift = Field::<E>::constant(Self::to_console_field(&(BigInt::from(1u64) << LIMB_BITS)));
+
+        let mut carry: Option<(Field<E>, BigInt)> = None;
+        for (i, (term, value)) in terms.iter().enumerate() {
+            // Add the carry from the previous limb.
+            let (sum, sum_value) = match &carry {
+                Some((carry, carry_value)) => (term + carry, value + carry_value),
+                None => (term.clone(), value.clone()),
+            };
+
+            match i + 1 == terms.len() {
+                // Ensure the final sum is zero.
+                true => E::assert_eq(&sum, E::zero()),
+                false => {
+                    // Witness the carry, which is exact when the lower limb bits of the sum are zero.
+                    let carry_value = &sum_value >> LIMB_BITS;
+                    let mode = if sum.is_constant() { Mode::Constant } else { Mode::Private };
+                    let next = Field::new(mode, Self::to_console_field(&carry_value));
+                    // Ensure the carry is within range.
+                    if !mode.is_constant() {
+                        (&next + &offset).to_lower_bits_le(carry_bits);
+                    }
+                    // Ensure `sum == carry * 2^LIMB_BITS`.
+                    E::assert_eq(&sum, &next * &shift);
+                    carry = Some((next, carry_value));
+                }
+            }
+        }
+    }
+
+    /// Returns the given signed integer as a console base field element.
+    pub(crate) fn to_console_field(value: &BigInt) -> console::Field<E::Network> {
+        let (sign, digits) = value.to_u64_digits();
+        let shift = console::Field::from_u64(1 << 32) * console::Field::from_u64(1 << 32);
+        let magnitude = digits
+            .iter()
+            .rev()
+            .fold(console::Field::zero(), |accumulator, digit| accumulator * shift + console::Field::from_u64(*digit));
+        match sign {
+            Sign::Minus => -magnitude,
+            _ => magnitude,
+        }
+    }
+
+    /// Returns the signed integer value of each limb of the given element.
+    pub(crate) fn signed_limbs(&self) -> Vec<BigInt> {
+        Self::to_limb_values(&self.eject_value(), P::num_limbs()).into_iter().map(BigInt::from).collect()
+    }
+
+    /// Samples a random element in the emulated field.
+    #[cfg(test)]
+    pub(crate) fn sample<R: console::Rng>(rng: &mut R) -> BigUint {
+        let limbs = (0..=P::num_limbs()).map(|_| u64::rand(rng)).collect::<Vec<_>>();
+        limbs.into_iter().rev().fold(BigUint::default(), |accumulator, limb| (accumulator << LIMB_BITS) + limb)
+            % P::modulus()
+    }
+}
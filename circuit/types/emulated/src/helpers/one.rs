@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> One for Emulated<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the `1` element of the emulated field.
+    fn one() -> Self {
+        Self::constant(BigUint::from(1u64))
+    }
+
+    /// Returns `true` if `self` is one.
+    fn is_one(&self) -> Self::Boolean {
+        self.is_equal(&Self::one())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type Element = Emulated<Circuit, Secp256k1Base>;
+
+    #[test]
+    fn test_one() {
+        let one = Element::one();
+        assert_eq!(BigUint::from(1u64), one.eject_value());
+        assert!(one.is_constant());
+        assert!(one.is_one().eject_value());
+        assert!(!Element::zero().is_one().eject_value());
+    }
+}
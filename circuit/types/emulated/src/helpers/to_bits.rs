@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> ToBits for Emulated<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the `P::num_limbs() * LIMB_BITS` **little-endian** bits of the emulated field element.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_BITS)).collect()
+    }
+
+    /// Outputs the `P::num_limbs() * LIMB_BITS` **big-endian** bits of the emulated field element.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = self.to_bits_le();
+        bits_le.reverse();
+        bits_le
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    type Element = Emulated<Circuit, Secp256r1Base>;
+
+    #[test]
+    fn test_to_bits_le() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let expected = Element::sample(&mut rng);
+                let candidate = Element::new(mode, expected.clone());
+
+                Circuit::scope(format!("ToBits {mode} {i}"), || {
+                    let bits = candidate.to_bits_le();
+                    assert_eq!(256, bits.len());
+                    for (index, bit) in bits.iter().enumerate() {
+                        assert_eq!(expected.bit(index as u64), bit.eject_value());
+                    }
+                    // Ensure the bits round trip.
+                    assert_eq!(expected, Element::from_bits_le(&bits).eject_value());
+                    assert_eq!(expected, Element::from_bits_be(&candidate.to_bits_be()).eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
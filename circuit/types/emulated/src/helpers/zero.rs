@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Zero for Emulated<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the `0` element of the emulated field.
+    fn zero() -> Self {
+        Self::constant(BigUint::default())
+    }
+
+    /// Returns `true` if `self` is zero.
+    fn is_zero(&self) -> Self::Boolean {
+        self.is_equal(&Self::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type Element = Emulated<Circuit, Secp256k1Base>;
+
+    #[test]
+    fn test_zero() {
+        let zero = Element::zero();
+        assert_eq!(BigUint::default(), zero.eject_value());
+        assert!(zero.is_constant());
+        assert!(zero.is_zero().eject_value());
+        assert!(!Element::one().is_zero().eject_value());
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Inverse for Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    /// Returns the multiplicative inverse of `self`.
+    ///
+    /// The inverse is computed via Fermat's little theorem, and thus requires a prime modulus.
+    fn inverse(&self) -> Self::Output {
+        let modulus = P::modulus();
+        let inverse = Self::new(witness_mode!(self), self.eject_value().modpow(&(&modulus - 2u64), &modulus));
+
+        // Ensure `self` * `self^(-1)` == 1.
+        let product = self * &inverse;
+        let one = Self::one();
+        for (limb, expected) in product.limbs.iter().zip_eq(&one.limbs) {
+            E::assert_eq(limb, expected);
+        }
+
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    type Element = Emulated<Circuit, Secp256k1Base>;
+
+    fn check_inverse(name: &str, mode: Mode, rng: &mut TestRng) {
+        let modulus = Secp256k1Base::modulus();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random nonzero element.
+            let given = Element::sample(rng).max(BigUint::from(1u64));
+            let candidate = Element::new(mode, given.clone());
+
+            Circuit::scope(name, || {
+                let result = candidate.inverse();
+                assert_eq!(BigUint::from(1u64), (&given * result.eject_value()) % &modulus);
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = TestRng::default();
+
+        check_inverse("Constant", Mode::Constant, &mut rng);
+        check_inverse("Public", Mode::Public, &mut rng);
+        check_inverse("Private", Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_inverse_of_zero_fails() {
+        let candidate = Element::new(Mode::Private, BigUint::default());
+        let _result = candidate.inverse();
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
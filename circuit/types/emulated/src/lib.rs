@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![forbid(unsafe_code)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+
+mod helpers;
+
+pub mod add;
+pub mod equal;
+pub mod inverse;
+pub mod mul;
+pub mod neg;
+pub mod parameters;
+pub use parameters::*;
+pub mod sub;
+pub mod ternary;
+
+#[cfg(test)]
+use console::{TestRng, Uniform};
+
+use snarkvm_circuit_environment::prelude::*;
+use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_field::Field;
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The number of bits in each limb of an emulated field element.
+pub const LIMB_BITS: usize = 64;
+
+/// An element of a foreign field with modulus `P::modulus()`, emulated over the base field of `E`.
+///
+/// The element is represented as `P::num_limbs()` little-endian limbs of `LIMB_BITS` bits each,
+/// and is always kept in canonical form, i.e. strictly less than the modulus.
+#[derive(Clone)]
+pub struct Emulated<E: Environment, P: EmulatedParameters> {
+    /// The little-endian limbs of the element.
+    limbs: Vec<Field<E>>,
+    /// PhantomData.
+    _parameters: PhantomData<P>,
+}
+
+impl<E: Environment, P: EmulatedParameters> Emulated<E, P> {
+    /// Returns the little-endian limbs of the element.
+    pub fn limbs(&self) -> &[Field<E>] {
+        &self.limbs
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: EmulatedParameters> Inject for Emulated<E, P> {
+    type Primitive = BigUint;
+
+    /// Initializes an emulated field element from an integer, which must be less than the modulus.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        // Ensure the value is less than the modulus.
+        if value >= P::modulus() {
+            E::halt(format!("Emulated field element {value} exceeds the modulus {}", P::modulus()))
+        }
+        // Inject the limbs, and ensure they are canonical.
+        let element = Self::from_limbs_unchecked(Self::witness_limbs(mode, &value, P::num_limbs()));
+        element.enforce_canonical();
+        element
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: EmulatedParameters> Eject for Emulated<E, P> {
+    type Primitive = BigUint;
+
+    /// Ejects the mode of the emulated field element.
+    fn eject_mode(&self) -> Mode {
+        self.limbs.eject_mode()
+    }
+
+    /// Ejects the emulated field element as an integer.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::to_integer(&self.limbs)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: EmulatedParameters> Debug for Emulated<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: EmulatedParameters> Display for Emulated<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    type Element = Emulated<Circuit, Secp256k1Base>;
+
+    #[test]
+    fn test_new() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let expected = Element::sample(&mut rng);
+                Circuit::scope(format!("New {mode}"), || {
+                    let candidate = Element::new(mode, expected.clone());
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_eq!(mode, candidate.eject_mode());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_new_fails_on_noncanonical() {
+        let result = std::panic::catch_unwind(|| Element::new(Mode::Private, Secp256k1Base::modulus()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let candidate = Element::new(Mode::Private, BigUint::from(12345u64));
+        assert_eq!("12345.private", format!("{candidate}"));
+    }
+}
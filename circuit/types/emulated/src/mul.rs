@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Mul<Emulated<E, P>> for Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn mul(self, other: Emulated<E, P>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Mul<&Emulated<E, P>> for Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn mul(self, other: &Emulated<E, P>) -> Self::Output {
+        let mut output = self;
+        output *= other;
+        output
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Mul<Emulated<E, P>> for &Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn mul(self, other: Emulated<E, P>) -> Self::Output {
+        other * self
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Mul<&Emulated<E, P>> for &Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn mul(self, other: &Emulated<E, P>) -> Self::Output {
+        let mut output = self.clone();
+        output *= other;
+        output
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> MulAssign<Emulated<E, P>> for Emulated<E, P> {
+    fn mul_assign(&mut self, other: Emulated<E, P>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> MulAssign<&Emulated<E, P>> for Emulated<E, P> {
+    fn mul_assign(&mut self, other: &Emulated<E, P>) {
+        let product = self.eject_value() * other.eject_value();
+
+        match self.is_constant() && other.is_constant() {
+            true => *self = Self::constant_reduced(&BigInt::from(product)),
+            false => {
+                let num_limbs = P::num_limbs();
+
+                // Witness the quotient and remainder, such that `self * other == quotient * modulus + remainder`.
+                // As both operands are less than the modulus, the quotient is as well, so it fits in `num_limbs` limbs.
+                let quotient_value = &product / P::modulus();
+                let quotient = Self::witness_limbs(Mode::Private, &quotient_value, num_limbs);
+                let remainder = Self::new(Mode::Private, product % P::modulus());
+
+                let (self_values, other_values) = (self.signed_limbs(), other.signed_limbs());
+                let quotient_values =
+                    Self::to_limb_values(&quotient_value, num_limbs).into_iter().map(BigInt::from).collect::<Vec<_>>();
+                let remainder_values = remainder.signed_limbs();
+
+                // Compute the limbs of `self * other - quotient * modulus - remainder`, before carrying.
+                let mut terms = vec![(Field::zero(), BigInt::default()); 2 * num_limbs - 1];
+                for i in 0..num_limbs {
+                    for j in 0..num_limbs {
+                        let modulus_limb = Field::constant(console::Field::from_u64(P::MODULUS[j]));
+                        terms[i + j].0 += &self.limbs[i] * &other.limbs[j] - &quotient[i] * modulus_limb;
+                        terms[i + j].1 += &self_values[i] * &other_values[j] - &quotient_values[i] * P::MODULUS[j];
+                    }
+                    terms[i].0 -= &remainder.limbs[i];
+                    terms[i].1 -= &remainder_values[i];
+                }
+
+                // Each term is bounded in absolute value by `(num_limbs + 1) * 2^(2 * LIMB_BITS)`.
+                let max_bits = 2 * LIMB_BITS + (usize::BITS - num_limbs.leading_zeros()) as usize;
+
+                // Ensure `self * other == quotient * modulus + remainder`.
+                Self::enforce_zero(&terms, max_bits);
+
+                *self = remainder;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_mul<P: EmulatedParameters>(name: &str, first: &BigUint, second: &BigUint, mode_a: Mode, mode_b: Mode) {
+        let expected = (first * second) % P::modulus();
+        let a = Emulated::<Circuit, P>::new(mode_a, first.clone());
+        let b = Emulated::<Circuit, P>::new(mode_b, second.clone());
+
+        Circuit::scope(name, || {
+            let candidate = &a * &b;
+            assert_eq!(expected, candidate.eject_value(), "({first} * {second})");
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<P: EmulatedParameters>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+        let maximum = P::modulus() - 1u64;
+
+        for i in 0..ITERATIONS {
+            let first = Emulated::<Circuit, P>::sample(&mut rng);
+            let second = Emulated::<Circuit, P>::sample(&mut rng);
+
+            check_mul::<P>(&format!("Mul: a * b {i}"), &first, &second, mode_a, mode_b);
+            check_mul::<P>(&format!("Mul: a * 0 {i}"), &first, &BigUint::default(), mode_a, mode_b);
+            check_mul::<P>(&format!("Mul: a * 1 {i}"), &first, &BigUint::from(1u64), mode_a, mode_b);
+            check_mul::<P>(&format!("Mul: a * (p - 1) {i}"), &first, &maximum, mode_a, mode_b);
+        }
+        check_mul::<P>("Mul: (p - 1) * (p - 1)", &maximum, &maximum, mode_a, mode_b);
+    }
+
+    /// A 512-bit RSA-style (composite) modulus, `(2^256 - 189) * (2^256 - 357)`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    struct Rsa512;
+
+    impl EmulatedParameters for Rsa512 {
+        const MODULUS: &'static [u64] = &[
+            0x0000000000010791,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0xfffffffffffffdde,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ];
+    }
+
+    #[test]
+    fn test_constant_times_constant() {
+        run_test::<Secp256k1Base>(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_times_private() {
+        run_test::<Secp256k1Base>(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_times_private() {
+        run_test::<Secp256k1Base>(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_times_private() {
+        run_test::<Secp256k1Base>(Mode::Private, Mode::Private);
+        run_test::<Secp256k1Scalar>(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_rsa_modulus() {
+        assert_eq!((BigUint::from(1u64) << 256) - 189u64, Rsa512::modulus() / ((BigUint::from(1u64) << 256) - 357u64));
+        run_test::<Rsa512>(Mode::Private, Mode::Private);
+    }
+}
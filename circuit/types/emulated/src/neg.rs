@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Neg for Emulated<E, P> {
+    type Output = Self;
+
+    /// Performs the unary `-` operation.
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Neg for &Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    /// Performs the unary `-` operation.
+    fn neg(self) -> Self::Output {
+        Emulated::zero() - self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    type Element = Emulated<Circuit, Secp256k1Base>;
+
+    fn check_neg(name: &str, mode: Mode, rng: &mut TestRng) {
+        let modulus = Secp256k1Base::modulus();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let given = Element::sample(rng);
+            let expected = (&modulus - &given) % &modulus;
+            let candidate = Element::new(mode, given);
+
+            // Check negation.
+            Circuit::scope(name, || {
+                let result = -candidate;
+                assert_eq!(expected, result.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_neg() {
+        let mut rng = TestRng::default();
+
+        check_neg("Constant", Mode::Constant, &mut rng);
+        check_neg("Public", Mode::Public, &mut rng);
+        check_neg("Private", Mode::Private, &mut rng);
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_circuit_environment::prelude::*;
+
+use num_bigint::BigUint;
+
+/// The parameters of an emulated (non-native) field.
+pub trait EmulatedParameters: 'static + Copy + Clone + Debug + PartialEq + Eq + Send + Sync {
+    /// The modulus of the emulated field, as little-endian 64-bit limbs.
+    /// The most-significant limb must be nonzero.
+    const MODULUS: &'static [u64];
+
+    /// Returns the modulus of the emulated field.
+    fn modulus() -> BigUint {
+        Self::MODULUS.iter().rev().fold(BigUint::default(), |accumulator, limb| (accumulator << 64) + *limb)
+    }
+
+    /// Returns the number of limbs in an element of the emulated field.
+    fn num_limbs() -> usize {
+        Self::MODULUS.len()
+    }
+}
+
+/// The base field of the secp256k1 curve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Base;
+
+impl EmulatedParameters for Secp256k1Base {
+    /// p = 2^256 - 2^32 - 977
+    const MODULUS: &'static [u64] = &[0xFFFFFFFEFFFFFC2F, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+}
+
+/// The scalar field of the secp256k1 curve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Scalar;
+
+impl EmulatedParameters for Secp256k1Scalar {
+    /// n = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141
+    const MODULUS: &'static [u64] = &[0xBFD25E8CD0364141, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF];
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256r1Base;
+
+impl EmulatedParameters for Secp256r1Base {
+    /// p = 2^256 - 2^224 + 2^192 + 2^96 - 1
+    const MODULUS: &'static [u64] = &[0xFFFFFFFFFFFFFFFF, 0x00000000FFFFFFFF, 0x0000000000000000, 0xFFFFFFFF00000001];
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256r1Scalar;
+
+impl EmulatedParameters for Secp256r1Scalar {
+    /// n = 0xFFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551
+    const MODULUS: &'static [u64] = &[0xF3B9CAC2FC632551, 0xBCE6FAADA7179E84, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFF00000000];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secp256k1_modulus() {
+        let expected = (BigUint::from(1u64) << 256) - (BigUint::from(1u64) << 32) - 977u64;
+        assert_eq!(expected, Secp256k1Base::modulus());
+        assert_eq!(4, Secp256k1Base::num_limbs());
+    }
+
+    #[test]
+    fn test_secp256r1_modulus() {
+        let one = BigUint::from(1u64);
+        let expected = (&one << 256u32) - (&one << 224u32) + (&one << 192u32) + (&one << 96u32) - 1u64;
+        assert_eq!(expected, Secp256r1Base::modulus());
+        assert_eq!(4, Secp256r1Base::num_limbs());
+        // Ensure the order of the curve is less than the base modulus.
+        assert!(Secp256r1Scalar::modulus() < Secp256r1Base::modulus());
+    }
+}
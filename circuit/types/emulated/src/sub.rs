@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Sub<Emulated<E, P>> for Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn sub(self, other: Emulated<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Sub<&Emulated<E, P>> for Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn sub(self, other: &Emulated<E, P>) -> Self::Output {
+        let mut output = self;
+        output -= other;
+        output
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Sub<Emulated<E, P>> for &Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn sub(self, other: Emulated<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> Sub<&Emulated<E, P>> for &Emulated<E, P> {
+    type Output = Emulated<E, P>;
+
+    fn sub(self, other: &Emulated<E, P>) -> Self::Output {
+        let mut output = self.clone();
+        output -= other;
+        output
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> SubAssign<Emulated<E, P>> for Emulated<E, P> {
+    fn sub_assign(&mut self, other: Emulated<E, P>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment, P: EmulatedParameters> SubAssign<&Emulated<E, P>> for Emulated<E, P> {
+    fn sub_assign(&mut self, other: &Emulated<E, P>) {
+        let (first, second) = (self.eject_value(), other.eject_value());
+
+        match self.is_constant() && other.is_constant() {
+            true => *self = Self::constant_reduced(&(BigInt::from(first) - BigInt::from(second))),
+            false => {
+                // Witness the borrow and difference, such that `self + borrow * modulus - other == difference`.
+                let is_borrow = first < second;
+                let borrow = Boolean::new(Mode::Private, is_borrow);
+                let difference = Self::new(Mode::Private, match is_borrow {
+                    true => first + P::modulus() - second,
+                    false => first - second,
+                });
+
+                let (self_values, other_values) = (self.signed_limbs(), other.signed_limbs());
+                let difference_values = difference.signed_limbs();
+
+                let terms = (0..P::num_limbs())
+                    .map(|i| {
+                        let modulus_limb = Field::constant(console::Field::from_u64(P::MODULUS[i]));
+                        let term = &self.limbs[i] + Field::from_boolean(&borrow) * modulus_limb
+                            - &other.limbs[i]
+                            - &difference.limbs[i];
+                        let value = &self_values[i] + BigInt::from(is_borrow as u64 * P::MODULUS[i])
+                            - &other_values[i]
+                            - &difference_values[i];
+                        (term, value)
+                    })
+                    .collect::<Vec<_>>();
+
+                // Ensure `self + borrow * modulus - other == difference`.
+                Self::enforce_zero(&terms, LIMB_BITS + 1);
+
+                *self = difference;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    type Element = Emulated<Circuit, Secp256k1Base>;
+
+    fn check_sub(name: &str, first: &BigUint, second: &BigUint, mode_a: Mode, mode_b: Mode) {
+        let modulus = Secp256k1Base::modulus();
+        let expected = (first + &modulus - second) % &modulus;
+        let a = Element::new(mode_a, first.clone());
+        let b = Element::new(mode_b, second.clone());
+
+        Circuit::scope(name, || {
+            let candidate = &a - &b;
+            assert_eq!(expected, candidate.eject_value(), "({first} - {second})");
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+        let maximum = Secp256k1Base::modulus() - 1u64;
+
+        for i in 0..ITERATIONS {
+            let first = Element::sample(&mut rng);
+            let second = Element::sample(&mut rng);
+
+            check_sub(&format!("Sub: a - b {i}"), &first, &second, mode_a, mode_b);
+            check_sub(&format!("Sub: a - a {i}"), &first, &first, mode_a, mode_b);
+            check_sub(&format!("Sub: 0 - a {i}"), &BigUint::default(), &first, mode_a, mode_b);
+            check_sub(&format!("Sub: a - (p - 1) {i}"), &first, &maximum, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_constant_minus_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_minus_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_minus_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_minus_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: EmulatedParameters> Ternary for Emulated<E, P> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    ///
+    /// As both elements are canonical, this selects their limbs.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)).collect();
+        Self::from_limbs_unchecked(limbs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    type Element = Emulated<Circuit, Secp256r1Base>;
+
+    #[test]
+    fn test_ternary() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let first = Element::sample(&mut rng);
+                let second = Element::sample(&mut rng);
+                let a = Element::new(mode, first.clone());
+                let b = Element::new(mode, second.clone());
+
+                for flag in [true, false] {
+                    let condition = Boolean::new(mode, flag);
+                    Circuit::scope(format!("Ternary {mode} {i} {flag}"), || {
+                        let candidate = Element::ternary(&condition, &a, &b);
+                        assert_eq!(if flag { &first } else { &second }, &candidate.eject_value());
+                        assert!(Circuit::is_satisfied_in_scope());
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+}
@@ -17,6 +17,7 @@ use super::*;
 pub mod from_bits;
 pub mod from_boolean;
 pub mod one;
+pub mod pack;
 pub mod to_bits;
 pub mod to_lower_bits;
 pub mod to_upper_bits;
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Packs the given **little-endian** bits into public field elements, each holding up to `E::BaseField::size_in_data_bits()` bits.
+    /// Each public field element costs one constraint, in place of one public variable per bit.
+    /// The verifier constructs the matching inputs with `console::Field::pack_bits_le`.
+    pub fn pack_public_bits_le(bits_le: &[Boolean<E>]) -> Vec<Self> {
+        bits_le
+            .chunks(E::BaseField::size_in_data_bits())
+            .map(|chunk| {
+                // Reconstruct the bits as a linear combination.
+                let packed = Field::from_bits_le(chunk);
+                // Inject the packed field element as `Mode::Public`.
+                let public = Field::new(Mode::Public, packed.eject_value());
+                // Ensure the public field element matches the packed bits.
+                E::assert_eq(&public, packed);
+                public
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_pack_public_bits_le(mode: Mode) {
+        let mut rng = TestRng::default();
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        for num_bits in [1, 8, size_in_data_bits, size_in_data_bits + 1, 600] {
+            // Compute the number of packed field elements.
+            let num_fields = ((num_bits + size_in_data_bits - 1) / size_in_data_bits) as u64;
+
+            for i in 0..ITERATIONS {
+                // Sample random bits.
+                let expected = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+                let given = expected.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+                Circuit::scope(format!("{mode} {num_bits} {i}"), || {
+                    let candidate = Field::pack_public_bits_le(&given);
+                    // Ensure the packed field elements match the verifier-side packing.
+                    let candidate = candidate.eject_value();
+                    assert_eq!(console::Field::pack_bits_le(&expected).unwrap(), candidate);
+                    assert_eq!(expected, console::Field::unpack_bits_le(&candidate, num_bits).unwrap());
+                    assert_scope!(0, num_fields, 0, num_fields);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_pack_public_bits_le_constant() {
+        check_pack_public_bits_le(Mode::Constant);
+    }
+
+    #[test]
+    fn test_pack_public_bits_le_public() {
+        check_pack_public_bits_le(Mode::Public);
+    }
+
+    #[test]
+    fn test_pack_public_bits_le_private() {
+        check_pack_public_bits_le(Mode::Private);
+    }
+}
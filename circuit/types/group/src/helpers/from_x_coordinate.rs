@@ -26,6 +26,20 @@ impl<E: Environment> Group<E> {
 
         Self::from_xy_coordinates(x, y)
     }
+
+    /// Initializes an affine group element from a given x-coordinate field element and sign bit,
+    /// where the sign bit is `true` if and only if the y-coordinate is the lexicographically largest root.
+    /// For safety, the resulting point is always enforced to be on the curve with constraints,
+    /// and to match the given x-coordinate and sign bit.
+    pub fn from_x_coordinate_with_sign(x: Field<E>, sign: &Boolean<E>) -> Self {
+        // Recover the point from the x-coordinate.
+        let point = Self::from_x_coordinate(x.clone());
+        // Ensure the point has the given x-coordinate.
+        E::assert_eq(&point.x, x);
+        // Ensure the y-coordinate of the point matches the given sign bit.
+        E::assert_eq(point.y.is_greater_than(&-&point.y), sign);
+        point
+    }
 }
 
 #[cfg(test)]
@@ -60,6 +74,35 @@ mod tests {
         }
     }
 
+    fn check_from_x_coordinate_with_sign(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+            // Inject the x-coordinate and sign bit.
+            let x_coordinate = Field::new(mode, point.to_x_coordinate());
+            let sign = Boolean::new(mode, point.to_sign());
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let affine = Group::<Circuit>::from_x_coordinate_with_sign(x_coordinate, &sign);
+                assert_eq!(point, affine.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+
+            // Ensure the opposite sign bit is not satisfied.
+            if !mode.is_constant() {
+                let x_coordinate = Field::new(mode, point.to_x_coordinate());
+                let sign = Boolean::new(mode, !point.to_sign());
+                let _affine = Group::<Circuit>::from_x_coordinate_with_sign(x_coordinate, &sign);
+                assert!(!Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
     #[test]
     fn test_from_x_coordinate_constant() {
         check_from_x_coordinate(Mode::Constant, 11, 0, 0, 0);
@@ -74,4 +117,19 @@ mod tests {
     fn test_from_x_coordinate_private() {
         check_from_x_coordinate(Mode::Private, 4, 0, 15, 13);
     }
+
+    #[test]
+    fn test_from_x_coordinate_with_sign_constant() {
+        check_from_x_coordinate_with_sign(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_with_sign_public() {
+        check_from_x_coordinate_with_sign(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_with_sign_private() {
+        check_from_x_coordinate_with_sign(Mode::Private);
+    }
 }
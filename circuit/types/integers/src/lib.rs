@@ -21,6 +21,7 @@ mod helpers;
 pub mod abs_checked;
 pub mod abs_wrapped;
 pub mod add_checked;
+pub mod add_overflowing;
 pub mod add_wrapped;
 pub mod and;
 pub mod compare;
@@ -29,6 +30,7 @@ pub mod div_wrapped;
 pub mod equal;
 pub mod modulo;
 pub mod mul_checked;
+pub mod mul_overflowing;
 pub mod mul_wrapped;
 pub mod neg;
 pub mod not;
@@ -42,6 +44,7 @@ pub mod shl_wrapped;
 pub mod shr_checked;
 pub mod shr_wrapped;
 pub mod sub_checked;
+pub mod sub_overflowing;
 pub mod sub_wrapped;
 pub mod ternary;
 pub mod xor;
@@ -158,11 +161,15 @@ impl<E: Environment, I: IntegerType> FromStr for Integer<E, I> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> MulOverflowing<Self> for Integer<E, I> {
+    type Output = (Self, Boolean<E>);
+
+    #[inline]
+    fn mul_overflowing(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the wrapped product and overflow flag, and return the new constants.
+            let (product, is_overflow) = self.eject_value().mul_overflowing(&other.eject_value());
+            (Integer::constant(product), Boolean::constant(*is_overflow))
+        } else if I::is_signed() {
+            // Multiply the absolute value of `self` and `other` in the base field.
+            // Note that it is safe to use abs_wrapped since we want Integer::MIN to be interpreted as an unsigned number.
+            let (product, carry) = Self::mul_with_carry(&self.abs_wrapped(), &other.abs_wrapped());
+
+            // The product abs(a) * abs(b) exceeded the unsigned maximum if any of the carry bits are set.
+            let carry_bits_nonzero = carry.iter().fold(Boolean::constant(false), |a, b| a | b);
+
+            // If the product should be positive, then it overflows if it exceeds the signed maximum.
+            let operands_same_sign = &self.msb().is_equal(other.msb());
+            let positive_product_overflows = operands_same_sign & product.msb();
+
+            // If the product should be negative, then it underflows if it exceeds the absolute value of the signed minimum.
+            let negative_product_underflows = {
+                let lower_product_bits_nonzero =
+                    product.bits_le[..(I::BITS as usize - 1)].iter().fold(Boolean::constant(false), |a, b| a | b);
+                let negative_product_lt_or_eq_signed_min =
+                    !product.msb() | (product.msb() & !lower_product_bits_nonzero);
+                !operands_same_sign & !negative_product_lt_or_eq_signed_min
+            };
+
+            // Compute the overflow flag.
+            let is_overflow = carry_bits_nonzero | positive_product_overflows | negative_product_underflows;
+
+            // Return the wrapped product of `self` and `other` with the appropriate sign, and the overflow flag.
+            // Note: The lower bits of abs(a) * abs(b) are the wrapped product, up to sign.
+            (Self::ternary(operands_same_sign, &product, &Self::zero().sub_wrapped(&product)), is_overflow)
+        } else {
+            // Compute the product of `self` and `other`.
+            let (product, carry) = Self::mul_with_carry(self, other);
+
+            // For unsigned multiplication, an overflow occurred if any of the carry bits are set.
+            let is_overflow = carry.iter().fold(Boolean::constant(false), |a, b| a | b);
+
+            // Return the wrapped product of `self` and `other`, and the overflow flag.
+            (product, is_overflow)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn MulOverflowing<Integer<E, I>, Output = (Integer<E, I>, Boolean<E>)>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
+        if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => {
+                        Count::is(4 * I::BITS, 0, (8 * I::BITS) + 5, (8 * I::BITS) + 8)
+                    }
+                    (_, _) => Count::is(3 * I::BITS, 0, (10 * I::BITS) + 8, (10 * I::BITS) + 12),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, (3 * I::BITS) - 1, 3 * I::BITS),
+                    (_, _) => Count::is(0, 0, 3 * I::BITS, (3 * I::BITS) + 1),
+                },
+            }
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u128, i128).
+        else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::is(4 * I::BITS, 0, 1159, 1163),
+                    (_, _) => Count::is(3 * I::BITS, 0, 1421, 1426),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, 513, 515),
+                    (_, _) => Count::is(0, 0, 517, 519),
+                },
+            }
+        } else {
+            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn MulOverflowing<Integer<E, I>, Output = (Integer<E, I>, Boolean<E>)>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_integer_binary;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_mul<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let (expected, expected_is_overflow) = first.mul_overflowing(&second);
+        Circuit::scope(name, || {
+            let (candidate, is_overflow) = a.mul_overflowing(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(*expected_is_overflow, is_overflow.eject_value());
+            assert_count!(
+                Integer<Circuit, I>,
+                MulOverflowing<Integer<Circuit, I>, Output = (Integer<Circuit, I>, Boolean<Circuit>)>,
+                &(mode_a, mode_b)
+            );
+            assert_output_mode!(
+                Integer<Circuit, I>,
+                MulOverflowing<Integer<Circuit, I>, Output = (Integer<Circuit, I>, Boolean<Circuit>)>,
+                &(mode_a, mode_b),
+                candidate
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // TODO (@pranav) Uniform random sampling almost always produces arguments that result in an overflow.
+            //  Is there a better method for sampling arguments?
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Mul: {mode_a} * {mode_b} {i}");
+            check_mul::<I>(&name, first, second, mode_a, mode_b);
+            check_mul::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("Double: {mode_a} * {mode_b} {i}");
+            check_mul::<I>(&name, first, console::Integer::one() + console::Integer::one(), mode_a, mode_b);
+            check_mul::<I>(&name, console::Integer::one() + console::Integer::one(), first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("Square: {mode_a} * {mode_b} {i}");
+            check_mul::<I>(&name, first, first, mode_a, mode_b);
+        }
+
+        // Check specific cases common to signed and unsigned integers.
+        check_mul::<I>("1 * MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_mul::<I>("MAX * 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_mul::<I>("1 * MIN", console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+        check_mul::<I>("MIN * 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_mul::<I>("0 * MAX", console::Integer::zero(), console::Integer::MAX, mode_a, mode_b);
+        check_mul::<I>("MAX * 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_mul::<I>("0 * MIN", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+        check_mul::<I>("MIN * 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+        check_mul::<I>("1 * 1", console::Integer::one(), console::Integer::one(), mode_a, mode_b);
+
+        // Check common overflow cases.
+        check_mul::<I>(
+            "MAX * 2",
+            console::Integer::MAX,
+            console::Integer::one() + console::Integer::one(),
+            mode_a,
+            mode_b,
+        );
+        check_mul::<I>(
+            "2 * MAX",
+            console::Integer::one() + console::Integer::one(),
+            console::Integer::MAX,
+            mode_a,
+            mode_b,
+        );
+
+        // Check additional corner cases for signed integers.
+        if I::is_signed() {
+            check_mul::<I>("MAX * -1", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_mul::<I>("-1 * MAX", -console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+            check_mul::<I>("MIN * -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_mul::<I>("-1 * MIN", -console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+            check_mul::<I>(
+                "MIN * -2",
+                console::Integer::MIN,
+                -console::Integer::one() - console::Integer::one(),
+                mode_a,
+                mode_b,
+            );
+            check_mul::<I>(
+                "-2 * MIN",
+                -console::Integer::one() - console::Integer::one(),
+                console::Integer::MIN,
+                mode_a,
+                mode_b,
+            );
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("Mul: ({first} * {second})");
+                check_mul::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, times_overflowing);
+    test_integer_binary!(run_test, i16, times_overflowing);
+    test_integer_binary!(run_test, i32, times_overflowing);
+    test_integer_binary!(run_test, i64, times_overflowing);
+    test_integer_binary!(run_test, i128, times_overflowing);
+
+    test_integer_binary!(run_test, u8, times_overflowing);
+    test_integer_binary!(run_test, u16, times_overflowing);
+    test_integer_binary!(run_test, u32, times_overflowing);
+    test_integer_binary!(run_test, u64, times_overflowing);
+    test_integer_binary!(run_test, u128, times_overflowing);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, times_overflowing, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, times_overflowing, exhaustive);
+}
@@ -74,33 +74,10 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
                 None => E::halt("Integer underflow on subtraction of two constants"),
             }
         } else {
-            // Instead of subtracting the bits of `self` and `other` directly, the integers are
-            // converted into a field elements, and subtracted, before converting back to integers.
-            // Note: This is safe as the field is larger than the maximum integer type supported.
-            let difference = self.to_field() + (!other).to_field() + Field::one();
-
-            // Extract the integer bits from the field element, with a carry bit.
-            let (difference, carry) = match difference.to_lower_bits_le(I::BITS as usize + 1).split_last() {
-                Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
-                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
-                None => E::halt("Malformed difference detected during integer subtraction"),
-            };
-
-            // Check for underflow.
-            match I::is_signed() {
-                // For signed subtraction, overflow and underflow conditions are:
-                //   - a > 0 && b < 0 && a - b > 0 (Overflow)
-                //   - a < 0 && b > 0 && a - b < 0 (Underflow)
-                //   - Note: if sign(a) == sign(b) then over/underflow is impossible.
-                //   - Note: the result of an overflow and underflow must be negative and positive, respectively.
-                true => {
-                    let is_different_signs = self.msb().is_not_equal(other.msb());
-                    let is_underflow = is_different_signs & difference.msb().is_equal(other.msb());
-                    E::assert_eq(is_underflow, E::zero());
-                }
-                // For unsigned subtraction, ensure the carry bit is one.
-                false => E::assert_eq(carry, E::one()),
-            }
+            // Compute the wrapped difference of `self` and `other`, and the underflow flag.
+            let (difference, is_underflow) = self.sub_overflowing(other);
+            // Ensure an underflow did not occur.
+            E::assert_eq(is_underflow, E::zero());
 
             // Return the difference of `self` and `other`.
             difference
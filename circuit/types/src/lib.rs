@@ -17,6 +17,7 @@
 pub use snarkvm_circuit_environment as environment;
 pub use snarkvm_circuit_types_address as address;
 pub use snarkvm_circuit_types_boolean as boolean;
+pub use snarkvm_circuit_types_emulated as emulated;
 pub use snarkvm_circuit_types_field as field;
 pub use snarkvm_circuit_types_group as group;
 pub use snarkvm_circuit_types_integers as integers;
@@ -25,6 +26,7 @@ pub use snarkvm_circuit_types_string as string;
 
 pub use address::Address;
 pub use boolean::Boolean;
+pub use emulated::Emulated;
 pub use environment::prelude::*;
 pub use field::Field;
 pub use group::Group;
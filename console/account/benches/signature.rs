@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_account::{Address, Field, PrivateKey, Signature};
+use snarkvm_console_network::{environment::prelude::*, Testnet3};
+
+use criterion::Criterion;
+
+type CurrentNetwork = Testnet3;
+type Entry = (Vec<Field<CurrentNetwork>>, Signature<CurrentNetwork>, Address<CurrentNetwork>);
+
+const BATCH_SIZES: &[usize] = &[1, 10, 100];
+const MESSAGE_SIZE: usize = 8;
+
+/// Samples the given number of `(message, signature, address)` entries.
+fn sample_entries(num_entries: usize, rng: &mut TestRng) -> Vec<Entry> {
+    (0..num_entries)
+        .map(|_| {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
+            let message: Vec<_> = (0..MESSAGE_SIZE).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng).unwrap();
+            (message, signature, address)
+        })
+        .collect()
+}
+
+fn signature_verify(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for batch_size in BATCH_SIZES {
+        let entries = sample_entries(*batch_size, rng);
+
+        c.bench_function(&format!("signature_verify/{batch_size}"), |b| {
+            b.iter(|| {
+                assert!(entries.iter().all(|(message, signature, address)| signature.verify(address, message)));
+            })
+        });
+    }
+}
+
+fn signature_verify_batch(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for batch_size in BATCH_SIZES {
+        let entries = sample_entries(*batch_size, rng);
+        let batch: Vec<_> =
+            entries.iter().map(|(message, signature, address)| (message.as_slice(), *signature, *address)).collect();
+
+        c.bench_function(&format!("signature_verify_batch/{batch_size}"), |b| {
+            b.iter(|| {
+                assert!(Signature::verify_batch(&batch, rng));
+            })
+        });
+    }
+}
+
+criterion_group! {
+    name = signature;
+    config = Criterion::default().sample_size(10);
+    targets = signature_verify, signature_verify_batch
+}
+
+criterion_main!(signature);
@@ -31,9 +31,9 @@ impl<N: Network> TryFrom<&PrivateKey<N>> for ComputeKey<N> {
     /// Derives the account compute key from an account private key.
     fn try_from(private_key: &PrivateKey<N>) -> Result<Self, Self::Error> {
         // Compute pk_sig := G^sk_sig.
-        let pk_sig = N::g_scalar_multiply(&private_key.sk_sig());
+        let pk_sig = N::g_scalar_multiply_constant_time(&private_key.sk_sig());
         // Compute pr_sig := G^r_sig.
-        let pr_sig = N::g_scalar_multiply(&private_key.r_sig());
+        let pr_sig = N::g_scalar_multiply_constant_time(&private_key.r_sig());
         // Output the compute key.
         Self::try_from((pk_sig, pr_sig))
     }
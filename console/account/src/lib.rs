@@ -46,6 +46,11 @@ pub mod view_key;
 #[cfg(feature = "view_key")]
 pub use view_key::*;
 
+#[cfg(feature = "view_key")]
+pub mod view_key_proof;
+#[cfg(feature = "view_key")]
+pub use view_key_proof::*;
+
 #[cfg(test)]
 mod tests {
     use crate::{Address, ComputeKey, PrivateKey, Signature, ViewKey};
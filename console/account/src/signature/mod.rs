@@ -16,6 +16,8 @@ mod bytes;
 mod parse;
 mod serialize;
 mod verify;
+mod verify_batch;
+pub use verify_batch::SignatureBatchEntry;
 
 #[cfg(feature = "private_key")]
 mod sign;
@@ -27,7 +29,7 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Field, Scalar};
+use snarkvm_console_types::{Field, Group, Scalar};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
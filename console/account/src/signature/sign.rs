@@ -27,7 +27,7 @@ impl<N: Network> Signature<N> {
         // Sample a random nonce from the scalar field.
         let nonce = Scalar::rand(rng);
         // Compute `g_r` as `nonce * G`.
-        let g_r = N::g_scalar_multiply(&nonce);
+        let g_r = N::g_scalar_multiply_constant_time(&nonce);
 
         // Derive the compute key from the private key.
         let compute_key = ComputeKey::try_from(private_key)?;
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A `(message, signature, address)` entry to verify in a batch.
+pub type SignatureBatchEntry<'a, N> = (&'a [Field<N>], Signature<N>, Address<N>);
+
+impl<N: Network> Signature<N> {
+    /// Verifies a batch of `(message, signature, address)` entries, returning `true` only if every signature is valid.
+    ///
+    /// The nonce commitment `G^response pk_sig^challenge` of each signature is an input to the hash of its challenge,
+    /// so it is recomputed for each signature. The conversions of the commitments, compute keys, and addresses
+    /// to affine coordinates share a single field inversion across the batch.
+    /// The address checks, `address == pk_sig + pr_sig + G^sk_prf`, are combined into a single check
+    /// using a random linear combination, which replaces one scalar multiplication per signature
+    /// with one multi-scalar multiplication over 128-bit coefficients.
+    pub fn verify_batch<R: Rng + CryptoRng>(entries: &[SignatureBatchEntry<N>], rng: &mut R) -> bool {
+        // Ensure the number of field elements in each message does not exceed the maximum allowed size.
+        if entries.iter().any(|(message, _, _)| message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize) {
+            eprintln!("Cannot verify the signatures: a signed message exceeds maximum allowed size");
+            return false;
+        }
+
+        // Collect the points of each entry as (g_r, pk_sig, pr_sig, address).
+        let mut points = Vec::with_capacity(4 * entries.len());
+        for (_, signature, address) in entries {
+            // Retrieve pk_sig.
+            let pk_sig = signature.compute_key.pk_sig();
+            // Retrieve pr_sig.
+            let pr_sig = signature.compute_key.pr_sig();
+            // Compute `g_r` := (response * G) + (challenge * pk_sig).
+            let g_r = N::g_scalar_multiply(&signature.response) + (pk_sig * signature.challenge);
+            points.extend([g_r, pk_sig, pr_sig, **address]);
+        }
+        // Compute the x-coordinates of the points, which batches the field inversions.
+        let x_coordinates = Group::to_x_coordinates(&points);
+
+        // Initialize the bases and coefficients of the address checks.
+        let mut bases = Vec::with_capacity(entries.len());
+        let mut coefficients = Vec::with_capacity(entries.len());
+        // Initialize the sum of the coefficients scaled by `sk_prf`.
+        let mut sk_prf_sum = Scalar::<N>::zero();
+
+        for ((message, signature, _), (points, x_coordinates)) in
+            entries.iter().zip_eq(points.chunks_exact(4).zip_eq(x_coordinates.chunks_exact(4)))
+        {
+            // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+            let mut preimage = Vec::with_capacity(4 + message.len());
+            preimage.extend(x_coordinates);
+            preimage.extend(message.iter());
+
+            // Hash to derive the verifier challenge, and return `false` if it is incorrect or this operation fails.
+            match N::hash_to_scalar_psd8(&preimage) {
+                Ok(candidate_challenge) if candidate_challenge == signature.challenge => (),
+                _ => return false,
+            }
+
+            // Sample a random 128-bit coefficient for the address check.
+            let coefficient = Scalar::new(N::Scalar::from(rng.gen::<u128>()));
+            // Prepare the address check as `coefficient * (address - pk_sig - pr_sig)`.
+            bases.push(points[3] - points[1] - points[2]);
+            coefficients.push(coefficient);
+            sk_prf_sum += coefficient * signature.compute_key.sk_prf();
+        }
+
+        // Return `true` if the combined address check holds, i.e. `sum(coefficient * pk_prf) == G^sum(coefficient * sk_prf)`.
+        match Group::msm(&bases, &coefficients) {
+            Ok(candidate) => candidate == N::g_scalar_multiply(&sk_prf_sum),
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type Entry<N> = (Vec<Field<N>>, Signature<N>, Address<N>);
+
+    const ITERATIONS: usize = 10;
+
+    /// Samples a batch of valid `(message, signature, address)` entries.
+    fn sample_entries(num_entries: usize, rng: &mut TestRng) -> Result<Vec<Entry<CurrentNetwork>>> {
+        (0..num_entries)
+            .map(|i| {
+                // Sample an address and a private key.
+                let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+                let address = Address::try_from(&private_key)?;
+                // Sign a message.
+                let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+                let signature = Signature::sign(&private_key, &message, rng)?;
+                Ok((message, signature, address))
+            })
+            .collect()
+    }
+
+    /// Returns the given entries with borrowed messages.
+    fn to_batch<N: Network>(entries: &[Entry<N>]) -> Vec<SignatureBatchEntry<N>> {
+        entries.iter().map(|(message, signature, address)| (message.as_slice(), *signature, *address)).collect()
+    }
+
+    #[test]
+    fn test_verify_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the entries.
+            let entries = sample_entries(i, rng)?;
+            // Check that the batch is valid.
+            assert!(Signature::verify_batch(&to_batch(&entries), rng));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_batch_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 1..ITERATIONS {
+            let entries = sample_entries(i, rng)?;
+            let index = rng.gen_range(0..i);
+
+            // Check that the batch is invalid for an incorrect message.
+            let mut candidate = entries.clone();
+            candidate[index].0.push(Uniform::rand(rng));
+            assert!(!Signature::verify_batch(&to_batch(&candidate), rng));
+
+            // Check that the batch is invalid for an incorrect address.
+            let mut candidate = entries.clone();
+            candidate[index].2 = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!Signature::verify_batch(&to_batch(&candidate), rng));
+
+            // Check that the batch is invalid for a signature from another entry.
+            if i > 1 {
+                let mut candidate = entries.clone();
+                candidate[index].1 = entries[(index + 1) % i].1;
+                assert!(!Signature::verify_batch(&to_batch(&candidate), rng));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_batch_fails_for_mismatching_compute_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 1..ITERATIONS {
+            let mut entries = sample_entries(i, rng)?;
+            let index = rng.gen_range(0..i);
+
+            // Sign the message of one entry for its address, using the compute key of another private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let (message, _, address) = &entries[index];
+            let nonce = Scalar::rand(rng);
+            let mut preimage = vec![];
+            preimage.extend(
+                [CurrentNetwork::g_scalar_multiply(&nonce), compute_key.pk_sig(), compute_key.pr_sig(), **address]
+                    .map(|point| point.to_x_coordinate()),
+            );
+            preimage.extend(message.iter());
+            let challenge = CurrentNetwork::hash_to_scalar_psd8(&preimage)?;
+            let response = nonce - (challenge * private_key.sk_sig());
+            entries[index].1 = Signature::from((challenge, response, compute_key));
+
+            // Check that only the address check fails.
+            let (message, signature, address) = &entries[index];
+            assert_eq!(signature.to_address(), Address::try_from(&private_key)?);
+            assert!(!signature.verify(address, message));
+            // Check that the batch is invalid.
+            assert!(!Signature::verify_batch(&to_batch(&entries), rng));
+        }
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@ use super::*;
 impl<N: Network> ViewKey<N> {
     /// Returns the address corresponding to the view key.
     pub fn to_address(&self) -> Address<N> {
-        Address::new(N::g_scalar_multiply(self))
+        Address::new(N::g_scalar_multiply_constant_time(self))
     }
 }
 
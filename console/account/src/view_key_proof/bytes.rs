@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ViewKeyProof<N> {
+    /// Reads a view key proof from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let challenge = Scalar::new(FromBytes::read_le(&mut reader)?);
+        let response = Scalar::new(FromBytes::read_le(&mut reader)?);
+        Ok(Self { challenge, response })
+    }
+}
+
+impl<N: Network> ToBytes for ViewKeyProof<N> {
+    /// Writes a view key proof to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new proof.
+            let proof = test_helpers::sample_proof(i, &mut rng);
+
+            // Check the byte representation.
+            let proof_bytes = proof.to_bytes_le()?;
+            assert_eq!(proof, ViewKeyProof::read_le(&proof_bytes[..])?);
+            assert!(ViewKeyProof::<CurrentNetwork>::read_le(&proof_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
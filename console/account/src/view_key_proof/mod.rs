@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod prove;
+mod verify;
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+use crate::PrivateKey;
+use crate::ViewKey;
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Scalar};
+
+/// A proof of knowledge of the view key of an address, which does not reveal the view key or private key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ViewKeyProof<N: Network> {
+    /// The verifier challenge to check against.
+    challenge: Scalar<N>,
+    /// The prover response to the challenge.
+    response: Scalar<N>,
+}
+
+impl<N: Network> From<(Scalar<N>, Scalar<N>)> for ViewKeyProof<N> {
+    /// Derives the view key proof from a tuple `(challenge, response)`.
+    fn from((challenge, response): (Scalar<N>, Scalar<N>)) -> Self {
+        Self { challenge, response }
+    }
+}
+
+impl<N: Network> ViewKeyProof<N> {
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a random view key proof.
+    pub(super) fn sample_proof(num_fields: u64, rng: &mut TestRng) -> ViewKeyProof<CurrentNetwork> {
+        // Sample a view key and an address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Generate a proof.
+        let message: Vec<_> = (0..num_fields).map(|_| Uniform::rand(rng)).collect();
+        let proof = ViewKeyProof::prove(&view_key, &message, rng).unwrap();
+        assert!(proof.verify(&address, &message));
+        proof
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new proof.
+            let proof = test_helpers::sample_proof(i, &mut rng);
+
+            // Check that the proof can be reconstructed from its parts.
+            let candidate = ViewKeyProof::from((proof.challenge(), proof.response()));
+            assert_eq!(proof, candidate);
+        }
+        Ok(())
+    }
+}
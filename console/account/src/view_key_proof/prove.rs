@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> ViewKeyProof<N> {
+    /// Returns a proof `(challenge, response)` that the view key corresponds to its address, where:
+    ///     challenge := HashToScalar(domain, nonce * G, address, message)
+    ///     response := nonce - challenge * view_key
+    ///
+    /// The message binds the proof to a context chosen by the verifier, such as a fresh nonce,
+    /// so that the proof may not be replayed to a different verifier.
+    pub fn prove<R: Rng + CryptoRng>(view_key: &ViewKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot prove the view key: the message exceeds maximum allowed size")
+        }
+
+        // Sample a random nonce from the scalar field.
+        let nonce = Scalar::rand(rng);
+        // Compute `g_r` as `nonce * G`.
+        let g_r = N::g_scalar_multiply_constant_time(&nonce);
+
+        // Derive the address from the view key.
+        let address = view_key.to_address();
+
+        // Construct the hash input as (domain, r * G, address, message).
+        let mut preimage = Vec::with_capacity(3 + message.len());
+        preimage.push(N::view_key_proof_domain());
+        preimage.extend([g_r, *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute the prover response.
+        let response = nonce - (challenge * **view_key);
+
+        // Output the proof.
+        Ok(Self { challenge, response })
+    }
+}
+
+impl<N: Network> ViewKey<N> {
+    /// Returns a proof that this view key corresponds to its address, for the given message.
+    pub fn prove_address<R: Rng + CryptoRng>(&self, message: &[Field<N>], rng: &mut R) -> Result<ViewKeyProof<N>> {
+        ViewKeyProof::prove(self, message, rng)
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> ViewKeyProof<N> {
+    /// Verifies (challenge == challenge') where:
+    ///     challenge' := HashToScalar(domain, G^response address^challenge, address, message)
+    pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            eprintln!("Cannot verify the view key proof: the message exceeds maximum allowed size");
+            return false;
+        }
+
+        // Compute `g_r` := (response * G) + (challenge * address).
+        let g_r = N::g_scalar_multiply(&self.response) + (**address * self.challenge);
+
+        // Construct the hash input as (domain, r * G, address, message).
+        let mut preimage = Vec::with_capacity(3 + message.len());
+        preimage.push(N::view_key_proof_domain());
+        preimage.extend([g_r, **address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        match N::hash_to_scalar_psd8(&preimage) {
+            // Return `true` if the candidate challenge is correct.
+            Ok(candidate_challenge) => self.challenge == candidate_challenge,
+            // Return `false` if the challenge errored.
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_prove_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a view key and an address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the proof is valid for the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let proof = view_key.prove_address(&message, rng)?;
+            assert!(proof.verify(&address, &message));
+
+            // Check that the proof is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(!proof.verify(&address, &failure_message));
+            }
+
+            // Check that the proof is invalid for an incorrect address.
+            let failure_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!proof.verify(&failure_address, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "signature")]
+    fn test_signature_is_not_a_proof() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a private key and an address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that a signature on the message does not verify as a view key proof.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = private_key.sign(&message, rng)?;
+            let proof = ViewKeyProof::from((signature.challenge(), signature.response()));
+            assert!(!proof.verify(&address, &message));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> CommitRange for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+    type Value = Field<E>;
+
+    /// Returns the BHP commitment of the `num_bits`-bit little-endian encoding of the given value and randomizer,
+    /// or an error if the value does not lie in the range `[0, 2^num_bits)`.
+    fn commit_range(
+        &self,
+        value: &Self::Value,
+        randomizer: &Self::Randomizer,
+        num_bits: usize,
+    ) -> Result<Self::Output> {
+        // Ensure the range is strictly smaller than the field modulus.
+        ensure!(
+            num_bits <= Field::<E>::size_in_data_bits(),
+            "Invalid range for BHP: expected <= {} bits, found {num_bits}",
+            Field::<E>::size_in_data_bits()
+        );
+        // Ensure the value lies in the range `[0, 2^num_bits)`.
+        let bits = value.to_bits_le();
+        ensure!(bits[num_bits..].iter().all(|bit| !bit), "The value {value} does not fit in {num_bits} bits");
+        // Commit to the lower `num_bits` bits of the value.
+        self.commit(&bits[..num_bits], randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_commit_range() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+
+        let mut rng = TestRng::default();
+
+        for num_bits in [1, 8, 32, 64, 128] {
+            for _ in 0..ITERATIONS {
+                let value = Field::<CurrentEnvironment>::from_u128(u128::rand(&mut rng) >> (128 - num_bits));
+                let randomizer = Uniform::rand(&mut rng);
+
+                // Ensure the commitment matches a commitment to the lower `num_bits` bits.
+                let expected = bhp.commit(&value.to_bits_le()[..num_bits], &randomizer)?;
+                assert_eq!(expected, bhp.commit_range(&value, &randomizer, num_bits)?);
+
+                // Ensure a value outside of the range fails.
+                let value =
+                    value + Field::from_u128(1u128 << (num_bits - 1)) + Field::from_u128(1u128 << (num_bits - 1));
+                assert!(bhp.commit_range(&value, &randomizer, num_bits).is_err());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_range_fails_on_oversized_range() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+
+        let mut rng = TestRng::default();
+        let randomizer = Uniform::rand(&mut rng);
+
+        let num_bits = Field::<CurrentEnvironment>::size_in_data_bits();
+        assert!(bhp.commit_range(&Field::zero(), &randomizer, num_bits).is_ok());
+        assert!(bhp.commit_range(&Field::zero(), &randomizer, num_bits + 1).is_err());
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ pub mod hasher;
 use hasher::BHPHasher;
 
 mod commit;
+mod commit_range;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
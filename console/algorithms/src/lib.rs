@@ -28,8 +28,11 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod p256;
+pub use p256::{P256Point, P256};
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
-pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+pub use poseidon::{AlgebraicSponge, DuplexSpongeMode, Poseidon, Poseidon2, Poseidon4, Poseidon8, PoseidonSponge};
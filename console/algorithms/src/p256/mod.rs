@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod sign;
+mod verify;
+
+use snarkvm_console_types::prelude::*;
+
+use num_bigint::BigUint;
+
+/// A point on the P-256 curve in affine coordinates, where `None` is the point at infinity.
+pub type P256Point = Option<(BigUint, BigUint)>;
+
+/// The NIST P-256 (secp256r1) curve `y^2 = x^3 - 3x + b`, which is used by WebAuthn and passkey authenticators.
+///
+/// The curve arithmetic is implemented over integers, as it is only used to verify signatures.
+/// It is *not* constant-time, and must not be used with secret scalars outside of tests and tooling.
+pub struct P256;
+
+impl P256 {
+    /// Returns the modulus of the base field of the curve.
+    pub fn modulus() -> BigUint {
+        Self::from_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff")
+    }
+
+    /// Returns the order of the curve, which is prime.
+    pub fn order() -> BigUint {
+        Self::from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551")
+    }
+
+    /// Returns the coefficient `b` of the curve.
+    pub fn b() -> BigUint {
+        Self::from_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b")
+    }
+
+    /// Returns the generator of the curve.
+    pub fn generator() -> (BigUint, BigUint) {
+        (
+            Self::from_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+            Self::from_hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"),
+        )
+    }
+
+    /// Returns `true` if the given coordinates are in the base field, and are a point on the curve.
+    pub fn is_on_curve(x: &BigUint, y: &BigUint) -> bool {
+        let p = Self::modulus();
+        if x >= &p || y >= &p {
+            return false;
+        }
+        (y * y) % &p == Self::rhs(x)
+    }
+
+    /// Returns `-point`.
+    pub fn negate(point: &P256Point) -> P256Point {
+        let p = Self::modulus();
+        point.as_ref().map(|(x, y)| (x.clone(), (&p - y) % &p))
+    }
+
+    /// Returns `first + second`.
+    pub fn add(first: &P256Point, second: &P256Point) -> P256Point {
+        let p = Self::modulus();
+        match (first, second) {
+            (None, point) | (point, None) => point.clone(),
+            (Some((x1, y1)), Some((x2, y2))) => {
+                let lambda = match x1 == x2 {
+                    // Return the point at infinity, if the points are inverses.
+                    true if (y1 + y2) % &p == BigUint::default() => return None,
+                    // Compute the slope of the tangent, i.e. `(3x^2 - 3) / 2y`.
+                    true => Self::divide(&(3u64 * x1 * x1 + &p * 3u64 - 3u64), &(2u64 * y1)),
+                    // Compute the slope of the line through the points, i.e. `(y2 - y1) / (x2 - x1)`.
+                    false => Self::divide(&(y2 + &p - y1), &(x2 + &p - x1)),
+                };
+                let x3 = (&lambda * &lambda + &p * 2u64 - x1 - x2) % &p;
+                let y3 = (&lambda * (x1 + &p - &x3) + &p - y1) % &p;
+                Some((x3, y3))
+            }
+        }
+    }
+
+    /// Returns `scalar * point`.
+    pub fn scalar_multiply(point: &P256Point, scalar: &BigUint) -> P256Point {
+        (0..scalar.bits()).rev().fold(None, |accumulator, i| {
+            let accumulator = Self::add(&accumulator, &accumulator);
+            match scalar.bit(i) {
+                true => Self::add(&accumulator, point),
+                false => accumulator,
+            }
+        })
+    }
+
+    /// Returns `x^3 - 3x + b` in the base field.
+    fn rhs(x: &BigUint) -> BigUint {
+        let p = Self::modulus();
+        (x * x * x + &p * 3u64 - 3u64 * x + Self::b()) % &p
+    }
+
+    /// Returns `numerator / denominator` in the base field, or `0` if the denominator is `0`.
+    fn divide(numerator: &BigUint, denominator: &BigUint) -> BigUint {
+        let p = Self::modulus();
+        (numerator * denominator.modpow(&(&p - 2u64), &p)) % &p
+    }
+
+    /// Returns the integer for the given big-endian hex string.
+    fn from_hex(hex: &str) -> BigUint {
+        BigUint::parse_bytes(hex.as_bytes(), 16).expect("Failed to parse a P-256 constant")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        let (x, y) = P256::generator();
+        assert!(P256::is_on_curve(&x, &y));
+        assert!(!P256::is_on_curve(&x, &(&y + 1u64)));
+        assert!(!P256::is_on_curve(&(&x + P256::modulus()), &y));
+
+        // Ensure the generator has the order of the curve.
+        let generator = Some(P256::generator());
+        assert_eq!(None, P256::scalar_multiply(&generator, &P256::order()));
+        assert_eq!(generator, P256::scalar_multiply(&generator, &(P256::order() + 1u64)));
+    }
+
+    #[test]
+    fn test_add() {
+        let generator = Some(P256::generator());
+        let double = P256::add(&generator, &generator);
+        let triple = P256::add(&double, &generator);
+        assert_eq!(triple, P256::scalar_multiply(&generator, &BigUint::from(3u64)));
+        assert_eq!(generator, P256::add(&triple, &P256::negate(&double)));
+        assert_eq!(None, P256::add(&generator, &P256::negate(&generator)));
+        assert_eq!(generator, P256::add(&generator, &None));
+
+        let (x, y) = triple.unwrap();
+        assert!(P256::is_on_curve(&x, &y));
+    }
+}
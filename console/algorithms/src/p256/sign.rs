@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl P256 {
+    /// Returns the public key for the given private key, which must be in `[1, n)`.
+    pub fn public_key(private_key: &BigUint) -> Result<(BigUint, BigUint)> {
+        ensure!(
+            private_key > &BigUint::default() && private_key < &Self::order(),
+            "The P-256 private key is out of range"
+        );
+        match Self::scalar_multiply(&Some(Self::generator()), private_key) {
+            Some(public_key) => Ok(public_key),
+            None => bail!("Failed to derive the P-256 public key"),
+        }
+    }
+
+    /// Returns the ECDSA signature `(r, s)` of the given 256-bit digest, with the given private key and nonce.
+    ///
+    /// The nonce must be sampled uniformly at random from `[1, n)` and never reused, as it reveals the private key.
+    /// The signature is produced as by an authenticator, and is intended for tests and tooling.
+    pub fn sign(private_key: &BigUint, digest: &BigUint, nonce: &BigUint) -> Result<(BigUint, BigUint)> {
+        let n = Self::order();
+        ensure!(private_key > &BigUint::default() && private_key < &n, "The P-256 private key is out of range");
+        ensure!(nonce > &BigUint::default() && nonce < &n, "The P-256 nonce is out of range");
+        ensure!(digest.bits() <= 256, "The P-256 digest exceeds 256 bits");
+
+        // Compute `r := (nonce * G).x mod n`.
+        let r = match Self::scalar_multiply(&Some(Self::generator()), nonce) {
+            Some((x, _)) => x % &n,
+            None => bail!("Failed to sign with the P-256 nonce"),
+        };
+        // Compute `s := nonce^(-1) * (digest + r * private_key) mod n`.
+        let s = (nonce.modpow(&(&n - 2u64), &n) * (digest + &r * private_key)) % &n;
+        ensure!(r != BigUint::default() && s != BigUint::default(), "Failed to sign with the P-256 nonce");
+        Ok((r, s))
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl P256 {
+    /// Returns `true` if `(r, s)` is a valid ECDSA signature of the given 256-bit digest, for the given public key.
+    ///
+    /// This method fails if the public key is not a point on the curve, or if `r` or `s` is not in `[1, n)`.
+    /// To match the circuit, it also fails if the public key is the generator or its inverse,
+    /// or if the signature recovers the point at infinity.
+    pub fn verify(public_key: &(BigUint, BigUint), digest: &BigUint, r: &BigUint, s: &BigUint) -> Result<bool> {
+        let n = Self::order();
+        let zero = BigUint::default();
+
+        // Ensure the inputs are well-formed.
+        let (x, y) = public_key;
+        ensure!(Self::is_on_curve(x, y), "The P-256 public key is not on the curve");
+        ensure!(x != &Self::generator().0, "The P-256 public key must not be the generator or its inverse");
+        ensure!(digest.bits() <= 256, "The P-256 digest exceeds 256 bits");
+        ensure!(r > &zero && r < &n, "The P-256 signature 'r' is out of range");
+        ensure!(s > &zero && s < &n, "The P-256 signature 's' is out of range");
+
+        // Compute `u1 := digest / s mod n`, and `u2 := r / s mod n`.
+        let s_inverse = s.modpow(&(&n - 2u64), &n);
+        let u1 = (digest % &n * &s_inverse) % &n;
+        let u2 = (r * &s_inverse) % &n;
+
+        // Compute `R := u1 * G + u2 * Q`.
+        let point = Self::add(
+            &Self::scalar_multiply(&Some(Self::generator()), &u1),
+            &Self::scalar_multiply(&Some(public_key.clone()), &u2),
+        );
+        // Ensure `R.x mod n == r`.
+        match point {
+            Some((x, _)) => Ok(&(x % &n) == r),
+            None => bail!("The P-256 signature recovers the point at infinity"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Samples a random integer in `[1, n)`.
+    fn sample_scalar(rng: &mut TestRng) -> BigUint {
+        let bytes = (0..40).map(|_| rng.gen()).collect::<Vec<u8>>();
+        BigUint::from_bytes_le(&bytes) % (P256::order() - 1u64) + 1u64
+    }
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = sample_scalar(&mut rng);
+            let public_key = P256::public_key(&private_key)?;
+            let digest = BigUint::from_bytes_le(&(0..32).map(|_| rng.gen()).collect::<Vec<u8>>());
+            let (r, s) = P256::sign(&private_key, &digest, &sample_scalar(&mut rng))?;
+
+            assert!(P256::verify(&public_key, &digest, &r, &s)?);
+            // Ensure the signature does not verify for a different digest or public key.
+            assert!(!P256::verify(&public_key, &(&digest ^ BigUint::from(1u64)), &r, &s)?);
+            let other_key = P256::public_key(&sample_scalar(&mut rng))?;
+            assert!(!P256::verify(&other_key, &digest, &r, &s)?);
+            // Ensure the signature does not verify if it is modified.
+            assert!(!P256::verify(&public_key, &digest, &r, &(&s % (P256::order() - 1u64) + 1u64))?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_fails_on_malformed_inputs() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = sample_scalar(&mut rng);
+        let public_key = P256::public_key(&private_key)?;
+        let digest = BigUint::from(12345u64);
+        let (r, s) = P256::sign(&private_key, &digest, &sample_scalar(&mut rng))?;
+
+        // Ensure the public key must be on the curve.
+        let (x, y) = public_key.clone();
+        assert!(P256::verify(&(x, y + 1u64), &digest, &r, &s).is_err());
+        // Ensure `r` and `s` must be in `[1, n)`.
+        assert!(P256::verify(&public_key, &digest, &BigUint::default(), &s).is_err());
+        assert!(P256::verify(&public_key, &digest, &r, &(&s + P256::order())).is_err());
+        // Ensure the digest must be at most 256 bits.
+        assert!(P256::verify(&public_key, &(BigUint::from(1u64) << 256u32), &r, &s).is_err());
+        Ok(())
+    }
+}
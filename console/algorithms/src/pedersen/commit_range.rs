@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> CommitRange for Pedersen<E, NUM_BITS> {
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+    type Value = Field<E>;
+
+    /// Returns the Pedersen commitment of the `num_bits`-bit little-endian encoding of the given value and randomizer,
+    /// or an error if the value does not lie in the range `[0, 2^num_bits)`.
+    fn commit_range(
+        &self,
+        value: &Self::Value,
+        randomizer: &Self::Randomizer,
+        num_bits: usize,
+    ) -> Result<Self::Output> {
+        // Ensure the range is within the parameter size.
+        ensure!(
+            num_bits <= NUM_BITS as usize,
+            "Invalid range for Pedersen: expected <= {NUM_BITS} bits, found {num_bits}"
+        );
+        // Ensure the value lies in the range `[0, 2^num_bits)`.
+        let bits = value.to_bits_le();
+        ensure!(bits[num_bits..].iter().all(|bit| !bit), "The value {value} does not fit in {num_bits} bits");
+        // Commit to the lower `num_bits` bits of the value.
+        self.commit(&bits[..num_bits], randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_commit_range() -> Result<()> {
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup("PedersenTest");
+
+        let mut rng = TestRng::default();
+
+        for num_bits in [1, 8, 32, 64] {
+            for _ in 0..ITERATIONS {
+                let value = Field::<CurrentEnvironment>::from_u64(u64::rand(&mut rng) >> (64 - num_bits));
+                let randomizer = Uniform::rand(&mut rng);
+
+                // Ensure the commitment matches a commitment to the lower `num_bits` bits.
+                let expected = pedersen.commit(&value.to_bits_le()[..num_bits], &randomizer)?;
+                assert_eq!(expected, pedersen.commit_range(&value, &randomizer, num_bits)?);
+
+                // Ensure a value outside of the range fails.
+                let value = value + Field::from_u128(1u128 << num_bits);
+                assert!(pedersen.commit_range(&value, &randomizer, num_bits).is_err());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_range_fails_on_oversized_range() {
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup("PedersenTest");
+
+        let mut rng = TestRng::default();
+        let randomizer = Uniform::rand(&mut rng);
+
+        assert!(pedersen.commit_range(&Field::zero(), &randomizer, 64).is_ok());
+        assert!(pedersen.commit_range(&Field::zero(), &randomizer, 65).is_err());
+    }
+}
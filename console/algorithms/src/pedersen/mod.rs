@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod commit;
+mod commit_range;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 mod sponge;
-pub(super) use sponge::*;
+pub use sponge::*;
 
 mod state;
 pub(super) use state::*;
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod helpers;
+pub use helpers::{AlgebraicSponge, DuplexSpongeMode, PoseidonSponge};
 
 mod hash;
 mod hash_many;
 mod hash_to_group;
 mod hash_to_scalar;
 mod prf;
+mod sponge;
 
 use crate::{poseidon::helpers::*, Elligator2};
 use snarkvm_console_types::prelude::*;
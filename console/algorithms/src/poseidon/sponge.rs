@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns a new sponge for streaming `absorb` and `squeeze` calls, domain-separated by this instance.
+    ///
+    /// The sponge is initialized with the preimage header `[ DOMAIN || -1 || [0; RATE-2] ]`.
+    /// As no input has a length of `-1`, its outputs are distinct from those of `hash_many`.
+    pub fn sponge(&self) -> PoseidonSponge<E, RATE, CAPACITY> {
+        // Construct the header: [ DOMAIN || -1 || [0; RATE-2] ].
+        let mut header = Vec::with_capacity(RATE);
+        header.push(self.domain);
+        header.push(-Field::<E>::one());
+        header.resize(RATE, Field::<E>::zero()); // Pad up to RATE.
+
+        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
+        sponge.absorb(&header);
+        sponge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const DOMAIN: &str = "PoseidonSponge0";
+    const ITERATIONS: usize = 10;
+
+    fn check_sponge<const RATE: usize>(rng: &mut TestRng) -> Result<()> {
+        let poseidon = Poseidon::<CurrentEnvironment, RATE>::setup(DOMAIN)?;
+
+        for num_inputs in 0..(3 * RATE) {
+            for _ in 0..ITERATIONS {
+                let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<Field<CurrentEnvironment>>>();
+
+                // Absorb the input all at once.
+                let mut expected = poseidon.sponge();
+                expected.absorb(&input);
+
+                // Absorb the input one element at a time.
+                let mut candidate = poseidon.sponge();
+                input.iter().for_each(|element| candidate.absorb(&[*element]));
+
+                // Ensure the streamed outputs match.
+                for num_outputs in 1..u16::try_from(2 * RATE)? {
+                    assert_eq!(expected.squeeze(num_outputs), candidate.squeeze(num_outputs));
+                }
+
+                // Ensure the sponge is distinct from `hash_many`.
+                assert_ne!(poseidon.sponge().squeeze(1)[0], poseidon.hash_many(&input, 1)[0]);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_sponge::<2>(&mut rng)?;
+        check_sponge::<4>(&mut rng)?;
+        check_sponge::<8>(&mut rng)
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Reads in a Merkle tree from the buffer, using the given leaf and path hashers.
+    ///
+    /// The stored internal nodes are restored as-is, and are not rehashed from the leaves.
+    /// Only the root is checked against the stored tree, so the buffer must come from a trusted source.
+    #[inline]
+    pub fn read_le<R: Read>(leaf_hasher: &LH, path_hasher: &PH, mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid Merkle tree version"));
+        }
+
+        // Read the depth.
+        let depth = u8::read_le(&mut reader)?;
+        // Ensure the depth matches the expected depth.
+        if depth != DEPTH {
+            return Err(error(format!("Invalid Merkle tree depth: expected {DEPTH}, found {depth}")));
+        }
+
+        // Read the number of leaves.
+        let number_of_leaves = usize::try_from(u64::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
+        // Compute the expected tree size, as the maximum number of leaves plus the number of nodes.
+        let tree_size = match number_of_leaves.checked_next_power_of_two().and_then(|n| n.checked_mul(2)) {
+            Some(size) => size - 1,
+            None => return Err(error("Integer overflow when computing the Merkle tree size")),
+        };
+        // Ensure the tree size is within the depth bound.
+        let tree_depth = tree_depth::<DEPTH>(tree_size).map_err(|e| error(e.to_string()))?;
+
+        // Read the tree.
+        let tree = (0..tree_size).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the root.
+        let root = Field::read_le(&mut reader)?;
+
+        // Compute the empty hash.
+        let empty_hash = path_hasher.hash_empty().map_err(|e| error(e.to_string()))?;
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = tree[0];
+        for _ in 0..DEPTH - tree_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = path_hasher.hash_children(&root_hash, &empty_hash).map_err(|e| error(e.to_string()))?;
+        }
+        // Ensure the root matches the stored tree.
+        if root_hash != root {
+            return Err(error("The Merkle tree root does not match the stored tree"));
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root,
+            tree,
+            empty_hash,
+            number_of_leaves,
+            checkpoints: Vec::new(),
+        })
+    }
+
+    /// Returns a Merkle tree from a byte array, using the given leaf and path hashers.
+    #[inline]
+    pub fn from_bytes_le(leaf_hasher: &LH, path_hasher: &PH, bytes: &[u8]) -> Result<Self> {
+        Ok(Self::read_le(leaf_hasher, path_hasher, bytes)?)
+    }
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8> ToBytes
+    for MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Writes the Merkle tree to the buffer, excluding the hashers and checkpoints.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the depth.
+        DEPTH.write_le(&mut writer)?;
+        // Write the number of leaves.
+        u64::try_from(self.number_of_leaves).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the tree.
+        self.tree.iter().try_for_each(|node| node.write_le(&mut writer))?;
+        // Write the root.
+        self.root.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+    use snarkvm_console_types::prelude::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_bytes<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+    ) -> Result<()> {
+        let expected = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        let candidate = MerkleTree::<E, LH, PH, DEPTH>::from_bytes_le(leaf_hasher, path_hasher, &expected_bytes)?;
+        assert_eq!(expected.root(), candidate.root());
+        assert_eq!(expected.tree(), candidate.tree());
+        assert_eq!(expected.empty_hash(), candidate.empty_hash());
+        assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+        assert!(MerkleTree::<E, LH, PH, DEPTH>::read_le(leaf_hasher, path_hasher, &expected_bytes[1..]).is_err());
+
+        // Ensure the restored Merkle tree proves the leaves.
+        for (leaf_index, leaf) in leaves.iter().enumerate() {
+            assert_eq!(expected.prove(leaf_index, leaf)?, candidate.prove(leaf_index, leaf)?);
+        }
+
+        // Ensure a Merkle tree with a mismatched root is rejected.
+        let mut invalid_bytes = expected_bytes.clone();
+        let root_start = invalid_bytes.len() - (Field::<E>::size_in_bits() + 7) / 8;
+        invalid_bytes[root_start..].copy_from_slice(&Field::<E>::one().to_bytes_le()?);
+        assert!(MerkleTree::<E, LH, PH, DEPTH>::from_bytes_le(leaf_hasher, path_hasher, &invalid_bytes).is_err());
+        // Ensure a Merkle tree with a mismatched depth is rejected.
+        assert!(MerkleTree::<E, LH, PH, 63>::from_bytes_le(leaf_hasher, path_hasher, &expected_bytes).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_bhp() -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let mut rng = TestRng::default();
+
+        for num_leaves in 0..ITERATIONS {
+            let leaves = (0..num_leaves)
+                .map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le())
+                .collect::<Vec<Vec<bool>>>();
+            check_bytes::<CurrentEnvironment, LH, PH, 32>(&leaf_hasher, &path_hasher, &leaves)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_poseidon() -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let mut rng = TestRng::default();
+
+        for num_leaves in 0..ITERATIONS {
+            let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+            check_bytes::<CurrentEnvironment, LH, PH, 32>(&leaf_hasher, &path_hasher, &leaves)?;
+        }
+        Ok(())
+    }
+}
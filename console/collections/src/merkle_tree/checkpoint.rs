@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A checkpoint of the Merkle tree, used to roll back the changes made since it was created.
+#[derive(Clone)]
+pub(super) struct Checkpoint<H> {
+    /// The root of the Merkle tree at the checkpoint.
+    root: H,
+    /// The number of leaves in the Merkle tree at the checkpoint.
+    number_of_leaves: usize,
+    /// The full Merkle tree at the checkpoint, if the tree has been replaced since the checkpoint.
+    tree: Option<Vec<H>>,
+    /// The original hashes of the nodes overwritten since the checkpoint, in the order they were overwritten.
+    overwritten: Vec<(usize, H)>,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Creates a checkpoint of the Merkle tree, which the changes made after it can be rolled back to.
+    pub fn checkpoint(&mut self) {
+        self.checkpoints.push(Checkpoint {
+            root: self.root,
+            number_of_leaves: self.number_of_leaves,
+            tree: None,
+            overwritten: Vec::new(),
+        });
+    }
+
+    /// Rolls back the Merkle tree to the latest checkpoint, and removes the checkpoint.
+    pub fn rollback(&mut self) -> Result<()> {
+        // Retrieve the latest checkpoint.
+        let checkpoint = match self.checkpoints.pop() {
+            Some(checkpoint) => checkpoint,
+            None => bail!("There is no checkpoint to roll back the Merkle tree to"),
+        };
+        // Restore the full Merkle tree, if it was replaced since the checkpoint.
+        if let Some(tree) = checkpoint.tree {
+            self.tree = tree;
+        }
+        // Restore the overwritten nodes, in the reverse order they were overwritten.
+        for (index, hash) in checkpoint.overwritten.into_iter().rev() {
+            self.tree[index] = hash;
+        }
+        // Restore the root and the number of leaves.
+        self.root = checkpoint.root;
+        self.number_of_leaves = checkpoint.number_of_leaves;
+        Ok(())
+    }
+
+    /// Removes all checkpoints, keeping the changes made since them.
+    pub fn clear_checkpoints(&mut self) {
+        self.checkpoints.clear();
+    }
+
+    /// Returns the number of checkpoints of the Merkle tree.
+    pub fn num_checkpoints(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Sets the node at the given index to the given hash, recording the original hash in the latest checkpoint.
+    pub(super) fn set_node(&mut self, index: usize, hash: PH::Hash) {
+        if let Some(checkpoint) = self.checkpoints.last_mut() {
+            // If the full tree is already stored in the checkpoint, there is no need to record the node.
+            if checkpoint.tree.is_none() {
+                checkpoint.overwritten.push((index, self.tree[index]));
+            }
+        }
+        self.tree[index] = hash;
+    }
+
+    /// Replaces the Merkle tree with the given updated Merkle tree, storing the full tree in the latest checkpoint.
+    pub(super) fn replace(&mut self, updated_tree: Self) {
+        if let Some(checkpoint) = self.checkpoints.last_mut() {
+            // If the full tree is not yet stored in the checkpoint, store the tree as it was at the checkpoint.
+            if checkpoint.tree.is_none() {
+                let mut tree = self.tree.clone();
+                for (index, hash) in checkpoint.overwritten.drain(..).rev() {
+                    tree[index] = hash;
+                }
+                checkpoint.tree = Some(tree);
+            }
+        }
+        self.tree = updated_tree.tree;
+        self.root = updated_tree.root;
+        self.number_of_leaves = updated_tree.number_of_leaves;
+    }
+}
@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
+
+mod checkpoint;
+use checkpoint::Checkpoint;
+
 mod helpers;
 pub use helpers::*;
 
+mod multi_path;
+pub use multi_path::*;
+
 mod path;
 pub use path::*;
 
@@ -44,6 +52,8 @@ pub struct MerkleTree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHas
     empty_hash: Field<E>,
     /// The number of hashed leaves in the tree.
     number_of_leaves: usize,
+    /// The checkpoints of the tree, from oldest to newest, used to roll back recent changes.
+    checkpoints: Vec<Checkpoint<PH::Hash>>,
 }
 
 impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
@@ -116,6 +126,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             tree,
             empty_hash,
             number_of_leaves: leaves.len(),
+            checkpoints: Vec::new(),
         })
     }
 
@@ -191,6 +202,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             tree,
             empty_hash: self.empty_hash,
             number_of_leaves: self.number_of_leaves + new_leaves.len(),
+            checkpoints: Vec::new(),
         })
     }
 
@@ -199,10 +211,18 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn append(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
         let timer = timer!("MerkleTree::append");
 
-        // Compute the updated Merkle tree with the new leaves.
-        let updated_tree = self.prepare_append(new_leaves)?;
-        // Update the tree at the very end, so the original tree is not altered in case of failure.
-        *self = updated_tree;
+        // Compute the maximum number of leaves in the current tree.
+        let max_leaves = self.tree.len() / 2 + 1;
+        match self.number_of_leaves + new_leaves.len() <= max_leaves {
+            // If the new leaves fit in the current tree, only update the paths from the new leaves to the root.
+            true => self.append_in_place(new_leaves)?,
+            false => {
+                // Compute the updated Merkle tree with the new leaves.
+                let updated_tree = self.prepare_append(new_leaves)?;
+                // Update the tree at the very end, so the original tree is not altered in case of failure.
+                self.replace(updated_tree);
+            }
+        }
 
         finish!(timer);
         Ok(())
@@ -216,7 +236,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Compute the updated Merkle tree with the new leaves.
         let updated_tree = self.prepare_update(leaf_index, new_leaf)?;
         // Update the tree at the very end, so the original tree is not altered in case of failure.
-        *self = updated_tree;
+        self.replace(updated_tree);
 
         finish!(timer);
         Ok(())
@@ -296,6 +316,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             tree,
             empty_hash: self.empty_hash,
             number_of_leaves: self.number_of_leaves,
+            checkpoints: Vec::new(),
         })
     }
 
@@ -419,7 +440,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
 
         // Update the rest of the tree with the updated hashes.
         for (index, hash) in updated_hashes.into_iter().flatten() {
-            self.tree[index] = hash;
+            self.set_node(index, hash);
         }
 
         finish!(timer);
@@ -504,6 +525,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             tree,
             empty_hash: self.empty_hash,
             number_of_leaves: updated_number_of_leaves,
+            checkpoints: Vec::new(),
         })
     }
 
@@ -515,7 +537,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Compute the updated Merkle tree with the last 'n' leaves removed.
         let updated_tree = self.prepare_remove_last_n(n)?;
         // Update the tree at the very end, so the original tree is not altered in case of failure.
-        *self = updated_tree;
+        self.replace(updated_tree);
 
         finish!(timer);
         Ok(())
@@ -569,11 +591,48 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         MerklePath::try_from((U64::new(leaf_index as u64), path))
     }
 
+    #[inline]
+    /// Returns the Merkle multi-path for the given leaf indices and leaves.
+    pub fn prove_many(&self, leaves: &BTreeMap<usize, LH::Leaf>) -> Result<MerkleMultiPath<E, DEPTH>> {
+        // Compute the Merkle path for each leaf.
+        let paths =
+            leaves.iter().map(|(leaf_index, leaf)| self.prove(*leaf_index, leaf)).collect::<Result<Vec<_>>>()?;
+        // Retrieve the leaf indices.
+        let leaf_indices = paths.iter().map(|path| path.leaf_index()).collect::<Vec<_>>();
+
+        // Initialize a vector for the Merkle multi-path siblings.
+        let mut siblings = Vec::new();
+        // Initialize a tracker for the path that each current node lies on, starting from the leaves.
+        let mut current_paths = (0..paths.len()).collect::<Vec<_>>();
+        // Iterate from the leaf level to the root level, storing the sibling hashes that cannot be computed.
+        for (level, merges) in MerkleMultiPath::<E, DEPTH>::merges(&leaf_indices).into_iter().enumerate() {
+            let mut next_paths = Vec::with_capacity(merges.len());
+            let mut i = 0;
+            for is_merged in merges {
+                // If the node is not merged, append its sibling hash to the Merkle multi-path.
+                if !is_merged {
+                    siblings.push(paths[current_paths[i]].siblings()[level]);
+                }
+                next_paths.push(current_paths[i]);
+                i += 1 + is_merged as usize;
+            }
+            current_paths = next_paths;
+        }
+
+        // Return the Merkle multi-path.
+        MerkleMultiPath::try_from((leaf_indices, siblings))
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    pub fn verify_many(&self, path: &MerkleMultiPath<E, DEPTH>, root: &PH::Hash, leaves: &[LH::Leaf]) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaves)
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
@@ -607,6 +666,82 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         self.number_of_leaves
     }
 
+    /// Appends the given new leaves to the Merkle tree, by updating only the paths from the new leaves to the root.
+    /// This requires the new leaves to fit in the current tree, and hashes `O(k * log n)` nodes for `k` new leaves.
+    #[inline]
+    fn append_in_place(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        let timer = timer!("MerkleTree::append_in_place");
+
+        // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
+        let start = self.tree.len() / 2;
+        // Ensure the new leaves fit in the current tree.
+        ensure!(
+            start + self.number_of_leaves + new_leaves.len() <= self.tree.len(),
+            "The new leaves do not fit in the current Merkle tree"
+        );
+
+        // Compute the new leaf hashes.
+        let leaf_hashes = self.leaf_hasher.hash_leaves(new_leaves)?;
+        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+
+        // Store the updated hashes by level, starting from the new leaf hashes.
+        let mut updated_hashes: Vec<Vec<(usize, Field<E>)>> = vec![leaf_hashes
+            .into_iter()
+            .enumerate()
+            .map(|(i, hash)| (start + self.number_of_leaves + i, hash))
+            .collect()];
+
+        // Compute the updated hashes for each level, iterating from the leaf level to the root level.
+        loop {
+            // This unwrap is safe, as the updated hashes vector is guaranteed to have at least one element.
+            let level = updated_hashes.last().unwrap();
+            // Determine the range of the parent indices in the next level.
+            let (first, last) = match (level.first(), level.last()) {
+                (Some((first, _)), Some((last, _))) => match (parent(*first), parent(*last)) {
+                    (Some(first), Some(last)) => (first, last),
+                    // If there is no parent, then we have reached the root.
+                    _ => break,
+                },
+                // If there are no updates, then there is nothing to compute.
+                _ => break,
+            };
+            // Retrieve the updated hash for the given index, or the existing hash from the tree.
+            let node = |index: usize| match level.binary_search_by_key(&index, |(index, _)| *index) {
+                Ok(position) => level[position].1,
+                Err(_) => self.tree[index],
+            };
+            // Construct the children for each updated node in the next level.
+            let tuples = (first..=last).map(|i| (node(left_child(i)), node(right_child(i)))).collect::<Vec<_>>();
+            // Compute the hashes for each updated node in the next level.
+            let hashes = self.path_hasher.hash_all_children(&tuples)?;
+            updated_hashes.push((first..=last).zip_eq(hashes).collect());
+        }
+
+        // Compute the padding depth.
+        let padding_depth = DEPTH - tree_depth::<DEPTH>(self.tree.len())?;
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = match updated_hashes.last().and_then(|level| level.first()) {
+            Some((0, hash)) => *hash,
+            _ => self.tree[0],
+        };
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hash)?;
+        }
+        lap!(timer, "Hashed {} padding levels", padding_depth);
+
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        for (index, hash) in updated_hashes.into_iter().flatten() {
+            self.set_node(index, hash);
+        }
+        self.root = root_hash;
+        self.number_of_leaves += new_leaves.len();
+
+        finish!(timer);
+        Ok(())
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
@@ -785,5 +920,9 @@ const fn is_left_child(index: usize) -> bool {
 /// Returns the index of the parent, given the index of a child.
 #[inline]
 const fn parent(index: usize) -> Option<usize> {
-    if index > 0 { Some((index - 1) >> 1) } else { None }
+    if index > 0 {
+        Some((index - 1) >> 1)
+    } else {
+        None
+    }
 }
@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle multi-path, which proves the membership of many leaves in a single proof.
+/// Sibling hashes that can be computed from the other leaves are omitted from the proof.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the path, in strictly increasing order.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains the sibling hashes that are not computed from the leaves,
+    /// ordered by level from the leaf to the root, and by index within each level.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<U64<E>>, Vec<Field<E>>)> for MerkleMultiPath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle multi-path.
+    fn try_from((leaf_indices, siblings): (Vec<U64<E>>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure there is at least one leaf index.
+        ensure!(!leaf_indices.is_empty(), "Merkle multi-path must contain at least one leaf index");
+        // Ensure the leaf indices are strictly increasing.
+        ensure!(leaf_indices.windows(2).all(|pair| pair[0] < pair[1]), "Merkle multi-path leaf indices must be sorted");
+        // Ensure the leaf indices are within the tree depth.
+        for leaf_index in &leaf_indices {
+            ensure!((**leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
+        }
+        // Ensure the Merkle multi-path has the correct number of siblings.
+        ensure!(
+            siblings.len() == Self::num_siblings(&leaf_indices),
+            "Found an incorrect number of Merkle multi-path siblings"
+        );
+        // Return the Merkle multi-path.
+        Ok(Self { leaf_indices, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns the leaf indices for the path.
+    pub fn leaf_indices(&self) -> &[U64<E>] {
+        &self.leaf_indices
+    }
+
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns, for each level from the leaf to the root, whether each node is merged with the next node.
+    /// Two nodes are merged if they are siblings, in which case neither requires a sibling hash in the path.
+    pub fn merges(leaf_indices: &[U64<E>]) -> Vec<Vec<bool>> {
+        let mut indices = leaf_indices.iter().map(|index| **index).collect::<Vec<_>>();
+        let mut merges = Vec::with_capacity(DEPTH as usize);
+        for _ in 0..DEPTH {
+            let mut level = Vec::with_capacity(indices.len());
+            let mut parents = Vec::with_capacity(indices.len());
+            let mut i = 0;
+            while i < indices.len() {
+                // Determine whether the node is a left child, whose sibling is the next node.
+                let is_merged = indices[i] & 1 == 0 && indices.get(i + 1) == Some(&(indices[i] + 1));
+                level.push(is_merged);
+                parents.push(indices[i] >> 1);
+                i += 1 + is_merged as usize;
+            }
+            merges.push(level);
+            indices = parents;
+        }
+        merges
+    }
+
+    /// Returns the number of sibling hashes required for the given leaf indices.
+    fn num_siblings(leaf_indices: &[U64<E>]) -> usize {
+        Self::merges(leaf_indices).iter().flatten().filter(|is_merged| !**is_merged).count()
+    }
+
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    /// The leaves must be given in the same order as the leaf indices.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure the number of leaves matches the number of leaf indices.
+        if leaves.len() != self.leaf_indices.len() {
+            eprintln!("Found an incorrect number of Merkle leaves");
+            return false;
+        }
+
+        // Initialize a tracker for the current hashes, by computing the leaf hashes to start.
+        let mut current_hashes = match leaf_hasher.hash_leaves(leaves) {
+            Ok(leaf_hashes) => leaf_hashes,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaves during verification: {error}");
+                return false;
+            }
+        };
+
+        // Initialize a tracker for the current indices, starting from the leaf indices.
+        let mut current_indices = self.leaf_indices.iter().map(|index| **index).collect::<Vec<_>>();
+        // Initialize an iterator over the sibling hashes.
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between leaf level and root.
+        for merges in Self::merges(&self.leaf_indices) {
+            let mut next_indices = Vec::with_capacity(merges.len());
+            let mut next_hashes = Vec::with_capacity(merges.len());
+            let mut i = 0;
+            for is_merged in merges {
+                // Construct the ordering of the left & right child hash for this node.
+                let (left, right) = match is_merged {
+                    // If the node is merged, then the next node is its sibling.
+                    true => (current_hashes[i], current_hashes[i + 1]),
+                    // Otherwise, order the current hash and sibling hash by the leaf index bit.
+                    false => {
+                        let sibling_hash = match siblings.next() {
+                            Some(sibling_hash) => *sibling_hash,
+                            None => {
+                                eprintln!("Found an incorrect number of Merkle multi-path siblings");
+                                return false;
+                            }
+                        };
+                        match current_indices[i] & 1 == 0 {
+                            true => (current_hashes[i], sibling_hash),
+                            false => (sibling_hash, current_hashes[i]),
+                        }
+                    }
+                };
+                // Compute the index and hash for the next level.
+                next_indices.push(current_indices[i] >> 1);
+                match path_hasher.hash_children(&left, &right) {
+                    Ok(hash) => next_hashes.push(hash),
+                    Err(error) => {
+                        eprintln!("Failed to hash the Merkle multi-path during verification: {error}");
+                        return false;
+                    }
+                }
+                i += 1 + is_merged as usize;
+            }
+            current_indices = next_indices;
+            current_hashes = next_hashes;
+        }
+
+        // Ensure all of the sibling hashes were used, and the final hash matches the given root.
+        siblings.next().is_none() && current_hashes.len() == 1 && current_hashes[0] == *root
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for MerkleMultiPath<E, DEPTH> {
+    /// Reads in a Merkle multi-path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of leaf indices.
+        let num_leaves = u32::read_le(&mut reader)?;
+        // Read the leaf indices.
+        let leaf_indices = (0..num_leaves).map(|_| U64::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the Merkle multi-path siblings.
+        let siblings = (0..Self::num_siblings(&leaf_indices))
+            .map(|_| Field::read_le(&mut reader))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle multi-path.
+        Self::try_from((leaf_indices, siblings)).map_err(|err| error(err.to_string()))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for MerkleMultiPath<E, DEPTH> {
+    /// Writes the Merkle multi-path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of leaf indices.
+        u32::try_from(self.leaf_indices.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the leaf indices.
+        self.leaf_indices.iter().try_for_each(|leaf_index| leaf_index.write_le(&mut writer))?;
+        // Write the Merkle multi-path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
@@ -51,6 +51,18 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         &self.siblings
     }
 
+    /// Returns the Merkle path padded with zero-valued siblings up to the maximum depth `MAX_DEPTH`.
+    /// The padded path is used to verify the leaf against a tree of depth `DEPTH` in a circuit of depth `MAX_DEPTH`.
+    pub fn pad<const MAX_DEPTH: u8>(&self) -> Result<MerklePath<E, MAX_DEPTH>> {
+        // Ensure the maximum depth is at least the tree depth.
+        ensure!(DEPTH <= MAX_DEPTH, "Cannot pad a Merkle path of depth {DEPTH} to depth {MAX_DEPTH}");
+        // Pad the siblings to the maximum depth.
+        let mut siblings = self.siblings.clone();
+        siblings.resize(MAX_DEPTH as usize, Field::zero());
+        // Return the padded Merkle path.
+        MerklePath::try_from((self.leaf_index, siblings))
+    }
+
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
     pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
         &self,
@@ -140,7 +152,7 @@ impl<E: Environment, const DEPTH: u8> Serialize for MerklePath<E, DEPTH> {
 impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for MerklePath<E, DEPTH> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         // Compute the size for: u64 + (Field::SIZE_IN_BYTES * DEPTH).
-        let size = 8 + DEPTH as usize * (Field::<E>::size_in_bits() + 7) / 8;
+        let size = 8 + DEPTH as usize * ((Field::<E>::size_in_bits() + 7) / 8);
         FromBytesDeserializer::<Self>::deserialize(deserializer, "Merkle path", size)
     }
 }
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves, and checkpoint it.
+/// 2. Append, update, and remove leaves in the Merkle tree, checkpointing after each change.
+/// 3. Roll back each change, and check that the Merkle tree matches the tree before the change.
+fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    additional_leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Record the state of the Merkle tree before each change.
+    let mut states = vec![];
+    let mut record = |merkle_tree: &mut MerkleTree<E, LH, PH, DEPTH>| {
+        states.push((*merkle_tree.root(), merkle_tree.tree().to_vec(), merkle_tree.number_of_leaves()));
+        merkle_tree.checkpoint();
+    };
+
+    // Append the additional leaves one at a time.
+    for leaf in additional_leaves {
+        record(&mut merkle_tree);
+        merkle_tree.append(std::slice::from_ref(leaf))?;
+    }
+    // Ensure the Merkle tree matches a Merkle tree constructed from all of the leaves.
+    let all_leaves = leaves.iter().chain(additional_leaves).cloned().collect::<Vec<_>>();
+    let expected = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &all_leaves)?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+
+    if !all_leaves.is_empty() {
+        // Update the first leaf with the last leaf.
+        record(&mut merkle_tree);
+        merkle_tree.update(0, &all_leaves[all_leaves.len() - 1])?;
+
+        // Update many leaves with the first leaf.
+        let updates = (0..all_leaves.len()).step_by(2).map(|i| (i, all_leaves[0].clone())).collect();
+        record(&mut merkle_tree);
+        merkle_tree.update_many(&updates)?;
+
+        // Remove the last leaf.
+        record(&mut merkle_tree);
+        merkle_tree.remove_last_n(1)?;
+    }
+
+    // Roll back each change, and ensure the Merkle tree matches the state before the change.
+    while let Some((root, tree, number_of_leaves)) = states.pop() {
+        merkle_tree.rollback()?;
+        assert_eq!(&root, merkle_tree.root());
+        assert_eq!(&tree, merkle_tree.tree());
+        assert_eq!(number_of_leaves, merkle_tree.number_of_leaves());
+    }
+    assert_eq!(0, merkle_tree.num_checkpoints());
+    assert!(merkle_tree.rollback().is_err());
+
+    // Ensure the rolled back Merkle tree still proves the original leaves.
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        assert!(merkle_tree.verify(&proof, merkle_tree.root(), leaf));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_checkpoint() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in 0..ITERATIONS {
+            for num_additional_leaves in [0, 1, 3, ITERATIONS] {
+                check_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                    &leaf_hasher,
+                    &path_hasher,
+                    &(0..num_leaves)
+                        .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                        .collect::<Vec<Vec<bool>>>(),
+                    &(0..num_additional_leaves)
+                        .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                        .collect::<Vec<Vec<bool>>>(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    run_tests!(&mut rng, [5, 10, 15]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_checkpoint() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in 0..ITERATIONS {
+            for num_additional_leaves in [0, 1, 3, ITERATIONS] {
+                check_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                    &leaf_hasher,
+                    &path_hasher,
+                    &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+                    &(0..num_additional_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    run_tests!(&mut rng, [5, 10, 15, 32]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_append_in_place() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Construct a Merkle tree with capacity for 8 leaves.
+    let leaves = (0..5).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let mut merkle_tree = MerkleTree::<_, LH, PH, 32>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let tree_size = merkle_tree.tree().len();
+
+    // Append leaves that fit in the current tree, and ensure the tree is not resized.
+    let additional_leaves = (0..3).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    merkle_tree.append(&additional_leaves)?;
+    assert_eq!(tree_size, merkle_tree.tree().len());
+
+    // Ensure the Merkle tree matches a Merkle tree constructed from all of the leaves.
+    let all_leaves = leaves.iter().chain(&additional_leaves).cloned().collect::<Vec<_>>();
+    let expected = MerkleTree::<_, LH, PH, 32>::new(&leaf_hasher, &path_hasher, &all_leaves)?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+    assert_eq!(8, merkle_tree.number_of_leaves());
+    Ok(())
+}
@@ -15,7 +15,11 @@
 use super::*;
 
 mod append;
+mod checkpoint;
+mod multi_path;
+mod pad;
 mod remove;
+mod serialize;
 mod update;
 mod update_many;
 
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+use std::collections::BTreeMap;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Prove and verify Merkle multi-paths for random subsets of the leaves.
+/// 3. Check that the Merkle multi-paths do not verify for the wrong root or leaves.
+fn check_merkle_multi_path<
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<CurrentEnvironment>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    rng: &mut TestRng,
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    for _ in 0..ITERATIONS {
+        // Select a random, non-empty subset of the leaves.
+        let mut subset = leaves
+            .iter()
+            .enumerate()
+            .filter(|_| rng.gen_bool(0.5))
+            .map(|(index, leaf)| (index, leaf.clone()))
+            .collect::<BTreeMap<_, _>>();
+        if subset.is_empty() {
+            let index = rng.gen_range(0..leaves.len());
+            subset.insert(index, leaves[index].clone());
+        }
+        let subset_leaves = subset.values().cloned().collect::<Vec<_>>();
+
+        // Prove and verify the Merkle multi-path.
+        let multi_path = merkle_tree.prove_many(&subset)?;
+        assert_eq!(subset.len(), multi_path.leaf_indices().len());
+        assert!(merkle_tree.verify_many(&multi_path, merkle_tree.root(), &subset_leaves));
+        // Ensure the Merkle multi-path is no larger than the individual Merkle paths combined.
+        assert!(multi_path.siblings().len() <= subset.len() * DEPTH as usize);
+
+        // Ensure the Merkle multi-path fails for the wrong root.
+        assert!(!merkle_tree.verify_many(&multi_path, &Field::rand(rng), &subset_leaves));
+        // Ensure the Merkle multi-path fails for the wrong number of leaves.
+        assert!(!merkle_tree.verify_many(&multi_path, merkle_tree.root(), &subset_leaves[1..]));
+        // Ensure the Merkle multi-path fails for the leaves in the wrong order.
+        if subset_leaves.len() > 1 {
+            let mut swapped_leaves = subset_leaves.clone();
+            swapped_leaves.swap(0, 1);
+            assert!(!merkle_tree.verify_many(&multi_path, merkle_tree.root(), &swapped_leaves));
+        }
+
+        // Ensure the Merkle multi-path round-trips through bytes.
+        let bytes = multi_path.to_bytes_le()?;
+        assert_eq!(multi_path, MerkleMultiPath::read_le(&bytes[..])?);
+    }
+
+    // Ensure the Merkle multi-path cannot be proven for a leaf that is not in the tree.
+    if leaves.len() > 1 {
+        assert!(merkle_tree.prove_many(&BTreeMap::from([(0, leaves[1].clone())])).is_err());
+    }
+    // Ensure the Merkle multi-path cannot be proven for no leaves.
+    assert!(merkle_tree.prove_many(&BTreeMap::new()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_bhp() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in [1, 2, 5, 11, 16] {
+        let leaves = (0..num_leaves)
+            .map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le())
+            .collect::<Vec<Vec<bool>>>();
+        check_merkle_multi_path::<LH, PH, 5>(&leaf_hasher, &path_hasher, &leaves, &mut rng)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_poseidon() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in [1, 2, 5, 11, 16] {
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        check_merkle_multi_path::<LH, PH, 5>(&leaf_hasher, &path_hasher, &leaves, &mut rng)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_malformed() {
+    // Ensure the leaf indices must be non-empty, strictly increasing, and in bounds.
+    assert!(MerkleMultiPath::<CurrentEnvironment, 2>::try_from((vec![], vec![])).is_err());
+    assert!(MerkleMultiPath::<CurrentEnvironment, 2>::try_from((vec![U64::new(1), U64::new(1)], vec![])).is_err());
+    assert!(MerkleMultiPath::<CurrentEnvironment, 2>::try_from((vec![U64::new(4)], vec![])).is_err());
+    // Ensure the number of siblings must match the leaf indices.
+    assert!(MerkleMultiPath::<CurrentEnvironment, 2>::try_from((vec![U64::new(0)], vec![Field::zero()])).is_err());
+    assert!(MerkleMultiPath::<CurrentEnvironment, 2>::try_from((vec![U64::new(0)], vec![Field::zero(); 2])).is_ok());
+    assert!(MerkleMultiPath::<CurrentEnvironment, 2>::try_from((vec![U64::new(0), U64::new(1)], vec![Field::zero()]))
+        .is_ok());
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::Poseidon;
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+#[test]
+fn test_merkle_path_pad() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Construct a Merkle tree of depth 4.
+    let leaves = (0..ITERATIONS).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(leaf_index, leaf)?;
+
+        // Pad the Merkle path to depth 8.
+        let padded_path = path.pad::<8>()?;
+        assert_eq!(path.leaf_index(), padded_path.leaf_index());
+        assert_eq!(path.siblings(), &padded_path.siblings()[..4]);
+        assert!(padded_path.siblings()[4..].iter().all(|sibling| sibling.is_zero()));
+
+        // Ensure padding to the same depth is a no-op.
+        assert_eq!(path, path.pad::<4>()?);
+        // Ensure the Merkle path cannot be padded to a smaller depth.
+        assert!(path.pad::<3>().is_err());
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::Poseidon;
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = Poseidon<CurrentEnvironment, 4>;
+type PH = Poseidon<CurrentEnvironment, 2>;
+
+const NUM_LEAVES: usize = 4;
+
+/// Returns the Merkle paths of a Merkle tree with random leaves.
+fn sample_paths<const DEPTH: u8>(rng: &mut TestRng) -> Result<Vec<MerklePath<CurrentEnvironment, DEPTH>>> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..NUM_LEAVES).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    leaves.iter().enumerate().map(|(leaf_index, leaf)| merkle_tree.prove(leaf_index, leaf)).collect()
+}
+
+fn check_serialize<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+    for expected in sample_paths::<DEPTH>(rng)? {
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(&expected_bytes[..], &bincode::serialize(&expected)?[..]);
+        assert_eq!(8 + DEPTH as usize * 32, expected_bytes.len());
+
+        // Deserialize
+        assert_eq!(expected, MerklePath::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes[..])?);
+
+        // Ensure the Merkle path is read exactly, when it is followed by another value.
+        let candidate_bytes = bincode::serialize(&(expected.clone(), u64::MAX))?;
+        let (candidate, trailing): (MerklePath<CurrentEnvironment, DEPTH>, u64) =
+            bincode::deserialize(&candidate_bytes[..])?;
+        assert_eq!((expected.clone(), u64::MAX), (candidate, trailing));
+
+        // Ensure insufficient bytes fail.
+        assert!(bincode::deserialize::<MerklePath<CurrentEnvironment, DEPTH>>(&expected_bytes[1..]).is_err());
+
+        // Ensure the JSON encoding round-trips.
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_path_serialize() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    check_serialize::<2>(&mut rng)?;
+    check_serialize::<4>(&mut rng)?;
+    check_serialize::<32>(&mut rng)?;
+    Ok(())
+}
@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod proof;
+pub use proof::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, MerklePath, PathHash};
+use snarkvm_console_types::prelude::*;
+
+use aleo_std::prelude::*;
+
+use std::collections::BTreeMap;
+
+/// A sparse Merkle tree of depth `DEPTH`, with a leaf slot for every index in `[0, 2^DEPTH)`.
+/// Only the nodes on the paths to occupied leaves are stored; every other node is the empty hash for its level.
+#[derive(Clone)]
+pub struct SparseMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The empty hash for each level, from the leaf level to the root level.
+    empty_hashes: Vec<PH::Hash>,
+    /// The non-empty nodes of the Merkle tree, keyed by their (level, index), where level `0` holds the leaf hashes.
+    nodes: BTreeMap<(u8, u64), PH::Hash>,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    SparseMerkleTree<E, LH, PH, DEPTH>
+{
+    #[inline]
+    /// Initializes a new sparse Merkle tree with the given leaves at the given leaf indices.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[(u64, LH::Leaf)]) -> Result<Self> {
+        let timer = timer!("SparseMerkleTree::new");
+
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the empty hash for each level, starting from the leaf level.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for level in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[level], &empty_hashes[level])?);
+        }
+        lap!(timer, "Hashed {} empty levels", DEPTH);
+
+        // Initialize the Merkle tree.
+        let mut tree = Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            empty_hashes,
+            nodes: BTreeMap::new(),
+        };
+        // Insert each leaf into the Merkle tree.
+        for (leaf_index, leaf) in leaves {
+            tree.insert(*leaf_index, leaf)?;
+        }
+        lap!(timer, "Inserted {} leaves", leaves.len());
+
+        finish!(timer);
+        Ok(tree)
+    }
+
+    #[inline]
+    /// Inserts the given leaf at the given leaf index, replacing the existing leaf if one exists.
+    pub fn insert(&mut self, leaf_index: u64, leaf: &LH::Leaf) -> Result<()> {
+        // Compute the leaf hash.
+        let leaf_hash = self.leaf_hasher.hash_leaf(leaf)?;
+        // Update the path from the leaf to the root.
+        self.update_path(leaf_index, leaf_hash)
+    }
+
+    #[inline]
+    /// Removes the leaf at the given leaf index, resetting it to the empty leaf.
+    pub fn remove(&mut self, leaf_index: u64) -> Result<()> {
+        // Ensure the leaf index is occupied.
+        ensure!(self.contains(leaf_index), "The Merkle leaf index {leaf_index} is not occupied");
+        // Update the path from the leaf to the root.
+        self.update_path(leaf_index, self.empty_hashes[0])
+    }
+
+    /// Returns `true` if the given leaf index is occupied.
+    pub fn contains(&self, leaf_index: u64) -> bool {
+        self.nodes.contains_key(&(0, leaf_index))
+    }
+
+    /// Returns the number of occupied leaves in the Merkle tree.
+    pub fn number_of_leaves(&self) -> usize {
+        self.nodes.range((0, 0)..=(0, u64::MAX)).count()
+    }
+
+    #[inline]
+    /// Returns the Merkle path for the given leaf index and leaf.
+    pub fn prove(&self, leaf_index: u64, leaf: &LH::Leaf) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the leaf index is occupied.
+        ensure!(self.contains(leaf_index), "The Merkle leaf index {leaf_index} is not occupied");
+        // Ensure the leaf hash matches the one in the tree.
+        ensure!(
+            self.node(0, leaf_index) == self.leaf_hasher.hash_leaf(leaf)?,
+            "The given Merkle leaf does not match the one in the Merkle tree"
+        );
+        // Return the Merkle path.
+        self.path(leaf_index)
+    }
+
+    #[inline]
+    /// Returns the non-membership proof for the given leaf index.
+    pub fn prove_non_membership(&self, leaf_index: u64) -> Result<NonMembershipProof<E, DEPTH>> {
+        // Ensure the leaf index is not occupied.
+        ensure!(!self.contains(leaf_index), "The Merkle leaf index {leaf_index} is occupied");
+        // Return the non-membership proof.
+        Ok(NonMembershipProof::new(self.path(leaf_index)?))
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
+    }
+
+    /// Returns `true` if the given non-membership proof is valid for the given root.
+    pub fn verify_non_membership(&self, proof: &NonMembershipProof<E, DEPTH>, root: &PH::Hash) -> bool {
+        proof.verify(&self.path_hasher, root)
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub fn root(&self) -> &PH::Hash {
+        self.nodes.get(&(DEPTH, 0)).unwrap_or(&self.empty_hashes[DEPTH as usize])
+    }
+
+    /// Returns the empty hash for the leaf level.
+    pub fn empty_hash(&self) -> &PH::Hash {
+        &self.empty_hashes[0]
+    }
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    SparseMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Returns the node at the given level and index.
+    fn node(&self, level: u8, index: u64) -> PH::Hash {
+        self.nodes.get(&(level, index)).copied().unwrap_or(self.empty_hashes[level as usize])
+    }
+
+    /// Ensures the given leaf index is within the tree depth.
+    fn check_leaf_index(leaf_index: u64) -> Result<()> {
+        ensure!((leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
+        Ok(())
+    }
+
+    /// Returns the Merkle path for the given leaf index.
+    fn path(&self, leaf_index: u64) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the leaf index is within the tree depth.
+        Self::check_leaf_index(leaf_index)?;
+        // Collect the sibling hashes along the path, from the leaf level to the root level.
+        let siblings = (0..DEPTH).map(|level| self.node(level, (leaf_index >> level) ^ 1)).collect();
+        // Return the Merkle path.
+        MerklePath::try_from((U64::new(leaf_index), siblings))
+    }
+
+    /// Updates the nodes along the path from the given leaf index to the root, starting from the given leaf hash.
+    fn update_path(&mut self, leaf_index: u64, leaf_hash: PH::Hash) -> Result<()> {
+        // Ensure the leaf index is within the tree depth.
+        Self::check_leaf_index(leaf_index)?;
+
+        // Compute the new hashes for the path from the leaf to the root.
+        let mut path_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        path_hashes.push(leaf_hash);
+        for level in 0..DEPTH {
+            let index = leaf_index >> level;
+            // Retrieve the current hash and sibling hash.
+            // This unwrap is safe, as the path hashes vector is guaranteed to have at least one element.
+            let current_hash = path_hashes.last().unwrap();
+            let sibling_hash = self.node(level, index ^ 1);
+            // Order the current and sibling hashes.
+            let (left, right) = match index & 1 == 0 {
+                true => (current_hash, &sibling_hash),
+                false => (&sibling_hash, current_hash),
+            };
+            // Compute and add the new parent hash to the path hashes.
+            path_hashes.push(self.path_hasher.hash_children(left, right)?);
+        }
+
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        for (level, hash) in (0..=DEPTH).zip_eq(path_hashes) {
+            // Compute the index of the node on this level, which saturates to `0` at the root of a 64-level tree.
+            let key = (level, leaf_index.checked_shr(level as u32).unwrap_or(0));
+            // Only store the nodes that differ from the empty hash for their level.
+            match hash == self.empty_hashes[level as usize] {
+                true => self.nodes.remove(&key),
+                false => self.nodes.insert(key, hash),
+            };
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A proof that a leaf index in a sparse Merkle tree is not occupied,
+/// given as the Merkle path from the empty leaf at the leaf index to the root.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonMembershipProof<E: Environment, const DEPTH: u8> {
+    /// The Merkle path for the empty leaf.
+    path: MerklePath<E, DEPTH>,
+}
+
+impl<E: Environment, const DEPTH: u8> NonMembershipProof<E, DEPTH> {
+    /// Initializes a new non-membership proof from the Merkle path for the empty leaf.
+    pub const fn new(path: MerklePath<E, DEPTH>) -> Self {
+        Self { path }
+    }
+
+    /// Returns the leaf index that is not occupied.
+    pub fn leaf_index(&self) -> U64<E> {
+        self.path.leaf_index()
+    }
+
+    /// Returns the Merkle path for the empty leaf.
+    pub const fn path(&self) -> &MerklePath<E, DEPTH> {
+        &self.path
+    }
+
+    /// Returns `true` if the leaf index is not occupied in the sparse Merkle tree with the given root.
+    pub fn verify<PH: PathHash<Hash = Field<E>>>(&self, path_hasher: &PH, root: &PH::Hash) -> bool {
+        // Initialize a tracker for the current hash, by computing the empty leaf hash to start.
+        let mut current_hash = match path_hasher.hash_empty() {
+            Ok(empty_hash) => empty_hash,
+            Err(error) => {
+                eprintln!("Failed to hash the empty Merkle leaf during verification: {error}");
+                return false;
+            }
+        };
+
+        // Compute the ordering of the current hash and sibling hash on each level.
+        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+        let leaf_index = *self.path.leaf_index();
+        let indicators = (0..DEPTH).map(|i| ((leaf_index >> i) & 1) == 0);
+
+        // Check levels between leaf level and root.
+        for (indicator, sibling_hash) in indicators.zip_eq(self.path.siblings()) {
+            // Construct the ordering of the left & right child hash for this level.
+            let (left, right) = match indicator {
+                true => (current_hash, *sibling_hash),
+                false => (*sibling_hash, current_hash),
+            };
+            // Update the current hash for the next level.
+            match path_hasher.hash_children(&left, &right) {
+                Ok(hash) => current_hash = hash,
+                Err(error) => {
+                    eprintln!("Failed to hash the Merkle path during verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Ensure the final hash matches the given root.
+        current_hash == *root
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for NonMembershipProof<E, DEPTH> {
+    /// Reads in a non-membership proof from a buffer.
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self::new(MerklePath::read_le(reader)?))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for NonMembershipProof<E, DEPTH> {
+    /// Writes the non-membership proof to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.path.write_le(writer)
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Construct the sparse Merkle tree for the leaves.
+/// 2. Check the membership proof of each leaf.
+/// 3. Check the non-membership proof of each unoccupied leaf index.
+/// 4. Remove the leaves, and check the tree is empty.
+fn check_sparse_merkle_tree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[(u64, LH::Leaf)],
+    unoccupied: &[u64],
+) -> Result<()> {
+    // Construct the empty sparse Merkle tree.
+    let empty_tree = SparseMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    // Construct the sparse Merkle tree for the given leaves.
+    let mut merkle_tree = SparseMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    assert_eq!(leaves.len(), merkle_tree.number_of_leaves());
+
+    // Ensure the root is independent of the insertion order.
+    let reversed = leaves.iter().rev().cloned().collect::<Vec<_>>();
+    let reversed_tree = SparseMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &reversed)?;
+    assert_eq!(merkle_tree.root(), reversed_tree.root());
+
+    // Check the membership proof of each leaf.
+    for (leaf_index, leaf) in leaves {
+        let proof = merkle_tree.prove(*leaf_index, leaf)?;
+        assert!(merkle_tree.verify(&proof, merkle_tree.root(), leaf));
+        // Ensure a non-membership proof cannot be produced for an occupied leaf index.
+        assert!(merkle_tree.prove_non_membership(*leaf_index).is_err());
+    }
+
+    // Check the non-membership proof of each unoccupied leaf index.
+    for leaf_index in unoccupied {
+        let proof = merkle_tree.prove_non_membership(*leaf_index)?;
+        assert_eq!(*leaf_index, *proof.leaf_index());
+        assert!(merkle_tree.verify_non_membership(&proof, merkle_tree.root()));
+        // Ensure the non-membership proof is not valid for a different root.
+        if !leaves.is_empty() {
+            assert!(!merkle_tree.verify_non_membership(&proof, empty_tree.root()));
+        }
+        // Ensure the non-membership proof round-trips through bytes.
+        assert_eq!(proof, NonMembershipProof::read_le(&proof.to_bytes_le()?[..])?);
+    }
+
+    // Remove the leaves, and ensure the tree is empty.
+    for (leaf_index, _) in leaves {
+        merkle_tree.remove(*leaf_index)?;
+        assert!(!merkle_tree.contains(*leaf_index));
+    }
+    assert_eq!(0, merkle_tree.number_of_leaves());
+    assert_eq!(empty_tree.root(), merkle_tree.root());
+    assert!(merkle_tree.nodes.is_empty());
+
+    Ok(())
+}
+
+/// Samples the given number of leaves at distinct leaf indices, and the given number of unoccupied leaf indices.
+fn sample_leaves<L, const DEPTH: u8>(
+    num_leaves: usize,
+    num_unoccupied: usize,
+    sample_leaf: impl Fn(&mut TestRng) -> L,
+    rng: &mut TestRng,
+) -> (Vec<(u64, L)>, Vec<u64>) {
+    let max_index = u64::MAX >> (64 - DEPTH);
+
+    let mut indices = std::collections::BTreeSet::new();
+    while indices.len() < num_leaves + num_unoccupied {
+        indices.insert(u64::rand(rng) & max_index);
+    }
+    let mut indices = indices.into_iter().collect::<Vec<_>>();
+
+    let unoccupied = indices.split_off(num_leaves);
+    let leaves = indices.into_iter().map(|index| (index, sample_leaf(rng))).collect();
+    (leaves, unoccupied)
+}
+
+#[test]
+fn test_sparse_merkle_tree_bhp() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in 0..ITERATIONS {
+        let (leaves, unoccupied) = sample_leaves::<_, 32>(
+            num_leaves,
+            ITERATIONS,
+            |rng| Field::<CurrentEnvironment>::rand(rng).to_bits_le(),
+            &mut rng,
+        );
+        check_sparse_merkle_tree::<CurrentEnvironment, LH, PH, 32>(&leaf_hasher, &path_hasher, &leaves, &unoccupied)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_poseidon() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in 0..ITERATIONS {
+        let (leaves, unoccupied) =
+            sample_leaves::<_, 64>(num_leaves, ITERATIONS, |rng| vec![Uniform::rand(rng)], &mut rng);
+        check_sparse_merkle_tree::<CurrentEnvironment, LH, PH, 64>(&leaf_hasher, &path_hasher, &leaves, &unoccupied)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_insert_replaces_leaf() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    let first_leaf = vec![Uniform::rand(&mut rng)];
+    let second_leaf = vec![Uniform::rand(&mut rng)];
+
+    // Insert the first leaf, and then replace it with the second leaf.
+    let mut merkle_tree =
+        SparseMerkleTree::<_, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &[(3, first_leaf.clone())])?;
+    merkle_tree.insert(3, &second_leaf)?;
+    assert_eq!(1, merkle_tree.number_of_leaves());
+
+    // Ensure the tree matches a tree constructed from the second leaf.
+    let expected = SparseMerkleTree::<_, LH, PH, 8>::new(&leaf_hasher, &path_hasher, &[(3, second_leaf.clone())])?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert!(merkle_tree.prove(3, &first_leaf).is_err());
+    assert!(merkle_tree.prove(3, &second_leaf).is_ok());
+
+    // Ensure leaf indices beyond the tree depth are rejected.
+    assert!(merkle_tree.insert(256, &first_leaf).is_err());
+    assert!(merkle_tree.prove_non_membership(256).is_err());
+    // Ensure an unoccupied leaf index cannot be removed.
+    assert!(merkle_tree.remove(4).is_err());
+    Ok(())
+}
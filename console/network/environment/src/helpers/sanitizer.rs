@@ -12,16 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{string_parser::is_char_supported, ParserResult};
+use crate::{string_parser::is_char_supported, ParserError, ParserResult};
 
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{anychar, char, line_ending, multispace1},
-    combinator::{cut, map, recognize, value, verify},
-    error::{ErrorKind, VerboseError, VerboseErrorKind},
-    multi::fold_many0,
-    sequence::{preceded, terminated},
+    combinator::{cut, map, not, recognize, value, verify},
+    error::ErrorKind,
+    multi::{fold_many0, many0},
+    sequence::{pair, preceded, terminated},
 };
 
 pub struct Sanitizer;
@@ -50,6 +50,28 @@ impl Sanitizer {
         )(string)
     }
 
+    /// Removes all leading whitespaces and comments from the given input, returning the doc comments.
+    ///
+    /// A doc comment is a line comment that starts with exactly three slashes, i.e. `/// Mints credits.`,
+    /// and is returned without the slashes and the single space that follows them.
+    pub fn parse_doc_comments(string: &str) -> ParserResult<Vec<&str>> {
+        let (string, _) = Self::parse_whitespaces(string)?;
+        map(
+            many0(terminated(
+                alt((map(Self::parse_doc_comment, Some), map(Self::parse_comment, |_| None))),
+                Self::parse_whitespaces,
+            )),
+            |comments| comments.into_iter().flatten().collect(),
+        )(string)
+    }
+
+    /// Removes the first leading doc comment from the given input, returning its contents.
+    pub fn parse_doc_comment(string: &str) -> ParserResult<&str> {
+        map(preceded(pair(tag("///"), not(char('/'))), cut(Self::str_till_eol)), |line| {
+            line.strip_prefix(' ').unwrap_or(line)
+        })(string)
+    }
+
     /// Parse a safe character (in the sense explained in [string_parser::is_char_supported]).
     /// Returns an error if no character is found or a non-safe character is found.
     /// The character is returned, along with the remaining input.
@@ -77,9 +99,7 @@ impl Sanitizer {
     fn eoi(string: &str) -> ParserResult<()> {
         match string.is_empty() {
             true => Ok((string, ())),
-            false => {
-                Err(nom::Err::Error(VerboseError { errors: vec![(string, VerboseErrorKind::Nom(ErrorKind::Eof))] }))
-            }
+            false => Err(nom::Err::Error(ParserError::new(string, ErrorKind::Eof))),
         }
     }
 
@@ -119,7 +139,11 @@ impl Sanitizer {
         map(
             recognize(Self::till(alt((value((), tag("\\\n")), value((), Sanitizer::parse_safe_char))), Self::eol)),
             |i| {
-                if i.as_bytes().last() == Some(&b'\n') { &i[0..i.len() - 1] } else { i }
+                if i.as_bytes().last() == Some(&b'\n') {
+                    &i[0..i.len() - 1]
+                } else {
+                    i
+                }
             },
         )(string)
     }
@@ -262,4 +286,20 @@ mod tests {
         assert!(Sanitizer::parse_comments("/** hel\x00lo */\nhello world").is_err());
         assert!(Sanitizer::parse_comments("/** hel\u{202a}lo */\nhello world").is_err());
     }
+
+    #[test]
+    fn test_doc_comments() {
+        assert_eq!(("hello world", vec![]), Sanitizer::parse_doc_comments("// hello\nhello world").unwrap());
+        assert_eq!(("hello world", vec!["hello"]), Sanitizer::parse_doc_comments("/// hello\nhello world").unwrap());
+        assert_eq!(
+            ("hello world", vec!["hello", "", "  world"]),
+            Sanitizer::parse_doc_comments("  /// hello\n///\n///   world\nhello world").unwrap()
+        );
+        assert_eq!(
+            ("hello world", vec!["hello", "world"]),
+            Sanitizer::parse_doc_comments("/// hello\n// skipped\n/* skipped */\n//// skipped\n/// world\nhello world")
+                .unwrap()
+        );
+        assert!(Sanitizer::parse_doc_comments("/// hel\x08lo\nhello world").is_err());
+    }
 }
@@ -15,7 +15,7 @@
 pub use super::*;
 
 pub use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
-pub use snarkvm_fields::{Field as _, PrimeField as _, SquareRootField as _, Zero as _};
+pub use snarkvm_fields::{batch_inversion, Field as _, PrimeField as _, SquareRootField as _, Zero as _};
 pub use snarkvm_utilities::{
     cfg_into_iter,
     cfg_iter,
@@ -27,10 +27,12 @@ pub use snarkvm_utilities::{
     FromBits as _,
     FromBytes,
     FromBytesDeserializer,
+    FromHexDeserializer,
     TestRng,
     ToBits as _,
     ToBytes,
     ToBytesSerializer,
+    ToHexSerializer,
     Uniform,
 };
 
@@ -76,6 +78,7 @@ pub use nom::{
     bytes::{complete::tag, streaming::take},
     character::complete::{alpha1, alphanumeric1, char, one_of},
     combinator::{complete, fail, map, map_res, opt, recognize},
+    error::context,
     multi::{many0, many1, separated_list0, separated_list1},
     sequence::{pair, terminated},
 };
@@ -24,6 +24,18 @@ pub trait Commit {
     fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output>;
 }
 
+/// A trait for a commitment scheme that commits to a value lying in the range `[0, 2^num_bits)`.
+pub trait CommitRange {
+    type Value;
+    type Output;
+    type Randomizer;
+
+    /// Returns the commitment to the `num_bits`-bit little-endian encoding of the given value and randomizer,
+    /// or an error if the value does not lie in the range `[0, 2^num_bits)`.
+    fn commit_range(&self, value: &Self::Value, randomizer: &Self::Randomizer, num_bits: usize)
+        -> Result<Self::Output>;
+}
+
 /// A trait for a commitment scheme.
 pub trait CommitUncompressed {
     type Input;
@@ -21,6 +21,13 @@ pub trait AddChecked<Rhs: ?Sized = Self> {
     fn add_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for adding two values, returning the wrapped sum and whether an overflow occurred.
+pub trait AddOverflowing<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn add_overflowing(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for adding two values, bounding the sum to `MAX` if an overflow occurs.
 pub trait AddSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -77,6 +84,13 @@ pub trait MulChecked<Rhs: ?Sized = Self> {
     fn mul_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for multiplying two values, returning the wrapped product and whether an overflow occurred.
+pub trait MulOverflowing<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn mul_overflowing(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for multiplying two values, bounding the product to `MAX` if an overflow occurs.
 pub trait MulSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -163,6 +177,13 @@ pub trait SubChecked<Rhs: ?Sized = Self> {
     fn sub_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for subtracting two values, returning the wrapped difference and whether an underflow occurred.
+pub trait SubOverflowing<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn sub_overflowing(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for subtracting two values, bounding the difference to `MIN` if an underflow occurs.
 pub trait SubSaturating<Rhs: ?Sized = Self> {
     type Output;
@@ -13,13 +13,71 @@
 // limitations under the License.
 
 use nom::{
-    error::{convert_error, VerboseError},
+    error::{convert_error, ContextError, ErrorKind, FromExternalError, ParseError, VerboseError, VerboseErrorKind},
     Err as NomErr,
     IResult,
 };
 
 /// The `nom`-compatible parser return type.
-pub type ParserResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+pub type ParserResult<'a, O> = IResult<&'a str, O, ParserError<'a>>;
+
+/// The `nom`-compatible parser error.
+///
+/// Unlike `VerboseError`, when all branches of an `alt` fail, the error that progressed furthest
+/// into the input is retained, so that the error points to the mistake, and not the start of the statement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParserError<'a>(VerboseError<&'a str>);
+
+impl<'a> ParserError<'a> {
+    /// Initializes a new parser error for the given input and error kind.
+    pub fn new(input: &'a str, kind: ErrorKind) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+
+    /// Returns the errors, from the innermost parser to the outermost parser.
+    pub fn errors(&self) -> &[(&'a str, VerboseErrorKind)] {
+        &self.0.errors
+    }
+
+    /// Returns the length of the input remaining at the innermost error.
+    fn remaining(&self) -> usize {
+        self.0.errors.first().map(|(input, _)| input.len()).unwrap_or(usize::MAX)
+    }
+}
+
+impl<'a> ParseError<&'a str> for ParserError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self(VerboseError::from_error_kind(input, kind))
+    }
+
+    fn append(input: &'a str, kind: ErrorKind, other: Self) -> Self {
+        Self(VerboseError::append(input, kind, other.0))
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self(VerboseError::from_char(input, c))
+    }
+
+    /// Returns the error that progressed furthest into the input, preferring the latter error on a tie.
+    fn or(self, other: Self) -> Self {
+        match self.remaining() < other.remaining() {
+            true => self,
+            false => other,
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for ParserError<'a> {
+    fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+        Self(VerboseError::add_context(input, context, other.0))
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for ParserError<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, error: E) -> Self {
+        Self(VerboseError::from_external_error(input, kind, error))
+    }
+}
 
 /// Converts a `ParserResult` into a human-readable message.
 pub fn convert_result<'a, O>(result: ParserResult<'a, O>, input: &'a str) -> String {
@@ -27,11 +85,145 @@ pub fn convert_result<'a, O>(result: ParserResult<'a, O>, input: &'a str) -> Str
         Ok(_) => "Parsing was successful.".to_string(),
         Err(error) => match error {
             NomErr::Incomplete(_) => "Parsing failed to consume the entire input.".to_string(),
-            NomErr::Error(err) | NomErr::Failure(err) => convert_error(input, err),
+            NomErr::Error(err) | NomErr::Failure(err) => convert_error(input, err.0),
         },
     }
 }
 
+/// A diagnostic for a string that failed to parse, with the line and column of the mistake.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParserDiagnostic {
+    /// The line of the mistake, starting from 1.
+    line: usize,
+    /// The column of the mistake, in characters, starting from 1.
+    column: usize,
+    /// The description of what was expected, if it is known.
+    expected: Option<String>,
+    /// The token that was found, or an empty string at the end of the input.
+    found: String,
+    /// The description of the mistake, if the string parsed but is invalid.
+    message: Option<String>,
+}
+
+impl ParserDiagnostic {
+    /// Returns the diagnostic for the given parser error on the given input.
+    ///
+    /// The diagnostic points to the innermost error, and is described by the outermost parser
+    /// labelled with `context` that failed at the same position, i.e. `expected operand`.
+    pub fn new(input: &str, error: &NomErr<ParserError>) -> Self {
+        match error {
+            NomErr::Incomplete(_) => Self::at(input, "", None),
+            NomErr::Error(error) | NomErr::Failure(error) => match error.errors().first() {
+                Some((remaining, kind)) => {
+                    // Retrieve the outermost label of a parser that failed at the same position, if one exists.
+                    let label = error.errors().iter().rev().find_map(|(input, kind)| match kind {
+                        VerboseErrorKind::Context(context) if input.len() == remaining.len() => Some(context),
+                        _ => None,
+                    });
+                    // Describe what was expected, from the label or the expected character.
+                    let expected = match (label, kind) {
+                        (Some(context), _) => Some(context.to_string()),
+                        (None, VerboseErrorKind::Char(c)) => Some(format!("'{c}'")),
+                        (None, _) => None,
+                    };
+                    Self::at(input, remaining, expected)
+                }
+                None => Self::at(input, input, None),
+            },
+        }
+    }
+
+    /// Returns the diagnostic for the given remainder of the input, which the parser did not consume.
+    pub fn remainder(input: &str, remainder: &str) -> Self {
+        Self::at(input, remainder, None)
+    }
+
+    /// Returns the diagnostic for the given remainder of the input, which the parser did not consume,
+    /// using the error that progressed furthest from parsing the remainder as one of the expected statements.
+    pub fn remainder_with<'a>(
+        input: &'a str,
+        remainder: &'a str,
+        errors: impl IntoIterator<Item = Option<NomErr<ParserError<'a>>>>,
+    ) -> Self {
+        errors
+            .into_iter()
+            .flatten()
+            .filter_map(|error| match error {
+                NomErr::Error(error) | NomErr::Failure(error) => Some(error),
+                NomErr::Incomplete(_) => None,
+            })
+            .reduce(ParseError::or)
+            .map(|error| Self::new(input, &NomErr::Error(error)))
+            .unwrap_or_else(|| Self::remainder(input, remainder))
+    }
+
+    /// Returns the diagnostic for a string that parsed but is invalid, with the given description of the mistake
+    /// at the start of the given remaining input, i.e. `'transfer' is already in use at line 12:1`.
+    pub fn error(input: &str, remaining: &str, message: impl core::fmt::Display) -> Self {
+        // Remove the trailing period of the description, as the position is appended to it.
+        let message = message.to_string().trim_end_matches('.').to_string();
+        Self { message: Some(message), ..Self::at(input, remaining, None) }
+    }
+
+    /// Returns the line of the mistake, starting from 1.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the mistake, in characters, starting from 1.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the description of what was expected, if it is known.
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    /// Returns the token that was found, or an empty string at the end of the input.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// Returns the description of the mistake, if the string parsed but is invalid.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Initializes the diagnostic at the start of the given remaining input.
+    fn at(input: &str, remaining: &str, expected: Option<String>) -> Self {
+        // Compute the offset of the remaining input, which is a suffix of the input.
+        let offset = input.len().saturating_sub(remaining.len());
+        let consumed = input.get(..offset).unwrap_or(input);
+        // Compute the line and column of the offset.
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.rsplit('\n').next().map(|line| line.chars().count()).unwrap_or(0) + 1;
+        // Retrieve the token at the offset, which ends at the next whitespace or delimiter.
+        let found = remaining
+            .split(|c: char| c.is_whitespace() || c == ';' || c == ',')
+            .next()
+            .filter(|token| !token.is_empty())
+            .or_else(|| remaining.chars().next().map(|c| &remaining[..c.len_utf8()]))
+            .unwrap_or("")
+            .to_string();
+        Self { line, column, expected, found, message: None }
+    }
+}
+
+impl core::fmt::Display for ParserDiagnostic {
+    /// Prints the diagnostic, i.e. `expected register, found 'r1u8' at line 42:17`.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match (&self.message, &self.expected, self.found.is_empty()) {
+            (Some(message), _, _) => write!(f, "{message}")?,
+            (None, Some(expected), false) => write!(f, "expected {expected}, found '{}'", self.found)?,
+            (None, Some(expected), true) => write!(f, "expected {expected}, found the end of input")?,
+            (None, None, false) => write!(f, "unexpected '{}'", self.found)?,
+            (None, None, true) => write!(f, "unexpected end of input")?,
+        }
+        write!(f, " at line {}:{}", self.line, self.column)
+    }
+}
+
 /// Operations to parse a string literal into an object.
 pub trait Parser: core::fmt::Display + core::str::FromStr {
     /// Parses a string literal into an object.
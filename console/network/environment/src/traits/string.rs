@@ -78,7 +78,11 @@ pub mod string_parser {
         // A "divide and conquer" approach for greater performance; ranges are
         // checked before single values and all the comparisons get "reused".
         if code < 0x202a {
-            if code <= 31 { !(9..14).contains(&code) || code == 11 || code == 12 } else { code == 127 }
+            if code <= 31 {
+                !(9..14).contains(&code) || code == 11 || code == 12
+            } else {
+                code == 127
+            }
         } else {
             code <= 0x202e || code >= 0x2066
         }
@@ -228,7 +232,7 @@ pub mod string_parser {
 
 #[test]
 fn test_parse_string() {
-    // to use parse_string_wrapper instead of string_parser::parse_string::<nom::error::VerboseError<&str>> in the tests below:
+    // to use parse_string_wrapper instead of string_parser::parse_string::<crate::ParserError> in the tests below:
     fn parse_string_wrapper(input: &str) -> crate::ParserResult<String> {
         string_parser::parse_string(input)
     }
@@ -313,6 +313,7 @@ pub(super) mod integer_type {
         str::FromStr,
     };
     use num_traits::{
+        ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub},
         CheckedNeg,
         CheckedRem,
         CheckedShr,
@@ -347,6 +348,9 @@ pub(super) mod integer_type {
         + Modulo
         + NumZero
         + NumOne
+        + OverflowingAdd
+        + OverflowingMul
+        + OverflowingSub
         + PartialOrd
         + Send
         + Sync
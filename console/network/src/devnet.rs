@@ -0,0 +1,683 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_algorithms::snark::marlin::MarlinSNARK;
+use snarkvm_console_algorithms::{
+    Blake2Xs,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+
+use core::{
+    any::{Any, TypeId},
+    marker::PhantomData,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+use std::{collections::HashMap, sync::RwLock};
+
+/// The limits of a development network, which default to the limits of `Testnet3`.
+///
+/// Implement this trait on a marker type, overriding the limits under test,
+/// and use `Devnet<MyConfig>` as the network, i.e.
+/// ```
+/// # use snarkvm_console_network::{Devnet, DevnetConfig, Network};
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct FewWrites;
+///
+/// impl DevnetConfig for FewWrites {
+///     const MAX_WRITES: u16 = 2;
+/// }
+///
+/// assert_eq!(Devnet::<FewWrites>::MAX_WRITES, 2);
+/// ```
+pub trait DevnetConfig: 'static + Copy + Clone + Debug + Eq + PartialEq + core::hash::Hash + Send + Sync {
+    /// The network ID.
+    const ID: u16 = 1;
+    /// The network name.
+    const NAME: &'static str = "Aleo Devnet";
+
+    /// The cost in microcredits per byte for the deployment transaction.
+    const DEPLOYMENT_FEE_MULTIPLIER: u64 = Testnet3::DEPLOYMENT_FEE_MULTIPLIER;
+    /// The anchor time per block in seconds, which must be greater than the round time per block.
+    const ANCHOR_TIME: u16 = Testnet3::ANCHOR_TIME;
+    /// The maximum number of prover solutions that can be included per block.
+    const MAX_PROVER_SOLUTIONS: usize = Testnet3::MAX_PROVER_SOLUTIONS;
+    /// The number of blocks per epoch.
+    const NUM_BLOCKS_PER_EPOCH: u32 = Testnet3::NUM_BLOCKS_PER_EPOCH;
+
+    /// The maximum number of mappings in a program.
+    const MAX_MAPPINGS: usize = Testnet3::MAX_MAPPINGS;
+    /// The maximum number of functions in a program.
+    const MAX_FUNCTIONS: usize = Testnet3::MAX_FUNCTIONS;
+    /// The maximum number of instructions in a closure or function.
+    const MAX_INSTRUCTIONS: usize = Testnet3::MAX_INSTRUCTIONS;
+    /// The maximum number of commands in finalize.
+    const MAX_COMMANDS: usize = Testnet3::MAX_COMMANDS;
+    /// The maximum number of write commands in finalize.
+    const MAX_WRITES: u16 = Testnet3::MAX_WRITES;
+    /// The maximum number of steps that may be metered when finalizing an execution.
+    const MAX_FINALIZE_STEPS: u64 = Testnet3::MAX_FINALIZE_STEPS;
+
+    /// The names of the instruction-set extensions enabled on this network.
+    const INSTRUCTION_SET_EXTENSIONS: &'static [&'static str] = &[];
+
+    /// The maximum degree of a universal SRS sampled locally from `INSECURE_SRS_SEED`, if any.
+    ///
+    /// If set, the network samples its universal SRS instead of downloading the production parameters.
+    /// This setup is **insecure**, as anyone who knows the seed can forge proofs. The 'credits.aleo'
+    /// and inclusion keys of `Testnet3` do not verify against a local SRS, so they are not loaded,
+    /// and must be synthesized instead, i.e. with `Process::setup`, which caches the inclusion keys.
+    const INSECURE_SRS_DEGREE: Option<usize> = None;
+    /// The seed of the local universal SRS, which every node of the network must share.
+    const INSECURE_SRS_SEED: u64 = 0;
+}
+
+/// The default limits of a development network, which are the limits of `Testnet3`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DefaultDevnetConfig;
+
+impl DevnetConfig for DefaultDevnetConfig {}
+
+/// A development network, which uses the parameters of `Testnet3` with the limits of the given config,
+/// so that private deployments can test the behavior of the limits without defining a new network.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Devnet<C: DevnetConfig = DefaultDevnetConfig>(PhantomData<C>);
+
+impl<C: DevnetConfig> Devnet<C> {
+    /// Returns the instance with the given name and type, initializing it on first use.
+    ///
+    /// Statics cannot be generic over the config, so the instances of every config are kept in one map.
+    fn instance<T: 'static + Send + Sync>(name: &'static str, initialize: impl FnOnce() -> T) -> &'static T {
+        type Instances = RwLock<HashMap<(TypeId, &'static str), &'static (dyn Any + Send + Sync)>>;
+        static INSTANCES: OnceCell<Instances> = OnceCell::new();
+        let instances = INSTANCES.get_or_init(Default::default);
+
+        let key = (TypeId::of::<T>(), name);
+        // Retrieve the instance, if it is initialized.
+        let instance = instances.read().unwrap_or_else(|error| error.into_inner()).get(&key).copied();
+        let instance = match instance {
+            Some(instance) => instance,
+            None => {
+                // Initialize the instance outside of the lock, as it may take a while.
+                let instance: &'static (dyn Any + Send + Sync) = Box::leak(Box::new(initialize()));
+                *instances.write().unwrap_or_else(|error| error.into_inner()).entry(key).or_insert(instance)
+            }
+        };
+        instance.downcast_ref::<T>().expect("Mismatching type of a devnet instance")
+    }
+
+    /// Returns the locally sampled universal SRS, if the config sets `INSECURE_SRS_DEGREE`.
+    fn insecure_universal_srs() -> Option<&'static UniversalSRS<<Self as Environment>::PairingCurve>> {
+        let max_degree = C::INSECURE_SRS_DEGREE?;
+        Some(Self::instance("insecure_universal_srs", || {
+            let rng = &mut ChaChaRng::seed_from_u64(C::INSECURE_SRS_SEED);
+            MarlinSNARK::<<Self as Environment>::PairingCurve, FiatShamir<Self>, MarlinHidingMode>::insecure_universal_setup(
+                max_degree, rng,
+            )
+            .expect("Failed to sample the local universal SRS")
+        }))
+    }
+
+    /// Returns the cell of the inclusion keys, which are synthesized from the local universal SRS.
+    #[allow(clippy::type_complexity)]
+    fn insecure_inclusion_key_cell() -> &'static OnceCell<(Arc<MarlinProvingKey<Self>>, Arc<MarlinVerifyingKey<Self>>)>
+    {
+        Self::instance("insecure_inclusion_keys", OnceCell::new)
+    }
+
+    /// Returns the inclusion keys, which are synthesized from the local universal SRS.
+    fn insecure_inclusion_keys() -> &'static (Arc<MarlinProvingKey<Self>>, Arc<MarlinVerifyingKey<Self>>) {
+        Self::insecure_inclusion_key_cell()
+            .get()
+            .expect("The inclusion keys of a local universal SRS must be synthesized, i.e. with `Process::setup`")
+    }
+
+    /// Initializes a new instance of group bases from a given input domain message.
+    fn new_bases(message: &str) -> Vec<Group<Self>> {
+        // Hash the given message to a point on the curve, to initialize the starting base.
+        let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
+
+        // Compute the bases up to the size of the scalar field (in bits).
+        let mut g = Group::<Self>::new(base);
+        let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
+        for _ in 0..Scalar::<Self>::size_in_bits() {
+            g_bases.push(g);
+            g = g.double();
+        }
+        g_bases
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 256 bits.
+    fn bhp_256() -> &'static BHP256<Self> {
+        Self::instance("bhp256", || BHP256::<Self>::setup("AleoBHP256").expect("Failed to setup BHP256"))
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 512 bits.
+    fn bhp_512() -> &'static BHP512<Self> {
+        Self::instance("bhp512", || BHP512::<Self>::setup("AleoBHP512").expect("Failed to setup BHP512"))
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 768 bits.
+    fn bhp_768() -> &'static BHP768<Self> {
+        Self::instance("bhp768", || BHP768::<Self>::setup("AleoBHP768").expect("Failed to setup BHP768"))
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 1024 bits.
+    fn bhp_1024() -> &'static BHP1024<Self> {
+        Self::instance("bhp1024", || BHP1024::<Self>::setup("AleoBHP1024").expect("Failed to setup BHP1024"))
+    }
+
+    /// Returns the Pedersen hash function, which can take an input of up to 64 bits.
+    fn pedersen_64() -> &'static Pedersen64<Self> {
+        Self::instance("ped64", || Pedersen64::<Self>::setup("AleoPedersen64"))
+    }
+
+    /// Returns the Pedersen hash function, which can take an input of up to 128 bits.
+    fn pedersen_128() -> &'static Pedersen128<Self> {
+        Self::instance("ped128", || Pedersen128::<Self>::setup("AleoPedersen128"))
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 2.
+    fn poseidon_2() -> &'static Poseidon2<Self> {
+        Self::instance("psd2", || Poseidon2::<Self>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2"))
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 4.
+    fn poseidon_4() -> &'static Poseidon4<Self> {
+        Self::instance("psd4", || Poseidon4::<Self>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4"))
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 8.
+    fn poseidon_8() -> &'static Poseidon8<Self> {
+        Self::instance("psd8", || Poseidon8::<Self>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8"))
+    }
+}
+
+impl<C: DevnetConfig> Environment for Devnet<C> {
+    type Affine = <Console as Environment>::Affine;
+    type BigInteger = <Console as Environment>::BigInteger;
+    type Field = <Console as Environment>::Field;
+    type PairingCurve = <Console as Environment>::PairingCurve;
+    type Projective = <Console as Environment>::Projective;
+    type Scalar = <Console as Environment>::Scalar;
+
+    /// The coefficient `A` of the twisted Edwards curve.
+    const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+    /// The coefficient `D` of the twisted Edwards curve.
+    const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+    /// The coefficient `A` of the Montgomery curve.
+    const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+    /// The coefficient `B` of the Montgomery curve.
+    const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+}
+
+impl<C: DevnetConfig> Network for Devnet<C> {
+    /// The block hash type.
+    type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The state root type.
+    type StateRoot = AleoID<Field<Self>, { hrp2!("ar") }>;
+    /// The transaction ID type.
+    type TransactionID = AleoID<Field<Self>, { hrp2!("at") }>;
+    /// The transition ID type.
+    type TransitionID = AleoID<Field<Self>, { hrp2!("as") }>;
+    /// The puzzle type.
+    type Puzzle = AleoPuzzle;
+
+    /// The anchor time per block in seconds.
+    const ANCHOR_TIME: u16 = C::ANCHOR_TIME;
+    /// The cost in microcredits per byte for the deployment transaction.
+    const DEPLOYMENT_FEE_MULTIPLIER: u64 = C::DEPLOYMENT_FEE_MULTIPLIER;
+    /// The network edition.
+    const EDITION: u16 = 0;
+    /// The network ID.
+    const ID: u16 = C::ID;
+    /// The function name for the inclusion circuit.
+    const INCLUSION_FUNCTION_NAME: &'static str = Testnet3::INCLUSION_FUNCTION_NAME;
+    /// The names of the instruction-set extensions enabled on this network.
+    const INSTRUCTION_SET_EXTENSIONS: &'static [&'static str] = C::INSTRUCTION_SET_EXTENSIONS;
+    /// The maximum number of commands in finalize.
+    const MAX_COMMANDS: usize = C::MAX_COMMANDS;
+    /// The maximum number of steps that may be metered when finalizing an execution.
+    const MAX_FINALIZE_STEPS: u64 = C::MAX_FINALIZE_STEPS;
+    /// The maximum number of functions in a program.
+    const MAX_FUNCTIONS: usize = C::MAX_FUNCTIONS;
+    /// The maximum number of instructions in a closure or function.
+    const MAX_INSTRUCTIONS: usize = C::MAX_INSTRUCTIONS;
+    /// The maximum number of mappings in a program.
+    const MAX_MAPPINGS: usize = C::MAX_MAPPINGS;
+    /// The maximum number of prover solutions that can be included per block.
+    const MAX_PROVER_SOLUTIONS: usize = C::MAX_PROVER_SOLUTIONS;
+    /// The maximum number of write commands in finalize.
+    const MAX_WRITES: u16 = C::MAX_WRITES;
+    /// The network name.
+    const NAME: &'static str = C::NAME;
+    /// The number of blocks per epoch.
+    const NUM_BLOCKS_PER_EPOCH: u32 = C::NUM_BLOCKS_PER_EPOCH;
+
+    /// Returns the genesis block bytes.
+    fn genesis_bytes() -> &'static [u8] {
+        Testnet3::genesis_bytes()
+    }
+
+    /// Returns the proving key for the given function name in `credits.aleo`.
+    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<MarlinProvingKey<Self>>> {
+        ensure!(
+            C::INSECURE_SRS_DEGREE.is_none(),
+            "The 'credits.aleo' keys of a local universal SRS must be synthesized"
+        );
+        Testnet3::get_credits_proving_key(function_name)
+    }
+
+    /// Returns the verifying key for the given function name in `credits.aleo`.
+    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<MarlinVerifyingKey<Self>>> {
+        ensure!(
+            C::INSECURE_SRS_DEGREE.is_none(),
+            "The 'credits.aleo' keys of a local universal SRS must be synthesized"
+        );
+        Testnet3::get_credits_verifying_key(function_name)
+    }
+
+    /// Returns the `proving key` for the inclusion circuit.
+    fn inclusion_proving_key() -> &'static Arc<MarlinProvingKey<Self>> {
+        match C::INSECURE_SRS_DEGREE {
+            Some(_) => &Self::insecure_inclusion_keys().0,
+            None => Testnet3::inclusion_proving_key(),
+        }
+    }
+
+    /// Returns the `verifying key` for the inclusion circuit.
+    fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>> {
+        match C::INSECURE_SRS_DEGREE {
+            Some(_) => &Self::insecure_inclusion_keys().1,
+            None => Testnet3::inclusion_verifying_key(),
+        }
+    }
+
+    /// Initializes the inclusion keys with the given synthesizer, if the config sets `INSECURE_SRS_DEGREE`.
+    fn synthesize_inclusion_keys(
+        synthesize: impl FnOnce() -> Result<(Arc<MarlinProvingKey<Self>>, Arc<MarlinVerifyingKey<Self>>)>,
+    ) -> Result<()> {
+        match C::INSECURE_SRS_DEGREE {
+            Some(_) => Self::insecure_inclusion_key_cell().get_or_try_init(synthesize).map(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the powers of `G`.
+    fn g_powers() -> &'static Vec<Group<Self>> {
+        Self::instance("g_powers", || Self::new_bases("AleoAccountEncryptionAndSignatureScheme0"))
+    }
+
+    /// Returns the scalar multiplication on the generator `G`.
+    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+        Self::g_powers()
+            .iter()
+            .zip_eq(&scalar.to_bits_le())
+            .filter_map(|(base, bit)| match bit {
+                true => Some(base),
+                false => None,
+            })
+            .sum()
+    }
+
+    /// Returns the universal SRS for Marlin, which is sampled locally if the config sets `INSECURE_SRS_DEGREE`.
+    fn marlin_universal_srs() -> Result<UniversalSRS<Self::PairingCurve>> {
+        match Self::insecure_universal_srs() {
+            Some(universal_srs) => Ok(universal_srs.clone()),
+            None => Testnet3::marlin_universal_srs(),
+        }
+    }
+
+    /// Returns the Marlin universal prover.
+    fn marlin_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+        match Self::insecure_universal_srs() {
+            Some(universal_srs) => Self::instance("insecure_universal_prover", || {
+                universal_srs.to_universal_prover().expect("Failed to convert the local universal SRS to the prover")
+            }),
+            None => Testnet3::marlin_universal_prover(),
+        }
+    }
+
+    /// Returns the Marlin universal verifier.
+    fn marlin_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
+        match Self::insecure_universal_srs() {
+            Some(universal_srs) => Self::instance("insecure_universal_verifier", || {
+                universal_srs
+                    .to_universal_verifier()
+                    .expect("Failed to convert the local universal SRS to the verifier")
+            }),
+            None => Testnet3::marlin_universal_verifier(),
+        }
+    }
+
+    /// Returns the sponge parameters used for the sponge in the Marlin SNARK.
+    fn marlin_fs_parameters() -> &'static FiatShamirParameters<Self> {
+        Testnet3::marlin_fs_parameters()
+    }
+
+    /// Returns the encryption domain as a constant field element.
+    fn encryption_domain() -> Field<Self> {
+        *Self::instance("encryption_domain", || Field::<Self>::new_domain_separator("AleoSymmetricEncryption0"))
+    }
+
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self> {
+        *Self::instance("graph_key_domain", || Field::<Self>::new_domain_separator("AleoGraphKey0"))
+    }
+
+    /// Returns the serial number domain as a constant field element.
+    fn serial_number_domain() -> Field<Self> {
+        *Self::instance("serial_number_domain", || Field::<Self>::new_domain_separator("AleoSerialNumber0"))
+    }
+
+    /// Returns the view key proof domain as a constant field element.
+    fn view_key_proof_domain() -> Field<Self> {
+        *Self::instance("view_key_proof_domain", || Field::<Self>::new_domain_separator("AleoViewKeyProof0"))
+    }
+
+    /// Returns the endorsement domain as a constant field element.
+    fn endorsement_domain() -> Field<Self> {
+        *Self::instance("endorsement_domain", || Field::<Self>::new_domain_separator("AleoEndorsement0"))
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+    fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        Self::bhp_256().commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+    fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        Self::bhp_512().commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+    fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        Self::bhp_768().commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+    fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        Self::bhp_1024().commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        Self::pedersen_64().commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        Self::pedersen_128().commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+    fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        Self::bhp_256().commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+    fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        Self::bhp_512().commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+    fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        Self::bhp_768().commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+    fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        Self::bhp_1024().commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        Self::pedersen_64().commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        Self::pedersen_128().commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+        Self::bhp_256().hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+        Self::bhp_512().hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+        Self::bhp_768().hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+        Self::bhp_1024().hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+        Self::pedersen_64().hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+        Self::pedersen_128().hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2.
+    fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+        Self::poseidon_2().hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4.
+    fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+        Self::poseidon_4().hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8.
+    fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+        Self::poseidon_8().hash(input)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 2.
+    fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        Self::poseidon_2().hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 4.
+    fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        Self::poseidon_4().hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 8.
+    fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        Self::poseidon_8().hash_many(input, num_outputs)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
+        Self::bhp_256().hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
+        Self::bhp_512().hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
+        Self::bhp_768().hash_uncompressed(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
+        Self::bhp_1024().hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
+        Self::pedersen_64().hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
+        Self::pedersen_128().hash_uncompressed(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+        Self::poseidon_2().hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+        Self::poseidon_4().hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+        Self::poseidon_8().hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+    fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        Self::poseidon_2().hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+    fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        Self::poseidon_4().hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+    fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        Self::poseidon_8().hash_to_scalar(input)
+    }
+
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
+    fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(Self::bhp_1024(), Self::bhp_512(), leaves)
+    }
+
+    /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
+    fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(Self::poseidon_4(), Self::poseidon_2(), leaves)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_bhp<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<bool>,
+    ) -> bool {
+        path.verify(Self::bhp_1024(), Self::bhp_512(), root, leaf)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_psd<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> bool {
+        path.verify(Self::poseidon_4(), Self::poseidon_2(), root, leaf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    struct FewWrites;
+
+    impl DevnetConfig for FewWrites {
+        const ANCHOR_TIME: u16 = 5;
+        const MAX_WRITES: u16 = 2;
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    struct LocalSrs;
+
+    impl DevnetConfig for LocalSrs {
+        const INSECURE_SRS_DEGREE: Option<usize> = Some(1 << 10);
+    }
+
+    type CurrentNetwork = Devnet<FewWrites>;
+
+    #[test]
+    fn test_devnet_limits() {
+        // Ensure the overridden limits are used.
+        assert_eq!(CurrentNetwork::MAX_WRITES, 2);
+        assert_eq!(CurrentNetwork::ANCHOR_TIME, 5);
+        // Ensure the remaining limits are the limits of `Testnet3`.
+        assert_eq!(CurrentNetwork::MAX_COMMANDS, Testnet3::MAX_COMMANDS);
+        assert_eq!(CurrentNetwork::MAX_FUNCTIONS, Testnet3::MAX_FUNCTIONS);
+        assert_eq!(Devnet::<DefaultDevnetConfig>::MAX_WRITES, Testnet3::MAX_WRITES);
+        assert_ne!(CurrentNetwork::ID, Testnet3::ID);
+    }
+
+    #[test]
+    fn test_devnet_parameters() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure the hash functions match the hash functions of `Testnet3`.
+        let input = (0..256).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        assert_eq!(CurrentNetwork::hash_bhp256(&input)?.to_string(), Testnet3::hash_bhp256(&input)?.to_string());
+        assert_eq!(
+            CurrentNetwork::hash_ped64(&input[..64])?.to_string(),
+            Testnet3::hash_ped64(&input[..64])?.to_string()
+        );
+        assert_eq!(CurrentNetwork::encryption_domain().to_string(), Testnet3::encryption_domain().to_string());
+
+        // Ensure the instances are initialized once.
+        assert!(core::ptr::eq(CurrentNetwork::g_powers(), CurrentNetwork::g_powers()));
+
+        // Compute G^r.
+        let scalar = Scalar::rand(rng);
+        let group = CurrentNetwork::g_scalar_multiply(&scalar);
+        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+        Ok(())
+    }
+
+    #[test]
+    fn test_devnet_insecure_universal_srs() -> Result<()> {
+        type LocalNetwork = Devnet<LocalSrs>;
+
+        // Ensure the universal SRS is sampled once, and is shared by the prover and verifier.
+        let universal_srs = LocalNetwork::marlin_universal_srs()?;
+        assert!(core::ptr::eq(LocalNetwork::marlin_universal_prover(), LocalNetwork::marlin_universal_prover()));
+        assert_eq!(LocalNetwork::marlin_universal_prover().max_degree, universal_srs.max_degree());
+        assert_eq!(LocalNetwork::marlin_universal_verifier().vk.beta_h, universal_srs.beta_h());
+
+        // Ensure the production keys are not loaded.
+        assert!(LocalNetwork::get_credits_verifying_key("transfer_public".to_string()).is_err());
+        assert!(LocalNetwork::get_credits_proving_key("transfer_public".to_string()).is_err());
+        Ok(())
+    }
+}
@@ -210,3 +210,59 @@ impl<F: FieldTrait, const PREFIX: u16> Distribution<AleoID<F, PREFIX>> for Stand
         AleoID::<F, PREFIX>(Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type TransactionID = <CurrentNetwork as Network>::TransactionID;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: TransactionID = Uniform::rand(&mut rng);
+
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected_string, serde_json::Value::from_str(&candidate_string)?.as_str().unwrap());
+
+            // Deserialize
+            assert_eq!(expected, TransactionID::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: TransactionID = Uniform::rand(&mut rng);
+
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(TransactionID::size_in_bytes(), expected_bytes.len());
+            assert_eq!(&expected_bytes[..], &bincode::serialize(&expected)?[..]);
+
+            // Deserialize
+            assert_eq!(expected, TransactionID::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes[..])?);
+
+            // Ensure the ID is read exactly, when it is followed by another value.
+            let candidate_bytes = bincode::serialize(&(expected, u64::MAX))?;
+            assert_eq!((expected, u64::MAX), bincode::deserialize(&candidate_bytes[..])?);
+
+            // Ensure insufficient bytes fail.
+            assert!(bincode::deserialize::<TransactionID>(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -17,3 +17,6 @@ pub use id::*;
 
 mod object;
 pub use object::*;
+
+mod puzzle;
+pub use puzzle::*;
@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+
+/// The marker of the puzzle that provers solve for the coinbase reward.
+///
+/// The puzzle selects the encoding of its solutions: the `ProverSolution` and `CoinbaseSolution` of a network
+/// write the header of the network puzzle before their payload, and read and check it before their payload.
+pub trait PuzzleMarker: 'static + Copy + Clone + Debug + Send + Sync {
+    /// Writes the header of a solution payload for this puzzle.
+    fn write_solution_header<W: Write>(_writer: W) -> IoResult<()> {
+        Ok(())
+    }
+
+    /// Reads and checks the header of a solution payload for this puzzle.
+    fn read_solution_header<R: Read>(_reader: R) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+/// The marker for the coinbase puzzle of the Aleo networks.
+///
+/// The puzzles are implemented in `snarkvm-synthesizer-coinbase`, which depends on this crate,
+/// so `Network::Puzzle` names the puzzle of a network with a marker type, which the coinbase crate
+/// maps to its puzzle implementation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AleoPuzzle {}
+
+/// The solutions of the Aleo networks have no header.
+impl PuzzleMarker for AleoPuzzle {}
@@ -25,6 +25,9 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+mod devnet;
+pub use devnet::*;
+
 mod testnet3;
 pub use testnet3::*;
 
@@ -35,7 +38,7 @@ pub mod prelude {
 use crate::environment::prelude::*;
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
-    snark::marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinHidingMode},
+    snark::marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinHidingMode, UniversalSRS},
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
 };
@@ -87,6 +90,10 @@ pub trait Network:
     /// The function name for the inclusion circuit.
     const INCLUSION_FUNCTION_NAME: &'static str;
 
+    /// The names of the instruction-set extensions enabled on this network.
+    /// Experimental opcodes are only available on networks that enable their extension.
+    const INSTRUCTION_SET_EXTENSIONS: &'static [&'static str] = &[];
+
     /// The fixed timestamp of the genesis block.
     const GENESIS_TIMESTAMP: i64 = 1680307200; // 2023-04-01 00:00:00 UTC
     /// The genesis block coinbase target.
@@ -142,6 +149,20 @@ pub trait Network:
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 10;
 
+    /// The maximum number of steps that may be metered when finalizing an execution.
+    /// Note: This is below the steps of a finalize scope with `MAX_COMMANDS` reads, so the limit is reachable.
+    const MAX_FINALIZE_STEPS: u64 = 1 << 16;
+    /// The number of steps metered for an instruction in finalize.
+    const FINALIZE_INSTRUCTION_STEPS: u64 = 1;
+    /// The number of steps metered for a mapping read in finalize (i.e. `contains`, `get`, `get.or_use`, `length`, or a key of `keys`).
+    const FINALIZE_READ_STEPS: u64 = 10;
+    /// The number of steps metered for a mapping write in finalize (i.e. `set`, `remove`, `clear`).
+    const FINALIZE_WRITE_STEPS: u64 = 20;
+    /// The number of steps metered for a `rand.chacha` command in finalize.
+    const FINALIZE_RAND_STEPS: u64 = 10;
+    /// The number of steps metered for a control-flow command in finalize (i.e. `branch`, `position`).
+    const FINALIZE_BRANCH_STEPS: u64 = 1;
+
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
     /// The maximum number of outputs per transition.
@@ -155,6 +176,8 @@ pub trait Network:
     type TransactionID: Bech32ID<Field<Self>>;
     /// The transition ID type.
     type TransitionID: Bech32ID<Field<Self>>;
+    /// The puzzle type, which marks the puzzle that provers solve for the coinbase reward, i.e. `AleoPuzzle`.
+    type Puzzle: PuzzleMarker;
 
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8];
@@ -171,12 +194,29 @@ pub trait Network:
     /// Returns the `verifying key` for the inclusion circuit.
     fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>>;
 
+    /// Initializes the inclusion keys with the given synthesizer, if the network synthesizes them instead of loading them.
+    #[allow(clippy::type_complexity)]
+    fn synthesize_inclusion_keys(
+        _synthesize: impl FnOnce() -> Result<(Arc<MarlinProvingKey<Self>>, Arc<MarlinVerifyingKey<Self>>)>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>>;
 
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
+    /// Returns the scalar multiplication on the generator `G`, where the sequence of operations
+    /// does not depend on the scalar. This method is intended for secret scalars.
+    fn g_scalar_multiply_constant_time(scalar: &Scalar<Self>) -> Group<Self> {
+        Self::g_powers()[0].mul_constant_time(scalar)
+    }
+
+    /// Returns the universal SRS for Marlin, which the circuit keys are synthesized from.
+    fn marlin_universal_srs() -> Result<UniversalSRS<Self::PairingCurve>>;
+
     /// Returns the Marlin universal prover.
     fn marlin_universal_prover() -> &'static UniversalProver<Self::PairingCurve>;
 
@@ -195,6 +235,12 @@ pub trait Network:
     /// Returns the serial number domain as a constant field element.
     fn serial_number_domain() -> Field<Self>;
 
+    /// Returns the view key proof domain as a constant field element.
+    fn view_key_proof_domain() -> Field<Self>;
+
+    /// Returns the endorsement domain as a constant field element.
+    fn endorsement_domain() -> Field<Self>;
+
     /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>>;
 
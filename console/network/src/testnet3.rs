@@ -39,6 +39,10 @@ lazy_static! {
     pub static ref GRAPH_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoGraphKey0");
     /// The serial number domain as a constant field element.
     pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
+    /// The view key proof domain as a constant field element.
+    pub static ref VIEW_KEY_PROOF_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoViewKeyProof0");
+    /// The endorsement domain as a constant field element.
+    pub static ref ENDORSEMENT_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoEndorsement0");
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
@@ -61,16 +65,12 @@ lazy_static! {
     /// The Poseidon hash function, using a rate of 8.
     pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
 
-    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<MarlinProvingKey<Console>>> = {
-        let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, MarlinProvingKey<Console>, Prover);
-        map
-    };
-    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<MarlinVerifyingKey<Console>>> = {
-        let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, MarlinVerifyingKey<Console>, Verifier);
-        map
-    };
+    /// The proving keys for `credits.aleo`, which are loaded on first use.
+    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, OnceCell<Arc<MarlinProvingKey<Console>>>> =
+        snarkvm_parameters::testnet3::CREDITS_FUNCTION_NAMES.iter().map(|name| (name.to_string(), OnceCell::new())).collect();
+    /// The verifying keys for `credits.aleo`, which are loaded on first use.
+    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, OnceCell<Arc<MarlinVerifyingKey<Console>>>> =
+        snarkvm_parameters::testnet3::CREDITS_FUNCTION_NAMES.iter().map(|name| (name.to_string(), OnceCell::new())).collect();
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -120,6 +120,8 @@ impl Network for Testnet3 {
     type TransactionID = AleoID<Field<Self>, { hrp2!("at") }>;
     /// The transition ID type.
     type TransitionID = AleoID<Field<Self>, { hrp2!("as") }>;
+    /// The puzzle type.
+    type Puzzle = AleoPuzzle;
 
     /// The network edition.
     const EDITION: u16 = 0;
@@ -139,25 +141,35 @@ impl Network for Testnet3 {
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<MarlinProvingKey<Self>>> {
         CREDITS_PROVING_KEYS
             .get(&function_name)
-            .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
+            .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))?
+            .get_or_try_init(|| {
+                let bytes = snarkvm_parameters::testnet3::credits_proving_key_bytes(&function_name)?;
+                // Skipping the first byte, which is the encoded version.
+                Ok(Arc::new(CircuitProvingKey::from_bytes_le(&bytes[1..])?))
+            })
     }
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<MarlinVerifyingKey<Self>>> {
         CREDITS_VERIFYING_KEYS
             .get(&function_name)
-            .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))
+            .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))?
+            .get_or_try_init(|| {
+                let bytes = snarkvm_parameters::testnet3::credits_verifying_key_bytes(&function_name)?;
+                // Skipping the first byte, which is the encoded version.
+                Ok(Arc::new(CircuitVerifyingKey::from_bytes_le(&bytes[1..])?))
+            })
     }
 
     /// Returns the `proving key` for the inclusion circuit.
     fn inclusion_proving_key() -> &'static Arc<MarlinProvingKey<Self>> {
         static INSTANCE: OnceCell<Arc<MarlinProvingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
+            // Map the bytes, which are unmapped once the key is recovered.
+            let bytes = snarkvm_parameters::testnet3::InclusionProver::load_mapped()
+                .expect("Failed to load inclusion proving key bytes.");
             // Skipping the first byte, which is the encoded version.
-            Arc::new(
-                CircuitProvingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY[1..])
-                    .expect("Failed to load inclusion proving key."),
-            )
+            Arc::new(CircuitProvingKey::from_bytes_le(&bytes[1..]).expect("Failed to load inclusion proving key."))
         })
     }
 
@@ -165,11 +177,11 @@ impl Network for Testnet3 {
     fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>> {
         static INSTANCE: OnceCell<Arc<MarlinVerifyingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
+            // Map the bytes, which are unmapped once the key is recovered.
+            let bytes = snarkvm_parameters::testnet3::InclusionVerifier::load_mapped()
+                .expect("Failed to load inclusion verifying key bytes.");
             // Skipping the first byte, which is the encoded version.
-            Arc::new(
-                CircuitVerifyingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY[1..])
-                    .expect("Failed to load inclusion verifying key."),
-            )
+            Arc::new(CircuitVerifyingKey::from_bytes_le(&bytes[1..]).expect("Failed to load inclusion verifying key."))
         })
     }
 
@@ -190,6 +202,11 @@ impl Network for Testnet3 {
             .sum()
     }
 
+    /// Returns the universal SRS for Marlin, which loads the powers on first use.
+    fn marlin_universal_srs() -> Result<UniversalSRS<Self::PairingCurve>> {
+        UniversalSRS::load()
+    }
+
     /// Returns the Marlin universal prover.
     fn marlin_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
@@ -232,6 +249,16 @@ impl Network for Testnet3 {
         *SERIAL_NUMBER_DOMAIN
     }
 
+    /// Returns the view key proof domain as a constant field element.
+    fn view_key_proof_domain() -> Field<Self> {
+        *VIEW_KEY_PROOF_DOMAIN
+    }
+
+    /// Returns the endorsement domain as a constant field element.
+    fn endorsement_domain() -> Field<Self> {
+        *ENDORSEMENT_DOMAIN
+    }
+
     /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
         BHP_256.commit(input, randomizer)
@@ -454,4 +481,11 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_g_scalar_multiply_constant_time() {
+        let scalar = Scalar::rand(&mut TestRng::default());
+        let group = CurrentNetwork::g_scalar_multiply_constant_time(&scalar);
+        assert_eq!(group, CurrentNetwork::g_scalar_multiply(&scalar));
+    }
 }
@@ -18,11 +18,39 @@ impl<N: Network> Ciphertext<N> {
     /// Decrypts `self` into plaintext using the given account view key & nonce.
     pub fn decrypt(&self, view_key: ViewKey<N>, nonce: Group<N>) -> Result<Plaintext<N>> {
         // Compute the plaintext view key.
-        let plaintext_view_key = (nonce * *view_key).to_x_coordinate();
+        let plaintext_view_key = nonce.mul_constant_time(&view_key).to_x_coordinate();
         // Decrypt the record.
         self.decrypt_symmetric(plaintext_view_key)
     }
 
+    /// Decrypts the given ciphertexts into plaintexts using the given account view key & nonces.
+    ///
+    /// This computes the plaintext view keys in batches, which is faster than decrypting each ciphertext.
+    /// Note: The batched scalar multiplications are not constant-time in the view key,
+    /// so `decrypt` should be used where timing side channels are a concern.
+    pub fn decrypt_many(view_key: ViewKey<N>, ciphertexts: &[(Self, Group<N>)]) -> Result<Vec<Plaintext<N>>> {
+        // Compute the plaintext view keys.
+        let nonces = ciphertexts.iter().map(|(_, nonce)| *nonce).collect::<Vec<_>>();
+        let plaintext_view_keys = Self::to_plaintext_view_keys(&view_key, &nonces);
+        // Decrypt the ciphertexts.
+        cfg_iter!(ciphertexts)
+            .zip_eq(&plaintext_view_keys)
+            .map(|((ciphertext, _), plaintext_view_key)| ciphertext.decrypt_symmetric(*plaintext_view_key))
+            .collect()
+    }
+
+    /// Returns the plaintext view keys for the given nonces, using the given account view key.
+    ///
+    /// The view key is recoded once for all of the nonces, and the field inversions are batched in each chunk.
+    pub(crate) fn to_plaintext_view_keys(view_key: &ViewKey<N>, nonces: &[Group<N>]) -> Vec<Field<N>> {
+        /// The number of nonces in each chunk, which are processed in parallel.
+        const CHUNK_SIZE: usize = 256;
+
+        cfg_chunks!(nonces, CHUNK_SIZE)
+            .flat_map(|nonces| Group::to_x_coordinates(&Group::batch_mul(nonces, view_key)))
+            .collect()
+    }
+
     /// Decrypts `self` into plaintext using the given plaintext view key.
     pub fn decrypt_symmetric(&self, plaintext_view_key: Field<N>) -> Result<Plaintext<N>> {
         // Determine the number of randomizers needed to encrypt the plaintext.
@@ -113,6 +141,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_decrypt_many() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Prepare the plaintexts and ciphertexts.
+        let mut plaintexts = Vec::new();
+        let mut ciphertexts = Vec::new();
+        for _ in 0..ITERATIONS {
+            let plaintext = Plaintext::<CurrentNetwork>::from(Literal::Field(Uniform::rand(&mut rng)));
+            let randomizer = Uniform::rand(&mut rng);
+            ciphertexts
+                .push((plaintext.encrypt(&address, randomizer)?, CurrentNetwork::g_scalar_multiply(&randomizer)));
+            plaintexts.push(plaintext);
+        }
+
+        // Ensure the ciphertexts are decrypted.
+        assert_eq!(plaintexts, Ciphertext::decrypt_many(view_key, &ciphertexts)?);
+        Ok(())
+    }
+
     #[test]
     fn test_encrypt_and_decrypt() -> Result<()> {
         let mut rng = TestRng::default();
@@ -28,6 +28,10 @@ use crate::Plaintext;
 use snarkvm_console_account::ViewKey;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group};
+use snarkvm_utilities::cfg_chunks;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
 use core::ops::Deref;
 
@@ -17,6 +17,8 @@ mod equal;
 mod from_bits;
 mod from_field;
 mod parse;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 mod size_in_bits;
 mod to_bits;
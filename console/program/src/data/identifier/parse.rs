@@ -24,9 +24,12 @@ impl<N: Network> Parser for Identifier<N> {
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Check for alphanumeric characters and underscores.
-        map_res(recognize(pair(alpha1, many0(alt((alphanumeric1, tag("_")))))), |identifier: &str| {
-            Self::from_str(identifier)
-        })(string)
+        context(
+            "identifier",
+            map_res(recognize(pair(alpha1, many0(alt((alphanumeric1, tag("_")))))), |identifier: &str| {
+                Self::from_str(identifier)
+            }),
+        )(string)
     }
 }
 
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Identifier<N> {
+    /// Returns a randomly-sampled identifier of up to 8 characters, which starts with a lowercase letter,
+    /// and continues with ASCII letters and digits.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample the first character, which must be a letter.
+        let first = char::from(b'a' + rng.gen_range(0..26));
+        // Sample the remaining characters.
+        let num_characters = rng.gen_range(0..8);
+        let identifier = core::iter::once(first)
+            .chain(rng.sample_iter(&Alphanumeric).take(num_characters).map(char::from))
+            .collect::<String>();
+        Self::from_str(&identifier).or_halt_with::<N>("Failed to sample an identifier")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random identifier.
+            let expected = Identifier::<CurrentNetwork>::sample(&mut rng);
+
+            // Ensure the identifier round trips through its string and byte representations.
+            assert_eq!(expected, Identifier::from_str(&expected.to_string())?);
+            assert_eq!(expected, Identifier::read_le(&expected.to_bytes_le()?[..])?);
+        }
+        Ok(())
+    }
+}
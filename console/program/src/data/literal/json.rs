@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the literal as canonical JSON, which is `{ "type": <literal type>, "value": <string> }`.
+    ///
+    /// The value is the text syntax of the literal without its type suffix, for example, `"5"` for `5u64`,
+    /// and a string literal is given without its quotes.
+    pub fn to_json(&self) -> serde_json::Value {
+        let literal_type = self.to_type();
+        let value = match self {
+            // The string literal is given without its quotes.
+            Self::String(string) => (**string).to_string(),
+            // The address and boolean literals have no type suffix.
+            Self::Address(..) | Self::Boolean(..) => self.to_string(),
+            // The remaining literals are given without their type suffix.
+            _ => {
+                let string = self.to_string();
+                string.strip_suffix(literal_type.type_name()).unwrap_or(&string).to_string()
+            }
+        };
+        serde_json::json!({ "type": literal_type.type_name(), "value": value })
+    }
+
+    /// Returns the literal from its canonical JSON. See `Literal::to_json` for the encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Retrieve the literal type.
+        let literal_type = match json.get("type").and_then(serde_json::Value::as_str) {
+            Some(literal_type) => LiteralType::from_str(literal_type)?,
+            None => bail!("Expected a literal type in the JSON literal '{json}'"),
+        };
+        // Retrieve the value.
+        let Some(value) = json.get("value").and_then(serde_json::Value::as_str) else {
+            bail!("Expected a string value in the JSON literal '{json}'")
+        };
+        // Parse the literal.
+        let literal = match literal_type {
+            LiteralType::String => {
+                // Ensure the string is within the allowed capacity.
+                ensure!(value.len() <= N::MAX_STRING_BYTES as usize, "The JSON string literal exceeds the capacity");
+                Self::String(StringType::new(value))
+            }
+            LiteralType::Address | LiteralType::Boolean => Self::from_str(value)?,
+            _ => Self::from_str(&format!("{value}{}", literal_type.type_name()))?,
+        };
+        // Ensure the literal type matches.
+        ensure!(literal.to_type() == literal_type, "Mismatching literal type in the JSON literal '{json}'");
+        Ok(literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use num_traits::FromPrimitive;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            for literal_type in (0..).map_while(LiteralType::from_u8) {
+                // Sample a new literal.
+                let expected = Literal::<CurrentNetwork>::sample(literal_type, &mut rng);
+                // Ensure the literal round-trips.
+                let json = expected.to_json();
+                assert_eq!(literal_type.type_name(), json["type"]);
+                assert_eq!(expected, Literal::from_json(&json)?);
+            }
+        }
+
+        // Ensure the encoding is canonical.
+        let check = |literal: &str, expected: serde_json::Value| -> Result<()> {
+            let literal = Literal::<CurrentNetwork>::from_str(literal)?;
+            assert_eq!(expected, literal.to_json());
+            assert_eq!(literal, Literal::from_json(&expected)?);
+            Ok(())
+        };
+        check("5u64", serde_json::json!({ "type": "u64", "value": "5" }))?;
+        check("-5i8", serde_json::json!({ "type": "i8", "value": "-5" }))?;
+        check("5field", serde_json::json!({ "type": "field", "value": "5" }))?;
+        check("true", serde_json::json!({ "type": "boolean", "value": "true" }))?;
+        check("\"hello world\"", serde_json::json!({ "type": "string", "value": "hello world" }))?;
+        check(
+            "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+            serde_json::json!({ "type": "address", "value": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah" }),
+        )?;
+
+        // Ensure the type suffix may not be given in the value, and the type must match.
+        assert!(Literal::<CurrentNetwork>::from_json(&serde_json::json!({ "type": "u64", "value": "5u64" })).is_err());
+        assert!(Literal::<CurrentNetwork>::from_json(&serde_json::json!({ "type": "u64", "value": "-5" })).is_err());
+        assert!(Literal::<CurrentNetwork>::from_json(&serde_json::json!({ "type": "u64", "value": 5 })).is_err());
+        assert!(Literal::<CurrentNetwork>::from_json(&serde_json::json!({ "type": "u63", "value": "5" })).is_err());
+        Ok(())
+    }
+}
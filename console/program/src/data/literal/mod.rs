@@ -16,6 +16,7 @@ mod bytes;
 mod downcast;
 mod equal;
 mod from_bits;
+mod json;
 mod parse;
 mod sample;
 mod serialize;
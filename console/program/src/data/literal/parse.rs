@@ -18,24 +18,27 @@ impl<N: Network> Parser for Literal<N> {
     /// Parses a string into a literal.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        alt((
-            map(Address::<N>::parse, |literal| Self::Address(literal)),
-            map(Boolean::<N>::parse, |literal| Self::Boolean(literal)),
-            map(Field::<N>::parse, |literal| Self::Field(literal)),
-            map(Group::<N>::parse, |literal| Self::Group(literal)),
-            map(I8::<N>::parse, |literal| Self::I8(literal)),
-            map(I16::<N>::parse, |literal| Self::I16(literal)),
-            map(I32::<N>::parse, |literal| Self::I32(literal)),
-            map(I64::<N>::parse, |literal| Self::I64(literal)),
-            map(I128::<N>::parse, |literal| Self::I128(literal)),
-            map(U8::<N>::parse, |literal| Self::U8(literal)),
-            map(U16::<N>::parse, |literal| Self::U16(literal)),
-            map(U32::<N>::parse, |literal| Self::U32(literal)),
-            map(U64::<N>::parse, |literal| Self::U64(literal)),
-            map(U128::<N>::parse, |literal| Self::U128(literal)),
-            map(Scalar::<N>::parse, |literal| Self::Scalar(literal)),
-            map(StringType::<N>::parse, |literal| Self::String(literal)),
-        ))(string)
+        context(
+            "literal",
+            alt((
+                map(Address::<N>::parse, |literal| Self::Address(literal)),
+                map(Boolean::<N>::parse, |literal| Self::Boolean(literal)),
+                map(Field::<N>::parse, |literal| Self::Field(literal)),
+                map(Group::<N>::parse, |literal| Self::Group(literal)),
+                map(I8::<N>::parse, |literal| Self::I8(literal)),
+                map(I16::<N>::parse, |literal| Self::I16(literal)),
+                map(I32::<N>::parse, |literal| Self::I32(literal)),
+                map(I64::<N>::parse, |literal| Self::I64(literal)),
+                map(I128::<N>::parse, |literal| Self::I128(literal)),
+                map(U8::<N>::parse, |literal| Self::U8(literal)),
+                map(U16::<N>::parse, |literal| Self::U16(literal)),
+                map(U32::<N>::parse, |literal| Self::U32(literal)),
+                map(U64::<N>::parse, |literal| Self::U64(literal)),
+                map(U128::<N>::parse, |literal| Self::U128(literal)),
+                map(Scalar::<N>::parse, |literal| Self::Scalar(literal)),
+                map(StringType::<N>::parse, |literal| Self::String(literal)),
+            )),
+        )(string)
     }
 }
 
@@ -48,11 +51,15 @@ impl<N: Network> FromStr for Literal<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -31,4 +31,4 @@ mod register;
 pub use register::Register;
 
 mod value;
-pub use value::Value;
+pub use value::{Difference, Value};
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the differences from `self` to `other`, in the order of the members of `self`,
+    /// followed by the members that are only in `other`. If the plaintexts are equal, this is empty.
+    pub fn diff(&self, other: &Self) -> Vec<Difference<N>> {
+        let mut differences = Vec::new();
+        self.diff_internal(other, &mut Vec::new(), &mut differences);
+        differences
+    }
+
+    /// Appends the differences from `self` to `other`, at the given path, to the given differences.
+    pub(in crate::data) fn diff_internal(
+        &self,
+        other: &Self,
+        path: &mut Vec<Identifier<N>>,
+        differences: &mut Vec<Difference<N>>,
+    ) {
+        match (self, other) {
+            // If both plaintexts are structs, compare their members.
+            (Self::Struct(members_a, ..), Self::Struct(members_b, ..)) => {
+                for (name, member_a) in members_a {
+                    path.push(*name);
+                    match members_b.get(name) {
+                        Some(member_b) => member_a.diff_internal(member_b, path, differences),
+                        None => differences.push(Difference::Removed(path.clone(), Value::Plaintext(member_a.clone()))),
+                    }
+                    path.pop();
+                }
+                // Add the members that are only in `other`.
+                for (name, member_b) in members_b.iter().filter(|(name, _)| !members_a.contains_key(*name)) {
+                    path.push(*name);
+                    differences.push(Difference::Added(path.clone(), Value::Plaintext(member_b.clone())));
+                    path.pop();
+                }
+            }
+            // Otherwise, compare the plaintexts.
+            (a, b) => {
+                if a != b {
+                    differences.push(Difference::Changed(
+                        path.clone(),
+                        Value::Plaintext(a.clone()),
+                        Value::Plaintext(b.clone()),
+                    ));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the plaintext as canonical JSON.
+    ///
+    /// A literal is encoded as `{ "type": <literal type>, "value": <string> }`, as in `Literal::to_json`,
+    /// and a struct is encoded as `{ "type": "struct", "members": { <name>: <plaintext>, ... } }`.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Literal(literal, ..) => literal.to_json(),
+            Self::Struct(members, ..) => {
+                let members = members.iter().map(|(name, member)| (name.to_string(), member.to_json())).collect();
+                serde_json::json!({ "type": "struct", "members": serde_json::Value::Object(members) })
+            }
+        }
+    }
+
+    /// Returns the plaintext from its canonical JSON. See `Plaintext::to_json` for the encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        Self::from_json_internal(json, 0)
+    }
+
+    /// Returns the plaintext from its canonical JSON, at the given depth.
+    fn from_json_internal(json: &serde_json::Value, depth: usize) -> Result<Self> {
+        match json.get("type").and_then(serde_json::Value::as_str) {
+            Some("struct") => {
+                // Ensure the depth is within the maximum limit.
+                ensure!(depth < N::MAX_DATA_DEPTH, "The JSON struct exceeds the maximum depth");
+                // Retrieve the members.
+                let Some(members) = json.get("members").and_then(serde_json::Value::as_object) else {
+                    bail!("Expected the members of the JSON struct '{json}'")
+                };
+                // Ensure the number of members is within the maximum limit.
+                ensure!(
+                    members.len() <= N::MAX_STRUCT_ENTRIES,
+                    "The JSON struct exceeds the maximum number of members"
+                );
+                // Parse the members.
+                let members = members
+                    .iter()
+                    .map(|(name, member)| {
+                        Ok((Identifier::from_str(name)?, Self::from_json_internal(member, depth + 1)?))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Self::Struct(members, Default::default()))
+            }
+            _ => Ok(Self::Literal(Literal::from_json(json)?, Default::default())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, token: { amount: 5u64, id: 7field } }",
+        )?;
+        let json = serde_json::json!({
+            "type": "struct",
+            "members": {
+                "owner": { "type": "address", "value": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah" },
+                "token": {
+                    "type": "struct",
+                    "members": {
+                        "amount": { "type": "u64", "value": "5" },
+                        "id": { "type": "field", "value": "7" }
+                    }
+                }
+            }
+        });
+        assert_eq!(json, expected.to_json());
+        assert_eq!(expected, Plaintext::from_json(&json)?);
+        // Ensure the members retain their order.
+        assert_eq!(json.to_string(), expected.to_json().to_string());
+
+        // Ensure an invalid member name is rejected.
+        let json = serde_json::json!({ "type": "struct", "members": { "0x": { "type": "u8", "value": "1" } } });
+        assert!(Plaintext::<CurrentNetwork>::from_json(&json).is_err());
+        Ok(())
+    }
+}
@@ -13,19 +13,23 @@
 // limitations under the License.
 
 mod bytes;
+mod diff;
 mod encrypt;
 mod equal;
 mod find;
 mod from_bits;
 mod from_fields;
+mod json;
 mod num_randomizers;
 mod parse;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal};
+use crate::{Ciphertext, Difference, Identifier, Literal, Value};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::LiteralType;
+
+/// The maximum depth of a randomly-sampled plaintext.
+const MAX_SAMPLE_DEPTH: usize = 3;
+/// The maximum number of members of a randomly-sampled struct.
+const MAX_SAMPLE_MEMBERS: usize = 4;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns a randomly-sampled plaintext, which is a literal of any type, or a struct of bounded depth and size.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        Self::sample_with_depth(MAX_SAMPLE_DEPTH, rng)
+    }
+
+    /// Returns a randomly-sampled plaintext, with at most the given depth of nested structs.
+    pub(crate) fn sample_with_depth<R: Rng + CryptoRng>(depth: usize, rng: &mut R) -> Self {
+        match depth == 0 || rng.gen() {
+            true => {
+                // Sample the literal type.
+                let literal_type = LiteralType::sample(rng);
+                // Sample the literal.
+                Self::from(Literal::sample(literal_type, rng))
+            }
+            false => {
+                // Sample the members, which are never empty.
+                // Note: A duplicate member name overwrites the earlier member.
+                let num_members = rng.gen_range(1..=MAX_SAMPLE_MEMBERS);
+                let members = (0..num_members)
+                    .map(|_| (Identifier::sample(rng), Self::sample_with_depth(depth - 1, rng)))
+                    .collect::<IndexMap<_, _>>();
+                Self::Struct(members, Default::default())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N: Network> arbitrary::Arbitrary<'a> for Plaintext<N> {
+    /// Returns a plaintext, which is sampled from an RNG that is seeded by the unstructured bytes.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+        Ok(Self::sample(&mut ChaChaRng::from_seed(u.arbitrary()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random plaintext.
+            let expected = Plaintext::<CurrentNetwork>::sample(&mut rng);
+
+            // Ensure the plaintext round trips through its string, byte, bit, and field representations.
+            assert_eq!(expected, Plaintext::from_str(&expected.to_string())?);
+            assert_eq!(expected, Plaintext::read_le(&expected.to_bytes_le()?[..])?);
+            assert_eq!(expected, Plaintext::from_bits_le(&expected.to_bits_le())?);
+            assert_eq!(expected, Plaintext::from_fields(&expected.to_fields()?)?);
+        }
+        Ok(())
+    }
+}
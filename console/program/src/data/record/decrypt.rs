@@ -18,11 +18,36 @@ impl<N: Network> Record<N, Ciphertext<N>> {
     /// Decrypts `self` into plaintext using the given view key.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
         // Compute the record view key.
-        let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+        let record_view_key = self.to_record_view_key(view_key);
         // Decrypt the record.
         self.decrypt_symmetric(&record_view_key)
     }
 
+    /// Decrypts the given records into plaintext using the given view key, for the records owned by the view key.
+    /// Returns `None` for each record that is not owned by the view key.
+    ///
+    /// This computes the record view keys in batches, which is faster than decrypting each record.
+    /// Note: The batched scalar multiplications are not constant-time in the view key,
+    /// so `decrypt` should be used where timing side channels are a concern.
+    #[allow(clippy::type_complexity)]
+    pub fn decrypt_many(records: &[Self], view_key: &ViewKey<N>) -> Result<Vec<Option<Record<N, Plaintext<N>>>>> {
+        // Compute the record view keys.
+        let nonces = records.iter().map(|record| record.nonce).collect::<Vec<_>>();
+        let record_view_keys = Ciphertext::to_plaintext_view_keys(view_key, &nonces);
+        // Compute the x-coordinate of the address.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Decrypt the records owned by the view key.
+        cfg_iter!(records)
+            .zip_eq(&record_view_keys)
+            .map(|(record, record_view_key)| {
+                match record.is_owner_with_record_view_key(record_view_key, &address_x_coordinate) {
+                    true => record.decrypt_symmetric(record_view_key).map(Some),
+                    false => Ok(None),
+                }
+            })
+            .collect()
+    }
+
     /// Decrypts `self` into plaintext using the given record view key.
     pub fn decrypt_symmetric(&self, record_view_key: &Field<N>) -> Result<Record<N, Plaintext<N>>> {
         // Determine the number of randomizers needed to encrypt the record.
@@ -121,6 +146,91 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_encrypt_dual_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the owner and auditor accounts.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+            let auditor_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let auditor_view_key = ViewKey::try_from(&auditor_private_key)?;
+            let auditor = Address::try_from(&auditor_private_key)?;
+
+            // Prepare the record.
+            let owner = match i % 2 == 0 {
+                true => Owner::Public(address),
+                false => Owner::Private(Plaintext::from(Literal::Address(address))),
+            };
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record {
+                owner,
+                data: IndexMap::from_iter([(
+                    Identifier::from_str("a")?,
+                    Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                )]),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+            };
+
+            // Encrypt the record for the owner and the auditor.
+            let (owner_ciphertext, auditor_ciphertext) = record.encrypt_dual(&auditor, randomizer)?;
+            // Ensure the owner ciphertext matches the single-recipient encryption.
+            assert_eq!(record.encrypt(randomizer)?, owner_ciphertext);
+            // Ensure both ciphertexts decrypt to the record.
+            assert_eq!(record, owner_ciphertext.decrypt(&view_key)?);
+            assert_eq!(record, auditor_ciphertext.decrypt(&auditor_view_key)?);
+            // Ensure the record owner is unchanged, so the auditor does not own the record.
+            assert!(owner_ciphertext.is_owner(&view_key));
+            assert!(!auditor_ciphertext.is_owner(&auditor_view_key));
+
+            // Ensure an incorrect randomizer fails.
+            assert!(record.encrypt_dual(&auditor, Scalar::rand(&mut rng)).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_many() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+        // Sample another address.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Prepare the records, alternating between the owners.
+        let mut expected = Vec::new();
+        let mut ciphertexts = Vec::new();
+        for i in 0..ITERATIONS {
+            let owner = match i % 4 {
+                0 => Owner::Public(address),
+                1 => Owner::Private(Plaintext::from(Literal::Address(address))),
+                2 => Owner::Public(other_address),
+                _ => Owner::Private(Plaintext::from(Literal::Address(other_address))),
+            };
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record {
+                owner,
+                data: IndexMap::from_iter([(
+                    Identifier::from_str("a")?,
+                    Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                )]),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+            };
+            ciphertexts.push(record.encrypt(randomizer)?);
+            expected.push(if i % 4 < 2 { Some(record) } else { None });
+        }
+
+        // Ensure the records owned by the view key are decrypted.
+        assert_eq!(expected, Record::decrypt_many(&ciphertexts, &view_key)?);
+        assert!(Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::decrypt_many(&[], &view_key)?.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_encrypt_and_decrypt() -> Result<()> {
         let mut rng = TestRng::default();
@@ -28,6 +28,25 @@ impl<N: Network> Record<N, Plaintext<N>> {
         }
     }
 
+    /// Encrypts `self` for the record owner and for the given auditor under the given randomizer,
+    /// returning the owner ciphertext and the auditor ciphertext.
+    ///
+    /// Both ciphertexts share the record nonce, so the auditor decrypts its ciphertext
+    /// with `Record::decrypt` using the auditor view key, as the owner does with theirs.
+    #[allow(clippy::type_complexity)]
+    pub fn encrypt_dual(
+        &self,
+        auditor: &Address<N>,
+        randomizer: Scalar<N>,
+    ) -> Result<(Record<N, Ciphertext<N>>, Record<N, Ciphertext<N>>)> {
+        // Encrypt the record for the owner, which ensures the randomizer corresponds to the record nonce.
+        let owner_ciphertext = self.encrypt(randomizer)?;
+        // Compute the auditor record view key.
+        let auditor_record_view_key = (**auditor * randomizer).to_x_coordinate();
+        // Encrypt the record for the auditor.
+        Ok((owner_ciphertext, self.encrypt_symmetric(&auditor_record_view_key)?))
+    }
+
     /// Encrypts `self` under the given record view key.
     pub fn encrypt_symmetric(&self, record_view_key: &Field<N>) -> Result<Record<N, Ciphertext<N>>> {
         // Determine the number of randomizers needed to encrypt the record.
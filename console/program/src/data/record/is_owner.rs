@@ -36,11 +36,29 @@ impl<N: Network> Record<N, Ciphertext<N>> {
             // If the owner is public, check if the address is the owner.
             Owner::Public(owner) => &owner.to_x_coordinate() == address_x_coordinate,
             // If the owner is private, decrypt the owner to check if it matches the address.
-            Owner::Private(ciphertext) => {
+            Owner::Private(..) => {
                 // Compute the record view key.
-                let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+                let record_view_key = self.to_record_view_key(view_key);
+                // Check if the address is the owner.
+                self.is_owner_with_record_view_key(&record_view_key, address_x_coordinate)
+            }
+        }
+    }
+
+    /// Decrypts `self` into plaintext using the given record view key,
+    /// and the x-coordinate of the address corresponding to the record view key.
+    pub(super) fn is_owner_with_record_view_key(
+        &self,
+        record_view_key: &Field<N>,
+        address_x_coordinate: &Field<N>,
+    ) -> bool {
+        match &self.owner {
+            // If the owner is public, check if the address is the owner.
+            Owner::Public(owner) => &owner.to_x_coordinate() == address_x_coordinate,
+            // If the owner is private, decrypt the owner to check if it matches the address.
+            Owner::Private(ciphertext) => {
                 // Compute the 0th randomizer.
-                let randomizer = N::hash_many_psd8(&[N::encryption_domain(), record_view_key], 1);
+                let randomizer = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], 1);
                 // Decrypt the owner.
                 let owner_x = ciphertext[0] - randomizer[0];
                 // Compare the x coordinates of computed and supplied addresses.
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the record as canonical JSON, which is:
+    /// `{ "type": "record", "owner": { "visibility": <visibility>, "value": <address> },
+    ///    "data": { <name>: { "visibility": <visibility>, "value": <plaintext> }, ... }, "nonce": <group> }`.
+    ///
+    /// The visibility is `constant`, `public`, or `private`, and the plaintext is encoded as in `Plaintext::to_json`.
+    /// The nonce is the x-coordinate of the nonce, without its type suffix.
+    pub fn to_json(&self) -> serde_json::Value {
+        // Encode the owner.
+        let owner = match &self.owner {
+            Owner::Public(address) => serde_json::json!({ "visibility": "public", "value": address.to_string() }),
+            Owner::Private(Plaintext::Literal(Literal::Address(address), ..)) => {
+                serde_json::json!({ "visibility": "private", "value": address.to_string() })
+            }
+            Owner::Private(plaintext) => serde_json::json!({ "visibility": "private", "value": plaintext.to_json() }),
+        };
+        // Encode the entries.
+        let data = self
+            .data
+            .iter()
+            .map(|(name, entry)| {
+                let (visibility, plaintext) = match entry {
+                    Entry::Constant(plaintext) => ("constant", plaintext),
+                    Entry::Public(plaintext) => ("public", plaintext),
+                    Entry::Private(plaintext) => ("private", plaintext),
+                };
+                (name.to_string(), serde_json::json!({ "visibility": visibility, "value": plaintext.to_json() }))
+            })
+            .collect();
+        // Encode the nonce.
+        let nonce = self.nonce.to_string();
+        let nonce = nonce.strip_suffix("group").unwrap_or(&nonce);
+
+        serde_json::json!({
+            "type": "record",
+            "owner": owner,
+            "data": serde_json::Value::Object(data),
+            "nonce": nonce,
+        })
+    }
+
+    /// Returns the record from its canonical JSON. See `Record::to_json` for the encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Ensure the JSON is a record.
+        ensure!(json.get("type").and_then(serde_json::Value::as_str) == Some("record"), "Expected a JSON record");
+
+        // Returns the visibility and value of the given JSON entry.
+        let visibility_and_value = |json: &serde_json::Value| -> Result<(String, serde_json::Value)> {
+            match (json.get("visibility").and_then(serde_json::Value::as_str), json.get("value")) {
+                (Some(visibility), Some(value)) => Ok((visibility.to_string(), value.clone())),
+                _ => bail!("Expected a visibility and value in the JSON record entry '{json}'"),
+            }
+        };
+
+        // Parse the owner.
+        let owner = match json.get("owner").map(visibility_and_value).transpose()? {
+            Some((visibility, serde_json::Value::String(address))) => {
+                let address = Address::from_str(&address)?;
+                match visibility.as_str() {
+                    "public" => Owner::Public(address),
+                    "private" => Owner::Private(Plaintext::from(Literal::Address(address))),
+                    _ => bail!("Invalid visibility '{visibility}' for the owner of the JSON record"),
+                }
+            }
+            _ => bail!("Expected an owner address in the JSON record '{json}'"),
+        };
+
+        // Parse the entries.
+        let Some(data) = json.get("data").and_then(serde_json::Value::as_object) else {
+            bail!("Expected the data of the JSON record '{json}'")
+        };
+        let data = data
+            .iter()
+            .map(|(name, entry)| {
+                let (visibility, value) = visibility_and_value(entry)?;
+                let plaintext = Plaintext::from_json(&value)?;
+                let entry = match visibility.as_str() {
+                    "constant" => Entry::Constant(plaintext),
+                    "public" => Entry::Public(plaintext),
+                    "private" => Entry::Private(plaintext),
+                    _ => bail!("Invalid visibility '{visibility}' for the entry '{name}' of the JSON record"),
+                };
+                Ok((Identifier::from_str(name)?, entry))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        // Parse the nonce.
+        let nonce = match json.get("nonce").and_then(serde_json::Value::as_str) {
+            Some(nonce) => Group::from_str(&format!("{nonce}group"))?,
+            None => bail!("Expected a nonce in the JSON record '{json}'"),
+        };
+
+        Self::from_plaintext(owner, data, nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 5u64.private, token: { id: 7field.public }, kind: 1u8.constant, _nonce: 0group.public }",
+        )?;
+        let json = serde_json::json!({
+            "type": "record",
+            "owner": { "visibility": "private", "value": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah" },
+            "data": {
+                "amount": { "visibility": "private", "value": { "type": "u64", "value": "5" } },
+                "token": {
+                    "visibility": "public",
+                    "value": { "type": "struct", "members": { "id": { "type": "field", "value": "7" } } }
+                },
+                "kind": { "visibility": "constant", "value": { "type": "u8", "value": "1" } }
+            },
+            "nonce": "0"
+        });
+        assert_eq!(json, expected.to_json());
+        assert_eq!(expected, Record::from_json(&json)?);
+
+        // Ensure an invalid visibility is rejected.
+        let mut invalid = json.clone();
+        invalid["data"]["amount"]["visibility"] = serde_json::json!("secret");
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_json(&invalid).is_err());
+        // Ensure a reserved entry name is rejected.
+        let mut invalid = json;
+        invalid["data"]["owner"] =
+            serde_json::json!({ "visibility": "public", "value": { "type": "u8", "value": "1" } });
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_json(&invalid).is_err());
+        Ok(())
+    }
+}
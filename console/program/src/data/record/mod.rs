@@ -24,15 +24,20 @@ mod encrypt;
 mod equal;
 mod find;
 mod is_owner;
+mod json;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serial_number;
 mod serialize;
 mod tag;
 mod to_bits;
+mod to_checksum;
 mod to_commitment;
 mod to_fields;
+mod to_nonce;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
 use snarkvm_console_account::{Address, PrivateKey, ViewKey};
@@ -41,6 +46,9 @@ use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
 use indexmap::IndexMap;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// A value stored in program record.
 #[derive(Clone)]
 pub struct Record<N: Network, Private: Visibility> {
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum number of entries of a randomly-sampled record.
+const MAX_SAMPLE_ENTRIES: usize = 4;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns a randomly-sampled record, with a public or private owner, and a bounded number of entries
+    /// of randomly-sampled plaintexts, each with a random visibility.
+    ///
+    /// The plaintexts of the entries are literals, or structs of literals, as the string representation
+    /// of an entry does not round trip for a nested struct that is not the last member of its struct.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample the owner.
+        let address = Address::new(Group::rand(rng));
+        let owner = match rng.gen() {
+            true => Owner::Public(address),
+            false => Owner::Private(Plaintext::from(Literal::Address(address))),
+        };
+
+        // Sample the entries, excluding the reserved entry names.
+        let num_entries = rng.gen_range(0..=MAX_SAMPLE_ENTRIES);
+        let data = (0..num_entries)
+            .map(|_| {
+                let plaintext = Plaintext::sample_with_depth(1, rng);
+                let entry = match rng.gen_range(0..3) {
+                    0 => Entry::Constant(plaintext),
+                    1 => Entry::Public(plaintext),
+                    _ => Entry::Private(plaintext),
+                };
+                (Identifier::sample(rng), entry)
+            })
+            .filter(|(name, _)| name.to_string() != "owner")
+            .collect();
+
+        Self::from_plaintext(owner, data, Group::rand(rng)).or_halt_with::<N>("Failed to sample a record")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random record.
+            let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::sample(&mut rng);
+
+            // Ensure the record round trips through its string and byte representations.
+            assert_eq!(expected, Record::from_str(&expected.to_string())?);
+            assert_eq!(expected, Record::read_le(&expected.to_bytes_le()?[..])?);
+        }
+        Ok(())
+    }
+}
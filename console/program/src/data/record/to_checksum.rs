@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the record checksum, from encrypting `self` under the given randomizer.
+    pub fn to_checksum(&self, randomizer: Scalar<N>) -> Result<Field<N>> {
+        self.encrypt(randomizer)?.to_checksum()
+    }
+
+    /// Returns the record checksum, from encrypting `self` under the given record view key.
+    pub fn to_checksum_symmetric(&self, record_view_key: &Field<N>) -> Result<Field<N>> {
+        self.encrypt_symmetric(record_view_key)?.to_checksum()
+    }
+
+    /// Returns the record checksum, from encrypting `self` for the record owner and for the given auditor.
+    pub fn to_dual_checksum(&self, auditor: &Address<N>, randomizer: Scalar<N>) -> Result<Field<N>> {
+        let (owner_ciphertext, auditor_ciphertext) = self.encrypt_dual(auditor, randomizer)?;
+        owner_ciphertext.to_dual_checksum(&auditor_ciphertext)
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns the record checksum, as the hash of the encrypted record.
+    pub fn to_checksum(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bits_le())
+    }
+
+    /// Returns the record checksum of an audited record, as the hash of the owner ciphertext
+    /// followed by the given auditor ciphertext.
+    pub fn to_dual_checksum(&self, auditor_ciphertext: &Self) -> Result<Field<N>> {
+        N::hash_bhp1024(&[self.to_bits_le(), auditor_ciphertext.to_bits_le()].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_checksum() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for index in 0..ITERATIONS {
+            // Sample a view key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Prepare the record, with the nonce for a sampled transition view key.
+            let tvk = Field::rand(&mut rng);
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+                Owner::Private(Plaintext::from(Literal::Address(address))),
+                IndexMap::from_iter([(
+                    Identifier::from_str("a")?,
+                    Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                )]),
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::to_nonce(&tvk, index)?,
+            )?;
+            let randomizer = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::to_randomizer(&tvk, index)?;
+
+            // Compute the expected checksum, from the encrypted record.
+            let ciphertext = record.encrypt(randomizer)?;
+            let expected = CurrentNetwork::hash_bhp1024(&ciphertext.to_bits_le())?;
+            assert_eq!(expected, ciphertext.to_checksum()?);
+
+            // Ensure the checksum is recomputed from the randomizer, or the view key of the owner.
+            assert_eq!(expected, record.to_checksum(randomizer)?);
+            assert_eq!(expected, record.to_checksum_symmetric(&record.to_record_view_key(&view_key))?);
+            assert_eq!(record.to_record_view_key(&view_key), ciphertext.to_record_view_key(&view_key));
+
+            // Ensure a mismatching randomizer is rejected.
+            assert!(record.to_checksum(Scalar::rand(&mut rng)).is_err());
+
+            // Compute the expected dual checksum, from the owner and auditor ciphertexts.
+            let auditor = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            let (owner_ciphertext, auditor_ciphertext) = record.encrypt_dual(&auditor, randomizer)?;
+            let expected_dual = CurrentNetwork::hash_bhp1024(
+                &[owner_ciphertext.to_bits_le(), auditor_ciphertext.to_bits_le()].concat(),
+            )?;
+            assert_eq!(expected_dual, owner_ciphertext.to_dual_checksum(&auditor_ciphertext)?);
+            assert_eq!(expected_dual, record.to_dual_checksum(&auditor, randomizer)?);
+            // Ensure the dual checksum differs from the owner checksum.
+            assert_ne!(expected, expected_dual);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// A helper method to derive the record randomizer from the transition view key and output index.
+    pub fn to_randomizer(tvk: &Field<N>, index: u64) -> Result<Scalar<N>> {
+        // Compute the randomizer as `HashToScalar(tvk || index)`.
+        N::hash_to_scalar_psd2(&[*tvk, Field::from_u64(index)])
+    }
+
+    /// A helper method to derive the record nonce from the transition view key and output index.
+    pub fn to_nonce(tvk: &Field<N>, index: u64) -> Result<Group<N>> {
+        // Compute the nonce as `randomizer * G`.
+        Ok(N::g_scalar_multiply(&Self::to_randomizer(tvk, index)?))
+    }
+
+    /// Returns the record view key of `self`, using the given view key.
+    pub fn to_record_view_key(&self, view_key: &ViewKey<N>) -> Field<N> {
+        // Compute the record view key as `(nonce * view_key).x`.
+        self.nonce.mul_constant_time(view_key).to_x_coordinate()
+    }
+}
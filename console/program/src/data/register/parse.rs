@@ -19,26 +19,28 @@ impl<N: Network> Parser for Register<N> {
     /// The register is of the form `r{locator}` or `r{locator}.{identifier}`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        // Parse the register character from the string.
-        let (string, _) = tag("r")(string)?;
-        // Parse the locator from the string.
-        let (string, locator) =
-            map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)?;
-        // Parse the identifier from the string, if it is a register member.
-        let (string, identifiers): (&str, Vec<Identifier<N>>) =
-            map_res(many0(pair(tag("."), Identifier::parse)), |identifiers| {
-                // Ensure the number of identifiers is within the limit.
-                if identifiers.len() <= N::MAX_DATA_DEPTH {
-                    Ok(identifiers.iter().cloned().map(|(_, identifier)| identifier).collect())
-                } else {
-                    Err(error(format!("Register \'r{locator}\' has too many identifiers ({})", identifiers.len())))
-                }
-            })(string)?;
-        // Return the register.
-        Ok((string, match identifiers.len() {
-            0 => Self::Locator(locator),
-            _ => Self::Member(locator, identifiers),
-        }))
+        context("register", |string| {
+            // Parse the register character from the string.
+            let (string, _) = tag("r")(string)?;
+            // Parse the locator from the string.
+            let (string, locator) =
+                map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)?;
+            // Parse the identifier from the string, if it is a register member.
+            let (string, identifiers): (&str, Vec<Identifier<N>>) =
+                map_res(many0(pair(tag("."), Identifier::parse)), |identifiers| {
+                    // Ensure the number of identifiers is within the limit.
+                    if identifiers.len() <= N::MAX_DATA_DEPTH {
+                        Ok(identifiers.iter().cloned().map(|(_, identifier)| identifier).collect())
+                    } else {
+                        Err(error(format!("Register \'r{locator}\' has too many identifiers ({})", identifiers.len())))
+                    }
+                })(string)?;
+            // Return the register.
+            Ok((string, match identifiers.len() {
+                0 => Self::Locator(locator),
+                _ => Self::Member(locator, identifiers),
+            }))
+        })(string)
     }
 }
 
@@ -51,11 +53,15 @@ impl<N: Network> FromStr for Register<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
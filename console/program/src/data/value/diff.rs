@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A difference between two values, at the path of member names from the root of the values.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Difference<N: Network> {
+    /// The member was added, with the given value.
+    Added(Vec<Identifier<N>>, Value<N>),
+    /// The member was removed, with the given (previous) value.
+    Removed(Vec<Identifier<N>>, Value<N>),
+    /// The member changed from the first value to the second value.
+    Changed(Vec<Identifier<N>>, Value<N>, Value<N>),
+}
+
+impl<N: Network> Difference<N> {
+    /// Returns the path of member names to the difference.
+    pub fn path(&self) -> &[Identifier<N>] {
+        match self {
+            Self::Added(path, ..) | Self::Removed(path, ..) | Self::Changed(path, ..) => path,
+        }
+    }
+}
+
+impl<N: Network> Value<N> {
+    /// Returns the differences from `self` to `other`, in the order of the members of `self`,
+    /// followed by the members that are only in `other`. If the values are equal, this is empty.
+    ///
+    /// Note: The record nonces are not compared, as every record has a different nonce.
+    pub fn diff(&self, other: &Self) -> Vec<Difference<N>> {
+        let mut differences = Vec::new();
+        match (self, other) {
+            (Self::Plaintext(a), Self::Plaintext(b)) => a.diff_internal(b, &mut Vec::new(), &mut differences),
+            (Self::Record(a), Self::Record(b)) => diff_records(a, b, &mut differences),
+            (a, b) => differences.push(Difference::Changed(vec![], a.clone(), b.clone())),
+        }
+        differences
+    }
+}
+
+/// Appends the differences from record `a` to record `b` to the given differences.
+fn diff_records<N: Network>(
+    a: &Record<N, Plaintext<N>>,
+    b: &Record<N, Plaintext<N>>,
+    differences: &mut Vec<Difference<N>>,
+) {
+    // Returns the plaintext of the given owner.
+    let to_plaintext = |owner: &Owner<N, Plaintext<N>>| match owner {
+        Owner::Public(address) => Plaintext::from(Literal::Address(*address)),
+        Owner::Private(plaintext) => plaintext.clone(),
+    };
+    // Returns the plaintext of the given entry.
+    let entry_plaintext = |entry: &Entry<N, Plaintext<N>>| match entry {
+        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => plaintext.clone(),
+    };
+
+    // Compare the owners.
+    if a.owner() != b.owner() {
+        // Note: This unwrap is safe, as 'owner' is a valid identifier.
+        let path = vec![Identifier::from_str("owner").unwrap()];
+        differences.push(Difference::Changed(
+            path,
+            Value::Plaintext(to_plaintext(a.owner())),
+            Value::Plaintext(to_plaintext(b.owner())),
+        ));
+    }
+
+    // Compare the entries of `a` to the entries of `b`.
+    for (name, entry_a) in a.data() {
+        match b.data().get(name) {
+            // If the entries have the same visibility, compare their plaintexts.
+            Some(entry_b) if core::mem::discriminant(entry_a) == core::mem::discriminant(entry_b) => {
+                entry_plaintext(entry_a).diff_internal(&entry_plaintext(entry_b), &mut vec![*name], differences)
+            }
+            // Otherwise, the entry changed visibility.
+            Some(entry_b) => differences.push(Difference::Changed(
+                vec![*name],
+                Value::Plaintext(entry_plaintext(entry_a)),
+                Value::Plaintext(entry_plaintext(entry_b)),
+            )),
+            None => differences.push(Difference::Removed(vec![*name], Value::Plaintext(entry_plaintext(entry_a)))),
+        }
+    }
+    // Add the entries that are only in `b`.
+    for (name, entry_b) in b.data().iter().filter(|(name, _)| !a.data().contains_key(*name)) {
+        differences.push(Difference::Added(vec![*name], Value::Plaintext(entry_plaintext(entry_b))));
+    }
+}
+
+impl<N: Network> Debug for Difference<N> {
+    /// Prints the difference as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Difference<N> {
+    /// Prints the difference as a string, such as `token.owner changed from X to Y`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the path, or `value` if the difference is at the root.
+        match self.path().is_empty() {
+            true => write!(f, "value")?,
+            false => write!(f, "{}", self.path().iter().join("."))?,
+        }
+        match self {
+            Self::Added(_, value) => write!(f, " added as {value}"),
+            Self::Removed(_, value) => write!(f, " removed from {value}"),
+            Self::Changed(_, a, b) => write!(f, " changed from {a} to {b}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_diff_plaintext() -> Result<()> {
+        let a = Value::<CurrentNetwork>::from_str("{ token: { amount: 1u64, memo: 2field }, owner: 3field }")?;
+        let b = Value::<CurrentNetwork>::from_str("{ token: { amount: 4u64, tag: true }, owner: 3field, new: 5u8 }")?;
+
+        // Ensure equal values have no differences.
+        assert!(a.diff(&a).is_empty());
+
+        // Ensure the differences are path-addressed.
+        let differences = a.diff(&b);
+        assert_eq!(
+            vec![
+                "token.amount changed from 1u64 to 4u64",
+                "token.memo removed from 2field",
+                "token.tag added as true",
+                "new added as 5u8",
+            ],
+            differences.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![Identifier::from_str("token")?, Identifier::from_str("amount")?], differences[0].path());
+
+        // Ensure a literal that changes to a struct is a single difference at the root.
+        let c = Value::<CurrentNetwork>::from_str("1u64")?;
+        assert_eq!(vec![Difference::Changed(vec![], c.clone(), a.clone())], c.diff(&a));
+        assert_eq!("value changed from 1u64 to 2u64", c.diff(&Value::from_str("2u64")?)[0].to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_record() -> Result<()> {
+        let a = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 1u64.private, memo: 2field.public, _nonce: 0group.public }",
+        )?;
+        let b = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, amount: 3u64.private, memo: 2field.private, _nonce: 2group.public }",
+        )?;
+
+        // Ensure equal records have no differences.
+        assert!(a.diff(&a).is_empty());
+
+        // Ensure the owner, entry values, and entry visibilities are compared.
+        assert_eq!(
+            vec![
+                "owner changed from aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah to aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+                "amount changed from 1u64 to 3u64",
+                "memo changed from 2field to 2field",
+            ],
+            a.diff(&b).iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+
+        // Ensure a record and a plaintext differ at the root.
+        let c = Value::<CurrentNetwork>::from_str("1u64")?;
+        assert_eq!(vec![Difference::Changed(vec![], a.clone(), c.clone())], a.diff(&c));
+        Ok(())
+    }
+}
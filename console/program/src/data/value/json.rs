@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the value as canonical JSON, which preserves the type of every literal.
+    ///
+    /// A plaintext value is encoded as in `Plaintext::to_json`, for example, `5u64` is encoded as
+    /// `{ "type": "u64", "value": "5" }`, and a record value is encoded as in `Record::to_json`,
+    /// which has the type `record`. Unlike the text syntax, the encoding does not need to be parsed.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.to_json(),
+            Self::Record(record) => record.to_json(),
+        }
+    }
+
+    /// Returns the value from its canonical JSON. See `Value::to_json` for the encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        match json.get("type").and_then(serde_json::Value::as_str) {
+            Some("record") => Ok(Self::Record(Record::from_json(json)?)),
+            _ => Ok(Self::Plaintext(Plaintext::from_json(json)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        for expected in [
+            "5u64",
+            "5field",
+            "{ a: 1u8, b: { c: true } }",
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, amount: 5u64.private, _nonce: 0group.public }",
+        ] {
+            let expected = Value::<CurrentNetwork>::from_str(expected)?;
+            // Ensure the value round-trips, through JSON and its string.
+            assert_eq!(expected, Value::from_json(&expected.to_json())?);
+            assert_eq!(expected, Value::from_json(&serde_json::Value::from_str(&expected.to_json().to_string())?)?);
+        }
+        // Ensure the types of the literals are preserved.
+        assert_ne!(
+            Value::<CurrentNetwork>::from_str("5u64")?.to_json(),
+            Value::<CurrentNetwork>::from_str("5field")?.to_json()
+        );
+        Ok(())
+    }
+}
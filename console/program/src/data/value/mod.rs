@@ -12,15 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod diff;
+pub use diff::Difference;
+
 mod bytes;
 mod equal;
 mod find;
+mod json;
 mod parse;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 mod to_bits;
 mod to_fields;
 
-use crate::{Entry, Identifier, Literal, Plaintext, Record};
+use crate::{Entry, Identifier, Literal, Owner, Plaintext, Record};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns a randomly-sampled value, which is a plaintext or a record.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        match rng.gen() {
+            true => Self::Plaintext(Plaintext::sample(rng)),
+            false => Self::Record(Record::sample(rng)),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N: Network> arbitrary::Arbitrary<'a> for Value<N> {
+    /// Returns a value, which is sampled from an RNG that is seeded by the unstructured bytes.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+        Ok(Self::sample(&mut ChaChaRng::from_seed(u.arbitrary()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let expected = Value::<CurrentNetwork>::sample(&mut rng);
+
+            // Ensure the value round trips through its string, byte, and JSON representations.
+            assert_eq!(expected, Value::from_str(&expected.to_string())?);
+            assert_eq!(expected, Value::read_le(&expected.to_bytes_le()?[..])?);
+            assert_eq!(expected, serde_json::from_str(&serde_json::to_string(&expected)?)?);
+        }
+        Ok(())
+    }
+}
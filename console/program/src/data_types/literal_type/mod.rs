@@ -14,6 +14,8 @@
 
 mod bytes;
 mod parse;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 
 use snarkvm_console_network::prelude::*;
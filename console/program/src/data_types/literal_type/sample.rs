@@ -0,0 +1,25 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl LiteralType {
+    /// Returns a randomly-sampled literal type.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        match Self::from_u8(rng.gen_range(0..=Self::String.type_id())) {
+            Some(literal_type) => literal_type,
+            None => unreachable!("The literal type ID is in range"),
+        }
+    }
+}
@@ -19,10 +19,13 @@ impl<N: Network> Parser for PlaintextType<N> {
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the plaintext type (order matters).
-        alt((
-            map(LiteralType::parse, |type_| Self::Literal(type_)),
-            map(Identifier::parse, |identifier| Self::Struct(identifier)),
-        ))(string)
+        context(
+            "plaintext type",
+            alt((
+                map(LiteralType::parse, |type_| Self::Literal(type_)),
+                map(Identifier::parse, |identifier| Self::Struct(identifier)),
+            )),
+        )(string)
     }
 }
 
@@ -34,11 +37,15 @@ impl<N: Network> FromStr for PlaintextType<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -16,7 +16,7 @@ mod entry_type;
 pub use entry_type::EntryType;
 
 mod helpers;
-use helpers::PublicOrPrivate;
+pub use helpers::PublicOrPrivate;
 
 mod bytes;
 mod parse;
@@ -39,6 +39,29 @@ pub struct RecordType<N: Network> {
 }
 
 impl<N: Network> RecordType<N> {
+    /// Initializes a new record type with the given name, owner visibility, and entries.
+    ///
+    /// # Errors
+    /// This method will halt if the entries have duplicate names, or an entry is named `owner`.
+    /// This method will halt if the number of entries exceeds the maximum limit.
+    pub fn new(
+        name: Identifier<N>,
+        owner: PublicOrPrivate,
+        entries: Vec<(Identifier<N>, EntryType<N>)>,
+    ) -> Result<Self> {
+        // Prepare the reserved entry names.
+        let reserved = [Identifier::from_str("owner")?];
+        // Ensure the entries has no duplicate names.
+        ensure!(
+            !has_duplicates(entries.iter().map(|(identifier, _)| identifier).chain(reserved.iter())),
+            "Duplicate entry type found in record '{name}'"
+        );
+        // Ensure the number of entries is within the maximum limit.
+        ensure!(entries.len() <= N::MAX_DATA_ENTRIES, "Record '{name}' exceeds the maximum number of entries");
+        // Return the record type.
+        Ok(Self { name, owner, entries: IndexMap::from_iter(entries) })
+    }
+
     /// Returns the name of the record type.
     pub const fn name(&self) -> &Identifier<N> {
         &self.name
@@ -19,11 +19,14 @@ impl<N: Network> Parser for RegisterType<N> {
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the mode from the string (ordering matters).
-        alt((
-            map(pair(Locator::parse, tag(".record")), |(locator, _)| Self::ExternalRecord(locator)),
-            map(pair(Identifier::parse, tag(".record")), |(identifier, _)| Self::Record(identifier)),
-            map(PlaintextType::parse, |plaintext_type| Self::Plaintext(plaintext_type)),
-        ))(string)
+        context(
+            "register type",
+            alt((
+                map(pair(Locator::parse, tag(".record")), |(locator, _)| Self::ExternalRecord(locator)),
+                map(pair(Identifier::parse, tag(".record")), |(identifier, _)| Self::Record(identifier)),
+                map(PlaintextType::parse, |plaintext_type| Self::Plaintext(plaintext_type)),
+            )),
+        )(string)
     }
 }
 
@@ -35,11 +38,15 @@ impl<N: Network> FromStr for RegisterType<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -30,6 +30,23 @@ pub struct Struct<N: Network> {
 }
 
 impl<N: Network> Struct<N> {
+    /// Initializes a new struct with the given name and members.
+    ///
+    /// # Errors
+    /// This method will halt if the members have duplicate names.
+    /// This method will halt if the number of members exceeds the maximum limit.
+    pub fn new(name: Identifier<N>, members: Vec<(Identifier<N>, PlaintextType<N>)>) -> Result<Self> {
+        // Ensure the members has no duplicate names.
+        ensure!(
+            !has_duplicates(members.iter().map(|(identifier, _)| identifier)),
+            "Duplicate identifier found in struct '{name}'"
+        );
+        // Ensure the number of members is within the maximum limit.
+        ensure!(members.len() <= N::MAX_STRUCT_ENTRIES, "Struct '{name}' exceeds the maximum number of members");
+        // Return the struct.
+        Ok(Self { name, members: IndexMap::from_iter(members) })
+    }
+
     /// Returns the name of the struct.
     #[inline]
     pub const fn name(&self) -> &Identifier<N> {
@@ -19,10 +19,13 @@ impl<N: Network> Parser for ProgramID<N> {
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the name, ".", and network-level domain (NLD) from the string.
-        map_res(pair(Identifier::parse, pair(tag("."), Identifier::parse)), |(name, (_, network))| {
-            // Return the program ID.
-            Self::try_from((name, network))
-        })(string)
+        context(
+            "program ID",
+            map_res(pair(Identifier::parse, pair(tag("."), Identifier::parse)), |(name, (_, network))| {
+                // Return the program ID.
+                Self::try_from((name, network))
+            }),
+        )(string)
     }
 }
 
@@ -35,11 +38,15 @@ impl<N: Network> FromStr for ProgramID<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Request<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid request version"));
         }
 
@@ -50,6 +50,11 @@ impl<N: Network> FromBytes for Request<N> {
         let tsk = FromBytes::read_le(&mut reader)?;
         // Read the transition commitment.
         let tcm = FromBytes::read_le(&mut reader)?;
+        // Read the expiration height, if the request expires.
+        let valid_until = match version {
+            1 => Some(FromBytes::read_le(&mut reader)?),
+            _ => None,
+        };
 
         Ok(Self::from((
             caller,
@@ -63,7 +68,8 @@ impl<N: Network> FromBytes for Request<N> {
             tvk,
             tsk,
             tcm,
-        )))
+        ))
+        .with_valid_until(valid_until))
     }
 }
 
@@ -71,7 +77,10 @@ impl<N: Network> ToBytes for Request<N> {
     /// Writes the request to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u8.write_le(&mut writer)?;
+        match self.valid_until {
+            None => 0u8.write_le(&mut writer)?,
+            Some(_) => 1u8.write_le(&mut writer)?,
+        }
 
         // Write the caller.
         self.caller.write_le(&mut writer)?;
@@ -109,7 +118,12 @@ impl<N: Network> ToBytes for Request<N> {
         // Write the transition secret key.
         self.tsk.write_le(&mut writer)?;
         // Write the transition commitment.
-        self.tcm.write_le(&mut writer)
+        self.tcm.write_le(&mut writer)?;
+        // Write the expiration height, if the request expires.
+        match self.valid_until {
+            Some(valid_until) => valid_until.write_le(&mut writer),
+            None => Ok(()),
+        }
     }
 }
 
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod sign;
+mod verify;
+
+use crate::Request;
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+/// An endorsement of a message by the signer of a request, which is signed with the transition secret key `tsk`.
+///
+/// The endorsement is verified against the transition public key `tpk`, which is public in the transition.
+/// As the circuit of the function proves `tpk` is the nonce commitment of the request signature,
+/// only the signer of the request can endorse a message for its transition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Endorsement<N: Network> {
+    /// The verifier challenge to check against.
+    challenge: Scalar<N>,
+    /// The signer response to the challenge.
+    response: Scalar<N>,
+}
+
+impl<N: Network> From<(Scalar<N>, Scalar<N>)> for Endorsement<N> {
+    /// Derives the endorsement from a tuple `(challenge, response)`.
+    fn from((challenge, response): (Scalar<N>, Scalar<N>)) -> Self {
+        Self { challenge, response }
+    }
+}
+
+impl<N: Network> Endorsement<N> {
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the signer response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a random endorsement, with the request it endorses for and its message.
+    pub(crate) fn sample_endorsement(
+        num_fields: u64,
+        rng: &mut TestRng,
+    ) -> (Endorsement<CurrentNetwork>, Request<CurrentNetwork>, Vec<Field<CurrentNetwork>>) {
+        // Sample a request.
+        let request = crate::request::test_helpers::sample_request(rng);
+
+        // Endorse a message.
+        let message: Vec<_> = (0..num_fields).map(|_| Uniform::rand(rng)).collect();
+        let endorsement = request.endorse(&message, rng).unwrap();
+        assert!(endorsement.verify(&request.to_tpk(), &message));
+        (endorsement, request, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_from() {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new endorsement.
+            let (endorsement, _, _) = test_helpers::sample_endorsement(i, &mut rng);
+
+            // Check that the endorsement can be reconstructed from its parts.
+            let candidate = Endorsement::from((endorsement.challenge(), endorsement.response()));
+            assert_eq!(endorsement, candidate);
+        }
+    }
+}
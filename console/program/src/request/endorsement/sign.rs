@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Endorsement<N> {
+    /// Returns an endorsement `(challenge, response)` for the given message, where:
+    ///     challenge := HashToScalar(domain, nonce * G, tpk, message)
+    ///     response := nonce - challenge * tsk
+    pub fn sign<R: Rng + CryptoRng>(tsk: &Scalar<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot endorse the message: the message exceeds maximum allowed size")
+        }
+
+        // Sample a random nonce from the scalar field.
+        let nonce = Scalar::rand(rng);
+        // Compute `g_r` as `nonce * G`.
+        let g_r = N::g_scalar_multiply_constant_time(&nonce);
+        // Compute the transition public key `tpk` as `tsk * G`.
+        let tpk = N::g_scalar_multiply_constant_time(tsk);
+
+        // Construct the hash input as (domain, r * G, tpk, message).
+        let mut preimage = Vec::with_capacity(3 + message.len());
+        preimage.push(N::endorsement_domain());
+        preimage.extend([g_r, tpk].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute the signer response.
+        let response = nonce - (challenge * tsk);
+
+        // Output the endorsement.
+        Ok(Self { challenge, response })
+    }
+}
+
+impl<N: Network> Request<N> {
+    /// Returns an endorsement of the given message by the signer of this request.
+    pub fn endorse<R: Rng + CryptoRng>(&self, message: &[Field<N>], rng: &mut R) -> Result<Endorsement<N>> {
+        Endorsement::sign(self.tsk(), message, rng)
+    }
+}
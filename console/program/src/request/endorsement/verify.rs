@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Endorsement<N> {
+    /// Verifies (challenge == challenge') where:
+    ///     challenge' := HashToScalar(domain, G^response tpk^challenge, tpk, message)
+    pub fn verify(&self, tpk: &Group<N>, message: &[Field<N>]) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            eprintln!("Cannot verify the endorsement: the message exceeds maximum allowed size");
+            return false;
+        }
+
+        // Compute `g_r` := (response * G) + (challenge * tpk).
+        let g_r = N::g_scalar_multiply(&self.response) + (*tpk * self.challenge);
+
+        // Construct the hash input as (domain, r * G, tpk, message).
+        let mut preimage = Vec::with_capacity(3 + message.len());
+        preimage.push(N::endorsement_domain());
+        preimage.extend([g_r, *tpk].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        match N::hash_to_scalar_psd8(&preimage) {
+            // Return `true` if the candidate challenge is correct.
+            Ok(candidate_challenge) => self.challenge == candidate_challenge,
+            // Return `false` if the challenge errored.
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_endorse_and_verify() {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an endorsement, with its request and message.
+            let (endorsement, request, message) = test_helpers::sample_endorsement(i, rng);
+            assert!(endorsement.verify(&request.to_tpk(), &message));
+
+            // Check that the endorsement is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(!endorsement.verify(&request.to_tpk(), &failure_message));
+            }
+
+            // Check that the endorsement is invalid for the transition public key of another request.
+            let (_, other_request, _) = test_helpers::sample_endorsement(i, rng);
+            assert!(!endorsement.verify(&other_request.to_tpk(), &message));
+        }
+    }
+}
@@ -139,6 +139,20 @@ mod tests {
         assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
     }
 
+    fn check_hex<T: Debug + PartialEq + ToBytes + FromBytes>(expected: T) {
+        // Serialize
+        let expected_string =
+            format!("0x{}", expected.to_bytes_le().unwrap().iter().map(|b| format!("{b:02x}")).collect::<String>());
+        let candidate = ToHexSerializer::serialize(&expected, serde_json::value::Serializer).unwrap();
+        assert_eq!(serde_json::Value::String(expected_string.clone()), candidate);
+
+        // Deserialize
+        assert_eq!(expected, FromHexDeserializer::<T>::deserialize(candidate).unwrap());
+        assert_eq!(expected, FromHexDeserializer::<T>::from_hex(&expected_string).unwrap());
+        assert!(FromHexDeserializer::<T>::from_hex(&expected_string[2..]).is_err());
+        assert!(FromHexDeserializer::<T>::from_hex(&format!("{expected_string}00")).is_err());
+    }
+
     #[test]
     fn test_serde_json() {
         for case in TEST_CASES.iter() {
@@ -152,4 +166,11 @@ mod tests {
             check_bincode(InputID::<CurrentNetwork>::from_str(case).unwrap());
         }
     }
+
+    #[test]
+    fn test_hex() {
+        for case in TEST_CASES.iter() {
+            check_hex(InputID::<CurrentNetwork>::from_str(case).unwrap());
+        }
+    }
 }
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod endorsement;
+pub use endorsement::Endorsement;
+
 mod input_id;
 pub use input_id::InputID;
 
@@ -50,6 +53,8 @@ pub struct Request<N: Network> {
     tsk: Scalar<N>,
     /// The transition commitment.
     tcm: Field<N>,
+    /// The last block height at which the request may be finalized, if the request expires.
+    valid_until: Option<u32>,
 }
 
 impl<N: Network>
@@ -87,7 +92,20 @@ impl<N: Network>
         if *network_id != N::ID {
             N::halt(format!("Invalid network ID. Expected {}, found {}", N::ID, *network_id))
         } else {
-            Self { caller, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tsk, tcm }
+            Self {
+                caller,
+                network_id,
+                program_id,
+                function_name,
+                input_ids,
+                inputs,
+                signature,
+                sk_tag,
+                tvk,
+                tsk,
+                tcm,
+                valid_until: None,
+            }
         }
     }
 }
@@ -159,10 +177,31 @@ impl<N: Network> Request<N> {
     pub const fn tcm(&self) -> &Field<N> {
         &self.tcm
     }
+
+    /// Returns the last block height at which the request may be finalized, if the request expires.
+    pub const fn valid_until(&self) -> Option<u32> {
+        self.valid_until
+    }
+
+    /// Returns the request, with the given expiration height.
+    /// Note: See `Request::sign_with_expiration` to create the request. This method is used to eject from a circuit.
+    pub const fn with_valid_until(mut self, valid_until: Option<u32>) -> Self {
+        self.valid_until = valid_until;
+        self
+    }
+
+    /// Returns the expiration height as a field element, if the request expires.
+    ///
+    /// The expiration is signed in the request after the function ID, and is a public input of the transition proof
+    /// after the transition commitment. A request that does not expire has neither, so its signature message
+    /// and its transition circuit are unchanged.
+    pub fn expiration_field(valid_until: Option<u32>) -> Option<Field<N>> {
+        valid_until.map(Field::from_u32)
+    }
 }
 
 #[cfg(test)]
-mod test_helpers {
+pub(crate) mod test_helpers {
     use super::*;
     use snarkvm_console_network::Testnet3;
 
@@ -170,6 +209,23 @@ mod test_helpers {
 
     const ITERATIONS: u64 = 1000;
 
+    /// Samples a random request, with a single public input.
+    pub(crate) fn sample_request(rng: &mut TestRng) -> Request<CurrentNetwork> {
+        // Sample a random private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Construct the input, and its input type.
+        let inputs = vec![Value::from_str("{ token_amount: 1u128 }").unwrap()];
+        let input_types = [ValueType::from_str("amount.public").unwrap()];
+
+        // Compute the signed request.
+        Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap()
+    }
+
     pub(super) fn sample_requests(rng: &mut TestRng) -> Vec<Request<CurrentNetwork>> {
         (0..ITERATIONS)
             .map(|i| {
@@ -202,9 +258,21 @@ mod test_helpers {
                     ValueType::from_str("token.aleo/token.record").unwrap(),
                 ];
 
+                // Sample an expiration height, for every other request.
+                let valid_until = (i % 2 == 1).then_some(u32::try_from(i).unwrap());
+
                 // Compute the signed request.
-                let request =
-                    Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap();
+                let request = Request::sign_with_expiration(
+                    &private_key,
+                    program_id,
+                    function_name,
+                    inputs.into_iter(),
+                    &input_types,
+                    valid_until,
+                    rng,
+                )
+                .unwrap();
+                assert_eq!(valid_until, request.valid_until());
                 assert!(request.verify(&input_types));
                 request
             })
@@ -33,6 +33,9 @@ impl<N: Network> Serialize for Request<N> {
                 transition.serialize_field("tvk", &self.tvk)?;
                 transition.serialize_field("tsk", &self.tsk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
+                if let Some(valid_until) = &self.valid_until {
+                    transition.serialize_field("valid_until", valid_until)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -71,7 +74,12 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
                     DeserializeExt::take_from_value::<D>(&mut request, "tsk")?,
                     // Retrieve the `tcm`.
                     DeserializeExt::take_from_value::<D>(&mut request, "tcm")?,
-                )))
+                ))
+                // Retrieve the expiration height, if the request expires.
+                .with_valid_until(match request.get("valid_until") {
+                    Some(_) => Some(DeserializeExt::take_from_value::<D>(&mut request, "valid_until")?),
+                    None => None,
+                }))
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "request"),
         }
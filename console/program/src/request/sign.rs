@@ -25,6 +25,25 @@ impl<N: Network> Request<N> {
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         input_types: &[ValueType<N>],
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_with_expiration(private_key, program_id, function_name, inputs, input_types, None, rng)
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types,
+    /// expiration height, and RNG, where:
+    ///     challenge := HashToScalar(r * G, pk_sig, pr_sig, caller, \[tvk, tcm, function ID, expiration, input IDs\])
+    ///     response := r - challenge * sk_sig
+    ///
+    /// The expiration is only signed if the request expires (see `Request::expiration_field`), so a request
+    /// without an expiration height has the same signature message as `Request::sign`.
+    pub fn sign_with_expiration<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        valid_until: Option<u32>,
+        rng: &mut R,
     ) -> Result<Self> {
         // Ensure the number of inputs matches the number of input types.
         if input_types.len() != inputs.len() {
@@ -57,12 +76,12 @@ impl<N: Network> Request<N> {
         // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
         let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
         // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
-        let g_r = N::g_scalar_multiply(&r);
+        let g_r = N::g_scalar_multiply_constant_time(&r);
 
         // Derive the caller from the compute key.
         let caller = Address::try_from(compute_key)?;
         // Compute the transition view key `tvk` as `r * caller`.
-        let tvk = (*caller * r).to_x_coordinate();
+        let tvk = caller.to_group().mul_constant_time(&r).to_x_coordinate();
         // Compute the transition commitment `tcm` as `Hash(tvk)`.
         let tcm = N::hash_psd2(&[tvk])?;
 
@@ -75,6 +94,8 @@ impl<N: Network> Request<N> {
         let mut message = Vec::with_capacity(5 + 2 * inputs.len());
         message.extend([g_r, pk_sig, pr_sig, *caller].map(|point| point.to_x_coordinate()));
         message.extend([tvk, tcm, function_id]);
+        // Extend the hash input with the expiration, if the request expires.
+        message.extend(Self::expiration_field(valid_until));
 
         // Initialize a vector to store the prepared inputs.
         let mut prepared_inputs = Vec::with_capacity(inputs.len());
@@ -171,9 +192,9 @@ impl<N: Network> Request<N> {
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
                     // Compute `h_r` as `r * H`.
-                    let h_r = h * r;
+                    let h_r = h.mul_constant_time(&r);
                     // Compute `gamma` as `sk_sig * H`.
-                    let gamma = h * sk_sig;
+                    let gamma = h.mul_constant_time(&sk_sig);
 
                     // Compute the `serial_number` from `gamma`.
                     let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
@@ -227,6 +248,7 @@ impl<N: Network> Request<N> {
             tvk,
             tsk: r,
             tcm,
+            valid_until,
         })
     }
 }
@@ -19,6 +19,8 @@ impl<N: Network> Request<N> {
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, caller, \[tvk, tcm, function ID, input IDs\])
+    ///
+    /// If the request expires, the expiration is signed after the function ID (see `Request::sign_with_expiration`).
     pub fn verify(&self, input_types: &[ValueType<N>]) -> bool {
         // Verify the transition public key, transition view key, and transition commitment are well-formed.
         {
@@ -76,6 +78,8 @@ impl<N: Network> Request<N> {
         message.push(self.tvk);
         message.push(self.tcm);
         message.push(function_id);
+        // Extend the signature message with the expiration, if the request expires.
+        message.extend(Self::expiration_field(self.valid_until));
 
         if let Err(error) = self.input_ids.iter().zip_eq(&self.inputs).zip_eq(input_types).enumerate().try_for_each(
             |(index, ((input_id, input), input_type))| {
@@ -268,4 +272,47 @@ mod tests {
             assert!(request.verify(&input_types));
         }
     }
+
+    #[test]
+    fn test_sign_and_verify_with_expiration() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS / 10 {
+            // Sample a random private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Construct a program ID and function name.
+            let program_id = ProgramID::from_str("token.aleo").unwrap();
+            let function_name = Identifier::from_str("transfer").unwrap();
+
+            // Construct the input, and its input type.
+            let inputs = [Value::from_str("{ token_amount: 9876543210u128 }").unwrap()];
+            let input_types = vec![ValueType::from_str("amount.public").unwrap()];
+
+            // Compute the signed request, with an expiration height.
+            let request = Request::sign_with_expiration(
+                &private_key,
+                program_id,
+                function_name,
+                inputs.iter(),
+                &input_types,
+                Some(10),
+                rng,
+            )
+            .unwrap();
+            assert_eq!(Some(10), request.valid_until());
+            assert!(request.verify(&input_types));
+
+            // Ensure the request is invalid, if the expiration height is changed.
+            assert!(!request.clone().with_valid_until(Some(11)).verify(&input_types));
+            // Ensure the request is invalid, if the expiration height is removed.
+            assert!(!request.clone().with_valid_until(None).verify(&input_types));
+
+            // Ensure the request is invalid, if an expiration height is added to a request that does not expire.
+            let request =
+                Request::sign(&private_key, program_id, function_name, inputs.iter(), &input_types, rng).unwrap();
+            assert!(request.verify(&input_types));
+            assert!(!request.with_valid_until(Some(10)).verify(&input_types));
+        }
+    }
 }
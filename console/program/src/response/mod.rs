@@ -12,24 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Identifier, ProgramID, Register, Value, ValueType};
+mod output_id;
+pub use output_id::OutputID;
+
+use crate::{Identifier, Plaintext, ProgramID, Record, Register, Value, ValueType};
+use snarkvm_console_account::Address;
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum OutputID<N: Network> {
-    /// The hash of the constant output.
-    Constant(Field<N>),
-    /// The hash of the public output.
-    Public(Field<N>),
-    /// The ciphertext hash of the private output.
-    Private(Field<N>),
-    /// The `(commitment, checksum)` tuple of the record output.
-    Record(Field<N>, Field<N>),
-    /// The hash of the external record output.
-    ExternalRecord(Field<N>),
-}
-
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Response<N: Network> {
     /// The output ID for the transition.
@@ -47,6 +37,9 @@ impl<N: Network> From<(Vec<OutputID<N>>, Vec<Value<N>>)> for Response<N> {
 
 impl<N: Network> Response<N> {
     /// Initializes a new response.
+    ///
+    /// If the program declares an auditor, each record output is additionally encrypted to the auditor,
+    /// and the record checksum commits to both ciphertexts.
     pub fn new(
         network_id: &U16<N>,
         program_id: &ProgramID<N>,
@@ -57,6 +50,7 @@ impl<N: Network> Response<N> {
         outputs: Vec<Value<N>>,
         output_types: &[ValueType<N>],
         output_operands: &[Option<Register<N>>],
+        auditor: Option<&Address<N>>,
     ) -> Result<Self> {
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
         let function_id =
@@ -149,15 +143,14 @@ impl<N: Network> Response<N> {
                         // Compute the record commitment.
                         let commitment = record.to_commitment(program_id, record_name)?;
 
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u64(output_register.locator());
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-                        let randomizer = N::hash_to_scalar_psd2(&[*tvk, index])?;
-
-                        // Encrypt the record, using the randomizer.
-                        let encrypted_record = record.encrypt(randomizer)?;
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = N::hash_bhp1024(&encrypted_record.to_bits_le())?;
+                        let randomizer = Record::<N, Plaintext<N>>::to_randomizer(tvk, output_register.locator())?;
+                        let checksum = match auditor {
+                            // Compute the record checksum, as the hash of the owner and auditor encrypted records.
+                            Some(auditor) => record.to_dual_checksum(auditor, randomizer)?,
+                            // Compute the record checksum, as the hash of the encrypted record (using the randomizer).
+                            None => record.to_checksum(randomizer)?,
+                        };
 
                         // Return the output ID.
                         Ok(OutputID::Record(commitment, checksum))
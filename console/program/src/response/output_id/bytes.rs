@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for OutputID<N> {
+    /// Reads the output ID from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Match the variant.
+        match variant {
+            // Constant output.
+            0 => Ok(Self::Constant(Field::read_le(&mut reader)?)),
+            // Public output.
+            1 => Ok(Self::Public(Field::read_le(&mut reader)?)),
+            // Private output.
+            2 => Ok(Self::Private(Field::read_le(&mut reader)?)),
+            // Record output.
+            3 => {
+                // Read the commitment.
+                let commitment = Field::read_le(&mut reader)?;
+                // Read the checksum.
+                let checksum = Field::read_le(&mut reader)?;
+                // Return the record output.
+                Ok(Self::Record(commitment, checksum))
+            }
+            // External record output.
+            4 => Ok(Self::ExternalRecord(Field::read_le(&mut reader)?)),
+            // Invalid output.
+            _ => Err(error("Invalid output ID variant")),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for OutputID<N> {
+    /// Writes the output ID to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            // Constant output.
+            Self::Constant(value) => {
+                // Write the variant.
+                0u8.write_le(&mut writer)?;
+                // Write the value.
+                value.write_le(&mut writer)
+            }
+            // Public output.
+            Self::Public(value) => {
+                // Write the variant.
+                1u8.write_le(&mut writer)?;
+                // Write the value.
+                value.write_le(&mut writer)
+            }
+            // Private output.
+            Self::Private(value) => {
+                // Write the variant.
+                2u8.write_le(&mut writer)?;
+                // Write the value.
+                value.write_le(&mut writer)
+            }
+            // Record output.
+            Self::Record(commitment, checksum) => {
+                // Write the variant.
+                3u8.write_le(&mut writer)?;
+                // Write the commitment.
+                commitment.write_le(&mut writer)?;
+                // Write the checksum.
+                checksum.write_le(&mut writer)
+            }
+            // External record output.
+            Self::ExternalRecord(value) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the value.
+                value.write_le(&mut writer)
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum OutputID<N: Network> {
+    /// The hash of the constant output.
+    Constant(Field<N>),
+    /// The hash of the public output.
+    Public(Field<N>),
+    /// The ciphertext hash of the private output.
+    Private(Field<N>),
+    /// The `(commitment, checksum)` tuple of the record output.
+    Record(Field<N>, Field<N>),
+    /// The hash of the external record output.
+    ExternalRecord(Field<N>),
+}
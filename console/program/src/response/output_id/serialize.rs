@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for OutputID<N> {
+    /// Serializes the output ID into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => match self {
+                Self::Constant(id) => {
+                    let mut output = serializer.serialize_struct("OutputID", 2)?;
+                    output.serialize_field("type", "constant")?;
+                    output.serialize_field("id", &id)?;
+                    output.end()
+                }
+                Self::Public(id) => {
+                    let mut output = serializer.serialize_struct("OutputID", 2)?;
+                    output.serialize_field("type", "public")?;
+                    output.serialize_field("id", &id)?;
+                    output.end()
+                }
+                Self::Private(id) => {
+                    let mut output = serializer.serialize_struct("OutputID", 2)?;
+                    output.serialize_field("type", "private")?;
+                    output.serialize_field("id", &id)?;
+                    output.end()
+                }
+                Self::Record(commitment, checksum) => {
+                    let mut output = serializer.serialize_struct("OutputID", 3)?;
+                    output.serialize_field("type", "record")?;
+                    output.serialize_field("commitment", &commitment)?;
+                    output.serialize_field("checksum", &checksum)?;
+                    output.end()
+                }
+                Self::ExternalRecord(id) => {
+                    let mut output = serializer.serialize_struct("OutputID", 2)?;
+                    output.serialize_field("type", "external_record")?;
+                    output.serialize_field("id", &id)?;
+                    output.end()
+                }
+            },
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for OutputID<N> {
+    /// Deserializes the output ID from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the output ID from a string into a value.
+                let mut output = serde_json::Value::deserialize(deserializer)?;
+                // Recover the output.
+                let output_id = match output.get("type").and_then(|t| t.as_str()) {
+                    Some("constant") => OutputID::Constant(DeserializeExt::take_from_value::<D>(&mut output, "id")?),
+                    Some("public") => OutputID::Public(DeserializeExt::take_from_value::<D>(&mut output, "id")?),
+                    Some("private") => OutputID::Private(DeserializeExt::take_from_value::<D>(&mut output, "id")?),
+                    Some("record") => OutputID::Record(
+                        DeserializeExt::take_from_value::<D>(&mut output, "commitment")?,
+                        DeserializeExt::take_from_value::<D>(&mut output, "checksum")?,
+                    ),
+                    Some("external_record") => {
+                        OutputID::ExternalRecord(DeserializeExt::take_from_value::<D>(&mut output, "id")?)
+                    }
+                    _ => return Err(de::Error::custom("Invalid output type")),
+                };
+                Ok(output_id)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "output ID"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Add test cases here to be checked for serialization.
+    const TEST_CASES: &[&str] = &[
+        "{\"type\":\"constant\",\"id\":\"5field\"}",
+        "{\"type\":\"public\",\"id\":\"0field\"}",
+        "{\"type\":\"private\",\"id\":\"123field\"}",
+        "{\"type\":\"record\",\"commitment\":\"123123field\",\"checksum\":\"0field\"}",
+        "{\"type\":\"external_record\",\"id\":\"123456789field\"}",
+    ];
+
+    fn check_serde_json<
+        T: Serialize + for<'a> Deserialize<'a> + Debug + Display + PartialEq + Eq + FromStr + ToBytes + FromBytes,
+    >(
+        expected: T,
+    ) {
+        // Serialize
+        let expected_string = expected.to_string();
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        let candidate = serde_json::from_str::<T>(&candidate_string).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_string, candidate_string);
+        assert_eq!(expected_string, candidate.to_string());
+
+        // Deserialize
+        assert_eq!(expected, T::from_str(&expected_string).unwrap_or_else(|_| panic!("FromStr: {expected_string}")));
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+    }
+
+    fn check_bincode<
+        T: Serialize + for<'a> Deserialize<'a> + Debug + Display + PartialEq + Eq + FromStr + ToBytes + FromBytes,
+    >(
+        expected: T,
+    ) {
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, T::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+    }
+
+    fn check_hex<T: Debug + PartialEq + ToBytes + FromBytes>(expected: T) {
+        // Serialize
+        let expected_string =
+            format!("0x{}", expected.to_bytes_le().unwrap().iter().map(|b| format!("{b:02x}")).collect::<String>());
+        let candidate = ToHexSerializer::serialize(&expected, serde_json::value::Serializer).unwrap();
+        assert_eq!(serde_json::Value::String(expected_string.clone()), candidate);
+
+        // Deserialize
+        assert_eq!(expected, FromHexDeserializer::<T>::deserialize(candidate).unwrap());
+        assert_eq!(expected, FromHexDeserializer::<T>::from_hex(&expected_string).unwrap());
+        assert!(FromHexDeserializer::<T>::from_hex(&expected_string[2..]).is_err());
+        assert!(FromHexDeserializer::<T>::from_hex(&format!("{expected_string}00")).is_err());
+    }
+
+    #[test]
+    fn test_serde_json() {
+        for case in TEST_CASES.iter() {
+            check_serde_json(OutputID::<CurrentNetwork>::from_str(case).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for case in TEST_CASES.iter() {
+            check_bincode(OutputID::<CurrentNetwork>::from_str(case).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_hex() {
+        for case in TEST_CASES.iter() {
+            check_hex(OutputID::<CurrentNetwork>::from_str(case).unwrap());
+        }
+    }
+}
@@ -276,6 +276,71 @@ pub mod test_helpers {
         ))
     }
 
+    /// Randomly sample the given number of state paths to a global state root, where every state path is in the same block.
+    pub fn sample_global_state_paths<N: Network>(num_paths: usize, rng: &mut TestRng) -> Result<Vec<StatePath<N>>> {
+        // Construct a transaction for each state path, each with one transition.
+        let mut transactions = Vec::with_capacity(num_paths);
+        for _ in 0..num_paths {
+            // Construct the transition path and transaction leaf.
+            let transition_leaf = TransitionLeaf::new_with_version(0, 3, Field::rand(rng));
+            let transition_tree: TransitionTree<N> = N::merkle_tree_bhp(&[transition_leaf.to_bits_le()])?;
+            let transition_id = transition_tree.root();
+            let transition_path = transition_tree.prove(0, &transition_leaf.to_bits_le())?;
+
+            // Construct the transaction path and transaction leaf.
+            let transaction_leaf = TransactionLeaf::new_execution(0, *transition_id);
+            let transaction_tree: TransactionTree<N> = N::merkle_tree_bhp(&[transaction_leaf.to_bits_le()])?;
+            let transaction_id = *transaction_tree.root();
+            let transaction_path = transaction_tree.prove(0, &transaction_leaf.to_bits_le())?;
+
+            transactions.push((transaction_id, transaction_path, transaction_leaf, transition_path, transition_leaf));
+        }
+
+        // Construct the transactions tree.
+        let transaction_ids = transactions.iter().map(|(id, ..)| id.to_bits_le()).collect::<Vec<_>>();
+        let transactions_tree: TransactionsTree<N> = N::merkle_tree_bhp(&transaction_ids)?;
+        let transactions_root = transactions_tree.root();
+
+        // Construct the block header path.
+        let header_leaf = HeaderLeaf::<N>::new(1, *transactions_root);
+        let header_tree: HeaderTree<N> =
+            N::merkle_tree_bhp(&[Field::<N>::zero().to_bits_le(), header_leaf.to_bits_le()])?;
+        let header_root = header_tree.root();
+        let header_path = header_tree.prove(1, &header_leaf.to_bits_le())?;
+
+        let previous_block_hash: N::BlockHash = Field::<N>::rand(rng).into();
+        let preimage = (*previous_block_hash).to_bits_le().into_iter().chain(header_root.to_bits_le());
+        let block_hash = N::hash_bhp1024(&preimage.collect::<Vec<_>>())?;
+
+        // Construct the global state root and block path.
+        let block_tree: BlockTree<N> = N::merkle_tree_bhp(&[block_hash.to_bits_le()])?;
+        let global_state_root = *block_tree.root();
+        let block_path = block_tree.prove(0, &block_hash.to_bits_le())?;
+
+        // Construct the state paths.
+        transactions
+            .into_iter()
+            .enumerate()
+            .map(|(index, (transaction_id, transaction_path, transaction_leaf, transition_path, transition_leaf))| {
+                Ok(StatePath::<N>::from(
+                    global_state_root.into(),
+                    block_path.clone(),
+                    block_hash.into(),
+                    previous_block_hash,
+                    *header_root,
+                    header_path.clone(),
+                    header_leaf,
+                    transactions_tree.prove(index, &transaction_id.to_bits_le())?,
+                    transaction_id.into(),
+                    transaction_path,
+                    transaction_leaf,
+                    transition_path,
+                    transition_leaf,
+                ))
+            })
+            .collect()
+    }
+
     /// Randomly sample a state path to a local state root.
     /// If a `commitment` is given, it is used. Otherwise, a `commitment` is randomly sampled.
     pub fn sample_local_state_path<N: Network>(
@@ -21,6 +21,7 @@ mod bytes;
 mod compare;
 mod from_bits;
 mod one;
+mod pack;
 mod parse;
 mod random;
 mod serialize;
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Packs the given **little-endian** bits into field elements, each holding up to `Field::size_in_data_bits()` bits.
+    /// This matches the packing of public bits at the circuit boundary, and is used to construct the verifier inputs.
+    pub fn pack_bits_le(bits_le: &[bool]) -> Result<Vec<Self>> {
+        bits_le.chunks(Self::size_in_data_bits()).map(Self::from_bits_le).collect()
+    }
+
+    /// Unpacks `num_bits` **little-endian** bits from the given packed field elements.
+    /// This method ensures the field elements are the canonical packing of exactly `num_bits` bits.
+    pub fn unpack_bits_le(fields: &[Self], num_bits: usize) -> Result<Vec<bool>> {
+        // Retrieve the number of bits held by each field element.
+        let size_in_data_bits = Self::size_in_data_bits();
+        // Ensure the number of field elements matches the number of bits.
+        let expected = (num_bits + size_in_data_bits - 1) / size_in_data_bits;
+        ensure!(fields.len() == expected, "Expected {expected} packed field elements, found {}", fields.len());
+
+        let mut bits_le = Vec::with_capacity(num_bits);
+        for (index, field) in fields.iter().enumerate() {
+            // Compute the number of bits in this field element.
+            let num_chunk_bits = core::cmp::min(size_in_data_bits, num_bits - index * size_in_data_bits);
+            // Ensure the excess bits are zero.
+            let field_bits = field.to_bits_le();
+            ensure!(
+                !field_bits[num_chunk_bits..].iter().any(|bit| *bit),
+                "Packed field element {index} is out of range"
+            );
+            bits_le.extend_from_slice(&field_bits[..num_chunk_bits]);
+        }
+        Ok(bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_pack_and_unpack_bits_le() -> Result<()> {
+        let mut rng = TestRng::default();
+        let size_in_data_bits = Field::<CurrentEnvironment>::size_in_data_bits();
+
+        for num_bits in [0, 1, 8, size_in_data_bits - 1, size_in_data_bits, size_in_data_bits + 1, 1000] {
+            for _ in 0..ITERATIONS {
+                // Sample random bits.
+                let bits_le = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+                // Pack the bits.
+                let packed = Field::<CurrentEnvironment>::pack_bits_le(&bits_le)?;
+                assert_eq!((num_bits + size_in_data_bits - 1) / size_in_data_bits, packed.len());
+
+                // Unpack the bits.
+                let candidate = Field::unpack_bits_le(&packed, num_bits)?;
+                assert_eq!(bits_le, candidate);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_bits_le_fails() -> Result<()> {
+        let packed = Field::<CurrentEnvironment>::pack_bits_le(&[true, false, true])?;
+
+        // Ensure the number of field elements must match the number of bits.
+        assert!(Field::unpack_bits_le(&packed, 0).is_err());
+        assert!(Field::unpack_bits_le(&[packed[0], packed[0]], 3).is_err());
+        // Ensure set bits beyond the given number of bits are rejected.
+        assert!(Field::unpack_bits_le(&packed, 2).is_err());
+        assert!(Field::unpack_bits_le(&packed, 3).is_ok());
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// Returns `self * scalar`, where the sequence of operations does not depend on the scalar.
+    ///
+    /// This method is intended for secret scalars, such as private keys and signing nonces.
+    /// It doubles and adds for every bit of the scalar, selects the addend arithmetically instead of branching,
+    /// and uses the unified addition formula, which has no special cases for the identity or for doubling.
+    /// The field arithmetic is only free of branches on the values if the `constant_time` feature is enabled.
+    pub fn mul_constant_time(&self, scalar: &Scalar<E>) -> Self {
+        let base = ExtendedPoint::<E>::from_group(self);
+
+        let mut product = ExtendedPoint::<E>::zero();
+        for bit in scalar.to_bits_le().iter().rev() {
+            product = product.add(&product);
+            product = product.add(&ExtendedPoint::select(*bit, &base, &ExtendedPoint::zero()));
+        }
+        product.to_group()
+    }
+}
+
+/// A point in extended twisted Edwards coordinates, i.e. `(x, y) = (X / Z, Y / Z)` with `T = XY / Z`.
+#[derive(Copy, Clone)]
+struct ExtendedPoint<E: Environment> {
+    x: E::Field,
+    y: E::Field,
+    t: E::Field,
+    z: E::Field,
+}
+
+impl<E: Environment> ExtendedPoint<E> {
+    /// Returns the identity.
+    fn zero() -> Self {
+        Self { x: E::Field::zero(), y: E::Field::one(), t: E::Field::zero(), z: E::Field::one() }
+    }
+
+    /// Returns the given group element in extended coordinates.
+    fn from_group(group: &Group<E>) -> Self {
+        let affine = group.group.to_affine();
+        let (x, y) = (affine.to_x_coordinate(), affine.to_y_coordinate());
+        Self { x, y, t: x * y, z: E::Field::one() }
+    }
+
+    /// Returns the group element, normalizing with an inversion by exponentiation (i.e. `z^(p - 2)`),
+    /// as the exponent is public, unlike the inputs of the binary extended Euclidean algorithm.
+    fn to_group(self) -> Group<E> {
+        // Compute `p - 2`, which does not underflow, as the modulus is an odd prime.
+        let mut exponent = E::Field::characteristic().to_vec();
+        let mut borrow = 2u64;
+        for limb in exponent.iter_mut() {
+            let (difference, underflow) = limb.overflowing_sub(borrow);
+            *limb = difference;
+            borrow = underflow as u64;
+        }
+        let z_inverse = self.z.pow(exponent);
+        Group::from_xy_coordinates_unchecked(Field::new(self.x * z_inverse), Field::new(self.y * z_inverse))
+    }
+
+    /// Returns `first` if `condition` is set, and `second` otherwise, as `second + condition * (first - second)`.
+    fn select(condition: bool, first: &Self, second: &Self) -> Self {
+        let condition = E::Field::from(condition as u64);
+        let select = |first: E::Field, second: E::Field| second + condition * (first - second);
+        Self {
+            x: select(first.x, second.x),
+            y: select(first.y, second.y),
+            t: select(first.t, second.t),
+            z: select(first.z, second.z),
+        }
+    }
+
+    /// Returns `self + other`, with the unified addition formula from "Twisted Edwards Curves Revisited" (3.1).
+    fn add(&self, other: &Self) -> Self {
+        let a = self.x * other.x;
+        let b = self.y * other.y;
+        let c = E::EDWARDS_D * self.t * other.t;
+        let d = self.z * other.z;
+        let e = (self.x + self.y) * (other.x + other.y) - a - b;
+        let f = d - c;
+        let g = d + c;
+        let h = b - E::EDWARDS_A * a;
+        Self { x: e * f, y: g * h, t: e * h, z: f * g }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_mul_constant_time() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let base: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let scalar: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+            assert_eq!(base * scalar, base.mul_constant_time(&scalar));
+        }
+
+        // Ensure the edge cases match.
+        let base: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+        assert_eq!(Group::zero(), base.mul_constant_time(&Scalar::zero()));
+        assert_eq!(base, base.mul_constant_time(&Scalar::one()));
+        assert_eq!(Group::zero(), Group::<CurrentEnvironment>::zero().mul_constant_time(&Scalar::one()));
+        assert_eq!(-base, base.mul_constant_time(&-Scalar::one()));
+    }
+}
@@ -30,4 +30,51 @@ impl<E: Environment> Group<E> {
         }
         bail!("Failed to recover an affine group from an x-coordinate of {x_coordinate}")
     }
+
+    /// Attempts to recover an affine group element from a given x-coordinate field element and sign bit,
+    /// where the sign bit is `true` if and only if the y-coordinate is the lexicographically largest root.
+    ///
+    /// Note that at most one of the two candidate points lies in the prime-order subgroup,
+    /// so this method fails if the sign bit does not select that point.
+    pub fn from_x_coordinate_with_sign(x_coordinate: Field<E>, sign: bool) -> Result<Self> {
+        match E::Affine::from_x_coordinate(*x_coordinate, sign) {
+            Some(point) if point.is_in_correct_subgroup_assuming_on_curve() => Ok(Self::new(point)),
+            _ => bail!("Failed to recover an affine group from an x-coordinate of {x_coordinate} with sign {sign}"),
+        }
+    }
+
+    /// Returns the sign bit of the group element, which is `true` if and only if
+    /// the y-coordinate is the lexicographically largest root.
+    pub fn to_sign(&self) -> bool {
+        let y_coordinate = self.to_y_coordinate();
+        *y_coordinate > *(-y_coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_x_coordinate_with_sign() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let (x_coordinate, sign) = (expected.to_x_coordinate(), expected.to_sign());
+
+            // Ensure the element is recovered from its x-coordinate and sign bit.
+            assert_eq!(expected, Group::from_x_coordinate_with_sign(x_coordinate, sign)?);
+            assert_eq!(expected, Group::from_x_coordinate(x_coordinate)?);
+            // Ensure the opposite sign bit does not recover an element in the subgroup.
+            assert!(Group::<CurrentEnvironment>::from_x_coordinate_with_sign(x_coordinate, !sign).is_err());
+        }
+        Ok(())
+    }
 }
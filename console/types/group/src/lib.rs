@@ -16,8 +16,10 @@
 #![warn(clippy::cast_possible_truncation)]
 
 mod arithmetic;
+mod batch_mul;
 mod bitwise;
 mod bytes;
+mod constant_time;
 mod from_bits;
 mod from_field;
 mod from_fields;
@@ -19,4 +19,39 @@ impl<E: Environment> Group<E> {
     pub fn to_x_coordinate(&self) -> Field<E> {
         Field::new(self.group.to_affine().to_x_coordinate())
     }
+
+    /// Returns the *x-coordinates* in the affine coordinates of the given groups.
+    ///
+    /// This shares a single field inversion across all of the groups, instead of one for each group.
+    pub fn to_x_coordinates(groups: &[Self]) -> Vec<Field<E>> {
+        // Normalize the groups, which batches the field inversions.
+        let mut projective = groups.iter().map(|group| group.group).collect::<Vec<_>>();
+        E::Projective::batch_normalization(&mut projective);
+        // Retrieve the x-coordinates.
+        projective.into_iter().map(|group| Field::new(group.to_affine().to_x_coordinate())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_x_coordinates() {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the groups, including the zero group.
+            let mut groups: Vec<Group<CurrentEnvironment>> = (0..i % 8).map(|_| Uniform::rand(&mut rng)).collect();
+            groups.push(Group::zero());
+            // Ensure the x-coordinates match.
+            let expected = groups.iter().map(Group::to_x_coordinate).collect::<Vec<_>>();
+            assert_eq!(expected, Group::to_x_coordinates(&groups));
+        }
+    }
 }
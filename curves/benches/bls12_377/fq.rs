@@ -186,6 +186,23 @@ pub(crate) fn bench_fq_mul_assign(c: &mut Criterion) {
     });
 }
 
+pub(crate) fn bench_fq_batch_product_in_place(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = TestRng::default();
+
+    let a: Vec<Fq> = (0..SAMPLES).map(|_| Fq::rand(&mut rng)).collect();
+    let b: Vec<Fq> = (0..SAMPLES).map(|_| Fq::rand(&mut rng)).collect();
+
+    c.bench_function("bls12_377: fq_batch_product_in_place", |c| {
+        c.iter(|| {
+            let mut tmp = a.clone();
+            Fq::batch_product_in_place(&mut tmp, &b);
+            tmp
+        })
+    });
+}
+
 pub(crate) fn bench_fq_double(c: &mut Criterion) {
     const SAMPLES: usize = 1000;
 
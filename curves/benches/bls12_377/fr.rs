@@ -186,6 +186,23 @@ pub(crate) fn bench_fr_mul_assign(c: &mut Criterion) {
     });
 }
 
+pub(crate) fn bench_fr_batch_product_in_place(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = TestRng::default();
+
+    let a: Vec<Fr> = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect();
+    let b: Vec<Fr> = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect();
+
+    c.bench_function("bls12_377: fr_batch_product_in_place", |c| {
+        c.iter(|| {
+            let mut tmp = a.clone();
+            Fr::batch_product_in_place(&mut tmp, &b);
+            tmp
+        })
+    });
+}
+
 pub(crate) fn bench_fr_double(c: &mut Criterion) {
     const SAMPLES: usize = 1000;
 
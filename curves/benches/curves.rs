@@ -41,6 +41,7 @@ criterion_group!(
     bls12_377::fq::bench_fq_add_assign,
     bls12_377::fq::bench_fq_sub_assign,
     bls12_377::fq::bench_fq_mul_assign,
+    bls12_377::fq::bench_fq_batch_product_in_place,
     bls12_377::fq::bench_fq_double,
     bls12_377::fq::bench_fq_square,
     bls12_377::fq::bench_fq_inverse,
@@ -81,6 +82,7 @@ criterion_group!(
     bls12_377::fr::bench_fr_add_assign,
     bls12_377::fr::bench_fr_sub_assign,
     bls12_377::fr::bench_fr_mul_assign,
+    bls12_377::fr::bench_fr_batch_product_in_place,
     bls12_377::fr::bench_fr_double,
     bls12_377::fr::bench_fr_square,
     bls12_377::fr::bench_fr_inverse,
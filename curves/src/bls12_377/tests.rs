@@ -195,6 +195,40 @@ fn test_fq_sum_of_products() {
     }
 }
 
+#[test]
+fn test_fr_batch_product_in_place() {
+    let mut rng = TestRng::default();
+    for i in [0, 1, 7, 8, 16, 31, 64, 100] {
+        let a = (0..i).map(|_| rng.gen()).collect::<Vec<Fr>>();
+        let b = (0..i).map(|_| rng.gen()).collect::<Vec<Fr>>();
+        let mut c = a.clone();
+        Fr::batch_product_in_place(&mut c, &b);
+        assert_eq!(c, a.into_iter().zip(b).map(|(a, b)| a * b).collect::<Vec<_>>());
+    }
+
+    // Check the products of the largest elements.
+    let mut c = vec![-Fr::one(); 16];
+    Fr::batch_product_in_place(&mut c, &vec![-Fr::one(); 16]);
+    assert_eq!(c, vec![Fr::one(); 16]);
+}
+
+#[test]
+fn test_fq_batch_product_in_place() {
+    let mut rng = TestRng::default();
+    for i in [0, 1, 7, 8, 16, 31, 64, 100] {
+        let a = (0..i).map(|_| rng.gen()).collect::<Vec<Fq>>();
+        let b = (0..i).map(|_| rng.gen()).collect::<Vec<Fq>>();
+        let mut c = a.clone();
+        Fq::batch_product_in_place(&mut c, &b);
+        assert_eq!(c, a.into_iter().zip(b).map(|(a, b)| a * b).collect::<Vec<_>>());
+    }
+
+    // Check the products of the largest elements.
+    let mut c = vec![-Fq::one(); 16];
+    Fq::batch_product_in_place(&mut c, &vec![-Fq::one(); 16]);
+    assert_eq!(c, vec![Fq::one(); 16]);
+}
+
 #[test]
 fn test_fq_repr_num_bits() {
     let mut a = BigInteger384::from(0);
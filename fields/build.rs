@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rustc_version::{version, Version};
+
+fn main() {
+    let version = version().unwrap();
+    // Declare the custom cfg flags, which are supported as of Rust 1.80.
+    if version >= Version::new(1, 80, 0) {
+        println!("cargo:rustc-check-cfg=cfg(avx512_intrinsics)");
+        // The NEON backend is only dispatched to if the build sets `--cfg snarkvm_neon_dispatch`.
+        println!("cargo:rustc-check-cfg=cfg(snarkvm_neon_dispatch)");
+    }
+    // The AVX-512 intrinsics are only stable as of Rust 1.89.
+    if version >= Version::new(1, 89, 0) {
+        println!("cargo:rustc-cfg=avx512_intrinsics");
+    } else if std::env::var_os("CARGO_FEATURE_AVX512").is_some() {
+        println!("cargo:warning=The `avx512` feature requires Rust 1.89 or newer, and is ignored by this compiler.");
+    }
+}
@@ -15,6 +15,7 @@
 use crate::{
     impl_add_sub_from_field_ref,
     impl_mul_div_from_field_ref,
+    vectorized::{self, MontgomeryLimbs},
     FftField,
     Field,
     FieldError,
@@ -70,11 +71,33 @@ impl<P: Fp384Parameters> Fp384<P> {
 
     #[inline]
     fn reduce(&mut self) {
-        if !self.is_valid() {
-            self.0.sub_noborrow(&P::MODULUS);
+        #[cfg(not(feature = "constant_time"))]
+        {
+            if !self.is_valid() {
+                self.0.sub_noborrow(&P::MODULUS);
+            }
+        }
+        #[cfg(feature = "constant_time")]
+        {
+            // Subtract the modulus, and keep the result unless it underflows, without branching on the value.
+            let mut reduced = self.0;
+            let borrow = reduced.sub_noborrow(&P::MODULUS);
+            self.0 = Self::select(borrow, &self.0, &reduced);
         }
     }
 
+    /// Returns `first` if `condition` is set, and `second` otherwise, without branching on the condition.
+    #[cfg(feature = "constant_time")]
+    #[inline]
+    fn select(condition: bool, first: &BigInteger, second: &BigInteger) -> BigInteger {
+        let mask = 0u64.wrapping_sub(condition as u64);
+        let mut result = *second;
+        for (limb, first) in result.0.iter_mut().zip(first.0.iter()) {
+            *limb = (first & mask) | (*limb & !mask);
+        }
+        result
+    }
+
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -165,6 +188,21 @@ impl<P: Fp384Parameters> Fp384<P> {
     }
 }
 
+impl<P: Fp384Parameters> MontgomeryLimbs<6> for Fp384<P> {
+    const INV: u64 = P::INV;
+    const MODULUS: [u64; 6] = P::MODULUS.0;
+
+    #[inline]
+    fn limbs(&self) -> &[u64; 6] {
+        &(self.0).0
+    }
+
+    #[inline]
+    fn limbs_mut(&mut self) -> &mut [u64; 6] {
+        &mut (self.0).0
+    }
+}
+
 impl<P: Fp384Parameters> Zero for Fp384<P> {
     #[inline]
     fn zero() -> Self {
@@ -271,6 +309,13 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
         result
     }
 
+    fn batch_product_in_place(a: &mut [Self], b: &[Self]) {
+        assert_eq!(a.len(), b.len(), "Mismatched lengths in batch product");
+        // 6 limbs of 64 bits fit in 8 limbs of 52 bits.
+        let offset = vectorized::batch_mul_assign::<Self, 6, 8>(a, b);
+        a[offset..].iter_mut().zip(&b[offset..]).for_each(|(a, b)| *a *= b);
+    }
+
     #[inline]
     fn double(&self) -> Self {
         let mut temp = *self;
@@ -696,12 +741,24 @@ impl<P: Fp384Parameters> Neg for Fp384<P> {
     #[inline]
     #[must_use]
     fn neg(self) -> Self {
-        if !self.is_zero() {
+        #[cfg(not(feature = "constant_time"))]
+        {
+            if !self.is_zero() {
+                let mut tmp = P::MODULUS;
+                tmp.sub_noborrow(&self.0);
+                Fp384::<P>(tmp, PhantomData)
+            } else {
+                self
+            }
+        }
+        #[cfg(feature = "constant_time")]
+        {
+            // Subtract from the modulus, and reduce the modulus to zero, without branching on the value.
             let mut tmp = P::MODULUS;
             tmp.sub_noborrow(&self.0);
-            Fp384::<P>(tmp, PhantomData)
-        } else {
-            self
+            let mut result = Fp384::<P>(tmp, PhantomData);
+            result.reduce();
+            result
         }
     }
 }
@@ -763,12 +820,22 @@ impl<'a, P: Fp384Parameters> AddAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if other.0 > self.0 {
-            self.0.add_nocarry(&P::MODULUS);
-        }
+        #[cfg(not(feature = "constant_time"))]
+        {
+            // If `other` is larger than `self`, add the modulus to self first.
+            if other.0 > self.0 {
+                self.0.add_nocarry(&P::MODULUS);
+            }
 
-        self.0.sub_noborrow(&other.0);
+            self.0.sub_noborrow(&other.0);
+        }
+        #[cfg(feature = "constant_time")]
+        {
+            // Subtract `other`, and add the modulus if it underflows, without branching on the values.
+            let borrow = self.0.sub_noborrow(&other.0);
+            let modulus = Self::select(borrow, &P::MODULUS, &BigInteger::from(0));
+            self.0.add_nocarry(&modulus);
+        }
     }
 }
 
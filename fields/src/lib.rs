@@ -13,7 +13,8 @@
 // limitations under the License.
 
 #![allow(clippy::module_inception)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(any(feature = "avx512", feature = "neon", test)), forbid(unsafe_code))]
+#![cfg_attr(any(feature = "avx512", feature = "neon", test), deny(unsafe_code))]
 
 #[macro_use]
 extern crate derivative;
@@ -50,6 +51,8 @@ pub use to_field_vec::*;
 pub mod traits;
 pub use traits::*;
 
+mod vectorized;
+
 use snarkvm_utilities::{
     biginteger::*,
     serialize::{CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize, CanonicalSerializeWithFlags},
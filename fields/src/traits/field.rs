@@ -125,6 +125,12 @@ pub trait Field:
         a.zip(b).map(|(a, b)| *a * b).sum::<Self>()
     }
 
+    /// Sets `a[i] = a[i] * b[i]` for every `i`.
+    fn batch_product_in_place(a: &mut [Self], b: &[Self]) {
+        assert_eq!(a.len(), b.len(), "Mismatched lengths in batch product");
+        a.iter_mut().zip(b).for_each(|(a, b)| *a *= b);
+    }
+
     /// Computes the multiplicative inverse of `self` if `self` is nonzero.
     #[must_use]
     fn inverse(&self) -> Option<Self>;
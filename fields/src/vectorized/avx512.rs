@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An AVX-512 IFMA backend, which multiplies 8 field elements at a time.
+//!
+//! The IFMA instructions multiply the low 52 bits of each 64-bit lane, and accumulate the low or
+//! high 52 bits of the 104-bit product. Each lane holds one field element, re-encoded in `L` limbs
+//! of 52 bits, and the product is computed with a CIOS Montgomery multiplication in radix `2^52`.
+//! As the Montgomery form of the field divides by `2^{64N}`, which need not be a multiple of `2^52`,
+//! the last reduction step only clears the remaining `64N - 52(L - 1)` bits.
+
+use super::MontgomeryLimbs;
+
+use core::arch::x86_64::*;
+
+/// The number of field elements processed at a time.
+const LANES: usize = 8;
+
+/// The mask for a 52-bit limb.
+const MASK: u64 = (1 << 52) - 1;
+
+/// Returns `true` if the CPU supports the AVX-512 IFMA instructions.
+pub(super) fn is_supported() -> bool {
+    std::is_x86_feature_detected!("avx512f") && std::is_x86_feature_detected!("avx512ifma")
+}
+
+/// Sets `a[i] = a[i] * b[i]` for the largest prefix of `a` whose length is a multiple of `LANES`,
+/// and returns the length of that prefix.
+///
+/// # Safety
+///
+/// The caller must ensure that `is_supported` holds, as the products use the AVX-512F and AVX-512 IFMA instructions.
+pub(super) unsafe fn batch_mul_assign<F: MontgomeryLimbs<N>, const N: usize, const L: usize>(
+    a: &mut [F],
+    b: &[F],
+) -> usize {
+    // The Montgomery reduction leaves values in `[0, 2p)`, which must fit in `N` 64-bit limbs.
+    if F::MODULUS[N - 1] >> 63 != 0 {
+        return 0;
+    }
+
+    let num_elements = a.len() - a.len() % LANES;
+    for (a, b) in a[..num_elements].chunks_exact_mut(LANES).zip(b.chunks_exact(LANES)) {
+        // Transpose the limbs, so that each vector holds the same limb of all lanes.
+        let mut lhs = [[0u64; LANES]; N];
+        let mut rhs = [[0u64; LANES]; N];
+        for lane in 0..LANES {
+            for i in 0..N {
+                lhs[i][lane] = a[lane].limbs()[i];
+                rhs[i][lane] = b[lane].limbs()[i];
+            }
+        }
+
+        // The caller ensures that the CPU supports AVX-512F and AVX-512 IFMA.
+        let product = mul_lanes::<N, L>(&lhs, &rhs, &F::MODULUS, F::INV);
+
+        for (lane, a) in a.iter_mut().enumerate() {
+            for (i, limb) in a.limbs_mut().iter_mut().enumerate() {
+                *limb = product[i][lane];
+            }
+        }
+    }
+    num_elements
+}
+
+/// Returns the Montgomery products `lhs * rhs * 2^{-64N} mod p` of the 8 lanes.
+#[target_feature(enable = "avx512f,avx512ifma")]
+unsafe fn mul_lanes<const N: usize, const L: usize>(
+    lhs: &[[u64; LANES]; N],
+    rhs: &[[u64; LANES]; N],
+    modulus: &[u64; N],
+    inv: u64,
+) -> [[u64; LANES]; N] {
+    let mut a = [_mm512_setzero_si512(); N];
+    let mut b = [_mm512_setzero_si512(); N];
+    for i in 0..N {
+        a[i] = _mm512_loadu_epi64(lhs[i].as_ptr() as *const i64);
+        b[i] = _mm512_loadu_epi64(rhs[i].as_ptr() as *const i64);
+    }
+    let (a, b) = (to_radix_52::<N, L>(&a), to_radix_52::<N, L>(&b));
+
+    let mut p = [_mm512_setzero_si512(); L];
+    for (p, limb) in p.iter_mut().zip(scalar_to_radix_52::<N, L>(modulus)) {
+        *p = _mm512_set1_epi64(limb as i64);
+    }
+
+    let t = montgomery_mul::<L>(&a, &b, &p, 64 * N - 52 * (L - 1), inv);
+    let t = from_radix_52::<N, L>(&reduce::<L>(&t, &p));
+
+    let mut result = [[0u64; LANES]; N];
+    for i in 0..N {
+        _mm512_storeu_epi64(result[i].as_mut_ptr() as *mut i64, t[i]);
+    }
+    result
+}
+
+/// Returns `a * b * 2^{-(52(L - 1) + s)} mod p` for each lane, in `[0, 2p)` and with normalized 52-bit limbs.
+/// The limbs of `a`, `b`, and `p` must be normalized, and `2p < 2^{52(L - 1) + s}` must hold.
+#[target_feature(enable = "avx512f,avx512ifma")]
+unsafe fn montgomery_mul<const L: usize>(
+    a: &[__m512i; L],
+    b: &[__m512i; L],
+    p: &[__m512i; L],
+    s: usize,
+    inv: u64,
+) -> [__m512i; L] {
+    let zero = _mm512_setzero_si512();
+    let inv = _mm512_set1_epi64((inv & MASK) as i64);
+
+    // The limbs of `t` are accumulated without carries, and `top` holds limb `L`.
+    let mut t = [zero; L];
+    let mut top = zero;
+    for (i, b_i) in b.iter().enumerate() {
+        // t += a * b_i.
+        for j in 0..L {
+            t[j] = _mm512_madd52lo_epu64(t[j], a[j], *b_i);
+        }
+        for j in 0..L - 1 {
+            t[j + 1] = _mm512_madd52hi_epu64(t[j + 1], a[j], *b_i);
+        }
+        top = _mm512_madd52hi_epu64(top, a[L - 1], *b_i);
+
+        // t += m * p, which clears the low 52 bits of t, or only the low `s` bits in the last step.
+        let mut m = _mm512_madd52lo_epu64(zero, t[0], inv);
+        if i == L - 1 {
+            m = _mm512_and_si512(m, _mm512_set1_epi64(((1u64 << s) - 1) as i64));
+        }
+        for j in 0..L {
+            t[j] = _mm512_madd52lo_epu64(t[j], p[j], m);
+        }
+        for j in 0..L - 1 {
+            t[j + 1] = _mm512_madd52hi_epu64(t[j + 1], p[j], m);
+        }
+        top = _mm512_madd52hi_epu64(top, p[L - 1], m);
+
+        // t /= 2^52, except in the last step.
+        if i < L - 1 {
+            let carry = _mm512_srli_epi64::<52>(t[0]);
+            for j in 0..L - 1 {
+                t[j] = t[j + 1];
+            }
+            t[0] = _mm512_add_epi64(t[0], carry);
+            t[L - 1] = top;
+            top = zero;
+        }
+    }
+
+    // Propagate the carries, so that each limb holds 52 bits.
+    let mask = _mm512_set1_epi64(MASK as i64);
+    for j in 0..L - 1 {
+        let carry = _mm512_srli_epi64::<52>(t[j]);
+        t[j] = _mm512_and_si512(t[j], mask);
+        t[j + 1] = _mm512_add_epi64(t[j + 1], carry);
+    }
+    let carry = _mm512_srli_epi64::<52>(t[L - 1]);
+    t[L - 1] = _mm512_and_si512(t[L - 1], mask);
+    top = _mm512_add_epi64(top, carry);
+
+    // t /= 2^s.
+    let (right, left) = (_mm512_set1_epi64(s as i64), _mm512_set1_epi64((52 - s) as i64));
+    for j in 0..L {
+        let next = if j + 1 < L { t[j + 1] } else { top };
+        t[j] = _mm512_and_si512(_mm512_or_si512(_mm512_srlv_epi64(t[j], right), _mm512_sllv_epi64(next, left)), mask);
+    }
+    t
+}
+
+/// Returns `t - p` for the lanes where `p <= t`, and `t` otherwise.
+#[target_feature(enable = "avx512f")]
+unsafe fn reduce<const L: usize>(t: &[__m512i; L], p: &[__m512i; L]) -> [__m512i; L] {
+    let mask = _mm512_set1_epi64(MASK as i64);
+
+    let mut reduced = [_mm512_setzero_si512(); L];
+    let mut borrow = _mm512_setzero_si512();
+    for j in 0..L {
+        let difference = _mm512_sub_epi64(_mm512_sub_epi64(t[j], p[j]), borrow);
+        borrow = _mm512_srli_epi64::<63>(difference);
+        reduced[j] = _mm512_and_si512(difference, mask);
+    }
+
+    // Keep `t` in the lanes where the subtraction underflowed.
+    let underflow = _mm512_test_epi64_mask(borrow, borrow);
+    let mut result = reduced;
+    for j in 0..L {
+        result[j] = _mm512_mask_blend_epi64(underflow, reduced[j], t[j]);
+    }
+    result
+}
+
+/// Re-encodes `N` 64-bit limbs as `L` 52-bit limbs, in each lane.
+#[target_feature(enable = "avx512f")]
+unsafe fn to_radix_52<const N: usize, const L: usize>(limbs: &[__m512i; N]) -> [__m512i; L] {
+    let mask = _mm512_set1_epi64(MASK as i64);
+
+    let mut result = [_mm512_setzero_si512(); L];
+    for (k, limb) in result.iter_mut().enumerate() {
+        let (word, offset) = (52 * k / 64, 52 * k % 64);
+        if word < N {
+            let mut value = _mm512_srlv_epi64(limbs[word], _mm512_set1_epi64(offset as i64));
+            if offset > 12 && word + 1 < N {
+                let high = _mm512_sllv_epi64(limbs[word + 1], _mm512_set1_epi64((64 - offset) as i64));
+                value = _mm512_or_si512(value, high);
+            }
+            *limb = _mm512_and_si512(value, mask);
+        }
+    }
+    result
+}
+
+/// Re-encodes `L` 52-bit limbs as `N` 64-bit limbs, in each lane, assuming the values fit in `N` limbs.
+#[target_feature(enable = "avx512f")]
+unsafe fn from_radix_52<const N: usize, const L: usize>(limbs: &[__m512i; L]) -> [__m512i; N] {
+    let mut result = [_mm512_setzero_si512(); N];
+    for (k, limb) in limbs.iter().enumerate() {
+        let (word, offset) = (52 * k / 64, 52 * k % 64);
+        if word < N {
+            result[word] = _mm512_or_si512(result[word], _mm512_sllv_epi64(*limb, _mm512_set1_epi64(offset as i64)));
+            if offset > 12 && word + 1 < N {
+                let high = _mm512_srlv_epi64(*limb, _mm512_set1_epi64((64 - offset) as i64));
+                result[word + 1] = _mm512_or_si512(result[word + 1], high);
+            }
+        }
+    }
+    result
+}
+
+/// Re-encodes `N` 64-bit limbs as `L` 52-bit limbs.
+fn scalar_to_radix_52<const N: usize, const L: usize>(limbs: &[u64; N]) -> [u64; L] {
+    let mut result = [0u64; L];
+    for (k, limb) in result.iter_mut().enumerate() {
+        let (word, offset) = (52 * k / 64, 52 * k % 64);
+        if word < N {
+            let mut value = limbs[word] >> offset;
+            if offset > 12 && word + 1 < N {
+                value |= limbs[word + 1] << (64 - offset);
+            }
+            *limb = value & MASK;
+        }
+    }
+    result
+}
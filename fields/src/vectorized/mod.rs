@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Vectorized backends for batches of Montgomery multiplications.
+//!
+//! A backend is compiled in when its feature is enabled, and is only selected at runtime
+//! if the CPU supports the required instructions. Otherwise, the products are left to the
+//! scalar implementation of the field.
+//!
+//! The `avx512` backend requires Rust 1.89 or newer, where the AVX-512 intrinsics are stable.
+//! On older compilers, the feature is ignored, and the build script emits a warning.
+//!
+//! The `neon` backend is compiled in with its feature, but is only selected at runtime if the build
+//! also sets `--cfg snarkvm_neon_dispatch`, until it has been validated on aarch64 hardware.
+
+#[cfg(all(feature = "avx512", avx512_intrinsics, target_arch = "x86_64"))]
+#[allow(unsafe_code)]
+mod avx512;
+
+// The NEON backend is also compiled in tests on other architectures, where its kernel runs on emulated intrinsics.
+#[cfg(any(all(feature = "neon", target_arch = "aarch64"), test))]
+#[cfg_attr(not(all(feature = "neon", target_arch = "aarch64", snarkvm_neon_dispatch)), allow(dead_code))]
+#[allow(unsafe_code)]
+mod neon;
+
+/// The minimum number of products for which a vectorized backend is used.
+#[cfg(any(
+    all(feature = "avx512", avx512_intrinsics, target_arch = "x86_64"),
+    all(feature = "neon", target_arch = "aarch64", snarkvm_neon_dispatch)
+))]
+const MIN_BATCH_SIZE: usize = 16;
+
+/// A prime field element in Montgomery form, stored as `N` little-endian 64-bit limbs.
+#[cfg_attr(
+    not(any(
+        all(feature = "avx512", avx512_intrinsics, target_arch = "x86_64"),
+        all(feature = "neon", target_arch = "aarch64", snarkvm_neon_dispatch),
+        test
+    )),
+    allow(dead_code)
+)]
+pub(crate) trait MontgomeryLimbs<const N: usize>: Copy {
+    /// The limbs of the modulus.
+    const MODULUS: [u64; N];
+    /// `-MODULUS^{-1} mod 2^64`.
+    const INV: u64;
+
+    /// Returns the limbs of the Montgomery representation.
+    fn limbs(&self) -> &[u64; N];
+
+    /// Returns the limbs of the Montgomery representation, mutably.
+    fn limbs_mut(&mut self) -> &mut [u64; N];
+}
+
+/// Sets `a[i] = a[i] * b[i]` for a prefix of `a` using a vectorized backend,
+/// and returns the length of that prefix. The remaining products are left to the caller.
+///
+/// `L` is the number of 52-bit limbs needed to hold `N` 64-bit limbs.
+#[allow(unused_variables)]
+pub(crate) fn batch_mul_assign<F: MontgomeryLimbs<N>, const N: usize, const L: usize>(a: &mut [F], b: &[F]) -> usize {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert!(52 * L >= 64 * N);
+
+    #[cfg(all(feature = "avx512", avx512_intrinsics, target_arch = "x86_64"))]
+    if a.len() >= MIN_BATCH_SIZE && avx512::is_supported() {
+        // SAFETY: The CPU supports AVX-512F and AVX-512 IFMA.
+        #[allow(unsafe_code)]
+        return unsafe { avx512::batch_mul_assign::<F, N, L>(a, b) };
+    }
+
+    #[cfg(all(feature = "neon", target_arch = "aarch64", snarkvm_neon_dispatch))]
+    if a.len() >= MIN_BATCH_SIZE && neon::is_supported() {
+        // SAFETY: The CPU supports NEON.
+        #[allow(unsafe_code)]
+        return unsafe { neon::batch_mul_assign::<F, N>(a, b) };
+    }
+
+    0
+}
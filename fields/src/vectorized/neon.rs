@@ -0,0 +1,382 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A NEON backend, which multiplies 2 field elements at a time.
+//!
+//! NEON has no 64-bit multiplication, so each lane holds one field element, re-encoded in `2N` limbs
+//! of 32 bits, and the product is computed with a CIOS Montgomery multiplication in radix `2^32`,
+//! using widening 32-bit multiply-accumulates. As `2^{32 * 2N} = 2^{64N}`, the result lands directly
+//! in the Montgomery form of the field.
+
+use super::MontgomeryLimbs;
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(not(target_arch = "aarch64"))]
+use emulated::*;
+
+/// The number of field elements processed at a time.
+const LANES: usize = 2;
+
+/// The maximum number of 32-bit limbs in a field element.
+const MAX_LIMBS: usize = 12;
+
+/// The mask for a 32-bit limb.
+const MASK: u64 = (1 << 32) - 1;
+
+/// Returns `true` if the CPU supports the NEON instructions.
+pub(super) fn is_supported() -> bool {
+    #[cfg(target_arch = "aarch64")]
+    return std::arch::is_aarch64_feature_detected!("neon");
+    #[cfg(not(target_arch = "aarch64"))]
+    return true;
+}
+
+/// Sets `a[i] = a[i] * b[i]` for the largest prefix of `a` whose length is a multiple of `LANES`,
+/// and returns the length of that prefix.
+///
+/// # Safety
+///
+/// The caller must ensure that `is_supported` holds, as the products use the NEON instructions.
+pub(super) unsafe fn batch_mul_assign<F: MontgomeryLimbs<N>, const N: usize>(a: &mut [F], b: &[F]) -> usize {
+    // Ensure the 32-bit limbs fit in the buffers of the kernel.
+    if 2 * N > MAX_LIMBS {
+        return 0;
+    }
+
+    let num_elements = a.len() - a.len() % LANES;
+    for (a, b) in a[..num_elements].chunks_exact_mut(LANES).zip(b.chunks_exact(LANES)) {
+        // Transpose the limbs, so that each vector holds the same limb of all lanes.
+        let mut lhs = [[0u64; LANES]; N];
+        let mut rhs = [[0u64; LANES]; N];
+        for lane in 0..LANES {
+            for i in 0..N {
+                lhs[i][lane] = a[lane].limbs()[i];
+                rhs[i][lane] = b[lane].limbs()[i];
+            }
+        }
+
+        // The caller ensures that the CPU supports NEON.
+        let product = mul_lanes::<N>(&lhs, &rhs, &F::MODULUS, F::INV);
+
+        for (lane, a) in a.iter_mut().enumerate() {
+            for (i, limb) in a.limbs_mut().iter_mut().enumerate() {
+                *limb = product[i][lane];
+            }
+        }
+    }
+    num_elements
+}
+
+/// Returns the Montgomery products `lhs * rhs * 2^{-64N} mod p` of the 2 lanes.
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "neon"))]
+unsafe fn mul_lanes<const N: usize>(
+    lhs: &[[u64; LANES]; N],
+    rhs: &[[u64; LANES]; N],
+    modulus: &[u64; N],
+    inv: u64,
+) -> [[u64; LANES]; N] {
+    let num_limbs = 2 * N;
+    let zero = vdupq_n_u64(0);
+    let mask = vdupq_n_u64(MASK);
+
+    // Split the 64-bit limbs into 32-bit limbs.
+    let (mut a, mut b, mut p) = ([zero; MAX_LIMBS], [zero; MAX_LIMBS], [zero; MAX_LIMBS]);
+    for i in 0..N {
+        let (lhs, rhs) = (vld1q_u64(lhs[i].as_ptr()), vld1q_u64(rhs[i].as_ptr()));
+        a[2 * i] = vandq_u64(lhs, mask);
+        a[2 * i + 1] = vshrq_n_u64::<32>(lhs);
+        b[2 * i] = vandq_u64(rhs, mask);
+        b[2 * i + 1] = vshrq_n_u64::<32>(rhs);
+        p[2 * i] = vdupq_n_u64(modulus[i] & MASK);
+        p[2 * i + 1] = vdupq_n_u64(modulus[i] >> 32);
+    }
+    // `-p^{-1} mod 2^32` is the low half of `-p^{-1} mod 2^64`.
+    let inv = vdup_n_u32(inv as u32);
+
+    // Each limb of `t` holds 32 bits, except the limb `num_limbs`, which holds the top bit of `t < 2p`.
+    let mut t = [zero; MAX_LIMBS + 2];
+    for b_i in b.iter().take(num_limbs) {
+        let b_i = vmovn_u64(*b_i);
+
+        // t += a * b_i. Each step is at most `(2^32 - 1)^2 + 2(2^32 - 1) = 2^64 - 1`, so it does not overflow.
+        let mut carry = zero;
+        for j in 0..num_limbs {
+            let sum = vmlal_u32(vaddq_u64(t[j], carry), vmovn_u64(a[j]), b_i);
+            t[j] = vandq_u64(sum, mask);
+            carry = vshrq_n_u64::<32>(sum);
+        }
+        let sum = vaddq_u64(t[num_limbs], carry);
+        t[num_limbs] = vandq_u64(sum, mask);
+        t[num_limbs + 1] = vshrq_n_u64::<32>(sum);
+
+        // t = (t + m * p) / 2^32, where `m` clears the low 32 bits of `t`.
+        let m = vmovn_u64(vmull_u32(vmovn_u64(t[0]), inv));
+        let mut carry = vshrq_n_u64::<32>(vmlal_u32(t[0], vmovn_u64(p[0]), m));
+        for j in 1..num_limbs {
+            let sum = vmlal_u32(vaddq_u64(t[j], carry), vmovn_u64(p[j]), m);
+            t[j - 1] = vandq_u64(sum, mask);
+            carry = vshrq_n_u64::<32>(sum);
+        }
+        let sum = vaddq_u64(t[num_limbs], carry);
+        t[num_limbs - 1] = vandq_u64(sum, mask);
+        t[num_limbs] = vaddq_u64(t[num_limbs + 1], vshrq_n_u64::<32>(sum));
+    }
+
+    // Compute `t - p`, and keep `t` in the lanes where the subtraction underflows.
+    let mut reduced = [zero; MAX_LIMBS];
+    let mut borrow = zero;
+    for j in 0..num_limbs {
+        let difference = vsubq_u64(vsubq_u64(t[j], p[j]), borrow);
+        borrow = vshrq_n_u64::<63>(difference);
+        reduced[j] = vandq_u64(difference, mask);
+    }
+    let underflow = vcgtq_u64(borrow, t[num_limbs]);
+
+    // Join the 32-bit limbs into 64-bit limbs.
+    let mut result = [[0u64; LANES]; N];
+    for (i, result) in result.iter_mut().enumerate() {
+        let low = vbslq_u64(underflow, t[2 * i], reduced[2 * i]);
+        let high = vbslq_u64(underflow, t[2 * i + 1], reduced[2 * i + 1]);
+        vst1q_u64(result.as_mut_ptr(), vorrq_u64(low, vshlq_n_u64::<32>(high)));
+    }
+    result
+}
+
+/// A scalar emulation of the NEON intrinsics used by the kernel, so that it can be tested on other architectures.
+#[cfg(not(target_arch = "aarch64"))]
+#[allow(non_camel_case_types)]
+mod emulated {
+    pub type uint64x2_t = [u64; 2];
+    pub type uint32x2_t = [u32; 2];
+
+    pub fn vdupq_n_u64(value: u64) -> uint64x2_t {
+        [value; 2]
+    }
+
+    pub fn vdup_n_u32(value: u32) -> uint32x2_t {
+        [value; 2]
+    }
+
+    pub unsafe fn vld1q_u64(ptr: *const u64) -> uint64x2_t {
+        [*ptr, *ptr.add(1)]
+    }
+
+    pub unsafe fn vst1q_u64(ptr: *mut u64, a: uint64x2_t) {
+        *ptr = a[0];
+        *ptr.add(1) = a[1];
+    }
+
+    pub fn vandq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        [a[0] & b[0], a[1] & b[1]]
+    }
+
+    pub fn vorrq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        [a[0] | b[0], a[1] | b[1]]
+    }
+
+    pub fn vaddq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        [a[0].wrapping_add(b[0]), a[1].wrapping_add(b[1])]
+    }
+
+    pub fn vsubq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        [a[0].wrapping_sub(b[0]), a[1].wrapping_sub(b[1])]
+    }
+
+    pub fn vshrq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t {
+        [a[0] >> N, a[1] >> N]
+    }
+
+    pub fn vshlq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t {
+        [a[0] << N, a[1] << N]
+    }
+
+    pub fn vmovn_u64(a: uint64x2_t) -> uint32x2_t {
+        [a[0] as u32, a[1] as u32]
+    }
+
+    pub fn vmull_u32(a: uint32x2_t, b: uint32x2_t) -> uint64x2_t {
+        [u64::from(a[0]) * u64::from(b[0]), u64::from(a[1]) * u64::from(b[1])]
+    }
+
+    pub fn vmlal_u32(a: uint64x2_t, b: uint32x2_t, c: uint32x2_t) -> uint64x2_t {
+        vaddq_u64(a, vmull_u32(b, c))
+    }
+
+    pub fn vcgtq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        [if a[0] > b[0] { u64::MAX } else { 0 }, if a[1] > b[1] { u64::MAX } else { 0 }]
+    }
+
+    pub fn vbslq_u64(mask: uint64x2_t, a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        [(mask[0] & a[0]) | (!mask[0] & b[0]), (mask[1] & a[1]) | (!mask[1] & b[1])]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use snarkvm_utilities::TestRng;
+
+    const ITERATIONS: usize = 100;
+
+    /// An element of the BLS12-377 base field, as 6 limbs in Montgomery form.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    struct Fq([u64; 6]);
+
+    impl MontgomeryLimbs<6> for Fq {
+        const INV: u64 = 9586122913090633727;
+        const MODULUS: [u64; 6] = [
+            0x8508c00000000001,
+            0x170b5d4430000000,
+            0x1ef3622fba094800,
+            0x1a22d9f300f5138f,
+            0xc63b05c06ca1493b,
+            0x01ae3a4617c510ea,
+        ];
+
+        fn limbs(&self) -> &[u64; 6] {
+            &self.0
+        }
+
+        fn limbs_mut(&mut self) -> &mut [u64; 6] {
+            &mut self.0
+        }
+    }
+
+    /// An element of the BLS12-377 scalar field, as 4 limbs in Montgomery form.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    struct Fr([u64; 4]);
+
+    impl MontgomeryLimbs<4> for Fr {
+        const INV: u64 = 725501752471715839;
+        const MODULUS: [u64; 4] = [725501752471715841, 6461107452199829505, 6968279316240510977, 1345280370688173398];
+
+        fn limbs(&self) -> &[u64; 4] {
+            &self.0
+        }
+
+        fn limbs_mut(&mut self) -> &mut [u64; 4] {
+            &mut self.0
+        }
+    }
+
+    /// Returns a random element below the modulus, by sampling below the top limb of the modulus.
+    fn sample<const N: usize>(modulus: &[u64; N], rng: &mut TestRng) -> [u64; N] {
+        let mut limbs = [0u64; N];
+        limbs.iter_mut().for_each(|limb| *limb = rng.gen());
+        limbs[N - 1] = rng.gen_range(0..modulus[N - 1]);
+        limbs
+    }
+
+    /// Returns the Montgomery product `a * b * 2^{-64N} mod p`, with a scalar CIOS multiplication in radix `2^64`.
+    fn mul_reference<const N: usize>(a: &[u64; N], b: &[u64; N], p: &[u64; N], inv: u64) -> [u64; N] {
+        let mut t = vec![0u64; N + 2];
+        for b_i in b {
+            let mut carry = 0u128;
+            for j in 0..N {
+                let sum = u128::from(t[j]) + u128::from(a[j]) * u128::from(*b_i) + carry;
+                t[j] = sum as u64;
+                carry = sum >> 64;
+            }
+            let sum = u128::from(t[N]) + carry;
+            t[N] = sum as u64;
+            t[N + 1] = (sum >> 64) as u64;
+
+            let m = t[0].wrapping_mul(inv);
+            let mut carry = (u128::from(t[0]) + u128::from(m) * u128::from(p[0])) >> 64;
+            for j in 1..N {
+                let sum = u128::from(t[j]) + u128::from(m) * u128::from(p[j]) + carry;
+                t[j - 1] = sum as u64;
+                carry = sum >> 64;
+            }
+            let sum = u128::from(t[N]) + carry;
+            t[N - 1] = sum as u64;
+            t[N] = t[N + 1] + (sum >> 64) as u64;
+        }
+
+        // Subtract the modulus, if `t >= p`.
+        let mut reduced = [0u64; N];
+        let mut borrow = 0u64;
+        for j in 0..N {
+            let (difference, underflow_0) = t[j].overflowing_sub(p[j]);
+            let (difference, underflow_1) = difference.overflowing_sub(borrow);
+            reduced[j] = difference;
+            borrow = u64::from(underflow_0 || underflow_1);
+        }
+        match borrow > t[N] {
+            true => t[..N].try_into().unwrap(),
+            false => reduced,
+        }
+    }
+
+    fn check_batch_mul_assign<F: MontgomeryLimbs<N> + core::fmt::Debug + Eq, const N: usize>(
+        new: impl Fn([u64; N]) -> F,
+        rng: &mut TestRng,
+    ) {
+        for i in 0..ITERATIONS {
+            // Sample the elements, including the largest element.
+            let mut a = (0..i % 9).map(|_| new(sample(&F::MODULUS, rng))).collect::<Vec<_>>();
+            let mut b = (0..i % 9).map(|_| new(sample(&F::MODULUS, rng))).collect::<Vec<_>>();
+            let mut largest = F::MODULUS;
+            largest[0] -= 1;
+            a.push(new(largest));
+            b.push(new(largest));
+
+            // Compute the expected products.
+            let expected = a
+                .iter()
+                .zip(&b)
+                .map(|(a, b)| new(mul_reference(a.limbs(), b.limbs(), &F::MODULUS, F::INV)))
+                .collect::<Vec<_>>();
+
+            // Ensure the vectorized products match, for the prefix of full lanes.
+            let mut candidate = a.clone();
+            // SAFETY: The CPU supports NEON, or the kernel runs on emulated intrinsics.
+            assert!(is_supported());
+            let num_elements = unsafe { batch_mul_assign::<F, N>(&mut candidate, &b) };
+            assert_eq!(num_elements, a.len() - a.len() % LANES);
+            assert_eq!(expected[..num_elements], candidate[..num_elements]);
+            assert_eq!(a[num_elements..], candidate[num_elements..]);
+        }
+    }
+
+    #[test]
+    fn test_mul_reference() {
+        let mut rng = TestRng::default();
+
+        // Ensure multiplying by `R^2` and then by `1` is the identity, for the BLS12-377 base field.
+        let r2 = [
+            0xb786686c9400cd22,
+            0x0329fcaab00431b1,
+            0x22a5f11162d6b46d,
+            0xbfdf7d03827dc3ac,
+            0x837e92f041790bf9,
+            0x006dfccb1e914b88,
+        ];
+        let one = [1, 0, 0, 0, 0, 0];
+        for _ in 0..ITERATIONS {
+            let a = sample(&Fq::MODULUS, &mut rng);
+            let montgomery = mul_reference(&a, &r2, &Fq::MODULUS, Fq::INV);
+            assert_eq!(a, mul_reference(&montgomery, &one, &Fq::MODULUS, Fq::INV));
+        }
+    }
+
+    #[test]
+    fn test_batch_mul_assign() {
+        let mut rng = TestRng::default();
+        check_batch_mul_assign(Fq, &mut rng);
+        check_batch_mul_assign(Fr, &mut rng);
+    }
+}
@@ -14,7 +14,7 @@
 
 use super::*;
 
-impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+impl<N: PuzzleNetwork, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns a candidate for the next block in the ledger.
     pub fn prepare_advance_to_next_block<R: Rng + CryptoRng>(
         &self,
@@ -77,7 +77,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let next_timestamp = OffsetDateTime::now_utc().unix_timestamp();
 
         // TODO (raychu86): Pay the provers.
-        let (proving_rewards, staking_rewards) = match candidate_solutions {
+        let proving_rewards = match candidate_solutions {
             Some(prover_solutions) => {
                 // Calculate the coinbase reward.
                 let coinbase_reward = coinbase_reward(
@@ -89,18 +89,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                 )?;
 
                 // Calculate the proving rewards.
-                let proving_rewards = proving_rewards(prover_solutions, coinbase_reward, cumulative_proof_target)?;
-                // Calculate the staking rewards.
-                let staking_rewards = Vec::<Ratify<N>>::new();
-                // Output the proving and staking rewards.
-                (proving_rewards, staking_rewards)
-            }
-            None => {
-                // Output the proving and staking rewards.
-                (vec![], vec![])
+                proving_rewards(prover_solutions, coinbase_reward, cumulative_proof_target)?
             }
+            None => vec![],
         };
 
+        // Calculate the staking rewards.
+        let staking_rewards = self.staking_rewards(&transactions)?;
+
         // Construct the ratifications.
         let mut ratifications = Vec::<Ratify<N>>::new();
         ratifications.extend_from_slice(&proving_rewards);
@@ -183,4 +179,37 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Returns the staking rewards for the given transactions of the next block,
+    /// from the priority fees of the accepted transactions.
+    ///
+    /// The priority fee of an execution that calls a program deployed in the same block is zero,
+    /// as the program is not yet in the VM, so the minimum cost of the execution is not computable.
+    /// Returns an error if the priority fee of any other accepted transaction is not computable.
+    pub(crate) fn staking_rewards(&self, transactions: &Transactions<N>) -> Result<Vec<Ratify<N>>> {
+        // Retrieve the program IDs of the accepted deployments.
+        let deployed_program_ids = transactions
+            .iter()
+            .filter_map(|confirmed| match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) => {
+                    Some(*deployment.program_id())
+                }
+                _ => None,
+            })
+            .collect::<IndexSet<_>>();
+
+        let mut priority_fee = 0u64;
+        for confirmed in transactions.iter().filter(|confirmed| confirmed.is_accepted()) {
+            // Skip the executions that call a program which is deployed in this block.
+            if let Transaction::Execute(_, execution, _) = confirmed.transaction() {
+                if execution.transitions().any(|transition| deployed_program_ids.contains(transition.program_id())) {
+                    continue;
+                }
+            }
+            priority_fee = priority_fee
+                .checked_add(self.vm.priority_fee(confirmed.transaction())?)
+                .ok_or_else(|| anyhow!("The priority fee overflowed for the next block"))?;
+        }
+        priority_fee_rewards(&self.current_committee.read(), priority_fee)
+    }
 }
@@ -14,7 +14,9 @@
 
 use super::*;
 
-impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+use core::sync::atomic::{AtomicBool, Ordering};
+
+impl<N: PuzzleNetwork, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given transaction is well-formed and unique.
     pub fn check_transaction_basic(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
         let transaction_id = transaction.id();
@@ -48,35 +50,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             }
         }
 
-        /* Fee */
-
-        // TODO (raychu86): Remove the split check when batch executions are integrated.
-        let can_skip_fee = match transaction.execution() {
-            Some(execution) => (transaction.is_mint() || transaction.is_split()) && execution.len() == 1,
-            None => false,
-        };
-
-        if !can_skip_fee {
-            // Retrieve the transaction fee.
-            let fee = *transaction.fee()?;
-            // Retrieve the minimum cost of the transaction.
-            let (cost, _) = match transaction {
-                // Compute the deployment cost.
-                Transaction::Deploy(_, _, deployment, _) => Deployment::cost(deployment)?,
-                // Compute the execution cost.
-                Transaction::Execute(_, execution, _) => Execution::cost(self.vm(), execution)?,
-                // TODO (howardwu): Plug in the Rejected struct, to compute the cost.
-                Transaction::Fee(_, _) => (0, (0, 0)),
-            };
-            // Ensure the transaction has a sufficient fee.
-            if cost > fee {
-                bail!("Transaction '{transaction_id}' has an insufficient fee - expected at least {cost} microcredits")
+        // Ensure the execution has not expired, as of the next block height.
+        if let Some(execution) = transaction.execution() {
+            let next_height = self.latest_height().saturating_add(1);
+            if execution.is_expired(next_height) {
+                bail!("Transaction '{transaction_id}' expired at block height {:?}", execution.valid_until())
             }
         }
 
         /* Proof */
 
-        // Ensure the transaction is valid.
+        // Ensure the transaction is valid, and its fee covers its minimum cost.
         self.vm().check_transaction(transaction, rejected_id)?;
 
         /* Program */
@@ -396,19 +380,76 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             bail!("Cannot validate a block with more than {} transactions", Transactions::<N>::MAX_TRANSACTIONS);
         }
 
+        /* Staking Rewards */
+
+        // Compute the expected staking rewards, from the priority fees of the accepted transactions.
+        // Note: This reads the process, so it must not run concurrently with the speculation
+        // in `check_next_transactions`, which holds the write lock on the process.
+        let expected_staking_rewards = self.staking_rewards(block.transactions())?;
+
+        /* Verification */
+
+        // Verify the transactions, the ratifications, and the coinbase solution concurrently.
+        // If any of the checks fails, the remaining checks are aborted as soon as possible.
+        let abort = AtomicBool::new(false);
+        let check = |result: Result<()>| {
+            if result.is_err() {
+                abort.store(true, Ordering::Relaxed);
+            }
+            result
+        };
+
+        #[cfg(not(feature = "serial"))]
+        let (transactions, (ratifications, coinbase)) = rayon::join(
+            || check(self.check_next_transactions(block, &abort)),
+            || {
+                rayon::join(
+                    || check(self.check_next_ratifications(block, &expected_staking_rewards)),
+                    || check(self.check_next_coinbase(block, &abort)),
+                )
+            },
+        );
+        #[cfg(feature = "serial")]
+        let (transactions, (ratifications, coinbase)) = (
+            check(self.check_next_transactions(block, &abort)),
+            (
+                check(self.check_next_ratifications(block, &expected_staking_rewards)),
+                check(self.check_next_coinbase(block, &abort)),
+            ),
+        );
+
+        // Note: An aborted check returns successfully, so the error is from the check that failed.
+        transactions.and(ratifications).and(coinbase)
+    }
+
+    /// Checks the transactions in the given next block are well-formed, unique, and match their speculation.
+    /// Returns early, without an error, if the given abort flag is set by a concurrent check.
+    fn check_next_transactions(&self, block: &Block<N>, abort: &AtomicBool) -> Result<()> {
         // Ensure each transaction is well-formed and unique.
         cfg_iter!(block.transactions()).try_for_each(|transaction| {
+            // Skip the transaction if a concurrent check has failed.
+            if abort.load(Ordering::Relaxed) {
+                return Ok(());
+            }
             // Construct the rejected ID.
             let rejected_id = match transaction {
                 ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => None,
-                ConfirmedTransaction::RejectedDeploy(_, _, rejected) => Some(rejected.to_id()?),
-                ConfirmedTransaction::RejectedExecute(_, _, rejected) => Some(rejected.to_id()?),
+                ConfirmedTransaction::RejectedDeploy(_, _, rejected, _) => Some(rejected.to_id()?),
+                ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => Some(rejected.to_id()?),
             };
 
-            self.check_transaction_basic(transaction, rejected_id)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
+            self.check_transaction_basic(transaction, rejected_id).map_err(|e| {
+                // Abort the remaining transactions.
+                abort.store(true, Ordering::Relaxed);
+                anyhow!("Invalid transaction found in the transactions list: {e}")
+            })
         })?;
 
+        // Skip the speculation if a concurrent check has failed.
+        if abort.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
@@ -428,32 +469,41 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                     Ok(tx.clone())
                 }
                 // Reconstruct the unconfirmed deployment transaction.
-                ConfirmedTransaction::RejectedDeploy(_, fee_transaction, rejected) => Transaction::from_deployment(
+                ConfirmedTransaction::RejectedDeploy(_, fee_transaction, rejected, _) => Transaction::from_deployment(
                     rejected.program_owner().copied().ok_or(anyhow!("Missing the program owner"))?,
                     rejected.deployment().cloned().ok_or(anyhow!("Missing the deployment"))?,
                     fee_transaction.fee_transition().ok_or(anyhow!("Missing the fee"))?,
                 ),
                 // Reconstruct the unconfirmed execution transaction.
-                ConfirmedTransaction::RejectedExecute(_, fee_transaction, rejected) => Transaction::from_execution(
+                ConfirmedTransaction::RejectedExecute(_, fee_transaction, rejected, _) => Transaction::from_execution(
                     rejected.execution().cloned().ok_or(anyhow!("Missing the execution"))?,
                     fee_transaction.fee_transition(),
                 ),
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Speculate on the transactions, followed by the custom ratifications.
+        let (transactions, ratify_operations) =
+            self.vm.speculate_with_ratifications(state, unconfirmed_transactions.iter(), block.ratifications())?;
         // Ensure the transactions after speculation match.
-        if block.transactions() != &self.vm.speculate(state, unconfirmed_transactions.iter())? {
+        if block.transactions() != &transactions {
             bail!("The transactions after speculation do not match the transactions in the block");
         }
 
         /* Finalize Root */
 
-        // Ensure that the block's finalize root matches the transactions.
-        let expected_finalize_root = block.transactions().to_finalize_root()?;
+        // Ensure that the block's finalize root matches the transactions, followed by the custom ratifications.
+        let expected_finalize_root = block.transactions().to_finalize_root_with_ratifications(&ratify_operations)?;
         if block.finalize_root() != expected_finalize_root {
             bail!("Invalid finalize root: expected '{expected_finalize_root}', got '{}'", block.finalize_root())
         }
 
+        Ok(())
+    }
+
+    /// Checks the ratifications root, the staking rewards, and the custom ratifications of the given next block,
+    /// against the given expected staking rewards.
+    fn check_next_ratifications(&self, block: &Block<N>, expected_staking_rewards: &[Ratify<N>]) -> Result<()> {
         /* Ratifications Root */
 
         // Compute the ratifications root of the block.
@@ -470,6 +520,29 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             bail!("Invalid ratifications root: expected '{ratifications_root}', got '{}'", block.ratifications_root())
         }
 
+        /* Staking Rewards */
+
+        // Retrieve the declared staking rewards.
+        let staking_rewards = block
+            .ratifications()
+            .iter()
+            .filter(|ratify| matches!(ratify, Ratify::StakingReward(..)))
+            .cloned()
+            .collect::<Vec<_>>();
+        // Ensure the declared staking rewards match the expected staking rewards.
+        if staking_rewards != expected_staking_rewards {
+            bail!("Invalid staking rewards: expected {expected_staking_rewards:?}, got {staking_rewards:?}")
+        }
+
+        // Ensure that the custom ratifications are of a registered kind, and are valid.
+        self.vm.check_ratifications(block)?;
+
+        Ok(())
+    }
+
+    /// Checks the coinbase solution of the given next block.
+    /// Returns early, without an error, if the given abort flag is set by a concurrent check.
+    fn check_next_coinbase(&self, block: &Block<N>, abort: &AtomicBool) -> Result<()> {
         /* Coinbase Proof */
 
         // Ensure the coinbase solution is valid, if it exists.
@@ -492,6 +565,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                     bail!("Puzzle commitment {puzzle_commitment} already exists in the ledger");
                 }
             }
+            // Skip the coinbase verification if a concurrent check has failed.
+            if abort.load(Ordering::Relaxed) {
+                return Ok(());
+            }
             // Ensure the coinbase solution is valid.
             if !self.coinbase_puzzle.verify(
                 coinbase,
@@ -511,3 +588,133 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_test_env, CurrentNetwork, TestEnv};
+
+    /// The error message of a transaction check that failed on the unauthorized mint.
+    const TRANSACTION_ERROR: &str = "Invalid transaction found in the transactions list";
+    /// The error message of a coinbase check that failed on the tampered accumulator point.
+    const COINBASE_ERROR: &str = "Coinbase accumulator point does not match the coinbase solution";
+
+    /// Returns a candidate next block with a mint transaction and a coinbase solution.
+    /// If `unauthorized_mint` is set, the mint is from an account outside of the committee, which invalidates the transactions.
+    /// If `invalid_coinbase` is set, the coinbase accumulator point in the header is tampered, which invalidates the coinbase.
+    fn sample_next_block(
+        env: &TestEnv,
+        unauthorized_mint: bool,
+        invalid_coinbase: bool,
+        rng: &mut TestRng,
+    ) -> Block<CurrentNetwork> {
+        let TestEnv { ledger, private_key, address, .. } = env;
+
+        // Construct the mint transaction.
+        let minter = match unauthorized_mint {
+            true => PrivateKey::new(rng).unwrap(),
+            false => *private_key,
+        };
+        let inputs = [Address::try_from(&minter).unwrap().to_string(), "1_000_u64".to_string()];
+        let transaction =
+            ledger.vm().execute(&minter, ("credits.aleo", "mint"), inputs.iter(), None, None, rng).unwrap();
+
+        // Construct the prover solution.
+        let epoch_challenge = ledger.latest_epoch_challenge().unwrap();
+        let solution = ledger.coinbase_puzzle().prove(&epoch_challenge, *address, rng.gen(), None).unwrap();
+
+        // Construct the next block.
+        let block =
+            ledger.prepare_advance_to_next_block(private_key, vec![transaction], Some(vec![solution]), rng).unwrap();
+        if !invalid_coinbase {
+            return block;
+        }
+
+        // Tamper the coinbase accumulator point, and re-sign the block.
+        let header = Header::from(
+            block.previous_state_root(),
+            block.transactions_root(),
+            block.finalize_root(),
+            block.ratifications_root(),
+            Field::zero(),
+            *block.metadata(),
+        )
+        .unwrap();
+        Block::new(
+            private_key,
+            block.previous_hash(),
+            header,
+            block.transactions().clone(),
+            block.ratifications().to_vec(),
+            block.coinbase().cloned(),
+            rng,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_next_transactions_abort() {
+        let rng = &mut TestRng::default();
+        let env = sample_test_env(rng);
+
+        // Construct a next block with invalid transactions.
+        let block = sample_next_block(&env, true, false, rng);
+
+        // Ensure the failed transaction check raises the abort flag.
+        let abort = AtomicBool::new(false);
+        let error = env.ledger.check_next_transactions(&block, &abort).unwrap_err();
+        assert!(error.to_string().contains(TRANSACTION_ERROR), "{error}");
+        assert!(abort.load(Ordering::Relaxed));
+
+        // Ensure an aborted transaction check returns early, without an error.
+        assert!(env.ledger.check_next_transactions(&block, &AtomicBool::new(true)).is_ok());
+
+        // Ensure the block is rejected with the transaction error.
+        let error = env.ledger.check_next_block(&block).unwrap_err();
+        assert!(error.to_string().contains(TRANSACTION_ERROR), "{error}");
+    }
+
+    #[test]
+    fn test_check_next_block_invalid_coinbase_aborts_transactions() {
+        let rng = &mut TestRng::default();
+        let env = sample_test_env(rng);
+
+        // Construct a next block with valid transactions, and an invalid coinbase.
+        let block = sample_next_block(&env, false, true, rng);
+
+        // Ensure the transactions are valid.
+        assert!(env.ledger.check_next_transactions(&block, &AtomicBool::new(false)).is_ok());
+        // Ensure the coinbase is invalid, even if a concurrent check has failed.
+        let error = env.ledger.check_next_coinbase(&block, &AtomicBool::new(true)).unwrap_err();
+        assert!(error.to_string().contains(COINBASE_ERROR), "{error}");
+
+        // Ensure the block is rejected with the coinbase error, which is not masked by the aborted transaction check.
+        let error = env.ledger.check_next_block(&block).unwrap_err();
+        assert!(error.to_string().contains(COINBASE_ERROR), "{error}");
+
+        // Ensure the ledger accepts the untampered block.
+        let block = sample_next_block(&env, false, false, rng);
+        env.ledger.check_next_block(&block).unwrap();
+        env.ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    #[test]
+    fn test_check_next_block_error_precedence() {
+        let rng = &mut TestRng::default();
+        let env = sample_test_env(rng);
+
+        // Construct a next block with invalid transactions, and an invalid coinbase.
+        let block = sample_next_block(&env, true, true, rng);
+
+        // Ensure the block is rejected.
+        let error = env.ledger.check_next_block(&block).unwrap_err().to_string();
+
+        // Without concurrency, every check runs to completion, so the transaction error takes precedence.
+        #[cfg(feature = "serial")]
+        assert!(error.contains(TRANSACTION_ERROR), "{error}");
+        // With concurrency, the first check to fail aborts the others, so the error is from either failed check.
+        // In particular, an aborted check never masks the error with a success.
+        #[cfg(not(feature = "serial"))]
+        assert!(error.contains(TRANSACTION_ERROR) || error.contains(COINBASE_ERROR), "{error}");
+    }
+}
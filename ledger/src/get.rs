@@ -14,7 +14,7 @@
 
 use super::*;
 
-impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+impl<N: PuzzleNetwork, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.vm.block_store().get_state_root(block_height)
@@ -25,6 +25,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
     }
 
+    /// Returns the state paths for the given commitments, in the same order.
+    pub fn get_state_paths(&self, commitments: &[Field<N>]) -> Result<Vec<StatePath<N>>> {
+        self.vm.block_store().get_state_paths_for_commitments(commitments)
+    }
+
     /// Returns the epoch challenge for the given block height.
     pub fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>> {
         // Compute the epoch number from the current block height.
@@ -196,6 +201,29 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             None => bail!("Missing signature for block {height}"),
         }
     }
+
+    /// Returns the given percentiles of the priority fees (in microcredits) of the accepted transactions
+    /// in the latest `num_blocks` blocks, as estimates of the current priority fee market.
+    /// If there are no such transactions, each percentile is zero.
+    pub fn get_priority_fee_percentiles(&self, num_blocks: u32, percentiles: &[u8]) -> Result<Vec<u64>> {
+        // Determine the heights of the latest blocks.
+        let end_height = self.latest_height().saturating_add(1);
+        let start_height = end_height.saturating_sub(num_blocks);
+
+        // Retrieve the priority fees of the accepted transactions that pay a fee.
+        let mut priority_fees = Vec::new();
+        for block in self.get_blocks(start_height..end_height)? {
+            for confirmed in block.transactions().iter().filter(|confirmed| confirmed.is_accepted()) {
+                let transaction = confirmed.transaction();
+                if transaction.fee_transition().is_some() {
+                    priority_fees.push(self.vm.priority_fee(transaction)?);
+                }
+            }
+        }
+
+        // Compute the percentiles of the priority fees.
+        priority_fee_percentiles(priority_fees, percentiles)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+};
+use synthesizer::{
+    block::{Block, ConfirmedTransaction, Header, Transactions},
+    store::ConsensusStorage,
+    vm::VM,
+};
+
+/// A builder for a genesis block, for development networks and tests that need accounts
+/// to be funded from the start, instead of the hardcoded genesis block of the network.
+///
+/// The genesis block mints the starting supply in `Block::NUM_GENESIS_TRANSACTIONS` equal shares.
+/// Each pre-funded account receives one share (or less), and the remaining shares are minted to the validator,
+/// which signs the genesis block and forms the initial committee of the ledger.
+#[derive(Clone)]
+pub struct GenesisBuilder<N: Network> {
+    /// The private key of the validator.
+    private_key: PrivateKey<N>,
+    /// The pre-funded accounts, with their amounts in microcredits.
+    allocations: Vec<(PrivateKey<N>, u64)>,
+}
+
+impl<N: Network> GenesisBuilder<N> {
+    /// Initializes a new genesis builder for the given validator.
+    pub const fn new(private_key: PrivateKey<N>) -> Self {
+        Self { private_key, allocations: Vec::new() }
+    }
+
+    /// Returns the amount of microcredits in each share of the starting supply.
+    pub fn share() -> u64 {
+        N::STARTING_SUPPLY.saturating_div(Block::<N>::NUM_GENESIS_TRANSACTIONS as u64)
+    }
+
+    /// Pre-funds the account of the given private key with the given amount of microcredits,
+    /// which is minted into a `credits` record for the account.
+    ///
+    /// The private key is required, as `credits.aleo/mint` is called by the receiver of the record.
+    pub fn with_allocation(mut self, private_key: PrivateKey<N>, amount: u64) -> Self {
+        self.allocations.push((private_key, amount));
+        self
+    }
+
+    /// Returns the genesis block, executing the mints on the given VM.
+    pub fn build<C: ConsensusStorage<N>, R: Rng + CryptoRng>(&self, vm: &VM<N, C>, rng: &mut R) -> Result<Block<N>> {
+        // Ensure there is a genesis transaction for each allocation.
+        ensure!(
+            self.allocations.len() <= Block::<N>::NUM_GENESIS_TRANSACTIONS,
+            "Cannot pre-fund more than {} accounts in the genesis block",
+            Block::<N>::NUM_GENESIS_TRANSACTIONS
+        );
+        // Ensure each allocation is within a share of the starting supply.
+        for (private_key, amount) in &self.allocations {
+            ensure!(
+                (1..=Self::share()).contains(amount),
+                "Cannot pre-fund '{}' with {amount} microcredits (the limit is {})",
+                Address::try_from(private_key)?,
+                Self::share()
+            );
+        }
+
+        // Prepare the mints, which assign the remaining shares to the validator.
+        let remaining = Block::<N>::NUM_GENESIS_TRANSACTIONS - self.allocations.len();
+        let mints = self.allocations.iter().copied().chain((0..remaining).map(|_| (self.private_key, Self::share())));
+
+        // Prepare the mint transactions.
+        let transactions = mints
+            .enumerate()
+            .map(|(index, (private_key, amount))| {
+                // Prepare the function inputs.
+                let inputs = [Address::try_from(&private_key)?.to_string(), format!("{amount}_u64")];
+                // Execute the mint function.
+                let transaction = vm.execute(&private_key, ("credits.aleo", "mint"), inputs.iter(), None, None, rng)?;
+                // Prepare the confirmed transaction.
+                ConfirmedTransaction::accepted_execute(u32::try_from(index)?, transaction, vec![])
+            })
+            .collect::<Result<Transactions<_>>>()?;
+
+        // Prepare the block header.
+        let header = Header::genesis(&transactions)?;
+        // Prepare the previous block hash.
+        let previous_hash = N::BlockHash::default();
+
+        // Construct the block, which does not require a coinbase solution.
+        let block = Block::new(&self.private_key, previous_hash, header, transactions, vec![], None, rng)?;
+        // Ensure the block is valid genesis block.
+        match block.is_genesis() {
+            true => Ok(block),
+            false => bail!("Failed to initialize a genesis block"),
+        }
+    }
+}
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod genesis;
+pub use genesis::*;
+
+mod priority_fee;
+pub use priority_fee::*;
+
 mod rewards;
 pub use rewards::*;
 
 mod supply;
 pub use supply::*;
 
-mod target;
-pub use target::*;
+pub use synthesizer::block::{anchor_block_height, coinbase_reward, coinbase_target, proof_target, staking_reward};
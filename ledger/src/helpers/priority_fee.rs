@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Address};
+use synthesizer::block::Ratify;
+
+use anyhow::Result;
+use indexmap::IndexSet;
+
+/// Returns the staking rewards for the given committee, from the given total priority fee in microcredits.
+///
+/// The priority fee is split evenly across the committee members, and the remainder is
+/// distributed one microcredit at a time, to the committee members in committee order.
+pub fn priority_fee_rewards<N: Network>(committee: &IndexSet<Address<N>>, priority_fee: u64) -> Result<Vec<Ratify<N>>> {
+    // If there is no priority fee, return no rewards.
+    if priority_fee == 0 {
+        return Ok(vec![]);
+    }
+    // Ensure the committee is not empty.
+    ensure!(!committee.is_empty(), "Cannot distribute the priority fee to an empty committee");
+
+    // Compute the share and remainder of each committee member.
+    let num_members = committee.len() as u64;
+    let (share, remainder) = (priority_fee / num_members, priority_fee % num_members);
+
+    // Construct the staking rewards, omitting the zero rewards.
+    Ok(committee
+        .iter()
+        .enumerate()
+        .map(|(index, address)| (address, share + u64::from((index as u64) < remainder)))
+        .filter(|(_, reward)| *reward > 0)
+        .map(|(address, reward)| Ratify::StakingReward(*address, reward))
+        .collect())
+}
+
+/// Returns the given percentiles of the given priority fees in microcredits, using the nearest-rank method.
+/// If there are no priority fees, each percentile is zero.
+pub fn priority_fee_percentiles(mut priority_fees: Vec<u64>, percentiles: &[u8]) -> Result<Vec<u64>> {
+    // Sort the priority fees in ascending order.
+    priority_fees.sort_unstable();
+
+    percentiles
+        .iter()
+        .map(|percentile| {
+            // Ensure the percentile is valid.
+            ensure!(*percentile <= 100, "Invalid percentile '{percentile}' - expected at most 100");
+            // Compute the index of the percentile, which is its rank (rounded up, and at least 1) minus 1.
+            let index = (usize::from(*percentile) * priority_fees.len()).saturating_sub(1) / 100;
+            Ok(priority_fees.get(index).copied().unwrap_or(0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_priority_fee_rewards() {
+        let rng = &mut TestRng::default();
+
+        let committee: IndexSet<Address<CurrentNetwork>> = (0..3).map(|_| Address::new(rng.gen())).collect();
+        let members = committee.iter().copied().collect::<Vec<_>>();
+
+        // Ensure the priority fee is split evenly, with the remainder to the first members.
+        assert_eq!(
+            vec![
+                Ratify::StakingReward(members[0], 4),
+                Ratify::StakingReward(members[1], 4),
+                Ratify::StakingReward(members[2], 3)
+            ],
+            priority_fee_rewards(&committee, 11).unwrap()
+        );
+        // Ensure the zero rewards are omitted.
+        assert_eq!(vec![Ratify::StakingReward(members[0], 1)], priority_fee_rewards(&committee, 1).unwrap());
+        assert!(priority_fee_rewards(&committee, 0).unwrap().is_empty());
+
+        // Ensure the priority fee may not be distributed to an empty committee.
+        assert!(priority_fee_rewards::<CurrentNetwork>(&IndexSet::new(), 1).is_err());
+        assert!(priority_fee_rewards::<CurrentNetwork>(&IndexSet::new(), 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_priority_fee_percentiles() {
+        let priority_fees = vec![50, 10, 40, 20, 30];
+
+        assert_eq!(
+            vec![10, 10, 20, 30, 40, 50],
+            priority_fee_percentiles(priority_fees.clone(), &[0, 1, 40, 50, 75, 100]).unwrap()
+        );
+        assert_eq!(vec![0, 0], priority_fee_percentiles(vec![], &[50, 100]).unwrap());
+        assert!(priority_fee_percentiles(priority_fees, &[101]).is_err());
+    }
+}
@@ -14,7 +14,30 @@
 
 use super::*;
 
-impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+impl<N: PuzzleNetwork, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns an iterator over the blocks in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// Each block is only loaded from storage when the iterator reaches it.
+    pub fn blocks(&self, heights: Range<u32>) -> impl '_ + Iterator<Item = Result<Block<N>>> {
+        heights.map(move |height| self.get_block(height))
+    }
+
+    /// Returns an iterator over the confirmed transactions, for all blocks in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// The transactions of each block are only loaded from storage when the iterator reaches the block.
+    pub fn transactions(&self, heights: Range<u32>) -> impl '_ + Iterator<Item = Result<ConfirmedTransaction<N>>> {
+        heights.flat_map(move |height| {
+            // Retrieve the block transactions, or the error if they cannot be retrieved.
+            let (transactions, error) = match self.get_transactions(height) {
+                Ok(transactions) => (Some(transactions), None),
+                Err(error) => (None, Some(Err(error))),
+            };
+            transactions.into_iter().flatten().map(Ok).chain(error)
+        })
+    }
+
     /// Returns an iterator over the state roots, for all blocks in `self`.
     pub fn state_roots(&self) -> impl '_ + Iterator<Item = Cow<'_, N::StateRoot>> {
         self.vm.block_store().state_roots()
@@ -48,8 +48,16 @@ use console::{
     types::{Field, Group},
 };
 use synthesizer::{
-    block::{Block, ConfirmedTransaction, Deployment, Execution, Header, Metadata, Ratify, Transaction, Transactions},
-    coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSolution, PuzzleCommitment},
+    block::{Block, ConfirmedTransaction, Header, Metadata, Ratify, Transaction, Transactions},
+    coinbase::{
+        CoinbaseSolution,
+        EpochChallenge,
+        NetworkPuzzle,
+        ProverSolution,
+        Puzzle,
+        PuzzleCommitment,
+        PuzzleNetwork,
+    },
     process::{FinalizeGlobalState, Program, Query},
     store::{ConsensusStorage, ConsensusStore},
     vm::VM,
@@ -84,13 +92,13 @@ pub enum RecordsFilter<N: Network> {
 }
 
 #[derive(Clone)]
-pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
+pub struct Ledger<N: PuzzleNetwork, C: ConsensusStorage<N>> {
     /// The VM state.
     vm: VM<N, C>,
     /// The genesis block.
     genesis: Block<N>,
     /// The coinbase puzzle.
-    coinbase_puzzle: CoinbasePuzzle<N>,
+    coinbase_puzzle: NetworkPuzzle<N>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
     /// The current epoch challenge.
@@ -99,7 +107,7 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<IndexSet<Address<N>>>>,
 }
 
-impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+impl<N: PuzzleNetwork, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Loads the ledger from storage.
     pub fn load(genesis: Block<N>, dev: Option<u16>) -> Result<Self> {
         let timer = timer!("Ledger::load");
@@ -151,7 +159,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let mut ledger = Self {
             vm,
             genesis: genesis.clone(),
-            coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
+            coinbase_puzzle: NetworkPuzzle::<N>::load()?,
             current_block: Arc::new(RwLock::new(genesis.clone())),
             current_epoch_challenge: Default::default(),
             current_committee: Default::default(),
@@ -196,7 +204,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Returns the coinbase puzzle.
-    pub const fn coinbase_puzzle(&self) -> &CoinbasePuzzle<N> {
+    pub const fn coinbase_puzzle(&self) -> &NetworkPuzzle<N> {
         &self.coinbase_puzzle
     }
 
@@ -294,7 +302,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 }
 
-impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+impl<N: PuzzleNetwork, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns the unspent `credits.aleo` records.
     pub fn find_unspent_credits_records(&self, view_key: &ViewKey<N>) -> Result<RecordMap<N>> {
         let microcredits = Identifier::from_str("microcredits")?;
@@ -14,19 +14,23 @@
 
 use crate::{
     test_helpers::{CurrentLedger, CurrentNetwork},
+    GenesisBuilder,
     RecordsFilter,
 };
 use console::{
-    account::PrivateKey,
+    account::{PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Entry, Identifier, Literal, Plaintext, Value},
+    program::{Entry, Identifier, Literal, Plaintext, Value, RATIFICATIONS_DEPTH},
+    types::Field,
 };
 use synthesizer::{
+    block::{Block, Header, Ratify},
     store::{helpers::memory::ConsensusMemory, ConsensusStore},
     vm::VM,
     ConfirmedTransaction,
     Program,
     Rejected,
+    RejectionCode,
     Transaction,
 };
 
@@ -49,6 +53,48 @@ fn test_load() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_genesis_builder() {
+    let rng = &mut TestRng::default();
+
+    // Sample the validator and the pre-funded account.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let account_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize the store.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    // Create a genesis block with the pre-funded account.
+    let genesis = GenesisBuilder::new(private_key)
+        .with_allocation(account_private_key, 1_000_000)
+        .build(&VM::from(store).unwrap(), rng)
+        .unwrap();
+    assert!(genesis.is_genesis());
+
+    // Initialize the ledger with the genesis block.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    assert_eq!(ledger.latest_block(), genesis);
+    // Ensure the validator is the committee.
+    assert_eq!(ledger.latest_committee().into_iter().collect::<Vec<_>>(), [genesis.signature().to_address()]);
+
+    // Ensure the pre-funded account has its record.
+    let view_key = ViewKey::try_from(account_private_key).unwrap();
+    let records = ledger.find_records(&view_key, RecordsFilter::SlowUnspent(account_private_key)).unwrap();
+    let microcredits = Identifier::from_str("microcredits").unwrap();
+    let amounts = records
+        .map(|(_, record)| match record.data().get(&microcredits) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount,
+            _ => 0,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(amounts, [1_000_000]);
+
+    // Ensure an allocation above a share of the starting supply is rejected.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    let result = GenesisBuilder::new(private_key)
+        .with_allocation(account_private_key, GenesisBuilder::<CurrentNetwork>::share() + 1)
+        .build(&VM::from(store).unwrap(), rng);
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_load_unchecked() {
     // Load the genesis block.
@@ -85,6 +131,55 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_state_paths() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    // Retrieve the genesis block.
+    let block = ledger.get_block(0).unwrap();
+
+    // Construct the state paths.
+    let commitments = block.transactions().commitments().copied().collect::<Vec<_>>();
+    let state_paths = ledger.get_state_paths(&commitments).unwrap();
+
+    // Ensure the state paths match the individually-constructed state paths.
+    assert_eq!(commitments.len(), state_paths.len());
+    for (commitment, state_path) in commitments.iter().zip(state_paths) {
+        assert!(ledger.get_state_path_for_commitment(commitment).unwrap() == state_path);
+    }
+    assert!(ledger.get_state_paths(&[]).unwrap().is_empty());
+
+    // Ensure a missing commitment fails.
+    assert!(ledger.get_state_paths(&[commitments[0], Field::rand(rng)]).is_err());
+}
+
+#[test]
+fn test_blocks_and_transactions() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    // Retrieve the genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+
+    // Ensure the blocks are streamed in order.
+    let blocks = ledger.blocks(0..1).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(vec![genesis.clone()], blocks);
+    // Ensure the transactions are streamed in order.
+    let transactions = ledger.transactions(0..1).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(genesis.transactions().iter().cloned().collect::<Vec<_>>(), transactions);
+
+    // Ensure an empty range yields nothing.
+    assert_eq!(0, ledger.blocks(1..1).count());
+    assert_eq!(0, ledger.transactions(1..1).count());
+
+    // Ensure a missing block yields an error.
+    assert!(ledger.blocks(0..2).nth(1).unwrap().is_err());
+    assert!(ledger.transactions(1..2).next().unwrap().is_err());
+}
+
 #[test]
 fn test_insufficient_finalize_fees() {
     let rng = &mut TestRng::default();
@@ -261,9 +356,14 @@ finalize failed_assert:
     let confirmed_transaction = next_block.transactions().iter().next().unwrap();
     assert!(confirmed_transaction.is_rejected());
     if let Transaction::Execute(_, execution, fee) = failed_assert_transaction {
+        // Ensure the rejection metadata records the failed `assert` command.
+        let metadata = confirmed_transaction.rejection_metadata().unwrap().clone();
+        assert_eq!(metadata.code(), RejectionCode::CommandFailed);
+        assert!(metadata.command().is_some());
+
         let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
         let expected_confirmed_transaction =
-            ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution));
+            ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution), metadata);
 
         assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
     }
@@ -274,3 +374,191 @@ finalize failed_assert:
     // Add the block with the rejected transaction to the ledger.
     ledger.advance_to_next_block(&next_block).unwrap();
 }
+
+#[test]
+fn test_priority_fee_staking_rewards() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Fetch the unspent records.
+    let mut records = ledger.find_unspent_credits_records(&view_key).unwrap().into_values();
+
+    // Construct two transfers with distinct priority fees.
+    let transactions = [1_000u64, 2_500]
+        .into_iter()
+        .map(|priority_fee| {
+            let inputs = [
+                Value::Record(records.next().unwrap()),
+                Value::from_str(&address.to_string()).unwrap(),
+                Value::from_str("100u64").unwrap(),
+            ];
+            let fee = Some((records.next().unwrap(), priority_fee));
+            ledger
+                .vm
+                .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), fee, None, rng)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_block(&private_key, transactions, None, rng).unwrap();
+
+    // Compute the priority fees of the accepted transactions.
+    let priority_fees = block
+        .transactions()
+        .iter()
+        .filter(|confirmed| confirmed.is_accepted())
+        .map(|confirmed| ledger.vm().priority_fee(confirmed.transaction()).unwrap())
+        .sum::<u64>();
+    assert_eq!(3_500, priority_fees);
+
+    // Ensure the staking rewards distribute exactly the priority fees.
+    let staking_rewards = block
+        .ratifications()
+        .iter()
+        .filter_map(|ratify| match ratify {
+            Ratify::StakingReward(_, amount) => Some(*amount),
+            _ => None,
+        })
+        .sum::<u64>();
+    assert_eq!(priority_fees, staking_rewards);
+
+    // Ensure the block is valid.
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+}
+
+#[test]
+fn test_tampered_staking_rewards() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Fetch the unspent records.
+    let mut records = ledger.find_unspent_credits_records(&view_key).unwrap().into_values();
+
+    // Construct a transfer with a priority fee.
+    let inputs = [
+        Value::Record(records.next().unwrap()),
+        Value::from_str(&address.to_string()).unwrap(),
+        Value::from_str("100u64").unwrap(),
+    ];
+    let fee = Some((records.next().unwrap(), 1_000));
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), fee, None, rng)
+        .unwrap();
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], None, rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+
+    // Returns the block with the given ratifications, re-signed with a matching ratifications root.
+    let mut tamper = |ratifications: Vec<Ratify<CurrentNetwork>>| {
+        // Compute the ratifications root.
+        let ratifications_root = *<CurrentNetwork as Network>::merkle_tree_bhp::<RATIFICATIONS_DEPTH>(
+            &ratifications.iter().map(|r| r.to_bytes_le().unwrap().to_bits_le()).collect::<Vec<_>>(),
+        )
+        .unwrap()
+        .root();
+        // Construct the header.
+        let header = Header::from(
+            block.previous_state_root(),
+            block.transactions_root(),
+            block.finalize_root(),
+            ratifications_root,
+            block.header().coinbase_accumulator_point(),
+            *block.metadata(),
+        )
+        .unwrap();
+        // Construct the block.
+        Block::new(
+            &private_key,
+            block.previous_hash(),
+            header,
+            block.transactions().clone(),
+            ratifications,
+            block.coinbase().cloned(),
+            rng,
+        )
+        .unwrap()
+    };
+
+    // Ensure a block with an inflated staking reward is rejected.
+    let inflated = block
+        .ratifications()
+        .iter()
+        .map(|ratify| match ratify {
+            Ratify::StakingReward(address, amount) => Ratify::StakingReward(*address, amount + 1),
+            ratify => ratify.clone(),
+        })
+        .collect();
+    let error = ledger.check_next_block(&tamper(inflated)).unwrap_err();
+    assert!(error.to_string().contains("Invalid staking rewards"), "{error}");
+
+    // Ensure a block with an omitted staking reward is rejected.
+    let omitted =
+        block.ratifications().iter().filter(|ratify| !matches!(ratify, Ratify::StakingReward(..))).cloned().collect();
+    let error = ledger.check_next_block(&tamper(omitted)).unwrap_err();
+    assert!(error.to_string().contains("Invalid staking rewards"), "{error}");
+
+    // Ensure the untampered block is valid.
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+}
+
+#[test]
+fn test_staking_rewards_with_deployment_in_same_block() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Fetch the unspent records.
+    let mut records = ledger.find_unspent_credits_records(&view_key).unwrap().into_values();
+
+    // Construct a program, which is not yet deployed in the ledger.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program staking_rewards.aleo;
+
+function double:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;",
+    )
+    .unwrap();
+
+    // Construct the deployment.
+    let deployment = ledger.vm().deploy(&private_key, &program, (records.next().unwrap(), 0), None, rng).unwrap();
+
+    // Construct an execution of the program with a priority fee, from a VM that already contains the program.
+    let vm = VM::from(ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap()).unwrap();
+    vm.add_next_block(&ledger.get_block(0).unwrap()).unwrap();
+    vm.process().write().add_program(&program).unwrap();
+    let inputs = [Value::from_str("1u32").unwrap()];
+    let fee = Some((records.next().unwrap(), 1_000));
+    let execution =
+        vm.execute(&private_key, ("staking_rewards.aleo", "double"), inputs.into_iter(), fee, None, rng).unwrap();
+
+    // Ensure the priority fee of the execution is not computable by the ledger.
+    assert!(ledger.vm().priority_fee(&execution).is_err());
+
+    // Ensure the execution has no priority fee, if the program is deployed in the same block.
+    let transactions = [
+        ConfirmedTransaction::accepted_deploy(0, deployment, vec![]).unwrap(),
+        ConfirmedTransaction::accepted_execute(1, execution.clone(), vec![]).unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    assert!(ledger.staking_rewards(&transactions).unwrap().is_empty());
+
+    // Ensure the staking rewards fail, if the program is not deployed in the same block.
+    let transactions = [ConfirmedTransaction::accepted_execute(0, execution, vec![]).unwrap()].into_iter().collect();
+    assert!(ledger.staking_rewards(&transactions).is_err());
+}
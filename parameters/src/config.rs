@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use std::{path::PathBuf, sync::RwLock};
+
+/// The environment variable with the comma-separated mirror URLs.
+pub const MIRRORS_ENV_VAR: &str = "ALEO_PARAMETERS_MIRRORS";
+/// The environment variable with the directory to load the parameter files from, without network access.
+pub const OFFLINE_DIR_ENV_VAR: &str = "ALEO_PARAMETERS_DIR";
+
+lazy_static! {
+    /// The config for the parameter files that are loaded in this process.
+    static ref CONFIG: RwLock<ParameterConfig> = RwLock::new(ParameterConfig::from_env());
+}
+
+/// The policy for a cached parameter file with a mismatching size or checksum.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ChecksumPolicy {
+    /// Rejects the file with an error.
+    #[default]
+    Strict,
+    /// Removes the file, and downloads it again.
+    Refetch,
+}
+
+/// The config for loading the parameter files, which are downloaded from the Aleo CDN by default.
+///
+/// The config is read when a parameter file is first loaded, so it must be set before any parameters are used.
+/// Unless it is set, the config is initialized from the `ALEO_PARAMETERS_MIRRORS` and `ALEO_PARAMETERS_DIR`
+/// environment variables.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParameterConfig {
+    /// The URLs of the mirrors, which are tried in order before the default URL.
+    mirrors: Vec<String>,
+    /// The directory to load the parameter files from, without network access, if any.
+    offline_dir: Option<PathBuf>,
+    /// The policy for a cached parameter file with a mismatching size or checksum.
+    checksum_policy: ChecksumPolicy,
+}
+
+impl ParameterConfig {
+    /// Initializes a new config, which downloads the parameter files from the Aleo CDN.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Initializes a new config from the `ALEO_PARAMETERS_MIRRORS` and `ALEO_PARAMETERS_DIR` environment variables.
+    pub fn from_env() -> Self {
+        let mut config = Self::new();
+        if let Ok(mirrors) = std::env::var(MIRRORS_ENV_VAR) {
+            for mirror in mirrors.split(',').map(str::trim).filter(|mirror| !mirror.is_empty()) {
+                config = config.with_mirror(mirror);
+            }
+        }
+        if let Some(directory) = std::env::var_os(OFFLINE_DIR_ENV_VAR) {
+            config = config.with_offline_dir(directory);
+        }
+        config
+    }
+
+    /// Adds a mirror URL, which is tried (after the previously added mirrors) before the default URL.
+    /// The parameter files are expected at the same paths as on the Aleo CDN.
+    pub fn with_mirror(mut self, url: impl Into<String>) -> Self {
+        self.mirrors.push(url.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Loads the parameter files from the given directory, without network access or the local cache.
+    /// The directory is expected to contain the versioned files, i.e. `powers-of-beta-16.usrs.<checksum>`.
+    pub fn with_offline_dir(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.offline_dir = Some(directory.into());
+        self
+    }
+
+    /// Sets the policy for a cached parameter file with a mismatching size or checksum.
+    pub fn with_checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = policy;
+        self
+    }
+
+    /// Returns the URLs of the mirrors.
+    pub fn mirrors(&self) -> &[String] {
+        &self.mirrors
+    }
+
+    /// Returns the directory to load the parameter files from, without network access, if any.
+    pub fn offline_dir(&self) -> Option<&PathBuf> {
+        self.offline_dir.as_ref()
+    }
+
+    /// Returns the policy for a cached parameter file with a mismatching size or checksum.
+    pub const fn checksum_policy(&self) -> ChecksumPolicy {
+        self.checksum_policy
+    }
+
+    /// Returns the config for the parameter files that are loaded in this process.
+    pub fn current() -> Self {
+        CONFIG.read().unwrap_or_else(|error| error.into_inner()).clone()
+    }
+
+    /// Sets the config for the parameter files that are loaded in this process.
+    pub fn set(config: Self) {
+        *CONFIG.write().unwrap_or_else(|error| error.into_inner()) = config;
+    }
+
+    /// Returns the URLs to download the parameter files from, in order, ending with the given default URL.
+    pub(crate) fn urls<'a>(&'a self, default_url: &'a str) -> impl Iterator<Item = &'a str> {
+        self.mirrors.iter().map(String::as_str).chain(core::iter::once(default_url))
+    }
+
+    /// Downloads the given parameter file from the first URL that has it, with the given fetch function.
+    pub(crate) fn fetch(
+        &self,
+        default_url: &str,
+        filename: &str,
+        expected_checksum: &str,
+        fetch: impl FnMut(&str) -> Result<Vec<u8>, ParameterError>,
+    ) -> Result<Vec<u8>, ParameterError> {
+        self.fetch_with(default_url, filename, fetch, |buffer| {
+            // Ensure the checksum matches.
+            let candidate_checksum = checksum!(&buffer);
+            match expected_checksum == candidate_checksum {
+                true => Ok(buffer),
+                false => checksum_error!(expected_checksum.to_string(), candidate_checksum),
+            }
+        })
+    }
+
+    /// Downloads the given file from the first URL that has it, with the given fetch function,
+    /// and returns the first download that is accepted by the given process function.
+    pub(crate) fn fetch_with(
+        &self,
+        default_url: &str,
+        filename: &str,
+        mut fetch: impl FnMut(&str) -> Result<Vec<u8>, ParameterError>,
+        mut process: impl FnMut(Vec<u8>) -> Result<Vec<u8>, ParameterError>,
+    ) -> Result<Vec<u8>, ParameterError> {
+        let mut result = Err(ParameterError::RemoteFetchDisabled);
+        for url in self.urls(default_url) {
+            result = fetch(&format!("{url}/{filename}")).and_then(&mut process);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_from_mirrors() {
+        let config = ParameterConfig::new().with_mirror("https://first.example/").with_mirror("https://second.example");
+        assert_eq!(config.urls("https://default.example").collect::<Vec<_>>(), [
+            "https://first.example",
+            "https://second.example",
+            "https://default.example"
+        ]);
+
+        // Ensure the mirrors are tried in order, until a file with the expected checksum is found.
+        let bytes = b"parameters".to_vec();
+        let mut requested = Vec::new();
+        let result = config.fetch("https://default.example", "file", &checksum!(&bytes), |url| {
+            requested.push(url.to_string());
+            match url {
+                "https://first.example/file" => Err(ParameterError::Message("Not found".to_string())),
+                "https://second.example/file" => Ok(b"corrupt".to_vec()),
+                _ => Ok(bytes.clone()),
+            }
+        });
+        assert_eq!(result.unwrap(), bytes);
+        assert_eq!(requested, [
+            "https://first.example/file",
+            "https://second.example/file",
+            "https://default.example/file"
+        ]);
+
+        // Ensure the last error is returned if no mirror has the file.
+        let result = config.fetch("https://default.example", "file", &checksum!(&bytes), |_| Ok(b"corrupt".to_vec()));
+        assert!(matches!(result, Err(ParameterError::ChecksumMismatch(..))));
+    }
+}
@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+#[cfg(not(feature = "wasm"))]
+use crate::ParameterConfig;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// The magic bytes at the start of a delta file.
+const DELTA_MAGIC: [u8; 8] = *b"ALEODLT1";
+
+/// An operation of a delta, which produces the next bytes of the new parameter file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DeltaOperation {
+    /// Copies the given range of bytes from the old parameter file.
+    Copy { offset: u64, length: u64 },
+    /// Inserts the given bytes.
+    Insert(Vec<u8>),
+}
+
+/// A binary delta between two versions of a parameter file, so that an upgrade only downloads the changed bytes.
+///
+/// A delta is published next to the new parameter file, as `<name>.<old version>-<new version>.delta`,
+/// where the versions are the first 7 characters of the checksums. The checksums of both versions are
+/// in the delta, so the old file and the result are verified when the delta is applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delta {
+    /// The checksum of the old parameter file.
+    old_checksum: String,
+    /// The checksum of the new parameter file.
+    new_checksum: String,
+    /// The size of the new parameter file.
+    new_size: u64,
+    /// The operations, which produce the new parameter file in order.
+    operations: Vec<DeltaOperation>,
+}
+
+impl Delta {
+    /// The default size of the blocks that are matched between the old and new parameter files.
+    pub const DEFAULT_BLOCK_SIZE: usize = 4096;
+
+    /// Initializes the delta from the old to the new parameter file, by copying each block of the
+    /// new file (at offsets that are a multiple of the block size) that is a block of the old file.
+    pub fn new(old: &[u8], new: &[u8], block_size: usize) -> Self {
+        let block_size = block_size.max(1);
+
+        // Index the blocks of the old parameter file.
+        let mut blocks = HashMap::new();
+        for (index, block) in old.chunks_exact(block_size).enumerate() {
+            blocks.entry(block).or_insert((index * block_size) as u64);
+        }
+
+        // Copy or insert each block of the new parameter file, merging consecutive operations.
+        let mut operations = Vec::new();
+        for block in new.chunks(block_size) {
+            match (blocks.get(block), operations.last_mut()) {
+                (Some(&offset), Some(DeltaOperation::Copy { offset: start, length })) if *start + *length == offset => {
+                    *length += block.len() as u64;
+                }
+                (Some(&offset), _) => operations.push(DeltaOperation::Copy { offset, length: block.len() as u64 }),
+                (None, Some(DeltaOperation::Insert(bytes))) => bytes.extend_from_slice(block),
+                (None, _) => operations.push(DeltaOperation::Insert(block.to_vec())),
+            }
+        }
+
+        Self { old_checksum: checksum!(old), new_checksum: checksum!(new), new_size: new.len() as u64, operations }
+    }
+
+    /// Returns the checksum of the old parameter file.
+    pub fn old_checksum(&self) -> &str {
+        &self.old_checksum
+    }
+
+    /// Returns the checksum of the new parameter file.
+    pub fn new_checksum(&self) -> &str {
+        &self.new_checksum
+    }
+
+    /// Returns the number of bytes that are inserted by the delta, which are downloaded with it.
+    pub fn num_inserted_bytes(&self) -> usize {
+        self.operations
+            .iter()
+            .map(|operation| match operation {
+                DeltaOperation::Copy { .. } => 0,
+                DeltaOperation::Insert(bytes) => bytes.len(),
+            })
+            .sum()
+    }
+
+    /// Returns the new parameter file, by applying the delta to the old parameter file.
+    pub fn apply(&self, old: &[u8]) -> Result<Vec<u8>, ParameterError> {
+        // Ensure the delta is for the old parameter file.
+        let candidate_checksum = checksum!(old);
+        if self.old_checksum != candidate_checksum {
+            return checksum_error!(self.old_checksum.clone(), candidate_checksum);
+        }
+
+        // Apply the operations.
+        let mut new = Vec::with_capacity(self.new_size as usize);
+        for operation in &self.operations {
+            match operation {
+                DeltaOperation::Copy { offset, length } => {
+                    let range = usize::try_from(*offset).ok().and_then(|offset| {
+                        let end = offset.checked_add(usize::try_from(*length).ok()?)?;
+                        old.get(offset..end)
+                    });
+                    match range {
+                        Some(bytes) => new.extend_from_slice(bytes),
+                        None => {
+                            return Err(ParameterError::Message("The delta copies beyond the old file".to_string()))
+                        }
+                    }
+                }
+                DeltaOperation::Insert(bytes) => new.extend_from_slice(bytes),
+            }
+        }
+
+        // Ensure the size and checksum of the new parameter file match.
+        if self.new_size != new.len() as u64 {
+            return Err(ParameterError::SizeMismatch(self.new_size as usize, new.len()));
+        }
+        let candidate_checksum = checksum!(&new);
+        if self.new_checksum != candidate_checksum {
+            return checksum_error!(self.new_checksum.clone(), candidate_checksum);
+        }
+        Ok(new)
+    }
+
+    /// Returns the bytes of the delta file.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ParameterError> {
+        let mut bytes = DELTA_MAGIC.to_vec();
+        for checksum in [&self.old_checksum, &self.new_checksum] {
+            bytes.extend(hex::decode(checksum).map_err(|error| ParameterError::Message(error.to_string()))?);
+        }
+        bytes.extend(self.new_size.to_le_bytes());
+        bytes.extend((self.operations.len() as u64).to_le_bytes());
+        for operation in &self.operations {
+            match operation {
+                DeltaOperation::Copy { offset, length } => {
+                    bytes.push(0);
+                    bytes.extend(offset.to_le_bytes());
+                    bytes.extend(length.to_le_bytes());
+                }
+                DeltaOperation::Insert(inserted) => {
+                    bytes.push(1);
+                    bytes.extend((inserted.len() as u64).to_le_bytes());
+                    bytes.extend(inserted);
+                }
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the delta from the bytes of a delta file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParameterError> {
+        let invalid = || ParameterError::Message("The delta file is malformed".to_string());
+
+        // Reads the given number of bytes from the delta file.
+        let mut remaining = bytes;
+        let mut read = |length: usize| -> Result<&[u8], ParameterError> {
+            if remaining.len() < length {
+                return Err(invalid());
+            }
+            let (bytes, rest) = remaining.split_at(length);
+            remaining = rest;
+            Ok(bytes)
+        };
+        if read(DELTA_MAGIC.len())? != DELTA_MAGIC {
+            return Err(invalid());
+        }
+        let old_checksum = hex::encode(read(32)?);
+        let new_checksum = hex::encode(read(32)?);
+        let new_size = u64::from_le_bytes(read(8)?.try_into().map_err(|_| invalid())?);
+        let num_operations = u64::from_le_bytes(read(8)?.try_into().map_err(|_| invalid())?);
+
+        let mut operations = Vec::new();
+        for _ in 0..num_operations {
+            let operation = match read(1)?[0] {
+                0 => {
+                    let offset = u64::from_le_bytes(read(8)?.try_into().map_err(|_| invalid())?);
+                    let length = u64::from_le_bytes(read(8)?.try_into().map_err(|_| invalid())?);
+                    DeltaOperation::Copy { offset, length }
+                }
+                1 => {
+                    let length = u64::from_le_bytes(read(8)?.try_into().map_err(|_| invalid())?);
+                    DeltaOperation::Insert(read(usize::try_from(length).map_err(|_| invalid())?)?.to_vec())
+                }
+                _ => return Err(invalid()),
+            };
+            operations.push(operation);
+        }
+        // Ensure the delta file is fully read.
+        match remaining.is_empty() {
+            true => Ok(Self { old_checksum, new_checksum, new_size, operations }),
+            false => Err(invalid()),
+        }
+    }
+}
+
+/// Returns the cached file of a previous version of the given parameter file, if there is one.
+///
+/// The parameter files are stored as `<name>.<version>`, where the version is the first 7 characters of the checksum.
+pub fn find_previous_version(file_path: &Path) -> Option<PathBuf> {
+    let filename = file_path.file_name()?.to_str()?;
+    let (name, _) = filename.rsplit_once('.')?;
+    std::fs::read_dir(file_path.parent()?).ok()?.filter_map(|entry| entry.ok()).map(|entry| entry.path()).find(|path| {
+        match path.file_name().and_then(|filename| filename.to_str()) {
+            Some(candidate) => match candidate.strip_prefix(name).and_then(|version| version.strip_prefix('.')) {
+                Some(version) => {
+                    candidate != filename && version.len() == 7 && version.chars().all(|c| c.is_ascii_hexdigit())
+                }
+                None => false,
+            },
+            None => false,
+        }
+    })
+}
+
+/// Returns the given parameter file, by downloading and applying the delta from the cached file of a previous version.
+/// Once the parameter file is updated, the previous version is removed.
+///
+/// Returns `None` if there is no previous version, or no mirror has a delta that applies.
+#[cfg(not(feature = "wasm"))]
+pub(crate) fn fetch_with_delta(
+    config: &ParameterConfig,
+    default_url: &str,
+    file_path: &Path,
+    expected_checksum: &str,
+    fetch: impl FnMut(&str) -> Result<Vec<u8>, ParameterError>,
+) -> Option<Vec<u8>> {
+    // Retrieve the previous version of the parameter file.
+    let previous_path = find_previous_version(file_path)?;
+    let old = std::fs::read(&previous_path).ok()?;
+
+    // Compose the filename of the delta, i.e. `<name>.<old version>-<new version>.delta`.
+    let filename = file_path.file_name()?.to_str()?;
+    let (name, new_version) = filename.rsplit_once('.')?;
+    let (_, old_version) = previous_path.file_name()?.to_str()?.rsplit_once('.')?;
+    let delta_filename = format!("{name}.{old_version}-{new_version}.delta");
+
+    // Download the delta, and apply it to the previous version.
+    let buffer = config
+        .fetch_with(default_url, &delta_filename, fetch, |bytes| {
+            let delta = Delta::from_bytes(&bytes)?;
+            if delta.new_checksum() != expected_checksum {
+                return checksum_error!(expected_checksum.to_string(), delta.new_checksum().to_string());
+            }
+            delta.apply(&old)
+        })
+        .ok()?;
+
+    // Remove the previous version, as it is replaced.
+    let _ = std::fs::remove_file(previous_path);
+    Some(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_delta_apply() {
+        let rng = &mut rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..ITERATIONS {
+            // Sample an old parameter file, and change a few of its bytes and its length.
+            let old = (0..rng.gen_range(0..100_000)).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let mut new = old.clone();
+            for _ in 0..rng.gen_range(0..5) {
+                if !new.is_empty() {
+                    let index = rng.gen_range(0..new.len());
+                    new[index] = new[index].wrapping_add(1);
+                }
+            }
+            new.extend((0..rng.gen_range(0..10_000)).map(|_| rng.gen::<u8>()));
+
+            // Ensure the delta produces the new parameter file, and round trips through bytes.
+            let delta = Delta::new(&old, &new, 1024);
+            assert_eq!(delta.apply(&old).unwrap(), new);
+            assert_eq!(Delta::from_bytes(&delta.to_bytes().unwrap()).unwrap(), delta);
+            // Ensure the delta only inserts the changed blocks.
+            assert!(delta.num_inserted_bytes() <= 5 * 1024 + new.len() - old.len() + 1024);
+
+            // Ensure the delta does not apply to a different file.
+            let mut other = old.clone();
+            other.push(0);
+            assert!(matches!(delta.apply(&other), Err(ParameterError::ChecksumMismatch(..))));
+        }
+    }
+
+    #[test]
+    fn test_delta_from_malformed_bytes() {
+        let delta = Delta::new(b"old parameters", b"new parameters", 4);
+        let bytes = delta.to_bytes().unwrap();
+        assert!(Delta::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Delta::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+        assert!(Delta::from_bytes(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_find_previous_version() {
+        let directory = std::env::temp_dir().join(format!("snarkvm-delta-test-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("degree.usrs.0123456"), b"old").unwrap();
+        std::fs::write(directory.join("degree.usrs.abcdef0.part"), b"partial").unwrap();
+
+        let file_path = directory.join("degree.usrs.abcdef0");
+        assert_eq!(find_previous_version(&file_path), Some(directory.join("degree.usrs.0123456")));
+        assert_eq!(find_previous_version(&directory.join("other.usrs.abcdef0")), None);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -25,6 +25,9 @@ pub enum ParameterError {
     #[error("{}", _0)]
     Message(String),
 
+    #[error("Parameter file {:?} is missing from the offline directory", _0)]
+    OfflineFileMissing(std::path::PathBuf),
+
     #[error("Remote fetch is disabled, enable compiler flag for feature")]
     RemoteFetchDisabled,
 
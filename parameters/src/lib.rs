@@ -27,9 +27,17 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod config;
+pub use config::*;
+
+pub mod delta;
+pub use delta::*;
+
 pub mod errors;
 pub use errors::*;
 
+pub use snarkvm_utilities::mmap::{replace_file, MappedBytes};
+
 pub mod testnet3;
 
 pub mod prelude {
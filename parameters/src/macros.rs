@@ -45,8 +45,6 @@ macro_rules! impl_store_and_remote_fetch {
     () => {
         #[cfg(not(feature = "wasm"))]
         fn store_bytes(buffer: &[u8], file_path: &std::path::Path) -> Result<(), $crate::errors::ParameterError> {
-            use snarkvm_utilities::Write;
-
             #[cfg(not(feature = "no_std_out"))]
             {
                 use colored::*;
@@ -59,10 +57,9 @@ macro_rules! impl_store_and_remote_fetch {
             directory_path.pop();
             let _ = std::fs::create_dir_all(directory_path)?;
 
-            // Attempt to write the parameter buffer to a file.
-            match std::fs::File::create(file_path) {
-                Ok(mut file) => file.write_all(&buffer)?,
-                Err(error) => eprintln!("{}", error),
+            // Attempt to write the parameter buffer to a file, without truncating a file that may be mapped.
+            if let Err(error) = $crate::replace_file(file_path, buffer) {
+                eprintln!("{}", error);
             }
             Ok(())
         }
@@ -101,6 +98,74 @@ macro_rules! impl_store_and_remote_fetch {
             Ok(transfer.perform()?)
         }
 
+        #[cfg(not(feature = "wasm"))]
+        fn remote_fetch_resumable(url: &str, file_path: &std::path::Path) -> Result<Vec<u8>, $crate::errors::ParameterError> {
+            use snarkvm_utilities::Write;
+
+            // The partial download is stored next to the parameter file, and resumed from its length.
+            let part_path = std::path::PathBuf::from(format!("{}.part", file_path.display()));
+            if let Some(directory) = part_path.parent() {
+                std::fs::create_dir_all(directory)?;
+            }
+            let offset = std::fs::metadata(&part_path).map(|metadata| metadata.len()).unwrap_or(0);
+            let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&part_path)?;
+
+            let mut easy = curl::easy::Easy::new();
+            easy.follow_location(true)?;
+            // Ensure an error response is not written to the partial download.
+            easy.fail_on_error(true)?;
+            easy.url(url)?;
+            easy.resume_from(offset)?;
+
+            #[cfg(not(feature = "no_std_out"))]
+            {
+                use colored::*;
+
+                let output = match offset {
+                    0 => format!("{:>15} - Downloading \"{}\"", "Installation", url),
+                    _ => format!("{:>15} - Resuming \"{}\" from {} MB", "Installation", url, offset / 1_048_576),
+                };
+                println!("{}", output.dimmed());
+
+                easy.progress(true)?;
+                easy.progress_function(move |total_download, current_download, _, _| {
+                    let percent = ((offset as f64 + current_download) / (offset as f64 + total_download)) * 100.0;
+                    let size_in_megabytes = (offset as f64 + total_download) as u64 / 1_048_576;
+                    let output = format!(
+                        "\r{:>15} - {:.2}% complete ({:#} MB total)",
+                        "Installation", percent, size_in_megabytes
+                    );
+                    print!("{}", output.dimmed());
+                    true
+                })?;
+            }
+
+            let mut transfer = easy.transfer();
+            transfer.write_function(|data| match file.write_all(data) {
+                Ok(()) => Ok(data.len()),
+                // Abort the transfer, as fewer bytes are reported as written.
+                Err(_) => Ok(0),
+            })?;
+            match transfer.perform() {
+                Ok(()) => {
+                    drop(transfer);
+                    // Read the completed download, and remove the partial download, so a corrupt download
+                    // is not resumed (the checksum is verified by the caller).
+                    let buffer = std::fs::read(&part_path)?;
+                    let _ = std::fs::remove_file(&part_path);
+                    Ok(buffer)
+                }
+                // Restart the download if the server does not support resuming it.
+                Err(error) if error.is_range_error() && offset > 0 => {
+                    drop(transfer);
+                    std::fs::remove_file(&part_path)?;
+                    Self::remote_fetch_resumable(url, file_path)
+                }
+                // Keep the partial download, so the next attempt resumes it.
+                Err(error) => Err(error.into()),
+            }
+        }
+
         #[cfg(feature = "wasm")]
         fn remote_fetch(url: &str) -> Result<Vec<u8>, $crate::errors::ParameterError> {
             // Use the browser's XmlHttpRequest object to download the parameter file synchronously.
@@ -168,75 +233,105 @@ macro_rules! impl_load_bytes_logic_local {
 
 macro_rules! impl_load_bytes_logic_remote {
     ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
+        // Retrieve the config for the parameter files.
+        let config = $crate::ParameterConfig::current();
+
         // Compose the correct file path for the parameter file.
-        let mut file_path = aleo_std::aleo_dir();
-        file_path.push($local_dir);
-        file_path.push($filename);
-
-        let buffer = if file_path.exists() {
-            // Attempts to load the parameter file locally with an absolute path.
-            std::fs::read(&file_path)?
-        } else {
-            // Downloads the missing parameters and stores it in the local directory for use.
-             #[cfg(not(feature = "no_std_out"))]
-            {
-                use colored::*;
-                let path = format!("(in {:?})", file_path);
-                eprintln!(
-                    "\n⚠️  \"{}\" does not exist. Downloading and storing it {}.\n",
-                    $filename, path.dimmed()
-                );
+        let file_path = match config.offline_dir() {
+            // Load the parameter file from the offline directory, if it is set.
+            Some(directory) => {
+                let file_path = directory.join($filename);
+                if !file_path.exists() {
+                    return Err($crate::errors::ParameterError::OfflineFileMissing(file_path));
+                }
+                file_path
             }
+            None => {
+                let mut file_path = aleo_std::aleo_dir();
+                file_path.push($local_dir);
+                file_path.push($filename);
+                file_path
+            }
+        };
 
-            // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
-
-            // Load remote file
-            cfg_if::cfg_if! {
-                if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
+        // Attempts to map the parameter file locally with an absolute path.
+        let buffer = match file_path.exists() {
+            true => Some($crate::MappedBytes::open(&file_path)?),
+            false => None,
+        };
+        // Discard the cached parameter file if it is corrupt, and the checksum policy is to download it again.
+        let buffer = match buffer {
+            Some(buffer)
+                if config.offline_dir().is_none()
+                    && config.checksum_policy() == $crate::ChecksumPolicy::Refetch
+                    && ($expected_size != buffer.len() || $expected_checksum != checksum!(&*buffer)) =>
+            {
+                remove_file!(file_path);
+                None
+            }
+            buffer => buffer,
+        };
 
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
-                    }
+        let buffer = match buffer {
+            Some(buffer) => buffer,
+            None => $crate::MappedBytes::from({
+                // Downloads the missing parameters and stores it in the local directory for use.
+                #[cfg(not(feature = "no_std_out"))]
+                {
+                    use colored::*;
+                    let path = format!("(in {:?})", file_path);
+                    eprintln!(
+                        "\n⚠️  \"{}\" does not exist. Downloading and storing it {}.\n",
+                        $filename, path.dimmed()
+                    );
+                }
 
-                    match Self::store_bytes(&buffer, &file_path) {
-                        Ok(()) => buffer,
-                        Err(_) => {
-                            eprintln!(
-                                "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
-                                $filename, file_path
-                            );
-                            buffer
+                // Load remote file, from the first mirror that has it.
+                cfg_if::cfg_if! {
+                    if #[cfg(not(feature = "wasm"))] {
+                        // Apply the delta from a previous version of the parameter file, if there is one,
+                        // and otherwise download the full parameter file, resuming any partial download.
+                        let delta = $crate::delta::fetch_with_delta(&config, $remote_url, &file_path, &$expected_checksum, |url| {
+                            let mut buffer = vec![];
+                            Self::remote_fetch(&mut buffer, url)?;
+                            Ok(buffer)
+                        });
+                        let buffer = match delta {
+                            Some(buffer) => buffer,
+                            None => config.fetch($remote_url, $filename, &$expected_checksum, |url| {
+                                Self::remote_fetch_resumable(url, &file_path)
+                            })?,
+                        };
+
+                        match Self::store_bytes(&buffer, &file_path) {
+                            Ok(()) => buffer,
+                            Err(_) => {
+                                eprintln!(
+                                    "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
+                                    $filename, file_path
+                                );
+                                buffer
+                            }
                         }
+                    } else if #[cfg(feature = "wasm")] {
+                        config.fetch($remote_url, $filename, &$expected_checksum, |url| Self::remote_fetch(url))?
+                    } else {
+                        return Err($crate::errors::ParameterError::RemoteFetchDisabled);
                     }
-                } else if #[cfg(feature = "wasm")] {
-                    let buffer = Self::remote_fetch(&url)?;
-
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
-                    }
-
-                    buffer
-                } else {
-                    return Err($crate::errors::ParameterError::RemoteFetchDisabled);
                 }
-            }
+            }),
         };
 
         // Ensure the size matches.
         if $expected_size != buffer.len() {
-            remove_file!(file_path);
+            if config.offline_dir().is_none() {
+                remove_file!(file_path);
+            }
             return Err($crate::errors::ParameterError::SizeMismatch($expected_size, buffer.len()));
         }
 
         // Ensure the checksum matches.
-        let candidate_checksum = checksum!(buffer.as_slice());
+        let candidate_checksum = checksum!(&*buffer);
         if $expected_checksum != candidate_checksum {
             return checksum_error!($expected_checksum, candidate_checksum)
         }
@@ -318,6 +413,11 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                Self::load_mapped().map($crate::MappedBytes::into_vec)
+            }
+
+            /// Returns the bytes of the parameter file, which are memory-mapped from disk where possible.
+            pub fn load_mapped() -> Result<$crate::MappedBytes, $crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
                 let metadata: serde_json::Value =
@@ -358,6 +458,11 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                Self::load_mapped().map($crate::MappedBytes::into_vec)
+            }
+
+            /// Returns the bytes of the parameter file, which are memory-mapped from disk where possible.
+            pub fn load_mapped() -> Result<$crate::MappedBytes, $crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
                 let metadata: serde_json::Value =
@@ -18,6 +18,8 @@ pub use genesis::*;
 pub mod powers;
 pub use powers::*;
 
+use once_cell::sync::Lazy;
+
 const REMOTE_URL: &str = "https://testnet3.parameters.aleo.org";
 
 // Degrees
@@ -83,6 +85,45 @@ impl_remote!(SplitVerifier, REMOTE_URL, "resources/", "split", "verifier");
 impl_remote!(FeeProver, REMOTE_URL, "resources/", "fee", "prover");
 impl_remote!(FeeVerifier, REMOTE_URL, "resources/", "fee", "verifier");
 
+/// The names of the functions in `credits.aleo`, which each have a proving and verifying key.
+pub const CREDITS_FUNCTION_NAMES: [&str; 8] = [
+    "mint",
+    "transfer_private",
+    "transfer_public",
+    "transfer_private_to_public",
+    "transfer_public_to_private",
+    "join",
+    "split",
+    "fee",
+];
+
+macro_rules! impl_credits_key_bytes {
+    ($fn_name:ident, $variant:ident, $kind:expr) => {
+        paste::paste! {
+            #[doc = concat!("Returns the memory-mapped bytes of the ", $kind, " key for the given function name in `credits.aleo`.")]
+            pub fn $fn_name(function_name: &str) -> Result<$crate::MappedBytes, $crate::errors::ParameterError> {
+                match function_name {
+                    "mint" => [<Mint $variant>]::load_mapped(),
+                    "transfer_private" => [<TransferPrivate $variant>]::load_mapped(),
+                    "transfer_public" => [<TransferPublic $variant>]::load_mapped(),
+                    "transfer_private_to_public" => [<TransferPrivateToPublic $variant>]::load_mapped(),
+                    "transfer_public_to_private" => [<TransferPublicToPrivate $variant>]::load_mapped(),
+                    "join" => [<Join $variant>]::load_mapped(),
+                    "split" => [<Split $variant>]::load_mapped(),
+                    "fee" => [<Fee $variant>]::load_mapped(),
+                    _ => Err($crate::errors::ParameterError::Message(format!(
+                        "There is no {} key for credits.aleo/{function_name}",
+                        $kind
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_credits_key_bytes!(credits_proving_key_bytes, Prover, "proving");
+impl_credits_key_bytes!(credits_verifying_key_bytes, Verifier, "verifying");
+
 #[macro_export]
 macro_rules! insert_credit_keys {
     ($map:ident, $type:ident<$network:ident>, $variant:ident) => {{
@@ -119,12 +160,14 @@ impl_remote!(InclusionVerifier, REMOTE_URL, "resources/", "inclusion", "verifier
 /// The function name for the inclusion circuit.
 pub const TESTNET3_INCLUSION_FUNCTION_NAME: &str = "inclusion";
 
-lazy_static! {
-    pub static ref INCLUSION_PROVING_KEY: Vec<u8> =
-        InclusionProver::load_bytes().expect("Failed to load inclusion proving key");
-    pub static ref INCLUSION_VERIFYING_KEY: Vec<u8> =
-        InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key");
-}
+/// The bytes of the inclusion proving key, which are retained for the lifetime of the program.
+#[deprecated(note = "Use `InclusionProver::load_mapped` instead, which does not retain the bytes in the heap")]
+pub static INCLUSION_PROVING_KEY: Lazy<Vec<u8>> =
+    Lazy::new(|| InclusionProver::load_bytes().expect("Failed to load inclusion proving key"));
+/// The bytes of the inclusion verifying key, which are retained for the lifetime of the program.
+#[deprecated(note = "Use `InclusionVerifier::load_mapped` instead, which does not retain the bytes in the heap")]
+pub static INCLUSION_VERIFYING_KEY: Lazy<Vec<u8>> =
+    Lazy::new(|| InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key"));
 
 #[cfg(test)]
 mod tests {
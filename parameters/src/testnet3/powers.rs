@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_curves::traits::{PairingCurve, PairingEngine};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::Field;
 use snarkvm_utilities::{
+    rand::Uniform,
     CanonicalDeserialize,
     CanonicalSerialize,
     Compress,
@@ -28,7 +30,12 @@ use snarkvm_utilities::{
 };
 
 use anyhow::{anyhow, bail, ensure, Result};
-use std::{collections::BTreeMap, ops::Range, sync::Arc};
+use rand::{CryptoRng, Rng};
+use std::{
+    collections::BTreeMap,
+    ops::{Mul, Range},
+    sync::Arc,
+};
 
 const NUM_POWERS_15: usize = 1 << 15;
 const NUM_POWERS_16: usize = 1 << 16;
@@ -73,6 +80,9 @@ pub struct PowersOfG<E: PairingEngine> {
     prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>>,
     /// beta * h
     beta_h: E::G2Affine,
+    /// Whether the powers were generated locally, in which case no further powers can be downloaded.
+    /// Note: This flag is not serialized.
+    is_local: bool,
 }
 
 impl<E: PairingEngine> PowersOfG<E> {
@@ -101,11 +111,98 @@ impl<E: PairingEngine> PowersOfG<E> {
             negative_powers_of_beta_h,
             prepared_negative_powers_of_beta_h,
             beta_h,
+            is_local: false,
         })
     }
 
+    /// Samples a fresh instance of the powers, with `num_powers` contiguous powers of beta G.
+    ///
+    /// This setup is **insecure**, as anyone who knows the randomness of `rng` can forge proofs.
+    /// It is intended for development and testing, so that the hard-coded powers do not need to be downloaded.
+    /// The returned powers can not be extended by downloading further powers.
+    pub fn insecure_setup<R: Rng + CryptoRng>(num_powers: usize, rng: &mut R) -> Result<Self> {
+        ensure!(num_powers.is_power_of_two(), "The number of powers ({num_powers}) must be a power of two");
+        ensure!(num_powers >= 2, "The number of powers ({num_powers}) must be at least 2");
+        ensure!(num_powers <= MAX_NUM_POWERS / 2, "The number of powers ({num_powers}) exceeds the maximum");
+
+        // The maximum degree of the SRS, which the shifted powers are aligned to.
+        let max_degree = MAX_NUM_POWERS - 1;
+
+        // Sample the trapdoor.
+        let beta = E::Fr::rand(rng);
+        let beta_inverse = beta.inverse().ok_or_else(|| anyhow!("Sampled a zero beta"))?;
+        let g = E::G1Affine::prime_subgroup_generator();
+        let gamma_g = g.mul(E::Fr::rand(rng)).to_affine();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        // Computes `base * beta^i` for each `i` in `exponents`.
+        let powers_of = |base: E::G1Affine, exponents: Range<usize>| {
+            let mut power = beta.pow([exponents.start as u64]);
+            let powers = exponents
+                .map(|_| {
+                    let result = base.mul(power);
+                    power *= beta;
+                    result
+                })
+                .collect::<Vec<_>>();
+            E::G1Projective::batch_normalization_into_affine(powers)
+        };
+
+        // Compute the normal and shifted powers of beta G.
+        let powers_of_beta_g = PowersOfBetaG {
+            powers_of_beta_g: powers_of(g, 0..num_powers),
+            shifted_powers_of_beta_g: powers_of(g, (MAX_NUM_POWERS - num_powers)..MAX_NUM_POWERS),
+        };
+
+        // Compute the degree bounds supported by the powers, which are of the form `2^k - 2`.
+        let degree_bounds = (1..=num_powers.trailing_zeros()).map(|k| (1usize << k) - 2).collect::<Vec<_>>();
+
+        // Compute the powers of beta * gamma G, for hiding, including those for the shifted powers.
+        let mut powers_of_beta_times_gamma_g = BTreeMap::new();
+        let mut insert_gamma_powers = |start: usize| {
+            for (i, power) in powers_of(gamma_g, start..start + 4).into_iter().enumerate() {
+                powers_of_beta_times_gamma_g.insert(start + i, power);
+            }
+        };
+        insert_gamma_powers(0);
+        for degree_bound in &degree_bounds {
+            insert_gamma_powers(max_degree - degree_bound);
+        }
+
+        // Compute the negative powers of beta H, for the degree bounds.
+        let negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine> = degree_bounds
+            .iter()
+            .map(|d| (*d, h.mul(beta_inverse.pow([(max_degree - d) as u64])).to_affine()))
+            .collect();
+        let prepared_negative_powers_of_beta_h =
+            negative_powers_of_beta_h.iter().map(|(d, affine)| (*d, affine.prepare())).collect();
+
+        Ok(Self {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g: Arc::new(powers_of_beta_times_gamma_g),
+            negative_powers_of_beta_h: Arc::new(negative_powers_of_beta_h),
+            prepared_negative_powers_of_beta_h: Arc::new(prepared_negative_powers_of_beta_h),
+            beta_h: h.mul(beta).to_affine(),
+            is_local: true,
+        })
+    }
+
+    /// Returns `true` if the powers were generated locally.
+    pub fn is_local(&self) -> bool {
+        self.is_local
+    }
+
+    /// Ensures the powers in `range` are available, if the powers were generated locally.
+    fn ensure_local_powers(&self, range: &Range<usize>) -> Result<()> {
+        if self.is_local && !range.is_empty() && !self.powers_of_beta_g.contains_powers(range) {
+            bail!("The locally generated SRS does not contain the powers of beta G in {range:?}")
+        }
+        Ok(())
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&mut self, range: Range<usize>) -> Result<()> {
+        self.ensure_local_powers(&range)?;
         self.powers_of_beta_g.download_powers_for(&range)
     }
 
@@ -126,11 +223,13 @@ impl<E: PairingEngine> PowersOfG<E> {
 
     /// Returns the `index`-th power of beta * G.
     pub fn power_of_beta_g(&mut self, index: usize) -> Result<E::G1Affine> {
+        self.ensure_local_powers(&(index..index + 1))?;
         self.powers_of_beta_g.power(index)
     }
 
     /// Returns the powers of `beta * G` that lie within `range`.
     pub fn powers_of_beta_g(&mut self, range: Range<usize>) -> Result<&[E::G1Affine]> {
+        self.ensure_local_powers(&range)?;
         self.powers_of_beta_g.powers(range)
     }
 
@@ -192,6 +291,7 @@ impl<E: PairingEngine> CanonicalDeserialize for PowersOfG<E> {
             negative_powers_of_beta_h,
             prepared_negative_powers_of_beta_h,
             beta_h,
+            is_local: false,
         };
         if let Validate::Yes = validate {
             powers.check()?;
@@ -17,6 +17,7 @@ use super::*;
 impl<N: Network> FromBytes for CoinbaseSolution<N> {
     /// Reads the coinbase solution from the buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        N::Puzzle::read_solution_header(&mut reader)?;
         let partial_solutions_len: u32 = FromBytes::read_le(&mut reader)?;
 
         let mut partial_solutions = Vec::with_capacity(partial_solutions_len as usize);
@@ -34,6 +35,7 @@ impl<N: Network> FromBytes for CoinbaseSolution<N> {
 impl<N: Network> ToBytes for CoinbaseSolution<N> {
     /// Writes the coinbase solution to the buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        N::Puzzle::write_solution_header(&mut writer)?;
         (u32::try_from(self.partial_solutions.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
 
         for individual_puzzle_solution in &self.partial_solutions {
@@ -28,7 +28,7 @@ mod puzzle_commitment;
 pub use puzzle_commitment::*;
 
 use crate::{hash_commitment, hash_commitments, CoinbasePuzzle};
-use console::{account::Address, prelude::*, types::Field};
+use console::{account::Address, network::PuzzleMarker, prelude::*, types::Field};
 use snarkvm_algorithms::{
     fft::{domain::FFTPrecomputation, DensePolynomial, EvaluationDomain},
     polycommit::kzg10::{KZGCommitment, KZGProof, LagrangeBasis, VerifierKey, KZG10},
@@ -74,6 +74,7 @@ impl<N: Network> CoinbaseProvingKey<N> {
             lagrange_basis_at_beta_g: Cow::Borrowed(self.lagrange_basis_at_beta_g.as_slice()),
             powers_of_beta_times_gamma_g: Cow::Owned(vec![]),
             domain: self.product_domain,
+            preprocessed_lagrange_basis_at_beta_g: None,
         }
     }
 
@@ -17,6 +17,7 @@ use super::*;
 impl<N: Network> FromBytes for ProverSolution<N> {
     /// Reads the prover solution from the buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        N::Puzzle::read_solution_header(&mut reader)?;
         let partial_solution: PartialSolution<N> = FromBytes::read_le(&mut reader)?;
         let proof = KZGProof::read_le(&mut reader)?;
 
@@ -27,6 +28,7 @@ impl<N: Network> FromBytes for ProverSolution<N> {
 impl<N: Network> ToBytes for ProverSolution<N> {
     /// Writes the prover solution to the buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        N::Puzzle::write_solution_header(&mut writer)?;
         self.partial_solution.write_le(&mut writer)?;
         self.proof.write_le(&mut writer)
     }
@@ -22,12 +22,15 @@ pub use helpers::*;
 mod hash;
 use hash::*;
 
+mod puzzle;
+pub use puzzle::*;
+
 #[cfg(test)]
 mod tests;
 
 use console::{
     account::Address,
-    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
+    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, CryptoRng, Network, Result, Rng, ToBytes},
     program::cfg_into_iter,
 };
 use snarkvm_algorithms::{
@@ -352,6 +355,64 @@ impl<N: Network> CoinbasePuzzle<N> {
         )?)
     }
 
+    /// Returns `true` if all of the given prover solutions are valid.
+    ///
+    /// The epoch polynomial is shared across the prover solutions, and their proofs are checked together
+    /// in one randomized batch of pairings, instead of a pair of pairings for each prover solution.
+    pub fn batch_verify<R: Rng + CryptoRng>(
+        &self,
+        prover_solutions: &[ProverSolution<N>],
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+        rng: &mut R,
+    ) -> Result<bool> {
+        // Ensure there exists prover solutions.
+        if prover_solutions.is_empty() {
+            bail!("Cannot batch verify an empty list of prover solutions.");
+        }
+
+        // Ensure the proofs are non-hiding.
+        if prover_solutions.iter().any(|solution| solution.proof().is_hiding()) {
+            return Ok(false);
+        }
+
+        // Retrieve the epoch polynomial.
+        let epoch_polynomial = epoch_challenge.epoch_polynomial();
+
+        // Compute the challenge point and the claimed value for each prover solution.
+        let (challenge_points, claimed_values): (Vec<_>, Vec<_>) = cfg_iter!(prover_solutions)
+            .map(|solution| {
+                // Ensure that the prover solution meets the proof target.
+                if solution.to_target()? < proof_target {
+                    bail!("Prover puzzle does not meet the proof target requirements.")
+                }
+                // Compute the challenge point.
+                let challenge_point = hash_commitment(&solution.commitment())?;
+                // Evaluate the epoch and prover polynomials at the challenge point.
+                let prover_polynomial = solution.to_prover_polynomial(epoch_challenge)?;
+                let claimed_value =
+                    epoch_polynomial.evaluate(challenge_point) * prover_polynomial.evaluate(challenge_point);
+                Ok((challenge_point, claimed_value))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        // Retrieve the commitments and the proofs.
+        let commitments = prover_solutions.iter().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        let proofs = prover_solutions.iter().map(|solution| *solution.proof()).collect::<Vec<_>>();
+
+        // Check the KZG proofs in one batch.
+        Ok(KZG10::batch_check(
+            self.coinbase_verifying_key(),
+            &commitments,
+            &challenge_points,
+            &claimed_values,
+            &proofs,
+            rng,
+        )?)
+    }
+
     /// Returns the coinbase proving key.
     pub fn coinbase_proving_key(&self) -> Result<&CoinbaseProvingKey<N>> {
         match self {
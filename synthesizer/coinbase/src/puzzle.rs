@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::network::AleoPuzzle;
+
+/// The puzzle that provers solve for the coinbase reward.
+///
+/// The `CoinbasePuzzle` is the puzzle of the Aleo networks. A test network may instantiate an alternative puzzle,
+/// by marking it as `Network::Puzzle` and mapping the marker to the puzzle with `SelectPuzzle`.
+/// The solutions are carried in the `ProverSolution` and `CoinbaseSolution` formats of the block,
+/// which are prefixed with the solution header of the puzzle marker, i.e. `PuzzleMarker`.
+pub trait Puzzle<N: Network>: Clone + Send + Sync {
+    /// Loads the puzzle.
+    fn load() -> Result<Self>;
+
+    /// Returns a prover solution to the puzzle.
+    fn prove(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>>;
+
+    /// Returns a coinbase solution for the given epoch challenge and prover solutions.
+    ///
+    /// # Note
+    /// This method does *not* check that the prover solutions are valid.
+    fn accumulate_unchecked(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+    ) -> Result<CoinbaseSolution<N>>;
+
+    /// Returns `true` if the coinbase solution is valid.
+    fn verify(
+        &self,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool>;
+
+    /// Returns `true` if all of the given prover solutions are valid.
+    fn batch_verify<R: Rng + CryptoRng>(
+        &self,
+        prover_solutions: &[ProverSolution<N>],
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+        rng: &mut R,
+    ) -> Result<bool>;
+}
+
+/// The mapping from the puzzle marker of a network, i.e. `Network::Puzzle`, to its puzzle.
+pub trait SelectPuzzle<N: Network> {
+    /// The puzzle of the network.
+    type Puzzle: Puzzle<N>;
+}
+
+impl<N: Network> SelectPuzzle<N> for AleoPuzzle {
+    type Puzzle = CoinbasePuzzle<N>;
+}
+
+/// A network whose puzzle marker selects a puzzle.
+///
+/// `Network` is defined in the console, which cannot name the puzzles, so the bound of `Network::Puzzle`
+/// on `SelectPuzzle` is carried by this trait, which is implemented for every network that satisfies it.
+pub trait PuzzleNetwork: Network {
+    /// The puzzle of the network.
+    type CoinbasePuzzle: Puzzle<Self>;
+}
+
+impl<N: Network> PuzzleNetwork for N
+where
+    N::Puzzle: SelectPuzzle<N>,
+{
+    type CoinbasePuzzle = <N::Puzzle as SelectPuzzle<N>>::Puzzle;
+}
+
+/// The puzzle of the given network.
+pub type NetworkPuzzle<N> = <N as PuzzleNetwork>::CoinbasePuzzle;
+
+impl<N: Network> Puzzle<N> for CoinbasePuzzle<N> {
+    /// Loads the coinbase puzzle proving and verifying keys.
+    fn load() -> Result<Self> {
+        CoinbasePuzzle::load()
+    }
+
+    /// Returns a prover solution to the coinbase puzzle.
+    fn prove(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        CoinbasePuzzle::prove(self, epoch_challenge, address, nonce, minimum_proof_target)
+    }
+
+    /// Returns a coinbase solution for the given epoch challenge and prover solutions.
+    fn accumulate_unchecked(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+    ) -> Result<CoinbaseSolution<N>> {
+        CoinbasePuzzle::accumulate_unchecked(self, epoch_challenge, prover_solutions)
+    }
+
+    /// Returns `true` if the coinbase solution is valid.
+    fn verify(
+        &self,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        CoinbasePuzzle::verify(self, coinbase_solution, epoch_challenge, coinbase_target, proof_target)
+    }
+
+    /// Returns `true` if all of the given prover solutions are valid.
+    fn batch_verify<R: Rng + CryptoRng>(
+        &self,
+        prover_solutions: &[ProverSolution<N>],
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+        rng: &mut R,
+    ) -> Result<bool> {
+        CoinbasePuzzle::batch_verify(self, prover_solutions, epoch_challenge, proof_target, rng)
+    }
+}
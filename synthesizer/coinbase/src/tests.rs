@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use super::*;
-use console::{account::*, network::Testnet3};
+use console::{
+    account::*,
+    network::{PuzzleMarker, Testnet3},
+    prelude::{error, FromBytes, IoResult, Read, Write},
+};
 use snarkvm_utilities::Uniform;
 
 use rand::RngCore;
@@ -52,6 +56,43 @@ fn test_coinbase_puzzle() {
     }
 }
 
+#[test]
+fn test_batch_verify() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for log_degree in 5..10 {
+        let degree = (1 << log_degree) - 1;
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, config).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+        for batch_size in 1..10 {
+            let solutions = (0..batch_size)
+                .map(|_| {
+                    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+                    let address = Address::try_from(private_key).unwrap();
+                    let nonce = u64::rand(&mut rng);
+                    puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+                })
+                .collect::<Vec<_>>();
+            assert!(puzzle.batch_verify(&solutions, &epoch_challenge, 0u64, &mut rng).unwrap());
+
+            // Ensure the batch is rejected for a different epoch challenge.
+            let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+            assert!(!puzzle.batch_verify(&solutions, &bad_epoch_challenge, 0u64, &mut rng).unwrap());
+
+            // Ensure the batch is rejected if it does not meet the proof target.
+            let proof_target = solutions.iter().map(|solution| solution.to_target().unwrap()).min().unwrap();
+            assert!(puzzle.batch_verify(&solutions, &epoch_challenge, proof_target, &mut rng).unwrap());
+            assert!(puzzle.batch_verify(&solutions, &epoch_challenge, proof_target + 1, &mut rng).is_err());
+        }
+    }
+}
+
 #[test]
 fn test_prover_solution_minimum_target() {
     let mut rng = TestRng::default();
@@ -106,3 +147,140 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+/// A puzzle in place of an alternative puzzle of a test network.
+#[derive(Clone)]
+struct TaggedPuzzle(CoinbasePuzzle<Testnet3>);
+
+/// The marker of the tagged puzzle, which tags the payload of its solutions.
+#[derive(Copy, Clone, Debug)]
+enum TaggedPuzzleMarker {}
+
+impl TaggedPuzzleMarker {
+    /// The tag of the solution payload.
+    const TAG: u8 = 7;
+}
+
+impl PuzzleMarker for TaggedPuzzleMarker {
+    fn write_solution_header<W: Write>(writer: W) -> IoResult<()> {
+        Self::TAG.write_le(writer)
+    }
+
+    fn read_solution_header<R: Read>(reader: R) -> IoResult<()> {
+        match u8::read_le(reader)? {
+            Self::TAG => Ok(()),
+            tag => Err(error(format!("Invalid solution tag {tag}"))),
+        }
+    }
+}
+
+impl SelectPuzzle<Testnet3> for TaggedPuzzleMarker {
+    type Puzzle = TaggedPuzzle;
+}
+
+impl Puzzle<Testnet3> for TaggedPuzzle {
+    fn load() -> Result<Self> {
+        bail!("The tagged puzzle is only trimmed from a local SRS")
+    }
+
+    fn prove(
+        &self,
+        epoch_challenge: &EpochChallenge<Testnet3>,
+        address: Address<Testnet3>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<Testnet3>> {
+        self.0.prove(epoch_challenge, address, nonce, minimum_proof_target)
+    }
+
+    fn accumulate_unchecked(
+        &self,
+        epoch_challenge: &EpochChallenge<Testnet3>,
+        prover_solutions: &[ProverSolution<Testnet3>],
+    ) -> Result<CoinbaseSolution<Testnet3>> {
+        self.0.accumulate_unchecked(epoch_challenge, prover_solutions)
+    }
+
+    fn verify(
+        &self,
+        coinbase_solution: &CoinbaseSolution<Testnet3>,
+        epoch_challenge: &EpochChallenge<Testnet3>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        self.0.verify(coinbase_solution, epoch_challenge, coinbase_target, proof_target)
+    }
+
+    fn batch_verify<R: Rng + CryptoRng>(
+        &self,
+        prover_solutions: &[ProverSolution<Testnet3>],
+        epoch_challenge: &EpochChallenge<Testnet3>,
+        proof_target: u64,
+        rng: &mut R,
+    ) -> Result<bool> {
+        self.0.batch_verify(prover_solutions, epoch_challenge, proof_target, rng)
+    }
+}
+
+/// Proves, verifies, and serializes prover solutions with the given puzzle and marker,
+/// and returns the solutions with their payloads.
+fn check_puzzle<M: PuzzleMarker, P: Puzzle<Testnet3>>(
+    puzzle: &P,
+    degree: u32,
+    rng: &mut TestRng,
+) -> Vec<(ProverSolution<Testnet3>, Vec<u8>)> {
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Prove the solutions.
+    let solutions = (0..4)
+        .map(|_| {
+            let private_key = PrivateKey::<Testnet3>::new(rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            puzzle.prove(&epoch_challenge, address, u64::rand(rng), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Ensure the solutions are verified by the puzzle.
+    assert!(puzzle.batch_verify(&solutions, &epoch_challenge, 0u64, rng).unwrap());
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Ensure the payloads of the solutions round trip.
+    solutions
+        .into_iter()
+        .map(|solution| {
+            let mut payload = Vec::new();
+            M::write_solution_header(&mut payload).unwrap();
+            solution.write_le(&mut payload).unwrap();
+
+            let mut reader = &payload[..];
+            M::read_solution_header(&mut reader).unwrap();
+            assert_eq!(solution, ProverSolution::read_le(reader).unwrap());
+            (solution, payload)
+        })
+        .collect()
+}
+
+#[test]
+fn test_select_puzzle() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 8) - 1;
+    let config = PuzzleConfig { degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(config).unwrap();
+
+    // Ensure the Aleo networks select the coinbase puzzle, whose solutions have no header.
+    let puzzle: NetworkPuzzle<Testnet3> = CoinbasePuzzle::trim(&srs, config).unwrap();
+    for (solution, payload) in check_puzzle::<<Testnet3 as Network>::Puzzle, _>(&puzzle, degree, &mut rng) {
+        assert_eq!(payload, solution.to_bytes_le().unwrap());
+    }
+
+    // Ensure an alternative puzzle is selected by its marker, and its marker writes its own header.
+    let puzzle: <TaggedPuzzleMarker as SelectPuzzle<Testnet3>>::Puzzle = TaggedPuzzle(puzzle);
+    for (solution, mut payload) in check_puzzle::<TaggedPuzzleMarker, _>(&puzzle, degree, &mut rng) {
+        assert_eq!(payload, [vec![TaggedPuzzleMarker::TAG], solution.to_bytes_le().unwrap()].concat());
+        // Ensure a payload with a different tag is rejected.
+        payload[0] = TaggedPuzzleMarker::TAG + 1;
+        assert!(TaggedPuzzleMarker::read_solution_header(&payload[..]).is_err());
+    }
+}
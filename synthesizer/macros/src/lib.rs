@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![forbid(unsafe_code)]
+
+use console::network::Testnet3;
+use synthesizer::Program;
+
+use quote::quote;
+use std::str::FromStr;
+use syn::{parse_macro_input, LitStr};
+
+/// Parses the given Aleo program at compile time, and expands to an expression that returns the `Program<N>`.
+///
+/// The build fails if the program does not parse, or if any of its declarations are rejected,
+/// i.e. for an undefined struct, a duplicate name, or a finalize scope with the wrong number of inputs.
+/// The program is checked for `Testnet3`, and the network `N` is inferred from the call site.
+/// Note: The instructions are type-checked when the program is added to a process.
+///
+/// ```ignore
+/// let program: Program<Testnet3> = aleo_program!(
+///     r"
+/// program hello.aleo;
+///
+/// function hello:
+///     input r0 as u32.public;
+///     input r1 as u32.private;
+///     add r0 r1 into r2;
+///     output r2 as u32.private;"
+/// );
+/// ```
+#[proc_macro]
+pub fn aleo_program(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let program = parse_macro_input!(input as LitStr);
+
+    // Ensure the program is valid, or fail the build at the program literal.
+    if let Err(error) = check_program(&program.value()) {
+        return syn::Error::new(program.span(), error).to_compile_error().into();
+    }
+
+    // As the program is valid, parsing it again at runtime does not fail.
+    quote! {
+        ::core::str::FromStr::from_str(#program)
+            .unwrap_or_else(|_| ::core::unreachable!("The program was checked at compile time"))
+    }
+    .into()
+}
+
+/// Returns an error message if the given string is not a valid program.
+fn check_program(program: &str) -> Result<(), String> {
+    Program::<Testnet3>::from_str(program).map(|_| ()).map_err(|error| format!("Invalid Aleo program - {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_program() {
+        // Ensure a valid program passes.
+        assert!(check_program(
+            r"
+program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;"
+        )
+        .is_ok());
+
+        // Ensure a syntax error fails.
+        assert!(check_program("program hello.aleo; function hello: input r0 as u32.public").is_err());
+        // Ensure an undefined struct fails.
+        assert!(check_program(
+            r"
+program hello.aleo;
+
+struct message:
+    data as undefined;"
+        )
+        .is_err());
+        // Ensure a finalize scope with the wrong number of inputs fails.
+        assert!(check_program(
+            r"
+program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    finalize r0;
+
+finalize hello:
+    input r0 as u32.public;
+    input r1 as u32.public;"
+        )
+        .is_err());
+    }
+}
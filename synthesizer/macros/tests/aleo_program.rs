@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::Testnet3, program::Identifier};
+use snarkvm_synthesizer_macros::aleo_program;
+use synthesizer::Program;
+
+use std::str::FromStr;
+
+type CurrentNetwork = Testnet3;
+
+#[test]
+fn test_aleo_program() {
+    let program: Program<CurrentNetwork> = aleo_program!(
+        r"
+program token.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];"
+    );
+
+    assert_eq!(program.id().to_string(), "token.aleo");
+    assert!(program.contains_mapping(&Identifier::from_str("account").unwrap()));
+    assert!(program.contains_function(&Identifier::from_str("mint_public").unwrap()));
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A program component, in the order it is declared.
+#[derive(Clone)]
+enum Component<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
+    Mapping(Mapping<N>),
+    Struct(Struct<N>),
+    Record(RecordType<N>),
+    Closure(ClosureBuilder<N, Instruction>),
+    Function(FunctionBuilder<N, Instruction, Command>),
+}
+
+/// A builder for a program, which constructs the program without parsing it from a string,
+/// for compilers and code generators that target snarkVM directly.
+///
+/// The components are checked in the order they are declared when the program is built,
+/// so a struct must be declared before it is used, as in a parsed program.
+#[derive(Clone)]
+pub struct ProgramBuilder<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
+    /// The ID of the program.
+    id: String,
+    /// The IDs of the imported programs.
+    imports: Vec<ProgramID<N>>,
+    /// The address of the auditor, if any.
+    auditor: Option<Address<N>>,
+    /// The mappings, structs, records, closures, and functions, in order of declaration.
+    components: Vec<Component<N, Instruction, Command>>,
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramBuilder<N, Instruction, Command> {
+    /// Initializes a new builder for the program with the given ID, i.e. `token.aleo`.
+    pub fn new(id: &str) -> Self {
+        Self { id: id.to_string(), imports: Vec::new(), auditor: None, components: Vec::new() }
+    }
+
+    /// Adds an import of the program with the given ID.
+    pub fn import(mut self, program_id: ProgramID<N>) -> Self {
+        self.imports.push(program_id);
+        self
+    }
+
+    /// Sets the auditor, which every record output of the program is additionally encrypted to.
+    pub fn auditor(mut self, auditor: Address<N>) -> Self {
+        self.auditor = Some(auditor);
+        self
+    }
+
+    /// Adds the given mapping.
+    pub fn mapping(mut self, mapping: Mapping<N>) -> Self {
+        self.components.push(Component::Mapping(mapping));
+        self
+    }
+
+    /// Adds the given struct.
+    pub fn struct_(mut self, struct_: Struct<N>) -> Self {
+        self.components.push(Component::Struct(struct_));
+        self
+    }
+
+    /// Adds the given record type.
+    pub fn record(mut self, record: RecordType<N>) -> Self {
+        self.components.push(Component::Record(record));
+        self
+    }
+
+    /// Adds the closure from the given builder.
+    pub fn closure(mut self, closure: ClosureBuilder<N, Instruction>) -> Self {
+        self.components.push(Component::Closure(closure));
+        self
+    }
+
+    /// Adds the function from the given builder.
+    pub fn function(mut self, function: FunctionBuilder<N, Instruction, Command>) -> Self {
+        self.components.push(Component::Function(function));
+        self
+    }
+
+    /// Returns the program.
+    ///
+    /// # Errors
+    /// This method will halt if the program ID is invalid.
+    /// This method will halt if any component is rejected by the program, as if it were parsed.
+    /// This method will halt if any closure or function fails to build.
+    pub fn build(self) -> Result<ProgramCore<N, Instruction, Command>> {
+        // Initialize a new program.
+        let mut program = ProgramCore::new(ProgramID::from_str(&self.id)?)?;
+        // Set the auditor, if any.
+        if let Some(auditor) = self.auditor {
+            program.set_auditor(auditor)?;
+        }
+        // Add the components, in order of declaration.
+        for component in self.components {
+            match component {
+                Component::Mapping(mapping) => program.add_mapping(mapping)?,
+                Component::Struct(struct_) => program.add_struct(struct_)?,
+                Component::Record(record) => program.add_record(record)?,
+                Component::Closure(closure) => program.add_closure(closure.build()?)?,
+                Component::Function(function) => program.add_function(function.build()?)?,
+            }
+        }
+        // Lastly, add the imports (if any), as in a parsed program.
+        for program_id in self.imports {
+            program.add_import(Import::new(program_id))?;
+        }
+        Ok(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::Testnet3,
+        program::{PublicOrPrivate, Register, RegisterType, ValueType},
+    };
+    use synthesizer::{
+        process::{Command, FinalizeCommand, Instruction, Program},
+        program::{
+            ClosureBuilder,
+            FinalizeBuilder,
+            FunctionBuilder,
+            MapKey,
+            MapValue,
+            Mapping,
+            Operand,
+            ProgramBuilder,
+        },
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_program_builder() -> Result<()> {
+        let expected = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program token.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+struct message:
+    first as field;
+    second as field;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+closure sum:
+    input r0 as message;
+    add r0.first r0.second into r1;
+    output r1 as field;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];",
+        )?;
+
+        // Prepare the operands and types.
+        let r = |locator| Operand::Register(Register::Locator(locator));
+        let u64_type = PlaintextType::from_str("u64")?;
+        let address_type = PlaintextType::from_str("address")?;
+
+        let candidate: Program<CurrentNetwork> = ProgramBuilder::new("token.aleo")
+            .import(ProgramID::from_str("credits.aleo")?)
+            .mapping(Mapping::new(
+                Identifier::from_str("account")?,
+                MapKey::new(Identifier::from_str("owner")?, address_type),
+                MapValue::new(Identifier::from_str("amount")?, u64_type),
+            ))
+            .struct_(Struct::new(Identifier::from_str("message")?, vec![
+                (Identifier::from_str("first")?, PlaintextType::from_str("field")?),
+                (Identifier::from_str("second")?, PlaintextType::from_str("field")?),
+            ])?)
+            .record(RecordType::new(Identifier::from_str("token")?, PublicOrPrivate::Private, vec![(
+                Identifier::from_str("amount")?,
+                EntryType::Private(u64_type),
+            )])?)
+            .closure(
+                ClosureBuilder::new("sum")
+                    .input(Register::Locator(0), RegisterType::from_str("message")?)
+                    .instruction(Instruction::from_str("add r0.first r0.second into r1;")?)
+                    .output(r(1), RegisterType::from_str("field")?),
+            )
+            .function(
+                FunctionBuilder::new("mint_public")
+                    .input(Register::Locator(0), ValueType::from_str("address.public")?)
+                    .input(Register::Locator(1), ValueType::from_str("u64.public")?)
+                    .finalize(
+                        FinalizeCommand::new(vec![r(0), r(1)])?,
+                        FinalizeBuilder::new("mint_public")
+                            .input(Register::Locator(0), address_type)
+                            .input(Register::Locator(1), u64_type)
+                            .command(Command::from_str("get.or_use account[r0] 0u64 into r2;")?)
+                            .command(Command::from_str("add r2 r1 into r3;")?)
+                            .command(Command::from_str("set r3 into account[r0];")?),
+                    ),
+            )
+            .build()?;
+        assert_eq!(expected, candidate);
+        // Ensure the program matches its parsed form.
+        assert_eq!(candidate, Program::from_str(&candidate.to_string())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_builder_fails() -> Result<()> {
+        type Builder = ProgramBuilder<CurrentNetwork, Instruction<CurrentNetwork>, Command<CurrentNetwork>>;
+
+        // Prepare a struct that uses another struct.
+        let inner = Struct::new(Identifier::from_str("inner")?, vec![(
+            Identifier::from_str("data")?,
+            PlaintextType::from_str("field")?,
+        )])?;
+        let outer = Struct::new(Identifier::from_str("outer")?, vec![(
+            Identifier::from_str("data")?,
+            PlaintextType::from_str("inner")?,
+        )])?;
+
+        // Ensure an invalid program ID fails.
+        assert!(Builder::new("token").build().is_err());
+        // Ensure a struct that is used before it is declared fails.
+        assert!(Builder::new("token.aleo").struct_(outer.clone()).struct_(inner.clone()).build().is_err());
+        assert!(Builder::new("token.aleo").struct_(inner.clone()).struct_(outer).build().is_ok());
+        // Ensure a duplicate name fails.
+        assert!(Builder::new("token.aleo").struct_(inner.clone()).struct_(inner).build().is_err());
+        // Ensure a function that fails to build fails.
+        let function = FunctionBuilder::new("mint").input(Register::Locator(0), ValueType::from_str("u64.public")?);
+        assert!(Builder::new("token.aleo")
+            .function(function.clone().input(Register::Locator(0), ValueType::from_str("u64.public")?))
+            .build()
+            .is_err());
+        assert!(Builder::new("token.aleo").function(function).build().is_ok());
+        Ok(())
+    }
+}
@@ -21,7 +21,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid program version"));
         }
 
@@ -31,6 +31,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Initialize the program.
         let mut program = ProgramCore::new(id).map_err(|e| error(e.to_string()))?;
 
+        // Read the auditor, which is only present in version 1.
+        if version == 1 {
+            program.set_auditor(Address::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
+        }
+
         // Read the number of program imports.
         let imports_len = u8::read_le(&mut reader)?;
         // Read the program imports.
@@ -68,12 +73,21 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
     for ProgramCore<N, Instruction, Command>
 {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        0u8.write_le(&mut writer)?;
+        match &self.auditor {
+            // Write the version, without an auditor.
+            None => 0u8.write_le(&mut writer)?,
+            // Write the version, with an auditor.
+            Some(_) => 1u8.write_le(&mut writer)?,
+        }
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
 
+        // Write the auditor, if the program declares one.
+        if let Some(auditor) = &self.auditor {
+            auditor.write_le(&mut writer)?;
+        }
+
         // Write the number of program imports.
         u8::try_from(self.imports.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
         // Write the program imports.
@@ -172,4 +186,36 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_auditor() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+auditor aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah;
+
+record token:
+    owner as address.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as token.record;
+    add r0.token_amount r0.token_amount into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let expected = Program::<CurrentNetwork>::from_str(program)?;
+        assert!(expected.auditor().is_some());
+
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the program is written with version 1.
+        assert_eq!(expected_bytes[0], 1);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.auditor(), candidate.auditor());
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Operand;
+
+/// A builder for a closure, which constructs the closure without parsing it from a string.
+///
+/// The statements are checked in order when the closure is built.
+#[derive(Clone)]
+pub struct ClosureBuilder<N: Network, Instruction: InstructionTrait<N>> {
+    /// The name of the closure.
+    name: String,
+    /// The input registers and register types, in order of the input registers.
+    inputs: Vec<(Register<N>, RegisterType<N>)>,
+    /// The instructions, in order of execution.
+    instructions: Vec<Instruction>,
+    /// The output operands and register types, in order of the desired output.
+    outputs: Vec<(Operand<N>, RegisterType<N>)>,
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>> ClosureBuilder<N, Instruction> {
+    /// Initializes a new builder for the closure with the given name.
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), inputs: Vec::new(), instructions: Vec::new(), outputs: Vec::new() }
+    }
+
+    /// Adds an input statement of the form `input {register} as {register_type};`.
+    pub fn input(mut self, register: Register<N>, register_type: RegisterType<N>) -> Self {
+        self.inputs.push((register, register_type));
+        self
+    }
+
+    /// Adds the given instruction.
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Adds an output statement of the form `output {operand} as {register_type};`.
+    pub fn output(mut self, operand: Operand<N>, register_type: RegisterType<N>) -> Self {
+        self.outputs.push((operand, register_type));
+        self
+    }
+
+    /// Returns the closure.
+    ///
+    /// # Errors
+    /// This method will halt if the name is not a valid identifier.
+    /// This method will halt if any statement is rejected by the closure, as if it were parsed.
+    pub fn build(self) -> Result<ClosureCore<N, Instruction>> {
+        // Initialize a new closure.
+        let mut closure = ClosureCore::new(Identifier::from_str(&self.name)?);
+        // Add the input statements.
+        for (register, register_type) in self.inputs {
+            closure.add_input(Input::new(register, register_type))?;
+        }
+        // Add the instructions.
+        for instruction in self.instructions {
+            closure.add_instruction(instruction)?;
+        }
+        // Add the output statements.
+        for (operand, register_type) in self.outputs {
+            closure.add_output(Output::new(operand, register_type))?;
+        }
+        Ok(closure)
+    }
+}
@@ -31,6 +31,12 @@ pub struct Input<N: Network> {
 }
 
 impl<N: Network> Input<N> {
+    /// Initializes a new input statement with the given register and register type.
+    #[inline]
+    pub const fn new(register: Register<N>, register_type: RegisterType<N>) -> Self {
+        Self { register, register_type }
+    }
+
     /// Returns the input register.
     #[inline]
     pub const fn register(&self) -> &Register<N> {
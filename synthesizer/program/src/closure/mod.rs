@@ -18,6 +18,9 @@ use input::*;
 mod output;
 use output::*;
 
+mod builder;
+pub use builder::ClosureBuilder;
+
 mod bytes;
 mod parse;
 
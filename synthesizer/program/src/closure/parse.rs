@@ -56,12 +56,19 @@ impl<N: Network, Instruction: InstructionTrait<N>> FromStr for ClosureCore<N, In
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Ensure the remainder is empty, or report the mistake in the next statement.
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder_with(string, remainder, [
+                        Instruction::parse(remainder).err(),
+                        Output::<N>::parse(remainder).err(),
+                    ])
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CommandTrait, InstructionTrait, ProgramCore};
+use console::{network::prelude::*, program::ProgramID};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The transitive import graph of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyGraph<N: Network> {
+    /// The ID of the program the graph is constructed for.
+    root: ProgramID<N>,
+    /// The direct imports of each resolved program, in the order of discovery from the root.
+    edges: IndexMap<ProgramID<N>, Vec<ProgramID<N>>>,
+    /// The imports that could not be resolved.
+    missing: IndexSet<ProgramID<N>>,
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the transitive import graph of the program, where imports are resolved from the given programs.
+    /// Imports that cannot be resolved are reported by `DependencyGraph::missing`.
+    pub fn dependency_graph<'a>(&'a self, programs: impl IntoIterator<Item = &'a Self>) -> DependencyGraph<N> {
+        // Index the given programs by their program ID.
+        let programs = programs.into_iter().map(|program| (*program.id(), program)).collect::<IndexMap<_, _>>();
+
+        let mut edges = IndexMap::new();
+        let mut missing = IndexSet::new();
+
+        // Traverse the imports, starting from this program.
+        let mut queue = vec![self];
+        while let Some(program) = queue.pop() {
+            // Skip the program if it was already traversed.
+            if edges.contains_key(program.id()) {
+                continue;
+            }
+            // Record the direct imports of the program.
+            edges.insert(*program.id(), program.imports().keys().copied().collect::<Vec<_>>());
+            // Resolve the imports that were not yet traversed.
+            for import_id in program.imports().keys().rev() {
+                if !edges.contains_key(import_id) {
+                    match programs.get(import_id) {
+                        Some(import) => queue.push(import),
+                        None => {
+                            missing.insert(*import_id);
+                        }
+                    }
+                }
+            }
+        }
+
+        DependencyGraph { root: *self.id(), edges, missing }
+    }
+}
+
+impl<N: Network> DependencyGraph<N> {
+    /// Returns the ID of the program the graph is constructed for.
+    pub const fn root(&self) -> &ProgramID<N> {
+        &self.root
+    }
+
+    /// Returns the IDs of the resolved programs in the graph, starting with the root.
+    pub fn programs(&self) -> impl '_ + ExactSizeIterator<Item = &ProgramID<N>> {
+        self.edges.keys()
+    }
+
+    /// Returns the direct imports of the given program, if it is resolved in the graph.
+    pub fn imports(&self, program_id: &ProgramID<N>) -> Option<&[ProgramID<N>]> {
+        self.edges.get(program_id).map(Vec::as_slice)
+    }
+
+    /// Returns the imports that could not be resolved.
+    pub const fn missing(&self) -> &IndexSet<ProgramID<N>> {
+        &self.missing
+    }
+
+    /// Returns `true` if the given program is resolved in the graph.
+    pub fn contains(&self, program_id: &ProgramID<N>) -> bool {
+        self.edges.contains_key(program_id)
+    }
+
+    /// Returns an import cycle in the graph, if one exists.
+    /// The cycle is returned as a path which starts and ends with the same program ID.
+    pub fn find_cycle(&self) -> Option<Vec<ProgramID<N>>> {
+        self.visit(&self.root, &mut Vec::new(), &mut IndexSet::new())
+    }
+
+    /// Returns the programs in topological order, such that every program appears after its imports.
+    /// This is the order in which the programs must be deployed, and ends with the root.
+    pub fn topological_order(&self) -> Result<Vec<ProgramID<N>>> {
+        // Ensure every import is resolved.
+        if !self.missing.is_empty() {
+            let missing = self.missing.iter().map(|program_id| program_id.to_string()).collect::<Vec<_>>();
+            bail!("'{}' has unresolved imports: {}", self.root, missing.join(", "))
+        }
+        // Order the programs, and ensure there are no import cycles.
+        let mut order = IndexSet::new();
+        if let Some(cycle) = self.visit(&self.root, &mut Vec::new(), &mut order) {
+            let cycle = cycle.iter().map(|program_id| program_id.to_string()).collect::<Vec<_>>();
+            bail!("'{}' has an import cycle: {}", self.root, cycle.join(" -> "))
+        }
+        Ok(order.into_iter().collect())
+    }
+
+    /// Visits the given program in depth-first order, appending each program to `order` after its imports.
+    /// Returns the first import cycle that is found, if one exists.
+    fn visit(
+        &self,
+        program_id: &ProgramID<N>,
+        path: &mut Vec<ProgramID<N>>,
+        order: &mut IndexSet<ProgramID<N>>,
+    ) -> Option<Vec<ProgramID<N>>> {
+        // Return early if the program was already ordered.
+        if order.contains(program_id) {
+            return None;
+        }
+        // If the program is on the current path, return the cycle.
+        if let Some(index) = path.iter().position(|candidate| candidate == program_id) {
+            let mut cycle = path[index..].to_vec();
+            cycle.push(*program_id);
+            return Some(cycle);
+        }
+        // Visit the imports of the program.
+        path.push(*program_id);
+        for import_id in self.imports(program_id).unwrap_or_default() {
+            if let Some(cycle) = self.visit(import_id, path, order) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        // Order the program after its imports.
+        order.insert(*program_id);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use synthesizer::process::Program;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a program with the given name and imports.
+    fn sample_program(name: &str, imports: &[&str]) -> Program<CurrentNetwork> {
+        let imports = imports.iter().map(|import| format!("import {import}.aleo;")).collect::<String>();
+        Program::from_str(&format!(
+            "{imports} program {name}.aleo; function compute: input r0 as u8.private; output r0 as u8.private;"
+        ))
+        .unwrap()
+    }
+
+    /// Returns the given program IDs.
+    fn program_ids(names: &[&str]) -> Vec<ProgramID<CurrentNetwork>> {
+        names.iter().map(|name| ProgramID::from_str(&format!("{name}.aleo")).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_dependency_graph() -> Result<()> {
+        // Construct a diamond, where `d.aleo` imports `b.aleo` and `c.aleo`, which both import `a.aleo`.
+        let a = sample_program("a", &[]);
+        let b = sample_program("b", &["a"]);
+        let c = sample_program("c", &["a"]);
+        let d = sample_program("d", &["b", "c"]);
+        let unrelated = sample_program("unrelated", &[]);
+
+        // Ensure the graph only contains the transitive imports.
+        let graph = d.dependency_graph([&unrelated, &c, &b, &a]);
+        assert_eq!(&program_ids(&["d"])[0], graph.root());
+        assert_eq!(program_ids(&["d", "b", "a", "c"]), graph.programs().copied().collect::<Vec<_>>());
+        assert_eq!(Some(program_ids(&["b", "c"]).as_slice()), graph.imports(d.id()));
+        assert!(!graph.contains(unrelated.id()));
+        assert!(graph.missing().is_empty());
+        assert!(graph.find_cycle().is_none());
+
+        // Ensure every program is ordered after its imports.
+        assert_eq!(program_ids(&["a", "b", "c", "d"]), graph.topological_order()?);
+
+        // Ensure a program without imports is its own graph.
+        assert_eq!(program_ids(&["a"]), a.dependency_graph([]).topological_order()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dependency_graph_missing() {
+        let b = sample_program("b", &["a"]);
+        let c = sample_program("c", &["b", "a"]);
+
+        // Ensure the unresolved import is reported.
+        let graph = c.dependency_graph([&b]);
+        assert_eq!(program_ids(&["a"]), graph.missing().iter().copied().collect::<Vec<_>>());
+        assert!(graph.find_cycle().is_none());
+        assert!(graph.topological_order().is_err());
+    }
+
+    #[test]
+    fn test_dependency_graph_cycle() {
+        let a = sample_program("a", &["c"]);
+        let b = sample_program("b", &["a"]);
+        let c = sample_program("c", &["b"]);
+        let d = sample_program("d", &["a"]);
+
+        // Ensure the cycle is reported as a path.
+        let graph = d.dependency_graph([&a, &b, &c]);
+        assert_eq!(Some(program_ids(&["a", "c", "b", "a"])), graph.find_cycle());
+        let error = graph.topological_order().unwrap_err();
+        assert!(error.to_string().contains("a.aleo -> c.aleo -> b.aleo -> a.aleo"));
+    }
+}
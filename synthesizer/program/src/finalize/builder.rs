@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A builder for a finalize scope, which constructs the finalize scope without parsing it from a string.
+///
+/// The statements are checked in order when the finalize scope is built.
+#[derive(Clone)]
+pub struct FinalizeBuilder<N: Network, Command: CommandTrait<N>> {
+    /// The name of the associated function.
+    name: String,
+    /// The input registers and plaintext types, in order of the input registers.
+    inputs: Vec<(Register<N>, PlaintextType<N>)>,
+    /// The commands, in order of execution.
+    commands: Vec<Command>,
+}
+
+impl<N: Network, Command: CommandTrait<N>> FinalizeBuilder<N, Command> {
+    /// Initializes a new builder for the finalize scope of the function with the given name.
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), inputs: Vec::new(), commands: Vec::new() }
+    }
+
+    /// Adds an input statement of the form `input {register} as {plaintext_type}.public;`.
+    pub fn input(mut self, register: Register<N>, plaintext_type: PlaintextType<N>) -> Self {
+        self.inputs.push((register, plaintext_type));
+        self
+    }
+
+    /// Adds the given command.
+    pub fn command(mut self, command: Command) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Returns the finalize scope.
+    ///
+    /// # Errors
+    /// This method will halt if the name is not a valid identifier.
+    /// This method will halt if any statement is rejected by the finalize scope, as if it were parsed.
+    pub fn build(self) -> Result<FinalizeCore<N, Command>> {
+        // Initialize a new finalize scope.
+        let mut finalize = FinalizeCore::new(Identifier::from_str(&self.name)?);
+        // Add the input statements.
+        for (register, plaintext_type) in self.inputs {
+            finalize.add_input(Input::new(register, plaintext_type))?;
+        }
+        // Add the commands.
+        for command in self.commands {
+            finalize.add_command(command)?;
+        }
+        Ok(finalize)
+    }
+}
@@ -15,6 +15,9 @@
 mod input;
 use input::*;
 
+mod builder;
+pub use builder::FinalizeBuilder;
+
 mod bytes;
 mod parse;
 
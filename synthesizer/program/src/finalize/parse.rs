@@ -59,12 +59,16 @@ impl<N: Network, Command: CommandTrait<N>> FromStr for FinalizeCore<N, Command>
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Ensure the remainder is empty, or report the mistake in the next statement.
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder_with(string, remainder, [Command::parse(remainder).err(),])
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -89,7 +93,7 @@ impl<N: Network, Command: CommandTrait<N>> Display for FinalizeCore<N, Command>
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::network::Testnet3;
+    use console::network::{Devnet, DevnetConfig, Testnet3};
     use synthesizer::process::Finalize;
 
     type CurrentNetwork = Testnet3;
@@ -146,4 +150,24 @@ finalize foo:
         let finalize = Finalize::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(expected, format!("{finalize}"),);
     }
+
+    #[test]
+    fn test_finalize_parse_devnet_limits() {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        struct TwoWrites;
+
+        impl DevnetConfig for TwoWrites {
+            const MAX_WRITES: u16 = 2;
+        }
+
+        let finalize = r"finalize foo:
+    input r0 as field.public;
+    set r0 into counts[0u8];
+    set r0 into counts[1u8];
+    set r0 into counts[2u8];";
+        // Ensure the write limit of the network is enforced.
+        assert!(Finalize::<CurrentNetwork>::from_str(finalize).is_ok());
+        assert!(Finalize::<Devnet<TwoWrites>>::from_str(finalize).is_err());
+        assert!(Finalize::<Devnet<TwoWrites>>::from_str(finalize.rsplit_once('\n').unwrap().0).is_ok());
+    }
 }
@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The options to format a program into its canonical text form.
+///
+/// The canonical form only depends on the program and the options, and not on the whitespace or
+/// comments of the source, so a deployed program is rendered the same as the program it was deployed from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// The number of spaces to indent statements by, or `None` to indent statements by a tab.
+    indent: Option<usize>,
+    /// Whether the opcodes of consecutive statements are padded, so that their operands are aligned.
+    align: bool,
+    /// Whether the doc comments of the declarations are printed, which is enabled by default.
+    docs: bool,
+}
+
+impl Default for FormatOptions {
+    /// Initializes the options to indent statements by four spaces, without alignment.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatOptions {
+    /// Initializes the options to indent statements by four spaces, without alignment.
+    pub const fn new() -> Self {
+        Self { indent: Some(4), align: false, docs: true }
+    }
+
+    /// Indents statements by the given number of spaces.
+    pub const fn with_indent(mut self, spaces: usize) -> Self {
+        self.indent = Some(spaces);
+        self
+    }
+
+    /// Indents statements by a tab.
+    pub const fn with_tabs(mut self) -> Self {
+        self.indent = None;
+        self
+    }
+
+    /// Pads the opcodes of consecutive statements to the same width, i.e. `input  r0 as u64.private;`,
+    /// so that the operands of each block of statements are aligned into a column.
+    pub const fn with_alignment(mut self) -> Self {
+        self.align = true;
+        self
+    }
+
+    /// Omits the doc comments of the declarations.
+    pub const fn without_docs(mut self) -> Self {
+        self.docs = false;
+        self
+    }
+
+    /// Returns the indentation of a statement.
+    fn indentation(&self) -> String {
+        match self.indent {
+            Some(spaces) => " ".repeat(spaces),
+            None => "\t".to_string(),
+        }
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the program in its canonical text form, with the given format options.
+    pub fn format(&self, options: FormatOptions) -> String {
+        let indentation = options.indentation();
+
+        // Initialize a string for the program.
+        let mut program = String::new();
+        // Initialize the block of statements of the current declaration.
+        let mut block: Vec<(&str, Option<&str>)> = Vec::new();
+
+        // Print the program, which is in its canonical form, one line at a time.
+        // Declarations are printed without indentation, and their statements are printed with indentation.
+        let string = self.to_string();
+        for line in string.lines().chain(core::iter::once("")) {
+            // Collect the statements of the current declaration.
+            if line.starts_with(char::is_whitespace) {
+                let statement = line.trim();
+                block.push(match statement.split_once(' ') {
+                    Some((opcode, operands)) => (opcode, Some(operands)),
+                    None => (statement, None),
+                });
+                continue;
+            }
+
+            // Print the block of statements, padding the opcodes to the same width if alignment is enabled.
+            let width = match options.align {
+                true => block.iter().filter(|(_, operands)| operands.is_some()).map(|(opcode, _)| opcode.len()).max(),
+                false => None,
+            }
+            .unwrap_or(0);
+            for (opcode, operands) in block.drain(..) {
+                match operands {
+                    Some(operands) => program.push_str(&format!("{indentation}{opcode:width$} {operands}\n")),
+                    None => program.push_str(&format!("{indentation}{opcode}\n")),
+                }
+            }
+
+            // Print the line, skipping the doc comments if they are disabled.
+            if !options.docs && line.starts_with("///") {
+                continue;
+            }
+            program.push_str(line);
+            program.push('\n');
+        }
+        // Remove the newline of the sentinel line.
+        program.pop();
+        program
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use synthesizer::{process::Program, program::FormatOptions};
+
+    type CurrentNetwork = Testnet3;
+
+    const PROGRAM: &str = r"program format.aleo;
+
+/// The balances of each account.
+mapping account:
+	key left as address.public;
+	value right as u64.public;
+
+function transfer:
+    input r0 as address.public;
+  input r1 as u64.public;
+    finalize self.caller r0 r1;
+
+finalize transfer:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    get.or_use account[r0] 0u64 into r3;
+    sub r3 r2 into r4;
+    set r4 into account[r0];
+";
+
+    #[test]
+    fn test_format_default() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(PROGRAM)?;
+        let expected = r"program format.aleo;
+
+/// The balances of each account.
+mapping account:
+    key left as address.public;
+    value right as u64.public;
+
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize self.caller r0 r1;
+
+finalize transfer:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    get.or_use account[r0] 0u64 into r3;
+    sub r3 r2 into r4;
+    set r4 into account[r0];
+";
+        assert_eq!(expected, program.format(FormatOptions::default()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_with_options() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(PROGRAM)?;
+        let expected = "program format.aleo;
+
+mapping account:
+\tkey   left as address.public;
+\tvalue right as u64.public;
+
+function transfer:
+\tinput    r0 as address.public;
+\tinput    r1 as u64.public;
+\tfinalize self.caller r0 r1;
+
+finalize transfer:
+\tinput      r0 as address.public;
+\tinput      r1 as address.public;
+\tinput      r2 as u64.public;
+\tget.or_use account[r0] 0u64 into r3;
+\tsub        r3 r2 into r4;
+\tset        r4 into account[r0];
+";
+        let formatted = program.format(FormatOptions::new().with_tabs().with_alignment().without_docs());
+        assert_eq!(expected, formatted);
+
+        // Ensure the formatted program parses into the same program.
+        assert_eq!(program, Program::from_str(&formatted)?);
+        // Ensure the indentation is configurable.
+        let formatted = program.format(FormatOptions::new().with_indent(2));
+        assert!(formatted.contains("\n  input r0 as address.public;\n"));
+        assert_eq!(program, Program::from_str(&formatted)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{finalize::FinalizeBuilder, Operand};
+
+/// A builder for a function, which constructs the function without parsing it from a string.
+///
+/// The statements are checked in order when the function is built.
+#[derive(Clone)]
+pub struct FunctionBuilder<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
+    /// The name of the function.
+    name: String,
+    /// The input registers and value types, in order of the input registers.
+    inputs: Vec<(Register<N>, ValueType<N>)>,
+    /// The instructions, in order of execution.
+    instructions: Vec<Instruction>,
+    /// The output operands and value types, in order of the desired output.
+    outputs: Vec<(Operand<N>, ValueType<N>)>,
+    /// The optional finalize command and finalize scope.
+    finalize: Option<(Command::FinalizeCommand, FinalizeBuilder<N, Command>)>,
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FunctionBuilder<N, Instruction, Command> {
+    /// Initializes a new builder for the function with the given name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            inputs: Vec::new(),
+            instructions: Vec::new(),
+            outputs: Vec::new(),
+            finalize: None,
+        }
+    }
+
+    /// Adds an input statement of the form `input {register} as {value_type};`.
+    pub fn input(mut self, register: Register<N>, value_type: ValueType<N>) -> Self {
+        self.inputs.push((register, value_type));
+        self
+    }
+
+    /// Adds the given instruction.
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Adds an output statement of the form `output {operand} as {value_type};`.
+    pub fn output(mut self, operand: Operand<N>, value_type: ValueType<N>) -> Self {
+        self.outputs.push((operand, value_type));
+        self
+    }
+
+    /// Sets the finalize command and the finalize scope.
+    pub fn finalize(mut self, command: Command::FinalizeCommand, finalize: FinalizeBuilder<N, Command>) -> Self {
+        self.finalize = Some((command, finalize));
+        self
+    }
+
+    /// Returns the function.
+    ///
+    /// # Errors
+    /// This method will halt if the name is not a valid identifier.
+    /// This method will halt if any statement is rejected by the function, as if it were parsed.
+    /// This method will halt if the finalize scope fails to build.
+    pub fn build(self) -> Result<FunctionCore<N, Instruction, Command>> {
+        // Initialize a new function.
+        let mut function = FunctionCore::new(Identifier::from_str(&self.name)?);
+        // Add the input statements.
+        for (register, value_type) in self.inputs {
+            function.add_input(Input::new(register, value_type))?;
+        }
+        // Add the instructions.
+        for instruction in self.instructions {
+            function.add_instruction(instruction)?;
+        }
+        // Add the output statements.
+        for (operand, value_type) in self.outputs {
+            function.add_output(Output::new(operand, value_type))?;
+        }
+        // Add the finalize scope, if it exists.
+        if let Some((command, finalize)) = self.finalize {
+            function.add_finalize(command, finalize.build()?)?;
+        }
+        Ok(function)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::PlaintextType};
+    use synthesizer::{
+        process::{Command, FinalizeCommand, Function, Instruction},
+        program::{FinalizeBuilder, FunctionBuilder, Operand},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_function_builder() -> Result<()> {
+        let expected = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    add r0 r0 into r1;",
+        )?;
+
+        let candidate = FunctionBuilder::<CurrentNetwork, Instruction<_>, Command<_>>::new("compute")
+            .input(Register::Locator(0), ValueType::from_str("u64.public")?)
+            .input(Register::Locator(1), ValueType::from_str("u64.private")?)
+            .instruction(Instruction::from_str("add r0 r1 into r2;")?)
+            .output(Operand::Register(Register::Locator(2)), ValueType::from_str("u64.private")?)
+            .finalize(
+                FinalizeCommand::new(vec![Operand::Register(Register::Locator(0))])?,
+                FinalizeBuilder::new("compute")
+                    .input(Register::Locator(0), PlaintextType::from_str("u64")?)
+                    .command(Command::from_str("add r0 r0 into r1;")?),
+            )
+            .build()?;
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_builder_fails() -> Result<()> {
+        type Builder = FunctionBuilder<CurrentNetwork, Instruction<CurrentNetwork>, Command<CurrentNetwork>>;
+
+        // Ensure an invalid name fails.
+        assert!(Builder::new("1compute").build().is_err());
+        // Ensure an input register that is not a locator fails.
+        let builder = Builder::new("compute")
+            .input(Register::Member(0, vec![Identifier::from_str("owner")?]), ValueType::from_str("u64.public")?);
+        assert!(builder.build().is_err());
+        // Ensure a finalize scope with a different name fails.
+        let builder = Builder::new("compute").input(Register::Locator(0), ValueType::from_str("u64.public")?).finalize(
+            FinalizeCommand::new(vec![Operand::Register(Register::Locator(0))])?,
+            FinalizeBuilder::new("other").input(Register::Locator(0), PlaintextType::from_str("u64")?),
+        );
+        assert!(builder.build().is_err());
+        Ok(())
+    }
+}
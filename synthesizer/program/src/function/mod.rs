@@ -18,13 +18,16 @@ use input::*;
 mod output;
 use output::*;
 
+mod builder;
+pub use builder::FunctionBuilder;
+
 mod bytes;
 mod parse;
 
 use crate::finalize::{CommandTrait, FinalizeCommandTrait, FinalizeCore};
 use console::{
     network::prelude::*,
-    program::{Identifier, Register, ValueType},
+    program::{Identifier, ProgramID, Register, ValueType},
 };
 
 use indexmap::IndexSet;
@@ -32,6 +35,9 @@ use indexmap::IndexSet;
 pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     /// Returns the destination registers of the instruction.
     fn destinations(&self) -> Vec<Register<N>>;
+    /// Returns the called program ID and resource, if the instruction is a call instruction.
+    /// The program ID is `None` if the resource is declared in the same program.
+    fn call_target(&self) -> Option<(Option<&ProgramID<N>>, &Identifier<N>)>;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
 }
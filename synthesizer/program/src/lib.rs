@@ -19,6 +19,9 @@
 mod closure;
 pub use closure::*;
 
+mod dependency_graph;
+pub use dependency_graph::*;
+
 pub mod finalize;
 pub use finalize::*;
 
@@ -28,14 +31,26 @@ pub use function::*;
 mod import;
 pub use import::*;
 
+mod lint;
+pub use lint::*;
+
 mod mapping;
 pub use mapping::*;
 
 mod operand;
 pub use operand::*;
 
+mod builder;
+pub use builder::ProgramBuilder;
+
+mod format;
+pub use format::FormatOptions;
+
 mod bytes;
 mod parse;
+mod recover;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 
 use console::{
@@ -51,6 +66,8 @@ use console::{
         many1,
         map,
         map_res,
+        opt,
+        pair,
         tag,
         take,
         Debug,
@@ -65,6 +82,7 @@ use console::{
         IoResult,
         Network,
         Parser,
+        ParserDiagnostic,
         ParserResult,
         Read,
         Result,
@@ -75,8 +93,10 @@ use console::{
         ToBytesSerializer,
         TypeName,
         Write,
+        Zero,
     },
     program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
+    types::Address,
 };
 
 use indexmap::IndexMap;
@@ -95,7 +115,7 @@ enum ProgramDefinition {
     Function,
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Eq)]
 pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
     /// The ID of the program.
     id: ProgramID<N>,
@@ -113,6 +133,30 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// A map of identifiers to the doc comments of their program declaration.
+    docs: IndexMap<Identifier<N>, String>,
+    /// The address of the auditor, which every record output of the program is additionally encrypted to.
+    auditor: Option<Address<N>>,
+}
+
+impl<N: Network, Instruction: InstructionTrait<N> + PartialEq, Command: CommandTrait<N> + PartialEq> PartialEq
+    for ProgramCore<N, Instruction, Command>
+{
+    /// Returns `true` if the programs are equal, ignoring their doc comments.
+    ///
+    /// Doc comments are not serialized into the program bytes, so that a deployed program
+    /// is equal to the program it was deployed from.
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.imports == other.imports
+            && self.identifiers == other.identifiers
+            && self.mappings == other.mappings
+            && self.structs == other.structs
+            && self.records == other.records
+            && self.closures == other.closures
+            && self.functions == other.functions
+            && self.auditor == other.auditor
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -131,6 +175,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            docs: IndexMap::new(),
+            auditor: None,
         })
     }
 
@@ -175,6 +221,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the doc comments of the declarations in the program.
+    pub const fn docs(&self) -> &IndexMap<Identifier<N>, String> {
+        &self.docs
+    }
+
+    /// Returns the auditor of the program, if the program declares one.
+    pub const fn auditor(&self) -> Option<&Address<N>> {
+        self.auditor.as_ref()
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -270,6 +326,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the doc comment of the declaration with the given name, if one exists.
+    pub fn get_docs(&self, name: &Identifier<N>) -> Option<&str> {
+        self.docs.get(name).map(String::as_str)
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -525,6 +586,81 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds the doc comment of a declaration to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the declaration does not exist in the program.
+    /// This method will halt if the declaration already has a doc comment.
+    #[inline]
+    fn add_docs(&mut self, name: Identifier<N>, docs: String) -> Result<()> {
+        // Ensure the declaration exists.
+        ensure!(self.identifiers.contains_key(&name), "'{name}' is not declared in the program.");
+        // Add the doc comment to the program.
+        if self.docs.insert(name, docs).is_some() {
+            bail!("'{name}' is already documented in the program.")
+        }
+        Ok(())
+    }
+
+    /// Sets the auditor of the program, which every record output of the program is additionally encrypted to.
+    ///
+    /// # Errors
+    /// This method will halt if the program already has an auditor.
+    /// This method will halt if the auditor is the zero address.
+    #[inline]
+    fn set_auditor(&mut self, auditor: Address<N>) -> Result<()> {
+        // Ensure the program does not already have an auditor.
+        ensure!(self.auditor.is_none(), "Program '{}' already has an auditor.", self.id);
+        // Ensure the auditor is not the zero address, which anyone could decrypt to.
+        ensure!(!auditor.is_zero(), "The auditor of program '{}' cannot be the zero address.", self.id);
+        // Set the auditor.
+        self.auditor = Some(auditor);
+        Ok(())
+    }
+
+    /// Replaces the closure with the same name in the program, i.e. with an optimized closure.
+    ///
+    /// # Errors
+    /// This method will halt if the closure does not exist in the program.
+    /// This method will halt if the number of instructions exceeds the allowed range.
+    #[inline]
+    pub fn replace_closure(&mut self, closure: ClosureCore<N, Instruction>) -> Result<()> {
+        // Retrieve the closure name.
+        let closure_name = *closure.name();
+
+        // Ensure the closure exists.
+        ensure!(self.closures.contains_key(&closure_name), "Closure '{closure_name}' does not exist in the program.");
+        // Ensure the number of instructions is within the allowed range.
+        ensure!(closure.instructions().len() <= N::MAX_INSTRUCTIONS, "Closure exceeds maximum instructions");
+
+        // Replace the closure, which retains its position in the program.
+        self.closures.insert(closure_name, closure);
+        Ok(())
+    }
+
+    /// Replaces the function with the same name in the program, i.e. with an optimized function.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist in the program.
+    /// This method will halt if the number of instructions exceeds the allowed range.
+    #[inline]
+    pub fn replace_function(&mut self, function: FunctionCore<N, Instruction, Command>) -> Result<()> {
+        // Retrieve the function name.
+        let function_name = *function.name();
+
+        // Ensure the function exists.
+        ensure!(
+            self.functions.contains_key(&function_name),
+            "Function '{function_name}' does not exist in the program."
+        );
+        // Ensure the number of instructions is within the allowed range.
+        ensure!(function.instructions().len() <= N::MAX_INSTRUCTIONS, "Function exceeds maximum instructions");
+
+        // Replace the function, which retains its position in the program.
+        self.functions.insert(function_name, function);
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
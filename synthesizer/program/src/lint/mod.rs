@@ -0,0 +1,340 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ClosureCore, CommandTrait, FunctionCore, InstructionTrait, ProgramCore};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register},
+};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The percentage of a limit at which a metric is reported as near the limit.
+pub const NEAR_LIMIT_PERCENT: usize = 90;
+
+/// The level of a lint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintLevel {
+    /// The metric is near its limit.
+    Warning,
+    /// The metric exceeds its limit.
+    Error,
+}
+
+/// A lint for a metric that is near or exceeds its limit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint<N: Network> {
+    /// The level of the lint.
+    pub level: LintLevel,
+    /// The closure or function the lint applies to, or `None` if it applies to the program.
+    pub resource: Option<Identifier<N>>,
+    /// The name of the metric.
+    pub metric: &'static str,
+    /// The value of the metric.
+    pub value: usize,
+    /// The limit of the metric.
+    pub limit: usize,
+}
+
+impl<N: Network> Display for Lint<N> {
+    /// Prints the lint, i.e. `warning: 'foo' has 9 finalize writes, near the limit of 10`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (level, relation) = match self.level {
+            LintLevel::Warning => ("warning", "near"),
+            LintLevel::Error => ("error", "exceeding"),
+        };
+        match &self.resource {
+            Some(resource) => write!(f, "{level}: '{resource}' has {} {}", self.value, self.metric)?,
+            None => write!(f, "{level}: the program has {} {}", self.value, self.metric)?,
+        }
+        write!(f, ", {relation} the limit of {}", self.limit)
+    }
+}
+
+/// The complexity metrics of a closure or function.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Complexity {
+    /// The number of inputs.
+    pub num_inputs: usize,
+    /// The number of outputs.
+    pub num_outputs: usize,
+    /// The number of instructions.
+    pub num_instructions: usize,
+    /// The number of distinct registers that are assigned, i.e. the register pressure.
+    pub num_registers: usize,
+    /// The number of call instructions.
+    pub num_calls: usize,
+    /// The depth of nested calls, where a call to another program counts as a single level.
+    pub call_depth: usize,
+    /// The number of finalize inputs.
+    pub num_finalize_inputs: usize,
+    /// The number of finalize commands.
+    pub num_commands: usize,
+    /// The number of finalize write commands.
+    pub num_writes: usize,
+}
+
+impl Complexity {
+    /// Returns the complexity of the given inputs and instructions.
+    /// The call depth only accounts for direct calls.
+    fn new<'a, N: Network, Instruction: InstructionTrait<N>>(
+        inputs: impl ExactSizeIterator<Item = &'a Register<N>>,
+        num_outputs: usize,
+        instructions: &[Instruction],
+    ) -> Self {
+        let num_inputs = inputs.len();
+        // Collect the distinct registers, from the inputs and the destinations.
+        let registers = inputs
+            .map(Register::locator)
+            .chain(instructions.iter().flat_map(|instruction| instruction.destinations()).map(|r| r.locator()))
+            .collect::<IndexSet<_>>();
+        // Count the call instructions.
+        let num_calls = instructions.iter().filter(|instruction| instruction.call_target().is_some()).count();
+
+        Self {
+            num_inputs,
+            num_outputs,
+            num_instructions: instructions.len(),
+            num_registers: registers.len(),
+            num_calls,
+            call_depth: usize::from(num_calls > 0),
+            ..Default::default()
+        }
+    }
+}
+
+/// The lint report of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintReport<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The complexity of each closure.
+    closures: IndexMap<Identifier<N>, Complexity>,
+    /// The complexity of each function.
+    functions: IndexMap<Identifier<N>, Complexity>,
+    /// The lints, in the order they were found.
+    lints: Vec<Lint<N>>,
+}
+
+impl<N: Network> LintReport<N> {
+    /// Returns the ID of the program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the complexity of each closure.
+    pub const fn closures(&self) -> &IndexMap<Identifier<N>, Complexity> {
+        &self.closures
+    }
+
+    /// Returns the complexity of each function.
+    pub const fn functions(&self) -> &IndexMap<Identifier<N>, Complexity> {
+        &self.functions
+    }
+
+    /// Returns the lints.
+    pub fn lints(&self) -> &[Lint<N>] {
+        &self.lints
+    }
+
+    /// Returns the lints with the given level.
+    pub fn lints_with_level(&self, level: LintLevel) -> impl '_ + Iterator<Item = &Lint<N>> {
+        self.lints.iter().filter(move |lint| lint.level == level)
+    }
+
+    /// Returns `true` if the report has no lints.
+    pub fn is_clean(&self) -> bool {
+        self.lints.is_empty()
+    }
+
+    /// Checks the given metric against its limit, and records a lint if it is near or exceeds the limit.
+    fn check(&mut self, resource: Option<Identifier<N>>, metric: &'static str, value: usize, limit: usize) {
+        let level = match value {
+            value if value > limit => LintLevel::Error,
+            value if value > 0 && value.saturating_mul(100) >= limit.saturating_mul(NEAR_LIMIT_PERCENT) => {
+                LintLevel::Warning
+            }
+            _ => return,
+        };
+        self.lints.push(Lint { level, resource, metric, value, limit });
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
+    /// Returns the complexity of the closure.
+    /// The call depth only accounts for direct calls; use `Program::lint` for the nested call depth.
+    pub fn complexity(&self) -> Complexity {
+        Complexity::new(self.inputs().iter().map(|input| input.register()), self.outputs().len(), self.instructions())
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FunctionCore<N, Instruction, Command> {
+    /// Returns the complexity of the function.
+    /// The call depth only accounts for direct calls; use `Program::lint` for the nested call depth.
+    pub fn complexity(&self) -> Complexity {
+        let mut complexity = Complexity::new(
+            self.inputs().iter().map(|input| input.register()),
+            self.outputs().len(),
+            self.instructions(),
+        );
+        if let Some(finalize) = self.finalize_logic() {
+            complexity.num_finalize_inputs = finalize.inputs().len();
+            complexity.num_commands = finalize.commands().len();
+            complexity.num_writes = finalize.num_writes() as usize;
+        }
+        complexity
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the lint report of the program, which contains the complexity of each closure and function,
+    /// and a lint for each metric that is near or exceeds its limit on the network.
+    pub fn lint(&self) -> LintReport<N> {
+        // Returns the nested call depth of the given instructions, using the call depths of the prior closures.
+        let call_depth = |instructions: &[Instruction], closures: &IndexMap<Identifier<N>, Complexity>| {
+            instructions
+                .iter()
+                .filter_map(|instruction| instruction.call_target())
+                .map(|(program_id, resource)| match program_id {
+                    None => 1 + closures.get(resource).map_or(0, |complexity| complexity.call_depth),
+                    Some(_) => 1,
+                })
+                .max()
+                .unwrap_or(0)
+        };
+
+        let mut closures = IndexMap::with_capacity(self.closures().len());
+        for (name, closure) in self.closures() {
+            let mut complexity = closure.complexity();
+            complexity.call_depth = call_depth(closure.instructions(), &closures);
+            closures.insert(*name, complexity);
+        }
+
+        let mut functions = IndexMap::with_capacity(self.functions().len());
+        for (name, function) in self.functions() {
+            let mut complexity = function.complexity();
+            complexity.call_depth = call_depth(function.instructions(), &closures);
+            functions.insert(*name, complexity);
+        }
+
+        let mut report = LintReport { program_id: *self.id(), closures, functions, lints: Vec::new() };
+
+        // Check the program limits.
+        report.check(None, "mappings", self.mappings().len(), N::MAX_MAPPINGS);
+        report.check(None, "functions", self.functions().len(), N::MAX_FUNCTIONS);
+
+        // Check the closure and function limits.
+        let resources = report.closures.clone().into_iter().chain(report.functions.clone());
+        for (name, complexity) in resources {
+            report.check(Some(name), "inputs", complexity.num_inputs, N::MAX_INPUTS);
+            report.check(Some(name), "outputs", complexity.num_outputs, N::MAX_OUTPUTS);
+            report.check(Some(name), "instructions", complexity.num_instructions, N::MAX_INSTRUCTIONS);
+            report.check(Some(name), "finalize inputs", complexity.num_finalize_inputs, N::MAX_INPUTS);
+            report.check(Some(name), "finalize commands", complexity.num_commands, N::MAX_COMMANDS);
+            report.check(Some(name), "finalize writes", complexity.num_writes, N::MAX_WRITES as usize);
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use synthesizer::{
+        process::Program,
+        program::{Lint, LintLevel},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_lint() -> Result<()> {
+        // Initialize a program with nested closure calls, and 9 of 10 writes in finalize.
+        let sets = (0..9).map(|i| format!("set r0 into counts[{i}u8];")).collect::<String>();
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program lint.aleo;
+
+mapping counts:
+    key owner as u8.public;
+    value count as u64.public;
+
+closure inner:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+closure outer:
+    input r0 as u64;
+    call inner r0 into r1;
+    add r0 r1 into r2;
+    output r2 as u64;
+
+function compute:
+    input r0 as u64.public;
+    call outer r0 into r1;
+    call inner r1 into r2;
+    output r2 as u64.public;
+    finalize r2;
+
+finalize compute:
+    input r0 as u64.public;
+    {sets}"
+        ))?;
+
+        // Check the complexity.
+        let report = program.lint();
+        assert_eq!(program.id(), report.program_id());
+
+        let inner = &report.closures()[&Identifier::from_str("inner")?];
+        assert_eq!(
+            (1, 1, 1, 2, 0, 0),
+            (
+                inner.num_inputs,
+                inner.num_outputs,
+                inner.num_instructions,
+                inner.num_registers,
+                inner.num_calls,
+                inner.call_depth
+            )
+        );
+        assert_eq!(1, report.closures()[&Identifier::from_str("outer")?].call_depth);
+
+        let compute = &report.functions()[&Identifier::from_str("compute")?];
+        assert_eq!((3, 2, 2), (compute.num_registers, compute.num_calls, compute.call_depth));
+        assert_eq!((1, 9, 9), (compute.num_finalize_inputs, compute.num_commands, compute.num_writes));
+        // Ensure the direct complexity only accounts for direct calls.
+        assert_eq!(1, program.get_function(&Identifier::from_str("compute")?)?.complexity().call_depth);
+
+        // Ensure the writes are reported as near the limit.
+        assert_eq!(
+            vec![Lint {
+                level: LintLevel::Warning,
+                resource: Some(Identifier::from_str("compute")?),
+                metric: "finalize writes",
+                value: 9,
+                limit: 10,
+            }],
+            report.lints()
+        );
+        assert_eq!("warning: 'compute' has 9 finalize writes, near the limit of 10", report.lints()[0].to_string());
+        assert_eq!(0, report.lints_with_level(LintLevel::Error).count());
+
+        // Ensure the credits program does not exceed any limits.
+        assert!(Program::<CurrentNetwork>::credits()?.lint().lints_with_level(LintLevel::Error).next().is_none());
+        Ok(())
+    }
+}
@@ -30,6 +30,12 @@ pub struct MapKey<N: Network> {
 }
 
 impl<N: Network> MapKey<N> {
+    /// Initializes a new key statement with the given name and plaintext type.
+    #[inline]
+    pub const fn new(name: Identifier<N>, plaintext_type: PlaintextType<N>) -> Self {
+        Self { name, plaintext_type }
+    }
+
     /// Returns the key name.
     #[inline]
     pub const fn name(&self) -> &Identifier<N> {
@@ -13,10 +13,10 @@
 // limitations under the License.
 
 mod key;
-use key::*;
+pub use key::MapKey;
 
 mod value;
-use value::*;
+pub use value::MapValue;
 
 mod bytes;
 mod parse;
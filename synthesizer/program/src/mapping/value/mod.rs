@@ -30,6 +30,12 @@ pub struct MapValue<N: Network> {
 }
 
 impl<N: Network> MapValue<N> {
+    /// Initializes a new value statement with the given name and plaintext type.
+    #[inline]
+    pub const fn new(name: Identifier<N>, plaintext_type: PlaintextType<N>) -> Self {
+        Self { name, plaintext_type }
+    }
+
     /// Returns the value name.
     #[inline]
     pub const fn name(&self) -> &Identifier<N> {
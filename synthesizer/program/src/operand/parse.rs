@@ -19,16 +19,19 @@ impl<N: Network> Parser for Operand<N> {
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the operand (order matters).
-        alt((
-            // Parse special operands before literals, registers, and program IDs.
-            // This ensures correctness in the case where a special operand is a prefix of, or could be parsed as, a literal, register, or program ID.
-            map(tag("group::GEN"), |_| Self::Literal(Literal::Group(Group::generator()))),
-            map(tag("self.caller"), |_| Self::Caller),
-            map(tag("block.height"), |_| Self::BlockHeight),
-            map(Literal::parse, |literal| Self::Literal(literal)),
-            map(Register::parse, |register| Self::Register(register)),
-            map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
-        ))(string)
+        context(
+            "operand",
+            alt((
+                // Parse special operands before literals, registers, and program IDs.
+                // This ensures correctness in the case where a special operand is a prefix of, or could be parsed as, a literal, register, or program ID.
+                map(tag("group::GEN"), |_| Self::Literal(Literal::Group(Group::generator()))),
+                map(tag("self.caller"), |_| Self::Caller),
+                map(tag("block.height"), |_| Self::BlockHeight),
+                map(Literal::parse, |literal| Self::Literal(literal)),
+                map(Register::parse, |register| Self::Register(register)),
+                map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
+            )),
+        )(string)
     }
 }
 
@@ -41,11 +44,15 @@ impl<N: Network> FromStr for Operand<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -14,21 +14,21 @@
 
 use super::*;
 
+/// A declaration of a program.
+pub(crate) enum Declaration<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
+    Mapping(Mapping<N>),
+    Struct(Struct<N>),
+    Record(RecordType<N>),
+    Closure(ClosureCore<N, Instruction>),
+    Function(FunctionCore<N, Instruction, Command>),
+}
+
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Parser
     for ProgramCore<N, Instruction, Command>
 {
     /// Parses a string into a program.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        // A helper to parse a program.
-        enum P<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
-            M(Mapping<N>),
-            I(Struct<N>),
-            R(RecordType<N>),
-            C(ClosureCore<N, Instruction>),
-            F(FunctionCore<N, Instruction, Command>),
-        }
-
         // Parse the imports from the string.
         let (string, imports) = many0(Import::parse)(string)?;
         // Parse the whitespace and comments from the string.
@@ -43,15 +43,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
+        // Parse the auditor statement from the string, if the program declares one.
+        let (string, auditor) = opt(Self::parse_auditor)(string)?;
 
-        // Parse the struct or function from the string.
-        let (string, components) = many1(alt((
-            map(Mapping::parse, |mapping| P::<N, Instruction, Command>::M(mapping)),
-            map(Struct::parse, |struct_| P::<N, Instruction, Command>::I(struct_)),
-            map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
-            map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
-            map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
-        )))(string)?;
+        // Parse the struct or function from the string, along with its doc comments.
+        let (string, declarations) = many1(Self::parse_declaration)(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
 
@@ -65,17 +61,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     return Err(error);
                 }
             };
-            // Construct the program with the parsed components.
-            for component in components.iter() {
-                let result = match component {
-                    P::M(mapping) => program.add_mapping(mapping.clone()),
-                    P::I(struct_) => program.add_struct(struct_.clone()),
-                    P::R(record) => program.add_record(record.clone()),
-                    P::C(closure) => program.add_closure(closure.clone()),
-                    P::F(function) => program.add_function(function.clone()),
-                };
-
-                match result {
+            // Set the auditor of the program, if it declares one.
+            if let Some(auditor) = auditor {
+                if let Err(error) = program.set_auditor(auditor) {
+                    eprintln!("{error}");
+                    return Err(error);
+                }
+            }
+            // Construct the program with the parsed declarations.
+            for (docs, declaration) in declarations.iter() {
+                match program.add_declaration(docs, declaration) {
                     Ok(_) => (),
                     Err(error) => {
                         eprintln!("{error}");
@@ -99,6 +94,74 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
     }
 }
 
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Parses an auditor statement from the string, i.e. `auditor aleo1...;`.
+    pub(crate) fn parse_auditor(string: &str) -> ParserResult<Address<N>> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'auditor' keyword from the string.
+        let (string, _) = tag("auditor")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the auditor address from the string.
+        let (string, auditor) = Address::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon ';' keyword from the string.
+        let (string, _) = tag(";")(string)?;
+        Ok((string, auditor))
+    }
+
+    /// Parses a mapping, struct, record, closure, or function from the string, along with its doc comments.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn parse_declaration(string: &str) -> ParserResult<(Vec<&str>, Declaration<N, Instruction, Command>)> {
+        pair(
+            Sanitizer::parse_doc_comments,
+            alt((
+                map(Mapping::parse, Declaration::Mapping),
+                map(Struct::parse, Declaration::Struct),
+                map(RecordType::parse, Declaration::Record),
+                map(ClosureCore::parse, Declaration::Closure),
+                map(FunctionCore::parse, Declaration::Function),
+            )),
+        )(string)
+    }
+
+    /// Adds the given declaration to the program, along with its doc comments.
+    pub(crate) fn add_declaration(
+        &mut self,
+        docs: &[&str],
+        declaration: &Declaration<N, Instruction, Command>,
+    ) -> Result<()> {
+        let name = match declaration {
+            Declaration::Mapping(mapping) => self.add_mapping(mapping.clone()).map(|_| *mapping.name())?,
+            Declaration::Struct(struct_) => self.add_struct(struct_.clone()).map(|_| *struct_.name())?,
+            Declaration::Record(record) => self.add_record(record.clone()).map(|_| *record.name())?,
+            Declaration::Closure(closure) => self.add_closure(closure.clone()).map(|_| *closure.name())?,
+            Declaration::Function(function) => self.add_function(function.clone()).map(|_| *function.name())?,
+        };
+        // Add the doc comments of the declaration, if any.
+        match docs.is_empty() {
+            true => Ok(()),
+            false => self.add_docs(name, docs.join("\n")),
+        }
+    }
+
+    /// Returns the diagnostic for the given remainder of the string, which did not parse,
+    /// by reporting the mistake in the next declaration or statement.
+    pub(crate) fn diagnose_remainder(string: &str, remainder: &str) -> ParserDiagnostic {
+        ParserDiagnostic::remainder_with(string, remainder, [
+            Mapping::<N>::parse(remainder).err(),
+            Struct::<N>::parse(remainder).err(),
+            RecordType::<N>::parse(remainder).err(),
+            ClosureCore::<N, Instruction>::parse(remainder).err(),
+            FunctionCore::<N, Instruction, Command>::parse(remainder).err(),
+            Instruction::parse(remainder).err(),
+            Command::parse(remainder).err(),
+        ])
+    }
+}
+
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FromStr
     for ProgramCore<N, Instruction, Command>
 {
@@ -108,12 +171,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Remaining invalid string is: \"{remainder}\"");
+                // Ensure the remainder is empty, or report the mistake in the next declaration or statement.
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    Self::diagnose_remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -149,7 +216,21 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
         // Print the program name.
         program += &format!("{} {};\n\n", Self::type_name(), self.id);
 
+        // Print the auditor, if the program declares one.
+        if let Some(auditor) = &self.auditor {
+            program += &format!("auditor {auditor};\n\n");
+        }
+
         for (identifier, definition) in self.identifiers.iter() {
+            // Print the doc comments of the definition, if any.
+            if let Some(docs) = self.docs.get(identifier) {
+                for line in docs.split('\n') {
+                    match line.is_empty() {
+                        true => program.push_str("///\n"),
+                        false => program.push_str(&format!("/// {line}\n")),
+                    }
+                }
+            }
             match definition {
                 ProgramDefinition::Mapping => match self.mappings.get(identifier) {
                     Some(mapping) => program.push_str(&format!("{mapping}\n\n")),
@@ -255,4 +336,92 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_auditor() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+auditor aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        // Ensure the auditor is parsed.
+        assert_eq!(
+            program.auditor(),
+            Some(&Address::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?)
+        );
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure the program fails to parse with the zero address as the auditor.
+        let zero = Address::<CurrentNetwork>::new(Zero::zero());
+        assert!(Program::<CurrentNetwork>::from_str(
+            &expected.replace("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah", &zero.to_string())
+        )
+        .is_err());
+        // Ensure the program fails to parse with two auditors.
+        let auditor = "auditor aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah;\n\n";
+        assert!(Program::<CurrentNetwork>::from_str(&expected.replace(auditor, &auditor.repeat(2))).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_parse_diagnostic() {
+        // Parse a program with a missing destination register.
+        let error = Program::<CurrentNetwork>::from_str(
+            r"program to_parse.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 1u32 into;
+    output r1 as u32.private;",
+        )
+        .unwrap_err();
+        // Ensure the error reports the line and column of the mistake.
+        assert_eq!(error.to_string(), "Failed to parse string. expected register, found ';' at line 5:21");
+    }
+
+    #[test]
+    fn test_program_doc_comments() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+/// A message with two fields.
+struct message:
+    first as field;
+    second as field;
+
+/// Computes the sum of the message fields.
+///
+/// The fields are added without overflow checks.
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into r1;
+    output r1 as field.private;
+";
+        // Parse a new program, with regular comments in between the doc comments.
+        let program = Program::<CurrentNetwork>::from_str(&expected.replace("///\n", "///\n// A regular comment.\n"))?;
+
+        // Ensure the doc comments are preserved.
+        assert_eq!(program.get_docs(&Identifier::from_str("message")?), Some("A message with two fields."));
+        assert_eq!(
+            program.get_docs(&Identifier::from_str("compute")?),
+            Some("Computes the sum of the message fields.\n\nThe fields are added without overflow checks.")
+        );
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+        // Ensure the doc comments do not affect the program bytes.
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The keywords that start a declaration, which the parser resumes from after an invalid declaration.
+const DECLARATION_KEYWORDS: [&str; 5] = ["mapping", "struct", "record", "closure", "function"];
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Parses a string into a program, recovering from invalid declarations, for editors and other tooling
+    /// that need feedback on a program while it is being written.
+    ///
+    /// Returns the program with every valid declaration, along with a diagnostic for each mistake.
+    /// After an invalid declaration, the parser resumes from the next line that starts a declaration.
+    /// The program is `None` if the program ID does not parse, as the declarations cannot be added.
+    pub fn parse_partial(string: &str) -> (Option<Self>, Vec<ParserDiagnostic>) {
+        let mut diagnostics = Vec::new();
+
+        // Parse the imports from the string, along with their positions.
+        let mut remaining = string;
+        let mut imports = Vec::new();
+        while let Ok((next, import)) = Import::<N>::parse(remaining) {
+            imports.push((Self::skip_comments(remaining), import));
+            remaining = next;
+        }
+
+        // Parse the program ID from the string.
+        let (id_string, id) = match Self::parse_header(remaining) {
+            Ok((next, header)) => {
+                remaining = next;
+                header
+            }
+            Err(error) => return (None, vec![ParserDiagnostic::new(string, &error)]),
+        };
+        // Initialize a new program.
+        let mut program = match Self::new(id) {
+            Ok(program) => program,
+            Err(error) => return (None, vec![ParserDiagnostic::error(string, id_string, error)]),
+        };
+
+        // Parse the declarations from the string.
+        loop {
+            // Parse the whitespace and comments from the string, and stop at the end of the string.
+            let start = Self::skip_comments(remaining);
+            if start.is_empty() {
+                break;
+            }
+            match Self::parse_declaration(remaining) {
+                // Add the declaration to the program.
+                Ok((next, (docs, declaration))) => {
+                    if let Err(error) = program.add_declaration(&docs, &declaration) {
+                        diagnostics.push(ParserDiagnostic::error(string, start, error));
+                    }
+                    remaining = next;
+                }
+                // Report the mistake, and resume from the next declaration.
+                Err(_) => {
+                    diagnostics.push(Self::diagnose_remainder(string, start));
+                    remaining = Self::skip_declaration(start);
+                }
+            }
+        }
+
+        // Lastly, add the imports (if any) to the program.
+        for (start, import) in imports {
+            if let Err(error) = program.add_import(import) {
+                diagnostics.push(ParserDiagnostic::error(string, start, error));
+            }
+        }
+        (Some(program), diagnostics)
+    }
+
+    /// Parses the program ID from the string, along with the string from the start of the program ID.
+    fn parse_header(string: &str) -> ParserResult<(&str, ProgramID<N>)> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'program' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (id_string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the program ID from the string.
+        let (string, id) = ProgramID::parse(id_string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon ';' keyword from the string.
+        let (string, _) = tag(";")(string)?;
+        Ok((string, (id_string, id)))
+    }
+
+    /// Returns the given string without its leading whitespace and comments.
+    fn skip_comments(string: &str) -> &str {
+        Sanitizer::parse(string).map(|(string, _)| string).unwrap_or(string)
+    }
+
+    /// Returns the given string from the next line that starts a declaration, or an empty string if there is none.
+    fn skip_declaration(string: &str) -> &str {
+        let mut remaining = string;
+        while let Some(index) = remaining.find('\n') {
+            remaining = &remaining[index + 1..];
+            // Check if the line starts with a declaration keyword, followed by whitespace.
+            let line = remaining.trim_start_matches([' ', '\t']);
+            if DECLARATION_KEYWORDS.iter().any(
+                |keyword| matches!(line.strip_prefix(keyword), Some(rest) if rest.starts_with(char::is_whitespace)),
+            ) {
+                return remaining;
+            }
+        }
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use synthesizer::process::Program;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse_partial() -> Result<()> {
+        let (program, diagnostics) = Program::<CurrentNetwork>::parse_partial(
+            r"program partial.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function compute:
+    input r0 as message.private;
+    add r0.first r0.second into;
+    output r1 as field.private;
+
+finalize compute:
+    input r0 as field.public;
+
+struct message:
+    first as field;
+
+function product:
+    input r0 as field.private;
+    square r0 into r1;
+    output r1 as field.private;",
+        );
+
+        // Ensure the valid declarations are in the program.
+        let program = program.unwrap();
+        assert!(program.contains_struct(&Identifier::from_str("message")?));
+        assert!(program.contains_function(&Identifier::from_str("product")?));
+
+        // Ensure each mistake is reported.
+        let diagnostics = diagnostics.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            "expected register, found ';' at line 9:32",
+            "'message' is already in use at line 15:1"
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_partial_invalid_program_id() {
+        let (program, diagnostics) = Program::<CurrentNetwork>::parse_partial("program partial;");
+        assert!(program.is_none());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line(), 1);
+    }
+
+    #[test]
+    fn test_parse_partial_valid_program() -> Result<()> {
+        let string = r"import credits.aleo;
+
+program partial.aleo;
+
+function compute:
+    input r0 as u64.private;
+    output r0 as u64.private;";
+
+        // Ensure the program is parsed without diagnostics.
+        let (program, diagnostics) = Program::<CurrentNetwork>::parse_partial(string);
+        assert!(diagnostics.is_empty());
+        assert_eq!(program, Some(Program::from_str(string)?));
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::{FunctionBuilder, MapKey, MapValue, Mapping, Operand};
+use console::{
+    network::prelude::{CryptoRng, OrHalt, Rng},
+    program::{LiteralType, PublicOrPrivate, Register, ValueType},
+};
+
+/// The maximum number of mappings, structs, records, and functions of a randomly-sampled program.
+const MAX_SAMPLE_DECLARATIONS: usize = 3;
+/// The maximum number of struct members, record entries, and function inputs of a randomly-sampled program.
+const MAX_SAMPLE_MEMBERS: usize = 4;
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns a randomly-sampled program, which declares mappings, structs, records, and at least one function.
+    ///
+    /// The types in the program are sampled literal types, or previously-declared structs and records.
+    /// As the instruction set is not known to this crate, each function outputs its inputs as they are.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample the program name, which must be lowercase.
+        let program_name = loop {
+            let name = Identifier::<N>::sample(rng).to_string().to_lowercase();
+            if !Self::KEYWORDS.contains(&name.as_str()) {
+                break name;
+            }
+        };
+        let mut builder = ProgramBuilder::new(&format!("{program_name}.aleo"));
+
+        // Initialize the names of the declarations, which must be unique in the program.
+        let mut names = vec![];
+
+        // Sample the mappings.
+        for _ in 0..rng.gen_range(0..=MAX_SAMPLE_DECLARATIONS) {
+            let name = Self::sample_name(&mut names, rng);
+            let key = MapKey::new(Self::sample_name(&mut vec![], rng), LiteralType::sample(rng).into());
+            let value = MapValue::new(Self::sample_name(&mut vec![], rng), LiteralType::sample(rng).into());
+            builder = builder.mapping(Mapping::new(name, key, value));
+        }
+
+        // Sample the structs, whose members may be previously-declared structs.
+        let mut structs = vec![];
+        for _ in 0..rng.gen_range(0..=MAX_SAMPLE_DECLARATIONS) {
+            let name = Self::sample_name(&mut names, rng);
+            let mut member_names = vec![];
+            let members = (0..rng.gen_range(1..=MAX_SAMPLE_MEMBERS))
+                .map(|_| (Self::sample_name(&mut member_names, rng), Self::sample_plaintext_type(&structs, rng)))
+                .collect();
+            builder = builder.struct_(Struct::new(name, members).or_halt_with::<N>("Failed to sample a struct"));
+            structs.push(name);
+        }
+
+        // Sample the records, whose entries may be structs.
+        let mut records = vec![];
+        for _ in 0..rng.gen_range(0..=MAX_SAMPLE_DECLARATIONS) {
+            let name = Self::sample_name(&mut names, rng);
+            let owner = match rng.gen() {
+                true => PublicOrPrivate::Public,
+                false => PublicOrPrivate::Private,
+            };
+            let mut entry_names = vec![];
+            let entries = (0..rng.gen_range(0..=MAX_SAMPLE_MEMBERS))
+                .map(|_| {
+                    let plaintext_type = Self::sample_plaintext_type(&structs, rng);
+                    let entry_type = match rng.gen_range(0..3) {
+                        0 => EntryType::Constant(plaintext_type),
+                        1 => EntryType::Public(plaintext_type),
+                        _ => EntryType::Private(plaintext_type),
+                    };
+                    (Self::sample_name(&mut entry_names, rng), entry_type)
+                })
+                .collect();
+            let record = RecordType::new(name, owner, entries).or_halt_with::<N>("Failed to sample a record");
+            builder = builder.record(record);
+            records.push(name);
+        }
+
+        // Sample the functions, whose inputs may be structs or records.
+        for _ in 0..rng.gen_range(1..=MAX_SAMPLE_DECLARATIONS) {
+            let mut function = FunctionBuilder::new(&Self::sample_name(&mut names, rng).to_string());
+            let value_types = (0..rng.gen_range(1..=MAX_SAMPLE_MEMBERS))
+                .map(|_| match rng.gen_range(0..4) {
+                    0 if !records.is_empty() => ValueType::Record(records[rng.gen_range(0..records.len())]),
+                    0 | 1 => ValueType::Constant(Self::sample_plaintext_type(&structs, rng)),
+                    2 => ValueType::Public(Self::sample_plaintext_type(&structs, rng)),
+                    _ => ValueType::Private(Self::sample_plaintext_type(&structs, rng)),
+                })
+                .collect::<Vec<_>>();
+            // Add the inputs, and output each input as it is.
+            for (locator, value_type) in (0..).zip(value_types.iter()) {
+                function = function.input(Register::Locator(locator), *value_type);
+            }
+            for (locator, value_type) in (0..).zip(value_types) {
+                function = function.output(Operand::Register(Register::Locator(locator)), value_type);
+            }
+            builder = builder.function(function);
+        }
+
+        builder.build().or_halt_with::<N>("Failed to sample a program")
+    }
+
+    /// Returns a randomly-sampled name, which is not a reserved keyword or opcode, and is not in the given names.
+    ///
+    /// The name does not start with a literal type, i.e. `u8x`, as such a struct name is parsed as the literal type.
+    fn sample_name<R: Rng + CryptoRng>(names: &mut Vec<Identifier<N>>, rng: &mut R) -> Identifier<N> {
+        loop {
+            let name = Identifier::sample(rng);
+            if !Self::is_reserved_keyword(&name)
+                && !Self::is_reserved_opcode(&name.to_string())
+                && LiteralType::parse(&name.to_string()).is_err()
+                && !names.contains(&name)
+            {
+                names.push(name);
+                return name;
+            }
+        }
+    }
+
+    /// Returns a randomly-sampled plaintext type, which is a literal type or one of the given structs.
+    fn sample_plaintext_type<R: Rng + CryptoRng>(structs: &[Identifier<N>], rng: &mut R) -> PlaintextType<N> {
+        match structs.is_empty() || rng.gen() {
+            true => PlaintextType::Literal(LiteralType::sample(rng)),
+            false => PlaintextType::Struct(structs[rng.gen_range(0..structs.len())]),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> arbitrary::Arbitrary<'a>
+    for ProgramCore<N, Instruction, Command>
+{
+    /// Returns a program, which is sampled from an RNG that is seeded by the unstructured bytes.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+        Ok(Self::sample(&mut ChaChaRng::from_seed(u.arbitrary()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::{prelude::TestRng, Testnet3};
+    use synthesizer::process::Program;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random program.
+            let expected = Program::<CurrentNetwork>::sample(&mut rng);
+
+            // Ensure the program round trips through its string and byte representations, which re-checks the program.
+            assert_eq!(expected, Program::from_str(&expected.to_string())?);
+            assert_eq!(expected, Program::read_le(&expected.to_bytes_le()?[..])?);
+        }
+        Ok(())
+    }
+}
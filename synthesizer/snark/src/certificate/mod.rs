@@ -18,6 +18,10 @@ mod bytes;
 mod parse;
 mod serialize;
 
+/// A certificate to verify, with its assignment and verifying key.
+pub type CertificateBatchEntry<'a, N> =
+    (&'a circuit::Assignment<<N as Environment>::Field>, &'a VerifyingKey<N>, &'a Certificate<N>);
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct Certificate<N: Network> {
     /// The certificate.
@@ -86,6 +90,47 @@ impl<N: Network> Certificate<N> {
     }
 }
 
+impl<N: Network> Certificate<N> {
+    /// Returns `true` if all of the certificates are valid for their assignments and verifying keys.
+    /// The certificates are checked together, with a single pairing check for the whole batch.
+    pub fn verify_batch<R: Rng + CryptoRng>(
+        locator: &str,
+        inputs: &[CertificateBatchEntry<N>],
+        rng: &mut R,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the inputs.
+        let circuits = inputs
+            .iter()
+            .map(|&(assignment, verifying_key, certificate)| (assignment, verifying_key.deref(), certificate.deref()))
+            .collect::<Vec<_>>();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::marlin_universal_verifier();
+        let fiat_shamir = N::marlin_fs_parameters();
+
+        // Verify the certificates.
+        match Marlin::<N>::verify_vk_batch(universal_verifier, fiat_shamir, &circuits, rng) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                {
+                    let elapsed = timer.elapsed().as_millis();
+                    println!("{}", format!(" • Verified certificates for '{locator}': {elapsed} ms").dimmed());
+                }
+
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Certificate verification failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+}
+
 impl<N: Network> Deref for Certificate<N> {
     type Target = marlin::Certificate<N::PairingCurve>;
 
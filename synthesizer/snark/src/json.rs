@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The canonical JSON encoding for SNARK objects, intended for consumption outside of snarkVM.
+//!
+//! Each object is encoded as
+//! `{ "version": 1, "type": "<type>", "network": <network ID>, "curve": "bls12_377", "data": "<hex>" }`,
+//! with the keys in this exact order. `data` is the lowercase hex encoding of the object's `to_bytes_le`
+//! representation, which is defined as follows:
+//! - Integers (lengths, batch sizes, etc.) are little-endian.
+//! - Field elements are in canonical (non-Montgomery) form, as 64-bit limbs ordered from least significant
+//!   to most significant, where each limb is little-endian. For BLS12-377, a scalar field element is 32 bytes,
+//!   and a base field element is 48 bytes.
+//! - Affine curve points are compressed to their `x`-coordinate, encoded as a base field element.
+//!   Bit 7 of the last byte is set if `y` is the lexicographically largest of `y` and `-y`,
+//!   and bit 6 of the last byte is set if the point is at infinity (in which case `x` is zero).
+
+use super::*;
+
+/// The version of the canonical JSON encoding.
+pub(crate) const JSON_VERSION: u8 = 1;
+/// The name of the pairing curve underlying every supported network.
+pub(crate) const JSON_CURVE: &str = "bls12_377";
+
+/// Returns the canonical JSON encoding of the given object.
+pub(crate) fn to_json<N: Network, T: ToBytes>(object_type: &str, object: &T) -> Result<serde_json::Value> {
+    // Encode the object bytes as lowercase hex.
+    let data = object.to_bytes_le()?.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+    // Construct the JSON object.
+    Ok(serde_json::json!({
+        "version": JSON_VERSION,
+        "type": object_type,
+        "network": N::ID,
+        "curve": JSON_CURVE,
+        "data": data,
+    }))
+}
+
+/// Returns the object from the given canonical JSON encoding.
+pub(crate) fn from_json<N: Network, T: FromBytes>(object_type: &str, json: &serde_json::Value) -> Result<T> {
+    // Ensure the JSON object has exactly the expected keys.
+    let object = json.as_object().ok_or_else(|| anyhow!("Expected a JSON object for the {object_type}"))?;
+    ensure!(object.len() == 5, "Expected 5 keys in the JSON {object_type}, found {}", object.len());
+
+    // Ensure the header matches.
+    ensure!(object.get("version") == Some(&JSON_VERSION.into()), "Invalid JSON version for the {object_type}");
+    ensure!(object.get("type") == Some(&object_type.into()), "Invalid JSON type for the {object_type}");
+    ensure!(object.get("network") == Some(&N::ID.into()), "Invalid JSON network for the {object_type}");
+    ensure!(object.get("curve") == Some(&JSON_CURVE.into()), "Invalid JSON curve for the {object_type}");
+
+    // Decode the object bytes from lowercase hex.
+    let data = object
+        .get("data")
+        .and_then(|data| data.as_str())
+        .ok_or_else(|| anyhow!("Missing JSON data for the {object_type}"))?;
+    ensure!(data.len() % 2 == 0, "Invalid hex length for the {object_type}");
+    ensure!(
+        data.bytes().all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(&c)),
+        "Invalid lowercase hex for the {object_type}"
+    );
+    let bytes = (0..data.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&data[i..i + 2], 16).map_err(|e| anyhow!("{e}")))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Deserialize the object, and ensure there are no trailing bytes.
+    let mut reader = &bytes[..];
+    let output = T::read_le(&mut reader)?;
+    ensure!(reader.is_empty(), "Found trailing bytes in the JSON {object_type}");
+    Ok(output)
+}
@@ -17,7 +17,11 @@
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(not(feature = "aleo-cli"), allow(unused_variables))]
 
-use console::network::{prelude::*, FiatShamir};
+use console::{
+    network::{prelude::*, FiatShamir},
+    types::Field,
+};
+pub use snarkvm_algorithms::traits::{ProvingJob, ProvingProgress};
 use snarkvm_algorithms::{snark::marlin, traits::SNARK};
 
 use once_cell::sync::OnceCell;
@@ -27,9 +31,16 @@ use std::sync::Arc;
 use colored::Colorize;
 
 type Marlin<N> = marlin::MarlinSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, marlin::MarlinHidingMode>;
+type MarlinNonHiding<N> =
+    marlin::MarlinSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, marlin::MarlinNonHidingMode>;
 
 mod certificate;
-pub use certificate::Certificate;
+pub use certificate::{Certificate, CertificateBatchEntry};
+
+mod json;
+
+mod prepared_verifying_key;
+pub use prepared_verifying_key::PreparedVerifyingKey;
 
 mod proof;
 pub use proof::Proof;
@@ -37,6 +48,9 @@ pub use proof::Proof;
 mod proving_key;
 pub use proving_key::ProvingKey;
 
+mod registry;
+pub use registry::VerifyingKeyRegistry;
+
 mod universal_srs;
 pub use universal_srs::UniversalSRS;
 
@@ -135,7 +149,7 @@ pub(crate) mod test_helpers {
 mod test {
     use super::*;
     use circuit::prelude::{Circuit, Environment, NumOne};
-    use console::network::Testnet3;
+    use console::network::{Devnet, DevnetConfig, Testnet3};
 
     type CurrentNetwork = Testnet3;
 
@@ -157,4 +171,89 @@ mod test {
         println!("\nShould not verify (i.e. verifier messages should print below):");
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
+
+    #[test]
+    fn test_marlin_insecure_universal_srs() {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        struct LocalSrs;
+
+        impl DevnetConfig for LocalSrs {
+            const INSECURE_SRS_DEGREE: Option<usize> = Some(1 << 10);
+        }
+
+        type LocalNetwork = Devnet<LocalSrs>;
+
+        let assignment = crate::test_helpers::sample_assignment();
+
+        // Marlin setup, prove, and verify, without downloading the universal SRS.
+        let srs = UniversalSRS::<LocalNetwork>::load().unwrap();
+        let (proving_key, verifying_key) = srs.to_circuit_key("test", &assignment).unwrap();
+        let proof = proving_key.prove("test", &assignment, &mut TestRng::default()).unwrap();
+
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+        assert!(!verifying_key.verify("test", &[one, one + one], &proof));
+    }
+
+    #[test]
+    fn test_marlin_non_hiding() {
+        let rng = &mut TestRng::default();
+
+        let assignment = crate::test_helpers::sample_assignment();
+        let (proving_key, verifying_key) = crate::test_helpers::sample_keys();
+
+        // Prove without hiding.
+        let proof = ProvingKey::prove_batch_non_hiding("test", &[(proving_key, vec![assignment])], rng).unwrap();
+        assert!(!proof.pc_proof.is_hiding());
+
+        // Ensure the proof is only accepted by the non-hiding verifier.
+        let one = <Circuit as Environment>::BaseField::one();
+        let inputs = vec![(verifying_key.clone(), vec![vec![one, one]])];
+        assert!(VerifyingKey::verify_batch_non_hiding("test", inputs.clone(), &proof));
+        assert!(!VerifyingKey::verify_batch("test", inputs, &proof));
+        assert!(!verifying_key.verify("test", &[one, one], &proof));
+
+        // Ensure the hiding proof is rejected by the non-hiding verifier.
+        let inputs = vec![(verifying_key, vec![vec![one, one]])];
+        assert!(!VerifyingKey::verify_batch_non_hiding("test", inputs, &crate::test_helpers::sample_proof()));
+    }
+
+    #[test]
+    fn test_marlin_hiding_bound() {
+        let rng = &mut TestRng::default();
+
+        let assignment = crate::test_helpers::sample_assignment();
+        let (proving_key, verifying_key) = crate::test_helpers::sample_keys();
+        let assignments = [(proving_key, vec![assignment])];
+        let one = <Circuit as Environment>::BaseField::one();
+        let inputs = vec![(verifying_key, vec![vec![one, one]])];
+
+        // Ensure the proofs for the supported hiding bounds verify with the same bound only.
+        for hiding_bound in [0, 1] {
+            let proof = ProvingKey::prove_batch_with_hiding_bound("test", &assignments, hiding_bound, rng).unwrap();
+            assert_eq!(proof.pc_proof.is_hiding(), hiding_bound > 0);
+            assert!(VerifyingKey::verify_batch_with_hiding_bound("test", inputs.clone(), hiding_bound, &proof));
+            assert!(!VerifyingKey::verify_batch_with_hiding_bound("test", inputs.clone(), 1 - hiding_bound, &proof));
+        }
+
+        // Ensure a hiding bound beyond the universal SRS is rejected.
+        assert!(ProvingKey::prove_batch_with_hiding_bound("test", &assignments, 2, rng).is_err());
+    }
+
+    #[test]
+    fn test_certificate_verify_batch() {
+        let rng = &mut TestRng::default();
+
+        let assignment = crate::test_helpers::sample_assignment();
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+        let certificate = crate::test_helpers::sample_certificate();
+
+        // Ensure the certificates verify individually and as a batch.
+        assert!(certificate.verify("test", &assignment, &verifying_key));
+        let inputs = [(&assignment, &verifying_key, &certificate), (&assignment, &verifying_key, &certificate)];
+        assert!(Certificate::verify_batch("test", &inputs, rng));
+
+        // Ensure an empty batch does not verify.
+        assert!(!Certificate::<CurrentNetwork>::verify_batch("test", &[], rng));
+    }
 }
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// A verifying key, with the values that only depend on the key (i.e. its evaluation domains
+/// and labeled commitments) precomputed, so that they are reused across verifications.
+#[derive(Clone)]
+pub struct PreparedVerifyingKey<N: Network> {
+    /// The prepared verifying key for the function.
+    prepared_verifying_key: Arc<marlin::PreparedCircuitVerifyingKey<N::PairingCurve>>,
+}
+
+impl<N: Network> PreparedVerifyingKey<N> {
+    /// Prepares the given verifying key.
+    pub fn prepare(verifying_key: &VerifyingKey<N>) -> Result<Self> {
+        let prepared_verifying_key =
+            marlin::PreparedCircuitVerifyingKey::prepare::<marlin::MarlinHidingMode>(verifying_key.inner().clone())
+                .map_err(|error| anyhow!("Failed to prepare the verifying key: {error:?}"))?;
+        Ok(Self { prepared_verifying_key: Arc::new(prepared_verifying_key) })
+    }
+
+    /// Returns `true` if this is the prepared form of the given verifying key.
+    pub fn is_prepared_from(&self, verifying_key: &VerifyingKey<N>) -> bool {
+        Arc::ptr_eq(&self.prepared_verifying_key.orig_vk, verifying_key.inner())
+            || *self.prepared_verifying_key.orig_vk == **verifying_key
+    }
+
+    /// Returns `true` if the batch proof is valid for the given public inputs.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch(
+        locator: &str,
+        inputs: Vec<(PreparedVerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        proof: &Proof<N>,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let keys_to_inputs: BTreeMap<_, _> = inputs
+            .iter()
+            .map(|(verifying_key, inputs)| (verifying_key.prepared_verifying_key.as_ref(), inputs.as_slice()))
+            .collect();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::marlin_universal_verifier();
+        let fiat_shamir = N::marlin_fs_parameters();
+
+        // Verify the batch proof.
+        match Marlin::<N>::verify_batch_prepared(universal_verifier, fiat_shamir, &keys_to_inputs, proof) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verified '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+}
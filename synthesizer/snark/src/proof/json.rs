@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Proof<N> {
+    /// Returns the canonical JSON encoding of the proof.
+    /// See the `json` module for the specification of the encoding.
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        crate::json::to_json::<N, _>("proof", self)
+    }
+
+    /// Returns the proof from its canonical JSON encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        crate::json::from_json::<N, _>("proof", json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Check the JSON header.
+        let json = expected.to_json()?;
+        assert_eq!(
+            vec!["version", "type", "network", "curve", "data"],
+            json.as_object().unwrap().keys().map(String::as_str).collect::<Vec<_>>()
+        );
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["type"], "proof");
+        assert_eq!(json["network"], CurrentNetwork::ID);
+        assert_eq!(json["curve"], "bls12_377");
+
+        // Check the data matches the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes.len() * 2, json["data"].as_str().unwrap().len());
+
+        // Check the round trip, including through a string.
+        assert_eq!(expected, Proof::from_json(&json)?);
+        assert_eq!(expected, Proof::from_json(&serde_json::Value::from_str(&json.to_string())?)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_invalid() -> Result<()> {
+        // Sample the proof.
+        let json = crate::test_helpers::sample_proof().to_json()?;
+
+        // Ensure a mismatched header is rejected.
+        for (key, value) in
+            [("version", 2.into()), ("type", "verifying_key".into()), ("network", 0.into()), ("curve", "bn254".into())]
+        {
+            let mut candidate = json.clone();
+            candidate[key] = value;
+            assert!(Proof::<CurrentNetwork>::from_json(&candidate).is_err());
+        }
+
+        // Ensure malformed data is rejected.
+        let data = json["data"].as_str().unwrap();
+        for candidate_data in [data[2..].to_string(), data[1..].to_string(), format!("{data}00"), data.to_uppercase()] {
+            let mut candidate = json.clone();
+            candidate["data"] = candidate_data.as_str().into();
+            assert!(Proof::<CurrentNetwork>::from_json(&candidate).is_err());
+        }
+
+        // Ensure unknown keys are rejected.
+        let mut candidate = json;
+        candidate["extra"] = 0.into();
+        assert!(Proof::<CurrentNetwork>::from_json(&candidate).is_err());
+
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 use super::*;
 
 mod bytes;
+mod json;
 mod parse;
 mod serialize;
 
@@ -24,12 +24,20 @@ use std::collections::BTreeMap;
 pub struct ProvingKey<N: Network> {
     /// The proving key for the function.
     proving_key: Arc<marlin::CircuitProvingKey<N::PairingCurve, marlin::MarlinHidingMode>>,
+    /// The proving key for non-hiding proofs, derived on first use.
+    non_hiding_proving_key: OnceCell<Arc<marlin::CircuitProvingKey<N::PairingCurve, marlin::MarlinNonHidingMode>>>,
 }
 
 impl<N: Network> ProvingKey<N> {
     /// Initializes a new proving key.
     pub const fn new(proving_key: Arc<marlin::CircuitProvingKey<N::PairingCurve, marlin::MarlinHidingMode>>) -> Self {
-        Self { proving_key }
+        Self { proving_key, non_hiding_proving_key: OnceCell::new() }
+    }
+
+    /// Precomputes the MSM window tables of the committer key, to speed up subsequent proofs with this key.
+    /// This uses roughly 20 times the memory of the committer key, and is shared between clones of the key.
+    pub fn preprocess(&self) {
+        self.proving_key.preprocess();
     }
 
     /// Returns a proof for the given assignment on the circuit.
@@ -60,6 +68,18 @@ impl<N: Network> ProvingKey<N> {
         locator: &str,
         assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
         rng: &mut R,
+    ) -> Result<Proof<N>> {
+        Self::prove_batch_with_job(locator, assignments, &ProvingJob::default(), rng)
+    }
+
+    /// Returns a proof for the given batch of proving keys and assignments,
+    /// reporting the progress of the prover to the given job, and stopping early if the job is cancelled.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_batch_with_job<R: Rng + CryptoRng>(
+        locator: &str,
+        assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+        job: &ProvingJob,
+        rng: &mut R,
     ) -> Result<Proof<N>> {
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
@@ -75,13 +95,76 @@ impl<N: Network> ProvingKey<N> {
         let fiat_shamir = N::marlin_fs_parameters();
 
         // Compute the proof.
-        let batch_proof = Proof::new(Marlin::<N>::prove_batch(universal_prover, fiat_shamir, &instances, rng)?);
+        let batch_proof =
+            Proof::new(Marlin::<N>::prove_batch_with_job(universal_prover, fiat_shamir, &instances, job, rng)?);
 
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executed '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
 
         Ok(batch_proof)
     }
+
+    /// Returns a non-hiding proof for the given batch of proving keys and assignments.
+    ///
+    /// A non-hiding proof omits the zero-knowledge blinding, which makes proving faster, but reveals
+    /// information about the private inputs. It must only be used in trusted environments, where the
+    /// assignments are not secret. A non-hiding proof is only accepted by `VerifyingKey::verify_batch_non_hiding`.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_batch_non_hiding<R: Rng + CryptoRng>(
+        locator: &str,
+        assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+        rng: &mut R,
+    ) -> Result<Proof<N>> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the instances.
+        let instances: BTreeMap<_, _> = assignments
+            .iter()
+            .map(|(proving_key, assignments)| (proving_key.non_hiding().as_ref(), assignments.as_slice()))
+            .collect();
+
+        // Retrieve the proving parameters.
+        let universal_prover = N::marlin_universal_prover();
+        let fiat_shamir = N::marlin_fs_parameters();
+
+        // Compute the proof.
+        let batch_proof =
+            Proof::new(MarlinNonHiding::<N>::prove_batch(universal_prover, fiat_shamir, &instances, rng)?);
+
+        #[cfg(feature = "aleo-cli")]
+        println!(
+            "{}",
+            format!(" • Executed '{locator}' without hiding (in {} ms)", timer.elapsed().as_millis()).dimmed()
+        );
+
+        Ok(batch_proof)
+    }
+
+    /// Returns a proof for the given batch of proving keys and assignments, with the given hiding bound.
+    ///
+    /// The hiding bound trades the zero-knowledge margin against prover time: a bound of 0 produces a
+    /// non-hiding proof (see `ProvingKey::prove_batch_non_hiding`), and a bound of 1 produces a hiding proof
+    /// (see `ProvingKey::prove_batch`). Larger bounds require more powers of `gamma_g` than the universal SRS
+    /// of the network provides, so they are rejected. The degree bounds are fixed by the circuit.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_batch_with_hiding_bound<R: Rng + CryptoRng>(
+        locator: &str,
+        assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+        hiding_bound: usize,
+        rng: &mut R,
+    ) -> Result<Proof<N>> {
+        match hiding_bound {
+            0 => Self::prove_batch_non_hiding(locator, assignments, rng),
+            1 => Self::prove_batch(locator, assignments, rng),
+            _ => bail!("The hiding bound ({hiding_bound}) exceeds the maximum supported by the universal SRS (1)"),
+        }
+    }
+
+    /// Returns the proving key for non-hiding proofs.
+    fn non_hiding(&self) -> &Arc<marlin::CircuitProvingKey<N::PairingCurve, marlin::MarlinNonHidingMode>> {
+        self.non_hiding_proving_key.get_or_init(|| Arc::new(self.proving_key.to_non_hiding()))
+    }
 }
 
 impl<N: Network> Deref for ProvingKey<N> {
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// A registry of content-addressed verifying keys, which maps each function locator and network edition
+/// to the ID of its verifying key. This allows a verifier to detect when a verifying key is substituted,
+/// for example, across program upgrades.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKeyRegistry<N: Network> {
+    /// The verifying key IDs, for each (locator, edition) pair.
+    keys: BTreeMap<(String, u16), Field<N>>,
+}
+
+impl<N: Network> Default for VerifyingKeyRegistry<N> {
+    /// Initializes an empty registry.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> VerifyingKeyRegistry<N> {
+    /// Initializes an empty registry.
+    pub const fn new() -> Self {
+        Self { keys: BTreeMap::new() }
+    }
+
+    /// Registers the verifying key for the given locator and edition, and returns its ID.
+    /// This method is idempotent, and returns an error if a different verifying key is already registered.
+    pub fn insert(&mut self, locator: &str, edition: u16, verifying_key: &VerifyingKey<N>) -> Result<Field<N>> {
+        // Compute the verifying key ID.
+        let id = verifying_key.to_id()?;
+        // Ensure the locator and edition are not registered to a different verifying key.
+        match self.keys.get(&(locator.to_string(), edition)) {
+            Some(existing) if *existing != id => {
+                bail!("A different verifying key is already registered for '{locator}' (edition {edition})")
+            }
+            Some(_) => (),
+            None => {
+                self.keys.insert((locator.to_string(), edition), id);
+            }
+        }
+        Ok(id)
+    }
+
+    /// Returns the ID of the verifying key registered for the given locator and edition, if it exists.
+    pub fn get(&self, locator: &str, edition: u16) -> Option<&Field<N>> {
+        self.keys.get(&(locator.to_string(), edition))
+    }
+
+    /// Returns the (locator, edition) pairs registered to the given verifying key ID.
+    pub fn lookup<'a>(&'a self, id: &'a Field<N>) -> impl 'a + Iterator<Item = (&'a str, u16)> {
+        self.keys
+            .iter()
+            .filter(move |(_, candidate)| *candidate == id)
+            .map(|((locator, edition), _)| (locator.as_str(), *edition))
+    }
+
+    /// Returns `true` if the given verifying key ID is registered.
+    pub fn contains_id(&self, id: &Field<N>) -> bool {
+        self.keys.values().any(|candidate| candidate == id)
+    }
+
+    /// Ensures the given verifying key is the one registered for the given locator and edition.
+    pub fn validate(&self, locator: &str, edition: u16, verifying_key: &VerifyingKey<N>) -> Result<()> {
+        // Retrieve the registered verifying key ID.
+        let Some(expected) = self.get(locator, edition) else {
+            bail!("No verifying key is registered for '{locator}' (edition {edition})")
+        };
+        // Ensure the verifying key ID matches.
+        let candidate = verifying_key.to_id()?;
+        ensure!(
+            *expected == candidate,
+            "Verifying key substitution detected for '{locator}' (edition {edition}): expected '{expected}', found '{candidate}'"
+        );
+        Ok(())
+    }
+
+    /// Removes the verifying key registered for the given locator and edition, and returns its ID.
+    pub fn remove(&mut self, locator: &str, edition: u16) -> Option<Field<N>> {
+        self.keys.remove(&(locator.to_string(), edition))
+    }
+
+    /// Returns the number of registered verifying keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a verifying key that differs from the sample verifying key.
+    fn sample_substitute(verifying_key: &VerifyingKey<CurrentNetwork>) -> VerifyingKey<CurrentNetwork> {
+        let mut substitute = (**verifying_key).clone();
+        substitute.circuit_info.num_constraints += 1;
+        VerifyingKey::new(Arc::new(substitute))
+    }
+
+    #[test]
+    fn test_registry() -> Result<()> {
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+        let substitute = sample_substitute(&verifying_key);
+
+        let mut registry = VerifyingKeyRegistry::<CurrentNetwork>::new();
+        assert!(registry.is_empty());
+
+        // Register the verifying key, and ensure it is content-addressed.
+        let id = registry.insert("test.aleo/main", 0, &verifying_key)?;
+        assert_eq!(id, verifying_key.to_id()?);
+        assert_ne!(id, substitute.to_id()?);
+        assert_eq!(Some(&id), registry.get("test.aleo/main", 0));
+        assert!(registry.contains_id(&id));
+
+        // Ensure registration is idempotent.
+        assert_eq!(id, registry.insert("test.aleo/main", 0, &verifying_key)?);
+        assert_eq!(1, registry.len());
+
+        // Ensure the same verifying key may be registered for another locator and edition.
+        registry.insert("test.aleo/main", 1, &verifying_key)?;
+        registry.insert("other.aleo/main", 0, &verifying_key)?;
+        assert_eq!(
+            vec![("other.aleo/main", 0), ("test.aleo/main", 0), ("test.aleo/main", 1)],
+            registry.lookup(&id).collect::<Vec<_>>()
+        );
+
+        // Ensure a substituted verifying key is rejected.
+        assert!(registry.insert("test.aleo/main", 0, &substitute).is_err());
+        registry.validate("test.aleo/main", 0, &verifying_key)?;
+        assert!(registry.validate("test.aleo/main", 0, &substitute).is_err());
+        assert!(registry.validate("test.aleo/main", 2, &verifying_key).is_err());
+
+        // Ensure an edition can be re-registered after removal.
+        assert_eq!(Some(id), registry.remove("test.aleo/main", 1));
+        registry.insert("test.aleo/main", 1, &substitute)?;
+        registry.validate("test.aleo/main", 1, &substitute)?;
+        assert_eq!(3, registry.len());
+
+        Ok(())
+    }
+}
@@ -68,7 +68,7 @@ impl<N: Network> Deref for UniversalSRS<N> {
             let timer = std::time::Instant::now();
 
             // Load the universal SRS.
-            let universal_srs = marlin::UniversalSRS::load().expect("Failed to load the universal SRS");
+            let universal_srs = N::marlin_universal_srs().expect("Failed to load the universal SRS");
 
             #[cfg(feature = "aleo-cli")]
             println!("{}", format!(" • Loaded universal setup (in {} ms)", timer.elapsed().as_millis()).dimmed());
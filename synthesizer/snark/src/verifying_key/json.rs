@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> VerifyingKey<N> {
+    /// Returns the canonical JSON encoding of the verifying key.
+    /// See the `json` module for the specification of the encoding.
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        crate::json::to_json::<N, _>("verifying_key", self)
+    }
+
+    /// Returns the verifying key from its canonical JSON encoding.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        crate::json::from_json::<N, _>("verifying_key", json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        // Sample the verifying key.
+        let (_, expected) = crate::test_helpers::sample_keys();
+
+        // Check the JSON header.
+        let json = expected.to_json()?;
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["type"], "verifying_key");
+        assert_eq!(json["network"], CurrentNetwork::ID);
+        assert_eq!(json["curve"], "bls12_377");
+
+        // Check the data matches the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes.len() * 2, json["data"].as_str().unwrap().len());
+
+        // Check the round trip, including through a string.
+        assert_eq!(expected, VerifyingKey::from_json(&json)?);
+        assert_eq!(expected, VerifyingKey::from_json(&serde_json::Value::from_str(&json.to_string())?)?);
+
+        // Ensure a proof cannot be decoded as a verifying key.
+        let proof_json = crate::test_helpers::sample_proof().to_json()?;
+        assert!(VerifyingKey::<CurrentNetwork>::from_json(&proof_json).is_err());
+
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 use super::*;
 
 mod bytes;
+mod json;
 mod parse;
 mod serialize;
 
@@ -32,6 +33,16 @@ impl<N: Network> VerifyingKey<N> {
         Self { verifying_key }
     }
 
+    /// Returns the circuit verifying key.
+    pub(crate) const fn inner(&self) -> &Arc<marlin::CircuitVerifyingKey<N::PairingCurve>> {
+        &self.verifying_key
+    }
+
+    /// Returns the content-addressed ID of the verifying key, computed as the BHP-1024 hash of its bytes.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
     /// Returns `true` if the proof is valid for the given public inputs.
     pub fn verify(&self, function_name: &str, inputs: &[N::Field], proof: &Proof<N>) -> bool {
         #[cfg(feature = "aleo-cli")]
@@ -87,6 +98,56 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `true` if the batch proof is valid for the given public inputs and hiding bound.
+    /// See `ProvingKey::prove_batch_with_hiding_bound` for the supported hiding bounds.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch_with_hiding_bound(
+        locator: &str,
+        inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        hiding_bound: usize,
+        proof: &Proof<N>,
+    ) -> bool {
+        match hiding_bound {
+            0 => Self::verify_batch_non_hiding(locator, inputs, proof),
+            1 => Self::verify_batch(locator, inputs, proof),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the non-hiding batch proof is valid for the given public inputs.
+    /// Hiding proofs are rejected; use `VerifyingKey::verify_batch` for those instead.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch_non_hiding(
+        locator: &str,
+        inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        proof: &Proof<N>,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let keys_to_inputs: BTreeMap<_, _> =
+            inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::marlin_universal_verifier();
+        let fiat_shamir = N::marlin_fs_parameters();
+
+        // Verify the batch proof.
+        match MarlinNonHiding::<N>::verify_batch(universal_verifier, fiat_shamir, &keys_to_inputs, proof) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verified '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
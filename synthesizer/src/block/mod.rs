@@ -27,10 +27,16 @@ pub use transactions::*;
 pub mod transition;
 pub use transition::*;
 
+mod target;
+pub use target::*;
+
 mod bytes;
 mod genesis;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 mod string;
+mod verify;
 
 use console::{
     account::{PrivateKey, Signature},
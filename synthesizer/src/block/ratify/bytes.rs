@@ -42,7 +42,22 @@ impl<N: Network> FromBytes for Ratify<N> {
                 // Return the ratify object.
                 Self::StakingReward(address, amount)
             }
-            2.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
+            2 => {
+                // Read the kind ID.
+                let kind: u16 = FromBytes::read_le(&mut reader)?;
+                // Read the number of payload bytes.
+                let num_bytes: u32 = FromBytes::read_le(&mut reader)?;
+                // Ensure the payload is within the allowed size.
+                if num_bytes as usize > Self::MAX_PAYLOAD_SIZE {
+                    return Err(error(format!("Ratify payload exceeds {} bytes", Self::MAX_PAYLOAD_SIZE)));
+                }
+                // Read the payload.
+                let mut payload = vec![0u8; num_bytes as usize];
+                reader.read_exact(&mut payload)?;
+                // Return the ratify object.
+                Self::Custom(kind, payload)
+            }
+            3.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
         };
         Ok(literal)
     }
@@ -65,6 +80,16 @@ impl<N: Network> ToBytes for Ratify<N> {
                 address.write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
+            Self::Custom(kind, payload) => {
+                // Ensure the payload is within the allowed size.
+                if payload.len() > Self::MAX_PAYLOAD_SIZE {
+                    return Err(error(format!("Ratify payload exceeds {} bytes", Self::MAX_PAYLOAD_SIZE)));
+                }
+                (2 as Variant).write_le(&mut writer)?;
+                kind.write_le(&mut writer)?;
+                (payload.len() as u32).write_le(&mut writer)?;
+                writer.write_all(payload)
+            }
         }
     }
 }
@@ -20,12 +20,20 @@ use console::{network::prelude::*, types::Address};
 
 type Variant = u8;
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum Ratify<N: Network> {
     /// The proving reward.
     ProvingReward(Address<N>, u64),
     /// The staking reward.
     StakingReward(Address<N>, u64),
+    /// A ratification of a registered kind, with its kind ID and its payload.
+    /// Note: The payload is validated and finalized by the `RatifyKind` registered in the VM.
+    Custom(u16, Vec<u8>),
+}
+
+impl<N: Network> Ratify<N> {
+    /// The maximum number of bytes in the payload of a custom ratification.
+    pub const MAX_PAYLOAD_SIZE: usize = 1 << 14; // 16 KiB
 }
 
 #[cfg(test)]
@@ -36,6 +44,10 @@ mod test_helpers {
     type CurrentNetwork = Testnet3;
 
     pub(crate) fn sample_ratify_objects(rng: &mut TestRng) -> Vec<Ratify<CurrentNetwork>> {
-        vec![Ratify::ProvingReward(Address::new(rng.gen()), 100), Ratify::StakingReward(Address::new(rng.gen()), 200)]
+        vec![
+            Ratify::ProvingReward(Address::new(rng.gen()), 100),
+            Ratify::StakingReward(Address::new(rng.gen()), 200),
+            Ratify::Custom(rng.gen(), (0..rng.gen_range(0..64)).map(|_| rng.gen()).collect()),
+        ]
     }
 }
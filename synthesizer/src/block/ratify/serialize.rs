@@ -35,6 +35,13 @@ impl<N: Network> Serialize for Ratify<N> {
                     input.serialize_field("amount", &amount)?;
                     input.end()
                 }
+                Self::Custom(kind, payload) => {
+                    let mut input = serializer.serialize_struct("Ratify", 3)?;
+                    input.serialize_field("type", "custom")?;
+                    input.serialize_field("kind", &kind)?;
+                    input.serialize_field("payload", &payload)?;
+                    input.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -67,6 +74,18 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
                         // Construct the ratify object.
                         Ratify::StakingReward(address, amount)
                     }
+                    Some("custom") => {
+                        // Retrieve the kind ID.
+                        let kind: u16 = DeserializeExt::take_from_value::<D>(&mut object, "kind")?;
+                        // Retrieve the payload.
+                        let payload: Vec<u8> = DeserializeExt::take_from_value::<D>(&mut object, "payload")?;
+                        // Ensure the payload is within the allowed size.
+                        if payload.len() > Ratify::<N>::MAX_PAYLOAD_SIZE {
+                            return Err(de::Error::custom("Ratify payload exceeds the maximum size"));
+                        }
+                        // Construct the ratify object.
+                        Ratify::Custom(kind, payload)
+                    }
                     _ => return Err(de::Error::custom("Invalid ratify object type")),
                 };
                 // Return the ratify object.
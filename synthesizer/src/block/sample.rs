@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{account::Address, program::RATIFICATIONS_DEPTH};
+
+/// The maximum number of transactions, and of ratifications, of a randomly-sampled block.
+const MAX_SAMPLE_TRANSACTIONS: u32 = 3;
+
+impl<N: Network> Block<N> {
+    /// Returns a randomly-sampled block, which is signed by a randomly-sampled private key.
+    ///
+    /// This is a structure-only fixture, for testing the serialization and storage of blocks.
+    /// The transactions are accepted executions that are sampled with `Transaction::sample_unproven`, and the header
+    /// commits to the transactions, finalize operations, and ratifications of the block. The transactions do not
+    /// contain proofs, the block does not contain a coinbase solution, and it does not extend a sampled previous block,
+    /// so it does not pass `Block::verify`.
+    pub fn sample_unproven<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample the transactions, with their finalize operations.
+        let transactions = (0..rng.gen_range(1..=MAX_SAMPLE_TRANSACTIONS))
+            .map(|index| {
+                let finalize_operations = (0..rng.gen_range(0..=MAX_SAMPLE_TRANSACTIONS))
+                    .map(|_| FinalizeOperation::InsertKeyValue(Field::rand(rng), Field::rand(rng), Field::rand(rng)))
+                    .collect();
+                ConfirmedTransaction::accepted_execute(index, Transaction::sample_unproven(rng), finalize_operations)
+                    .or_halt_with::<N>("Failed to sample a confirmed transaction")
+            })
+            .collect::<Transactions<N>>();
+
+        // Sample the ratifications.
+        let ratifications = (0..rng.gen_range(0..=MAX_SAMPLE_TRANSACTIONS))
+            .map(|_| match rng.gen() {
+                true => Ratify::ProvingReward(Address::new(Group::rand(rng)), rng.gen()),
+                false => Ratify::StakingReward(Address::new(Group::rand(rng)), rng.gen()),
+            })
+            .collect::<Vec<_>>();
+        // Compute the ratifications root.
+        let ratifications_root = N::merkle_tree_bhp::<RATIFICATIONS_DEPTH>(
+            &ratifications
+                .iter()
+                .map(|ratify| Ok::<_, Error>(ratify.to_bytes_le()?.to_bits_le()))
+                .collect::<Result<Vec<_>, _>>()
+                .or_halt_with::<N>("Failed to serialize the ratifications"),
+        )
+        .or_halt_with::<N>("Failed to compute the ratifications root");
+
+        // Sample the metadata, which is valid for a block after the genesis block.
+        let height = rng.gen_range(1..u32::MAX);
+        let coinbase_target = rng.gen_range(N::GENESIS_COINBASE_TARGET..u64::MAX);
+        let metadata = Metadata::new(
+            N::ID,
+            rng.gen_range(1..u64::MAX),
+            height,
+            rng.gen_range(1..u64::MAX),
+            // The cumulative weight and proof target fit in a u64, as larger integers do not round trip through JSON.
+            u128::from(rng.gen::<u64>()),
+            u128::from(rng.gen::<u64>()),
+            coinbase_target,
+            rng.gen_range(N::GENESIS_PROOF_TARGET..coinbase_target),
+            rng.gen_range(N::GENESIS_COINBASE_TARGET..u64::MAX),
+            rng.gen_range(N::GENESIS_TIMESTAMP..i64::MAX),
+            rng.gen_range(N::GENESIS_TIMESTAMP + 1..i64::MAX),
+        )
+        .or_halt_with::<N>("Failed to sample the block metadata");
+
+        // Construct the header.
+        let header = Header::from(
+            Field::rand(rng),
+            transactions.to_transactions_root().or_halt_with::<N>("Failed to compute the transactions root"),
+            transactions.to_finalize_root().or_halt_with::<N>("Failed to compute the finalize root"),
+            *ratifications_root.root(),
+            Field::zero(),
+            metadata,
+        )
+        .or_halt_with::<N>("Failed to sample the block header");
+
+        // Sign the block.
+        let private_key = PrivateKey::new(rng).or_halt_with::<N>("Failed to sample a private key");
+        let previous_hash = N::BlockHash::from(Field::rand(rng));
+        Self::new(&private_key, previous_hash, header, transactions, ratifications, None, rng)
+            .or_halt_with::<N>("Failed to sample a block")
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N: Network> arbitrary::Arbitrary<'a> for Block<N> {
+    /// Returns an unproven block, which is sampled from an RNG that is seeded by the unstructured bytes
+    /// (see `Block::sample_unproven`).
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+        Ok(Self::sample_unproven(&mut ChaChaRng::from_seed(u.arbitrary()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_sample_unproven() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random block.
+            let expected = Block::<CurrentNetwork>::sample_unproven(&mut rng);
+
+            // Ensure the block passes the checks of its constructor.
+            let candidate = Block::from(
+                expected.previous_hash(),
+                *expected.header(),
+                expected.transactions().clone(),
+                expected.ratifications().clone(),
+                None,
+                *expected.signature(),
+            )?;
+            assert_eq!(expected, candidate);
+
+            // Ensure the block round trips through its string and byte representations.
+            assert_eq!(expected, Block::from_str(&expected.to_string())?);
+            assert_eq!(expected, Block::read_le(&expected.to_bytes_le()?[..])?);
+        }
+        Ok(())
+    }
+}
@@ -17,7 +17,10 @@ mod cost;
 mod serialize;
 mod string;
 
-use crate::{block::Transaction, process::Program};
+use crate::{
+    block::Transaction,
+    process::{Command, Program},
+};
 use console::{
     network::prelude::*,
     program::{Identifier, ProgramID},
@@ -122,6 +125,16 @@ impl<N: Network> Deployment<N> {
         &self.verifying_keys
     }
 
+    /// Returns `true` if the program opts into a program admin, by using the `clear` command in a finalize scope.
+    pub fn registers_admin(&self) -> bool {
+        self.program
+            .functions()
+            .values()
+            .filter_map(|function| function.finalize_logic())
+            .flat_map(|finalize| finalize.commands())
+            .any(|command| matches!(command, Command::Clear(..)))
+    }
+
     /// Returns the deployment ID.
     pub fn to_deployment_id(&self) -> Result<Field<N>> {
         Ok(*Transaction::deployment_tree(self, None)?.root())
@@ -20,7 +20,8 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        // Note: Version 1 is an execution with an expiration height, and version 2 may also be sponsored.
+        if version > 2 {
             return Err(error("Invalid execution version"));
         }
         // Read the number of transitions.
@@ -43,16 +44,42 @@ impl<N: Network> FromBytes for Execution<N> {
             1 => Some(Proof::read_le(&mut reader)?),
             _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
         };
+        // Read the expiration height, and the sponsorship.
+        let (valid_until, sponsorship) = match version {
+            0 => (None, None),
+            1 => (Some(u32::read_le(&mut reader)?), None),
+            _ => {
+                // Read the expiration variant.
+                let expiration_variant = u8::read_le(&mut reader)?;
+                // Read the expiration height.
+                let valid_until = match expiration_variant {
+                    0 => None,
+                    1 => Some(u32::read_le(&mut reader)?),
+                    _ => return Err(error(format!("Invalid expiration variant '{expiration_variant}'"))),
+                };
+                // Read the sponsorship.
+                (valid_until, Some(Sponsorship::read_le(&mut reader)?))
+            }
+        };
         // Return the new `Execution` instance.
-        Self::from(transitions.into_iter(), global_state_root, proof).map_err(|e| error(e.to_string()))
+        Ok(Self::from(transitions.into_iter(), global_state_root, proof)
+            .map_err(|e| error(e.to_string()))?
+            .with_valid_until(valid_until)
+            .with_sponsorship(sponsorship))
     }
 }
 
 impl<N: Network> ToBytes for Execution<N> {
     /// Writes the execution to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Determine the version.
+        let version = match (&self.valid_until, &self.sponsorship) {
+            (None, None) => 0u8,
+            (Some(_), None) => 1u8,
+            (_, Some(_)) => 2u8,
+        };
         // Write the version.
-        0u8.write_le(&mut writer)?;
+        version.write_le(&mut writer)?;
         // Write the number of transitions.
         (u8::try_from(self.transitions.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
         // Write the transitions.
@@ -69,7 +96,23 @@ impl<N: Network> ToBytes for Execution<N> {
                 proof.write_le(&mut writer)?;
             }
         }
-        Ok(())
+        // Write the expiration height, and the sponsorship.
+        match (&self.valid_until, &self.sponsorship) {
+            (None, None) => Ok(()),
+            (Some(valid_until), None) => valid_until.write_le(&mut writer),
+            (valid_until, Some(sponsorship)) => {
+                // Write the expiration height.
+                match valid_until {
+                    None => 0u8.write_le(&mut writer)?,
+                    Some(valid_until) => {
+                        1u8.write_le(&mut writer)?;
+                        valid_until.write_le(&mut writer)?;
+                    }
+                }
+                // Write the sponsorship.
+                sponsorship.write_le(&mut writer)
+            }
+        }
     }
 }
 
@@ -89,6 +132,21 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
         assert!(Execution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation, with an expiration height.
+        let expected = expected.with_valid_until(Some(10));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
+
+        // Check the byte representation, with a sponsorship, with and without an expiration height.
+        let mut rng = TestRng::default();
+        for sponsorship in super::super::sponsorship::test_helpers::sample_sponsorships(&mut rng) {
+            for valid_until in [None, Some(10)] {
+                let expected = expected.clone().with_valid_until(valid_until).with_sponsorship(Some(sponsorship));
+                let expected_bytes = expected.to_bytes_le()?;
+                assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
+            }
+        }
         Ok(())
     }
 }
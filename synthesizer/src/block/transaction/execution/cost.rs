@@ -137,11 +137,15 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
         Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
+        Command::Instruction(Instruction::EcdsaVerifyP256(_)) => Ok(1_000_000),
         // TODO: The following 'finalize' commands are currently priced higher than expected.
         //  Expect these numbers to change as their usage is stabilized.
+        Command::Clear(_) => Ok(1_000_000),
         Command::Contains(_) => Ok(250_000),
         Command::Get(_) => Ok(500_000),
         Command::GetOrUse(_) => Ok(500_000),
+        Command::Keys(keys) => Ok(500_000 * keys.destinations().len() as u64),
+        Command::Length(_) => Ok(250_000),
         Command::RandChaCha(_) => Ok(500_000),
         Command::Remove(_) => Ok(10_000),
         Command::Set(_) => Ok(1_000_000),
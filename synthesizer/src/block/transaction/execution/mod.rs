@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod sponsorship;
+pub use sponsorship::*;
+
 mod bytes;
 mod cost;
 mod serialize;
@@ -25,7 +28,11 @@ use crate::{
     Finalize,
     VM,
 };
-use console::{account::Field, network::prelude::*, program::LiteralType};
+use console::{
+    account::Field,
+    network::prelude::*,
+    program::LiteralType,
+};
 
 use indexmap::IndexMap;
 use std::collections::HashMap;
@@ -38,12 +45,22 @@ pub struct Execution<N: Network> {
     global_state_root: N::StateRoot,
     /// The proof.
     proof: Option<Proof<N>>,
+    /// The last block height at which the execution may be finalized, if the execution expires.
+    valid_until: Option<u32>,
+    /// The sponsorship of the execution, if its fee is paid by a sponsor.
+    sponsorship: Option<Sponsorship<N>>,
 }
 
 impl<N: Network> Execution<N> {
     /// Initialize a new `Execution` instance.
     pub fn new() -> Self {
-        Self { transitions: Default::default(), global_state_root: Default::default(), proof: None }
+        Self {
+            transitions: Default::default(),
+            global_state_root: Default::default(),
+            proof: None,
+            valid_until: None,
+            sponsorship: None,
+        }
     }
 
     /// Initializes a new `Execution` instance with the given transitions.
@@ -53,13 +70,37 @@ impl<N: Network> Execution<N> {
         proof: Option<Proof<N>>,
     ) -> Result<Self> {
         // Construct the execution.
-        let execution = Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, proof };
+        let execution = Self {
+            transitions: transitions.map(|t| (*t.id(), t)).collect(),
+            global_state_root,
+            proof,
+            valid_until: None,
+            sponsorship: None,
+        };
         // Ensure the transitions are not empty.
         ensure!(!execution.transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
         // Return the new `Execution` instance.
         Ok(execution)
     }
 
+    /// Returns the execution, with the given expiration height.
+    ///
+    /// The expiration height is signed in the root request (see `Request::sign_with_expiration`),
+    /// and is a public input of the root transition, so the proof does not verify if it is changed or removed.
+    pub const fn with_valid_until(mut self, valid_until: Option<u32>) -> Self {
+        self.valid_until = valid_until;
+        self
+    }
+
+    /// Returns the execution, with the given sponsorship.
+    ///
+    /// The sponsorship is committed to by the execution ID, except for the sponsor signature,
+    /// so a sponsored execution must be submitted with the fee that the sponsor signed.
+    pub const fn with_sponsorship(mut self, sponsorship: Option<Sponsorship<N>>) -> Self {
+        self.sponsorship = sponsorship;
+        self
+    }
+
     /// Returns the size in bytes.
     pub fn size_in_bytes(&self) -> Result<u64> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
@@ -75,9 +116,35 @@ impl<N: Network> Execution<N> {
         self.proof.as_ref()
     }
 
+    /// Returns the last block height at which the execution may be finalized, if it expires.
+    pub const fn valid_until(&self) -> Option<u32> {
+        self.valid_until
+    }
+
+    /// Returns the sponsorship of the execution, if its fee is paid by a sponsor.
+    pub const fn sponsorship(&self) -> Option<&Sponsorship<N>> {
+        self.sponsorship.as_ref()
+    }
+
+    /// Returns `true` if the execution has expired at the given block height.
+    pub fn is_expired(&self, block_height: u32) -> bool {
+        matches!(self.valid_until(), Some(valid_until) if block_height > valid_until)
+    }
+
     /// Returns the execution ID.
     pub fn to_execution_id(&self) -> Result<Field<N>> {
-        Ok(*Transaction::execution_tree(self, &None)?.root())
+        // Compute the root of the execution tree.
+        let root = *Transaction::execution_tree(self, &None)?.root();
+        // If the execution expires, bind the expiration height to the execution ID.
+        let execution_id = match self.valid_until() {
+            Some(valid_until) => N::hash_psd2(&[root, Field::from_u32(valid_until)])?,
+            None => root,
+        };
+        // If the execution is sponsored, bind the sponsorship to the execution ID.
+        match &self.sponsorship {
+            Some(sponsorship) => N::hash_psd4(&[&[execution_id], sponsorship.to_fields()?.as_slice()].concat()),
+            None => Ok(execution_id),
+        }
     }
 }
 
@@ -145,3 +212,50 @@ impl<N: Network> Execution<N> {
         self.transitions.values().flat_map(Transition::commitments)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_valid_until() {
+        // Sample the execution.
+        let execution = crate::process::test_helpers::sample_execution();
+        assert_eq!(None, execution.valid_until());
+        assert!(!execution.is_expired(u32::MAX));
+
+        // Set the expiration height.
+        let expiring = execution.clone().with_valid_until(Some(10));
+        assert_eq!(Some(10), expiring.valid_until());
+        assert!(!expiring.is_expired(9));
+        assert!(!expiring.is_expired(10));
+        assert!(expiring.is_expired(11));
+
+        // Ensure the expiration height is bound to the execution ID.
+        let execution_id = execution.to_execution_id().unwrap();
+        assert_ne!(execution_id, expiring.to_execution_id().unwrap());
+        assert_ne!(
+            expiring.to_execution_id().unwrap(),
+            execution.with_valid_until(Some(11)).to_execution_id().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sponsorship() {
+        let mut rng = snarkvm_utilities::TestRng::default();
+
+        // Sample the execution.
+        let execution = crate::process::test_helpers::sample_execution();
+        assert_eq!(None, execution.sponsorship());
+        let execution_id = execution.to_execution_id().unwrap();
+
+        // Sample the sponsorships, which differ only in the sponsor signature.
+        let sponsorships = super::sponsorship::test_helpers::sample_sponsorships(&mut rng);
+        let sponsored = execution.clone().with_sponsorship(Some(sponsorships[0]));
+        assert_eq!(Some(&sponsorships[0]), sponsored.sponsorship());
+
+        // Ensure the sponsorship is bound to the execution ID.
+        let sponsored_id = sponsored.to_execution_id().unwrap();
+        assert_ne!(execution_id, sponsored_id);
+        // Ensure the sponsor signature is not bound to the execution ID, as it signs the fee.
+        assert_eq!(sponsored_id, execution.with_sponsorship(Some(sponsorships[1])).to_execution_id().unwrap());
+    }
+}
@@ -21,13 +21,19 @@ impl<N: Network> Serialize for Execution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut execution = serializer.serialize_struct("Execution", 3)?;
+                let mut execution = serializer.serialize_struct("Execution", 5)?;
                 execution
                     .serialize_field("transitions", &self.transitions.values().collect::<Vec<&Transition<N>>>())?;
                 execution.serialize_field("global_state_root", &self.global_state_root)?;
                 if let Some(proof) = &self.proof {
                     execution.serialize_field("proof", proof)?;
                 }
+                if let Some(valid_until) = &self.valid_until {
+                    execution.serialize_field("valid_until", valid_until)?;
+                }
+                if let Some(sponsorship) = &self.sponsorship {
+                    execution.serialize_field("sponsorship", sponsorship)?;
+                }
                 execution.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -50,8 +56,21 @@ impl<'de, N: Network> Deserialize<'de> for Execution<N> {
                 let proof =
                     serde_json::from_value(execution.get_mut("proof").unwrap_or(&mut serde_json::Value::Null).take())
                         .map_err(de::Error::custom)?;
+                // Retrieve the expiration height.
+                let valid_until = serde_json::from_value(
+                    execution.get_mut("valid_until").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
+                // Retrieve the sponsorship.
+                let sponsorship = serde_json::from_value(
+                    execution.get_mut("sponsorship").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
                 // Recover the execution.
-                Self::from(transitions.into_iter(), global_state_root, proof).map_err(de::Error::custom)
+                Ok(Self::from(transitions.into_iter(), global_state_root, proof)
+                    .map_err(de::Error::custom)?
+                    .with_valid_until(valid_until)
+                    .with_sponsorship(sponsorship))
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution"),
         }
@@ -76,6 +95,18 @@ mod tests {
         assert_eq!(expected, Execution::from_str(expected_string)?);
         assert_eq!(expected, serde_json::from_str(&candidate_string)?);
 
+        // Check the serialization, with a sponsorship, with and without an expiration height.
+        let mut rng = TestRng::default();
+        for (sponsorship, valid_until) in super::super::sponsorship::test_helpers::sample_sponsorships(&mut rng)
+            .into_iter()
+            .zip([None, Some(10)])
+        {
+            let expected = expected.clone().with_valid_until(valid_until).with_sponsorship(Some(sponsorship));
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+            assert_eq!(expected, Execution::from_str(&expected.to_string())?);
+        }
+
         Ok(())
     }
 
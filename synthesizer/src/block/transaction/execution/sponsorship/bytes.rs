@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Sponsorship<N> {
+    /// Reads the sponsorship from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid sponsorship version"));
+        }
+        // Read the sponsor.
+        let sponsor = Address::read_le(&mut reader)?;
+        // Read the endorsement.
+        let endorsement = Endorsement::read_le(&mut reader)?;
+        // Read the signature variant.
+        let signature_variant = u8::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = match signature_variant {
+            0 => None,
+            1 => Some(Signature::read_le(&mut reader)?),
+            _ => return Err(error(format!("Invalid signature variant '{signature_variant}'"))),
+        };
+        // Return the sponsorship.
+        Ok(Self::from(sponsor, endorsement, signature))
+    }
+}
+
+impl<N: Network> ToBytes for Sponsorship<N> {
+    /// Writes the sponsorship to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the sponsor.
+        self.sponsor.write_le(&mut writer)?;
+        // Write the endorsement.
+        self.endorsement.write_le(&mut writer)?;
+        // Write the signature.
+        match &self.signature {
+            None => 0u8.write_le(&mut writer),
+            Some(signature) => {
+                1u8.write_le(&mut writer)?;
+                signature.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for expected in crate::block::transaction::execution::sponsorship::test_helpers::sample_sponsorships(&mut rng) {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Sponsorship::read_le(&expected_bytes[..])?);
+            assert!(Sponsorship::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use crate::block::Fee;
+use console::{
+    account::{Address, PrivateKey, Signature},
+    network::prelude::*,
+    program::{Endorsement, Request},
+    types::{Field, Group},
+};
+
+/// The sponsorship of an execution, whose fee is paid by a sponsor on behalf of the signer of the execution.
+///
+/// The signer designates the sponsor by endorsing the sponsor address for the root transition of the execution,
+/// and the sponsor signs the ID of the fee transition it pays. As the fee is bound to the execution ID,
+/// which commits to the sponsorship, the signatures of the signer and sponsor are bound together.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Sponsorship<N: Network> {
+    /// The address of the sponsor.
+    sponsor: Address<N>,
+    /// The endorsement of the sponsor, by the signer of the root request of the execution.
+    endorsement: Endorsement<N>,
+    /// The signature of the sponsor on the ID of the fee transition, once the sponsor pays the fee.
+    signature: Option<Signature<N>>,
+}
+
+impl<N: Network> Sponsorship<N> {
+    /// Initializes a new sponsorship, for the given sponsor, which is endorsed by the signer of the given root request.
+    pub fn new<R: Rng + CryptoRng>(request: &Request<N>, sponsor: Address<N>, rng: &mut R) -> Result<Self> {
+        // Endorse the sponsor.
+        let endorsement = request.endorse(&Self::endorsement_message(&sponsor)?, rng)?;
+        // Return the sponsorship.
+        Ok(Self { sponsor, endorsement, signature: None })
+    }
+
+    /// Initializes the sponsorship from its components.
+    pub const fn from(sponsor: Address<N>, endorsement: Endorsement<N>, signature: Option<Signature<N>>) -> Self {
+        Self { sponsor, endorsement, signature }
+    }
+
+    /// Returns the sponsorship, signed by the sponsor for the given fee.
+    pub fn sign<R: Rng + CryptoRng>(self, private_key: &PrivateKey<N>, fee: &Fee<N>, rng: &mut R) -> Result<Self> {
+        // Ensure the private key belongs to the sponsor.
+        ensure!(Address::try_from(private_key)? == self.sponsor, "The private key does not belong to the sponsor");
+        // Sign the fee transition ID.
+        let signature = private_key.sign(&Self::signature_message(fee), rng)?;
+        // Return the signed sponsorship.
+        Ok(Self { signature: Some(signature), ..self })
+    }
+
+    /// Returns the address of the sponsor.
+    pub const fn sponsor(&self) -> &Address<N> {
+        &self.sponsor
+    }
+
+    /// Returns the endorsement of the sponsor, by the signer of the execution.
+    pub const fn endorsement(&self) -> &Endorsement<N> {
+        &self.endorsement
+    }
+
+    /// Returns the signature of the sponsor on the fee transition ID, if the sponsor has paid the fee.
+    pub const fn signature(&self) -> Option<&Signature<N>> {
+        self.signature.as_ref()
+    }
+
+    /// Ensures the sponsor is endorsed for the root transition with the given `tpk`, and has signed the given fee.
+    pub fn verify(&self, tpk: &Group<N>, fee: &Fee<N>) -> Result<()> {
+        // Ensure the signer of the execution endorsed the sponsor.
+        ensure!(
+            self.endorsement.verify(tpk, &Self::endorsement_message(&self.sponsor)?),
+            "The sponsor '{}' is not endorsed by the signer of the execution",
+            self.sponsor
+        );
+        // Ensure the sponsor signed the fee.
+        match &self.signature {
+            Some(signature) => ensure!(
+                signature.verify(&self.sponsor, &Self::signature_message(fee)),
+                "The fee '{}' is not signed by the sponsor '{}'",
+                fee.transition_id(),
+                self.sponsor
+            ),
+            None => bail!("The fee '{}' is not signed by the sponsor '{}'", fee.transition_id(), self.sponsor),
+        }
+        Ok(())
+    }
+
+    /// Returns the field elements of the sponsorship, which are committed to by the execution ID.
+    /// Note: The sponsor signature is excluded, as it signs the fee, which is bound to the execution ID.
+    pub fn to_fields(&self) -> Result<Vec<Field<N>>> {
+        Ok(vec![
+            self.sponsor.to_field()?,
+            self.endorsement.challenge().to_field()?,
+            self.endorsement.response().to_field()?,
+        ])
+    }
+}
+
+impl<N: Network> Sponsorship<N> {
+    /// Returns the message endorsed by the signer of the execution, for the given sponsor.
+    /// Note: The message is tagged with its purpose, so it is never a valid message for another endorsement.
+    fn endorsement_message(sponsor: &Address<N>) -> Result<Vec<Field<N>>> {
+        Ok(vec![Field::new_domain_separator("AleoSponsorEndorsement0"), sponsor.to_field()?])
+    }
+
+    /// Returns the message signed by the sponsor, for the given fee.
+    /// Note: The message is tagged with its purpose, so it is never a valid message for another signature.
+    fn signature_message(fee: &Fee<N>) -> Vec<Field<N>> {
+        vec![Field::new_domain_separator("AleoSponsorFee0"), **fee.transition_id()]
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{network::Testnet3, types::Scalar};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a random sponsorship, with and without a sponsor signature.
+    pub(crate) fn sample_sponsorships(rng: &mut TestRng) -> Vec<Sponsorship<CurrentNetwork>> {
+        // Sample the sponsor.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let sponsor = Address::try_from(&private_key).unwrap();
+        // Sample the endorsement.
+        let endorsement = Endorsement::from((Scalar::rand(rng), Scalar::rand(rng)));
+        // Sample the signature.
+        let signature = Signature::sign(&private_key, &[Field::rand(rng)], rng).unwrap();
+
+        vec![Sponsorship::from(sponsor, endorsement, None), Sponsorship::from(sponsor, endorsement, Some(signature))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::Testnet3,
+        program::{Identifier, ProgramID, Value, ValueType},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_endorsement_message_is_tagged() {
+        let rng = &mut TestRng::default();
+
+        // Sample the request of the signer.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let request = Request::sign(
+            &private_key,
+            ProgramID::from_str("token.aleo").unwrap(),
+            Identifier::from_str("transfer").unwrap(),
+            [Value::<CurrentNetwork>::from_str("1u64").unwrap()].into_iter(),
+            &[ValueType::from_str("u64.public").unwrap()],
+            rng,
+        )
+        .unwrap();
+        let tpk = request.to_tpk();
+
+        // Sample the sponsor.
+        let sponsor = Address::try_from(&PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Ensure the sponsorship endorses the tagged message.
+        let sponsorship = Sponsorship::new(&request, sponsor, rng).unwrap();
+        let message = Sponsorship::endorsement_message(&sponsor).unwrap();
+        assert!(sponsorship.endorsement().verify(&tpk, &message));
+
+        // Ensure an endorsement of the untagged sponsor address is not a sponsorship, and vice versa.
+        let untagged = request.endorse(&[sponsor.to_field().unwrap()], rng).unwrap();
+        assert!(!untagged.verify(&tpk, &message));
+        assert!(!sponsorship.endorsement().verify(&tpk, &[sponsor.to_field().unwrap()]));
+    }
+}
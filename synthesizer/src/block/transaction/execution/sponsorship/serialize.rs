@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for Sponsorship<N> {
+    /// Serializes the sponsorship into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut sponsorship = serializer.serialize_struct("Sponsorship", 3)?;
+                sponsorship.serialize_field("sponsor", &self.sponsor)?;
+                sponsorship.serialize_field("endorsement", &self.endorsement)?;
+                if let Some(signature) = &self.signature {
+                    sponsorship.serialize_field("signature", signature)?;
+                }
+                sponsorship.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Sponsorship<N> {
+    /// Deserializes the sponsorship from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the sponsorship from a string into a value.
+                let mut sponsorship = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the sponsor.
+                let sponsor = DeserializeExt::take_from_value::<D>(&mut sponsorship, "sponsor")?;
+                // Retrieve the endorsement.
+                let endorsement = DeserializeExt::take_from_value::<D>(&mut sponsorship, "endorsement")?;
+                // Retrieve the signature.
+                let signature = serde_json::from_value(
+                    sponsorship.get_mut("signature").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
+                // Recover the sponsorship.
+                Ok(Self::from(sponsor, endorsement, signature))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "sponsorship"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for expected in crate::block::transaction::execution::sponsorship::test_helpers::sample_sponsorships(&mut rng) {
+            // Serialize
+            let candidate_string = serde_json::to_string(&expected)?;
+
+            // Deserialize
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for expected in crate::block::transaction::execution::sponsorship::test_helpers::sample_sponsorships(&mut rng) {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, Sponsorship::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
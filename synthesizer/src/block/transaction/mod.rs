@@ -23,6 +23,8 @@ pub use fee::*;
 
 mod bytes;
 mod merkle;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 mod string;
 
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum number of transitions of a randomly-sampled execution.
+const MAX_SAMPLE_TRANSITIONS: usize = 3;
+
+impl<N: Network> Transaction<N> {
+    /// Returns a randomly-sampled execute transaction, whose transitions are sampled with `Transition::sample`,
+    /// and whose execution may expire.
+    ///
+    /// This is a structure-only fixture, for testing the serialization and storage of transactions.
+    /// The execution does not contain a proof, so the transaction is well-formed, but does not pass `VM::verify`.
+    /// As a deployment and a fee must be proven against the `credits.aleo` and program verifying keys,
+    /// only execute transactions without a fee are sampled.
+    pub fn sample_unproven<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample the transitions.
+        let num_transitions = rng.gen_range(1..=MAX_SAMPLE_TRANSITIONS);
+        let transitions = (0..num_transitions).map(|_| Transition::sample(rng)).collect::<Vec<_>>();
+        // Sample the global state root.
+        let global_state_root = N::StateRoot::from(Field::rand(rng));
+        // Sample the expiration height.
+        let valid_until = match rng.gen() {
+            true => Some(rng.gen()),
+            false => None,
+        };
+        // Construct the execution.
+        let execution = Execution::from(transitions.into_iter(), global_state_root, None)
+            .or_halt_with::<N>("Failed to sample an execution")
+            .with_valid_until(valid_until);
+        Self::from_execution(execution, None).or_halt_with::<N>("Failed to sample a transaction")
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N: Network> arbitrary::Arbitrary<'a> for Transaction<N> {
+    /// Returns an unproven transaction, which is sampled from an RNG that is seeded by the unstructured bytes
+    /// (see `Transaction::sample_unproven`).
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+        Ok(Self::sample_unproven(&mut ChaChaRng::from_seed(u.arbitrary()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 25;
+
+    #[test]
+    fn test_sample_unproven() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random transaction.
+            let expected = Transaction::<CurrentNetwork>::sample_unproven(&mut rng);
+            // Ensure the transaction is an execution, whose ID is computed from the execution.
+            assert_eq!(expected, Transaction::from_execution(expected.execution().unwrap().clone(), None)?);
+
+            // Ensure the transaction round trips through its string and byte representations.
+            assert_eq!(expected, Transaction::from_str(&expected.to_string())?);
+            assert_eq!(expected, Transaction::read_le(&expected.to_bytes_le()?[..])?);
+        }
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@ use super::*;
 impl<N: Network> FromBytes for ConfirmedTransaction<N> {
     /// Reads the confirmed transaction from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
         let variant = u8::read_le(&mut reader)?;
         match variant {
             0 => {
@@ -45,27 +46,37 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 // Return the confirmed transaction.
                 Self::accepted_execute(index, transaction, finalize).map_err(|e| error(e.to_string()))
             }
-            2 => {
+            2 | 4 => {
                 // Read the index.
                 let index = u32::read_le(&mut reader)?;
                 // Read the transaction.
                 let transaction = Transaction::<N>::read_le(&mut reader)?;
                 // Read the rejected deployment.
                 let rejected = Rejected::<N>::read_le(&mut reader)?;
+                // Read the rejection metadata, which the original variant does not have.
+                let metadata = match variant {
+                    2 => RejectionMetadata::from_code(RejectionCode::Unknown),
+                    _ => RejectionMetadata::<N>::read_le(&mut reader)?,
+                };
                 // Return the confirmed transaction.
-                Self::rejected_deploy(index, transaction, rejected).map_err(|e| error(e.to_string()))
+                Self::rejected_deploy(index, transaction, rejected, metadata).map_err(|e| error(e.to_string()))
             }
-            3 => {
+            3 | 5 => {
                 // Read the index.
                 let index = u32::read_le(&mut reader)?;
                 // Read the transaction.
                 let transaction = Transaction::<N>::read_le(&mut reader)?;
                 // Read the rejected execution.
                 let rejected = Rejected::<N>::read_le(&mut reader)?;
+                // Read the rejection metadata, which the original variant does not have.
+                let metadata = match variant {
+                    3 => RejectionMetadata::from_code(RejectionCode::Unknown),
+                    _ => RejectionMetadata::<N>::read_le(&mut reader)?,
+                };
                 // Return the confirmed transaction.
-                Self::rejected_execute(index, transaction, rejected).map_err(|e| error(e.to_string()))
+                Self::rejected_execute(index, transaction, rejected, metadata).map_err(|e| error(e.to_string()))
             }
-            4.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
+            6.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
         }
     }
 }
@@ -98,25 +109,43 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the finalize operations.
                 finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
             }
-            Self::RejectedDeploy(index, transaction, rejected) => {
-                // Write the variant.
-                2u8.write_le(&mut writer)?;
+            Self::RejectedDeploy(index, transaction, rejected, metadata) => {
+                // Write the variant, where a rejection without metadata keeps the original variant.
+                let has_metadata = metadata.is_recorded();
+                match has_metadata {
+                    true => 4u8.write_le(&mut writer)?,
+                    false => 2u8.write_le(&mut writer)?,
+                }
                 // Write the index.
                 index.write_le(&mut writer)?;
                 // Write the transaction.
                 transaction.write_le(&mut writer)?;
                 // Write the rejected deployment.
-                rejected.write_le(&mut writer)
+                rejected.write_le(&mut writer)?;
+                // Write the rejection metadata, if there is any.
+                match has_metadata {
+                    true => metadata.write_le(&mut writer),
+                    false => Ok(()),
+                }
             }
-            Self::RejectedExecute(index, transaction, rejected) => {
-                // Write the variant.
-                3u8.write_le(&mut writer)?;
+            Self::RejectedExecute(index, transaction, rejected, metadata) => {
+                // Write the variant, where a rejection without metadata keeps the original variant.
+                let has_metadata = metadata.is_recorded();
+                match has_metadata {
+                    true => 5u8.write_le(&mut writer)?,
+                    false => 3u8.write_le(&mut writer)?,
+                }
                 // Write the index.
                 index.write_le(&mut writer)?;
                 // Write the transaction.
                 transaction.write_le(&mut writer)?;
                 // Write the rejected execution.
-                rejected.write_le(&mut writer)
+                rejected.write_le(&mut writer)?;
+                // Write the rejection metadata, if there is any.
+                match has_metadata {
+                    true => metadata.write_le(&mut writer),
+                    false => Ok(()),
+                }
             }
         }
     }
@@ -138,4 +167,40 @@ mod tests {
             assert!(ConfirmedTransaction::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_bytes_without_rejection_metadata() {
+        let unknown = RejectionMetadata::from_code(RejectionCode::Unknown);
+
+        for confirmed in crate::block::transactions::confirmed::test_helpers::sample_confirmed_transactions() {
+            // Replace the rejection metadata with the unknown rejection.
+            let (expected, variant, metadata) = match confirmed {
+                ConfirmedTransaction::RejectedDeploy(index, transaction, rejected, metadata) => {
+                    (ConfirmedTransaction::RejectedDeploy(index, transaction, rejected, unknown.clone()), 2, metadata)
+                }
+                ConfirmedTransaction::RejectedExecute(index, transaction, rejected, metadata) => {
+                    (ConfirmedTransaction::RejectedExecute(index, transaction, rejected, unknown.clone()), 3, metadata)
+                }
+                _ => continue,
+            };
+            // Ensure the original variant is used, without the rejection metadata.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected_bytes[0], variant);
+            let bytes_with_metadata = match expected.clone() {
+                ConfirmedTransaction::RejectedDeploy(index, transaction, rejected, _) => {
+                    ConfirmedTransaction::RejectedDeploy(index, transaction, rejected, metadata.clone())
+                }
+                ConfirmedTransaction::RejectedExecute(index, transaction, rejected, _) => {
+                    ConfirmedTransaction::RejectedExecute(index, transaction, rejected, metadata.clone())
+                }
+                _ => unreachable!(),
+            }
+            .to_bytes_le()
+            .unwrap();
+            assert_eq!(bytes_with_metadata[0], variant + 2);
+            assert_eq!(bytes_with_metadata.len(), expected_bytes.len() + metadata.to_bytes_le().unwrap().len());
+            // Check the byte representation.
+            assert_eq!(expected, ConfirmedTransaction::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
 }
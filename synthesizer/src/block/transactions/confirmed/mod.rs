@@ -16,7 +16,7 @@ mod bytes;
 mod serialize;
 mod string;
 
-use crate::block::{rejected::Rejected, FinalizeOperation, Transaction};
+use crate::block::{rejected::Rejected, FinalizeOperation, RejectionCode, RejectionMetadata, Transaction};
 use console::network::prelude::*;
 
 pub type NumFinalizeSize = u16;
@@ -28,10 +28,10 @@ pub enum ConfirmedTransaction<N: Network> {
     AcceptedDeploy(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
     /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations)`.
     AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
-    /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment)`.
-    RejectedDeploy(u32, Transaction<N>, Rejected<N>),
-    /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution)`.
-    RejectedExecute(u32, Transaction<N>, Rejected<N>),
+    /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment, metadata)`.
+    RejectedDeploy(u32, Transaction<N>, Rejected<N>, RejectionMetadata<N>),
+    /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution, metadata)`.
+    RejectedExecute(u32, Transaction<N>, Rejected<N>, RejectionMetadata<N>),
 }
 
 impl<N: Network> ConfirmedTransaction<N> {
@@ -41,25 +41,31 @@ impl<N: Network> ConfirmedTransaction<N> {
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
     ) -> Result<Self> {
-        // Retrieve the program from the deployment, and ensure the transaction is a deploy transaction.
-        let program = match &transaction {
-            Transaction::Deploy(_, _, deployment, _) => deployment.program(),
+        // Retrieve the deployment, and ensure the transaction is a deploy transaction.
+        let deployment = match &transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment,
             Transaction::Execute(..) | Transaction::Fee(..) => {
                 bail!("Transaction '{}' is not a deploy transaction", transaction.id())
             }
         };
-        // Ensure the number of program mappings matches the number of finalize operations.
-        if program.mappings().len() != finalize_operations.len() {
+        // Compute the expected number of finalize operations, including the admin registration if the program opts in.
+        let num_mappings = deployment.program().mappings().len();
+        let num_expected = num_mappings + usize::from(deployment.registers_admin());
+        // Ensure the expected number of finalize operations matches the number of finalize operations.
+        if num_expected != finalize_operations.len() {
             bail!(
-                "The number of program mappings ({}) does not match the nubmer of finalize operations ({})",
-                program.mappings().len(),
+                "The number of finalize operations ({}) does not match the expected number ({num_expected})",
                 finalize_operations.len()
             )
         }
         // Ensure the finalize operations contain the correct types.
-        for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an initialize mapping.
-            if !matches!(operation, FinalizeOperation::InitializeMapping(..)) {
+        for (index, operation) in finalize_operations.iter().enumerate() {
+            // Ensure the finalize operation is an initialize mapping, followed by the admin registration.
+            let is_valid = match index == num_mappings {
+                true => matches!(operation, FinalizeOperation::RegisterAdmin(..)),
+                false => matches!(operation, FinalizeOperation::InitializeMapping(..)),
+            };
+            if !is_valid {
                 bail!("Transaction '{}' (deploy) contains an invalid finalize operation type", transaction.id())
             }
         }
@@ -75,12 +81,15 @@ impl<N: Network> ConfirmedTransaction<N> {
     ) -> Result<Self> {
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an insert or update key-value operation.
+            // Ensure the finalize operation is an insert or update key-value operation, or a replace mapping operation.
             match operation {
-                FinalizeOperation::InsertKeyValue(..) | FinalizeOperation::UpdateKeyValue(..) => (),
+                FinalizeOperation::InsertKeyValue(..)
+                | FinalizeOperation::UpdateKeyValue(..)
+                | FinalizeOperation::ReplaceMapping(..) => (),
                 FinalizeOperation::InitializeMapping(..)
                 | FinalizeOperation::RemoveMapping(..)
-                | FinalizeOperation::RemoveKeyValue(..) => {
+                | FinalizeOperation::RemoveKeyValue(..)
+                | FinalizeOperation::RegisterAdmin(..) => {
                     bail!("Transaction '{}' (execute) contains an invalid finalize operation type", transaction.id())
                 }
             }
@@ -93,23 +102,33 @@ impl<N: Network> ConfirmedTransaction<N> {
     }
 
     /// Returns a new instance of a rejected deploy transaction.
-    pub fn rejected_deploy(index: u32, transaction: Transaction<N>, rejected: Rejected<N>) -> Result<Self> {
+    pub fn rejected_deploy(
+        index: u32,
+        transaction: Transaction<N>,
+        rejected: Rejected<N>,
+        metadata: RejectionMetadata<N>,
+    ) -> Result<Self> {
         ensure!(rejected.is_deployment(), "Rejected deployment is not a deployment");
 
         // Ensure the transaction is a fee transaction.
         match transaction.is_fee() {
-            true => Ok(Self::RejectedDeploy(index, transaction, rejected)),
+            true => Ok(Self::RejectedDeploy(index, transaction, rejected, metadata)),
             false => bail!("Transaction '{}' is not a fee transaction", transaction.id()),
         }
     }
 
     /// Returns a new instance of a rejected execute transaction.
-    pub fn rejected_execute(index: u32, transaction: Transaction<N>, rejected: Rejected<N>) -> Result<Self> {
+    pub fn rejected_execute(
+        index: u32,
+        transaction: Transaction<N>,
+        rejected: Rejected<N>,
+        metadata: RejectionMetadata<N>,
+    ) -> Result<Self> {
         ensure!(rejected.is_execution(), "Rejected execution is not an execution");
 
         // Ensure the transaction is a fee transaction.
         match transaction.is_fee() {
-            true => Ok(Self::RejectedExecute(index, transaction, rejected)),
+            true => Ok(Self::RejectedExecute(index, transaction, rejected, metadata)),
             false => bail!("Transaction '{}' is not a fee transaction", transaction.id()),
         }
     }
@@ -146,8 +165,8 @@ impl<N: Network> ConfirmedTransaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
             Self::AcceptedExecute(_, transaction, _) => transaction,
-            Self::RejectedDeploy(_, transaction, _) => transaction,
-            Self::RejectedExecute(_, transaction, _) => transaction,
+            Self::RejectedDeploy(_, transaction, ..) => transaction,
+            Self::RejectedExecute(_, transaction, ..) => transaction,
         }
     }
 
@@ -156,8 +175,8 @@ impl<N: Network> ConfirmedTransaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
             Self::AcceptedExecute(_, transaction, _) => transaction,
-            Self::RejectedDeploy(_, transaction, _) => transaction,
-            Self::RejectedExecute(_, transaction, _) => transaction,
+            Self::RejectedDeploy(_, transaction, ..) => transaction,
+            Self::RejectedExecute(_, transaction, ..) => transaction,
         }
     }
 
@@ -177,6 +196,14 @@ impl<N: Network> ConfirmedTransaction<N> {
             Self::RejectedDeploy(..) | Self::RejectedExecute(..) => None,
         }
     }
+
+    /// Returns the rejection metadata, if the confirmed transaction is rejected.
+    pub fn rejection_metadata(&self) -> Option<&RejectionMetadata<N>> {
+        match self {
+            Self::AcceptedDeploy(..) | Self::AcceptedExecute(..) => None,
+            Self::RejectedDeploy(.., metadata) | Self::RejectedExecute(.., metadata) => Some(metadata),
+        }
+    }
 }
 
 impl<N: Network> Deref for ConfirmedTransaction<N> {
@@ -220,8 +247,11 @@ pub(crate) mod test_helpers {
         // Extract the rejected deployment.
         let rejected = crate::rejected::test_helpers::sample_rejected_deployment(rng);
 
+        // Sample the rejection metadata.
+        let metadata = RejectionMetadata::from_code(RejectionCode::DeploymentFailed);
+
         // Return the confirmed transaction.
-        ConfirmedTransaction::rejected_deploy(index, fee_transaction, rejected).unwrap()
+        ConfirmedTransaction::rejected_deploy(index, fee_transaction, rejected, metadata).unwrap()
     }
 
     /// Samples a rejected execute transaction at the given index.
@@ -232,8 +262,11 @@ pub(crate) mod test_helpers {
         // Extract the rejected execution.
         let rejected = crate::rejected::test_helpers::sample_rejected_execution(rng);
 
+        // Sample the rejection metadata.
+        let metadata = crate::block::transactions::rejection::test_helpers::sample_rejection_metadata().pop().unwrap();
+
         // Return the confirmed transaction.
-        ConfirmedTransaction::rejected_execute(index, fee_transaction, rejected).unwrap()
+        ConfirmedTransaction::rejected_execute(index, fee_transaction, rejected, metadata).unwrap()
     }
 
     /// Sample a list of randomly confirmed transactions.
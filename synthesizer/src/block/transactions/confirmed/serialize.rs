@@ -39,22 +39,32 @@ impl<N: Network> Serialize for ConfirmedTransaction<N> {
                     object.serialize_field("finalize", finalize_operations)?;
                     object.end()
                 }
-                Self::RejectedDeploy(index, transaction, rejected_deployment) => {
-                    let mut object = serializer.serialize_struct("ConfirmedTransaction", 5)?;
+                Self::RejectedDeploy(index, transaction, rejected_deployment, metadata) => {
+                    let num_fields = 5 + usize::from(metadata.is_recorded());
+                    let mut object = serializer.serialize_struct("ConfirmedTransaction", num_fields)?;
                     object.serialize_field("status", "rejected")?;
                     object.serialize_field("type", "deploy")?;
                     object.serialize_field("index", index)?;
                     object.serialize_field("transaction", transaction)?;
                     object.serialize_field("rejected", &rejected_deployment)?;
+                    // Serialize the rejection metadata, if it is recorded.
+                    if metadata.is_recorded() {
+                        object.serialize_field("metadata", metadata)?;
+                    }
                     object.end()
                 }
-                Self::RejectedExecute(index, transaction, rejected_execution) => {
-                    let mut object = serializer.serialize_struct("ConfirmedTransaction", 5)?;
+                Self::RejectedExecute(index, transaction, rejected_execution, metadata) => {
+                    let num_fields = 5 + usize::from(metadata.is_recorded());
+                    let mut object = serializer.serialize_struct("ConfirmedTransaction", num_fields)?;
                     object.serialize_field("status", "rejected")?;
                     object.serialize_field("type", "execute")?;
                     object.serialize_field("index", index)?;
                     object.serialize_field("transaction", transaction)?;
                     object.serialize_field("rejected", &rejected_execution)?;
+                    // Serialize the rejection metadata, if it is recorded.
+                    if metadata.is_recorded() {
+                        object.serialize_field("metadata", metadata)?;
+                    }
                     object.end()
                 }
             },
@@ -97,14 +107,24 @@ impl<'de, N: Network> Deserialize<'de> for ConfirmedTransaction<N> {
                     (Some("rejected"), Some("deploy")) => {
                         // Parse the rejected deployment.
                         let rejected: Rejected<N> = DeserializeExt::take_from_value::<D>(&mut object, "rejected")?;
+                        // Parse the rejection metadata, if it is recorded.
+                        let metadata = match object.get("metadata").is_some() {
+                            true => DeserializeExt::take_from_value::<D>(&mut object, "metadata")?,
+                            false => RejectionMetadata::from_code(RejectionCode::Unknown),
+                        };
                         // Return the rejected deploy transaction.
-                        Self::rejected_deploy(index, transaction, rejected).map_err(de::Error::custom)
+                        Self::rejected_deploy(index, transaction, rejected, metadata).map_err(de::Error::custom)
                     }
                     (Some("rejected"), Some("execute")) => {
                         // Parse the rejected execution.
                         let rejected: Rejected<N> = DeserializeExt::take_from_value::<D>(&mut object, "rejected")?;
+                        // Parse the rejection metadata, if it is recorded.
+                        let metadata = match object.get("metadata").is_some() {
+                            true => DeserializeExt::take_from_value::<D>(&mut object, "metadata")?,
+                            false => RejectionMetadata::from_code(RejectionCode::Unknown),
+                        };
                         // Return the rejected execute transaction.
-                        Self::rejected_execute(index, transaction, rejected).map_err(de::Error::custom)
+                        Self::rejected_execute(index, transaction, rejected, metadata).map_err(de::Error::custom)
                     }
                     _ => Err(de::Error::custom("Invalid confirmed transaction type")),
                 }
@@ -160,6 +180,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serde_json_without_rejection_metadata() {
+        for transaction in crate::block::transactions::confirmed::test_helpers::sample_confirmed_transactions() {
+            // Replace the rejection metadata with the unknown rejection.
+            let unknown = RejectionMetadata::from_code(RejectionCode::Unknown);
+            let expected = match transaction {
+                ConfirmedTransaction::RejectedDeploy(index, transaction, rejected, _) => {
+                    ConfirmedTransaction::RejectedDeploy(index, transaction, rejected, unknown)
+                }
+                ConfirmedTransaction::RejectedExecute(index, transaction, rejected, _) => {
+                    ConfirmedTransaction::RejectedExecute(index, transaction, rejected, unknown)
+                }
+                _ => continue,
+            };
+            // Ensure the rejection metadata is omitted, and the confirmed transaction is recovered without it.
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert!(!candidate_string.contains("\"metadata\""));
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
     #[test]
     fn test_bincode() {
         for transaction in crate::block::transactions::confirmed::test_helpers::sample_confirmed_transactions() {
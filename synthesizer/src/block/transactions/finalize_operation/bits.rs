@@ -75,7 +75,21 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            5.. => bail!("Invalid finalize operation variant '{variant}'"),
+            5 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::ReplaceMapping(mapping_id))
+            }
+            6 => {
+                // Read the program ID.
+                let program_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the admin.
+                let admin = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::RegisterAdmin(program_id, admin))
+            }
+            7.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 
@@ -139,7 +153,21 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            5.. => bail!("Invalid finalize operation variant '{variant}'"),
+            5 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::ReplaceMapping(mapping_id))
+            }
+            6 => {
+                // Read the program ID.
+                let program_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the admin.
+                let admin = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::RegisterAdmin(program_id, admin))
+            }
+            7.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 }
@@ -205,6 +233,26 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 ]
                 .concat()
             }
+            Self::ReplaceMapping(mapping_id) => {
+                vec![
+                    // Write the variant.
+                    5u8.to_bits_le(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_le(),
+                ]
+                .concat()
+            }
+            Self::RegisterAdmin(program_id, admin) => {
+                vec![
+                    // Write the variant.
+                    6u8.to_bits_le(),
+                    // Write the program ID.
+                    program_id.to_bits_le(),
+                    // Write the admin.
+                    admin.to_bits_le(),
+                ]
+                .concat()
+            }
         }
     }
 
@@ -268,6 +316,26 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 ]
                 .concat()
             }
+            Self::ReplaceMapping(mapping_id) => {
+                vec![
+                    // Write the variant.
+                    5u8.to_bits_be(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_be(),
+                ]
+                .concat()
+            }
+            Self::RegisterAdmin(program_id, admin) => {
+                vec![
+                    // Write the variant.
+                    6u8.to_bits_be(),
+                    // Write the program ID.
+                    program_id.to_bits_be(),
+                    // Write the admin.
+                    admin.to_bits_be(),
+                ]
+                .concat()
+            }
         }
     }
 }
@@ -63,7 +63,21 @@ impl<N: Network> FromBytes for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            5.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
+            5 => {
+                // Read the mapping ID.
+                let mapping_id = Field::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::ReplaceMapping(mapping_id))
+            }
+            6 => {
+                // Read the program ID.
+                let program_id = Field::read_le(&mut reader)?;
+                // Read the admin.
+                let admin = Field::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::RegisterAdmin(program_id, admin))
+            }
+            7.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
         }
     }
 }
@@ -114,6 +128,20 @@ impl<N: Network> ToBytes for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_le(&mut writer)?;
             }
+            Self::ReplaceMapping(mapping_id) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the mapping ID.
+                mapping_id.write_le(&mut writer)?;
+            }
+            Self::RegisterAdmin(program_id, admin) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the program ID.
+                program_id.write_le(&mut writer)?;
+                // Write the admin.
+                admin.write_le(&mut writer)?;
+            }
         }
         Ok(())
     }
@@ -35,6 +35,10 @@ pub enum FinalizeOperation<N: Network> {
     RemoveKeyValue(Field<N>, u64),
     /// Removes a mapping from the program tree, as (`mapping ID`).
     RemoveMapping(Field<N>),
+    /// Replaces a mapping in the program tree with an empty mapping, as (`mapping ID`).
+    ReplaceMapping(Field<N>),
+    /// Registers the admin of a program, as (`program ID`, `admin`).
+    RegisterAdmin(Field<N>, Field<N>),
 }
 
 #[cfg(test)]
@@ -69,6 +73,16 @@ pub(crate) mod test_helpers {
         FinalizeOperation::RemoveMapping(Uniform::rand(rng))
     }
 
+    /// Samples a random `ReplaceMapping`.
+    pub(crate) fn sample_replace_mapping(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        FinalizeOperation::ReplaceMapping(Uniform::rand(rng))
+    }
+
+    /// Samples a random `RegisterAdmin`.
+    pub(crate) fn sample_register_admin(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        FinalizeOperation::RegisterAdmin(Uniform::rand(rng), Uniform::rand(rng))
+    }
+
     /// Samples a list of random `FinalizeOperation`.
     pub(crate) fn sample_finalize_operations() -> Vec<FinalizeOperation<CurrentNetwork>> {
         let rng = &mut TestRng::default();
@@ -79,11 +93,15 @@ pub(crate) mod test_helpers {
             sample_update_key_value(rng),
             sample_remove_key_value(rng),
             sample_remove_mapping(rng),
+            sample_replace_mapping(rng),
+            sample_register_admin(rng),
             sample_initialize_mapping(rng),
             sample_insert_key_value(rng),
             sample_update_key_value(rng),
             sample_remove_key_value(rng),
             sample_remove_mapping(rng),
+            sample_replace_mapping(rng),
+            sample_register_admin(rng),
         ]
     }
 }
@@ -50,6 +50,15 @@ impl<N: Network> Serialize for FinalizeOperation<N> {
                         operation.serialize_field("type", "remove_mapping")?;
                         operation.serialize_field("mapping_id", mapping_id)?;
                     }
+                    Self::ReplaceMapping(mapping_id) => {
+                        operation.serialize_field("type", "replace_mapping")?;
+                        operation.serialize_field("mapping_id", mapping_id)?;
+                    }
+                    Self::RegisterAdmin(program_id, admin) => {
+                        operation.serialize_field("type", "register_admin")?;
+                        operation.serialize_field("program_id", program_id)?;
+                        operation.serialize_field("admin", admin)?;
+                    }
                 }
                 operation.end()
             }
@@ -108,6 +117,20 @@ impl<'de, N: Network> Deserialize<'de> for FinalizeOperation<N> {
                         // Return the operation.
                         Self::RemoveMapping(mapping_id)
                     }
+                    Some("replace_mapping") => {
+                        // Deserialize the mapping ID.
+                        let mapping_id = DeserializeExt::take_from_value::<D>(&mut operation, "mapping_id")?;
+                        // Return the operation.
+                        Self::ReplaceMapping(mapping_id)
+                    }
+                    Some("register_admin") => {
+                        // Deserialize the program ID.
+                        let program_id = DeserializeExt::take_from_value::<D>(&mut operation, "program_id")?;
+                        // Deserialize the admin.
+                        let admin = DeserializeExt::take_from_value::<D>(&mut operation, "admin")?;
+                        // Return the operation.
+                        Self::RegisterAdmin(program_id, admin)
+                    }
                     _ => return Err(de::Error::custom("Invalid finalize operation type")),
                 };
                 // Return the operation.
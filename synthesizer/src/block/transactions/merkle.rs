@@ -17,8 +17,14 @@ use super::*;
 impl<N: Network> Transactions<N> {
     /// Returns the finalize root of the transactions.
     pub fn to_finalize_root(&self) -> Result<Field<N>> {
+        self.to_finalize_root_with_ratifications(&[])
+    }
+
+    /// Returns the finalize root of the transactions, followed by the given finalize operations
+    /// of the custom ratifications in the block.
+    pub fn to_finalize_root_with_ratifications(&self, operations: &[FinalizeOperation<N>]) -> Result<Field<N>> {
         // Prepare the leaves.
-        let leaves = self.finalize_operations().map(|op| op.to_bits_le());
+        let leaves = self.finalize_operations().chain(operations).map(|op| op.to_bits_le());
         // Compute the finalize tree.
         let tree = N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves.collect::<Vec<_>>())?;
         // Return the finalize root.
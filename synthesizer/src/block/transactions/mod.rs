@@ -18,6 +18,9 @@ pub use confirmed::*;
 pub mod rejected;
 pub use rejected::*;
 
+pub mod rejection;
+pub use rejection::*;
+
 mod finalize_operation;
 pub use finalize_operation::*;
 
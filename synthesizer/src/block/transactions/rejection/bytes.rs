@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for RejectionMetadata<N> {
+    /// Reads the rejection metadata from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid rejection metadata version"));
+        }
+
+        // Read the error code.
+        let code = RejectionCode::from_u8(u8::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
+        // Read the aborted command.
+        let command = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => {
+                Some((ProgramID::read_le(&mut reader)?, Identifier::read_le(&mut reader)?, u32::read_le(&mut reader)?))
+            }
+            variant => return Err(error(format!("Invalid rejection command variant '{variant}'"))),
+        };
+        // Read the mapping key.
+        let mapping_key = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some((Identifier::read_le(&mut reader)?, Plaintext::read_le(&mut reader)?)),
+            variant => return Err(error(format!("Invalid rejection mapping key variant '{variant}'"))),
+        };
+        // Return the rejection metadata.
+        Self::new(code, command, mapping_key).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for RejectionMetadata<N> {
+    /// Writes the rejection metadata to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the error code.
+        self.code.to_u8().write_le(&mut writer)?;
+        // Write the aborted command.
+        match &self.command {
+            None => 0u8.write_le(&mut writer)?,
+            Some((program_id, function_name, index)) => {
+                1u8.write_le(&mut writer)?;
+                program_id.write_le(&mut writer)?;
+                function_name.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+            }
+        }
+        // Write the mapping key.
+        match &self.mapping_key {
+            None => 0u8.write_le(&mut writer),
+            Some((mapping_name, key)) => {
+                1u8.write_le(&mut writer)?;
+                mapping_name.write_le(&mut writer)?;
+                key.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        for expected in crate::block::transactions::rejection::test_helpers::sample_rejection_metadata() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, RejectionMetadata::read_le(&expected_bytes[..]).unwrap());
+            assert!(RejectionMetadata::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+}
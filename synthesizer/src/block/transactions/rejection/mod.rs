@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID},
+};
+
+/// The error code of a rejected transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RejectionCode {
+    /// The reason for the rejection is unknown.
+    Unknown,
+    /// The deployment failed to finalize, for example, as the program already exists.
+    DeploymentFailed,
+    /// The execution failed to finalize, before a command was evaluated.
+    ExecutionFailed,
+    /// A command in a finalize scope failed.
+    CommandFailed,
+    /// A command in a finalize scope halted.
+    CommandHalted,
+    /// The finalize scopes exceeded the step limit.
+    StepLimitExceeded,
+    /// The execution expired, as the block height exceeds its expiration height.
+    Expired,
+}
+
+impl RejectionCode {
+    /// Returns the rejection code for the given `u8`.
+    pub fn from_u8(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::DeploymentFailed),
+            2 => Ok(Self::ExecutionFailed),
+            3 => Ok(Self::CommandFailed),
+            4 => Ok(Self::CommandHalted),
+            5 => Ok(Self::StepLimitExceeded),
+            6 => Ok(Self::Expired),
+            7.. => bail!("Invalid rejection code '{code}'"),
+        }
+    }
+
+    /// Returns the `u8` value of the rejection code.
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Self::Unknown => 0,
+            Self::DeploymentFailed => 1,
+            Self::ExecutionFailed => 2,
+            Self::CommandFailed => 3,
+            Self::CommandHalted => 4,
+            Self::StepLimitExceeded => 5,
+            Self::Expired => 6,
+        }
+    }
+
+    /// Returns the name of the rejection code.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::DeploymentFailed => "deployment_failed",
+            Self::ExecutionFailed => "execution_failed",
+            Self::CommandFailed => "command_failed",
+            Self::CommandHalted => "command_halted",
+            Self::StepLimitExceeded => "step_limit_exceeded",
+            Self::Expired => "expired",
+        }
+    }
+
+    /// Returns the rejection code for the given name.
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "unknown" => Ok(Self::Unknown),
+            "deployment_failed" => Ok(Self::DeploymentFailed),
+            "execution_failed" => Ok(Self::ExecutionFailed),
+            "command_failed" => Ok(Self::CommandFailed),
+            "command_halted" => Ok(Self::CommandHalted),
+            "step_limit_exceeded" => Ok(Self::StepLimitExceeded),
+            "expired" => Ok(Self::Expired),
+            _ => bail!("Invalid rejection code '{name}'"),
+        }
+    }
+}
+
+/// The metadata of a rejected transaction, which explains why the transaction was rejected.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RejectionMetadata<N: Network> {
+    /// The error code.
+    code: RejectionCode,
+    /// The program ID, function name, and index of the aborted finalize command, if a command aborted.
+    command: Option<(ProgramID<N>, Identifier<N>, u32)>,
+    /// The mapping name and key accessed by the aborted finalize command, if the command accesses a mapping.
+    mapping_key: Option<(Identifier<N>, Plaintext<N>)>,
+}
+
+impl<N: Network> RejectionMetadata<N> {
+    /// Initializes the rejection metadata.
+    pub fn new(
+        code: RejectionCode,
+        command: Option<(ProgramID<N>, Identifier<N>, u32)>,
+        mapping_key: Option<(Identifier<N>, Plaintext<N>)>,
+    ) -> Result<Self> {
+        // Ensure the mapping key is only given with the aborted command.
+        ensure!(
+            command.is_some() || mapping_key.is_none(),
+            "The rejection metadata has a mapping key without a command"
+        );
+        // Ensure the command is only given with a known error code.
+        ensure!(code != RejectionCode::Unknown || command.is_none(), "The rejection metadata has an unknown error code");
+        Ok(Self { code, command, mapping_key })
+    }
+
+    /// Initializes the rejection metadata for the given error code, without an aborted command.
+    pub const fn from_code(code: RejectionCode) -> Self {
+        Self { code, command: None, mapping_key: None }
+    }
+
+    /// Returns the error code.
+    pub const fn code(&self) -> RejectionCode {
+        self.code
+    }
+
+    /// Returns `true` if the rejection is recorded, i.e. the error code is not `Unknown`.
+    ///
+    /// Note: The transactions that were rejected before the rejection metadata was recorded have the `Unknown` code.
+    pub fn is_recorded(&self) -> bool {
+        self.code != RejectionCode::Unknown
+    }
+
+    /// Returns the program ID, function name, and index of the aborted finalize command, if a command aborted.
+    pub fn command(&self) -> Option<(&ProgramID<N>, &Identifier<N>, u32)> {
+        self.command.as_ref().map(|(program_id, function_name, index)| (program_id, function_name, *index))
+    }
+
+    /// Returns the mapping name and key accessed by the aborted finalize command, if the command accesses a mapping.
+    pub fn mapping_key(&self) -> Option<(&Identifier<N>, &Plaintext<N>)> {
+        self.mapping_key.as_ref().map(|(mapping_name, key)| (mapping_name, key))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a list of rejection metadata.
+    pub(crate) fn sample_rejection_metadata() -> Vec<RejectionMetadata<CurrentNetwork>> {
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah").unwrap();
+
+        vec![
+            RejectionMetadata::from_code(RejectionCode::Unknown),
+            RejectionMetadata::from_code(RejectionCode::DeploymentFailed),
+            RejectionMetadata::from_code(RejectionCode::Expired),
+            RejectionMetadata::new(RejectionCode::CommandHalted, Some((program_id, function_name, 0)), None).unwrap(),
+            RejectionMetadata::new(
+                RejectionCode::CommandFailed,
+                Some((program_id, function_name, 3)),
+                Some((mapping_name, key)),
+            )
+            .unwrap(),
+        ]
+    }
+}
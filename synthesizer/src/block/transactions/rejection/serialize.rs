@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for RejectionMetadata<N> {
+    /// Serializes the rejection metadata into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let num_fields =
+                    1 + 3 * usize::from(self.command.is_some()) + 2 * usize::from(self.mapping_key.is_some());
+                let mut metadata = serializer.serialize_struct("RejectionMetadata", num_fields)?;
+                metadata.serialize_field("code", self.code.name())?;
+                if let Some((program_id, function_name, index)) = &self.command {
+                    metadata.serialize_field("program_id", program_id)?;
+                    metadata.serialize_field("function_name", function_name)?;
+                    metadata.serialize_field("index", index)?;
+                }
+                if let Some((mapping_name, key)) = &self.mapping_key {
+                    metadata.serialize_field("mapping_name", mapping_name)?;
+                    metadata.serialize_field("key", key)?;
+                }
+                metadata.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RejectionMetadata<N> {
+    /// Deserializes the rejection metadata from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the rejection metadata from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+
+                // Parse the error code.
+                let code: String = DeserializeExt::take_from_value::<D>(&mut object, "code")?;
+                let code = RejectionCode::from_name(&code).map_err(de::Error::custom)?;
+                // Parse the aborted command, if it exists.
+                let command = match object.get("program_id").is_some() {
+                    true => Some((
+                        DeserializeExt::take_from_value::<D>(&mut object, "program_id")?,
+                        DeserializeExt::take_from_value::<D>(&mut object, "function_name")?,
+                        DeserializeExt::take_from_value::<D>(&mut object, "index")?,
+                    )),
+                    false => None,
+                };
+                // Parse the mapping key, if it exists.
+                let mapping_key = match object.get("mapping_name").is_some() {
+                    true => Some((
+                        DeserializeExt::take_from_value::<D>(&mut object, "mapping_name")?,
+                        DeserializeExt::take_from_value::<D>(&mut object, "key")?,
+                    )),
+                    false => None,
+                };
+                // Return the rejection metadata.
+                Self::new(code, command, mapping_key).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "rejection metadata"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_serde_json<
+        T: Serialize + for<'a> Deserialize<'a> + Debug + Display + PartialEq + Eq + FromStr + ToBytes + FromBytes,
+    >(
+        expected: T,
+    ) {
+        // Serialize
+        let expected_string = expected.to_string();
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        let candidate = serde_json::from_str::<T>(&candidate_string).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_string, candidate_string);
+        assert_eq!(expected_string, candidate.to_string());
+
+        // Deserialize
+        assert_eq!(expected, T::from_str(&expected_string).unwrap_or_else(|_| panic!("FromStr: {expected_string}")));
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+    }
+
+    fn check_bincode<
+        T: Serialize + for<'a> Deserialize<'a> + Debug + Display + PartialEq + Eq + FromStr + ToBytes + FromBytes,
+    >(
+        expected: T,
+    ) {
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, T::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+    }
+
+    #[test]
+    fn test_serde_json() {
+        for metadata in crate::block::transactions::rejection::test_helpers::sample_rejection_metadata() {
+            check_serde_json(metadata);
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for metadata in crate::block::transactions::rejection::test_helpers::sample_rejection_metadata() {
+            check_bincode(metadata);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Transition<N> {
+    /// Returns the outputs of the transition that belong to the given view key, along with their output indices.
+    ///
+    /// Records owned by the view key are decrypted into `Value::Record`.
+    /// If the view key belongs to the caller of the transition, which is checked against the transition commitment,
+    /// then the private outputs are also decrypted into `Value::Plaintext`. All other outputs are skipped.
+    pub fn decrypt_outputs(&self, view_key: &ViewKey<N>) -> Result<Vec<(usize, Value<N>)>> {
+        // Compute the candidate transition view key as `view_key * tpk`.
+        let tvk = (self.tpk * **view_key).to_x_coordinate();
+        // Check if the view key belongs to the caller, as `tcm == Hash(tvk)`.
+        let is_caller = N::hash_psd2(&[tvk])? == self.tcm;
+
+        // If the view key belongs to the caller, compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = match is_caller {
+            true => Some(N::hash_bhp1024(
+                &(U16::<N>::new(N::ID), self.program_id.name(), self.program_id.network(), self.function_name)
+                    .to_bits_le(),
+            )?),
+            false => None,
+        };
+
+        // Compute the x-coordinate of the address, which is used to check record ownership.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+
+        let mut outputs = Vec::new();
+        for (index, output) in self.outputs.iter().enumerate() {
+            match (output, function_id) {
+                // Decrypt the private output, if the view key belongs to the caller.
+                (Output::Private(_, Some(ciphertext)), Some(function_id)) => {
+                    // Construct the (console) output index as a field element.
+                    let output_index = Field::from_u16(u16::try_from(self.inputs.len() + index)?);
+                    // Compute the output view key as `Hash(function ID || tvk || index)`.
+                    let output_view_key = N::hash_psd4(&[function_id, tvk, output_index])?;
+                    // Decrypt the private output.
+                    outputs.push((index, Value::Plaintext(ciphertext.decrypt_symmetric(output_view_key)?)));
+                }
+                // Decrypt the record, if it is owned by the view key.
+                (Output::Record(_, _, Some(record)), _) | (Output::AuditedRecord(_, _, Some((record, _))), _)
+                    if record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) =>
+                {
+                    outputs.push((index, Value::Record(record.decrypt(view_key)?)));
+                }
+                _ => (),
+            }
+        }
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::{Entry, Literal, Owner, PlaintextType, ValueType},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a transition with a private output and a record output owned by the given address.
+    fn sample_transition(
+        private_key: &PrivateKey<CurrentNetwork>,
+        owner: Address<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Result<(Transition<CurrentNetwork>, Value<CurrentNetwork>, Value<CurrentNetwork>)> {
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let function_name = Identifier::from_str("mint")?;
+
+        // Sign a request with one public input.
+        let input_types = [ValueType::from_str("u64.public")?];
+        let request = Request::sign(private_key, program_id, function_name, ["100u64"].into_iter(), &input_types, rng)?;
+
+        // Prepare a private output.
+        let private_output = Value::<CurrentNetwork>::from_str("100u64")?;
+        // Prepare a record output, with the nonce derived from the transition view key.
+        let nonce = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::to_nonce(request.tvk(), 2)?;
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            Owner::Private(Plaintext::from(Literal::Address(owner))),
+            [(Identifier::from_str("amount")?, Entry::Private(Plaintext::from_str("100u64")?))].into_iter().collect(),
+            nonce,
+        )?;
+        let record_output = Value::Record(record);
+
+        // Construct the response.
+        let output_types =
+            [ValueType::Private(PlaintextType::from_str("u64")?), ValueType::Record(Identifier::from_str("token")?)];
+        let output_registers = [Some(Register::Locator(1)), Some(Register::Locator(2))];
+        let response = Response::new(
+            request.network_id(),
+            &program_id,
+            &function_name,
+            request.inputs().len(),
+            request.tvk(),
+            request.tcm(),
+            vec![private_output.clone(), record_output.clone()],
+            &output_types,
+            &output_registers,
+            None,
+        )?;
+
+        // Construct the transition.
+        let transition = Transition::from(&request, &response, None, &output_types, &output_registers, None)?;
+        Ok((transition, private_output, record_output))
+    }
+
+    #[test]
+    fn test_decrypt_outputs() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the caller and the recipient.
+        let caller = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let caller_view_key = ViewKey::try_from(&caller)?;
+        let recipient = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let recipient_view_key = ViewKey::try_from(&recipient)?;
+        let stranger_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        // Sample a transition from the caller, with a record for the recipient.
+        let (transition, private_output, record_output) =
+            sample_transition(&caller, Address::try_from(&recipient)?, rng)?;
+
+        // Ensure the caller recovers only the private output.
+        assert_eq!(transition.decrypt_outputs(&caller_view_key)?, vec![(0, private_output)]);
+        // Ensure the recipient recovers only the record.
+        assert_eq!(transition.decrypt_outputs(&recipient_view_key)?, vec![(1, record_output)]);
+        // Ensure a stranger recovers nothing.
+        assert!(transition.decrypt_outputs(&stranger_view_key)?.is_empty());
+
+        // Sample a transition from the caller, with a record for the caller.
+        let (transition, private_output, record_output) = sample_transition(&caller, Address::try_from(&caller)?, rng)?;
+
+        // Ensure the caller recovers both outputs.
+        assert_eq!(transition.decrypt_outputs(&caller_view_key)?, vec![(0, private_output), (1, record_output)]);
+        Ok(())
+    }
+}
@@ -19,17 +19,22 @@ pub mod output;
 pub use output::Output;
 
 mod bytes;
+mod decrypt;
 mod merkle;
+#[cfg(any(test, feature = "arbitrary"))]
+mod sample;
 mod serialize;
 mod string;
 
 use console::{
+    account::{Address, ViewKey},
     network::prelude::*,
     program::{
         Ciphertext,
         Identifier,
         InputID,
         OutputID,
+        Plaintext,
         ProgramID,
         Record,
         Register,
@@ -42,7 +47,7 @@ use console::{
         ValueType,
         TRANSITION_DEPTH,
     },
-    types::{Field, Group},
+    types::{Field, Group, U16},
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -84,12 +89,16 @@ impl<N: Network> Transition<N> {
     }
 
     /// Initializes a new transition from a request and response.
+    ///
+    /// If the program declares an auditor, each record output is an audited record,
+    /// which contains the record ciphertext for the owner and for the auditor.
     pub fn from(
         request: &Request<N>,
         response: &Response<N>,
         finalize: Option<Vec<Value<N>>>,
         output_types: &[ValueType<N>],
         output_registers: &[Option<Register<N>>],
+        auditor: Option<&Address<N>>,
     ) -> Result<Self> {
         let network_id = *request.network_id();
         let program_id = *request.program_id();
@@ -209,20 +218,45 @@ impl<N: Network> Transition<N> {
                         // Ensure the commitment matches.
                         ensure!(*commitment == candidate_cm, "The output record commitment is incorrect");
 
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u64(output_register.locator());
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-                        let randomizer = N::hash_to_scalar_psd2(&[*request.tvk(), index])?;
-
-                        // Encrypt the record, using the randomizer.
-                        let record_ciphertext = record.encrypt(randomizer)?;
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let ciphertext_checksum = N::hash_bhp1024(&record_ciphertext.to_bits_le())?;
-                        // Ensure the checksum matches.
-                        ensure!(*checksum == ciphertext_checksum, "The output record ciphertext checksum is incorrect");
-
-                        // Return the record output.
-                        Ok(Output::Record(*commitment, *checksum, Some(record_ciphertext)))
+                        let randomizer =
+                            Record::<N, Plaintext<N>>::to_randomizer(request.tvk(), output_register.locator())?;
+
+                        match auditor {
+                            Some(auditor) => {
+                                // Encrypt the record for the owner and for the auditor, using the randomizer.
+                                let (owner_ciphertext, auditor_ciphertext) =
+                                    record.encrypt_dual(auditor, randomizer)?;
+                                // Compute the record checksum, as the hash of the owner and auditor encrypted records.
+                                let ciphertext_checksum = owner_ciphertext.to_dual_checksum(&auditor_ciphertext)?;
+                                // Ensure the checksum matches.
+                                ensure!(
+                                    *checksum == ciphertext_checksum,
+                                    "The output audited record ciphertext checksum is incorrect"
+                                );
+
+                                // Return the audited record output.
+                                Ok(Output::AuditedRecord(
+                                    *commitment,
+                                    *checksum,
+                                    Some((owner_ciphertext, auditor_ciphertext)),
+                                ))
+                            }
+                            None => {
+                                // Encrypt the record, using the randomizer.
+                                let record_ciphertext = record.encrypt(randomizer)?;
+                                // Compute the record checksum, as the hash of the encrypted record.
+                                let ciphertext_checksum = record_ciphertext.to_checksum()?;
+                                // Ensure the checksum matches.
+                                ensure!(
+                                    *checksum == ciphertext_checksum,
+                                    "The output record ciphertext checksum is incorrect"
+                                );
+
+                                // Return the record output.
+                                Ok(Output::Record(*commitment, *checksum, Some(record_ciphertext)))
+                            }
+                        }
                     }
                     (OutputID::ExternalRecord(hash), Value::Record(record)) => {
                         // Construct the (console) output index as a field element.
@@ -348,6 +382,7 @@ impl<N: Network> Transition<N> {
             Output::Private(_, _) => false,
             Output::Record(output_cm, _, _) => output_cm == commitment,
             Output::ExternalRecord(_) => false,
+            Output::AuditedRecord(output_cm, _, _) => output_cm == commitment,
         })
     }
 }
@@ -362,6 +397,8 @@ impl<N: Network> Transition<N> {
             Output::Record(output_cm, _, Some(record)) if output_cm == commitment => Some(record),
             Output::Record(_, _, _) => None,
             Output::ExternalRecord(_) => None,
+            Output::AuditedRecord(output_cm, _, Some((record, _))) if output_cm == commitment => Some(record),
+            Output::AuditedRecord(_, _, _) => None,
         })
     }
 }
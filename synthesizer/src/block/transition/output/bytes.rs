@@ -62,7 +62,18 @@ impl<N: Network> FromBytes for Output<N> {
                 let commitment = FromBytes::read_le(&mut reader)?;
                 Self::ExternalRecord(commitment)
             }
-            5.. => return Err(error(format!("Failed to decode output variant {index}"))),
+            5 => {
+                let commitment = FromBytes::read_le(&mut reader)?;
+                let checksum = FromBytes::read_le(&mut reader)?;
+                let record_ciphertexts_exist: bool = FromBytes::read_le(&mut reader)?;
+                let record_ciphertexts = match record_ciphertexts_exist {
+                    true => Some((FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?)),
+                    false => None,
+                };
+
+                Self::AuditedRecord(commitment, checksum, record_ciphertexts)
+            }
+            6.. => return Err(error(format!("Failed to decode output variant {index}"))),
         };
         Ok(literal)
     }
@@ -121,6 +132,19 @@ impl<N: Network> ToBytes for Output<N> {
                 (4 as Variant).write_le(&mut writer)?;
                 commitment.write_le(&mut writer)
             }
+            Self::AuditedRecord(commitment, checksum, record_ciphertexts) => {
+                (5 as Variant).write_le(&mut writer)?;
+                commitment.write_le(&mut writer)?;
+                checksum.write_le(&mut writer)?;
+                match record_ciphertexts {
+                    Some((owner_record, auditor_record)) => {
+                        true.write_le(&mut writer)?;
+                        owner_record.write_le(&mut writer)?;
+                        auditor_record.write_le(&mut writer)
+                    }
+                    None => false.write_le(&mut writer),
+                }
+            }
         }
     }
 }
@@ -37,6 +37,8 @@ pub enum Output<N: Network> {
     Record(Field<N>, Field<N>, Option<Record<N, Ciphertext<N>>>),
     /// The output commitment of the external record. Note: This is **not** the record commitment.
     ExternalRecord(Field<N>),
+    /// The commitment, checksum, and (optional) owner and auditor record ciphertexts, of a program with an auditor.
+    AuditedRecord(Field<N>, Field<N>, Option<(Record<N, Ciphertext<N>>, Record<N, Ciphertext<N>>)>),
 }
 
 impl<N: Network> Output<N> {
@@ -48,6 +50,7 @@ impl<N: Network> Output<N> {
             Output::Private(_, _) => 2,
             Output::Record(_, _, _) => 3,
             Output::ExternalRecord(_) => 4,
+            Output::AuditedRecord(_, _, _) => 5,
         }
     }
 
@@ -59,12 +62,17 @@ impl<N: Network> Output<N> {
             Output::Private(id, ..) => id,
             Output::Record(commitment, ..) => commitment,
             Output::ExternalRecord(id) => id,
+            Output::AuditedRecord(commitment, ..) => commitment,
         }
     }
 
     /// Returns the output as a transition leaf.
     pub fn to_transition_leaf(&self, index: u8) -> TransitionLeaf<N> {
-        TransitionLeaf::new_with_version(index, self.variant(), *self.id())
+        match self {
+            // An audited record is a record in the transition tree, so that it is spent as any other record.
+            Output::AuditedRecord(commitment, ..) => TransitionLeaf::new_with_version(index, 3, *commitment),
+            _ => TransitionLeaf::new_with_version(index, self.variant(), *self.id()),
+        }
     }
 
     /// Returns the commitment and record, if the output is a record.
@@ -72,6 +80,7 @@ impl<N: Network> Output<N> {
     pub const fn record(&self) -> Option<(&Field<N>, &Record<N, Ciphertext<N>>)> {
         match self {
             Output::Record(commitment, _, Some(record)) => Some((commitment, record)),
+            Output::AuditedRecord(commitment, _, Some((record, _))) => Some((commitment, record)),
             _ => None,
         }
     }
@@ -81,6 +90,15 @@ impl<N: Network> Output<N> {
     pub fn into_record(self) -> Option<(Field<N>, Record<N, Ciphertext<N>>)> {
         match self {
             Output::Record(commitment, _, Some(record)) => Some((commitment, record)),
+            Output::AuditedRecord(commitment, _, Some((record, _))) => Some((commitment, record)),
+            _ => None,
+        }
+    }
+
+    /// Returns the auditor record ciphertext, if the output is an audited record.
+    pub const fn auditor_record(&self) -> Option<&Record<N, Ciphertext<N>>> {
+        match self {
+            Output::AuditedRecord(_, _, Some((_, auditor_record))) => Some(auditor_record),
             _ => None,
         }
     }
@@ -88,7 +106,7 @@ impl<N: Network> Output<N> {
     /// Returns the commitment, if the output is a record.
     pub const fn commitment(&self) -> Option<&Field<N>> {
         match self {
-            Output::Record(commitment, ..) => Some(commitment),
+            Output::Record(commitment, ..) | Output::AuditedRecord(commitment, ..) => Some(commitment),
             _ => None,
         }
     }
@@ -96,7 +114,7 @@ impl<N: Network> Output<N> {
     /// Returns the commitment, if the output is a record, and consumes `self`.
     pub fn into_commitment(self) -> Option<Field<N>> {
         match self {
-            Output::Record(commitment, ..) => Some(commitment),
+            Output::Record(commitment, ..) | Output::AuditedRecord(commitment, ..) => Some(commitment),
             _ => None,
         }
     }
@@ -105,6 +123,7 @@ impl<N: Network> Output<N> {
     pub const fn nonce(&self) -> Option<&Group<N>> {
         match self {
             Output::Record(_, _, Some(record)) => Some(record.nonce()),
+            Output::AuditedRecord(_, _, Some((record, _))) => Some(record.nonce()),
             _ => None,
         }
     }
@@ -113,6 +132,7 @@ impl<N: Network> Output<N> {
     pub fn into_nonce(self) -> Option<Group<N>> {
         match self {
             Output::Record(_, _, Some(record)) => Some(record.into_nonce()),
+            Output::AuditedRecord(_, _, Some((record, _))) => Some(record.into_nonce()),
             _ => None,
         }
     }
@@ -120,7 +140,7 @@ impl<N: Network> Output<N> {
     /// Returns the checksum, if the output is a record.
     pub const fn checksum(&self) -> Option<&Field<N>> {
         match self {
-            Output::Record(_, checksum, ..) => Some(checksum),
+            Output::Record(_, checksum, ..) | Output::AuditedRecord(_, checksum, ..) => Some(checksum),
             _ => None,
         }
     }
@@ -128,7 +148,7 @@ impl<N: Network> Output<N> {
     /// Returns the checksum, if the output is a record, and consumes `self`.
     pub fn into_checksum(self) -> Option<Field<N>> {
         match self {
-            Output::Record(_, checksum, ..) => Some(checksum),
+            Output::Record(_, checksum, ..) | Output::AuditedRecord(_, checksum, ..) => Some(checksum),
             _ => None,
         }
     }
@@ -198,10 +218,20 @@ impl<N: Network> Output<N> {
                 Ok(candidate_hash) => Ok(checksum == &candidate_hash),
                 Err(error) => Err(error),
             },
+            Output::AuditedRecord(_, checksum, Some((owner_record, auditor_record))) => {
+                // Ensure both ciphertexts share the record nonce.
+                ensure!(owner_record.nonce() == auditor_record.nonce(), "The audited record nonces do not match");
+                // Ensure the checksum of both ciphertexts matches.
+                match owner_record.to_dual_checksum(auditor_record) {
+                    Ok(candidate_hash) => Ok(checksum == &candidate_hash),
+                    Err(error) => Err(error),
+                }
+            }
             Output::Constant(_, None)
             | Output::Public(_, None)
             | Output::Private(_, None)
-            | Output::Record(_, _, None) => {
+            | Output::Record(_, _, None)
+            | Output::AuditedRecord(_, _, None) => {
                 // This enforces that the transition *must* contain the value for this transition output.
                 // A similar rule is enforced for the transition input.
                 bail!("A transition output value is missing")
@@ -222,7 +252,11 @@ impl<N: Network> Output<N> {
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use console::{network::Testnet3, program::Literal};
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::Literal,
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -252,6 +286,10 @@ pub(crate) mod test_helpers {
         ).unwrap();
         let record_ciphertext = record.encrypt(randomizer).unwrap();
         let record_checksum = CurrentNetwork::hash_bhp1024(&record_ciphertext.to_bits_le()).unwrap();
+        // Sample a random audited record.
+        let auditor = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let (owner_ciphertext, auditor_ciphertext) = record.encrypt_dual(&auditor, randomizer).unwrap();
+        let audited_checksum = owner_ciphertext.to_dual_checksum(&auditor_ciphertext).unwrap();
 
         vec![
             (transition_id, input),
@@ -264,6 +302,66 @@ pub(crate) mod test_helpers {
             (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
             (Uniform::rand(rng), Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext))),
             (Uniform::rand(rng), Output::ExternalRecord(Uniform::rand(rng))),
+            (Uniform::rand(rng), Output::AuditedRecord(Uniform::rand(rng), Uniform::rand(rng), None)),
+            (
+                Uniform::rand(rng),
+                Output::AuditedRecord(
+                    Uniform::rand(rng),
+                    audited_checksum,
+                    Some((owner_ciphertext, auditor_ciphertext)),
+                ),
+            ),
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_audited_record() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a record.
+        let randomizer = Uniform::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            &format!("{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"),
+        )?;
+        // Encrypt the record for the owner and for the auditor.
+        let auditor = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let (owner_ciphertext, auditor_ciphertext) = record.encrypt_dual(&auditor, randomizer)?;
+        let checksum = owner_ciphertext.to_dual_checksum(&auditor_ciphertext)?;
+
+        // Ensure the audited record is valid.
+        let (function_id, tcm) = (Uniform::rand(rng), Uniform::rand(rng));
+        let output =
+            Output::AuditedRecord(Uniform::rand(rng), checksum, Some((owner_ciphertext.clone(), auditor_ciphertext)));
+        assert!(output.verify(function_id, &tcm, 0));
+        // Ensure the audited record is committed to as a record in the transition tree.
+        assert_eq!(output.to_transition_leaf(0).variant(), 3);
+
+        // Ensure the audited record is invalid without the auditor ciphertext.
+        assert!(!Output::Record(Uniform::rand(rng), checksum, Some(owner_ciphertext.clone())).verify(
+            function_id,
+            &tcm,
+            0
+        ));
+        assert!(!Output::AuditedRecord(Uniform::rand(rng), checksum, None).verify(function_id, &tcm, 0));
+
+        // Ensure the audited record is invalid with an auditor ciphertext for a different auditor.
+        let other_auditor = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let (_, other_ciphertext) = record.encrypt_dual(&other_auditor, randomizer)?;
+        let output = Output::AuditedRecord(Uniform::rand(rng), checksum, Some((owner_ciphertext, other_ciphertext)));
+        assert!(!output.verify(function_id, &tcm, 0));
+
+        Ok(())
+    }
+}
@@ -64,6 +64,17 @@ impl<N: Network> Serialize for Output<N> {
                     output.serialize_field("id", &id)?;
                     output.end()
                 }
+                Self::AuditedRecord(id, checksum, value) => {
+                    let mut output = serializer.serialize_struct("Output", 5)?;
+                    output.serialize_field("type", "audited_record")?;
+                    output.serialize_field("id", &id)?;
+                    output.serialize_field("checksum", &checksum)?;
+                    if let Some((owner_record, auditor_record)) = value {
+                        output.serialize_field("value", &owner_record)?;
+                        output.serialize_field("auditor_value", &auditor_record)?;
+                    }
+                    output.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -106,6 +117,22 @@ impl<'de, N: Network> Deserialize<'de> for Output<N> {
                         })
                     }
                     Some("external_record") => Output::ExternalRecord(id),
+                    Some("audited_record") => {
+                        // Retrieve the checksum.
+                        let checksum: Field<N> = DeserializeExt::take_from_value::<D>(&mut output, "checksum")?;
+                        // Retrieve the owner and auditor record ciphertexts.
+                        let owner_record = output.get("value").and_then(|v| v.as_str());
+                        let auditor_record = output.get("auditor_value").and_then(|v| v.as_str());
+                        // Return the audited record.
+                        Output::AuditedRecord(id, checksum, match (owner_record, auditor_record) {
+                            (Some(owner_record), Some(auditor_record)) => Some((
+                                Record::<N, Ciphertext<N>>::from_str(owner_record).map_err(de::Error::custom)?,
+                                Record::<N, Ciphertext<N>>::from_str(auditor_record).map_err(de::Error::custom)?,
+                            )),
+                            (None, None) => None,
+                            _ => return Err(de::Error::custom("Missing a record ciphertext in the audited record")),
+                        })
+                    }
                     _ => return Err(de::Error::custom("Invalid output type")),
                 };
 
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum number of inputs, and of outputs, of a randomly-sampled transition.
+const MAX_SAMPLE_VALUES: usize = 4;
+
+impl<N: Network> Transition<N> {
+    /// Returns a randomly-sampled transition, whose inputs and outputs are well-formed for its function.
+    ///
+    /// The constant, public, and private inputs and outputs contain their values, and their IDs are the hashes
+    /// of their values, so they pass `Input::verify` and `Output::verify`. The serial numbers, tags, and commitments
+    /// of the records, as well as the transition public key and transition commitment, are random.
+    /// This is a structure-only fixture, as the transition is not the output of an execution,
+    /// so an execution of sampled transitions can not be proven.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample the program ID and function name.
+        let name = Identifier::from_str(&Identifier::<N>::sample(rng).to_string().to_lowercase())
+            .or_halt_with::<N>("Failed to sample a program name");
+        let network = Identifier::from_str("aleo").or_halt_with::<N>("Failed to initialize the program network");
+        let program_id = ProgramID::try_from((name, network)).or_halt_with::<N>("Failed to sample a program ID");
+        let function_name = Identifier::sample(rng);
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le(),
+        )
+        .or_halt_with::<N>("Failed to compute the function ID");
+        // Sample the transition public key and transition commitment.
+        let tpk = Group::rand(rng);
+        let tcm = Field::rand(rng);
+
+        // Sample the inputs.
+        let inputs = (0..rng.gen_range(1..=MAX_SAMPLE_VALUES))
+            .map(|index| match rng.gen_range(0..5) {
+                0 => {
+                    let plaintext = Plaintext::sample(rng);
+                    Input::Constant(Self::sample_value_id(function_id, &plaintext, tcm, index), Some(plaintext))
+                }
+                1 => {
+                    let plaintext = Plaintext::sample(rng);
+                    Input::Public(Self::sample_value_id(function_id, &plaintext, tcm, index), Some(plaintext))
+                }
+                2 => {
+                    let ciphertext = Self::sample_ciphertext(rng);
+                    Input::Private(Self::sample_ciphertext_id(&ciphertext), Some(ciphertext))
+                }
+                3 => Input::Record(Field::rand(rng), Field::rand(rng)),
+                _ => Input::ExternalRecord(Field::rand(rng)),
+            })
+            .collect::<Vec<_>>();
+
+        // Sample the outputs, which are indexed after the inputs.
+        let outputs = (inputs.len()..inputs.len() + rng.gen_range(0..=MAX_SAMPLE_VALUES))
+            .map(|index| match rng.gen_range(0..5) {
+                0 => {
+                    let plaintext = Plaintext::sample(rng);
+                    Output::Constant(Self::sample_value_id(function_id, &plaintext, tcm, index), Some(plaintext))
+                }
+                1 => {
+                    let plaintext = Plaintext::sample(rng);
+                    Output::Public(Self::sample_value_id(function_id, &plaintext, tcm, index), Some(plaintext))
+                }
+                2 => {
+                    let ciphertext = Self::sample_ciphertext(rng);
+                    Output::Private(Self::sample_ciphertext_id(&ciphertext), Some(ciphertext))
+                }
+                3 => {
+                    let record = Record::<N, Plaintext<N>>::sample(rng)
+                        .encrypt_symmetric(&Field::rand(rng))
+                        .or_halt_with::<N>("Failed to encrypt a record");
+                    let checksum =
+                        N::hash_bhp1024(&record.to_bits_le()).or_halt_with::<N>("Failed to compute a record checksum");
+                    Output::Record(Field::rand(rng), checksum, Some(record))
+                }
+                _ => Output::ExternalRecord(Field::rand(rng)),
+            })
+            .collect::<Vec<_>>();
+
+        // Sample the inputs for finalize.
+        let finalize = match rng.gen() {
+            true => {
+                let num_values = rng.gen_range(0..=MAX_SAMPLE_VALUES);
+                Some((0..num_values).map(|_| Value::Plaintext(Plaintext::sample(rng))).collect())
+            }
+            false => None,
+        };
+
+        Self::new(program_id, function_name, inputs, outputs, finalize, tpk, tcm)
+            .or_halt_with::<N>("Failed to sample a transition")
+    }
+
+    /// Returns the ID of a constant or public value, as `Hash(function ID || value || tcm || index)`.
+    fn sample_value_id(function_id: Field<N>, plaintext: &Plaintext<N>, tcm: Field<N>, index: usize) -> Field<N> {
+        let fields = plaintext.to_fields().or_halt_with::<N>("Failed to convert a plaintext to fields");
+        let preimage = [&[function_id], fields.as_slice(), &[tcm, Field::from_u16(index as u16)]].concat();
+        N::hash_psd8(&preimage).or_halt_with::<N>("Failed to compute a value ID")
+    }
+
+    /// Returns a randomly-sampled ciphertext of a private value.
+    fn sample_ciphertext<R: Rng + CryptoRng>(rng: &mut R) -> Ciphertext<N> {
+        Plaintext::sample(rng).encrypt_symmetric(Field::rand(rng)).or_halt_with::<N>("Failed to encrypt a plaintext")
+    }
+
+    /// Returns the ID of a private value, as the hash of its ciphertext.
+    fn sample_ciphertext_id(ciphertext: &Ciphertext<N>) -> Field<N> {
+        let fields = ciphertext.to_fields().or_halt_with::<N>("Failed to convert a ciphertext to fields");
+        N::hash_psd8(&fields).or_halt_with::<N>("Failed to compute a ciphertext ID")
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N: Network> arbitrary::Arbitrary<'a> for Transition<N> {
+    /// Returns a transition, which is sampled from an RNG that is seeded by the unstructured bytes.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+        Ok(Self::sample(&mut ChaChaRng::from_seed(u.arbitrary()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random transition.
+            let expected = Transition::<CurrentNetwork>::sample(&mut rng);
+
+            // Ensure the inputs and outputs are well-formed.
+            let (program_id, function_name) = (expected.program_id(), expected.function_name());
+            let network_id = U16::<CurrentNetwork>::new(CurrentNetwork::ID);
+            let function_id = CurrentNetwork::hash_bhp1024(
+                &(network_id, program_id.name(), program_id.network(), function_name).to_bits_le(),
+            )?;
+            let num_inputs = expected.inputs().len();
+            for (index, input) in expected.inputs().iter().enumerate() {
+                assert!(input.verify(function_id, expected.tcm(), index));
+            }
+            for (index, output) in expected.outputs().iter().enumerate() {
+                assert!(output.verify(function_id, expected.tcm(), num_inputs + index));
+            }
+
+            // Ensure the transition round trips through its string and byte representations.
+            assert_eq!(expected, Transition::from_str(&expected.to_string())?);
+            assert_eq!(expected, Transition::read_le(&expected.to_bytes_le()?[..])?);
+        }
+        Ok(())
+    }
+}
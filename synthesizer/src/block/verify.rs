@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Block<N> {
+    /// Checks that the given block headers form a valid chain, in ascending order of height,
+    /// without the block bodies, so that a light client can cheaply track the chain tip.
+    ///
+    /// Each header must be well-formed, and must follow the previous header in its height, round,
+    /// timestamp, cumulative weight, and its coinbase and proof targets.
+    ///
+    /// Note: As a block header does not include its previous block hash, the block hashes are not checked.
+    pub fn verify_header_chain(headers: &[Header<N>]) -> Result<()> {
+        // Ensure each header is well-formed.
+        for header in headers {
+            ensure!(header.is_valid(), "Invalid block header for block {}: {header:?}", header.height());
+        }
+
+        // Ensure each header follows the previous header.
+        for (previous, header) in headers.iter().tuple_windows() {
+            let height = header.height();
+
+            // Ensure the height is the next height.
+            ensure!(previous.height().checked_add(1) == Some(height), "Block {height} has an incorrect block height");
+            // TODO (raychu86): Ensure the next round number includes timeouts.
+            // Ensure the round is the next round.
+            ensure!(previous.round().checked_add(1) == Some(header.round()), "Block {height} has an incorrect round");
+            // Ensure the timestamp is after the previous timestamp.
+            ensure!(
+                header.timestamp() > previous.timestamp(),
+                "Block {height} has a timestamp {} that is not after the previous timestamp {}",
+                header.timestamp(),
+                previous.timestamp()
+            );
+
+            // Ensure the cumulative weight includes the cumulative proof target.
+            ensure!(
+                header.cumulative_weight()
+                    == previous.cumulative_weight().saturating_add(header.cumulative_proof_target()),
+                "Block {height} has a cumulative weight that does not include its cumulative proof target"
+            );
+            // Ensure the last coinbase target and timestamp are updated if, and only if, the block has a coinbase.
+            let (expected_last_coinbase_target, expected_last_coinbase_timestamp) =
+                match header.cumulative_proof_target() > 0 {
+                    true => (header.coinbase_target(), header.timestamp()),
+                    false => (previous.last_coinbase_target(), previous.last_coinbase_timestamp()),
+                };
+            ensure!(
+                header.last_coinbase_target() == expected_last_coinbase_target,
+                "Block {height} has an incorrect last coinbase target"
+            );
+            ensure!(
+                header.last_coinbase_timestamp() == expected_last_coinbase_timestamp,
+                "Block {height} has an incorrect last coinbase timestamp"
+            );
+
+            // Ensure the coinbase target is correct.
+            let expected_coinbase_target = coinbase_target(
+                previous.last_coinbase_target(),
+                previous.last_coinbase_timestamp(),
+                header.timestamp(),
+                N::ANCHOR_TIME,
+                N::NUM_BLOCKS_PER_EPOCH,
+                N::GENESIS_COINBASE_TARGET,
+            )?;
+            ensure!(
+                header.coinbase_target() == expected_coinbase_target,
+                "Block {height} has an invalid coinbase target: expected {expected_coinbase_target}, got {}",
+                header.coinbase_target()
+            );
+            // Ensure the proof target is correct.
+            let expected_proof_target = proof_target(expected_coinbase_target, N::GENESIS_PROOF_TARGET);
+            ensure!(
+                header.proof_target() == expected_proof_target,
+                "Block {height} has an invalid proof target: expected {expected_proof_target}, got {}",
+                header.proof_target()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the next header after the given header, with the given cumulative proof target.
+    fn sample_next_header(previous: &Header<CurrentNetwork>, cumulative_proof_target: u128) -> Header<CurrentNetwork> {
+        let timestamp = previous.timestamp() + CurrentNetwork::ANCHOR_TIME as i64;
+        let coinbase_target = coinbase_target(
+            previous.last_coinbase_target(),
+            previous.last_coinbase_timestamp(),
+            timestamp,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap();
+        let (last_coinbase_target, last_coinbase_timestamp) = match cumulative_proof_target > 0 {
+            true => (coinbase_target, timestamp),
+            false => (previous.last_coinbase_target(), previous.last_coinbase_timestamp()),
+        };
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            previous.round() + 1,
+            previous.height() + 1,
+            previous.total_supply_in_microcredits(),
+            previous.cumulative_weight() + cumulative_proof_target,
+            cumulative_proof_target,
+            coinbase_target,
+            proof_target(coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET),
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        )
+        .unwrap();
+        Header::from(Field::one(), Field::one(), Field::one(), Field::one(), Field::zero(), metadata).unwrap()
+    }
+
+    /// Samples a header chain of the given length, where every other block has a coinbase.
+    fn sample_header_chain(num_headers: usize) -> Vec<Header<CurrentNetwork>> {
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            1,
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            0,
+            0,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+        )
+        .unwrap();
+        let mut headers = vec![Header::from(
+            Field::one(),
+            Field::one(),
+            Field::one(),
+            Field::one(),
+            Field::zero(),
+            metadata,
+        )
+        .unwrap()];
+        for i in 1..num_headers {
+            let cumulative_proof_target = if i % 2 == 0 { 100 } else { 0 };
+            headers.push(sample_next_header(headers.last().unwrap(), cumulative_proof_target));
+        }
+        headers
+    }
+
+    #[test]
+    fn test_verify_header_chain() {
+        let headers = sample_header_chain(10);
+
+        // Ensure the header chain is valid, including its sub-chains.
+        assert!(Block::verify_header_chain(&headers).is_ok());
+        assert!(Block::verify_header_chain(&headers[3..7]).is_ok());
+        assert!(Block::<CurrentNetwork>::verify_header_chain(&[]).is_ok());
+
+        // Ensure a missing header is rejected.
+        let mut missing = headers.clone();
+        missing.remove(4);
+        assert!(Block::verify_header_chain(&missing).is_err());
+
+        // Ensure out-of-order headers are rejected.
+        let mut reordered = headers.clone();
+        reordered.swap(4, 5);
+        assert!(Block::verify_header_chain(&reordered).is_err());
+
+        // Ensure a chain with a header from a fork is rejected, as the next header does not follow it.
+        let mut forked = headers;
+        forked[5] = sample_next_header(&forked[4], 100);
+        assert!(Block::verify_header_chain(&forked[..6]).is_ok());
+        assert!(Block::verify_header_chain(&forked).is_err());
+    }
+}
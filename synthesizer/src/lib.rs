@@ -38,6 +38,9 @@ pub use process::*;
 pub mod store;
 pub use store::*;
 
+pub mod vectors;
+pub use vectors::*;
+
 pub mod vm;
 pub use vm::*;
 
@@ -51,5 +54,5 @@ pub mod prelude {
 
     // TODO (howardwu): These will be refactored into their own modules.
     //  Config flags should be added to these after modularization so that they can be disabled.
-    pub use crate::{block::*, cow_to_cloned, cow_to_copied, process::*, store::*, vm::*};
+    pub use crate::{block::*, cow_to_cloned, cow_to_copied, process::*, store::*, vectors::*, vm::*};
 }
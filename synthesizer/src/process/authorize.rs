@@ -28,4 +28,25 @@ impl<N: Network> Process<N> {
         // Authorize the call.
         self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
     }
+
+    /// Authorizes a call to the program function for the given inputs, which expires after the given block height.
+    #[inline]
+    pub fn authorize_with_expiration<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        valid_until: Option<u32>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Authorize the call.
+        self.get_stack(program_id)?.authorize_with_expiration::<A, R>(
+            private_key,
+            function_name,
+            inputs,
+            valid_until,
+            rng,
+        )
+    }
 }
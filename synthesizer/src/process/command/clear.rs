@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FinalizeOperation, FinalizeStorage, FinalizeStore, Opcode, RegistersLoad, Stack, StackProgram};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal},
+};
+use snarkvm_synthesizer_program::Operand;
+
+/// A clear command, e.g. `clear mapping r0;`
+/// Removes every (`key`, `value`) entry in `mapping`, if `admin` is the registered admin of the program.
+/// A program opts into an admin by using `clear`, in which case the program owner is registered as the admin on deploy.
+/// The `admin` must be a finalize input that the function sets to `self.caller`, which binds it to the transition caller.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Clear<N: Network> {
+    /// The mapping name.
+    mapping: Identifier<N>,
+    /// The address of the program admin.
+    admin: Operand<N>,
+}
+
+impl<N: Network> Clear<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("clear")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.admin.clone()]
+    }
+
+    /// Returns the mapping name.
+    #[inline]
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the admin address.
+    #[inline]
+    pub const fn admin(&self) -> &Operand<N> {
+        &self.admin
+    }
+}
+
+impl<N: Network> Clear<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize<P: FinalizeStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &FinalizeStore<N, P>,
+        registers: &mut impl RegistersLoad<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
+            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        }
+
+        // Load the admin operand as an address.
+        let admin = match registers.load_literal(stack, &self.admin)? {
+            Literal::Address(admin) => admin,
+            _ => bail!("Invalid admin in 'clear': expected an 'address'"),
+        };
+        // Ensure the address is the registered admin of the program.
+        match store.get_admin_speculative(stack.program_id())? {
+            Some(expected) if expected == admin => (),
+            Some(_) => bail!("'{admin}' is not the admin of '{}'", stack.program_id()),
+            None => bail!("Program '{}' does not have an admin", stack.program_id()),
+        }
+
+        // Clear the mapping in storage, and return the finalize operation.
+        store.clear_mapping(stack.program_id(), &self.mapping)
+    }
+}
+
+impl<N: Network> Parser for Clear<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the admin operand from the string.
+        let (string, admin) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, admin }))
+    }
+}
+
+impl<N: Network> FromStr for Clear<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
+        }
+    }
+}
+
+impl<N: Network> Debug for Clear<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Clear<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and admin operand.
+        write!(f, "{} {};", self.mapping, self.admin)
+    }
+}
+
+impl<N: Network> FromBytes for Clear<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = Identifier::read_le(&mut reader)?;
+        // Read the admin operand.
+        let admin = Operand::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, admin })
+    }
+}
+
+impl<N: Network> ToBytes for Clear<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the admin operand.
+        self.admin.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, clear) = Clear::<CurrentNetwork>::parse("clear account r0;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(clear.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(clear.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(clear.admin, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+    }
+}
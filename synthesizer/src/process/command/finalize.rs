@@ -38,6 +38,19 @@ impl<N: Network, const VARIANT: u8> FinalizeCommandTrait for FinalizeOperation<N
 }
 
 impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
+    /// Initializes a new operation with the given operands.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>) -> Result<Self> {
+        // Ensure the number of operands is less than or equal to MAX_INPUTS.
+        ensure!(
+            operands.len() <= N::MAX_INPUTS,
+            "The number of operands must be <= {}, found {}",
+            N::MAX_INPUTS,
+            operands.len()
+        );
+        Ok(Self { operands })
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -61,6 +74,12 @@ impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the operation, with its operands replaced by the given function.
+    #[inline]
+    pub fn map_operands(&self, operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>) -> Result<Self> {
+        Ok(Self { operands: self.operands.iter().map(operand).collect::<Result<_>>()? })
+    }
 }
 
 impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
@@ -128,11 +147,15 @@ impl<N: Network, const VARIANT: u8> FromStr for FinalizeOperation<N, VARIANT> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
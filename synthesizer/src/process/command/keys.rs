@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FinalizeStorage, FinalizeStore, Opcode, RegistersLoad as LoadTrait, RegistersStore, Stack, StackProgram};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register, Value},
+};
+use snarkvm_synthesizer_program::Operand;
+
+/// A keys command, e.g. `keys accounts[r0] r1 into r2 r3 r4;`.
+/// Reads up to one key per destination from `mapping`, in insertion order, starting after the `cursor` key,
+/// and stores the keys in `destinations`. If the `cursor` is not a key in the mapping, e.g. the `default` key,
+/// the keys are read from the first key. If the mapping runs out of keys, `default` is stored
+/// in the remaining destinations. To read the next keys, pass the last destination as the `cursor`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Keys<N: Network> {
+    /// The mapping name.
+    mapping: Identifier<N>,
+    /// The key preceding the first key to read.
+    cursor: Operand<N>,
+    /// The default key.
+    default: Operand<N>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> Keys<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("keys")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.cursor.clone(), self.default.clone()]
+    }
+
+    /// Returns the mapping name.
+    #[inline]
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the cursor.
+    #[inline]
+    pub const fn cursor(&self) -> &Operand<N> {
+        &self.cursor
+    }
+
+    /// Returns the default key.
+    #[inline]
+    pub const fn default(&self) -> &Operand<N> {
+        &self.default
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+}
+
+impl<N: Network> Keys<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize<P: FinalizeStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &FinalizeStore<N, P>,
+        registers: &mut (impl LoadTrait<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
+            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        }
+
+        // Load the cursor key.
+        let cursor = registers.load_plaintext(stack, &self.cursor)?;
+
+        // Retrieve the keys from storage.
+        let keys =
+            match store.get_keys_speculative(stack.program_id(), &self.mapping, &cursor, self.destinations.len())? {
+                Some(keys) => keys,
+                None => bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping),
+            };
+        // Load the default key, if the mapping runs out of keys.
+        let default = match keys.len() < self.destinations.len() {
+            true => Some(registers.load_plaintext(stack, &self.default)?),
+            false => None,
+        };
+
+        // Assign the keys to the destination registers, followed by the default key.
+        let keys = keys.into_iter().chain(default.into_iter().cycle());
+        for (destination, key) in self.destinations.iter().zip(keys) {
+            registers.store(stack, destination, Value::Plaintext(key))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for Keys<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the cursor operand from the string.
+        let (string, cursor) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the default key from the string.
+        let (string, default) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the destination registers from the string.
+        let (string, destinations) = map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+            // Ensure the number of destinations is within the bounds.
+            match destinations.len() <= N::MAX_OPERANDS {
+                true => Ok(destinations),
+                false => Err(error("Failed to parse 'keys' opcode: too many destinations")),
+            }
+        })(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, cursor, default, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for Keys<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
+        }
+    }
+}
+
+impl<N: Network> Debug for Keys<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Keys<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.is_empty() || self.destinations.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping, cursor, and default operands.
+        write!(f, "{}[{}] {} into", self.mapping, self.cursor, self.default)?;
+        // Print the destination registers.
+        self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        write!(f, ";")
+    }
+}
+
+impl<N: Network> FromBytes for Keys<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = Identifier::read_le(&mut reader)?;
+        // Read the cursor operand.
+        let cursor = Operand::read_le(&mut reader)?;
+        // Read the default key.
+        let default = Operand::read_le(&mut reader)?;
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations == 0 || num_destinations > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be in [1, {}]", N::MAX_OPERANDS)));
+        }
+        // Read the destination registers.
+        let destinations = (0..num_destinations).map(|_| Register::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+        // Return the command.
+        Ok(Self { mapping, cursor, default, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for Keys<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.is_empty() || self.destinations.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be in [1, {}]", N::MAX_OPERANDS)));
+        }
+
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the cursor operand.
+        self.cursor.write_le(&mut writer)?;
+        // Write the default key.
+        self.default.write_le(&mut writer)?;
+        // Write the number of destination registers.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, keys) = Keys::<CurrentNetwork>::parse("keys account[r0] r1 into r2 r3 r4;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(keys.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(keys.operands().len(), 2, "The number of operands is incorrect");
+        assert_eq!(keys.cursor, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(keys.default, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(
+            keys.destinations,
+            vec![Register::Locator(2), Register::Locator(3), Register::Locator(4)],
+            "The destinations are incorrect"
+        );
+
+        // Ensure a command without destinations fails to parse.
+        assert!(Keys::<CurrentNetwork>::from_str("keys account[r0] r1 into;").is_err());
+    }
+}
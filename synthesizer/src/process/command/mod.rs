@@ -15,6 +15,9 @@
 mod branch;
 pub use branch::*;
 
+mod clear;
+pub use clear::*;
+
 mod contains;
 pub use contains::*;
 
@@ -27,6 +30,12 @@ pub use get::*;
 mod get_or_use;
 pub use get_or_use::*;
 
+mod keys;
+pub use keys::*;
+
+mod length;
+pub use length::*;
+
 mod rand_chacha;
 pub use crate::process::command::rand_chacha::*;
 
@@ -41,7 +50,7 @@ pub use set::*;
 
 use crate::{
     process::{FinalizeOperation, FinalizeRegisters, Instruction, Stack},
-    program::{CommandTrait, InstructionTrait},
+    program::{CommandTrait, InstructionTrait, Operand},
     store::{FinalizeStorage, FinalizeStore},
 };
 use console::{
@@ -53,6 +62,8 @@ use console::{
 pub enum Command<N: Network> {
     /// Evaluates the instruction.
     Instruction(Instruction<N>),
+    /// Removes every (`key`, `value`) entry from the `mapping`, if the `admin` operand is the admin of the program.
+    Clear(Clear<N>),
     /// Returns true if the `key` operand is present in `mapping`, and stores the result into `destination`.
     Contains(Contains<N>),
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
@@ -60,6 +71,11 @@ pub enum Command<N: Network> {
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
     /// If the key is not present, `default` is stored `destination`.
     GetOrUse(GetOrUse<N>),
+    /// Gets up to one key per destination from `mapping`, starting at the `cursor` operand, and stores the keys into `destinations`.
+    /// If the mapping runs out of keys, `default` is stored in the remaining `destinations`.
+    Keys(Keys<N>),
+    /// Gets the number of entries in `mapping` and stores the result into `destination`.
+    Length(Length<N>),
     /// Generates a random value using the `rand.chacha` command and stores the result into `destination`.
     RandChaCha(RandChaCha<N>),
     /// Removes the (`key`, `value`) entry from the `mapping`.
@@ -85,8 +101,11 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Contains(contains) => vec![contains.destination().clone()],
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
+            Command::Keys(keys) => keys.destinations(),
+            Command::Length(length) => vec![length.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
-            Command::Remove(_)
+            Command::Clear(_)
+            | Command::Remove(_)
             | Command::Set(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
@@ -129,11 +148,51 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     /// Returns `true` if the command is a write operation.
     #[inline]
     fn is_write(&self) -> bool {
-        matches!(self, Command::Set(_) | Command::Remove(_))
+        matches!(self, Command::Clear(_) | Command::Set(_) | Command::Remove(_))
     }
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the number of steps metered for the command in finalize, as weighted by the network.
+    #[inline]
+    pub fn steps(&self) -> u64 {
+        match self {
+            Command::Instruction(..) => N::FINALIZE_INSTRUCTION_STEPS,
+            Command::Contains(..) | Command::Get(..) | Command::GetOrUse(..) | Command::Length(..) => {
+                N::FINALIZE_READ_STEPS
+            }
+            // A 'keys' command is metered as one read per destination.
+            Command::Keys(keys) => N::FINALIZE_READ_STEPS.saturating_mul(keys.destinations().len() as u64),
+            Command::RandChaCha(..) => N::FINALIZE_RAND_STEPS,
+            Command::Clear(..) | Command::Remove(..) | Command::Set(..) => N::FINALIZE_WRITE_STEPS,
+            Command::BranchEq(..) | Command::BranchNeq(..) | Command::Position(..) => N::FINALIZE_BRANCH_STEPS,
+        }
+    }
+
+    /// Returns the mapping name and key operand, if the command accesses a mapping.
+    #[inline]
+    pub fn mapping_access(&self) -> Option<(&Identifier<N>, &Operand<N>)> {
+        match self {
+            Command::Contains(contains) => Some((contains.mapping_name(), contains.key())),
+            Command::Get(get) => Some((get.mapping_name(), get.key())),
+            Command::GetOrUse(get_or_use) => Some((get_or_use.mapping_name(), get_or_use.key())),
+            Command::Set(set) => Some((set.mapping_name(), set.key())),
+            Command::Remove(remove) => Some((remove.mapping_name(), remove.key())),
+            _ => None,
+        }
+    }
+
+    /// Returns the mapping name, if the command accesses the mapping as a whole, rather than a single key.
+    #[inline]
+    pub fn mapping_scan(&self) -> Option<&Identifier<N>> {
+        match self {
+            Command::Clear(clear) => Some(clear.mapping_name()),
+            Command::Keys(keys) => Some(keys.mapping_name()),
+            Command::Length(length) => Some(length.mapping_name()),
+            _ => None,
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize<P: FinalizeStorage<N>>(
@@ -145,12 +204,18 @@ impl<N: Network> Command<N> {
         match self {
             // Finalize the instruction, and return no finalize operation.
             Command::Instruction(instruction) => instruction.finalize(stack, registers).map(|_| None),
+            // Finalize the 'clear' command, and return the finalize operation.
+            Command::Clear(clear) => clear.finalize(stack, store, registers).map(Some),
             // Finalize the 'contains' command, and return no finalize operation.
             Command::Contains(contains) => contains.finalize(stack, store, registers).map(|_| None),
             // Finalize the 'get' command, and return no finalize operation.
             Command::Get(get) => get.finalize(stack, store, registers).map(|_| None),
             // Finalize the 'get.or_use' command, and return no finalize operation.
             Command::GetOrUse(get_or_use) => get_or_use.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'keys' command, and return no finalize operation.
+            Command::Keys(keys) => keys.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'length' command, and return no finalize operation.
+            Command::Length(length) => length.finalize(stack, store, registers).map(|_| None),
             // Finalize the `rand.chacha` command, and return no finalize operation.
             Command::RandChaCha(rand_chacha) => rand_chacha.finalize(stack, store, registers).map(|_| None),
             // Finalize the 'remove' command, and return the finalize operation.
@@ -193,8 +258,14 @@ impl<N: Network> FromBytes for Command<N> {
             8 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             9 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `keys` operation.
+            10 => Ok(Self::Keys(Keys::read_le(&mut reader)?)),
+            // Read the `length` operation.
+            11 => Ok(Self::Length(Length::read_le(&mut reader)?)),
+            // Read the `clear` operation.
+            12 => Ok(Self::Clear(Clear::read_le(&mut reader)?)),
             // Invalid variant.
-            10.. => Err(error(format!("Invalid command variant: {variant}"))),
+            13.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -263,6 +334,24 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::Keys(keys) => {
+                // Write the variant.
+                10u8.write_le(&mut writer)?;
+                // Write the `keys` operation.
+                keys.write_le(&mut writer)
+            }
+            Self::Length(length) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `length` operation.
+                length.write_le(&mut writer)
+            }
+            Self::Clear(clear) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `clear` operation.
+                clear.write_le(&mut writer)
+            }
         }
     }
 }
@@ -274,9 +363,12 @@ impl<N: Network> Parser for Command<N> {
         // Parse the command.
         // Note that the order of the parsers is important.
         alt((
+            map(Clear::parse, |clear| Self::Clear(clear)),
             map(Contains::parse, |contains| Self::Contains(contains)),
             map(GetOrUse::parse, |get_or_use| Self::GetOrUse(get_or_use)),
             map(Get::parse, |get| Self::Get(get)),
+            map(Keys::parse, |keys| Self::Keys(keys)),
+            map(Length::parse, |length| Self::Length(length)),
             map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
             map(Remove::parse, |remove| Self::Remove(remove)),
             map(Set::parse, |set| Self::Set(set)),
@@ -297,11 +389,15 @@ impl<N: Network> FromStr for Command<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -318,9 +414,12 @@ impl<N: Network> Display for Command<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Self::Instruction(instruction) => Display::fmt(instruction, f),
+            Self::Clear(clear) => Display::fmt(clear, f),
             Self::Contains(contains) => Display::fmt(contains, f),
             Self::Get(get) => Display::fmt(get, f),
             Self::GetOrUse(get_or_use) => Display::fmt(get_or_use, f),
+            Self::Keys(keys) => Display::fmt(keys, f),
+            Self::Length(length) => Display::fmt(length, f),
             Self::RandChaCha(rand_chacha) => Display::fmt(rand_chacha, f),
             Self::Remove(remove) => Display::fmt(remove, f),
             Self::Set(set) => Display::fmt(set, f),
@@ -354,6 +453,12 @@ mod tests {
         let expected = "increment object[r0] by r1;";
         Command::<CurrentNetwork>::parse(expected).unwrap_err();
 
+        // Clear
+        let expected = "clear object r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // Contains
         let expected = "contains object[r0] into r1;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -372,6 +477,18 @@ mod tests {
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
 
+        // Keys
+        let expected = "keys object[r0] r1 into r2 r3;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Length
+        let expected = "length object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -431,6 +548,12 @@ mod tests {
         let expected = "increment object[r0] by r1;";
         Command::<CurrentNetwork>::parse(expected).unwrap_err();
 
+        // Clear
+        let expected = "clear object r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Clear(Clear::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // Contains
         let expected = "contains object[r0] into r1;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -449,6 +572,18 @@ mod tests {
         assert_eq!(Command::GetOrUse(GetOrUse::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
 
+        // Keys
+        let expected = "keys object[r0] r1 into r2 r3;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Keys(Keys::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Length
+        let expected = "length object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Length(Length::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
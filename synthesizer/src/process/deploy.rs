@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
     /// Deploys the given program ID, if it does not exist.
     #[inline]
@@ -37,6 +40,21 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys the given program ID, if it does not exist, after optimizing the program with the given optimizer.
+    /// Note that the deployment contains the optimized program, which is synthesized into the circuit keys.
+    #[inline]
+    pub fn deploy_optimized<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        optimizer: &Optimizer,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        // Optimize the program.
+        let program = optimizer.optimize(program)?;
+        // Deploy the optimized program.
+        self.deploy::<A, R>(&program, rng)
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -60,4 +78,74 @@ impl<N: Network> Process<N> {
 
         Ok(())
     }
+
+    /// Adds the newly-deployed programs, in the order of their imports.
+    /// The stacks of programs that do not depend on one another are computed in parallel.
+    /// This method assumes the given deployments **are valid**.
+    #[inline]
+    pub fn load_deployments(&mut self, deployments: &[Deployment<N>]) -> Result<()> {
+        let timer = timer!("Process::load_deployments");
+
+        self.add_stacks_in_import_order(deployments, Deployment::program, |process, deployment| {
+            // Compute the program stack.
+            let stack = Stack::new(process, deployment.program())?;
+            // Insert the verifying keys.
+            for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+                stack.insert_verifying_key(function_name, verifying_key.clone())?;
+            }
+            Ok(stack)
+        })?;
+
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Adds the stacks for the given items, in the order of their imports.
+    ///
+    /// The imports are resolved as a dependency DAG, one layer at a time: each layer consists of the
+    /// pending programs whose imports are all in the process, and its stacks are computed in parallel.
+    /// Programs that already exist in the process are skipped.
+    pub(crate) fn add_stacks_in_import_order<T: Sync>(
+        &mut self,
+        items: &[T],
+        program: impl Fn(&T) -> &Program<N> + Sync,
+        stack: impl Fn(&Self, &T) -> Result<Stack<N>> + Sync,
+    ) -> Result<()> {
+        // Retrieve the pending items, skipping programs that already exist in the process.
+        let mut pending: Vec<&T> = Vec::with_capacity(items.len());
+        for item in items.iter().filter(|item| !self.contains_program(program(item).id())) {
+            // Ensure the program is not given more than once.
+            let program_id = program(item).id();
+            ensure!(
+                !pending.iter().any(|candidate| program(candidate).id() == program_id),
+                "Program '{program_id}' is given more than once"
+            );
+            pending.push(item);
+        }
+
+        while !pending.is_empty() {
+            // Split off the layer of programs whose imports are all in the process.
+            let (layer, remaining): (Vec<_>, Vec<_>) = pending
+                .into_iter()
+                .partition(|item| program(item).imports().keys().all(|import| self.contains_program(import)));
+
+            // Ensure progress is made, which fails if an import is missing or the imports are cyclic.
+            if layer.is_empty() {
+                let program_ids = remaining.iter().map(|item| program(item).id().to_string()).collect::<Vec<_>>();
+                bail!("Failed to resolve the imports of {}", program_ids.join(", "))
+            }
+
+            // Compute the stacks of the layer in parallel.
+            let stacks = cfg_iter!(layer).map(|item| stack(self, item)).collect::<Result<Vec<_>>>()?;
+            // Add the stacks to the process.
+            for stack in stacks {
+                self.add_stack(stack);
+            }
+
+            pending = remaining;
+        }
+
+        Ok(())
+    }
 }
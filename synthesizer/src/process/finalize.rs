@@ -13,9 +13,86 @@
 // limitations under the License.
 
 use super::*;
+use crate::block::{RejectionCode, RejectionMetadata};
+use snarkvm_synthesizer_program::{CommandTrait, Operand};
+
+/// The failure of a command in the finalize scope of a function, which aborts finalize.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeFailure<N: Network> {
+    /// The program ID of the finalize scope.
+    program_id: ProgramID<N>,
+    /// The function name of the finalize scope.
+    function_name: Identifier<N>,
+    /// The index of the command in the finalize scope.
+    index: usize,
+    /// The command.
+    command: Command<N>,
+    /// The mapping name and key accessed by the command, if the command accesses a mapping.
+    mapping_key: Option<(Identifier<N>, Plaintext<N>)>,
+    /// The error code.
+    code: RejectionCode,
+    /// The error message.
+    error: String,
+}
+
+impl<N: Network> FinalizeFailure<N> {
+    /// Returns the program ID of the finalize scope.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the finalize scope.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the index of the command in the finalize scope.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the command.
+    pub const fn command(&self) -> &Command<N> {
+        &self.command
+    }
+
+    /// Returns the mapping name and key accessed by the command, if the command accesses a mapping.
+    pub fn mapping_key(&self) -> Option<(&Identifier<N>, &Plaintext<N>)> {
+        self.mapping_key.as_ref().map(|(mapping_name, key)| (mapping_name, key))
+    }
+
+    /// Returns the error code.
+    pub const fn code(&self) -> RejectionCode {
+        self.code
+    }
+
+    /// Returns the error message.
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+
+    /// Returns the rejection metadata of the failure, which is recorded for the rejected transaction.
+    pub fn to_rejection_metadata(&self) -> Result<RejectionMetadata<N>> {
+        RejectionMetadata::new(
+            self.code,
+            Some((self.program_id, self.function_name, u32::try_from(self.index)?)),
+            self.mapping_key.clone(),
+        )
+    }
+}
+
+impl<N: Network> Display for FinalizeFailure<N> {
+    /// Prints the failure as an error message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "'finalize' failed to evaluate command ({}): {}", self.command, self.error)
+    }
+}
+
+impl<N: Network> std::error::Error for FinalizeFailure<N> {}
 
 impl<N: Network> Process<N> {
-    /// Finalizes the deployment.
+    /// Finalizes the deployment, and registers the program owner as the admin of the program,
+    /// if the program opts into an admin (see `Deployment::registers_admin`).
     /// This method assumes the given deployment **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
@@ -23,6 +100,7 @@ impl<N: Network> Process<N> {
         &self,
         store: &FinalizeStore<N, P>,
         deployment: &Deployment<N>,
+        program_owner: &ProgramOwner<N>,
     ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("Process::finalize_deployment");
 
@@ -42,7 +120,7 @@ impl<N: Network> Process<N> {
         // Initialize the mappings, and store their finalize operations.
         atomic_batch_scope!(store, {
             // Initialize a list for the finalize operations.
-            let mut finalize_operations = Vec::with_capacity(deployment.program().mappings().len());
+            let mut finalize_operations = Vec::with_capacity(deployment.program().mappings().len() + 1);
 
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
@@ -51,6 +129,12 @@ impl<N: Network> Process<N> {
             }
             lap!(timer, "Initialize the program mappings");
 
+            // Register the program owner as the admin, if the program opts into an admin.
+            if deployment.registers_admin() {
+                finalize_operations.push(store.register_admin(program_id, program_owner.address())?);
+                lap!(timer, "Register the program admin");
+            }
+
             finish!(timer);
 
             // Return the stack and finalize operations.
@@ -58,7 +142,7 @@ impl<N: Network> Process<N> {
         })
     }
 
-    /// Finalizes the execution.
+    /// Finalizes the execution, and returns the finalize operations.
     /// This method assumes the given execution **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
@@ -68,6 +152,39 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_execution_with_trace(state, store, execution, None).map(|(operations, _)| operations)
+    }
+
+    /// Finalizes the execution, and returns the finalize operations with the number of metered steps.
+    /// If a trace is given, every command executed, register written, and mapping read and write is
+    /// recorded in the trace; if finalize aborts, the trace ends with the error.
+    /// This method assumes the given execution **is valid**.
+    /// This method should **only** be called by `VM::simulate()` or `VM::trace_finalize()`.
+    #[inline]
+    pub(crate) fn finalize_execution_with_trace<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        mut trace: Option<&mut FinalizeTrace<N>>,
+    ) -> Result<(Vec<FinalizeOperation<N>>, u64)> {
+        let result = self.finalize_execution_inner(state, store, execution, trace.as_deref_mut());
+        // If finalize aborts, record the error in the trace.
+        if let (Err(error), Some(trace)) = (&result, trace) {
+            trace.push(FinalizeEvent::Abort { error: error.to_string() });
+        }
+        result
+    }
+
+    /// Finalizes the execution, recording the trace if it is given.
+    #[inline]
+    fn finalize_execution_inner<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        trace: Option<&mut FinalizeTrace<N>>,
+    ) -> Result<(Vec<FinalizeOperation<N>>, u64)> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -90,8 +207,12 @@ impl<N: Network> Process<N> {
         lap!(timer, "Verify the number of transitions");
 
         atomic_batch_scope!(store, {
+            // Move the trace into the atomic batch.
+            let mut trace = trace;
             // Initialize a list for finalize operations.
             let mut finalize_operations = Vec::new();
+            // Initialize a meter for the number of steps.
+            let mut steps = 0u64;
 
             // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
             //  Currently this loop assumes a linearly execution stack.
@@ -137,6 +258,38 @@ impl<N: Network> Process<N> {
                     while counter < finalize.commands().len() {
                         // Retrieve the command.
                         let command = &finalize.commands()[counter];
+                        // Record the command in the trace.
+                        if let Some(trace) = trace.as_deref_mut() {
+                            trace.push(FinalizeEvent::Command {
+                                program_id: *transition.program_id(),
+                                function_name: *function_name,
+                                index: counter,
+                                command: command.clone(),
+                            });
+                        }
+                        // Initializes the failure of the command, with the given error message.
+                        let index = counter;
+                        let failure = |registers: &FinalizeRegisters<N>, code, error: String| FinalizeFailure {
+                            program_id: *transition.program_id(),
+                            function_name: *function_name,
+                            index,
+                            command: command.clone(),
+                            mapping_key: command.mapping_access().and_then(|(mapping_name, key)| {
+                                Some((*mapping_name, registers.load_plaintext(stack, key).ok()?))
+                            }),
+                            code,
+                            error,
+                        };
+                        // Meter the command, and ensure the step limit is not exceeded.
+                        steps = steps.saturating_add(command.steps());
+                        // Record the metered steps in the trace.
+                        if let Some(trace) = trace.as_deref_mut() {
+                            trace.set_steps(steps);
+                        }
+                        if steps > N::MAX_FINALIZE_STEPS {
+                            let error = format!("exceeded the step limit of {} steps", N::MAX_FINALIZE_STEPS);
+                            return Err(failure(&registers, RejectionCode::StepLimitExceeded, error).into());
+                        }
                         // Finalize the command.
                         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| match &command {
                             Command::BranchEq(branch_eq) => {
@@ -159,10 +312,20 @@ impl<N: Network> Process<N> {
                             Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
                             // If the evaluation succeeds with no operation, continue.
                             Ok(Ok(None)) => (),
-                            // If the evaluation fails, bail and return the error.
-                            Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
-                            // If the evaluation fails, bail and return the error.
-                            Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                            // If the evaluation fails, return the failure.
+                            Ok(Err(error)) => {
+                                return Err(failure(&registers, RejectionCode::CommandFailed, error.to_string()).into());
+                            }
+                            // If the evaluation halts, return the failure.
+                            Err(_) => {
+                                let error = "the command halted".to_string();
+                                return Err(failure(&registers, RejectionCode::CommandHalted, error).into());
+                            }
+                        }
+
+                        // Record the effects of the command in the trace.
+                        if let Some(trace) = trace.as_deref_mut() {
+                            trace_effects(trace, command, stack, store, &registers)?;
                         }
                     }
                     lap!(timer, "Finalize transition for {function_name}");
@@ -170,12 +333,51 @@ impl<N: Network> Process<N> {
             }
             finish!(timer);
 
-            // Return the finalize operations.
-            Ok(finalize_operations)
+            // Return the finalize operations and the number of metered steps.
+            Ok((finalize_operations, steps))
         })
     }
 }
 
+// A helper function that records the mapping reads and writes, and the register writes, of a finalized command.
+fn trace_effects<N: Network, P: FinalizeStorage<N>>(
+    trace: &mut FinalizeTrace<N>,
+    command: &Command<N>,
+    stack: &Stack<N>,
+    store: &FinalizeStore<N, P>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<()> {
+    let program_id = *stack.program_id();
+
+    // Record the mapping read or write.
+    if let Some((mapping_name, key)) = command.mapping_access() {
+        let key = registers.load_plaintext(stack, key)?;
+        match command {
+            // A write records the stored value.
+            Command::Set(set) => {
+                let value = Some(registers.load(stack, set.value())?);
+                trace.push(FinalizeEvent::MappingWrite { program_id, mapping_name: *mapping_name, key, value });
+            }
+            // A removal records `None` as the value.
+            Command::Remove(..) => {
+                trace.push(FinalizeEvent::MappingWrite { program_id, mapping_name: *mapping_name, key, value: None });
+            }
+            // A read records the value in storage, or `None` if the key does not exist.
+            _ => {
+                let value = store.get_value_speculative(&program_id, mapping_name, &key)?;
+                trace.push(FinalizeEvent::MappingRead { program_id, mapping_name: *mapping_name, key, value });
+            }
+        }
+    }
+
+    // Record the register writes.
+    for register in command.destinations() {
+        let value = registers.load(stack, &Operand::Register(register.clone()))?;
+        trace.push(FinalizeEvent::RegisterWrite { register, value });
+    }
+    Ok(())
+}
+
 // A helper function that returns the index to branch to.
 #[inline]
 fn branch_to<N: Network, const VARIANT: u8>(
@@ -233,7 +435,9 @@ mod tests {
         assert!(!process.contains_program(program.id()));
 
         // Finalize the deployment.
-        let (stack, _) = process.finalize_deployment(vm.finalize_store(), &deployment).unwrap();
+        let private_key = PrivateKey::new(rng).unwrap();
+        let program_owner = ProgramOwner::new(&private_key, deployment.to_deployment_id().unwrap(), rng).unwrap();
+        let (stack, _) = process.finalize_deployment(vm.finalize_store(), &deployment, &program_owner).unwrap();
         // Add the stack *manually* to the process.
         process.add_stack(stack);
 
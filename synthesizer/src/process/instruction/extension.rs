@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An instruction-set extension is a named set of experimental opcodes.
+/// The opcodes of an extension are only enabled on the networks that list the extension
+/// in `Network::INSTRUCTION_SET_EXTENSIONS`, which allows new instructions to be trialed
+/// on test networks before they are enabled everywhere.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionSetExtension {
+    /// The name of the extension.
+    pub name: &'static str,
+    /// The opcodes introduced by the extension.
+    pub opcodes: &'static [Opcode],
+}
+
+impl InstructionSetExtension {
+    /// Returns `true` if the extension is enabled on the given network.
+    pub fn is_enabled<N: Network>(&self) -> bool {
+        N::INSTRUCTION_SET_EXTENSIONS.contains(&self.name)
+    }
+}
+
+impl<N: Network> Instruction<N> {
+    /// The list of all instruction-set extensions.
+    /// To add an experimental instruction, register its opcode in an extension here.
+    pub const EXTENSIONS: &'static [InstructionSetExtension] =
+        &[InstructionSetExtension { name: "p256", opcodes: &[Opcode::Literal("ecdsa.verify.p256")] }];
+
+    /// Returns the instruction-set extension that introduces the given opcode, if one exists.
+    pub fn extension(opcode: Opcode) -> Option<&'static InstructionSetExtension> {
+        find_extension(Self::EXTENSIONS, opcode)
+    }
+
+    /// Returns `true` if the given opcode is enabled on the network.
+    /// An opcode is enabled if it is not part of an extension, or if its extension is enabled on the network.
+    pub fn is_enabled(opcode: Opcode) -> bool {
+        match Self::extension(opcode) {
+            Some(extension) => extension.is_enabled::<N>(),
+            None => true,
+        }
+    }
+
+    /// Ensures the given opcode is enabled on the network.
+    pub fn ensure_is_enabled(opcode: Opcode) -> Result<()> {
+        match Self::extension(opcode) {
+            Some(extension) if !extension.is_enabled::<N>() => bail!(
+                "Opcode '{opcode}' requires the '{}' instruction-set extension, which is not enabled on '{}'",
+                extension.name,
+                N::NAME
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the extension in the given list that introduces the given opcode, if one exists.
+fn find_extension(
+    extensions: &'static [InstructionSetExtension],
+    opcode: Opcode,
+) -> Option<&'static InstructionSetExtension> {
+    extensions.iter().find(|extension| extension.opcodes.contains(&opcode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const SAMPLE_EXTENSIONS: &[InstructionSetExtension] =
+        &[InstructionSetExtension { name: "sample", opcodes: &[Opcode::Literal("sample.op")] }];
+
+    #[test]
+    fn test_stable_opcodes_are_enabled() {
+        // Ensure the stable opcodes are enabled.
+        for opcode in Instruction::<CurrentNetwork>::OPCODES {
+            if Instruction::<CurrentNetwork>::extension(*opcode).is_none() {
+                assert!(Instruction::<CurrentNetwork>::is_enabled(*opcode));
+                assert!(Instruction::<CurrentNetwork>::ensure_is_enabled(*opcode).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_extension_opcodes_are_disabled() {
+        // Ensure the extension opcodes are instructions, which are disabled on a network that does not opt in.
+        for extension in Instruction::<CurrentNetwork>::EXTENSIONS {
+            for opcode in extension.opcodes {
+                assert!(Instruction::<CurrentNetwork>::OPCODES.contains(opcode));
+                assert!(!Instruction::<CurrentNetwork>::is_enabled(*opcode));
+                assert!(Instruction::<CurrentNetwork>::ensure_is_enabled(*opcode).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_extension_is_gated_by_network() {
+        // Ensure the extension is found for its opcodes.
+        let extension = find_extension(SAMPLE_EXTENSIONS, Opcode::Literal("sample.op")).unwrap();
+        assert_eq!("sample", extension.name);
+        assert!(find_extension(SAMPLE_EXTENSIONS, Opcode::Literal("add")).is_none());
+
+        // Ensure the extension is not enabled on a network that does not opt in to it.
+        assert!(!extension.is_enabled::<CurrentNetwork>());
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::time::Instant;
+
+/// The histogram of the latency in seconds of an instruction, labeled by `opcode` and `operation`.
+pub const LATENCY: &str = "snarkvm_instruction_latency_seconds";
+/// The histogram of the latency in seconds of a hash or commit instruction, labeled by `function` and `operation`.
+pub const HASH_LATENCY: &str = "snarkvm_hash_latency_seconds";
+
+/// Records the latency of the given `operation` (i.e. `evaluate` or `execute`) on an instruction
+/// with the given `opcode`, which started at `start`.
+///
+/// Note: The latency of a `call` instruction includes the latency of the instructions in the callee.
+pub(super) fn record_latency(opcode: Opcode, operation: &'static str, start: Instant) {
+    let seconds = start.elapsed().as_secs_f64();
+    ::metrics::histogram!(LATENCY, seconds, "opcode" => *opcode, "operation" => operation);
+    // For a hash or commit instruction, record the latency of the hash function (i.e. `bhp256` in `hash.bhp256`).
+    if let Opcode::Hash(opcode) | Opcode::Commit(opcode) = opcode {
+        if let Some((_, function)) = opcode.split_once('.') {
+            ::metrics::histogram!(HASH_LATENCY, seconds, "function" => function, "operation" => operation);
+        }
+    }
+}
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod extension;
+pub use extension::*;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
 mod opcode;
 pub use opcode::*;
 
@@ -48,6 +54,7 @@ use console::{
             FromStr,
             IoResult,
             Parser,
+            ParserDiagnostic,
             ParserResult,
             Read,
             Result,
@@ -57,7 +64,7 @@ use console::{
         },
         Network,
     },
-    program::{Register, RegisterType},
+    program::{Identifier, Literal, ProgramID, Register, RegisterType},
 };
 use snarkvm_synthesizer_program::Operand;
 
@@ -181,6 +188,8 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Verifies the P-256 signature `(r, s)` on `digest` for the public key `(x, y)`, storing the outcome in `destination`.
+    EcdsaVerifyP256(EcdsaVerifyP256<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -271,6 +280,7 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            EcdsaVerifyP256,
         }}
     };
     // A variant **without** curly braces:
@@ -350,6 +360,19 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns the called program ID and resource, if the instruction is a call instruction.
+    /// The program ID is `None` if the resource is declared in the same program.
+    #[inline]
+    fn call_target(&self) -> Option<(Option<&ProgramID<N>>, &Identifier<N>)> {
+        match self {
+            Instruction::Call(call) => match call.operator() {
+                CallOperator::Locator(locator) => Some((Some(locator.program_id()), locator.resource())),
+                CallOperator::Resource(resource) => Some((None, resource)),
+            },
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the given name is a reserved opcode.
     #[inline]
     fn is_reserved_opcode(name: &str) -> bool {
@@ -374,24 +397,52 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.operands())
     }
 
+    /// Returns the instruction, with its operands and destination registers replaced by the given functions.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: &impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        instruction!(self, |instruction| Ok(Self::from(instruction.map_registers(operand, destination)?)))
+    }
+
+    /// Returns the output of the instruction, if it is a literal that can be computed without executing the instruction.
+    #[inline]
+    pub fn constant_output(&self) -> Option<Literal<N>> {
+        instruction!(self, |instruction| instruction.constant_output())
+    }
+
     /// Evaluates the instruction.
     #[inline]
+    #[allow(clippy::let_and_return)]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(
         &self,
         stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        instruction!(self, |instruction| instruction.evaluate(stack, registers))
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let result = instruction!(self, |instruction| instruction.evaluate(stack, registers));
+        #[cfg(feature = "metrics")]
+        metrics::record_latency(self.opcode(), "evaluate", start);
+        result
     }
 
     /// Executes the instruction.
     #[inline]
+    #[allow(clippy::let_and_return)]
     pub fn execute<A: circuit::Aleo<Network = N>>(
         &self,
         stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        instruction!(self, |instruction| instruction.execute::<A>(stack, registers))
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let result = instruction!(self, |instruction| instruction.execute::<A>(stack, registers));
+        #[cfg(feature = "metrics")]
+        metrics::record_latency(self.opcode(), "execute", start);
+        result
     }
 
     /// Finalizes the instruction.
@@ -440,7 +491,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            59,
+            60,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -15,7 +15,7 @@
 use crate::{Opcode, RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram};
 use console::{
     network::prelude::*,
-    program::{Register, RegisterType},
+    program::{Literal, Register, RegisterType},
 };
 use snarkvm_synthesizer_program::Operand;
 
@@ -61,6 +61,23 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the instruction, with its operands and destination registers replaced by the given functions.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        _destination: &impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        let operands = self.operands.iter().map(operand).collect::<Result<_>>()?;
+        Ok(Self { operands })
+    }
+
+    /// Returns the output of the instruction, if it is a literal that can be computed without executing the instruction.
+    #[inline]
+    pub fn constant_output(&self) -> Option<Literal<N>> {
+        None
+    }
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
@@ -192,11 +209,15 @@ impl<N: Network, const VARIANT: u8> FromStr for AssertInstruction<N, VARIANT> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -30,7 +30,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Locator, Register, RegisterType, Request, ValueType},
+    program::{Identifier, Literal, Locator, Register, RegisterType, Request, ValueType},
 };
 use snarkvm_synthesizer_program::Operand;
 
@@ -60,11 +60,15 @@ impl<N: Network> FromStr for CallOperator<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -156,6 +160,24 @@ impl<N: Network> Call<N> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         self.destinations.clone()
     }
+
+    /// Returns the instruction, with its operands and destination registers replaced by the given functions.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: &impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        let operands = self.operands.iter().map(operand).collect::<Result<_>>()?;
+        let destinations = self.destinations.iter().map(destination).collect::<Result<_>>()?;
+        Ok(Self { operator: self.operator.clone(), operands, destinations })
+    }
+
+    /// Returns the output of the instruction, if it is a literal that can be computed without executing the instruction.
+    #[inline]
+    pub fn constant_output(&self) -> Option<Literal<N>> {
+        None
+    }
 }
 
 impl<N: Network> Call<N> {
@@ -306,16 +328,15 @@ impl<N: Network> Call<N> {
             let num_public = A::num_public();
 
             use circuit::Eject;
-            // Eject the existing circuit.
-            let r1cs = A::eject_r1cs_and_reset();
-            let (request, response) = {
+            // Execute the call in an isolated circuit, restoring the existing circuit afterwards.
+            let (request, response) = A::isolated(|| -> Result<_> {
                 // Eject the circuit inputs.
                 let inputs = inputs.eject_value();
 
                 // Initialize an RNG.
                 let rng = &mut rand::thread_rng();
 
-                match registers.call_stack() {
+                Ok(match registers.call_stack() {
                     // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
                     CallStack::Authorize(_, private_key, authorization)
                     | CallStack::Synthesize(_, private_key, authorization) => {
@@ -391,10 +412,8 @@ impl<N: Network> Call<N> {
                         // Return the request and response.
                         (request, response)
                     }
-                }
-            };
-            // Inject the existing circuit.
-            A::inject_r1cs(r1cs);
+                })
+            })?;
 
             use circuit::Inject;
 
@@ -627,11 +646,15 @@ impl<N: Network> FromStr for Call<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -40,7 +40,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::Field,
+    types::{Field, Group},
 };
 use snarkvm_synthesizer_program::Operand;
 
@@ -51,6 +51,7 @@ use indexmap::IndexMap;
 pub enum CastType<N: Network> {
     GroupXCoordinate,
     GroupYCoordinate,
+    GroupFromXCoordinate,
     RegisterType(RegisterType<N>),
 }
 
@@ -60,6 +61,7 @@ impl<N: Network> Parser for CastType<N> {
         alt((
             map(tag("group.x"), |_| Self::GroupXCoordinate),
             map(tag("group.y"), |_| Self::GroupYCoordinate),
+            map(tag("group.from_x"), |_| Self::GroupFromXCoordinate),
             map(RegisterType::parse, |register_type| Self::RegisterType(register_type)),
         ))(string)
     }
@@ -70,6 +72,7 @@ impl<N: Network> Display for CastType<N> {
         match self {
             Self::GroupXCoordinate => write!(f, "group.x"),
             Self::GroupYCoordinate => write!(f, "group.y"),
+            Self::GroupFromXCoordinate => write!(f, "group.from_x"),
             Self::RegisterType(register_type) => write!(f, "{}", register_type),
         }
     }
@@ -90,11 +93,15 @@ impl<N: Network> FromStr for CastType<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -108,6 +115,7 @@ impl<N: Network> ToBytes for CastType<N> {
             Self::RegisterType(register_type) => {
                 2u8.write_le(&mut writer).and_then(|_| register_type.write_le(&mut writer))
             }
+            Self::GroupFromXCoordinate => 3u8.write_le(&mut writer),
         }
     }
 }
@@ -120,7 +128,8 @@ impl<N: Network> FromBytes for CastType<N> {
             0 => Ok(Self::GroupXCoordinate),
             1 => Ok(Self::GroupYCoordinate),
             2 => Ok(Self::RegisterType(RegisterType::read_le(&mut reader)?)),
-            3.. => Err(error(format!("Failed to deserialize cast type variant {variant}"))),
+            3 => Ok(Self::GroupFromXCoordinate),
+            4.. => Err(error(format!("Failed to deserialize cast type variant {variant}"))),
         }
     }
 }
@@ -155,6 +164,23 @@ impl<N: Network> Cast<N> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction, with its operands and destination registers replaced by the given functions.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: &impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        let operands = self.operands.iter().map(operand).collect::<Result<_>>()?;
+        Ok(Self { operands, destination: destination(&self.destination)?, cast_type: self.cast_type.clone() })
+    }
+
+    /// Returns the output of the instruction, if it is a literal that can be computed without executing the instruction.
+    #[inline]
+    pub fn constant_output(&self) -> Option<Literal<N>> {
+        None
+    }
+
     /// Returns the register type.
     #[inline]
     pub fn register_type(&self) -> &RegisterType<N> {
@@ -162,6 +188,7 @@ impl<N: Network> Cast<N> {
             CastType::GroupXCoordinate | CastType::GroupYCoordinate => {
                 &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))
             }
+            CastType::GroupFromXCoordinate => &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group)),
             CastType::RegisterType(register_type) => register_type,
         }
     }
@@ -201,6 +228,17 @@ impl<N: Network> Cast<N> {
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(field))))
             }
+            CastType::GroupFromXCoordinate => {
+                ensure!(inputs.len() == 2, "Casting from a group x-coordinate requires exactly 2 operands");
+                let group = match (&inputs[0], &inputs[1]) {
+                    (
+                        Value::Plaintext(Plaintext::Literal(Literal::Field(x_coordinate), ..)),
+                        Value::Plaintext(Plaintext::Literal(Literal::Boolean(sign), ..)),
+                    ) => Group::from_x_coordinate_with_sign(*x_coordinate, **sign)?,
+                    _ => bail!("Casting from a group x-coordinate requires a field element and a boolean"),
+                };
+                registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Group(group))))
+            }
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(literal_type))) => {
                 ensure!(inputs.len() == 1, "Casting to a literal requires exactly 1 operand");
                 let value = match &inputs[0] {
@@ -329,6 +367,24 @@ impl<N: Network> Cast<N> {
                     circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::Field(field))),
                 )
             }
+            CastType::GroupFromXCoordinate => {
+                ensure!(inputs.len() == 2, "Casting from a group x-coordinate requires exactly 2 operands");
+                let group = match (&inputs[0], &inputs[1]) {
+                    (
+                        circuit::Value::Plaintext(circuit::Plaintext::Literal(
+                            circuit::Literal::Field(x_coordinate),
+                            ..,
+                        )),
+                        circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Boolean(sign), ..)),
+                    ) => circuit::Group::from_x_coordinate_with_sign(x_coordinate.clone(), sign),
+                    _ => bail!("Casting from a group x-coordinate requires a field element and a boolean"),
+                };
+                registers.store_circuit(
+                    stack,
+                    &self.destination,
+                    circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::Group(group))),
+                )
+            }
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(literal_type))) => {
                 ensure!(inputs.len() == 1, "Casting to a literal requires exactly 1 operand");
                 let value = match &inputs[0] {
@@ -502,6 +558,17 @@ impl<N: Network> Cast<N> {
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(field))))
             }
+            CastType::GroupFromXCoordinate => {
+                ensure!(inputs.len() == 2, "Casting from a group x-coordinate requires exactly 2 operands");
+                let group = match (&inputs[0], &inputs[1]) {
+                    (
+                        Value::Plaintext(Plaintext::Literal(Literal::Field(x_coordinate), ..)),
+                        Value::Plaintext(Plaintext::Literal(Literal::Boolean(sign), ..)),
+                    ) => Group::from_x_coordinate_with_sign(*x_coordinate, **sign)?,
+                    _ => bail!("Casting from a group x-coordinate requires a field element and a boolean"),
+                };
+                registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Group(group))))
+            }
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(literal_type))) => {
                 ensure!(inputs.len() == 1, "Casting to a literal requires exactly 1 operand");
                 let value = match &inputs[0] {
@@ -548,6 +615,19 @@ impl<N: Network> Cast<N> {
                     input_types[0]
                 );
             }
+            CastType::GroupFromXCoordinate => {
+                ensure!(input_types.len() == 2, "Casting from a group x-coordinate requires exactly 2 operands");
+                ensure!(
+                    matches!(input_types[0], RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))),
+                    "Type mismatch: expected 'field', found '{}'",
+                    input_types[0]
+                );
+                ensure!(
+                    matches!(input_types[1], RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))),
+                    "Type mismatch: expected 'boolean', found '{}'",
+                    input_types[1]
+                );
+            }
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(..))) => {
                 ensure!(input_types.len() == 1, "Casting to a literal requires exactly 1 operand");
             }
@@ -649,6 +729,7 @@ impl<N: Network> Cast<N> {
             CastType::GroupXCoordinate | CastType::GroupYCoordinate => {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))
             }
+            CastType::GroupFromXCoordinate => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group)),
             CastType::RegisterType(register_type) => register_type,
         }])
     }
@@ -743,6 +824,7 @@ impl<N: Network> Parser for Cast<N> {
             CastType::GroupXCoordinate
             | CastType::GroupYCoordinate
             | CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(_))) => 1,
+            CastType::GroupFromXCoordinate => 2,
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(_))) => N::MAX_STRUCT_ENTRIES,
             CastType::RegisterType(RegisterType::Record(_))
             | CastType::RegisterType(RegisterType::ExternalRecord(_)) => N::MAX_RECORD_ENTRIES,
@@ -767,11 +849,15 @@ impl<N: Network> FromStr for Cast<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
@@ -791,6 +877,7 @@ impl<N: Network> Display for Cast<N> {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
             | CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(_))) => 1,
+            CastType::GroupFromXCoordinate => 2,
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(_))) => N::MAX_STRUCT_ENTRIES,
             CastType::RegisterType(RegisterType::Record(_))
             | CastType::RegisterType(RegisterType::ExternalRecord(_)) => N::MAX_RECORD_ENTRIES,
@@ -836,6 +923,7 @@ impl<N: Network> FromBytes for Cast<N> {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
             | CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(_))) => 1,
+            CastType::GroupFromXCoordinate => 2,
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(_))) => N::MAX_STRUCT_ENTRIES,
             CastType::RegisterType(RegisterType::Record(_))
             | CastType::RegisterType(RegisterType::ExternalRecord(_)) => N::MAX_RECORD_ENTRIES,
@@ -857,6 +945,7 @@ impl<N: Network> ToBytes for Cast<N> {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
             | CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(_))) => 1,
+            CastType::GroupFromXCoordinate => 2,
             CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(_))) => N::MAX_STRUCT_ENTRIES,
             CastType::RegisterType(RegisterType::Record(_))
             | CastType::RegisterType(RegisterType::ExternalRecord(_)) => N::MAX_RECORD_ENTRIES,
@@ -907,6 +996,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_from_group_x_coordinate() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 r1 into r2 as group.from_x").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(cast.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(cast.cast_type, CastType::GroupFromXCoordinate, "The cast type is incorrect");
+        assert_eq!(cast.to_string(), "cast r0 r1 into r2 as group.from_x");
+
+        // Ensure the cast round-trips through bytes.
+        let bytes = cast.to_bytes_le().unwrap();
+        assert_eq!(cast, Cast::<CurrentNetwork>::from_bytes_le(&bytes).unwrap());
+
+        // Ensure the cast does not accept more than two operands.
+        assert!(Cast::<CurrentNetwork>::parse("cast r0 r1 r2 into r3 as group.from_x").is_err());
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
@@ -96,6 +96,23 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         vec![self.destination.clone()]
     }
 
+    /// Returns the instruction, with its operands and destination registers replaced by the given functions.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: &impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        let operands = self.operands.iter().map(operand).collect::<Result<_>>()?;
+        Ok(Self { operands, destination: destination(&self.destination)?, destination_type: self.destination_type })
+    }
+
+    /// Returns the output of the instruction, if it is a literal that can be computed without executing the instruction.
+    #[inline]
+    pub fn constant_output(&self) -> Option<Literal<N>> {
+        None
+    }
+
     /// Returns the destination register type.
     #[inline]
     pub const fn destination_type(&self) -> LiteralType {
@@ -275,11 +292,15 @@ impl<N: Network, const VARIANT: u8> FromStr for CommitInstruction<N, VARIANT> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
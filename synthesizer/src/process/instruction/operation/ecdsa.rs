@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Literals, Opcode, Operation};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType},
+    types::Boolean,
+};
+
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+/// Verifies an ECDSA signature over the NIST P-256 curve (i.e. a passkey signature), storing the outcome in `destination`.
+///
+/// The public key `(x, y)`, the digest, and the signature `(r, s)` are each given as two `u128` operands,
+/// with the most-significant half first, i.e.
+/// `ecdsa.verify.p256 x_hi x_lo y_hi y_lo digest_hi digest_lo r_hi r_lo s_hi s_lo into destination;`.
+/// A public key that is not on the curve, or a signature that is not in `[1, n)`, halts the program.
+pub type EcdsaVerifyP256<N> = Literals<N, EcdsaVerifyP256Operation<N>, 10>;
+
+/// The implementation of the P-256 signature verification.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EcdsaVerifyP256Operation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 10> for EcdsaVerifyP256Operation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("ecdsa.verify.p256");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 10]) -> Result<Literal<N>> {
+        // Combine the halves of each 256-bit integer.
+        let integers = inputs
+            .chunks(2)
+            .map(|halves| match halves {
+                [Literal::U128(high), Literal::U128(low)] => Ok((BigUint::from(**high) << 128u32) + **low),
+                _ => bail!("Instruction '{}' expects 'u128' operands", Self::OPCODE),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let [x, y, digest, r, s] = <[BigUint; 5]>::try_from(integers).map_err(|_| anyhow!("Expected 5 integers"))?;
+        // Verify the signature.
+        let is_valid = console::algorithms::P256::verify(&(x, y), &digest, &r, &s)?;
+        Ok(Literal::Boolean(Boolean::new(is_valid)))
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 10]) -> Result<circuit::Literal<A>> {
+        use circuit::ToBits;
+
+        // Combine the halves of each 256-bit integer, in little-endian order.
+        let bits = inputs
+            .chunks(2)
+            .map(|halves| match halves {
+                [circuit::Literal::U128(high), circuit::Literal::U128(low)] => {
+                    Ok([low.to_bits_le(), high.to_bits_le()].concat())
+                }
+                _ => bail!("Instruction '{}' expects 'u128' operands", Self::OPCODE),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Verify the signature.
+        let is_valid = circuit::P256::<A>::verify(&bits[0], &bits[1], &bits[2], &bits[3], &bits[4]);
+        Ok(circuit::Literal::Boolean(is_valid))
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 10]) -> Result<LiteralType> {
+        match inputs.iter().all(|input| *input == LiteralType::U128) {
+            true => Ok(LiteralType::Boolean),
+            false => bail!("Instruction '{}' expects 'u128' operands", Self::OPCODE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{AleoV0, Eject, Inject};
+    use console::{network::Testnet3, types::U128};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Returns the given 256-bit integers as `u128` halves, with the most-significant half first.
+    fn sample_inputs(integers: [&BigUint; 5]) -> [Literal<CurrentNetwork>; 10] {
+        let mask = (BigUint::from(1u64) << 128u32) - 1u64;
+        let halves = integers
+            .iter()
+            .flat_map(|integer| [*integer >> 128u32, *integer & &mask])
+            .map(|half| Literal::U128(U128::new(u128::try_from(half).unwrap())))
+            .collect::<Vec<_>>();
+        halves.try_into().unwrap()
+    }
+
+    /// Returns a valid signature, as `(public_key, digest, r, s)`.
+    fn sample_signature(rng: &mut TestRng) -> ((BigUint, BigUint), BigUint, BigUint, BigUint) {
+        let mut sample_scalar = || BigUint::from_bytes_le(&(0..31).map(|_| rng.gen()).collect::<Vec<u8>>()) + 1u64;
+        let (private_key, nonce) = (sample_scalar(), sample_scalar());
+        let digest = sample_scalar();
+        let public_key = console::algorithms::P256::public_key(&private_key).unwrap();
+        let (r, s) = console::algorithms::P256::sign(&private_key, &digest, &nonce).unwrap();
+        (public_key, digest, r, s)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut rng = TestRng::default();
+        let ((x, y), digest, r, s) = sample_signature(&mut rng);
+
+        // Ensure a valid signature verifies.
+        let inputs = sample_inputs([&x, &y, &digest, &r, &s]);
+        let output = EcdsaVerifyP256Operation::<CurrentNetwork>::evaluate(&inputs).unwrap();
+        assert_eq!(Literal::Boolean(Boolean::new(true)), output);
+
+        // Ensure the signature does not verify for a different digest.
+        let inputs = sample_inputs([&x, &y, &(&digest + 1u64), &r, &s]);
+        let output = EcdsaVerifyP256Operation::<CurrentNetwork>::evaluate(&inputs).unwrap();
+        assert_eq!(Literal::Boolean(Boolean::new(false)), output);
+
+        // Ensure a public key that is not on the curve halts.
+        let inputs = sample_inputs([&x, &(&y + 1u64), &digest, &r, &s]);
+        assert!(EcdsaVerifyP256Operation::<CurrentNetwork>::evaluate(&inputs).is_err());
+    }
+
+    #[test]
+    fn test_execute_matches_evaluate() {
+        let mut rng = TestRng::default();
+        let ((x, y), digest, r, s) = sample_signature(&mut rng);
+
+        for digest in [digest.clone(), &digest + 1u64] {
+            let inputs = sample_inputs([&x, &y, &digest, &r, &s]);
+            let expected = EcdsaVerifyP256Operation::<CurrentNetwork>::evaluate(&inputs).unwrap();
+            // Note: Constant inputs are used, as private inputs synthesize millions of constraints.
+            let circuit_inputs =
+                inputs.map(|input| circuit::Literal::<CurrentAleo>::new(circuit::Mode::Constant, input));
+            let candidate = EcdsaVerifyP256Operation::<CurrentNetwork>::execute(&circuit_inputs).unwrap();
+            assert_eq!(expected, candidate.eject_value());
+        }
+    }
+
+    #[test]
+    fn test_output_type() {
+        let inputs = [LiteralType::U128; 10];
+        assert_eq!(LiteralType::Boolean, EcdsaVerifyP256Operation::<CurrentNetwork>::output_type(&inputs).unwrap());
+
+        let mut inputs = [LiteralType::U128; 10];
+        inputs[3] = LiteralType::Field;
+        assert!(EcdsaVerifyP256Operation::<CurrentNetwork>::output_type(&inputs).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let string = "ecdsa.verify.p256 r0 r1 r2 r3 r4 r5 r6 r7 r8 r9 into r10";
+        let (remainder, instruction) = EcdsaVerifyP256::<CurrentNetwork>::parse(string).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(10, instruction.operands().len());
+        assert_eq!(string, instruction.to_string());
+    }
+}
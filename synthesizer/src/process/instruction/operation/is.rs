@@ -72,6 +72,34 @@ impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction, with its operands and destination registers replaced by the given functions.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: &impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        let operands = self.operands.iter().map(operand).collect::<Result<_>>()?;
+        Ok(Self { operands, destination: destination(&self.destination)? })
+    }
+
+    /// Returns the output of the instruction, if it is a literal that can be computed without executing the instruction.
+    /// This is the case if the operands are identical, or are literals of the same type.
+    #[inline]
+    pub fn constant_output(&self) -> Option<Literal<N>> {
+        let is_equal = match (&self.operands[0], &self.operands[1]) {
+            (Operand::Literal(a), Operand::Literal(b)) if a.to_type() == b.to_type() => a == b,
+            (Operand::Literal(..), _) | (_, Operand::Literal(..)) => return None,
+            (a, b) if a == b => true,
+            _ => return None,
+        };
+        match VARIANT {
+            0 => Some(Literal::Boolean(Boolean::new(is_equal))),
+            1 => Some(Literal::Boolean(Boolean::new(!is_equal))),
+            _ => None,
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
@@ -207,11 +235,15 @@ impl<N: Network, const VARIANT: u8> FromStr for IsInstruction<N, VARIANT> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
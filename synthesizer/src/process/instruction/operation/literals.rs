@@ -67,6 +67,39 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the instruction, with its operands and destination registers replaced by the given functions.
+    #[inline]
+    pub fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Result<Operand<N>>,
+        destination: &impl Fn(&Register<N>) -> Result<Register<N>>,
+    ) -> Result<Self> {
+        let operands = self.operands.iter().map(operand).collect::<Result<_>>()?;
+        Ok(Self { operands, destination: destination(&self.destination)?, _phantom: PhantomData })
+    }
+
+    /// Returns the output of the instruction, if it is a literal that can be computed without executing the instruction.
+    /// This is the case if every operand is a literal and the operation succeeds on them.
+    #[inline]
+    pub fn constant_output(&self) -> Option<Literal<N>> {
+        // Retrieve the literal operands.
+        let inputs = self
+            .operands
+            .iter()
+            .map(|operand| match operand {
+                Operand::Literal(literal) => Some(literal.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let inputs: [Literal<N>; NUM_OPERANDS] = inputs.try_into().ok()?;
+        // Ensure the operand types are valid.
+        let output_type = O::output_type(&inputs.clone().map(|input| input.to_type())).ok()?;
+        // Evaluate the operation, which fails if the instruction would halt.
+        let output = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| O::evaluate(&inputs))).ok()?.ok()?;
+        // Ensure the output type is correct.
+        (output.to_type() == output_type).then_some(output)
+    }
 }
 
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
@@ -253,11 +286,15 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string. {}",
+                    ParserDiagnostic::remainder(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string. {}", ParserDiagnostic::new(string, &error)),
         }
     }
 }
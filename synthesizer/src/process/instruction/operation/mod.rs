@@ -24,6 +24,9 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod ecdsa;
+pub use ecdsa::*;
+
 mod hash;
 pub use hash::*;
 
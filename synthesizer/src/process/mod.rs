@@ -18,6 +18,9 @@ pub use command::*;
 mod instruction;
 pub use instruction::*;
 
+mod optimize;
+pub use optimize::*;
+
 mod stack;
 pub use stack::*;
 
@@ -34,21 +37,23 @@ mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
 
+pub use finalize::FinalizeFailure;
+
 #[cfg(test)]
 mod tests;
 
 use crate::{
     atomic_batch_scope,
-    block::{Deployment, Execution, Fee, FinalizeOperation, Input, Transition},
+    block::{Deployment, Execution, Fee, FinalizeOperation, Input, Output, Transition},
     store::{FinalizeStorage, FinalizeStore},
 };
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Request, Response, Value},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Request, Response, Value},
     types::{Field, U16, U64},
 };
-use snarkvm_synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use snarkvm_synthesizer_snark::{PreparedVerifyingKey, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -69,6 +74,8 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The mapping of circuit IDs to prepared verifying keys.
+    prepared_verifying_keys: Arc<RwLock<HashMap<[u8; 32], PreparedVerifyingKey<N>>>>,
 }
 
 impl<N: Network> Process<N> {
@@ -78,7 +85,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            prepared_verifying_keys: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -96,6 +107,15 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Synthesize credits program keys");
 
+        // Synthesize the inclusion circuit keys, if the network does not load them.
+        N::synthesize_inclusion_keys(|| {
+            let assignment = InclusionAssignment::<N>::sample(rng)?.to_circuit_assignment::<A>()?;
+            let (proving_key, verifying_key) =
+                process.universal_srs.to_circuit_key(N::INCLUSION_FUNCTION_NAME, &assignment)?;
+            Ok((Arc::new((*proving_key).clone()), Arc::new((*verifying_key).clone())))
+        })?;
+        lap!(timer, "Synthesize inclusion keys");
+
         // Add the 'credits.aleo' stack to the process.
         process.add_stack(stack);
 
@@ -113,6 +133,14 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
+    /// Adds the new programs to the process, in the order of their imports.
+    /// If you intend to `execute` the programs, use `deploy` and `finalize_deployment` instead.
+    #[inline]
+    pub fn add_programs(&mut self, programs: &[Program<N>]) -> Result<()> {
+        // Compute the program stacks, and add them to the process.
+        self.add_stacks_in_import_order(programs, |program| program, Stack::new)
+    }
+
     /// Adds a new stack to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
@@ -129,34 +157,29 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            prepared_verifying_keys: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
         lap!(timer, "Load credits program");
 
-        // Compute the 'credits.aleo' program stack.
-        let stack = Stack::new(&process, &program)?;
+        // Compute the 'credits.aleo' program stack, which loads the proving keys on first use.
+        let stack = Stack::new(&process, &program)?.with_lazy_credits_keys();
         lap!(timer, "Initialize stack");
 
-        // Synthesize the 'credits.aleo' circuit keys.
+        // Load the 'credits.aleo' verifying keys.
         for function_name in program.functions().keys() {
-            // Load the proving key.
-            let proving_key = N::get_credits_proving_key(function_name.to_string())?;
-            stack.insert_proving_key(function_name, ProvingKey::new(proving_key.clone()))?;
-            lap!(timer, "Load proving key for {function_name}");
-
             // Load the verifying key.
             let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
             stack.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone()))?;
             lap!(timer, "Load verifying key for {function_name}");
         }
-        lap!(timer, "Load circuit keys");
-
-        // Initialize the inclusion proving key.
-        let _ = N::inclusion_proving_key();
-        lap!(timer, "Load inclusion proving key");
+        lap!(timer, "Load verifying keys");
 
         // Initialize the inclusion verifying key.
         let _ = N::inclusion_verifying_key();
@@ -175,7 +198,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            prepared_verifying_keys: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -196,7 +223,11 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn load_with_cache(cache: &mut HashMap<String, (ProvingKey<N>, VerifyingKey<N>)>) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            prepared_verifying_keys: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -282,6 +313,29 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.get_verifying_key(&function_name)
     }
 
+    /// Returns the prepared verifying key for the given verifying key.
+    /// The prepared verifying key is cached, so that verifying many proofs for the same function only prepares it once.
+    #[inline]
+    pub fn get_prepared_verifying_key(&self, verifying_key: &VerifyingKey<N>) -> Result<PreparedVerifyingKey<N>> {
+        // Note: As the circuit ID is claimed by the verifying key, a cached key is only used if it was prepared
+        // from an identical verifying key.
+        if let Some(prepared_verifying_key) = self.prepared_verifying_keys.read().get(&verifying_key.id.0) {
+            if prepared_verifying_key.is_prepared_from(verifying_key) {
+                return Ok(prepared_verifying_key.clone());
+            }
+        }
+        // Prepare the verifying key, and cache it.
+        let prepared_verifying_key = PreparedVerifyingKey::prepare(verifying_key)?;
+        self.prepared_verifying_keys.write().insert(verifying_key.id.0, prepared_verifying_key.clone());
+        Ok(prepared_verifying_key)
+    }
+
+    /// Returns the prepared inclusion verifying key.
+    #[inline]
+    pub fn get_prepared_inclusion_verifying_key(&self) -> Result<PreparedVerifyingKey<N>> {
+        self.get_prepared_verifying_key(&VerifyingKey::new(N::inclusion_verifying_key().clone()))
+    }
+
     /// Inserts the given proving key, for the given program ID and function name.
     #[inline]
     pub fn insert_proving_key(
@@ -315,6 +369,18 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Synthesizes the proving and verifying key of the expiring circuit for the given program ID and function name.
+    #[inline]
+    pub fn synthesize_expiring_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Synthesize the proving and verifying key.
+        self.get_stack(program_id)?.synthesize_expiring_key::<A, R>(function_name, rng)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
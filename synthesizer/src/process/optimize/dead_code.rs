@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+/// Returns the program, with the dead instructions removed from each closure and function.
+/// An instruction is dead if none of its destination registers are consumed, and it cannot halt.
+pub(super) fn eliminate_dead_code<N: Network>(program: &Program<N>) -> Result<Program<N>> {
+    let mut optimized = program.clone();
+
+    // Remove the dead instructions in each closure.
+    for closure in program.closures().values() {
+        let outputs = closure.outputs().iter().map(|output| output.operand());
+        let (instructions, registers) =
+            remove_dead_instructions(closure.inputs().len(), closure.instructions(), outputs)?;
+        optimized.replace_closure(rebuild_closure(closure, &instructions, &registers)?)?;
+    }
+
+    // Remove the dead instructions in each function.
+    for function in program.functions().values() {
+        // Note: The finalize operands are consumed in addition to the outputs.
+        let outputs = function
+            .outputs()
+            .iter()
+            .map(|output| output.operand())
+            .chain(function.finalize_command().into_iter().flat_map(|command| command.operands()));
+        let (instructions, registers) =
+            remove_dead_instructions(function.inputs().len(), function.instructions(), outputs)?;
+        optimized.replace_function(rebuild_function(function, &instructions, &registers)?)?;
+    }
+
+    Ok(optimized)
+}
+
+/// Returns the given instructions with the dead instructions removed, and the register map of the result.
+fn remove_dead_instructions<'a, N: Network>(
+    num_inputs: usize,
+    instructions: &[Instruction<N>],
+    outputs: impl Iterator<Item = &'a Operand<N>>,
+) -> Result<(Vec<Instruction<N>>, RegisterMap<N>)> {
+    /// Returns the register locator of the given operand, if it is a register.
+    fn locator<N: Network>(operand: &Operand<N>) -> Option<u64> {
+        match operand {
+            Operand::Register(register) => Some(register.locator()),
+            _ => None,
+        }
+    }
+
+    // Initialize the live registers, from the outputs.
+    let mut live = outputs.filter_map(locator).collect::<HashSet<_>>();
+
+    // Determine the live instructions, in reverse order.
+    let mut is_live = vec![false; instructions.len()];
+    for (index, instruction) in instructions.iter().enumerate().rev() {
+        // An instruction is live if it can halt, or if one of its destination registers is live.
+        if !is_removable(instruction)
+            || instruction.destinations().iter().any(|destination| live.contains(&destination.locator()))
+        {
+            is_live[index] = true;
+            live.extend(instruction.operands().iter().filter_map(locator));
+        }
+    }
+
+    // Rewrite the live instructions, which reassigns their destination registers in order.
+    let mut registers = RegisterMap::new(num_inputs);
+    let instructions = instructions
+        .iter()
+        .zip_eq(is_live)
+        .filter(|(_, is_live)| *is_live)
+        .map(|(instruction, _)| registers.rewrite(instruction))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((instructions, registers))
+}
+
+/// Returns `true` if the given instruction may be removed when its destination registers are not consumed.
+/// This holds for instructions that cannot halt on well-typed operands, so removing them does not change
+/// which inputs the closure or function accepts. Assertions, calls, casts, and checked arithmetic are retained.
+fn is_removable<N: Network>(instruction: &Instruction<N>) -> bool {
+    matches!(
+        instruction,
+        Instruction::AbsWrapped(..)
+            | Instruction::AddWrapped(..)
+            | Instruction::And(..)
+            | Instruction::CommitBHP256(..)
+            | Instruction::CommitBHP512(..)
+            | Instruction::CommitBHP768(..)
+            | Instruction::CommitBHP1024(..)
+            | Instruction::CommitPED64(..)
+            | Instruction::CommitPED128(..)
+            | Instruction::Double(..)
+            | Instruction::GreaterThan(..)
+            | Instruction::GreaterThanOrEqual(..)
+            | Instruction::HashBHP256(..)
+            | Instruction::HashBHP512(..)
+            | Instruction::HashBHP768(..)
+            | Instruction::HashBHP1024(..)
+            | Instruction::HashPED64(..)
+            | Instruction::HashPED128(..)
+            | Instruction::HashPSD2(..)
+            | Instruction::HashPSD4(..)
+            | Instruction::HashPSD8(..)
+            | Instruction::IsEq(..)
+            | Instruction::IsNeq(..)
+            | Instruction::LessThan(..)
+            | Instruction::LessThanOrEqual(..)
+            | Instruction::MulWrapped(..)
+            | Instruction::Nand(..)
+            | Instruction::Nor(..)
+            | Instruction::Not(..)
+            | Instruction::Or(..)
+            | Instruction::PowWrapped(..)
+            | Instruction::ShlWrapped(..)
+            | Instruction::ShrWrapped(..)
+            | Instruction::Square(..)
+            | Instruction::SubWrapped(..)
+            | Instruction::Ternary(..)
+            | Instruction::Xor(..)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_eliminate_dead_code() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program dead_code.aleo;
+
+mapping totals:
+    key owner as u8.public;
+    value total as u8.public;
+
+closure mix:
+    input r0 as u8;
+    input r1 as u8;
+    xor r0 r1 into r2;
+    and r0 r1 into r3;
+    output r3 as u8;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    is.eq r0 r1 into r2;
+    ternary r2 r0 r1 into r3;
+    add r0 r1 into r4;
+    hash.psd2 r0 into r5 as field;
+    add.w r0 r1 into r6;
+    call mix r6 r1 into r7;
+    sub.w r0 r1 into r8;
+    output r6 as u8.private;
+    finalize r8;
+
+finalize compute:
+    input r0 as u8.public;
+    set r0 into totals[0u8];",
+        )
+        .unwrap();
+
+        let optimized = Optimizer::new().with_dead_code_elimination().optimize(&program).unwrap();
+
+        // Ensure the dead instructions are removed from the closure.
+        let closure = optimized.get_closure(&Identifier::from_str("mix").unwrap()).unwrap();
+        let expected = r"closure mix:
+    input r0 as u8;
+    input r1 as u8;
+    and r0 r1 into r2;
+    output r2 as u8;";
+        assert_eq!(expected, closure.to_string());
+
+        // Ensure the dead instructions are removed from the function, and the checked addition and call are retained.
+        let function = optimized.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+        let expected = r"function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    add.w r0 r1 into r3;
+    call mix r3 r1 into r4;
+    sub.w r0 r1 into r5;
+    output r3 as u8.private;
+   finalize r5;
+
+finalize compute:
+    input r0 as u8.public;
+    set r0 into totals[0u8];";
+        assert_eq!(expected, function.to_string());
+
+        // Ensure the optimization is idempotent.
+        assert_eq!(optimized, Optimizer::new().with_dead_code_elimination().optimize(&optimized).unwrap());
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Identifier;
+
+use indexmap::IndexMap;
+
+/// Returns the program, with each call to a closure of at most `max_instructions` instructions
+/// replaced by the instructions of the closure.
+pub(super) fn inline_closures<N: Network>(program: &Program<N>, max_instructions: usize) -> Result<Program<N>> {
+    let mut optimized = program.clone();
+
+    // Inline the calls in each closure, in declaration order, so a closure is inlined after its own calls are.
+    for closure in program.closures().values() {
+        let (instructions, registers) =
+            inline_calls(closure.inputs().len(), closure.instructions(), optimized.closures(), max_instructions)?;
+        optimized.replace_closure(rebuild_closure(closure, &instructions, &registers)?)?;
+    }
+
+    // Inline the calls in each function.
+    for function in program.functions().values() {
+        let (instructions, registers) =
+            inline_calls(function.inputs().len(), function.instructions(), optimized.closures(), max_instructions)?;
+        optimized.replace_function(rebuild_function(function, &instructions, &registers)?)?;
+    }
+
+    Ok(optimized)
+}
+
+/// Returns the given instructions with the eligible closure calls inlined, and the register map of the result.
+fn inline_calls<N: Network>(
+    num_inputs: usize,
+    instructions: &[Instruction<N>],
+    closures: &IndexMap<Identifier<N>, Closure<N>>,
+    max_instructions: usize,
+) -> Result<(Vec<Instruction<N>>, RegisterMap<N>)> {
+    let mut registers = RegisterMap::new(num_inputs);
+    let mut inlined = Vec::with_capacity(instructions.len());
+
+    for instruction in instructions {
+        // Retrieve the closure, if the instruction is a call to a closure in this program that is small enough.
+        let closure = match instruction.call_target() {
+            Some((None, name)) => closures.get(name).filter(|closure| closure.instructions().len() <= max_instructions),
+            _ => None,
+        };
+
+        let Some(closure) = closure else {
+            inlined.push(registers.rewrite(instruction)?);
+            continue;
+        };
+
+        // Ensure the call matches the closure signature.
+        ensure!(
+            closure.inputs().len() == instruction.operands().len()
+                && closure.outputs().len() == instruction.destinations().len(),
+            "Call to closure '{}' does not match its signature",
+            closure.name()
+        );
+
+        // Bind the closure inputs to the call operands.
+        let mut closure_registers = registers.scope();
+        for (input, operand) in closure.inputs().iter().zip(instruction.operands()) {
+            closure_registers.alias(input.register(), registers.operand(operand)?);
+        }
+        // Inline the closure instructions.
+        for closure_instruction in closure.instructions() {
+            inlined.push(closure_registers.rewrite(closure_instruction)?);
+        }
+        registers.next = closure_registers.next;
+
+        // Bind the call destinations to the closure outputs.
+        for (destination, output) in instruction.destinations().iter().zip(closure.outputs()) {
+            registers.alias(destination, closure_registers.operand(output.operand())?);
+        }
+    }
+
+    Ok((inlined, registers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the program with the small closures inlined.
+    fn inline(program: &str, max_instructions: usize) -> Program<CurrentNetwork> {
+        let program = Program::from_str(program).unwrap();
+        Optimizer::new().with_closure_inlining(max_instructions).optimize(&program).unwrap()
+    }
+
+    #[test]
+    fn test_inline_closures() {
+        let program = r"
+program inline.aleo;
+
+struct pair:
+    first as u8;
+    second as u8;
+
+closure sum:
+    input r0 as pair;
+    add r0.first r0.second into r1;
+    output r1 as u8;
+
+closure sum_squared:
+    input r0 as u8;
+    input r1 as u8;
+    cast r0 r1 into r2 as pair;
+    call sum r2 into r3;
+    mul r3 r3 into r4;
+    output r4 as u8;
+
+closure first:
+    input r0 as u8;
+    input r1 as u8;
+    add r0 r1 into r2;
+    output r0 as u8;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call sum_squared r0 r1 into r2;
+    call first 1u8 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as u8.private;";
+
+        // Ensure the closures are inlined, and the registers are reassigned in order.
+        let optimized = inline(program, 4);
+        let function = optimized.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+        let expected = r"function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    cast r0 r1 into r2 as pair;
+    add r2.first r2.second into r3;
+    mul r3 r3 into r4;
+    add 1u8 r4 into r5;
+    add r4 1u8 into r6;
+    output r6 as u8.private;";
+        assert_eq!(expected, function.to_string());
+
+        // Ensure the closures remain in the program, with their own calls inlined.
+        assert_eq!(3, optimized.closures().len());
+        let closure = optimized.get_closure(&Identifier::from_str("sum_squared").unwrap()).unwrap();
+        assert_eq!(3, closure.instructions().len());
+        assert!(closure.instructions().iter().all(|instruction| instruction.call_target().is_none()));
+
+        // Ensure the closures above the threshold are not inlined.
+        let optimized = inline(program, 1);
+        let function = optimized.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+        let expected = r"function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call sum_squared r0 r1 into r2;
+    add 1u8 r2 into r3;
+    add r2 1u8 into r4;
+    output r4 as u8.private;";
+        assert_eq!(expected, function.to_string());
+
+        // Ensure the program is unchanged with every optimization disabled.
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        assert_eq!(program, Optimizer::new().without_peephole().optimize(&program).unwrap());
+    }
+}
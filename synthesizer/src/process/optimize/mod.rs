@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod dead_code;
+mod inline;
+mod peephole;
+
+use crate::{Closure, Function, Instruction, Program};
+use console::{network::prelude::*, program::Register};
+use snarkvm_synthesizer_program::{InstructionTrait, Operand};
+
+use std::collections::HashMap;
+
+/// An optimizer for programs, which rewrites a program into an equivalent program that synthesizes into fewer constraints.
+///
+/// The optimizations are optional, and must be applied before the program is deployed,
+/// as the optimized program is synthesized into different circuit keys than the original program.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Optimizer {
+    /// Whether wasteful instruction patterns are rewritten, which is enabled by default.
+    peephole: bool,
+    /// The maximum number of instructions in a closure that is inlined at its call sites, if inlining is enabled.
+    max_inline_instructions: Option<usize>,
+    /// Whether instructions whose destination registers are never consumed are removed.
+    eliminate_dead_code: bool,
+}
+
+impl Default for Optimizer {
+    /// Initializes a new optimizer, with the peephole optimizations enabled.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Optimizer {
+    /// Initializes a new optimizer, with the peephole optimizations enabled, and every other optimization disabled.
+    pub const fn new() -> Self {
+        Self { peephole: true, max_inline_instructions: None, eliminate_dead_code: false }
+    }
+
+    /// Disables the peephole optimizations, which constant-fold arithmetic, and remove double casts
+    /// and redundant `is.eq` and `is.neq` chains. With every optimization disabled, the program is left byte-exact.
+    pub const fn without_peephole(mut self) -> Self {
+        self.peephole = false;
+        self
+    }
+
+    /// Enables inlining of closures with at most `max_instructions` instructions at their call sites.
+    pub const fn with_closure_inlining(mut self, max_instructions: usize) -> Self {
+        self.max_inline_instructions = Some(max_instructions);
+        self
+    }
+
+    /// Enables removal of the instructions whose destination registers are never consumed, and that cannot halt.
+    pub const fn with_dead_code_elimination(mut self) -> Self {
+        self.eliminate_dead_code = true;
+        self
+    }
+
+    /// Returns the optimized program.
+    pub fn optimize<N: Network>(&self, program: &Program<N>) -> Result<Program<N>> {
+        let mut program = program.clone();
+        // Inline the closures.
+        if let Some(max_instructions) = self.max_inline_instructions {
+            program = inline::inline_closures(&program, max_instructions)?;
+        }
+        // Rewrite the wasteful instruction patterns, which includes the patterns exposed by inlining.
+        if self.peephole {
+            program = peephole::rewrite_patterns(&program)?;
+        }
+        // Remove the dead code, which includes the code made dead by inlining.
+        if self.eliminate_dead_code {
+            program = dead_code::eliminate_dead_code(&program)?;
+        }
+        Ok(program)
+    }
+}
+
+/// A map from the registers of an original closure or function to the operands of its optimized counterpart.
+/// As destination registers must be assigned in order, the map allocates the destination registers of the optimized body.
+struct RegisterMap<N: Network> {
+    /// The operand for each register locator of the original body.
+    operands: HashMap<u64, Operand<N>>,
+    /// The next register locator of the optimized body.
+    next: u64,
+}
+
+impl<N: Network> RegisterMap<N> {
+    /// Initializes a new register map for a body with the given number of inputs, which retain their registers.
+    fn new(num_inputs: usize) -> Self {
+        let operands = (0..num_inputs as u64).map(|locator| (locator, Operand::Register(Register::Locator(locator))));
+        Self { operands: operands.collect(), next: num_inputs as u64 }
+    }
+
+    /// Initializes a new register map for an inlined body, which allocates after the registers of this map.
+    fn scope(&self) -> Self {
+        Self { operands: HashMap::new(), next: self.next }
+    }
+
+    /// Maps the given register to the given operand.
+    fn alias(&mut self, register: &Register<N>, operand: Operand<N>) {
+        self.operands.insert(register.locator(), operand);
+    }
+
+    /// Returns the given operand, with its register substituted.
+    fn operand(&self, operand: &Operand<N>) -> Result<Operand<N>> {
+        let Operand::Register(register) = operand else {
+            return Ok(operand.clone());
+        };
+        // Retrieve the substitute for the register.
+        let Some(substitute) = self.operands.get(&register.locator()) else {
+            bail!("Register '{register}' is not defined")
+        };
+        match (register, substitute) {
+            (Register::Locator(..), substitute) => Ok(substitute.clone()),
+            // Append the member path to the path of the substitute.
+            (Register::Member(_, path), Operand::Register(Register::Locator(locator))) => {
+                Ok(Operand::Register(Register::Member(*locator, path.clone())))
+            }
+            (Register::Member(_, path), Operand::Register(Register::Member(locator, prefix))) => {
+                Ok(Operand::Register(Register::Member(*locator, prefix.iter().chain(path).copied().collect())))
+            }
+            (Register::Member(..), substitute) => bail!("Cannot access a member of '{substitute}'"),
+        }
+    }
+
+    /// Returns the given instruction, with its operands substituted and its destination registers allocated.
+    fn rewrite(&mut self, instruction: &Instruction<N>) -> Result<Instruction<N>> {
+        let substituted = self.substitute(instruction)?;
+        self.allocate(&substituted)
+    }
+
+    /// Returns the given instruction, with its operands substituted.
+    fn substitute(&self, instruction: &Instruction<N>) -> Result<Instruction<N>> {
+        instruction.map_registers(&|operand| self.operand(operand), &|destination| Ok(destination.clone()))
+    }
+
+    /// Returns the given instruction, with its destination registers allocated.
+    /// Note: The operands of the given instruction must already be substituted.
+    fn allocate(&mut self, instruction: &Instruction<N>) -> Result<Instruction<N>> {
+        // Allocate the destination registers.
+        let destinations = instruction
+            .destinations()
+            .iter()
+            .zip(self.next..)
+            .map(|(destination, locator)| (destination.locator(), Register::Locator(locator)))
+            .collect::<HashMap<_, _>>();
+        // Rewrite the destination registers.
+        let allocated = instruction.map_registers(&|operand| Ok(operand.clone()), &|destination| {
+            destinations
+                .get(&destination.locator())
+                .cloned()
+                .ok_or_else(|| anyhow!("Destination register '{destination}' is not allocated"))
+        })?;
+        // Map the destination registers.
+        self.next += destinations.len() as u64;
+        for (locator, destination) in destinations {
+            self.operands.insert(locator, Operand::Register(destination));
+        }
+        Ok(allocated)
+    }
+}
+
+/// Returns the closure with the given instructions, and with its outputs substituted using the given register map.
+/// As a closure must contain at least one instruction, the closure is returned unchanged if no instructions are given.
+fn rebuild_closure<N: Network>(
+    closure: &Closure<N>,
+    instructions: &[Instruction<N>],
+    registers: &RegisterMap<N>,
+) -> Result<Closure<N>> {
+    if instructions.is_empty() {
+        return Ok(closure.clone());
+    }
+    let mut string = format!("closure {}:", closure.name());
+    closure.inputs().iter().for_each(|input| string.push_str(&format!("\n    {input}")));
+    instructions.iter().for_each(|instruction| string.push_str(&format!("\n    {instruction}")));
+    for output in closure.outputs() {
+        let operand = registers.operand(output.operand())?;
+        string.push_str(&format!("\n    output {operand} as {};", output.register_type()));
+    }
+    // Parse the closure, which ensures it is well-formed.
+    Closure::from_str(&string)
+}
+
+/// Returns the function with the given instructions, and with its outputs and finalize operands
+/// substituted using the given register map.
+fn rebuild_function<N: Network>(
+    function: &Function<N>,
+    instructions: &[Instruction<N>],
+    registers: &RegisterMap<N>,
+) -> Result<Function<N>> {
+    let mut string = format!("function {}:", function.name());
+    function.inputs().iter().for_each(|input| string.push_str(&format!("\n    {input}")));
+    instructions.iter().for_each(|instruction| string.push_str(&format!("\n    {instruction}")));
+    for output in function.outputs() {
+        let operand = registers.operand(output.operand())?;
+        string.push_str(&format!("\n    output {operand} as {};", output.value_type()));
+    }
+    // Note: The finalize scope has its own registers, so only the finalize operands are substituted.
+    if let Some((command, finalize)) = function.finalize() {
+        let command = command.map_operands(&|operand| registers.operand(operand))?;
+        string.push_str(&format!("\n    {command}\n\n{finalize}"));
+    }
+    // Parse the function, which ensures it is well-formed.
+    Function::from_str(&string)
+}
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::CastType;
+use console::program::{Identifier, Literal, PlaintextType, RegisterType, ValueType};
+
+/// Returns the program, with the wasteful instruction patterns in each closure and function rewritten.
+/// The patterns are arithmetic on literals, casts that rebuild a struct from its own members,
+/// and `is.eq` and `is.neq` instructions that compare a boolean with a literal or repeat an earlier comparison.
+pub(super) fn rewrite_patterns<N: Network>(program: &Program<N>) -> Result<Program<N>> {
+    let mut optimized = program.clone();
+
+    // Rewrite the patterns in each closure.
+    for closure in program.closures().values() {
+        let structs = closure.inputs().iter().filter_map(|input| match input.register_type() {
+            RegisterType::Plaintext(PlaintextType::Struct(name)) => Some((input.register().locator(), *name)),
+            _ => None,
+        });
+        let (instructions, registers) =
+            rewrite_instructions(program, closure.inputs().len(), structs.collect(), closure.instructions())?;
+        optimized.replace_closure(rebuild_closure(closure, &instructions, &registers)?)?;
+    }
+
+    // Rewrite the patterns in each function.
+    for function in program.functions().values() {
+        let structs = function.inputs().iter().filter_map(|input| match input.value_type() {
+            ValueType::Constant(PlaintextType::Struct(name))
+            | ValueType::Public(PlaintextType::Struct(name))
+            | ValueType::Private(PlaintextType::Struct(name)) => Some((input.register().locator(), *name)),
+            _ => None,
+        });
+        let (instructions, registers) =
+            rewrite_instructions(program, function.inputs().len(), structs.collect(), function.instructions())?;
+        optimized.replace_function(rebuild_function(function, &instructions, &registers)?)?;
+    }
+
+    Ok(optimized)
+}
+
+/// The rewrite of an instruction.
+enum Rewrite<N: Network> {
+    /// The destination register is replaced by the given operand, and the instruction is removed.
+    Alias(Operand<N>),
+    /// The instruction is replaced by the given instruction.
+    Replace(Instruction<N>),
+}
+
+/// Returns the given instructions with the wasteful patterns rewritten, and the register map of the result.
+/// The given struct types are the struct names of the input registers.
+fn rewrite_instructions<N: Network>(
+    program: &Program<N>,
+    num_inputs: usize,
+    mut structs: HashMap<u64, Identifier<N>>,
+    instructions: &[Instruction<N>],
+) -> Result<(Vec<Instruction<N>>, RegisterMap<N>)> {
+    let mut registers = RegisterMap::new(num_inputs);
+    let mut rewritten = Vec::with_capacity(instructions.len());
+    // The `is.eq` and `is.neq` comparisons in the rewritten body, as the operands for each destination register.
+    let mut comparisons = HashMap::new();
+
+    for instruction in instructions {
+        let substituted = registers.substitute(instruction)?;
+        let instruction = match rewrite(program, &substituted, &structs, &comparisons)? {
+            Some(Rewrite::Alias(operand)) => {
+                registers.alias(&substituted.destinations()[0], operand);
+                continue;
+            }
+            Some(Rewrite::Replace(replacement)) => registers.allocate(&replacement)?,
+            None => registers.allocate(&substituted)?,
+        };
+
+        // Record the struct type or comparison computed by the instruction, to rewrite the later instructions.
+        match &instruction {
+            Instruction::Cast(cast) => {
+                if let CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(name))) = cast.cast_type() {
+                    structs.insert(cast.destinations()[0].locator(), *name);
+                }
+            }
+            Instruction::IsEq(is) => {
+                comparisons.insert(is.destinations()[0].locator(), (true, is.operands().to_vec()));
+            }
+            Instruction::IsNeq(is) => {
+                comparisons.insert(is.destinations()[0].locator(), (false, is.operands().to_vec()));
+            }
+            _ => (),
+        }
+        rewritten.push(instruction);
+    }
+
+    Ok((rewritten, registers))
+}
+
+/// Returns the rewrite of the given instruction, if it matches a wasteful pattern.
+/// Note: The operands of the given instruction must already be substituted.
+fn rewrite<N: Network>(
+    program: &Program<N>,
+    instruction: &Instruction<N>,
+    structs: &HashMap<u64, Identifier<N>>,
+    comparisons: &HashMap<u64, (bool, Vec<Operand<N>>)>,
+) -> Result<Option<Rewrite<N>>> {
+    // Fold the instruction, if its output is a constant.
+    if let Some(literal) = instruction.constant_output() {
+        return Ok(Some(Rewrite::Alias(Operand::Literal(literal))));
+    }
+
+    match instruction {
+        // Remove a cast that rebuilds a struct from the members of a register of the same struct type.
+        Instruction::Cast(cast) => {
+            let CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(name))) = cast.cast_type() else {
+                return Ok(None);
+            };
+            // Retrieve the register whose members are the operands.
+            let Some(Operand::Register(Register::Member(locator, _))) = cast.operands().first() else {
+                return Ok(None);
+            };
+            if structs.get(locator) != Some(name) {
+                return Ok(None);
+            }
+            // Ensure the operands are the members of the register, in order.
+            let struct_ = program.get_struct(name)?;
+            let is_rebuilt = cast.operands().len() == struct_.members().len()
+                && cast.operands().iter().zip_eq(struct_.members().keys()).all(|(operand, member)| {
+                    matches!(operand, Operand::Register(Register::Member(candidate, path))
+                        if candidate == locator && path.as_slice() == [*member])
+                });
+            match is_rebuilt {
+                true => Ok(Some(Rewrite::Alias(Operand::Register(Register::Locator(*locator))))),
+                false => Ok(None),
+            }
+        }
+        Instruction::IsEq(is) => rewrite_comparison(true, is.operands(), &is.destinations()[0], comparisons),
+        Instruction::IsNeq(is) => rewrite_comparison(false, is.operands(), &is.destinations()[0], comparisons),
+        _ => Ok(None),
+    }
+}
+
+/// Returns the rewrite of an `is.eq` (if `is_eq` is `true`) or `is.neq` comparison, if it is redundant.
+fn rewrite_comparison<N: Network>(
+    is_eq: bool,
+    operands: &[Operand<N>],
+    destination: &Register<N>,
+    comparisons: &HashMap<u64, (bool, Vec<Operand<N>>)>,
+) -> Result<Option<Rewrite<N>>> {
+    // Remove a repeated comparison, in either operand order.
+    for (locator, (candidate_is_eq, candidate_operands)) in comparisons {
+        let is_repeated =
+            candidate_operands.as_slice() == operands || candidate_operands.iter().rev().eq(operands.iter());
+        if *candidate_is_eq == is_eq && is_repeated {
+            return Ok(Some(Rewrite::Alias(Operand::Register(Register::Locator(*locator)))));
+        }
+    }
+
+    // Retrieve the boolean operand, if the other operand is a boolean literal.
+    let (operand, value) = match operands {
+        [Operand::Literal(Literal::Boolean(value)), operand] | [operand, Operand::Literal(Literal::Boolean(value))] => {
+            (operand, **value)
+        }
+        _ => return Ok(None),
+    };
+    // Remove a comparison that is the boolean operand itself.
+    if value == is_eq {
+        return Ok(Some(Rewrite::Alias(operand.clone())));
+    }
+    // Otherwise, the comparison is the negation of the boolean operand.
+    // If the boolean operand is itself a comparison, invert that comparison instead.
+    if let Operand::Register(Register::Locator(locator)) = operand {
+        if let Some((candidate_is_eq, operands)) = comparisons.get(locator) {
+            // Note: The inverted comparison may itself repeat an earlier comparison.
+            if let Some(rewrite) = rewrite_comparison(!candidate_is_eq, operands, destination, comparisons)? {
+                return Ok(Some(rewrite));
+            }
+            let opcode = if *candidate_is_eq { "is.neq" } else { "is.eq" };
+            let replacement = format!("{opcode} {} {} into {destination};", operands[0], operands[1]);
+            return Ok(Some(Rewrite::Replace(Instruction::from_str(&replacement)?)));
+        }
+    }
+    Ok(Some(Rewrite::Replace(Instruction::from_str(&format!("not {operand} into {destination};"))?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_rewrite_patterns() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program peephole.aleo;
+
+struct pair:
+    first as u8;
+    second as u8;
+
+closure fold:
+    input r0 as u8;
+    add 1u8 2u8 into r1;
+    mul r1 3u8 into r2;
+    add r0 r2 into r3;
+    output r3 as u8;
+
+function compute:
+    input r0 as pair.private;
+    input r1 as u8.private;
+    cast r0.first r0.second into r2 as pair;
+    cast r2.first r2.second into r3 as pair;
+    cast r3.second r3.first into r4 as pair;
+    is.eq r0.first r1 into r5;
+    is.eq r5 true into r6;
+    is.eq r1 r0.first into r7;
+    is.eq false r7 into r8;
+    is.neq r8 true into r9;
+    is.neq r9 true into r10;
+    add 255u8 1u8 into r11;
+    output r3 as pair.private;
+    output r4 as pair.private;
+    output r6 as boolean.private;
+    output r10 as boolean.private;
+    output r11 as u8.private;",
+        )
+        .unwrap();
+
+        let optimized = Optimizer::new().optimize(&program).unwrap();
+
+        // Ensure the arithmetic on literals is folded, and the folded constants are propagated.
+        let closure = optimized.get_closure(&Identifier::from_str("fold").unwrap()).unwrap();
+        let expected = r"closure fold:
+    input r0 as u8;
+    add r0 9u8 into r1;
+    output r1 as u8;";
+        assert_eq!(expected, closure.to_string());
+
+        // Ensure the double casts and redundant comparisons are removed, and the overflowing addition is retained.
+        let function = optimized.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+        let expected = r"function compute:
+    input r0 as pair.private;
+    input r1 as u8.private;
+    cast r0.second r0.first into r2 as pair;
+    is.eq r0.first r1 into r3;
+    is.neq r0.first r1 into r4;
+    add 255u8 1u8 into r5;
+    output r0 as pair.private;
+    output r2 as pair.private;
+    output r3 as boolean.private;
+    output r4 as boolean.private;
+    output r5 as u8.private;";
+        assert_eq!(expected, function.to_string());
+
+        // Ensure the program is unchanged with the peephole optimizations disabled.
+        assert_eq!(program, Optimizer::new().without_peephole().optimize(&program).unwrap());
+    }
+}
@@ -23,6 +23,19 @@ impl<N: Network> Stack<N> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_expiration::<A, R>(private_key, function_name, inputs, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, which expires after the given block height.
+    #[inline]
+    pub fn authorize_with_expiration<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        valid_until: Option<u32>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("Stack::authorize");
 
@@ -31,6 +44,10 @@ impl<N: Network> Stack<N> {
 
         // Prepare the function name.
         let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("authorize", program = %self.program.id(), function = %function_name).entered();
+
         // Retrieve the function.
         let function = self.get_function(&function_name)?;
         // Retrieve the input types.
@@ -47,7 +64,15 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Verify the number of inputs");
 
         // Compute the request.
-        let request = Request::sign(private_key, *self.program.id(), function_name, inputs, &input_types, rng)?;
+        let request = Request::sign_with_expiration(
+            private_key,
+            *self.program.id(),
+            function_name,
+            inputs,
+            &input_types,
+            valid_until,
+            rng,
+        )?;
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(&[request.clone()]);
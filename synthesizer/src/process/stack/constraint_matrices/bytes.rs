@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ConstraintMatrices<N> {
+    /// Reads the constraint matrices from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid constraint matrices version"));
+        }
+
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = Identifier::read_le(&mut reader)?;
+        // Read the number of public inputs.
+        let num_public_inputs = u64::read_le(&mut reader)?;
+        // Read the number of private inputs.
+        let num_private_inputs = u64::read_le(&mut reader)?;
+        // Read the number of constraints.
+        let num_constraints = u64::read_le(&mut reader)?;
+
+        // Reads the rows of one matrix.
+        let mut read_matrix = || -> IoResult<Vec<ConstraintRow<N>>> {
+            let mut rows = Vec::new();
+            for _ in 0..num_constraints {
+                // Read the number of terms in the row.
+                let num_terms = u64::read_le(&mut reader)?;
+                // Read the terms.
+                let row = (0..num_terms)
+                    .map(|_| Ok((Field::read_le(&mut reader)?, u64::read_le(&mut reader)?)))
+                    .collect::<IoResult<Vec<_>>>()?;
+                rows.push(row);
+            }
+            Ok(rows)
+        };
+        let a = read_matrix()?;
+        let b = read_matrix()?;
+        let c = read_matrix()?;
+
+        // Return the constraint matrices.
+        Self::new(program_id, function_name, num_public_inputs, num_private_inputs, a, b, c)
+            .map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ConstraintMatrices<N> {
+    /// Writes the constraint matrices to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the function name.
+        self.function_name.write_le(&mut writer)?;
+        // Write the number of public inputs.
+        self.num_public_inputs.write_le(&mut writer)?;
+        // Write the number of private inputs.
+        self.num_private_inputs.write_le(&mut writer)?;
+        // Write the number of constraints.
+        self.num_constraints().write_le(&mut writer)?;
+        // Write the rows of each matrix.
+        for row in self.a.iter().chain(&self.b).chain(&self.c) {
+            // Write the number of terms in the row.
+            (row.len() as u64).write_le(&mut writer)?;
+            // Write the terms.
+            for (coefficient, index) in row {
+                coefficient.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        let expected = crate::process::stack::constraint_matrices::test_helpers::sample_constraint_matrices();
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, ConstraintMatrices::read_le(&expected_bytes[..]).unwrap());
+        assert!(ConstraintMatrices::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+    }
+}
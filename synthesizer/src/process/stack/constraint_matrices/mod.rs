@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+    types::Field,
+};
+
+/// A sparse row of a constraint matrix, as `(coefficient, variable index)` terms.
+pub type ConstraintRow<N> = Vec<(Field<N>, u64)>;
+
+/// The R1CS constraint matrices and public input layout of a function circuit,
+/// for consumption by external provers that do not run the synthesizer.
+///
+/// The variables are indexed as `[1, public inputs, private inputs]`, where index 0 is the constant one.
+/// Each constraint `i` enforces `<A_i, z> * <B_i, z> = <C_i, z>` over this variable vector `z`.
+/// The matrices are exported as synthesized, before any padding that is specific to a proof system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintMatrices<N: Network> {
+    /// The program ID of the function.
+    program_id: ProgramID<N>,
+    /// The name of the function.
+    function_name: Identifier<N>,
+    /// The number of public inputs, excluding the constant one.
+    num_public_inputs: u64,
+    /// The number of private inputs.
+    num_private_inputs: u64,
+    /// The rows of the A matrix.
+    a: Vec<ConstraintRow<N>>,
+    /// The rows of the B matrix.
+    b: Vec<ConstraintRow<N>>,
+    /// The rows of the C matrix.
+    c: Vec<ConstraintRow<N>>,
+}
+
+impl<N: Network> ConstraintMatrices<N> {
+    /// Initializes the constraint matrices, ensuring every term refers to an allocated variable.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        num_public_inputs: u64,
+        num_private_inputs: u64,
+        a: Vec<ConstraintRow<N>>,
+        b: Vec<ConstraintRow<N>>,
+        c: Vec<ConstraintRow<N>>,
+    ) -> Result<Self> {
+        // Ensure the matrices have the same number of rows.
+        ensure!(a.len() == b.len() && b.len() == c.len(), "The constraint matrices have mismatched numbers of rows");
+        // Ensure the variable indices are within bounds.
+        let num_variables = num_public_inputs
+            .checked_add(num_private_inputs)
+            .and_then(|num_inputs| num_inputs.checked_add(1))
+            .ok_or_else(|| anyhow!("The number of variables overflowed"))?;
+        for (_, index) in a.iter().chain(&b).chain(&c).flatten() {
+            ensure!(*index < num_variables, "Variable index {index} is out of bounds ({num_variables} variables)");
+        }
+        Ok(Self { program_id, function_name, num_public_inputs, num_private_inputs, a, b, c })
+    }
+
+    /// Initializes the constraint matrices from the given circuit assignment.
+    pub fn from_assignment(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+    ) -> Result<Self> {
+        let num_public_inputs = assignment.num_public();
+        let num_private_inputs = assignment.num_private();
+
+        // Converts a linear combination of the assignment into a sparse row.
+        let to_row = |lc: &circuit::AssignmentLC<N::Field>| -> Result<ConstraintRow<N>> {
+            let mut row = Vec::with_capacity(lc.terms().len() + 1);
+            if !lc.constant().is_zero() {
+                row.push((Field::new(lc.constant()), 0));
+            }
+            for (variable, coefficient) in lc.terms() {
+                let index = match variable {
+                    circuit::AssignmentVariable::Constant(_) => bail!("The assignment contains a constant term"),
+                    circuit::AssignmentVariable::Public(index) => 1 + index,
+                    circuit::AssignmentVariable::Private(index) => 1 + num_public_inputs + index,
+                };
+                row.push((Field::new(*coefficient), index));
+            }
+            Ok(row)
+        };
+
+        let num_constraints = assignment.constraints().len();
+        let mut a = Vec::with_capacity(num_constraints);
+        let mut b = Vec::with_capacity(num_constraints);
+        let mut c = Vec::with_capacity(num_constraints);
+        for (a_lc, b_lc, c_lc) in assignment.constraints() {
+            a.push(to_row(a_lc)?);
+            b.push(to_row(b_lc)?);
+            c.push(to_row(c_lc)?);
+        }
+
+        Self::new(program_id, function_name, num_public_inputs, num_private_inputs, a, b, c)
+    }
+
+    /// Returns the program ID of the function.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the function.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of public inputs, excluding the constant one.
+    pub const fn num_public_inputs(&self) -> u64 {
+        self.num_public_inputs
+    }
+
+    /// Returns the number of private inputs.
+    pub const fn num_private_inputs(&self) -> u64 {
+        self.num_private_inputs
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> u64 {
+        self.a.len() as u64
+    }
+
+    /// Returns the rows of the A matrix.
+    pub fn a(&self) -> &[ConstraintRow<N>] {
+        &self.a
+    }
+
+    /// Returns the rows of the B matrix.
+    pub fn b(&self) -> &[ConstraintRow<N>] {
+        &self.b
+    }
+
+    /// Returns the rows of the C matrix.
+    pub fn c(&self) -> &[ConstraintRow<N>] {
+        &self.c
+    }
+
+    /// Returns `true` if the given public and private inputs satisfy every constraint.
+    pub fn is_satisfied(&self, public_inputs: &[Field<N>], private_inputs: &[Field<N>]) -> bool {
+        // Ensure the number of inputs matches the layout.
+        if public_inputs.len() as u64 != self.num_public_inputs {
+            return false;
+        }
+        if private_inputs.len() as u64 != self.num_private_inputs {
+            return false;
+        }
+        // Construct the variable vector.
+        let z = std::iter::once(&Field::one()).chain(public_inputs).chain(private_inputs).copied().collect::<Vec<_>>();
+        // Evaluates a sparse row on the variable vector.
+        let evaluate = |row: &ConstraintRow<N>| {
+            row.iter().map(|(coefficient, index)| *coefficient * z[*index as usize]).sum::<Field<N>>()
+        };
+        // Check each constraint.
+        self.a.iter().zip_eq(&self.b).zip_eq(&self.c).all(|((a, b), c)| evaluate(a) * evaluate(b) == evaluate(c))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the constraint matrices for `x * y = z + 1`, where `x` is public and `y, z` are private.
+    pub(crate) fn sample_constraint_matrices() -> ConstraintMatrices<CurrentNetwork> {
+        let one = Field::one();
+        ConstraintMatrices::new(
+            ProgramID::from_str("test.aleo").unwrap(),
+            Identifier::from_str("run").unwrap(),
+            1,
+            2,
+            vec![vec![(one, 1)]],
+            vec![vec![(one, 2)]],
+            vec![vec![(one, 3), (one, 0)]],
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("test.aleo").unwrap();
+        let function_name = Identifier::from_str("run").unwrap();
+        let row = |index: u64| vec![(Field::one(), index)];
+
+        // Ensure the matrices must have the same number of rows.
+        let result = ConstraintMatrices::new(program_id, function_name, 1, 2, vec![row(1)], vec![row(2)], vec![]);
+        assert!(result.is_err());
+        // Ensure the variable indices must be in bounds.
+        let result = ConstraintMatrices::new(program_id, function_name, 1, 2, vec![row(1)], vec![row(2)], vec![row(4)]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_satisfied() {
+        let matrices = test_helpers::sample_constraint_matrices();
+        let field = |value: &str| Field::<CurrentNetwork>::from_str(value).unwrap();
+
+        // Check `3 * 5 = 14 + 1`.
+        assert!(matrices.is_satisfied(&[field("3field")], &[field("5field"), field("14field")]));
+        // Check `3 * 5 != 15 + 1`.
+        assert!(!matrices.is_satisfied(&[field("3field")], &[field("5field"), field("15field")]));
+        // Check the inputs must match the layout.
+        assert!(!matrices.is_satisfied(&[field("3field"), field("5field")], &[field("14field")]));
+    }
+}
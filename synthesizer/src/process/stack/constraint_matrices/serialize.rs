@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for ConstraintMatrices<N> {
+    /// Serializes the constraint matrices into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut matrices = serializer.serialize_struct("ConstraintMatrices", 7)?;
+                matrices.serialize_field("program_id", &self.program_id)?;
+                matrices.serialize_field("function_name", &self.function_name)?;
+                matrices.serialize_field("num_public_inputs", &self.num_public_inputs)?;
+                matrices.serialize_field("num_private_inputs", &self.num_private_inputs)?;
+                matrices.serialize_field("a", &self.a)?;
+                matrices.serialize_field("b", &self.b)?;
+                matrices.serialize_field("c", &self.c)?;
+                matrices.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ConstraintMatrices<N> {
+    /// Deserializes the constraint matrices from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the constraint matrices from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+                // Recover the constraint matrices.
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut object, "program_id")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "function_name")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "num_public_inputs")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "num_private_inputs")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "a")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "b")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "c")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "constraint matrices"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let expected = crate::process::stack::constraint_matrices::test_helpers::sample_constraint_matrices();
+
+        // Serialize
+        let expected_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected, serde_json::from_str(&expected_string).unwrap());
+        // Deserialize
+        let candidate: ConstraintMatrices<_> = serde_json::from_str(&expected_string).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_string, serde_json::to_string(&candidate).unwrap());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let expected = crate::process::stack::constraint_matrices::test_helpers::sample_constraint_matrices();
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+        // Deserialize
+        assert_eq!(expected, ConstraintMatrices::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+    }
+}
@@ -71,6 +71,9 @@ impl<N: Network> Stack<N> {
 
         let program_id = self.program.id();
 
+        // Initialize a vector for the assignments, verifying keys, and certificates.
+        let mut certificate_inputs = Vec::with_capacity(deployment.verifying_keys().len());
+
         // Iterate through the program functions.
         for (function, (_, (verifying_key, certificate))) in
             deployment.program().functions().values().zip_eq(deployment.verifying_keys())
@@ -113,20 +116,31 @@ impl<N: Network> Stack<N> {
             // Synthesize the circuit.
             let _response = self.execute_function::<A>(call_stack)?;
             lap!(timer, "Synthesize the circuit");
-            // Check the certificate.
-            match assignments.read().last() {
-                None => {
-                    bail!("The assignment for function '{}' is missing in '{program_id}'", function.name())
-                }
-                Some(assignment) => {
-                    // Ensure the certificate is valid.
-                    if !certificate.verify(&function.name().to_string(), assignment, verifying_key) {
-                        bail!("The certificate for function '{}' is invalid in '{program_id}'", function.name())
-                    }
-                    lap!(timer, "Ensure the certificate is valid");
-                }
+            // Retrieve the assignment.
+            let Some(assignment) = assignments.write().pop() else {
+                bail!("The assignment for function '{}' is missing in '{program_id}'", function.name())
             };
+            // Add the assignment, verifying key, and certificate to the batch.
+            certificate_inputs.push((function.name(), assignment, verifying_key, certificate));
+        }
+
+        // Check Certificates //
+
+        // Ensure the certificates are valid, with a single batched check.
+        let batch = certificate_inputs
+            .iter()
+            .map(|(_, assignment, verifying_key, certificate)| (assignment, *verifying_key, *certificate))
+            .collect::<Vec<_>>();
+        if !Certificate::verify_batch(&program_id.to_string(), &batch, rng) {
+            // Find the invalid certificate, to report it.
+            for (function_name, assignment, verifying_key, certificate) in &certificate_inputs {
+                if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
+                    bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
+                }
+            }
+            bail!("The certificates are invalid in '{program_id}'")
         }
+        lap!(timer, "Ensure the certificates are valid");
 
         finish!(timer);
 
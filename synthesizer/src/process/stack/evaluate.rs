@@ -211,6 +211,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             outputs,
             &function.output_types(),
             &output_registers,
+            self.program.auditor(),
         )
     }
 }
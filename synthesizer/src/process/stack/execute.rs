@@ -136,6 +136,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "synthesize",
+            program = %console_request.program_id(),
+            function = %console_request.function_name()
+        )
+        .entered();
+
         // Ensure the network ID matches.
         ensure!(
             **console_request.network_id() == N::ID,
@@ -216,9 +224,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
+        // Initialize a tracker for the number of constraints before each instruction.
+        let mut instruction_offsets = Vec::with_capacity(function.instructions().len() + 1);
 
         // Execute the instructions.
         for instruction in function.instructions() {
+            // Record the number of constraints before executing the instruction.
+            instruction_offsets.push(A::num_constraints());
+
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -238,6 +251,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
         }
+        // Record the number of constraints after executing the instructions.
+        instruction_offsets.push(A::num_constraints());
         lap!(timer, "Execute the instructions");
 
         // Load the outputs.
@@ -303,6 +318,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             outputs,
             &output_types,
             &output_registers,
+            self.program.auditor(),
         );
         lap!(timer, "Construct the response");
 
@@ -393,25 +409,43 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // If the circuit is in `Execute` mode, then ensure the circuit is satisfied.
         if let CallStack::Execute(..) = registers.call_stack() {
-            // If the circuit is empty or not satisfied, then throw an error.
+            // If the circuit is empty, then throw an error.
             ensure!(
-                A::num_constraints() > 0 && A::is_satisfied(),
-                "'{}/{}' is not satisfied on the given inputs ({} constraints).",
+                A::num_constraints() > 0,
+                "'{}/{}' is not satisfied on the given inputs (0 constraints).",
                 self.program.id(),
                 function.name(),
-                A::num_constraints()
             );
+            // If the circuit is not satisfied, then throw an error, reporting the first failing constraint.
+            if let Some(unsatisfied) = A::first_unsatisfied_constraint() {
+                bail!(
+                    "'{}/{}' is not satisfied on the given inputs ({} constraints). {unsatisfied}, enforced in {}",
+                    self.program.id(),
+                    function.name(),
+                    A::num_constraints(),
+                    Self::locate_constraint(unsatisfied.index(), function.instructions(), &instruction_offsets)
+                );
+            }
         }
 
         // Eject the circuit assignment and reset the circuit.
         let assignment = A::eject_assignment_and_reset();
 
+        // Determine if the request expires, in which case the expiring circuit key is used.
+        let expires = console_request.valid_until().is_some();
+
         // If the circuit is in `Synthesize` or `Execute` mode, synthesize the circuit key, if it does not exist.
         if matches!(registers.call_stack(), CallStack::Synthesize(..))
             || matches!(registers.call_stack(), CallStack::Execute(..))
         {
+            // If the expiring circuit key does not exist, then synthesize it.
+            if expires && !self.contains_expiring_key(function.name()) {
+                // Add the expiring circuit key to the mapping.
+                self.synthesize_expiring_from_assignment(function.name(), &assignment)?;
+                lap!(timer, "Synthesize the {} expiring circuit key", function.name());
+            }
             // If the proving key does not exist, then synthesize it.
-            if !self.contains_proving_key(function.name()) {
+            else if !expires && !self.contains_proving_key(function.name()) {
                 // Add the circuit key to the mapping.
                 self.synthesize_from_assignment(function.name(), &assignment)?;
                 lap!(timer, "Synthesize the {} circuit key", function.name());
@@ -429,9 +463,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             registers.ensure_console_and_circuit_registers_match()?;
 
             // Construct the transition.
-            let transition = Transition::from(&console_request, &response, finalize, &output_types, &output_registers)?;
+            let transition = Transition::from(
+                &console_request,
+                &response,
+                finalize,
+                &output_types,
+                &output_registers,
+                self.program.auditor(),
+            )?;
             // Retrieve the proving key.
-            let proving_key = self.get_proving_key(function.name())?;
+            let proving_key = match expires {
+                true => self.get_expiring_proving_key(function.name())?,
+                false => self.get_proving_key(function.name())?,
+            };
             // Construct the call metrics.
             let metrics = CallMetrics {
                 program_id: *self.program_id(),
@@ -459,6 +503,22 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns a description of the function segment that enforced the constraint at the given index,
+    /// where `offsets` contains the number of constraints before each instruction and after the last instruction.
+    fn locate_constraint(index: u64, instructions: &[Instruction<N>], offsets: &[u64]) -> String {
+        match (offsets.first(), offsets.last()) {
+            // If the constraint precedes the instructions, it was enforced when verifying the request.
+            (Some(first), _) if index < *first => "the request".to_string(),
+            // If the constraint follows the instructions, it was enforced when constructing the response.
+            (_, Some(last)) if index >= *last => "the response".to_string(),
+            // Otherwise, find the instruction whose constraints contain the index.
+            _ => match offsets.windows(2).position(|window| window[0] <= index && index < window[1]) {
+                Some(position) => format!("instruction {position} ('{}')", instructions[position]),
+                None => "an unknown location".to_string(),
+            },
+        }
+    }
+
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
     pub(crate) fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
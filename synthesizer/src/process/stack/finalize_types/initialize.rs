@@ -14,7 +14,7 @@
 
 use super::*;
 use crate::{
-    process::{Branch, Contains, Get, GetOrUse, RandChaCha, Remove, Set, MAX_ADDITIONAL_SEEDS},
+    process::{Branch, Clear, Contains, Get, GetOrUse, Keys, Length, RandChaCha, Remove, Set, MAX_ADDITIONAL_SEEDS},
     RegisterTypes,
 };
 
@@ -135,9 +135,12 @@ impl<N: Network> FinalizeTypes<N> {
     ) -> Result<()> {
         match command {
             Command::Instruction(instruction) => self.check_instruction(stack, finalize.name(), instruction)?,
+            Command::Clear(clear) => self.check_clear(stack, finalize.name(), clear)?,
             Command::Contains(contains) => self.check_contains(stack, finalize.name(), contains)?,
             Command::Get(get) => self.check_get(stack, finalize.name(), get)?,
             Command::GetOrUse(get_or_use) => self.check_get_or_use(stack, finalize.name(), get_or_use)?,
+            Command::Keys(keys) => self.check_keys(stack, finalize.name(), keys)?,
+            Command::Length(length) => self.check_length(stack, finalize.name(), length)?,
             Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, finalize.name(), rand_chacha)?,
             Command::Remove(remove) => self.check_remove(stack, finalize.name(), remove)?,
             Command::Set(set) => self.check_set(stack, finalize.name(), set)?,
@@ -179,6 +182,46 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `clear` command is well-formed.
+    #[inline]
+    fn check_clear(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        clear: &Clear<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `clear` is defined in the program.
+        if !stack.program().contains_mapping(clear.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", clear.mapping_name(), stack.program_id())
+        }
+        // Retrieve the register type of the admin.
+        let admin_type = self.get_type_from_operand(stack, clear.admin())?;
+        // Check that the admin type is an address.
+        if admin_type != PlaintextType::Literal(LiteralType::Address) {
+            bail!("Admin type in `clear` '{admin_type}' must be an 'address'.")
+        }
+        // Ensure the admin is a finalize input, which the function sets to `self.caller`.
+        // This binds the admin to the verified caller of the transition.
+        let is_caller = match clear.admin() {
+            Operand::Register(Register::Locator(locator)) if self.inputs.contains_key(locator) => {
+                // Retrieve the finalize operands of the function.
+                let function = stack.get_function(finalize_name)?;
+                match function.finalize() {
+                    Some((command, _)) => command.operands().get(*locator as usize) == Some(&Operand::Caller),
+                    None => false,
+                }
+            }
+            _ => false,
+        };
+        if !is_caller {
+            bail!(
+                "Admin in `clear` in '{}/{finalize_name}' must be a finalize input set to 'self.caller'.",
+                stack.program_id()
+            )
+        }
+        Ok(())
+    }
+
     /// Ensures the given `contains` command is well-formed.
     #[inline]
     fn check_contains(
@@ -291,6 +334,73 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `keys` command is well-formed.
+    #[inline]
+    fn check_keys(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        keys: &Keys<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `keys` is defined in the program.
+        if !stack.program().contains_mapping(keys.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", keys.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(keys.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the cursor.
+        let cursor_type = self.get_type_from_operand(stack, keys.cursor())?;
+        // Check that the cursor type matches the key type in the mapping.
+        if *mapping_key_type != cursor_type {
+            bail!(
+                "Cursor type in `keys` '{cursor_type}' does not match the key type in the mapping '{mapping_key_type}'."
+            )
+        }
+        // Retrieve the register type of the default key.
+        let default_key_type = self.get_type_from_operand(stack, keys.default())?;
+        // Check that the default key type matches the key type in the mapping.
+        if *mapping_key_type != default_key_type {
+            bail!(
+                "Default key type in `keys` '{default_key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+            )
+        }
+        // Ensure the number of destinations is within bounds.
+        if keys.destinations().is_empty() || keys.destinations().len() > N::MAX_OPERANDS {
+            bail!("The number of destinations must be in [1, {}]", N::MAX_OPERANDS)
+        }
+        for destination in keys.destinations() {
+            // Ensure the destination register is a locator (and does not reference a member).
+            ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+            // Insert the destination register.
+            self.add_destination(destination, *mapping_key_type)?;
+        }
+        Ok(())
+    }
+
+    /// Ensures the given `length` command is well-formed.
+    #[inline]
+    fn check_length(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        length: &Length<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `length` is defined in the program.
+        if !stack.program().contains_mapping(length.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", length.mapping_name(), stack.program_id())
+        }
+        // Get the destination register.
+        let destination = length.destination().clone();
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, PlaintextType::Literal(LiteralType::U32))?;
+        Ok(())
+    }
+
     /// Ensure the given `rand.chacha` command is well-formed.
     #[inline]
     fn check_rand_chacha(
@@ -432,6 +542,9 @@ impl<N: Network> FinalizeTypes<N> {
         finalize_name: &Identifier<N>,
         instruction: &Instruction<N>,
     ) -> Result<()> {
+        // Ensure the opcode is enabled on the network.
+        Instruction::<N>::ensure_is_enabled(instruction.opcode())?;
+
         match instruction.opcode() {
             Opcode::Literal(opcode) => {
                 // Ensure the opcode **is** a reserved opcode.
@@ -27,6 +27,8 @@ impl<N: Network> Stack<N> {
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            expiring_keys: Default::default(),
+            lazy_credits_keys: false,
         };
 
         // Add all of the imports into the stack.
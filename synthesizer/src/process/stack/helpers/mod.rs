@@ -14,6 +14,7 @@
 
 use super::*;
 
+mod export;
 mod initialize;
 mod matches;
 mod sample;
@@ -26,7 +26,43 @@ impl<N: Network> Stack<N> {
         if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
             return Ok(());
         }
+        // Synthesize the circuit key.
+        self.synthesize_key_with_expiration::<A, R>(function_name, None, rng)?;
 
+        // Ensure the proving key exists.
+        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
+        // Ensure the verifying key exists.
+        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
+        Ok(())
+    }
+
+    /// Synthesizes the proving key and verifying key of the expiring circuit for the given function name.
+    #[inline]
+    pub fn synthesize_expiring_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // If the expiring circuit key already exists, skip the synthesis for this function.
+        if self.contains_expiring_key(function_name) {
+            return Ok(());
+        }
+        // Synthesize the circuit key, with a request that expires.
+        // Note: The circuit does not depend on the expiration height, only on its presence.
+        self.synthesize_key_with_expiration::<A, R>(function_name, Some(0), rng)?;
+
+        // Ensure the expiring circuit key exists.
+        ensure!(self.contains_expiring_key(function_name), "Function '{function_name}' is missing an expiring key.");
+        Ok(())
+    }
+
+    /// Synthesizes the circuit key for the given function name, with a request that has the given expiration height.
+    fn synthesize_key_with_expiration<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        valid_until: Option<u32>,
+        rng: &mut R,
+    ) -> Result<()> {
         // Retrieve the program ID.
         let program_id = self.program_id();
         // Retrieve the function input types.
@@ -51,19 +87,21 @@ impl<N: Network> Stack<N> {
             .collect::<Result<Vec<_>>>()?;
 
         // Compute the request, with a burner private key.
-        let request =
-            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        let request = Request::sign_with_expiration(
+            &burner_private_key,
+            *program_id,
+            *function_name,
+            inputs.into_iter(),
+            &input_types,
+            valid_until,
+            rng,
+        )?;
         // Initialize the authorization.
         let authorization = Authorization::new(&[request.clone()]);
         // Initialize the call stack.
         let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
         // Synthesize the circuit.
         let _response = self.execute_function::<A>(call_stack)?;
-
-        // Ensure the proving key exists.
-        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
-        // Ensure the verifying key exists.
-        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
         Ok(())
     }
 
@@ -86,4 +124,30 @@ impl<N: Network> Stack<N> {
         // Insert the verifying key.
         self.insert_verifying_key(function_name, verifying_key)
     }
+
+    /// Synthesizes and stores the `(proving_key, verifying_key)` of the expiring circuit
+    /// for the given function name and assignment.
+    #[inline]
+    pub fn synthesize_expiring_from_assignment(
+        &self,
+        function_name: &Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+    ) -> Result<()> {
+        // If the expiring circuit key already exists, skip the synthesis for this function.
+        if self.contains_expiring_key(function_name) {
+            return Ok(());
+        }
+        // Ensure the function name exists in the program.
+        ensure!(
+            self.program.contains_function(function_name),
+            "Function '{function_name}' does not exist in program '{}'.",
+            self.program.id()
+        );
+
+        // Synthesize the proving and verifying key.
+        let keys = self.universal_srs.to_circuit_key(&format!("{function_name}.expiring"), assignment)?;
+        // Insert the proving and verifying key.
+        self.expiring_keys.write().insert(*function_name, keys);
+        Ok(())
+    }
 }
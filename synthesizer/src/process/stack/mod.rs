@@ -15,6 +15,9 @@
 mod authorization;
 pub use authorization::*;
 
+mod constraint_matrices;
+pub use constraint_matrices::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
@@ -172,6 +175,10 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of function name to the proving and verifying key of its expiring circuit.
+    expiring_keys: Arc<RwLock<IndexMap<Identifier<N>, (ProvingKey<N>, VerifyingKey<N>)>>>,
+    /// Whether the missing proving keys of 'credits.aleo' are loaded from the parameters on first use.
+    lazy_credits_keys: bool,
 }
 
 impl<N: Network> Stack<N> {
@@ -299,10 +306,19 @@ impl<N: Network> StackProgram<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Loads the missing proving keys of 'credits.aleo' from the parameters on first use,
+    /// so that only the proving keys of the functions that are executed are kept in memory.
+    #[inline]
+    pub(crate) fn with_lazy_credits_keys(mut self) -> Self {
+        self.lazy_credits_keys = self.program.id().to_string() == "credits.aleo";
+        self
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
         self.proving_keys.read().contains_key(function_name)
+            || (self.lazy_credits_keys && self.program.contains_function(function_name))
     }
 
     /// Returns `true` if the verifying key for the given function name exists.
@@ -315,9 +331,17 @@ impl<N: Network> Stack<N> {
     #[inline]
     pub fn get_proving_key(&self, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
         // Return the proving key, if it exists.
-        match self.proving_keys.read().get(function_name) {
-            Some(proving_key) => Ok(proving_key.clone()),
-            None => bail!("Proving key not found for: {}/{function_name}", self.program.id()),
+        if let Some(proving_key) = self.proving_keys.read().get(function_name) {
+            return Ok(proving_key.clone());
+        }
+        // Otherwise, load the proving key of 'credits.aleo', if it is loaded on first use.
+        match self.lazy_credits_keys && self.program.contains_function(function_name) {
+            true => {
+                let proving_key = ProvingKey::new(N::get_credits_proving_key(function_name.to_string())?.clone());
+                self.proving_keys.write().insert(*function_name, proving_key.clone());
+                Ok(proving_key)
+            }
+            false => bail!("Proving key not found for: {}/{function_name}", self.program.id()),
         }
     }
 
@@ -359,6 +383,35 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Returns `true` if the proving and verifying key of the expiring circuit for the given function name exist.
+    ///
+    /// A request that expires has its expiration height as an additional public input
+    /// (see `Request::expiration_field`), so the root transition of an expiring execution
+    /// is proven and verified with this circuit.
+    /// The key is synthesized from the program on first use, as it is not part of the deployment.
+    #[inline]
+    pub fn contains_expiring_key(&self, function_name: &Identifier<N>) -> bool {
+        self.expiring_keys.read().contains_key(function_name)
+    }
+
+    /// Returns the proving key of the expiring circuit for the given function name.
+    #[inline]
+    pub fn get_expiring_proving_key(&self, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
+        match self.expiring_keys.read().get(function_name) {
+            Some((proving_key, _)) => Ok(proving_key.clone()),
+            None => bail!("Expiring proving key not found for: {}/{function_name}", self.program.id()),
+        }
+    }
+
+    /// Returns the verifying key of the expiring circuit for the given function name.
+    #[inline]
+    pub fn get_expiring_verifying_key(&self, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        match self.expiring_keys.read().get(function_name) {
+            Some((_, verifying_key)) => Ok(verifying_key.clone()),
+            None => bail!("Expiring verifying key not found for: {}/{function_name}", self.program.id()),
+        }
+    }
+
     /// Removes the proving key for the given function name.
     #[inline]
     pub fn remove_proving_key(&self, function_name: &Identifier<N>) {
@@ -317,6 +317,9 @@ impl<N: Network> RegisterTypes<N> {
         closure_or_function_name: &Identifier<N>,
         instruction: &Instruction<N>,
     ) -> Result<()> {
+        // Ensure the opcode is enabled on the network.
+        Instruction::<N>::ensure_is_enabled(instruction.opcode())?;
+
         match instruction.opcode() {
             Opcode::Literal(opcode) => {
                 // Ensure the opcode **is** a reserved opcode.
@@ -13,14 +13,20 @@
 // limitations under the License.
 
 use super::*;
-use crate::store::{
-    helpers::memory::{BlockMemory, FinalizeMemory},
-    BlockStore,
+use crate::{
+    block::{Block, ConfirmedTransaction, Header, RejectionCode, Transaction, Transactions},
+    store::{
+        helpers::memory::{BlockMemory, FinalizeMemory},
+        BlockStore,
+    },
+};
+use circuit::{
+    network::{AleoDevnet, AleoV0},
+    Assignment,
 };
-use circuit::{network::AleoV0, Assignment};
 use console::{
     account::{Address, PrivateKey, ViewKey},
-    network::Testnet3,
+    network::{Devnet, DevnetConfig, Testnet3},
     program::{Identifier, Literal, Value},
     types::Field,
 };
@@ -34,6 +40,15 @@ fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
     FinalizeGlobalState::from(block_height, [0u8; 32])
 }
 
+/// Samples a new program owner for the given deployment.
+fn sample_program_owner<R: Rng + CryptoRng>(
+    deployment: &Deployment<CurrentNetwork>,
+    rng: &mut R,
+) -> ProgramOwner<CurrentNetwork> {
+    let private_key = PrivateKey::new(rng).unwrap();
+    ProgramOwner::new(&private_key, deployment.to_deployment_id().unwrap(), rng).unwrap()
+}
+
 #[test]
 fn test_program_evaluate_function() {
     let program = Program::<CurrentNetwork>::from_str(
@@ -1190,7 +1205,12 @@ finalize compute:
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    let program_owner = sample_program_owner(&deployment, rng);
+    let (stack, finalize_operations) =
+        process.finalize_deployment(&finalize_store, &deployment, &program_owner).unwrap();
+    // Ensure the deployment does not register an admin, as the program does not use `clear`.
+    assert!(!deployment.registers_admin());
+    assert!(finalize_operations.iter().all(|operation| matches!(operation, FinalizeOperation::InitializeMapping(..))));
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1231,8 +1251,47 @@ finalize compute:
     // Verify the execution.
     process.verify_execution(&execution).unwrap();
 
+    // Ensure finalize is rejected on a network whose step limit is below the steps of the execution.
+    {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        struct FewSteps;
+
+        impl DevnetConfig for FewSteps {
+            // Allow 'get.or_use' and 'add', but not 'set'.
+            const MAX_FINALIZE_STEPS: u64 = Testnet3::FINALIZE_READ_STEPS + Testnet3::FINALIZE_INSTRUCTION_STEPS;
+        }
+
+        // Note: The devnet uses the parameters of `Testnet3`, so the execution is valid on the devnet.
+        let execution = Execution::<Devnet<FewSteps>>::read_le(&execution.to_bytes_le().unwrap()[..]).unwrap();
+        let program = Program::<Devnet<FewSteps>>::from_str(&program.to_string()).unwrap();
+
+        // Initialize the process and finalize store on the devnet.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+        finalize_store.initialize_mapping(program.id(), &Identifier::from_str("account").unwrap()).unwrap();
+
+        // Ensure the step limit is exceeded on the 'set' command.
+        let error = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution).unwrap_err();
+        let failure = error.downcast::<FinalizeFailure<Devnet<FewSteps>>>().unwrap();
+        assert_eq!(RejectionCode::StepLimitExceeded, failure.code());
+        assert_eq!(2, failure.index());
+        // Ensure the mapping is not updated.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str(&caller.to_string()).unwrap();
+        assert!(!finalize_store.contains_key_speculative(program.id(), &mapping_name, &key).unwrap());
+    }
+
     // Now, finalize the execution.
-    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution).unwrap();
+    let (_, steps) =
+        process.finalize_execution_with_trace(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    // Check that the steps are metered for 'get.or_use', 'add', and 'set'.
+    assert_eq!(
+        steps,
+        CurrentNetwork::FINALIZE_READ_STEPS
+            + CurrentNetwork::FINALIZE_INSTRUCTION_STEPS
+            + CurrentNetwork::FINALIZE_WRITE_STEPS
+    );
 
     // Check that the account balance is now 8.
     let candidate = finalize_store
@@ -1300,7 +1359,8 @@ finalize compute:
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    let program_owner = sample_program_owner(&deployment, rng);
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment, &program_owner).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1424,7 +1484,8 @@ finalize mint_public:
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    let program_owner = sample_program_owner(&deployment, rng);
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment, &program_owner).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1550,7 +1611,8 @@ finalize mint_public:
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    let program_owner = sample_program_owner(&deployment, rng);
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment, &program_owner).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1689,7 +1751,8 @@ finalize compute:
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    let program_owner = sample_program_owner(&deployment, rng);
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment, &program_owner).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -2048,6 +2111,113 @@ fn test_complex_execution_order() {
     process.verify_execution(&execution).unwrap();
 }
 
+#[test]
+fn test_process_add_programs_in_import_order() {
+    // Initialize the programs, where `two.aleo` imports `zero.aleo` and `one.aleo`, and `three.aleo` imports `two.aleo`.
+    let programs = [
+        r"
+    import two.aleo;
+    program three.aleo;
+    function e:
+        input r0 as u8.private;
+        call two.aleo/b r0 r0 into r1;
+        output r1 as u8.private;",
+        r"
+    import zero.aleo;
+    import one.aleo;
+    program two.aleo;
+    function b:
+        input r0 as u8.private;
+        input r1 as u8.private;
+        call zero.aleo/c r0 r1 into r2;
+        call one.aleo/d r1 r2 into r3;
+        output r3 as u8.private;",
+        r"
+    program one.aleo;
+    function d:
+        input r0 as u8.private;
+        input r1 as u8.private;
+        add r0 r1 into r2;
+        output r2 as u8.private;",
+        r"
+    program zero.aleo;
+    function c:
+        input r0 as u8.private;
+        input r1 as u8.private;
+        add r0 r1 into r2;
+        output r2 as u8.private;",
+    ]
+    .iter()
+    .map(|program| Program::<CurrentNetwork>::from_str(program).unwrap())
+    .collect::<Vec<_>>();
+
+    // Ensure the programs are added, even though they are given in reverse import order.
+    let mut process = Process::load().unwrap();
+    process.add_programs(&programs).unwrap();
+    for program in &programs {
+        assert!(process.contains_program(program.id()));
+    }
+    // Ensure adding the programs again is a no-op.
+    process.add_programs(&programs).unwrap();
+
+    // Ensure a missing import is rejected.
+    let mut process = Process::load().unwrap();
+    assert!(process.add_programs(&programs[..2]).is_err());
+    assert!(!process.contains_program(programs[0].id()));
+
+    // Ensure a duplicate program is rejected.
+    let mut process = Process::load().unwrap();
+    assert!(process.add_programs(&[programs[3].clone(), programs[3].clone()]).is_err());
+}
+
+#[test]
+fn test_process_clear_requires_caller() {
+    // Samples a program where the `clear` admin is finalized from the given function operand.
+    let sample_program = |program_name: &str, admin: &str| {
+        Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program {program_name}.aleo;
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+function reset:
+    input r0 as address.public;
+    finalize {admin};
+finalize reset:
+    input r0 as address.public;
+    clear account r0;"
+        ))
+        .unwrap()
+    };
+
+    // Ensure the program is added if the admin is `self.caller`.
+    let mut process = Process::load().unwrap();
+    process.add_program(&sample_program("by_caller", "self.caller")).unwrap();
+
+    // Ensure the program is rejected if the admin is an input.
+    assert!(process.add_program(&sample_program("by_input", "r0")).is_err());
+    // Ensure the program is rejected if the admin is a literal.
+    let literal = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap()).unwrap();
+    assert!(process.add_program(&sample_program("by_literal", &literal.to_string())).is_err());
+
+    // Ensure the program is rejected if the admin is not a finalize input.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program by_register.aleo;
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+function reset:
+    finalize self.caller;
+finalize reset:
+    input r0 as address.public;
+    ternary true r0 r0 into r1;
+    clear account r1;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_set_with_struct() {
     // Initialize a new program.
@@ -2115,7 +2285,8 @@ finalize compute:
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    let program_owner = sample_program_owner(&deployment, rng);
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment, &program_owner).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -2229,6 +2400,62 @@ fn test_sanity_check_transfer_and_fee() {
     }
 }
 
+#[test]
+fn test_process_transfer_private_on_insecure_devnet() {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    struct LocalSrs;
+
+    impl DevnetConfig for LocalSrs {
+        const INSECURE_SRS_DEGREE: Option<usize> = Some(1 << 18);
+    }
+
+    type LocalNetwork = Devnet<LocalSrs>;
+    type LocalAleo = AleoDevnet<LocalSrs>;
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let private_key = PrivateKey::<LocalNetwork>::new(rng).unwrap();
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let caller = Address::try_from(&private_key).unwrap();
+
+    // Construct the process, which synthesizes the 'credits.aleo' and inclusion keys from the local universal SRS.
+    let process = Process::<LocalNetwork>::setup::<LocalAleo, _>(rng).unwrap();
+    // Initialize the block store.
+    let block_store = BlockStore::<LocalNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Executes the given function in 'credits.aleo', and ensures the execution verifies.
+    let execute = |function_name: &str, inputs: &[Value<LocalNetwork>], rng: &mut TestRng| {
+        let authorization =
+            process.authorize::<LocalAleo, _>(&private_key, "credits.aleo", function_name, inputs.iter(), rng).unwrap();
+        let (_, mut trace) = process.execute::<LocalAleo>(authorization).unwrap();
+        trace.prepare(&block_store).unwrap();
+        let execution = trace.prove_execution::<LocalAleo, _>(&format!("credits.aleo/{function_name}"), rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+        execution
+    };
+
+    // Mint a record, and add it to the block store.
+    let inputs = [Value::from_str(&format!("{caller}")).unwrap(), Value::from_str("1_000_000_u64").unwrap()];
+    let transaction = Transaction::from_execution(execute("mint", &inputs, rng), None).unwrap();
+    let transactions =
+        Transactions::from_iter([ConfirmedTransaction::accepted_execute(0, transaction, vec![]).unwrap()]);
+    let header = Header::genesis(&transactions).unwrap();
+    let block = Block::new(&private_key, Default::default(), header, transactions, vec![], None, rng).unwrap();
+    block_store.insert(&block).unwrap();
+
+    // Transfer the record privately, which proves its inclusion in the block store.
+    let (_, record) = block.records().next().unwrap();
+    let record = record.decrypt(&view_key).unwrap();
+    let inputs =
+        [Value::Record(record), Value::from_str(&format!("{caller}")).unwrap(), Value::from_str("1_u64").unwrap()];
+    let execution = execute("transfer_private", &inputs, rng);
+    assert_eq!(execution.len(), 1);
+
+    // Ensure the inclusion keys are synthesized once.
+    assert!(core::ptr::eq(LocalNetwork::inclusion_verifying_key(), LocalNetwork::inclusion_verifying_key()));
+}
+
 #[test]
 fn test_process_execute_and_verify_call_to_closure() {
     // Initialize a new program.
@@ -2328,6 +2555,61 @@ function compute:
     process.verify_execution(&execution).unwrap();
 }
 
+#[test]
+fn test_stack_export_constraint_matrices() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program export.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    mul r0 r1 into r2;
+    add r2 r0 into r3;
+    output r3 as field.private;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+    // Retrieve the stack.
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Export the constraint matrices.
+    let matrices = stack.export_constraint_matrices::<CurrentAleo, _>(&function_name, rng).unwrap();
+    assert_eq!(program.id(), matrices.program_id());
+    assert_eq!(&function_name, matrices.function_name());
+    assert!(matrices.num_constraints() > 0);
+
+    // Ensure the matrices do not depend on the sampled inputs.
+    let candidate = stack.export_constraint_matrices::<CurrentAleo, _>(&function_name, rng).unwrap();
+    assert_eq!(matrices, candidate);
+
+    // Compute an assignment for the function.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+    let assignment = get_assignment(stack, &private_key, function_name, &[r0, r1], rng);
+    assert_eq!(assignment.num_public(), matrices.num_public_inputs());
+    assert_eq!(assignment.num_private(), matrices.num_private_inputs());
+    assert_eq!(assignment.num_constraints(), matrices.num_constraints());
+
+    // Ensure the assignment satisfies the exported matrices.
+    let public_inputs = assignment.public_inputs().values().map(|value| Field::new(*value)).collect::<Vec<_>>();
+    let private_inputs = assignment.private_inputs().values().map(|value| Field::new(*value)).collect::<Vec<_>>();
+    assert!(matrices.is_satisfied(&public_inputs, &private_inputs));
+    let mut private_inputs = private_inputs;
+    private_inputs[0] += Field::one();
+    assert!(!matrices.is_satisfied(&public_inputs, &private_inputs));
+}
+
 fn get_assignment(
     stack: &Stack<CurrentNetwork>,
     private_key: &PrivateKey<CurrentNetwork>,
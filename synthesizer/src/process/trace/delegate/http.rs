@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A function that returns the authentication headers for the given request body.
+pub type ProvingAuthenticator = Arc<dyn Fn(&str) -> Result<Vec<(String, String)>> + Send + Sync>;
+
+/// A reference `ProvingDelegate`, which posts the proving request as JSON to a remote prover,
+/// and expects the proof as JSON in response.
+#[derive(Clone)]
+pub struct HttpProvingClient {
+    /// The URL of the remote prover.
+    url: String,
+    /// The static headers of every request, i.e. an API key.
+    headers: Vec<(String, String)>,
+    /// The authenticator, which computes per-request headers, i.e. a signature of the body.
+    authenticator: Option<ProvingAuthenticator>,
+}
+
+impl HttpProvingClient {
+    /// Initializes a new client for the remote prover at the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into(), headers: Vec::new(), authenticator: None }
+    }
+
+    /// Returns the client with the given header on every request.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Returns the client with the given authenticator, which computes the headers of each request from its body.
+    pub fn with_authenticator(mut self, authenticator: ProvingAuthenticator) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Returns the URL of the remote prover.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the headers for a request with the given body.
+    fn headers_for(&self, body: &str) -> Result<Vec<(String, String)>> {
+        let mut headers = self.headers.clone();
+        if let Some(authenticator) = &self.authenticator {
+            headers.extend(authenticator(body)?);
+        }
+        Ok(headers)
+    }
+}
+
+impl<N: Network> ProvingDelegate<N> for HttpProvingClient {
+    /// Posts the proving request to the remote prover, and returns its proof.
+    fn prove(&self, request: &ProvingRequest<N>) -> Result<Proof<N>> {
+        // Serialize the request.
+        let body = serde_json::to_string(request)?;
+        // Prepare the request.
+        let mut http_request = ureq::post(&self.url).set("Content-Type", "application/json");
+        for (name, value) in self.headers_for(&body)? {
+            http_request = http_request.set(&name, &value);
+        }
+        // Send the request.
+        let response = http_request.send_string(&body)?;
+        match response.status() {
+            200 => Ok(response.into_json()?),
+            status => bail!("The remote prover at {} responded with status {status}", self.url),
+        }
+    }
+}
+
+impl Debug for HttpProvingClient {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Note: The headers are omitted, as they may contain credentials.
+        f.debug_struct("HttpProvingClient").field("url", &self.url).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_for() {
+        let client = HttpProvingClient::new("http://localhost:3030/prove")
+            .with_header("X-Api-Key", "key")
+            .with_authenticator(Arc::new(|body: &str| Ok(vec![("X-Body-Length".to_string(), body.len().to_string())])));
+        assert_eq!(client.url(), "http://localhost:3030/prove");
+
+        let headers = client.headers_for("{}").unwrap();
+        assert_eq!(headers, vec![
+            ("X-Api-Key".to_string(), "key".to_string()),
+            ("X-Body-Length".to_string(), "2".to_string())
+        ]);
+        // Ensure the headers are not printed.
+        assert!(!format!("{client:?}").contains("key"));
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod http;
+pub use http::*;
+
+use crate::snark::{Proof, ProvingKey, VerifyingKey};
+use circuit::Assignment;
+use console::{network::prelude::*, types::Field};
+
+use std::sync::Arc;
+
+/// A delegate that computes proofs on behalf of the prover, i.e. a remote proving service.
+///
+/// The delegate is not trusted: every proof it returns is checked against the verifying keys of the request.
+pub trait ProvingDelegate<N: Network>: Send + Sync {
+    /// Returns a batch proof for the given proving request.
+    fn prove(&self, request: &ProvingRequest<N>) -> Result<Proof<N>>;
+}
+
+/// The assignments of one circuit in a proving request.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ProvingTask<N: Network> {
+    /// The ID of the verifying key of the circuit.
+    circuit_id: Field<N>,
+    /// The `(public inputs, private inputs)` of each assignment, in the variable layout of `ConstraintMatrices`.
+    assignments: Vec<(Vec<Field<N>>, Vec<Field<N>>)>,
+}
+
+impl<N: Network> ProvingTask<N> {
+    /// Returns the ID of the verifying key of the circuit.
+    pub const fn circuit_id(&self) -> &Field<N> {
+        &self.circuit_id
+    }
+
+    /// Returns the `(public inputs, private inputs)` of each assignment.
+    pub fn assignments(&self) -> &[(Vec<Field<N>>, Vec<Field<N>>)] {
+        &self.assignments
+    }
+}
+
+/// A request for a batch proof, which a `ProvingDelegate` answers with a proof.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ProvingRequest<N: Network> {
+    /// The locator of the batch, i.e. `credits.aleo/transfer_public`.
+    locator: String,
+    /// The proving tasks.
+    tasks: Vec<ProvingTask<N>>,
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// Returns the locator of the batch.
+    pub fn locator(&self) -> &str {
+        &self.locator
+    }
+
+    /// Returns the proving tasks.
+    pub fn tasks(&self) -> &[ProvingTask<N>] {
+        &self.tasks
+    }
+}
+
+/// Returns a proof for the given batch of proving keys and assignments from the delegate,
+/// ensuring the proof is valid for the verifying keys and public inputs of the assignments.
+pub(super) fn prove_batch_with_delegate<N: Network>(
+    locator: &str,
+    proving_tasks: &[(ProvingKey<N>, Vec<Assignment<N::Field>>)],
+    delegate: &dyn ProvingDelegate<N>,
+) -> Result<Proof<N>> {
+    // Prepare the verifying keys and the proving tasks.
+    let mut verifier_inputs = Vec::with_capacity(proving_tasks.len());
+    let mut tasks = Vec::with_capacity(proving_tasks.len());
+    for (proving_key, assignments) in proving_tasks {
+        // Retrieve the verifying key.
+        let verifying_key = VerifyingKey::<N>::new(Arc::new(proving_key.circuit_verifying_key.clone()));
+        // Prepare the inputs of each assignment.
+        let assignments = assignments
+            .iter()
+            .map(|assignment| {
+                let public_inputs = assignment.public_inputs().values().map(|value| Field::new(*value)).collect();
+                let private_inputs = assignment.private_inputs().values().map(|value| Field::new(*value)).collect();
+                (public_inputs, private_inputs)
+            })
+            .collect::<Vec<(Vec<_>, Vec<_>)>>();
+        // Prepare the public inputs for the verifier.
+        let public_inputs =
+            assignments.iter().map(|(public_inputs, _)| public_inputs.iter().map(|input| **input).collect()).collect();
+
+        tasks.push(ProvingTask { circuit_id: verifying_key.to_id()?, assignments });
+        verifier_inputs.push((verifying_key, public_inputs));
+    }
+
+    // Request the proof from the delegate.
+    let request = ProvingRequest { locator: locator.to_string(), tasks };
+    let proof = delegate.prove(&request)?;
+
+    // Ensure the proof is valid.
+    ensure!(
+        VerifyingKey::verify_batch(locator, verifier_inputs, &proof),
+        "The delegated proof for '{locator}' is invalid"
+    );
+    Ok(proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snark::UniversalSRS;
+    use circuit::prelude::{Circuit, Environment as CircuitEnvironment, Inject, Mode};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A delegate that proves the given tasks locally, regardless of the request.
+    struct LocalDelegate(
+        Vec<(ProvingKey<CurrentNetwork>, Vec<Assignment<<Circuit as CircuitEnvironment>::BaseField>>)>,
+    );
+
+    impl ProvingDelegate<CurrentNetwork> for LocalDelegate {
+        fn prove(&self, request: &ProvingRequest<CurrentNetwork>) -> Result<Proof<CurrentNetwork>> {
+            ProvingKey::prove_batch(request.locator(), &self.0, &mut TestRng::default())
+        }
+    }
+
+    /// Returns the assignment for `y = x^65`, where `x` is public.
+    fn sample_assignment(x: u64) -> Assignment<<Circuit as CircuitEnvironment>::BaseField> {
+        let x = circuit::Field::<Circuit>::new(Mode::Public, Field::from_u64(x));
+        let mut y = x.clone();
+        for _ in 0..64 {
+            y *= &x;
+        }
+        Circuit::eject_assignment_and_reset()
+    }
+
+    #[test]
+    fn test_prove_batch_with_delegate() {
+        // Synthesize the circuit keys.
+        let assignment = sample_assignment(3);
+        let universal_srs = UniversalSRS::<CurrentNetwork>::load().unwrap();
+        let (proving_key, verifying_key) = universal_srs.to_circuit_key("power", &assignment).unwrap();
+        let proving_tasks = vec![(proving_key.clone(), vec![assignment])];
+
+        // Ensure a valid proof from the delegate is accepted.
+        let delegate = LocalDelegate(proving_tasks.clone());
+        let proof = prove_batch_with_delegate("test.aleo/power", &proving_tasks, &delegate).unwrap();
+        let inputs = vec![*Field::<CurrentNetwork>::one(), *Field::<CurrentNetwork>::from_u64(3)];
+        assert!(VerifyingKey::verify_batch("test.aleo/power", vec![(verifying_key, vec![inputs])], &proof));
+
+        // Ensure a proof for other public inputs is rejected.
+        let delegate = LocalDelegate(vec![(proving_key, vec![sample_assignment(4)])]);
+        assert!(prove_batch_with_delegate("test.aleo/power", &proving_tasks, &delegate).is_err());
+    }
+
+    #[test]
+    fn test_proving_request_serde_json() {
+        let assignment = sample_assignment(3);
+        let request = ProvingRequest::<CurrentNetwork> {
+            locator: "test.aleo/power".to_string(),
+            tasks: vec![ProvingTask {
+                circuit_id: Field::from_u64(1),
+                assignments: vec![(
+                    assignment.public_inputs().values().map(|value| Field::new(*value)).collect(),
+                    assignment.private_inputs().values().map(|value| Field::new(*value)).collect(),
+                )],
+            }],
+        };
+        assert_eq!(request.tasks()[0].assignments()[0].0.len(), 2);
+
+        // Ensure the request round-trips through JSON.
+        let string = serde_json::to_string(&request).unwrap();
+        assert_eq!(request, serde_json::from_str(&string).unwrap());
+    }
+}
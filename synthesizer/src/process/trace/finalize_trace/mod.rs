@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Command;
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Register, Value},
+};
+
+/// An event in the trace of a finalize execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FinalizeEvent<N: Network> {
+    /// The command at the given index was executed in the finalize scope of the given function.
+    Command { program_id: ProgramID<N>, function_name: Identifier<N>, index: usize, command: Command<N> },
+    /// The value was written to the register.
+    RegisterWrite { register: Register<N>, value: Value<N> },
+    /// The key was read from the mapping (i.e. `contains`, `get`, `get.or_use`),
+    /// where the value is `None` if the key does not exist.
+    MappingRead { program_id: ProgramID<N>, mapping_name: Identifier<N>, key: Plaintext<N>, value: Option<Value<N>> },
+    /// The key was written to the mapping (i.e. `set`, `remove`),
+    /// where the value is `None` if the key was removed.
+    MappingWrite { program_id: ProgramID<N>, mapping_name: Identifier<N>, key: Plaintext<N>, value: Option<Value<N>> },
+    /// The finalize execution aborted with the given error.
+    Abort { error: String },
+}
+
+impl<N: Network> Display for FinalizeEvent<N> {
+    /// Prints the event as a single line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Command { program_id, function_name, index, command } => {
+                write!(f, "{program_id}/{function_name} [{index}]: {command}")
+            }
+            Self::RegisterWrite { register, value } => write!(f, "  {register} = {value}"),
+            Self::MappingRead { program_id, mapping_name, key, value: Some(value) } => {
+                write!(f, "  read {program_id}/{mapping_name}[{key}] = {value}")
+            }
+            Self::MappingRead { program_id, mapping_name, key, value: None } => {
+                write!(f, "  read {program_id}/{mapping_name}[{key}] (missing)")
+            }
+            Self::MappingWrite { program_id, mapping_name, key, value: Some(value) } => {
+                write!(f, "  write {program_id}/{mapping_name}[{key}] = {value}")
+            }
+            Self::MappingWrite { program_id, mapping_name, key, value: None } => {
+                write!(f, "  remove {program_id}/{mapping_name}[{key}]")
+            }
+            Self::Abort { error } => write!(f, "abort: {error}"),
+        }
+    }
+}
+
+/// A structured trace of a finalize execution, which records every command executed,
+/// register written, and mapping read and write, in order of execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeTrace<N: Network> {
+    /// The events, in order of execution.
+    events: Vec<FinalizeEvent<N>>,
+    /// The number of steps metered, up to and including the last command.
+    steps: u64,
+}
+
+impl<N: Network> Default for FinalizeTrace<N> {
+    /// Initializes an empty trace.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> FinalizeTrace<N> {
+    /// Initializes an empty trace.
+    pub const fn new() -> Self {
+        Self { events: Vec::new(), steps: 0 }
+    }
+
+    /// Returns the events, in order of execution.
+    pub fn events(&self) -> &[FinalizeEvent<N>] {
+        &self.events
+    }
+
+    /// Returns the number of steps metered, up to and including the last command.
+    pub const fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Returns the executed commands, in order of execution.
+    pub fn commands(&self) -> impl '_ + Iterator<Item = &Command<N>> {
+        self.events.iter().filter_map(|event| match event {
+            FinalizeEvent::Command { command, .. } => Some(command),
+            _ => None,
+        })
+    }
+
+    /// Returns the error, if the finalize execution aborted.
+    pub fn abort_error(&self) -> Option<&str> {
+        match self.events.last() {
+            Some(FinalizeEvent::Abort { error }) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Appends the event to the trace.
+    pub(crate) fn push(&mut self, event: FinalizeEvent<N>) {
+        self.events.push(event);
+    }
+
+    /// Sets the number of steps metered.
+    pub(crate) fn set_steps(&mut self, steps: u64) {
+        self.steps = steps;
+    }
+}
+
+impl<N: Network> Display for FinalizeTrace<N> {
+    /// Prints the trace, with one event per line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for event in &self.events {
+            writeln!(f, "{event}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_finalize_trace() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("testing.aleo").unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let command = Command::from_str("get.or_use account[r0] 0u64 into r1;").unwrap();
+
+        let mut trace = FinalizeTrace::<CurrentNetwork>::new();
+        trace.push(FinalizeEvent::Command { program_id, function_name, index: 0, command: command.clone() });
+        trace.push(FinalizeEvent::MappingRead {
+            program_id,
+            mapping_name,
+            key: Plaintext::from_str("1u8").unwrap(),
+            value: None,
+        });
+        trace.push(FinalizeEvent::RegisterWrite {
+            register: Register::from_str("r1").unwrap(),
+            value: Value::from_str("0u64").unwrap(),
+        });
+        assert_eq!(None, trace.abort_error());
+        trace.push(FinalizeEvent::Abort { error: "failed".to_string() });
+
+        assert_eq!(4, trace.events().len());
+        assert_eq!(vec![&command], trace.commands().collect::<Vec<_>>());
+        assert_eq!(Some("failed"), trace.abort_error());
+        assert_eq!(
+            "testing.aleo/compute [0]: get.or_use account[r0] 0u64 into r1;\n  read testing.aleo/account[1u8] (missing)\n  r1 = 0u64\nabort: failed\n",
+            trace.to_string()
+        );
+    }
+}
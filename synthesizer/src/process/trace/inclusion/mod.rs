@@ -24,7 +24,18 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{InputID, StatePath, TransactionLeaf, TransitionLeaf, TransitionPath, TRANSACTION_DEPTH},
+    program::{
+        InputID,
+        Plaintext,
+        Record,
+        StatePath,
+        TransactionLeaf,
+        TransactionTree,
+        TransitionLeaf,
+        TransitionPath,
+        TransitionTree,
+        TRANSACTION_DEPTH,
+    },
     types::{Field, Group},
 };
 
@@ -86,8 +97,8 @@ impl<N: Network> Inclusion<N> {
 
         // Process the outputs.
         for (index, output) in transition.outputs().iter().enumerate() {
-            // Filter the outputs for records.
-            if let Output::Record(commitment, ..) = output {
+            // Filter the outputs for records, including audited records.
+            if let Output::Record(commitment, ..) | Output::AuditedRecord(commitment, ..) = output {
                 // Compute the output index.
                 let output_index = u8::try_from(input_ids.len().saturating_add(index))?;
                 // Compute the transaction leaf.
@@ -176,6 +187,36 @@ impl<N: Network> InclusionAssignment<N> {
         Self { state_path, commitment, gamma, serial_number, local_state_root, is_global }
     }
 
+    /// Samples an assignment for a record in a local state path, which is used to synthesize the inclusion circuit keys.
+    pub fn sample<R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
+        // Sample the commitment and gamma, and compute the serial number.
+        let commitment = Field::rand(rng);
+        let gamma = Group::rand(rng);
+        let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
+
+        // Construct the transition path and transition leaf.
+        let transition_leaf = TransitionLeaf::new_with_version(0, 3, commitment);
+        let transition_tree: TransitionTree<N> = N::merkle_tree_bhp(&[transition_leaf.to_bits_le()])?;
+        let transition_path = transition_tree.prove(0, &transition_leaf.to_bits_le())?;
+
+        // Construct the transaction path and transaction leaf.
+        let transaction_leaf = TransactionLeaf::new_execution(0, *transition_tree.root());
+        let transaction_tree: TransactionTree<N> = N::merkle_tree_bhp(&[transaction_leaf.to_bits_le()])?;
+        let transaction_path = transaction_tree.prove(0, &transaction_leaf.to_bits_le())?;
+        let local_state_root = (*transaction_tree.root()).into();
+
+        // Construct the state path.
+        let state_path = StatePath::new_local(
+            Field::<N>::rand(rng).into(),
+            local_state_root,
+            transaction_path,
+            transaction_leaf,
+            transition_path,
+            transition_leaf,
+        )?;
+        Ok(Self::new(state_path, commitment, gamma, serial_number, local_state_root, false))
+    }
+
     /// The circuit for state path verification.
     ///
     /// # Diagram
@@ -15,6 +15,12 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod delegate;
+pub use delegate::*;
+
+mod finalize_trace;
+pub use finalize_trace::*;
+
 mod inclusion;
 pub use inclusion::*;
 
@@ -23,7 +29,7 @@ pub use query::*;
 
 use crate::{
     block::{Execution, Fee, Input, Transition},
-    snark::{Proof, ProvingKey, VerifyingKey},
+    snark::{PreparedVerifyingKey, Proof, ProvingJob, ProvingKey},
     store::BlockStorage,
 };
 use circuit::Assignment;
@@ -154,6 +160,17 @@ impl<N: Network> Trace<N> {
         &self,
         locator: &str,
         rng: &mut R,
+    ) -> Result<Execution<N>> {
+        self.prove_execution_with_job::<A, R>(locator, &ProvingJob::default(), rng)
+    }
+
+    /// Returns a new execution with a proof, for the current inclusion assignments and global state root.
+    /// The prover reports its progress to the given job, and stops early if the job is cancelled.
+    pub fn prove_execution_with_job<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        locator: &str,
+        job: &ProvingJob,
+        rng: &mut R,
     ) -> Result<Execution<N>> {
         // Ensure this is not a fee.
         ensure!(!self.is_fee(), "The trace cannot prove execution for fee");
@@ -172,13 +189,52 @@ impl<N: Network> Trace<N> {
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
         let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, job, rng)?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
 
+    /// Returns a new execution with a proof from the given delegate, for the current inclusion assignments and global state root.
+    /// The proof is checked against the verifying keys before it is returned.
+    pub fn prove_execution_with_delegate<A: circuit::Aleo<Network = N>>(
+        &self,
+        locator: &str,
+        delegate: &dyn ProvingDelegate<N>,
+    ) -> Result<Execution<N>> {
+        // Ensure this is not a fee.
+        ensure!(!self.is_fee(), "The trace cannot prove execution for fee");
+        // Ensure there are no fee transitions.
+        ensure!(
+            self.transitions.iter().all(|transition| !transition.is_fee()),
+            "The trace cannot prove execution for a fee, call 'prove_fee_with_delegate' instead"
+        );
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Construct the proving tasks.
+        let proving_tasks = self.transition_tasks.values().cloned().collect();
+        let proving_tasks = Self::prepare_proving_tasks::<A>(proving_tasks, inclusion_assignments, *global_state_root)?;
+        // Compute the proof.
+        let proof = delegate::prove_batch_with_delegate(locator, &proving_tasks, delegate)?;
+        // Return the execution.
+        Execution::from(self.transitions.iter().cloned(), *global_state_root, Some(proof))
+    }
+
     /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
     pub fn prove_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Fee<N>> {
+        self.prove_fee_with_job::<A, R>(&ProvingJob::default(), rng)
+    }
+
+    /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
+    /// The prover reports its progress to the given job, and stops early if the job is cancelled.
+    pub fn prove_fee_with_job<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        job: &ProvingJob,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
         // Ensure this is a fee.
         ensure!(self.is_fee(), "The trace cannot prove fee for execution");
         // Retrieve the inclusion assignments.
@@ -199,17 +255,46 @@ impl<N: Network> Trace<N> {
             proving_tasks,
             inclusion_assignments,
             *global_state_root,
+            job,
             rng,
         )?;
         // Return the fee.
         Ok(Fee::from(fee_transition.clone(), global_state_root, Some(proof)))
     }
 
+    /// Returns a new fee with a proof from the given delegate, for the current inclusion assignment and global state root.
+    /// The proof is checked against the verifying keys before it is returned.
+    pub fn prove_fee_with_delegate<A: circuit::Aleo<Network = N>>(
+        &self,
+        delegate: &dyn ProvingDelegate<N>,
+    ) -> Result<Fee<N>> {
+        // Ensure this is a fee.
+        ensure!(self.is_fee(), "The trace cannot prove fee for execution");
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Ensure there is only 1 inclusion assignment.
+        ensure!(inclusion_assignments.len() == 1, "Expected 1 inclusion assignment for proving the fee");
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Retrieve the fee transition.
+        let fee_transition = &self.transitions[0];
+        // Construct the proving tasks.
+        let proving_tasks = self.transition_tasks.values().cloned().collect();
+        let proving_tasks = Self::prepare_proving_tasks::<A>(proving_tasks, inclusion_assignments, *global_state_root)?;
+        // Compute the proof.
+        let proof = delegate::prove_batch_with_delegate("credits.aleo/fee", &proving_tasks, delegate)?;
+        // Return the fee.
+        Ok(Fee::from(fee_transition.clone(), *global_state_root, Some(proof)))
+    }
+
     /// Checks the proof for the execution.
     /// Note: This does *not* check that the global state root exists in the ledger.
     pub fn verify_execution_proof(
         locator: &str,
-        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        verifier_inputs: Vec<(PreparedVerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        inclusion_verifying_key: PreparedVerifyingKey<N>,
         execution: &Execution<N>,
     ) -> Result<()> {
         // Retrieve the global state root.
@@ -219,7 +304,14 @@ impl<N: Network> Trace<N> {
             bail!("Expected the execution to contain a proof")
         };
         // Verify the execution proof.
-        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
+        match Self::verify_batch(
+            locator,
+            verifier_inputs,
+            inclusion_verifying_key,
+            global_state_root,
+            execution.transitions(),
+            proof,
+        ) {
             Ok(()) => Ok(()),
             Err(e) => bail!("Execution is invalid - {e}"),
         }
@@ -227,7 +319,11 @@ impl<N: Network> Trace<N> {
 
     /// Checks the proof for the fee.
     /// Note: This does *not* check that the global state root exists in the ledger.
-    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+    pub fn verify_fee_proof(
+        verifier_inputs: (PreparedVerifyingKey<N>, Vec<Vec<N::Field>>),
+        inclusion_verifying_key: PreparedVerifyingKey<N>,
+        fee: &Fee<N>,
+    ) -> Result<()> {
         // Retrieve the global state root.
         let global_state_root = fee.global_state_root();
         // Ensure the global state root is not zero.
@@ -246,6 +342,7 @@ impl<N: Network> Trace<N> {
         match Self::verify_batch(
             "credits.aleo/fee",
             vec![verifier_inputs],
+            inclusion_verifying_key,
             global_state_root,
             [fee.transition()].into_iter(),
             proof,
@@ -260,11 +357,27 @@ impl<N: Network> Trace<N> {
     /// Returns the global state root and proof for the given assignments.
     fn prove_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         locator: &str,
-        mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
+        proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
         global_state_root: N::StateRoot,
+        job: &ProvingJob,
         rng: &mut R,
     ) -> Result<(N::StateRoot, Proof<N>)> {
+        // Construct the proving tasks, including the inclusion assignments.
+        let proving_tasks = Self::prepare_proving_tasks::<A>(proving_tasks, inclusion_assignments, global_state_root)?;
+        // Compute the proof.
+        let proof = ProvingKey::prove_batch_with_job(locator, &proving_tasks, job, rng)?;
+        // Return the global state root and proof.
+        Ok((global_state_root, proof))
+    }
+
+    /// Returns the given proving tasks, with the inclusion proving task for the given inclusion assignments.
+    #[allow(clippy::type_complexity)]
+    fn prepare_proving_tasks<A: circuit::Aleo<Network = N>>(
+        mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
+        inclusion_assignments: &[InclusionAssignment<N>],
+        global_state_root: N::StateRoot,
+    ) -> Result<Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>> {
         // Ensure the global state root is not zero.
         // Note: To protect user privacy, even when there are *no* inclusion assignments,
         // the user must provide a real global state root (which is checked in consensus).
@@ -291,17 +404,15 @@ impl<N: Network> Trace<N> {
             proving_tasks.push((proving_key, batch_inclusions));
         }
 
-        // Compute the proof.
-        let proof = ProvingKey::prove_batch(locator, &proving_tasks, rng)?;
-        // Return the global state root and proof.
-        Ok((global_state_root, proof))
+        Ok(proving_tasks)
     }
 
     /// Checks the proof for the given inputs.
     /// Note: This does *not* check that the global state root exists in the ledger.
     fn verify_batch<'a>(
         locator: &str,
-        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        mut verifier_inputs: Vec<(PreparedVerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        inclusion_verifying_key: PreparedVerifyingKey<N>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
@@ -310,13 +421,11 @@ impl<N: Network> Trace<N> {
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
         if !batch_inclusion_inputs.is_empty() {
-            // Fetch the inclusion verifying key.
-            let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
             // Insert the inclusion verifier inputs.
-            verifier_inputs.push((verifying_key, batch_inclusion_inputs));
+            verifier_inputs.push((inclusion_verifying_key, batch_inclusion_inputs));
         }
         // Verify the proof.
-        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
+        match PreparedVerifyingKey::verify_batch(locator, verifier_inputs, proof) {
             true => Ok(()),
             false => bail!("Failed to verify proof"),
         }
@@ -21,6 +21,9 @@ impl<N: Network> Process<N> {
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("verify_execution", transitions = execution.len()).entered();
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -51,6 +54,9 @@ impl<N: Network> Process<N> {
         // Initialize a map of transition IDs to references of the transition.
         let mut transition_map = HashMap::new();
 
+        // Retrieve the ID of the root transition, as only the root request signs the expiration height.
+        let root_id = *execution.peek()?.id();
+
         // Verify each transition.
         for transition in execution.transitions() {
             #[cfg(debug_assertions)]
@@ -105,15 +111,45 @@ impl<N: Network> Process<N> {
             // Retrieve the function from the stack.
             let function = stack.get_function(transition.function_name())?;
 
+            // Ensure the record outputs are audited records if and only if the program declares an auditor.
+            let is_audited = stack.program().auditor().is_some();
+            if transition.outputs().iter().any(|output| match output {
+                Output::Record(..) => is_audited,
+                Output::AuditedRecord(..) => !is_audited,
+                _ => false,
+            }) {
+                match is_audited {
+                    true => {
+                        bail!("The record outputs of '{}' must be encrypted to its auditor", transition.program_id())
+                    }
+                    false => bail!("The program '{}' does not declare an auditor", transition.program_id()),
+                }
+            }
+
+            // Retrieve the expiration height of the transition.
+            let valid_until = if *transition.id() == root_id { execution.valid_until() } else { None };
+
             // Construct the verifier inputs for the transition.
-            let inputs = self.to_transition_verifier_inputs(transition, &function, &call_graph, &mut transition_map)?;
+            let inputs = self.to_transition_verifier_inputs(
+                transition,
+                &function,
+                valid_until,
+                &call_graph,
+                &mut transition_map,
+            )?;
             lap!(timer, "Constructed the verifier inputs for a transition of {}", function.name());
 
+            // Retrieve the verifying key, which is the expiring circuit key if the request expires.
+            let verifying_key = match valid_until.is_some() {
+                true => stack.get_expiring_verifying_key(function.name())?,
+                false => stack.get_verifying_key(function.name())?,
+            };
+
             // Save the verifying key and its inputs.
             verifier_inputs
-                .entry(Locator::new(*stack.program_id(), *function.name()))
+                .entry((Locator::new(*stack.program_id(), *function.name()), valid_until.is_some()))
                 // Retrieve the verifying key, if it does not already exist.
-                .or_insert((stack.get_verifying_key(function.name())?, vec![]))
+                .or_insert((self.get_prepared_verifying_key(&verifying_key)?, vec![]))
                 .1
                 .push(inputs);
             lap!(timer, "Stored the verifier inputs for a transition of {}", function.name());
@@ -130,7 +166,12 @@ impl<N: Network> Process<N> {
         // Construct the list of verifier inputs.
         let verifier_inputs = verifier_inputs.values().cloned().collect();
         // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        Trace::verify_execution_proof(
+            &locator,
+            verifier_inputs,
+            self.get_prepared_inclusion_verifying_key()?,
+            execution,
+        )?;
         lap!(timer, "Verify the proof");
 
         finish!(timer);
@@ -144,6 +185,7 @@ impl<N: Network> Process<N> {
         &self,
         transition: &Transition<N>,
         function: &Function<N>,
+        valid_until: Option<u32>,
         call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
         transition_map: &mut HashMap<N::TransitionID, &Transition<N>>,
     ) -> Result<Vec<N::Field>> {
@@ -152,6 +194,8 @@ impl<N: Network> Process<N> {
 
         // [Inputs] Construct the verifier inputs to verify the proof.
         let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **transition.tcm()];
+        // [Inputs] Extend the verifier inputs with the expiration height, if the request expires.
+        inputs.extend(Request::<N>::expiration_field(valid_until).map(|expiration| *expiration));
         // [Inputs] Extend the verifier inputs with the input IDs.
         inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
 
@@ -101,10 +101,11 @@ impl<N: Network> Process<N> {
         ensure!(fee.finalize().is_none(), "The fee transition should not contain finalize inputs");
 
         // Retrieve the verifying key.
-        let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
+        let verifying_key =
+            self.get_prepared_verifying_key(&self.get_verifying_key(stack.program_id(), function.name())?)?;
 
         // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
+        Trace::verify_fee_proof((verifying_key, vec![inputs]), self.get_prepared_inclusion_verifying_key()?, fee)?;
         lap!(timer, "Verify the fee proof");
 
         finish!(timer);
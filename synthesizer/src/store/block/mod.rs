@@ -14,7 +14,17 @@
 
 use crate::{
     atomic_batch_scope,
-    block::{Block, Header, NumFinalizeSize, Ratify, Transaction, Transactions},
+    block::{
+        Block,
+        Header,
+        NumFinalizeSize,
+        Ratify,
+        Rejected,
+        RejectionCode,
+        RejectionMetadata,
+        Transaction,
+        Transactions,
+    },
     cow_to_cloned,
     cow_to_copied,
     store::{
@@ -30,14 +40,19 @@ use crate::{
 use console::{
     account::Signature,
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockPath, BlockTree, HeaderLeaf, HeaderPath, ProgramID, StatePath},
     types::Field,
 };
 use snarkvm_synthesizer_coinbase::{CoinbaseSolution, PuzzleCommitment};
 
 use anyhow::Result;
 use parking_lot::RwLock;
-use std::{borrow::Cow, io::Cursor, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{hash_map::Entry, HashMap},
+    io::Cursor,
+    sync::Arc,
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -71,13 +86,13 @@ fn to_confirmed_tuple<N: Network>(
             // Return the confirmed tuple.
             Ok((ConfirmedTxType::AcceptedExecute(index), tx, (num_finalize, finalize).to_bytes_le()?))
         }
-        ConfirmedTransaction::RejectedDeploy(index, tx, rejected) => {
+        ConfirmedTransaction::RejectedDeploy(index, tx, rejected, metadata) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedDeploy(index), tx, rejected.to_bytes_le()?))
+            Ok((ConfirmedTxType::RejectedDeploy(index), tx, to_rejected_blob(rejected, metadata)?))
         }
-        ConfirmedTransaction::RejectedExecute(index, tx, rejected) => {
+        ConfirmedTransaction::RejectedExecute(index, tx, rejected, metadata) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedExecute(index), tx, rejected.to_bytes_le()?))
+            Ok((ConfirmedTxType::RejectedExecute(index), tx, to_rejected_blob(rejected, metadata)?))
         }
     }
 }
@@ -109,11 +124,45 @@ fn to_confirmed_transaction<N: Network>(
             ConfirmedTransaction::accepted_execute(index, transaction, finalize)
         }
         ConfirmedTxType::RejectedDeploy(index) => {
-            ConfirmedTransaction::rejected_deploy(index, transaction, FromBytes::read_le(&*blob)?)
+            // Read the rejected deployment and the rejection metadata.
+            let (rejected, metadata) = from_rejected_blob(&blob)?;
+            ConfirmedTransaction::rejected_deploy(index, transaction, rejected, metadata)
         }
         ConfirmedTxType::RejectedExecute(index) => {
-            ConfirmedTransaction::rejected_execute(index, transaction, FromBytes::read_le(&*blob)?)
+            // Read the rejected execution and the rejection metadata.
+            let (rejected, metadata) = from_rejected_blob(&blob)?;
+            ConfirmedTransaction::rejected_execute(index, transaction, rejected, metadata)
+        }
+    }
+}
+
+/// The version of the rejected blob, which records the rejection metadata after the rejected transaction.
+///
+/// Note: The original rejected blob is the rejected transaction alone, whose first byte is its variant,
+/// i.e. `0` or `1`. It is still written for a rejected transaction without recorded rejection metadata.
+const REJECTED_BLOB_VERSION: u8 = 2;
+
+/// Returns the blob of the given rejected transaction and rejection metadata.
+fn to_rejected_blob<N: Network>(rejected: Rejected<N>, metadata: RejectionMetadata<N>) -> Result<Vec<u8>> {
+    match metadata.is_recorded() {
+        true => Ok((REJECTED_BLOB_VERSION, (rejected, metadata)).to_bytes_le()?),
+        false => Ok(rejected.to_bytes_le()?),
+    }
+}
+
+/// Returns the rejected transaction and rejection metadata from the given blob,
+/// which is in either the original or the versioned layout.
+fn from_rejected_blob<N: Network>(blob: &[u8]) -> Result<(Rejected<N>, RejectionMetadata<N>)> {
+    // Initialize a cursor.
+    let mut cursor = Cursor::new(blob);
+    match blob.first() {
+        // Read the version, the rejected transaction, and the rejection metadata.
+        Some(&REJECTED_BLOB_VERSION) => {
+            let _version = u8::read_le(&mut cursor)?;
+            Ok(FromBytes::read_le(&mut cursor)?)
         }
+        // Read the rejected transaction, which has no rejection metadata.
+        _ => Ok((Rejected::read_le(&mut cursor)?, RejectionMetadata::from_code(RejectionCode::Unknown))),
     }
 }
 
@@ -454,84 +503,123 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns a state path for the given `commitment`.
     fn get_state_path_for_commitment(&self, commitment: &Field<N>, block_tree: &BlockTree<N>) -> Result<StatePath<N>> {
-        // Ensure the commitment exists.
-        if !self.transition_store().contains_commitment(commitment)? {
-            bail!("Commitment '{commitment}' does not exist");
+        match self.get_state_paths_for_commitments(&[*commitment], block_tree)?.pop() {
+            Some(state_path) => Ok(state_path),
+            None => bail!("Failed to construct the state path for commitment '{commitment}'"),
         }
+    }
 
-        // Find the transition that contains the commitment.
-        let transition_id = self.transition_store().find_transition_id(commitment)?;
-        // Find the transaction that contains the transition.
-        let transaction_id = match self.transaction_store().find_transaction_id_from_transition_id(&transition_id)? {
-            Some(transaction_id) => transaction_id,
-            None => bail!("The transaction ID for commitment '{commitment}' is missing in storage"),
-        };
-        // Find the block that contains the transaction.
-        let block_hash = match self.find_block_hash(&transaction_id)? {
-            Some(block_hash) => block_hash,
-            None => bail!("The block hash for commitment '{commitment}' is missing in storage"),
-        };
-
-        // Retrieve the transition.
-        let transition = match self.transition_store().get_transition(&transition_id)? {
-            Some(transition) => transition,
-            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
-        };
-        // Retrieve the block.
-        let block = match self.get_block(&block_hash)? {
-            Some(block) => block,
-            None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
-        };
-
-        // Construct the global state root and block path.
+    /// Returns the state paths for the given `commitments`, in the same order.
+    ///
+    /// The block lookups and the block and header paths are shared by the commitments in the same block.
+    fn get_state_paths_for_commitments(
+        &self,
+        commitments: &[Field<N>],
+        block_tree: &BlockTree<N>,
+    ) -> Result<Vec<StatePath<N>>> {
+        // Construct the global state root.
         let global_state_root = *block_tree.root();
-        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
-
         // Ensure the global state root exists in storage.
         if !self.reverse_state_root_map().contains_key_confirmed(&global_state_root.into())? {
-            bail!("The global state root '{global_state_root}' for commitment '{commitment}' is missing in storage");
+            bail!("The global state root '{global_state_root}' is missing in storage");
         }
 
-        // Construct the transition path and transaction leaf.
-        let transition_leaf = transition.to_leaf(commitment, false)?;
-        let transition_path = transition.to_path(&transition_leaf)?;
+        // Initialize a map of the block hash to the block, block path, header root, header path, and header leaf.
+        let mut blocks = HashMap::<_, (Block<N>, BlockPath<N>, Field<N>, HeaderPath<N>, HeaderLeaf<N>)>::new();
 
-        // Construct the transactions path.
-        let transactions = block.transactions();
-        let transactions_path = match transactions.to_path(transaction_id) {
-            Ok(transactions_path) => transactions_path,
-            Err(_) => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
-        };
+        commitments
+            .iter()
+            .map(|commitment| {
+                // Ensure the commitment exists.
+                if !self.transition_store().contains_commitment(commitment)? {
+                    bail!("Commitment '{commitment}' does not exist");
+                }
 
-        // Construct the transaction path and transaction leaf.
-        let transaction = match transactions.get(&transaction_id) {
-            Some(transaction) => transaction,
-            None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
-        };
-        let transaction_leaf = transaction.to_leaf(transition.id())?;
-        let transaction_path = transaction.to_path(&transaction_leaf)?;
-
-        // Construct the block header path.
-        let block_header = block.header();
-        let header_root = block_header.to_root()?;
-        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
-        let header_path = block_header.to_path(&header_leaf)?;
-
-        Ok(StatePath::from(
-            global_state_root.into(),
-            block_path,
-            block.hash(),
-            block.previous_hash(),
-            header_root,
-            header_path,
-            header_leaf,
-            transactions_path,
-            transaction.id(),
-            transaction_path,
-            transaction_leaf,
-            transition_path,
-            transition_leaf,
-        ))
+                // Find the transition that contains the commitment.
+                let transition_id = self.transition_store().find_transition_id(commitment)?;
+                // Find the transaction that contains the transition.
+                let transaction_id =
+                    match self.transaction_store().find_transaction_id_from_transition_id(&transition_id)? {
+                        Some(transaction_id) => transaction_id,
+                        None => bail!("The transaction ID for commitment '{commitment}' is missing in storage"),
+                    };
+                // Find the block that contains the transaction.
+                let block_hash = match self.find_block_hash(&transaction_id)? {
+                    Some(block_hash) => block_hash,
+                    None => bail!("The block hash for commitment '{commitment}' is missing in storage"),
+                };
+
+                // Retrieve the transition.
+                let transition = match self.transition_store().get_transition(&transition_id)? {
+                    Some(transition) => transition,
+                    None => {
+                        bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage")
+                    }
+                };
+
+                // Retrieve the block, and construct its block path and header path, if this is its first commitment.
+                let (block, block_path, header_root, header_path, header_leaf) = match blocks.entry(block_hash) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        // Retrieve the block.
+                        let block = match self.get_block(&block_hash)? {
+                            Some(block) => block,
+                            None => {
+                                bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage")
+                            }
+                        };
+                        // Construct the block path.
+                        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+                        // Construct the block header path.
+                        let block_header = block.header();
+                        let header_root = block_header.to_root()?;
+                        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
+                        let header_path = block_header.to_path(&header_leaf)?;
+
+                        entry.insert((block, block_path, header_root, header_path, header_leaf))
+                    }
+                };
+
+                // Construct the transition path and transaction leaf.
+                let transition_leaf = transition.to_leaf(commitment, false)?;
+                let transition_path = transition.to_path(&transition_leaf)?;
+
+                // Construct the transactions path.
+                let transactions = block.transactions();
+                let transactions_path = match transactions.to_path(transaction_id) {
+                    Ok(transactions_path) => transactions_path,
+                    Err(_) => {
+                        bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block")
+                    }
+                };
+
+                // Construct the transaction path and transaction leaf.
+                let transaction = match transactions.get(&transaction_id) {
+                    Some(transaction) => transaction,
+                    None => {
+                        bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block")
+                    }
+                };
+                let transaction_leaf = transaction.to_leaf(transition.id())?;
+                let transaction_path = transaction.to_path(&transaction_leaf)?;
+
+                Ok(StatePath::from(
+                    global_state_root.into(),
+                    block_path.clone(),
+                    block.hash(),
+                    block.previous_hash(),
+                    *header_root,
+                    header_path.clone(),
+                    *header_leaf,
+                    transactions_path,
+                    transaction.id(),
+                    transaction_path,
+                    transaction_leaf,
+                    transition_path,
+                    transition_leaf,
+                ))
+            })
+            .collect()
     }
 
     /// Returns the previous block hash of the given `block height`.
@@ -778,6 +866,23 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Removes the last 'n' block hashes from the block tree, without removing the blocks from storage.
+    /// This restores the block tree when an atomic batch that inserts the blocks is aborted.
+    pub(crate) fn rewind_tree(&self, n: u32) -> Result<()> {
+        // If there are no block hashes to remove, return early.
+        if n == 0 {
+            return Ok(());
+        }
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Prepare an updated Merkle tree removing the last 'n' block hashes.
+        let updated_tree = tree.prepare_remove_last_n(usize::try_from(n)?)?;
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -855,6 +960,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, or `None` if the block store is empty.
+    pub fn current_block_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -865,6 +975,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
+    /// Returns the state paths for the given `commitments`, in the same order.
+    pub fn get_state_paths_for_commitments(&self, commitments: &[Field<N>]) -> Result<Vec<StatePath<N>>> {
+        self.storage.get_state_paths_for_commitments(commitments, &self.tree.read())
+    }
+
     /// Returns the previous block hash of the given `block height`.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         self.storage.get_previous_block_hash(height)
@@ -930,6 +1045,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.reverse_state_root_map().contains_key_confirmed(state_root)
     }
 
+    /// Returns `true` if the given state root exists, including the state roots in a pending atomic batch.
+    pub fn contains_state_root_speculative(&self, state_root: &N::StateRoot) -> Result<bool> {
+        self.storage.reverse_state_root_map().contains_key_speculative(state_root)
+    }
+
     /// Returns `true` if the given block height exists.
     pub fn contains_block_height(&self, height: u32) -> Result<bool> {
         self.storage.id_map().contains_key_confirmed(&height)
@@ -1043,4 +1163,27 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_rejected_blob() {
+        let rng = &mut TestRng::default();
+
+        let unknown = RejectionMetadata::from_code(RejectionCode::Unknown);
+        let recorded = RejectionMetadata::from_code(RejectionCode::DeploymentFailed);
+
+        for rejected in [
+            crate::rejected::test_helpers::sample_rejected_deployment(rng),
+            crate::rejected::test_helpers::sample_rejected_execution(rng),
+        ] {
+            // Ensure the original layout is read, without the rejection metadata.
+            let blob = rejected.to_bytes_le().unwrap();
+            assert_eq!(to_rejected_blob(rejected.clone(), unknown.clone()).unwrap(), blob);
+            assert_eq!(from_rejected_blob(&blob).unwrap(), (rejected.clone(), unknown.clone()));
+
+            // Ensure the versioned layout is read, with the rejection metadata.
+            let blob = to_rejected_blob(rejected.clone(), recorded.clone()).unwrap();
+            assert_eq!(blob[0], REJECTED_BLOB_VERSION);
+            assert_eq!(from_rejected_blob(&blob).unwrap(), (rejected, recorded.clone()));
+        }
+    }
 }
@@ -16,7 +16,7 @@ use crate::store::{helpers::memory::MemoryMap, FinalizeStorage};
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
-    types::Field,
+    types::{Address, Field},
 };
 
 use indexmap::{IndexMap, IndexSet};
@@ -34,6 +34,17 @@ pub struct FinalizeMemory<N: Network> {
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
+    /// The admin map.
+    admin_map: MemoryMap<ProgramID<N>, Address<N>>,
+    /// The key order map.
+    key_order_map: MemoryMap<Field<N>, (Option<Field<N>>, Option<Field<N>>)>,
+    /// The key bounds map.
+    key_bounds_map: MemoryMap<Field<N>, (Field<N>, Field<N>)>,
+    /// The length map.
+    length_map: MemoryMap<Field<N>, u64>,
+    /// The history map.
+    #[cfg(feature = "history")]
+    history_map: MemoryMap<Field<N>, Vec<(u32, Option<Value<N>>)>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -45,6 +56,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type AdminMap = MemoryMap<ProgramID<N>, Address<N>>;
+    type KeyOrderMap = MemoryMap<Field<N>, (Option<Field<N>>, Option<Field<N>>)>;
+    type KeyBoundsMap = MemoryMap<Field<N>, (Field<N>, Field<N>)>;
+    type LengthMap = MemoryMap<Field<N>, u64>;
+    #[cfg(feature = "history")]
+    type HistoryMap = MemoryMap<Field<N>, Vec<(u32, Option<Value<N>>)>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -54,6 +71,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            admin_map: MemoryMap::default(),
+            key_order_map: MemoryMap::default(),
+            key_bounds_map: MemoryMap::default(),
+            length_map: MemoryMap::default(),
+            #[cfg(feature = "history")]
+            history_map: MemoryMap::default(),
             dev,
         })
     }
@@ -83,6 +106,32 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.value_map
     }
 
+    /// Returns the admin map.
+    fn admin_map(&self) -> &Self::AdminMap {
+        &self.admin_map
+    }
+
+    /// Returns the key order map.
+    fn key_order_map(&self) -> &Self::KeyOrderMap {
+        &self.key_order_map
+    }
+
+    /// Returns the key bounds map.
+    fn key_bounds_map(&self) -> &Self::KeyBoundsMap {
+        &self.key_bounds_map
+    }
+
+    /// Returns the length map.
+    fn length_map(&self) -> &Self::LengthMap {
+        &self.length_map
+    }
+
+    /// Returns the history map.
+    #[cfg(feature = "history")]
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
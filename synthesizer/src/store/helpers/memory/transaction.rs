@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    block::Sponsorship,
     process::Program,
     snark::{Certificate, Proof, VerifyingKey},
     store::{
@@ -184,7 +185,7 @@ pub struct ExecutionMemory<N: Network> {
     /// The reverse ID map.
     reverse_id_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
-    inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>, Option<u32>, Option<Sponsorship<N>>)>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
 }
@@ -193,7 +194,7 @@ pub struct ExecutionMemory<N: Network> {
 impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = MemoryMap<N::TransitionID, N::TransactionID>;
-    type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>, Option<u32>, Option<Sponsorship<N>>)>;
     type FeeStorage = FeeMemory<N>;
 
     /// Initializes the execution storage.
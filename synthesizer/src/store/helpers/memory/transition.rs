@@ -228,6 +228,8 @@ pub struct OutputMemory<N: Network> {
     record_nonce: MemoryMap<Group<N>, Field<N>>,
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     external_record: MemoryMap<Field<N>, ()>,
+    /// The mapping of `commitment` to `(optional) auditor record ciphertext`, for audited records.
+    auditor_record: MemoryMap<Field<N>, Option<Record<N, Ciphertext<N>>>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -242,6 +244,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
     type RecordMap = MemoryMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
     type RecordNonceMap = MemoryMap<Group<N>, Field<N>>;
     type ExternalRecordMap = MemoryMap<Field<N>, ()>;
+    type AuditorRecordMap = MemoryMap<Field<N>, Option<Record<N, Ciphertext<N>>>>;
 
     /// Initializes the transition output storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -254,6 +257,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
             record: Default::default(),
             record_nonce: Default::default(),
             external_record: Default::default(),
+            auditor_record: Default::default(),
             dev,
         })
     }
@@ -298,6 +302,11 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
         &self.external_record
     }
 
+    /// Returns the auditor record map.
+    fn auditor_record_map(&self) -> &Self::AuditorRecordMap {
+        &self.auditor_record
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 pub mod memory;
+// Note: The RocksDB storage is only available with the `rocks` feature,
+// so that the in-memory builds (e.g. `wasm`) do not depend on RocksDB.
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
 
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use rocksdb::{BlockBasedOptions, Cache, DBCompactionStyle, DBCompressionType};
+use std::path::PathBuf;
+
+/// A builder for the options of the RocksDB database, to tune it to the hardware of the operator.
+///
+/// Note: As all of the maps share the default column family, these options apply to every map.
+#[derive(Clone, Debug)]
+pub struct RocksDBBuilder {
+    /// The block cache size in bytes, or `None` for the RocksDB default.
+    block_cache_size: Option<usize>,
+    /// The compaction style.
+    compaction_style: DBCompactionStyle,
+    /// The compression type.
+    compression_type: DBCompressionType,
+    /// The compression type of each level, or `None` to use the compression type for every level.
+    compression_per_level: Option<Vec<DBCompressionType>>,
+    /// The maximum total size in bytes of the write-ahead log, or `None` for the RocksDB default.
+    max_total_wal_size: Option<u64>,
+    /// The directory of the write-ahead log, or `None` for the database directory.
+    wal_dir: Option<PathBuf>,
+    /// The number of background threads for flushes and compactions.
+    parallelism: i32,
+}
+
+impl Default for RocksDBBuilder {
+    /// Initializes the builder with the default options of the database.
+    fn default() -> Self {
+        Self {
+            block_cache_size: None,
+            compaction_style: DBCompactionStyle::Level,
+            compression_type: DBCompressionType::Lz4,
+            compression_per_level: None,
+            max_total_wal_size: None,
+            wal_dir: None,
+            parallelism: 2,
+        }
+    }
+}
+
+impl RocksDBBuilder {
+    /// Sets the block cache size in bytes.
+    pub fn block_cache_size(mut self, block_cache_size: usize) -> Self {
+        self.block_cache_size = Some(block_cache_size);
+        self
+    }
+
+    /// Sets the compaction style.
+    pub fn compaction_style(mut self, compaction_style: DBCompactionStyle) -> Self {
+        self.compaction_style = compaction_style;
+        self
+    }
+
+    /// Sets the compression type.
+    ///
+    /// Note: Only the `None` and `Lz4` compression types are compiled into the database.
+    pub fn compression_type(mut self, compression_type: DBCompressionType) -> Self {
+        self.compression_type = compression_type;
+        self
+    }
+
+    /// Sets the compression type of each level, starting from level 0.
+    pub fn compression_per_level(mut self, compression_per_level: Vec<DBCompressionType>) -> Self {
+        self.compression_per_level = Some(compression_per_level);
+        self
+    }
+
+    /// Sets the maximum total size in bytes of the write-ahead log.
+    pub fn max_total_wal_size(mut self, max_total_wal_size: u64) -> Self {
+        self.max_total_wal_size = Some(max_total_wal_size);
+        self
+    }
+
+    /// Sets the directory of the write-ahead log, for example, to place it on a separate disk.
+    pub fn wal_dir(mut self, wal_dir: impl Into<PathBuf>) -> Self {
+        self.wal_dir = Some(wal_dir.into());
+        self
+    }
+
+    /// Sets the number of background threads for flushes and compactions.
+    pub fn parallelism(mut self, parallelism: i32) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    /// Opens the database with the given `network_id` and `(optional) development ID`, using these options.
+    ///
+    /// As the database is only opened once per process, this must be called before any of the stores are opened,
+    /// after which the stores use the database opened here.
+    pub fn open(self, network_id: u16, dev: Option<u16>) -> Result<RocksDB> {
+        // Ensure the database is not yet open, as its options would not apply.
+        if DATABASE.get().is_some() {
+            bail!("The database is already open - its options must be set before the stores are opened");
+        }
+        RocksDB::open_with(&self, network_id, dev)
+    }
+
+    /// Returns the RocksDB options.
+    pub(super) fn options(&self) -> rocksdb::Options {
+        let mut options = rocksdb::Options::default();
+        options.set_compaction_style(self.compaction_style);
+        options.set_compression_type(self.compression_type);
+        if let Some(compression_per_level) = &self.compression_per_level {
+            options.set_compression_per_level(compression_per_level);
+        }
+
+        // Set the block cache.
+        if let Some(block_cache_size) = self.block_cache_size {
+            let mut block_options = BlockBasedOptions::default();
+            block_options.set_block_cache(&Cache::new_lru_cache(block_cache_size));
+            options.set_block_based_table_factory(&block_options);
+        }
+
+        // Set the write-ahead log options.
+        if let Some(max_total_wal_size) = self.max_total_wal_size {
+            options.set_max_total_wal_size(max_total_wal_size);
+        }
+        if let Some(wal_dir) = &self.wal_dir {
+            options.set_wal_dir(wal_dir);
+        }
+
+        // Register the prefix length.
+        let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
+        options.set_prefix_extractor(prefix_extractor);
+
+        options.increase_parallelism(self.parallelism);
+        options.create_if_missing(true);
+        options
+    }
+}
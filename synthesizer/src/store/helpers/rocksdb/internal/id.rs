@@ -133,6 +133,7 @@ pub enum TransitionOutputMap {
     Record = DataID::OutputRecordMap as u16,
     RecordNonce = DataID::OutputRecordNonceMap as u16,
     ExternalRecord = DataID::OutputExternalRecordMap as u16,
+    AuditorRecord = DataID::OutputAuditorRecordMap as u16,
 }
 
 /// The RocksDB map prefix for transaction-related entries.
@@ -169,6 +170,11 @@ pub enum ProgramMap {
     KeyValueID = DataID::KeyValueIDMap as u16,
     Key = DataID::KeyMap as u16,
     Value = DataID::ValueMap as u16,
+    History = DataID::HistoryMap as u16,
+    Admin = DataID::AdminMap as u16,
+    KeyOrder = DataID::KeyOrderMap as u16,
+    KeyBounds = DataID::KeyBoundsMap as u16,
+    Length = DataID::LengthMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -248,6 +254,14 @@ enum DataID {
     KeyValueIDMap,
     KeyMap,
     ValueMap,
+    HistoryMap,
+    // Output (continued)
+    OutputAuditorRecordMap,
+    // Program (continued)
+    AdminMap,
+    KeyOrderMap,
+    KeyBoundsMap,
+    LengthMap,
 
     // Testing
     #[cfg(test)]
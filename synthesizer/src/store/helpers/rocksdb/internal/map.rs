@@ -24,6 +24,9 @@ use std::{borrow::Cow, sync::atomic::Ordering};
 pub struct DataMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> {
     pub(super) database: RocksDB,
     pub(super) context: Vec<u8>,
+    /// The label of the map, for the metrics.
+    #[cfg(feature = "metrics")]
+    pub(super) label: String,
     /// The tracker for whether a database transaction is in progress.
     pub(super) batch_in_progress: Arc<AtomicBool>,
     /// The database transaction.
@@ -53,7 +56,14 @@ impl<
                 // Prepare the prefixed key and serialized value.
                 let raw_key = self.create_prefixed_key(&key)?;
                 let raw_value = bincode::serialize(&value)?;
+                #[cfg(feature = "metrics")]
+                let (start, num_bytes) = (std::time::Instant::now(), raw_key.len() + raw_value.len());
                 self.database.put(raw_key, raw_value)?;
+                #[cfg(feature = "metrics")]
+                {
+                    super::metrics::record_latency(&self.label, "write", start);
+                    super::metrics::record_written_bytes(&self.label, num_bytes);
+                }
             }
         }
 
@@ -74,7 +84,11 @@ impl<
             false => {
                 // Prepare the prefixed key.
                 let raw_key = self.create_prefixed_key(key)?;
+                #[cfg(feature = "metrics")]
+                let start = std::time::Instant::now();
                 self.database.delete(raw_key)?;
+                #[cfg(feature = "metrics")]
+                super::metrics::record_latency(&self.label, "write", start);
             }
         }
 
@@ -182,8 +196,18 @@ impl<
                 };
             }
 
+            #[cfg(feature = "metrics")]
+            let (start, num_bytes) = (std::time::Instant::now(), batch.size_in_bytes());
+
             // Execute all the operations atomically.
             self.database.rocksdb.write(batch)?;
+
+            #[cfg(feature = "metrics")]
+            {
+                super::metrics::record_latency(&self.label, "batch_write", start);
+                super::metrics::record_written_bytes(&self.label, num_bytes);
+                self.database.record_metrics();
+            }
         }
 
         // Clear the checkpoint stack.
@@ -327,10 +351,12 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         Q: Serialize + ?Sized,
     {
         let raw_key = self.create_prefixed_key(key)?;
-        match self.database.get_pinned(&raw_key)? {
-            Some(data) => Ok(Some(data)),
-            None => Ok(None),
-        }
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+        let data = self.database.get_pinned(&raw_key)?;
+        #[cfg(feature = "metrics")]
+        super::metrics::record_latency(&self.label, "read", start);
+        Ok(data)
     }
 }
 
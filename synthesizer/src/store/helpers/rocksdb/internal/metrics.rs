@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use rocksdb::properties;
+use std::time::Instant;
+
+/// The histogram of the latency in seconds of a storage operation, labeled by `map` and `operation`.
+pub const LATENCY: &str = "snarkvm_storage_latency_seconds";
+/// The counter of the bytes written to storage, labeled by `map`.
+pub const WRITTEN_BYTES: &str = "snarkvm_storage_written_bytes_total";
+
+/// The gauges of the database, and their RocksDB properties.
+/// Note: As all of the maps share the default column family, these gauges cover the entire database.
+const DATABASE_GAUGES: [(&str, &std::ffi::CStr); 6] = [
+    ("snarkvm_storage_live_data_bytes", properties::ESTIMATE_LIVE_DATA_SIZE),
+    ("snarkvm_storage_sst_files_bytes", properties::TOTAL_SST_FILES_SIZE),
+    ("snarkvm_storage_num_keys", properties::ESTIMATE_NUM_KEYS),
+    ("snarkvm_storage_running_compactions", properties::NUM_RUNNING_COMPACTIONS),
+    ("snarkvm_storage_pending_compaction_bytes", properties::ESTIMATE_PENDING_COMPACTION_BYTES),
+    ("snarkvm_storage_running_flushes", properties::NUM_RUNNING_FLUSHES),
+];
+
+/// Records the latency of the given storage `operation` on the given `map`, which started at `start`.
+pub(super) fn record_latency(map: &str, operation: &'static str, start: Instant) {
+    ::metrics::histogram!(LATENCY, start.elapsed().as_secs_f64(), "map" => map.to_string(), "operation" => operation);
+}
+
+/// Records the given number of bytes written to the given `map`.
+pub(super) fn record_written_bytes(map: &str, num_bytes: usize) {
+    ::metrics::counter!(WRITTEN_BYTES, num_bytes as u64, "map" => map.to_string());
+}
+
+impl RocksDB {
+    /// Records the size and compaction gauges of the database.
+    ///
+    /// Note: The properties that cannot be read are skipped, as the metrics must never fail a storage operation.
+    pub fn record_metrics(&self) {
+        for (name, property) in DATABASE_GAUGES {
+            if let Ok(Some(value)) = self.rocksdb.property_int_value(property) {
+                ::metrics::gauge!(name, value as f64);
+            }
+        }
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod builder;
+pub use builder::*;
+
 pub mod id;
 pub use id::*;
 
@@ -21,6 +24,9 @@ use iterator::*;
 pub mod map;
 pub use map::*;
 
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
 #[cfg(test)]
 mod tests;
 
@@ -38,6 +44,9 @@ use std::{
 
 pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
 
+/// The database, which is opened once per process.
+static DATABASE: OnceCell<RocksDB> = OnceCell::new();
+
 pub trait Database {
     /// Opens the database.
     fn open(network_id: u16, dev: Option<u16>) -> Result<Self>
@@ -45,7 +54,7 @@ pub trait Database {
         Self: Sized;
 
     /// Opens the map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
-    fn open_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+    fn open_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16> + Debug>(
         network_id: u16,
         dev: Option<u16>,
         map_id: T,
@@ -76,41 +85,14 @@ impl Database for RocksDB {
     ///
     /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
     /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
+    ///
+    /// If the database is not yet open, it is opened with the default options of `RocksDBBuilder`.
     fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
-        static DB: OnceCell<RocksDB> = OnceCell::new();
-
-        // Retrieve the database.
-        let database = DB
-            .get_or_try_init(|| {
-                // Customize database options.
-                let mut options = rocksdb::Options::default();
-                options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-                // Register the prefix length.
-                let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-                options.set_prefix_extractor(prefix_extractor);
-
-                let primary = aleo_std::aleo_ledger_dir(network_id, dev);
-                let rocksdb = {
-                    options.increase_parallelism(2);
-                    options.create_if_missing(true);
-
-                    Arc::new(rocksdb::DB::open(&options, primary)?)
-                };
-
-                Ok::<_, anyhow::Error>(RocksDB { rocksdb, network_id, dev })
-            })?
-            .clone();
-
-        // Ensure the database network ID and development ID match.
-        match database.network_id == network_id && database.dev == dev {
-            true => Ok(database),
-            false => bail!("Mismatching network ID or development ID in the database"),
-        }
+        Self::open_with(&RocksDBBuilder::default(), network_id, dev)
     }
 
     /// Opens the map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
-    fn open_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+    fn open_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16> + Debug>(
         network_id: u16,
         dev: Option<u16>,
         map_id: T,
@@ -118,6 +100,10 @@ impl Database for RocksDB {
         // Open the RocksDB database.
         let database = Self::open(network_id, dev)?;
 
+        // Construct the label of the map, for the metrics.
+        #[cfg(feature = "metrics")]
+        let label = format!("{map_id:?}");
+
         // Combine contexts to create a new scope.
         let mut context = database.network_id.to_le_bytes().to_vec();
         context.extend_from_slice(&(map_id.into()).to_le_bytes());
@@ -126,6 +112,8 @@ impl Database for RocksDB {
         Ok(DataMap {
             database,
             context,
+            #[cfg(feature = "metrics")]
+            label,
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
@@ -134,29 +122,36 @@ impl Database for RocksDB {
 }
 
 impl RocksDB {
+    /// Opens the database with the given options, if it is not yet open.
+    fn open_with(builder: &RocksDBBuilder, network_id: u16, dev: Option<u16>) -> Result<Self> {
+        // Retrieve the database.
+        let database = DATABASE
+            .get_or_try_init(|| {
+                let primary = aleo_std::aleo_ledger_dir(network_id, dev);
+                let rocksdb = Arc::new(rocksdb::DB::open(&builder.options(), primary)?);
+
+                Ok::<_, anyhow::Error>(RocksDB { rocksdb, network_id, dev })
+            })?
+            .clone();
+
+        // Ensure the database network ID and development ID match.
+        match database.network_id == network_id && database.dev == dev {
+            true => Ok(database),
+            false => bail!("Mismatching network ID or development ID in the database"),
+        }
+    }
+
     /// Opens the test database.
     #[cfg(test)]
     fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
         let database = {
-            // Customize database options.
-            let mut options = rocksdb::Options::default();
-            options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-            // Register the prefix length.
-            let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-            options.set_prefix_extractor(prefix_extractor);
-
             // Construct the directory for the test database.
             let primary = match dev {
                 Some(dev) => temp_dir.join(dev.to_string()),
                 None => temp_dir,
             };
 
-            let rocksdb = {
-                options.increase_parallelism(2);
-                options.create_if_missing(true);
-                Arc::new(rocksdb::DB::open(&options, primary)?)
-            };
+            let rocksdb = Arc::new(rocksdb::DB::open(&RocksDBBuilder::default().options(), primary)?);
 
             Ok::<_, anyhow::Error>(RocksDB { rocksdb, network_id: u16::MAX, dev })
         }?;
@@ -170,7 +165,7 @@ impl RocksDB {
 
     /// Opens the test map.
     #[cfg(test)]
-    fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+    fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16> + Debug>(
         temp_dir: std::path::PathBuf,
         dev: Option<u16>,
         map_id: T,
@@ -178,6 +173,10 @@ impl RocksDB {
         // Open the RocksDB test database.
         let database = Self::open_testing(temp_dir, dev)?;
 
+        // Construct the label of the map, for the metrics.
+        #[cfg(feature = "metrics")]
+        let label = format!("{map_id:?}");
+
         // Combine contexts to create a new scope.
         let mut context = database.network_id.to_le_bytes().to_vec();
         context.extend_from_slice(&(map_id.into()).to_le_bytes());
@@ -186,6 +185,8 @@ impl RocksDB {
         Ok(DataMap {
             database,
             context,
+            #[cfg(feature = "metrics")]
+            label,
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::store::helpers::{
-    rocksdb::{DataMap, MapID, RocksDB, TestMap as TestMapID},
+    rocksdb::{DataMap, MapID, RocksDB, RocksDBBuilder, TestMap as TestMapID},
     Map,
     MapRead,
 };
@@ -42,6 +42,24 @@ fn test_open() {
     let _storage = RocksDB::open_testing(temp_dir(), None).expect("Failed to open storage");
 }
 
+#[test]
+#[serial]
+fn test_open_with_options() {
+    // Configure the options of the database.
+    let builder = RocksDBBuilder::default()
+        .block_cache_size(8 * 1024 * 1024)
+        .compaction_style(rocksdb::DBCompactionStyle::Universal)
+        .compression_type(rocksdb::DBCompressionType::Lz4)
+        .compression_per_level(vec![rocksdb::DBCompressionType::None, rocksdb::DBCompressionType::Lz4])
+        .max_total_wal_size(16 * 1024 * 1024)
+        .parallelism(4);
+
+    // Ensure the database opens with the options.
+    let database = rocksdb::DB::open(&builder.options(), temp_dir()).expect("Failed to open storage");
+    database.put(b"key", b"value").expect("Failed to insert");
+    assert_eq!(Some(b"value".to_vec()), database.get(b"key").expect("Failed to get"));
+}
+
 #[test]
 #[serial]
 fn test_open_map() {
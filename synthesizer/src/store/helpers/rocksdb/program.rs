@@ -19,7 +19,7 @@ use crate::store::{
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
-    types::Field,
+    types::{Address, Field},
 };
 
 use indexmap::{IndexMap, IndexSet};
@@ -37,6 +37,17 @@ pub struct FinalizeDB<N: Network> {
     key_map: DataMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: DataMap<Field<N>, Value<N>>,
+    /// The admin map.
+    admin_map: DataMap<ProgramID<N>, Address<N>>,
+    /// The key order map.
+    key_order_map: DataMap<Field<N>, (Option<Field<N>>, Option<Field<N>>)>,
+    /// The key bounds map.
+    key_bounds_map: DataMap<Field<N>, (Field<N>, Field<N>)>,
+    /// The length map.
+    length_map: DataMap<Field<N>, u64>,
+    /// The history map.
+    #[cfg(feature = "history")]
+    history_map: DataMap<Field<N>, Vec<(u32, Option<Value<N>>)>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -48,6 +59,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type KeyValueIDMap = DataMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = DataMap<Field<N>, Plaintext<N>>;
     type ValueMap = DataMap<Field<N>, Value<N>>;
+    type AdminMap = DataMap<ProgramID<N>, Address<N>>;
+    type KeyOrderMap = DataMap<Field<N>, (Option<Field<N>>, Option<Field<N>>)>;
+    type KeyBoundsMap = DataMap<Field<N>, (Field<N>, Field<N>)>;
+    type LengthMap = DataMap<Field<N>, u64>;
+    #[cfg(feature = "history")]
+    type HistoryMap = DataMap<Field<N>, Vec<(u32, Option<Value<N>>)>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -57,6 +74,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             key_value_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
             key_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Key))?,
             value_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Value))?,
+            admin_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Admin))?,
+            key_order_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyOrder))?,
+            key_bounds_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyBounds))?,
+            length_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Length))?,
+            #[cfg(feature = "history")]
+            history_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::History))?,
             dev,
         })
     }
@@ -86,6 +109,32 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.value_map
     }
 
+    /// Returns the admin map.
+    fn admin_map(&self) -> &Self::AdminMap {
+        &self.admin_map
+    }
+
+    /// Returns the key order map.
+    fn key_order_map(&self) -> &Self::KeyOrderMap {
+        &self.key_order_map
+    }
+
+    /// Returns the key bounds map.
+    fn key_bounds_map(&self) -> &Self::KeyBoundsMap {
+        &self.key_bounds_map
+    }
+
+    /// Returns the length map.
+    fn length_map(&self) -> &Self::LengthMap {
+        &self.length_map
+    }
+
+    /// Returns the history map.
+    #[cfg(feature = "history")]
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    block::Sponsorship,
     process::Program,
     snark::{Certificate, Proof, VerifyingKey},
     store::{
@@ -196,7 +197,7 @@ pub struct ExecutionDB<N: Network> {
     /// The reverse ID map.
     reverse_id_map: DataMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
-    inclusion_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    inclusion_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>, Option<u32>, Option<Sponsorship<N>>)>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
 }
@@ -205,7 +206,7 @@ pub struct ExecutionDB<N: Network> {
 impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
     type IDMap = DataMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = DataMap<N::TransitionID, N::TransactionID>;
-    type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>, Option<u32>, Option<Sponsorship<N>>)>;
     type FeeStorage = FeeDB<N>;
 
     /// Initializes the execution storage.
@@ -228,6 +228,8 @@ pub struct OutputDB<N: Network> {
     record_nonce: DataMap<Group<N>, Field<N>>,
     /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
     external_record: DataMap<Field<N>, ()>,
+    /// The mapping of `commitment` to `(optional) auditor record ciphertext`, for audited records.
+    auditor_record: DataMap<Field<N>, Option<Record<N, Ciphertext<N>>>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -242,6 +244,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
     type RecordMap = DataMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
     type RecordNonceMap = DataMap<Group<N>, Field<N>>;
     type ExternalRecordMap = DataMap<Field<N>, ()>;
+    type AuditorRecordMap = DataMap<Field<N>, Option<Record<N, Ciphertext<N>>>>;
 
     /// Initializes the transition output storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -254,6 +257,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
             record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::Record))?,
             record_nonce: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::RecordNonce))?,
             external_record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::ExternalRecord))?,
+            auditor_record: rocksdb::RocksDB::open_map(N::ID, dev, MapID::TransitionOutput(TransitionOutputMap::AuditorRecord))?,
             dev,
         })
     }
@@ -298,6 +302,11 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
         &self.external_record
     }
 
+    /// Returns the auditor record map.
+    fn auditor_record_map(&self) -> &Self::AuditorRecordMap {
+        &self.auditor_record
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -17,17 +17,24 @@ use crate::{
     block::FinalizeOperation,
     cow_to_cloned,
     cow_to_copied,
-    store::helpers::{Map, MapRead},
+    store::{
+        helpers::{Map, MapRead},
+        FinalizeDump,
+    },
 };
 use console::{
     network::prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
-    types::Field,
+    types::{Address, Field},
 };
 
 use anyhow::Result;
 use core::marker::PhantomData;
 use indexmap::{IndexMap, IndexSet};
+#[cfg(feature = "history")]
+use parking_lot::Mutex;
+#[cfg(feature = "history")]
+use std::sync::Arc;
 
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
@@ -51,6 +58,17 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `program ID` to `admin address`.
+    type AdminMap: for<'a> Map<'a, ProgramID<N>, Address<N>>;
+    /// The mapping of `key ID` to `(previous key ID, next key ID)`, in the insertion order of the mapping.
+    type KeyOrderMap: for<'a> Map<'a, Field<N>, (Option<Field<N>>, Option<Field<N>>)>;
+    /// The mapping of `mapping ID` to `(first key ID, last key ID)`, for each mapping with keys.
+    type KeyBoundsMap: for<'a> Map<'a, Field<N>, (Field<N>, Field<N>)>;
+    /// The mapping of `mapping ID` to the number of key-value pairs in the mapping.
+    type LengthMap: for<'a> Map<'a, Field<N>, u64>;
+    /// The mapping of `key ID` to `[(block height, value)]`, where the value is `None` if the key was removed.
+    #[cfg(feature = "history")]
+    type HistoryMap: for<'a> Map<'a, Field<N>, Vec<(u32, Option<Value<N>>)>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -65,6 +83,17 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the admin map.
+    fn admin_map(&self) -> &Self::AdminMap;
+    /// Returns the key order map.
+    fn key_order_map(&self) -> &Self::KeyOrderMap;
+    /// Returns the key bounds map.
+    fn key_bounds_map(&self) -> &Self::KeyBoundsMap;
+    /// Returns the length map.
+    fn length_map(&self) -> &Self::LengthMap;
+    /// Returns the history map.
+    #[cfg(feature = "history")]
+    fn history_map(&self) -> &Self::HistoryMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -76,6 +105,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.admin_map().start_atomic();
+        self.key_order_map().start_atomic();
+        self.key_bounds_map().start_atomic();
+        self.length_map().start_atomic();
+        #[cfg(feature = "history")]
+        self.history_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -85,6 +120,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.admin_map().is_atomic_in_progress()
+            || self.key_order_map().is_atomic_in_progress()
+            || self.key_bounds_map().is_atomic_in_progress()
+            || self.length_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -94,6 +133,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_checkpoint();
         self.key_map().atomic_checkpoint();
         self.value_map().atomic_checkpoint();
+        self.admin_map().atomic_checkpoint();
+        self.key_order_map().atomic_checkpoint();
+        self.key_bounds_map().atomic_checkpoint();
+        self.length_map().atomic_checkpoint();
+        #[cfg(feature = "history")]
+        self.history_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -103,6 +148,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().clear_latest_checkpoint();
         self.key_map().clear_latest_checkpoint();
         self.value_map().clear_latest_checkpoint();
+        self.admin_map().clear_latest_checkpoint();
+        self.key_order_map().clear_latest_checkpoint();
+        self.key_bounds_map().clear_latest_checkpoint();
+        self.length_map().clear_latest_checkpoint();
+        #[cfg(feature = "history")]
+        self.history_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -112,6 +163,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_rewind();
         self.key_map().atomic_rewind();
         self.value_map().atomic_rewind();
+        self.admin_map().atomic_rewind();
+        self.key_order_map().atomic_rewind();
+        self.key_bounds_map().atomic_rewind();
+        self.length_map().atomic_rewind();
+        #[cfg(feature = "history")]
+        self.history_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -121,6 +178,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.admin_map().abort_atomic();
+        self.key_order_map().abort_atomic();
+        self.key_bounds_map().abort_atomic();
+        self.length_map().abort_atomic();
+        #[cfg(feature = "history")]
+        self.history_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -129,7 +192,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.admin_map().finish_atomic()?;
+        self.key_order_map().finish_atomic()?;
+        self.key_bounds_map().finish_atomic()?;
+        self.length_map().finish_atomic()?;
+        #[cfg(feature = "history")]
+        self.history_map().finish_atomic()?;
+        Ok(())
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -174,6 +244,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.mapping_id_map().insert((*program_id, *mapping_name), mapping_id)?;
             // Initialize the key-value ID map.
             self.key_value_id_map().insert(mapping_id, IndexMap::new())?;
+            // Initialize the length of the mapping.
+            self.length_map().insert(mapping_id, 0)?;
 
             Ok(())
         })?;
@@ -225,6 +297,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.key_map().insert(key_id, key)?;
             // Insert the value.
             self.value_map().insert(key_id, value)?;
+            // Append the key ID to the key order.
+            self.append_key_id(mapping_id, key_id)?;
+            // Increment the length of the mapping.
+            self.add_to_mapping_length(mapping_id, 1)?;
 
             Ok(())
         })?;
@@ -233,6 +309,72 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(FinalizeOperation::InsertKeyValue(mapping_id, key_id, value_id))
     }
 
+    /// Stores the given `(key, value)` pairs at the given `program ID` and `mapping name` in storage.
+    /// If the `mapping name` is not initialized, an error is returned.
+    /// If any `key` already exists, the method returns an error, and no pair is stored.
+    ///
+    /// Note: The key-value IDs of the mapping are loaded and written once for all pairs,
+    /// whereas calling `insert_key_value` for each pair rewrites them for every pair.
+    fn insert_key_values(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        entries: Vec<(Plaintext<N>, Value<N>)>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id_speculative(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot insert key-values."),
+        };
+        // Retrieve the key-value IDs for the mapping ID.
+        let mut key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
+            Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+            None => {
+                bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot insert key-values.")
+            }
+        };
+
+        // Compute the key ID and value ID of each pair.
+        let mut key_ids = Vec::with_capacity(entries.len());
+        for (key, value) in &entries {
+            // Compute the key ID.
+            let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+            // Compute the value ID.
+            let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+
+            // Ensure the key ID does not already exist.
+            if self.key_map().contains_key_speculative(&key_id)? {
+                bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert again.")
+            }
+            // Insert the new key-value ID, ensuring the key ID is not repeated in the given pairs.
+            if key_value_ids.insert(key_id, value_id).is_some() {
+                bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert key-values.");
+            }
+            key_ids.push((key_id, value_id));
+        }
+
+        atomic_batch_scope!(self, {
+            // Update the key-value ID map with the new key-value IDs.
+            self.key_value_id_map().insert(mapping_id, key_value_ids)?;
+            // Insert the keys and values.
+            for ((key, value), (key_id, _)) in entries.into_iter().zip(&key_ids) {
+                self.key_map().insert(*key_id, key)?;
+                self.value_map().insert(*key_id, value)?;
+                self.append_key_id(mapping_id, *key_id)?;
+            }
+            // Increment the length of the mapping.
+            self.add_to_mapping_length(mapping_id, u64::try_from(key_ids.len())?)?;
+
+            Ok(())
+        })?;
+
+        // Return the finalize operations.
+        Ok(key_ids
+            .into_iter()
+            .map(|(key_id, value_id)| FinalizeOperation::InsertKeyValue(mapping_id, key_id, value_id))
+            .collect())
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` does not exist, the `(key, value)` pair is initialized.
@@ -261,8 +403,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                 bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot update key-value.")
             }
         };
+        // Determine if the key ID is new.
+        let is_new_key = !self.key_map().contains_key_speculative(&key_id)?;
         // If the key ID does not exist, insert it in the key-value ID map.
-        if !self.key_map().contains_key_speculative(&key_id)? {
+        if is_new_key {
             // Ensure the key ID does not already exist.
             // If this fails, then there is inconsistent state, and likely data corruption.
             if key_value_ids.contains_key(&key_id) {
@@ -285,6 +429,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.key_map().insert(key_id, key)?;
             // Insert the value.
             self.value_map().insert(key_id, value)?;
+            // Append the key ID to the key order, and increment the length of the mapping, if it is new.
+            if is_new_key {
+                self.append_key_id(mapping_id, key_id)?;
+                self.add_to_mapping_length(mapping_id, 1)?;
+            }
 
             Ok(())
         })?;
@@ -333,6 +482,13 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.key_map().remove(&key_id)?;
             // Remove the value.
             self.value_map().remove(&key_id)?;
+            // Remove the key ID from the key order.
+            self.unlink_key_id(mapping_id, key_id)?;
+            // Decrement the length of the mapping.
+            match self.get_length_speculative(&mapping_id)?.checked_sub(1) {
+                Some(length) => self.length_map().insert(mapping_id, length)?,
+                None => bail!("Illegal operation: mapping ID '{mapping_id}' has no length left - cannot remove."),
+            }
 
             Ok(())
         })?;
@@ -341,6 +497,40 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(FinalizeOperation::RemoveKeyValue(mapping_id, index))
     }
 
+    /// Removes all key-value pairs for the given `program ID` and `mapping name` from storage,
+    /// while keeping the mapping initialized.
+    fn clear_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<FinalizeOperation<N>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id_speculative(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot clear mapping."),
+        };
+        // Retrieve the key-value IDs for the mapping ID.
+        let key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
+            Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+            None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot clear mapping."),
+        };
+
+        atomic_batch_scope!(self, {
+            // Reset the key IDs.
+            self.key_value_id_map().insert(mapping_id, IndexMap::new())?;
+            // Reset the key order and the length.
+            self.key_bounds_map().remove(&mapping_id)?;
+            self.length_map().insert(mapping_id, 0)?;
+            // Remove the keys.
+            for key_id in key_value_ids.keys() {
+                self.key_map().remove(key_id)?;
+                self.value_map().remove(key_id)?;
+                self.key_order_map().remove(key_id)?;
+            }
+
+            Ok(())
+        })?;
+
+        // Return the finalize operation.
+        Ok(FinalizeOperation::ReplaceMapping(mapping_id))
+    }
+
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
     /// along with all associated key-value pairs in storage.
     fn remove_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<FinalizeOperation<N>> {
@@ -374,10 +564,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.mapping_id_map().remove(&(*program_id, *mapping_name))?;
             // Remove the key IDs.
             self.key_value_id_map().remove(&mapping_id)?;
+            // Remove the key order and the length.
+            self.key_bounds_map().remove(&mapping_id)?;
+            self.length_map().remove(&mapping_id)?;
             // Remove the keys.
             for key_id in key_value_ids.keys() {
                 self.key_map().remove(key_id)?;
                 self.value_map().remove(key_id)?;
+                self.key_order_map().remove(key_id)?;
             }
 
             Ok(())
@@ -399,6 +593,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the mapping names.
             self.program_id_map().remove(program_id)?;
+            // Remove the admin address.
+            self.admin_map().remove(program_id)?;
 
             // Remove each mapping.
             for mapping_name in mapping_names.iter() {
@@ -423,10 +619,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.mapping_id_map().remove(&(*program_id, *mapping_name))?;
                 // Remove the key IDs.
                 self.key_value_id_map().remove(&mapping_id)?;
+                // Remove the key order and the length.
+                self.key_bounds_map().remove(&mapping_id)?;
+                self.length_map().remove(&mapping_id)?;
                 // Remove the keys.
                 for key_id in key_value_ids.keys() {
                     self.key_map().remove(key_id)?;
                     self.value_map().remove(key_id)?;
+                    self.key_order_map().remove(key_id)?;
                 }
             }
 
@@ -434,6 +634,79 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Appends the given `key ID` to the key order of the given `mapping ID`.
+    /// This method must be called in an atomic batch, when the `key ID` is inserted in the mapping.
+    fn append_key_id(&self, mapping_id: Field<N>, key_id: Field<N>) -> Result<()> {
+        match self.key_bounds_map().get_speculative(&mapping_id)? {
+            // If the mapping has keys, link the key ID after the last key ID.
+            Some(bounds) => {
+                let (first_key_id, last_key_id) = cow_to_copied!(bounds);
+                let (previous_key_id, _) = self.get_key_links_speculative(&last_key_id)?;
+                self.key_order_map().insert(last_key_id, (previous_key_id, Some(key_id)))?;
+                self.key_order_map().insert(key_id, (Some(last_key_id), None))?;
+                self.key_bounds_map().insert(mapping_id, (first_key_id, key_id))
+            }
+            // If the mapping has no keys, the key ID is both the first and last key ID.
+            None => {
+                self.key_order_map().insert(key_id, (None, None))?;
+                self.key_bounds_map().insert(mapping_id, (key_id, key_id))
+            }
+        }
+    }
+
+    /// Removes the given `key ID` from the key order of the given `mapping ID`.
+    /// This method must be called in an atomic batch, when the `key ID` is removed from the mapping.
+    fn unlink_key_id(&self, mapping_id: Field<N>, key_id: Field<N>) -> Result<()> {
+        // Retrieve the neighbors of the key ID.
+        let (previous, next) = self.get_key_links_speculative(&key_id)?;
+        // Link the previous key ID to the next key ID.
+        if let Some(previous_key_id) = previous {
+            let (before, _) = self.get_key_links_speculative(&previous_key_id)?;
+            self.key_order_map().insert(previous_key_id, (before, next))?;
+        }
+        // Link the next key ID to the previous key ID.
+        if let Some(next_key_id) = next {
+            let (_, after) = self.get_key_links_speculative(&next_key_id)?;
+            self.key_order_map().insert(next_key_id, (previous, after))?;
+        }
+        // Remove the key ID.
+        self.key_order_map().remove(&key_id)?;
+
+        // Retrieve the first and last key IDs of the mapping.
+        let (first_key_id, last_key_id) = match self.key_bounds_map().get_speculative(&mapping_id)? {
+            Some(bounds) => cow_to_copied!(bounds),
+            None => bail!("Illegal operation: mapping ID '{mapping_id}' has no key order - cannot unlink key ID."),
+        };
+        // Update the first and last key IDs, removing them if the mapping has no keys left.
+        let first_key_id = if first_key_id == key_id { next } else { Some(first_key_id) };
+        let last_key_id = if last_key_id == key_id { previous } else { Some(last_key_id) };
+        match (first_key_id, last_key_id) {
+            (Some(first_key_id), Some(last_key_id)) => {
+                self.key_bounds_map().insert(mapping_id, (first_key_id, last_key_id))
+            }
+            _ => self.key_bounds_map().remove(&mapping_id),
+        }
+    }
+
+    /// Adds the given `amount` to the length of the given `mapping ID`.
+    /// This method must be called in an atomic batch, when key-value pairs are inserted in the mapping.
+    fn add_to_mapping_length(&self, mapping_id: Field<N>, amount: u64) -> Result<()> {
+        match self.get_length_speculative(&mapping_id)?.checked_add(amount) {
+            Some(length) => self.length_map().insert(mapping_id, length),
+            None => bail!("Illegal operation: the length of mapping ID '{mapping_id}' overflowed - cannot insert."),
+        }
+    }
+
+    /// Registers the given `admin` address for the given `program ID` in storage.
+    fn register_admin(&self, program_id: &ProgramID<N>, admin: Address<N>) -> Result<FinalizeOperation<N>> {
+        // Compute the program ID, as a field element.
+        let program_id_field = N::hash_bhp1024(&program_id.to_bits_le())?;
+        // Register the admin.
+        self.admin_map().insert(*program_id, admin)?;
+        // Return the finalize operation.
+        Ok(FinalizeOperation::RegisterAdmin(program_id_field, admin.to_field()?))
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -564,6 +837,22 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the speculative `(previous key ID, next key ID)` in the key order, for the given `key ID`.
+    fn get_key_links_speculative(&self, key_id: &Field<N>) -> Result<(Option<Field<N>>, Option<Field<N>>)> {
+        match self.key_order_map().get_speculative(key_id)? {
+            Some(links) => Ok(cow_to_copied!(links)),
+            None => bail!("Missing key ID '{key_id}' in the key order"),
+        }
+    }
+
+    /// Returns the speculative number of key-value pairs for the given `mapping ID`.
+    fn get_length_speculative(&self, mapping_id: &Field<N>) -> Result<u64> {
+        match self.length_map().get_speculative(mapping_id)? {
+            Some(length) => Ok(cow_to_copied!(length)),
+            None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - missing length."),
+        }
+    }
+
     /// Returns the speculative key for the given `key ID`.
     fn get_key_speculative(&self, key_id: &Field<N>) -> Result<Option<Plaintext<N>>> {
         match self.key_map().get_speculative(key_id)? {
@@ -617,6 +906,177 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Ok(None),
         }
     }
+
+    /// Returns the speculative number of key-value pairs for the given `program ID` and `mapping name`.
+    /// If the mapping is not initialized, `None` is returned.
+    fn get_mapping_length_speculative(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<usize>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id_speculative(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Retrieve the length of the mapping.
+        // Note: The length is updated on every insertion and removal, so the mapping is never loaded.
+        match self.length_map().get_speculative(&mapping_id)? {
+            Some(length) => Ok(Some(usize::try_from(cow_to_copied!(length))?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the confirmed admin address for the given `program ID`.
+    fn get_admin_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<Address<N>>> {
+        match self.admin_map().get_confirmed(program_id)? {
+            Some(admin) => Ok(Some(cow_to_copied!(admin))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the speculative admin address for the given `program ID`.
+    fn get_admin_speculative(&self, program_id: &ProgramID<N>) -> Result<Option<Address<N>>> {
+        match self.admin_map().get_speculative(program_id)? {
+            Some(admin) => Ok(Some(cow_to_copied!(admin))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, in insertion order.
+    /// The keys start after the given `cursor` key, or at the first key if the `cursor` is not in the mapping.
+    /// If the mapping is not initialized, `None` is returned.
+    ///
+    /// Note: The keys are read by following the key order from the cursor, so the mapping is never loaded as a whole.
+    fn get_keys_speculative(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        cursor: &Plaintext<N>,
+        limit: usize,
+    ) -> Result<Option<Vec<Plaintext<N>>>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id_speculative(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Compute the key ID of the cursor.
+        let cursor_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&cursor.to_bits_le())?).to_bits_le())?;
+        // Retrieve the key ID after the cursor, or the first key ID if the cursor is not in the mapping.
+        let mut next = match self.key_order_map().get_speculative(&cursor_id)? {
+            Some(links) => cow_to_copied!(links).1,
+            None => self.key_bounds_map().get_speculative(&mapping_id)?.map(|bounds| cow_to_copied!(bounds).0),
+        };
+
+        // Retrieve the keys, in the key order.
+        let mut keys = Vec::new();
+        while let Some(key_id) = next.filter(|_| keys.len() < limit) {
+            match self.get_key_speculative(&key_id)? {
+                Some(key) => keys.push(key),
+                None => bail!("Missing key '{key_id}' in storage"),
+            }
+            next = self.get_key_links_speculative(&key_id)?.1;
+        }
+        Ok(Some(keys))
+    }
+
+    /// Returns a dump of the confirmed admin, mappings, and key-value pairs for the given `program ID`.
+    /// If the `program ID` is not initialized, an error is returned.
+    fn export_program(&self, program_id: &ProgramID<N>) -> Result<FinalizeDump<N>> {
+        // Retrieve the mapping names.
+        let mapping_names = match self.get_mapping_names_confirmed(program_id)? {
+            Some(mapping_names) => mapping_names,
+            None => bail!("Illegal operation: program ID '{program_id}' is not initialized - cannot export."),
+        };
+
+        let mut mappings = IndexMap::with_capacity(mapping_names.len());
+        for mapping_name in mapping_names {
+            // Retrieve the mapping ID.
+            let mapping_id = match self.get_mapping_id_confirmed(program_id, &mapping_name)? {
+                Some(mapping_id) => mapping_id,
+                None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot export."),
+            };
+            // Retrieve the key-value IDs for the mapping ID.
+            let key_value_ids = match self.key_value_id_map().get_confirmed(&mapping_id)? {
+                Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+                None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot export."),
+            };
+            // Retrieve the key-value pairs.
+            let entries = key_value_ids
+                .keys()
+                .map(|key_id| {
+                    let key = match self.key_map().get_confirmed(key_id)? {
+                        Some(key) => cow_to_cloned!(key),
+                        None => bail!("Missing key '{key_id}' in storage - cannot export."),
+                    };
+                    let value = match self.value_map().get_confirmed(key_id)? {
+                        Some(value) => cow_to_cloned!(value),
+                        None => bail!("Missing value for key '{key_id}' in storage - cannot export."),
+                    };
+                    Ok((key, value))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            mappings.insert(mapping_name, entries);
+        }
+
+        // Return the dump, with the program admin.
+        FinalizeDump::new(*program_id, self.get_admin_confirmed(program_id)?, mappings)
+    }
+
+    /// Records the speculative values of the given `key IDs` in the history, at the given `block height`.
+    #[cfg(feature = "history")]
+    fn record_history(&self, height: u32, key_ids: impl IntoIterator<Item = Field<N>>) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for key_id in key_ids {
+                // Retrieve the speculative value.
+                let value = self.get_value_from_key_id_speculative(&key_id)?;
+                // Retrieve the history of the key ID.
+                let mut history = match self.history_map().get_speculative(&key_id)? {
+                    Some(history) => cow_to_cloned!(history),
+                    None => Vec::new(),
+                };
+                match history.last_mut() {
+                    // If the value is unchanged, skip the key ID.
+                    Some((_, previous)) if *previous == value => continue,
+                    None if value.is_none() => continue,
+                    // If the value already changed at this block height, overwrite it.
+                    Some((previous_height, previous)) if *previous_height == height => *previous = value,
+                    // Otherwise, append the value.
+                    _ => history.push((height, value)),
+                }
+                // Update the history of the key ID.
+                self.history_map().insert(key_id, history)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    #[cfg(feature = "history")]
+    fn get_value_at_height_confirmed(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id_confirmed(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Compute the key ID.
+        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+        // Retrieve the history of the key ID.
+        match self.history_map().get_confirmed(&key_id)? {
+            Some(history) => {
+                // Find the last value that was set at or before the given block height.
+                let index = history.partition_point(|(previous_height, _)| *previous_height <= height);
+                Ok(index.checked_sub(1).and_then(|index| history[index].1.clone()))
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 /// The finalize store.
@@ -624,6 +1084,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The key IDs that were updated since the history was last recorded.
+    #[cfg(feature = "history")]
+    updated_key_ids: Arc<Mutex<IndexSet<Field<N>>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -637,7 +1100,12 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self {
+            storage,
+            #[cfg(feature = "history")]
+            updated_key_ids: Default::default(),
+            _phantom: PhantomData,
+        })
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -660,7 +1128,33 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.insert_key_value(program_id, mapping_name, key, value)
+        let operation = self.storage.insert_key_value(program_id, mapping_name, key, value)?;
+        // Track the key ID for the history.
+        #[cfg(feature = "history")]
+        if let FinalizeOperation::InsertKeyValue(_, key_id, _) = operation {
+            self.updated_key_ids.lock().insert(key_id);
+        }
+        Ok(operation)
+    }
+
+    /// Stores the given `(key, value)` pairs at the given `program ID` and `mapping name` in storage.
+    /// If the `mapping name` is not initialized, an error is returned.
+    /// If any `key` already exists, the method returns an error, and no pair is stored.
+    pub fn insert_key_values(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        entries: Vec<(Plaintext<N>, Value<N>)>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let operations = self.storage.insert_key_values(program_id, mapping_name, entries)?;
+        // Track the key IDs for the history.
+        #[cfg(feature = "history")]
+        for operation in &operations {
+            if let FinalizeOperation::InsertKeyValue(_, key_id, _) = operation {
+                self.updated_key_ids.lock().insert(*key_id);
+            }
+        }
+        Ok(operations)
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -674,7 +1168,13 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.update_key_value(program_id, mapping_name, key, value)
+        let operation = self.storage.update_key_value(program_id, mapping_name, key, value)?;
+        // Track the key ID for the history.
+        #[cfg(feature = "history")]
+        if let FinalizeOperation::UpdateKeyValue(_, _, key_id, _) = operation {
+            self.updated_key_ids.lock().insert(key_id);
+        }
+        Ok(operation)
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
@@ -684,7 +1184,41 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: &Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.remove_key_value(program_id, mapping_name, key)
+        // Retrieve the key ID, to track it for the history.
+        #[cfg(feature = "history")]
+        let key_id = self.storage.get_key_id_speculative(program_id, mapping_name, key)?;
+
+        let operation = self.storage.remove_key_value(program_id, mapping_name, key)?;
+        // Track the key ID for the history.
+        #[cfg(feature = "history")]
+        if let Some(key_id) = key_id {
+            self.updated_key_ids.lock().insert(key_id);
+        }
+        Ok(operation)
+    }
+
+    /// Removes all key-value pairs for the given `program ID` and `mapping name` from storage,
+    /// while keeping the mapping initialized.
+    pub fn clear_mapping(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        // Retrieve the key IDs, to track them for the history.
+        #[cfg(feature = "history")]
+        let key_ids = match self.storage.get_mapping_id_speculative(program_id, mapping_name)? {
+            Some(mapping_id) => match self.storage.key_value_id_map().get_speculative(&mapping_id)? {
+                Some(key_value_ids) => key_value_ids.keys().copied().collect::<Vec<_>>(),
+                None => vec![],
+            },
+            None => vec![],
+        };
+
+        let operation = self.storage.clear_mapping(program_id, mapping_name)?;
+        // Track the key IDs for the history.
+        #[cfg(feature = "history")]
+        self.updated_key_ids.lock().extend(key_ids);
+        Ok(operation)
     }
 
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
@@ -703,6 +1237,34 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.remove_program(program_id)
     }
 
+    /// Initializes the program admin, mappings, and key-value pairs of the given dump in storage.
+    /// If the `program ID` of the dump is already initialized, an error is returned.
+    pub fn import(&self, dump: &FinalizeDump<N>) -> Result<()> {
+        // Ensure the program ID does not already exist.
+        if self.storage.program_id_map().contains_key_speculative(dump.program_id())? {
+            bail!("Illegal operation: program ID '{}' already exists in storage - cannot import.", dump.program_id())
+        }
+
+        atomic_batch_scope!(self, {
+            // Register the program admin.
+            if let Some(admin) = dump.admin() {
+                self.register_admin(dump.program_id(), *admin)?;
+            }
+            for (mapping_name, entries) in dump.mappings() {
+                // Initialize the mapping.
+                self.initialize_mapping(dump.program_id(), mapping_name)?;
+                // Insert the key-value pairs, in a single write of the mapping.
+                self.insert_key_values(dump.program_id(), mapping_name, entries.clone())?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Registers the given `admin` address for the given `program ID` in storage.
+    pub fn register_admin(&self, program_id: &ProgramID<N>, admin: Address<N>) -> Result<FinalizeOperation<N>> {
+        self.storage.register_admin(program_id, admin)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -731,6 +1293,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+        // Clear the updated key IDs, as their updates are discarded.
+        #[cfg(feature = "history")]
+        self.updated_key_ids.lock().clear();
+    }
+
+    /// Records the values of the key IDs that were updated since the history was last recorded,
+    /// as the values at the given `block height`.
+    #[cfg(feature = "history")]
+    pub fn record_history(&self, height: u32) -> Result<()> {
+        let key_ids = core::mem::take(&mut *self.updated_key_ids.lock());
+        self.storage.record_history(height, key_ids)
     }
 
     /// Finishes an atomic batch write operation.
@@ -788,6 +1361,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_mapping_names_speculative(program_id)
     }
 
+    /// Returns a dump of the confirmed admin, mappings, and key-value pairs for the given `program ID`.
+    pub fn export(&self, program_id: &ProgramID<N>) -> Result<FinalizeDump<N>> {
+        self.storage.export_program(program_id)
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     pub fn get_value_confirmed(
         &self,
@@ -807,13 +1385,57 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
+
+    /// Returns the confirmed admin address for the given `program ID`.
+    pub fn get_admin_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<Address<N>>> {
+        self.storage.get_admin_confirmed(program_id)
+    }
+
+    /// Returns the speculative admin address for the given `program ID`.
+    pub fn get_admin_speculative(&self, program_id: &ProgramID<N>) -> Result<Option<Address<N>>> {
+        self.storage.get_admin_speculative(program_id)
+    }
+
+    /// Returns the speculative number of key-value pairs for the given `program ID` and `mapping name`.
+    pub fn get_mapping_length_speculative(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<usize>> {
+        self.storage.get_mapping_length_speculative(program_id, mapping_name)
+    }
+
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, in insertion order,
+    /// starting after the given `cursor` key, or at the first key if the `cursor` is not in the mapping.
+    pub fn get_keys_speculative(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        cursor: &Plaintext<N>,
+        limit: usize,
+    ) -> Result<Option<Vec<Plaintext<N>>>> {
+        self.storage.get_keys_speculative(program_id, mapping_name, cursor, limit)
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    #[cfg(feature = "history")]
+    pub fn get_value_at_height_confirmed(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height_confirmed(program_id, mapping_name, key, height)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::store::helpers::memory::FinalizeMemory;
-    use console::network::Testnet3;
+    use console::{account::PrivateKey, network::Testnet3};
+    use snarkvm_utilities::TestRng;
 
     type CurrentNetwork = Testnet3;
 
@@ -948,9 +1570,9 @@ mod tests {
             let new_value = Value::from_str("123456789u128").unwrap();
 
             // Ensure calling `insert_key_value` with a different key and value fails.
-            assert!(
-                finalize_store.insert_key_value(&program_id, &mapping_name, key.clone(), new_value.clone()).is_err()
-            );
+            assert!(finalize_store
+                .insert_key_value(&program_id, &mapping_name, key.clone(), new_value.clone())
+                .is_err());
             // Ensure the key is still initialized.
             assert!(finalize_store.contains_key_confirmed(&program_id, &mapping_name, &key).unwrap());
             // Ensure the value still returns Some(value).
@@ -1102,6 +1724,162 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_keys() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        // Initialize a cursor that is not a key in the mapping.
+        let start = Plaintext::from_str("100field").unwrap();
+        // Ensure an un-initialized mapping returns None.
+        assert!(finalize_store.get_keys_speculative(&program_id, &mapping_name, &start, 10).unwrap().is_none());
+
+        // Initialize the mapping, and insert the keys and values.
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        // Retrieves up to 4 keys after the given cursor.
+        let get_keys = |cursor: &Plaintext<CurrentNetwork>| {
+            finalize_store.get_keys_speculative(&program_id, &mapping_name, cursor, 4).unwrap().unwrap()
+        };
+        assert!(get_keys(&start).is_empty());
+        let keys = (0..10).map(|item| Plaintext::from_str(&format!("{item}field")).unwrap()).collect::<Vec<_>>();
+        for (item, key) in keys.iter().enumerate() {
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(&program_id, &mapping_name, key.clone(), value).unwrap();
+        }
+
+        // Ensure the keys are returned in pages, in the order they were inserted.
+        assert_eq!(keys[0..4], get_keys(&start));
+        assert_eq!(keys[4..8], get_keys(&keys[3]));
+        assert_eq!(keys[8..], get_keys(&keys[7]));
+        assert!(get_keys(&keys[9]).is_empty());
+
+        // Ensure removing keys does not skip the remaining keys.
+        finalize_store.remove_key_value(&program_id, &mapping_name, &keys[4]).unwrap();
+        finalize_store.remove_key_value(&program_id, &mapping_name, &keys[9]).unwrap();
+        assert_eq!(keys[5..9], get_keys(&keys[3]));
+        assert_eq!(vec![keys[8].clone()], get_keys(&keys[7]));
+        // Ensure a removed cursor starts at the first key.
+        assert_eq!(keys[0..4], get_keys(&keys[4]));
+
+        // Ensure new keys are appended, and updated keys keep their position.
+        let value = Value::from_str("0u64").unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, keys[4].clone(), value.clone()).unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, keys[8].clone(), value.clone()).unwrap();
+        assert_eq!(vec![keys[8].clone(), keys[4].clone()], get_keys(&keys[7]));
+        finalize_store.insert_key_values(&program_id, &mapping_name, vec![(keys[9].clone(), value)]).unwrap();
+        assert_eq!(vec![keys[4].clone(), keys[9].clone()], get_keys(&keys[8]));
+
+        // Ensure clearing the mapping removes the keys.
+        finalize_store.clear_mapping(&program_id, &mapping_name).unwrap();
+        assert!(get_keys(&start).is_empty());
+    }
+
+    #[test]
+    fn test_get_mapping_length() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        // Ensure an un-initialized mapping returns None.
+        assert!(finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap().is_none());
+
+        // Initialize the mapping.
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        assert_eq!(Some(0), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Insert the keys and values.
+        for item in 0..10 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+        }
+        assert_eq!(Some(10), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Ensure updating a key does not change the length.
+        let key = Plaintext::from_str("0field").unwrap();
+        let value = Value::from_str("100u64").unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, key.clone(), value).unwrap();
+        assert_eq!(Some(10), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Ensure removing a key decrements the length.
+        finalize_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
+        assert_eq!(Some(9), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Ensure inserting keys, or updating a new key, increments the length.
+        let value = Value::from_str("0u64").unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, key, value.clone()).unwrap();
+        assert_eq!(Some(10), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+        let entries = (10..15).map(|item| (Plaintext::from_str(&format!("{item}field")).unwrap(), value.clone()));
+        finalize_store.insert_key_values(&program_id, &mapping_name, entries.collect()).unwrap();
+        assert_eq!(Some(15), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Ensure clearing the mapping resets the length.
+        finalize_store.clear_mapping(&program_id, &mapping_name).unwrap();
+        assert_eq!(Some(0), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Ensure removing the mapping removes the length.
+        finalize_store.remove_mapping(&program_id, &mapping_name).unwrap();
+        assert!(finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_clear_mapping_and_admin() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID, mapping name, and admin.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let admin = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        // Ensure clearing an un-initialized mapping fails.
+        assert!(finalize_store.clear_mapping(&program_id, &mapping_name).is_err());
+
+        // Initialize the mapping, and register the admin.
+        let mapping_id = match finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap() {
+            FinalizeOperation::InitializeMapping(mapping_id) => mapping_id,
+            operation => panic!("Unexpected finalize operation: {operation:?}"),
+        };
+        assert!(finalize_store.get_admin_speculative(&program_id).unwrap().is_none());
+        let operation = finalize_store.register_admin(&program_id, admin).unwrap();
+        let program_id_field = CurrentNetwork::hash_bhp1024(&program_id.to_bits_le()).unwrap();
+        assert_eq!(FinalizeOperation::RegisterAdmin(program_id_field, admin.to_field().unwrap()), operation);
+        assert_eq!(Some(admin), finalize_store.get_admin_confirmed(&program_id).unwrap());
+        assert_eq!(Some(admin), finalize_store.get_admin_speculative(&program_id).unwrap());
+
+        // Insert the keys and values.
+        for item in 0..10 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+        }
+        assert_eq!(Some(10), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Clear the mapping.
+        let operation = finalize_store.clear_mapping(&program_id, &mapping_name).unwrap();
+        assert_eq!(FinalizeOperation::ReplaceMapping(mapping_id), operation);
+
+        // Ensure the mapping is empty, but still initialized.
+        assert!(finalize_store.contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+        assert_eq!(Some(0), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+        let key = Plaintext::from_str("0field").unwrap();
+        assert!(!finalize_store.contains_key_confirmed(&program_id, &mapping_name, &key).unwrap());
+
+        // Ensure the mapping can be written to again.
+        finalize_store.insert_key_value(&program_id, &mapping_name, key, Value::from_str("0u64").unwrap()).unwrap();
+        assert_eq!(Some(1), finalize_store.get_mapping_length_speculative(&program_id, &mapping_name).unwrap());
+
+        // Ensure removing the program removes the admin.
+        finalize_store.remove_program(&program_id).unwrap();
+        assert!(finalize_store.get_admin_confirmed(&program_id).unwrap().is_none());
+    }
+
     #[test]
     fn test_remove_mapping() {
         // Initialize a program ID and mapping name.
@@ -1230,6 +2008,103 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_export_import() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_names = [Identifier::from_str("account").unwrap(), Identifier::from_str("empty").unwrap()];
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        // Ensure exporting an un-initialized program fails.
+        assert!(finalize_store.export(&program_id).is_err());
+
+        // Initialize the mappings, and insert the keys and values in reverse order.
+        for mapping_name in &mapping_names {
+            finalize_store.initialize_mapping(&program_id, mapping_name).unwrap();
+        }
+        for item in (0..100).rev() {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(&program_id, &mapping_names[0], key, value).unwrap();
+        }
+        // Remove a key.
+        let removed_key = Plaintext::from_str("50field").unwrap();
+        finalize_store.remove_key_value(&program_id, &mapping_names[0], &removed_key).unwrap();
+        // Register the program admin.
+        let admin = Address::try_from(PrivateKey::new(&mut TestRng::default()).unwrap()).unwrap();
+        finalize_store.register_admin(&program_id, admin).unwrap();
+
+        // Export the program.
+        let dump = finalize_store.export(&program_id).unwrap();
+        assert_eq!(&program_id, dump.program_id());
+        assert_eq!(mapping_names.to_vec(), dump.mappings().keys().copied().collect::<Vec<_>>());
+        assert_eq!(99, dump.num_entries());
+        assert_eq!(Some(&admin), dump.admin());
+        // Ensure importing into the same store fails.
+        assert!(finalize_store.import(&dump).is_err());
+
+        // Import the program into a new finalize store.
+        let candidate_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        candidate_store.import(&dump).unwrap();
+        // Ensure the admin matches.
+        assert_eq!(Some(admin), candidate_store.get_admin_confirmed(&program_id).unwrap());
+        // Ensure the mappings, keys, and values match.
+        for mapping_name in &mapping_names {
+            assert!(candidate_store.contains_mapping_confirmed(&program_id, mapping_name).unwrap());
+        }
+        for item in 0..100 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            assert_eq!(
+                finalize_store.get_value_confirmed(&program_id, &mapping_names[0], &key).unwrap(),
+                candidate_store.get_value_confirmed(&program_id, &mapping_names[0], &key).unwrap()
+            );
+        }
+        assert!(!candidate_store.contains_key_confirmed(&program_id, &mapping_names[0], &removed_key).unwrap());
+
+        // Ensure the dump is canonical, as the new store was populated in a different order.
+        assert_eq!(dump, candidate_store.export(&program_id).unwrap());
+        assert_eq!(dump.to_bytes_le().unwrap(), candidate_store.export(&program_id).unwrap().to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_insert_key_values() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        // Ensure inserting into an un-initialized mapping fails.
+        assert!(finalize_store.insert_key_values(&program_id, &mapping_name, vec![]).is_err());
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        // Sample the key-value pairs.
+        let entries = (0..10)
+            .map(|item| {
+                (Plaintext::from_str(&format!("{item}field")).unwrap(), Value::from_str(&format!("{item}u64")).unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure a repeated key fails, without inserting any pair.
+        let mut repeated = entries.clone();
+        repeated.push(entries[0].clone());
+        assert!(finalize_store.insert_key_values(&program_id, &mapping_name, repeated).is_err());
+        assert!(!finalize_store.contains_key_confirmed(&program_id, &mapping_name, &entries[0].0).unwrap());
+
+        // Insert the key-value pairs.
+        let operations = finalize_store.insert_key_values(&program_id, &mapping_name, entries.clone()).unwrap();
+        assert_eq!(entries.len(), operations.len());
+        for (key, value) in &entries {
+            assert_eq!(
+                Some(value.clone()),
+                finalize_store.get_value_confirmed(&program_id, &mapping_name, key).unwrap()
+            );
+        }
+        // Ensure inserting an existing key fails.
+        assert!(finalize_store.insert_key_values(&program_id, &mapping_name, entries[..1].to_vec()).is_err());
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
@@ -1289,4 +2164,55 @@ mod tests {
         check_initialize_insert_remove(&finalize_store, program_id, mapping_name);
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn test_history() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare a key and values.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap());
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        let value_at = |height| finalize_store.get_value_at_height_confirmed(&program_id, &mapping_name, &key, height);
+
+        // Insert the key at block 2.
+        finalize_store.insert_key_value(&program_id, &mapping_name, key.clone(), value_1.clone()).unwrap();
+        finalize_store.record_history(2).unwrap();
+        // Update the key twice at block 4, and record the last value.
+        finalize_store.update_key_value(&program_id, &mapping_name, key.clone(), value_1.clone()).unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, key.clone(), value_2.clone()).unwrap();
+        finalize_store.record_history(4).unwrap();
+        // Leave the key unchanged at block 5.
+        finalize_store.update_key_value(&program_id, &mapping_name, key.clone(), value_2.clone()).unwrap();
+        finalize_store.record_history(5).unwrap();
+        // Remove the key at block 6.
+        finalize_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
+        finalize_store.record_history(6).unwrap();
+
+        // Ensure the values are returned as of each block height.
+        assert_eq!(None, value_at(1).unwrap());
+        assert_eq!(Some(value_1.clone()), value_at(2).unwrap());
+        assert_eq!(Some(value_1.clone()), value_at(3).unwrap());
+        assert_eq!(Some(value_2.clone()), value_at(4).unwrap());
+        assert_eq!(Some(value_2), value_at(5).unwrap());
+        assert_eq!(None, value_at(6).unwrap());
+        assert_eq!(None, value_at(u32::MAX).unwrap());
+
+        // Ensure an aborted update is not recorded.
+        finalize_store.start_atomic();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key.clone(), value_1).unwrap();
+        finalize_store.abort_atomic();
+        finalize_store.record_history(7).unwrap();
+        assert_eq!(None, value_at(7).unwrap());
+
+        // Ensure an uninitialized mapping has no values.
+        let unknown = Identifier::from_str("unknown").unwrap();
+        assert_eq!(None, finalize_store.get_value_at_height_confirmed(&program_id, &unknown, &key, 2).unwrap());
+    }
 }
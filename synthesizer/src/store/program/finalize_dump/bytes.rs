@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for FinalizeDump<N> {
+    /// Reads the finalize dump from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid finalize dump version"));
+        }
+
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the admin.
+        let admin = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Address::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid admin variant '{variant}' in the finalize dump"))),
+        };
+        // Read the number of mappings.
+        let num_mappings = u16::read_le(&mut reader)?;
+        // Read the mappings.
+        let mut mappings = IndexMap::with_capacity(num_mappings as usize);
+        for _ in 0..num_mappings {
+            // Read the mapping name.
+            let mapping_name = Identifier::read_le(&mut reader)?;
+            // Read the number of key-value pairs.
+            let num_entries = u64::read_le(&mut reader)?;
+            // Read the key-value pairs.
+            let entries = (0..num_entries)
+                .map(|_| Ok((Plaintext::read_le(&mut reader)?, Value::read_le(&mut reader)?)))
+                .collect::<IoResult<Vec<_>>>()?;
+            // Insert the mapping, ensuring it is unique.
+            if mappings.insert(mapping_name, entries).is_some() {
+                return Err(error(format!("Duplicate mapping '{mapping_name}' in the finalize dump")));
+            }
+        }
+
+        // Return the finalize dump.
+        Self::new(program_id, admin, mappings).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for FinalizeDump<N> {
+    /// Writes the finalize dump to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the admin.
+        match &self.admin {
+            None => 0u8.write_le(&mut writer)?,
+            Some(admin) => {
+                1u8.write_le(&mut writer)?;
+                admin.write_le(&mut writer)?;
+            }
+        }
+        // Write the number of mappings.
+        u16::try_from(self.mappings.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the mappings.
+        for (mapping_name, entries) in &self.mappings {
+            // Write the mapping name.
+            mapping_name.write_le(&mut writer)?;
+            // Write the number of key-value pairs.
+            (entries.len() as u64).write_le(&mut writer)?;
+            // Write the key-value pairs.
+            for (key, value) in entries {
+                key.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        let expected = crate::store::program::finalize_dump::test_helpers::sample_finalize_dump();
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, FinalizeDump::read_le(&expected_bytes[..]).unwrap());
+        assert!(FinalizeDump::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+    }
+}
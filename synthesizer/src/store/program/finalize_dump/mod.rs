@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use console::{
+    account::Address,
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+use indexmap::IndexMap;
+
+/// The key-value pairs of a mapping.
+pub type MappingEntries<N> = Vec<(Plaintext<N>, Value<N>)>;
+
+/// A canonical dump of the mappings of a program in finalize storage,
+/// for seeding the state of a new network and for rehearsing migrations.
+///
+/// The mappings are in the order they were initialized, and the key-value pairs
+/// of each mapping are sorted by the byte representation of their key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeDump<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The program admin, if one is registered.
+    admin: Option<Address<N>>,
+    /// The key-value pairs of each mapping.
+    mappings: IndexMap<Identifier<N>, MappingEntries<N>>,
+}
+
+impl<N: Network> FinalizeDump<N> {
+    /// Initializes a new dump, sorting the key-value pairs of each mapping and ensuring the keys are unique.
+    pub fn new(
+        program_id: ProgramID<N>,
+        admin: Option<Address<N>>,
+        mappings: IndexMap<Identifier<N>, MappingEntries<N>>,
+    ) -> Result<Self> {
+        let mappings = mappings
+            .into_iter()
+            .map(|(mapping_name, entries)| {
+                // Sort the key-value pairs by the byte representation of their key.
+                let mut entries = entries
+                    .into_iter()
+                    .map(|(key, value)| Ok((key.to_bytes_le()?, key, value)))
+                    .collect::<Result<Vec<_>>>()?;
+                entries.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+                // Ensure the keys are unique.
+                if entries.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+                    bail!("Found a duplicate key in mapping '{mapping_name}' of '{program_id}'")
+                }
+                Ok((mapping_name, entries.into_iter().map(|(_, key, value)| (key, value)).collect()))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+        Ok(Self { program_id, admin, mappings })
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the program admin, if one is registered.
+    pub const fn admin(&self) -> Option<&Address<N>> {
+        self.admin.as_ref()
+    }
+
+    /// Returns the key-value pairs of each mapping.
+    pub const fn mappings(&self) -> &IndexMap<Identifier<N>, MappingEntries<N>> {
+        &self.mappings
+    }
+
+    /// Returns the total number of key-value pairs.
+    pub fn num_entries(&self) -> usize {
+        self.mappings.values().map(|entries| entries.len()).sum()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a dump with two mappings, one of which is empty.
+    pub(crate) fn sample_finalize_dump() -> FinalizeDump<CurrentNetwork> {
+        let entries = (0..10)
+            .map(|i| (Plaintext::from_str(&format!("{i}field")).unwrap(), Value::from_str(&format!("{i}u64")).unwrap()))
+            .collect();
+        let admin = Address::try_from(PrivateKey::new(&mut TestRng::default()).unwrap()).unwrap();
+        FinalizeDump::new(
+            ProgramID::from_str("hello.aleo").unwrap(),
+            Some(admin),
+            IndexMap::from([
+                (Identifier::from_str("account").unwrap(), entries),
+                (Identifier::from_str("empty").unwrap(), vec![]),
+            ]),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() {
+        let expected = test_helpers::sample_finalize_dump();
+        assert_eq!(10, expected.num_entries());
+
+        // Ensure the key-value pairs are sorted, regardless of their order.
+        let mut mappings = expected.mappings().clone();
+        mappings.values_mut().for_each(|entries| entries.reverse());
+        assert_eq!(expected, FinalizeDump::new(*expected.program_id(), expected.admin, mappings.clone()).unwrap());
+
+        // Ensure duplicate keys fail.
+        let (key, _) = mappings[0][0].clone();
+        mappings[0].push((key, Value::from_str("0u64").unwrap()));
+        assert!(FinalizeDump::<CurrentNetwork>::new(*expected.program_id(), expected.admin, mappings).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for FinalizeDump<N> {
+    /// Serializes the finalize dump into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut dump = serializer.serialize_struct("FinalizeDump", 3)?;
+                dump.serialize_field("program_id", &self.program_id)?;
+                dump.serialize_field("admin", &self.admin)?;
+                dump.serialize_field("mappings", &self.mappings)?;
+                dump.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FinalizeDump<N> {
+    /// Deserializes the finalize dump from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the finalize dump from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+                // Recover the finalize dump.
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut object, "program_id")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "admin")?,
+                    DeserializeExt::take_from_value::<D>(&mut object, "mappings")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "finalize dump"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let expected = crate::store::program::finalize_dump::test_helpers::sample_finalize_dump();
+
+        // Serialize
+        let expected_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected, serde_json::from_str(&expected_string).unwrap());
+        // Deserialize
+        let candidate: FinalizeDump<_> = serde_json::from_str(&expected_string).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_string, serde_json::to_string(&candidate).unwrap());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let expected = crate::store::program::finalize_dump::test_helpers::sample_finalize_dump();
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+        // Deserialize
+        assert_eq!(expected, FinalizeDump::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+    }
+}
@@ -14,3 +14,6 @@
 
 mod finalize;
 pub use finalize::*;
+
+mod finalize_dump;
+pub use finalize_dump::*;
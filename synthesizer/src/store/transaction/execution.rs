@@ -14,7 +14,7 @@
 
 use crate::{
     atomic_batch_scope,
-    block::{Execution, Transaction, Transition},
+    block::{Execution, Sponsorship, Transaction, Transition},
     cow_to_cloned,
     cow_to_copied,
     snark::Proof,
@@ -37,8 +37,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type IDMap: for<'a> Map<'a, N::TransactionID, (Vec<N::TransitionID>, bool)>;
     /// The mapping of `transition ID` to `transaction ID`.
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
-    /// The mapping of `transaction ID` to `(global state root, (optional) proof)`.
-    type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transaction ID` to `(global state root, (optional) proof, (optional) expiration, (optional) sponsorship)`.
+    type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>, Option<u32>, Option<Sponsorship<N>>)>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -136,6 +136,10 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         let global_state_root = execution.global_state_root();
         // Retrieve the proof.
         let proof = execution.proof().cloned();
+        // Retrieve the expiration height.
+        let valid_until = execution.valid_until();
+        // Retrieve the sponsorship.
+        let sponsorship = execution.sponsorship().copied();
 
         atomic_batch_scope!(self, {
             // Store the transition IDs.
@@ -149,8 +153,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
                 self.transition_store().insert(transition)?;
             }
 
-            // Store the global state root and proof.
-            self.inclusion_map().insert(*transaction_id, (global_state_root, proof))?;
+            // Store the global state root, proof, expiration height, and sponsorship.
+            self.inclusion_map().insert(*transaction_id, (global_state_root, proof, valid_until, sponsorship))?;
 
             // Store the fee.
             if let Some(fee) = fee {
@@ -182,7 +186,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
                 self.transition_store().remove(&transition_id)?;
             }
 
-            // Remove the global state root and proof.
+            // Remove the global state root, proof, and expiration height.
             self.inclusion_map().remove(transaction_id)?;
 
             // Remove the fee.
@@ -219,11 +223,12 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
 
-        // Retrieve the global state root and proof.
-        let (global_state_root, proof) = match self.inclusion_map().get_confirmed(transaction_id)? {
-            Some(inclusion) => cow_to_cloned!(inclusion),
-            None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
-        };
+        // Retrieve the global state root, proof, expiration height, and sponsorship.
+        let (global_state_root, proof, valid_until, sponsorship) =
+            match self.inclusion_map().get_confirmed(transaction_id)? {
+                Some(inclusion) => cow_to_cloned!(inclusion),
+                None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
+            };
 
         // Initialize a vector for the transitions.
         let mut transitions = Vec::new();
@@ -237,7 +242,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Return the execution.
-        Ok(Some(Execution::from(transitions.into_iter(), global_state_root, proof)?))
+        Ok(Some(
+            Execution::from(transitions.into_iter(), global_state_root, proof)?
+                .with_valid_until(valid_until)
+                .with_sponsorship(sponsorship),
+        ))
     }
 
     /// Returns the transaction for the given `transaction ID`.
@@ -248,11 +257,12 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
 
-        // Retrieve the global state root and proof.
-        let (global_state_root, proof) = match self.inclusion_map().get_confirmed(transaction_id)? {
-            Some(inclusion) => cow_to_cloned!(inclusion),
-            None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
-        };
+        // Retrieve the global state root, proof, expiration height, and sponsorship.
+        let (global_state_root, proof, valid_until, sponsorship) =
+            match self.inclusion_map().get_confirmed(transaction_id)? {
+                Some(inclusion) => cow_to_cloned!(inclusion),
+                None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
+            };
 
         // Initialize a vector for the transitions.
         let mut transitions = Vec::new();
@@ -266,7 +276,9 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Construct the execution.
-        let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
+        let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?
+            .with_valid_until(valid_until)
+            .with_sponsorship(sponsorship);
 
         // Construct the transaction.
         let transaction = match has_fee {
@@ -44,6 +44,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     type RecordNonceMap: for<'a> Map<'a, Group<N>, Field<N>>;
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
+    /// The mapping of `commitment` to `(optional) auditor record ciphertext`, for audited records.
+    type AuditorRecordMap: for<'a> Map<'a, Field<N>, Option<Record<N, Ciphertext<N>>>>;
 
     /// Initializes the transition output storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -64,6 +66,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     fn record_nonce_map(&self) -> &Self::RecordNonceMap;
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
+    /// Returns the auditor record map.
+    fn auditor_record_map(&self) -> &Self::AuditorRecordMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -78,6 +82,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().start_atomic();
         self.record_nonce_map().start_atomic();
         self.external_record_map().start_atomic();
+        self.auditor_record_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -90,6 +95,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             || self.record_map().is_atomic_in_progress()
             || self.record_nonce_map().is_atomic_in_progress()
             || self.external_record_map().is_atomic_in_progress()
+            || self.auditor_record_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -102,6 +108,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().atomic_checkpoint();
         self.record_nonce_map().atomic_checkpoint();
         self.external_record_map().atomic_checkpoint();
+        self.auditor_record_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -114,6 +121,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().clear_latest_checkpoint();
         self.record_nonce_map().clear_latest_checkpoint();
         self.external_record_map().clear_latest_checkpoint();
+        self.auditor_record_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -126,6 +134,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().atomic_rewind();
         self.record_nonce_map().atomic_rewind();
         self.external_record_map().atomic_rewind();
+        self.auditor_record_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -138,6 +147,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().abort_atomic();
         self.record_nonce_map().abort_atomic();
         self.external_record_map().abort_atomic();
+        self.auditor_record_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -149,7 +159,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().finish_atomic()?;
         self.record_map().finish_atomic()?;
         self.record_nonce_map().finish_atomic()?;
-        self.external_record_map().finish_atomic()
+        self.external_record_map().finish_atomic()?;
+        self.auditor_record_map().finish_atomic()
     }
 
     /// Stores the given `(transition ID, output)` pair into storage.
@@ -176,6 +187,21 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                         self.record_map().insert(commitment, (checksum, optional_record))?
                     }
                     Output::ExternalRecord(output_id) => self.external_record_map().insert(output_id, ())?,
+                    Output::AuditedRecord(commitment, checksum, optional_records) => {
+                        // Split the optional records into the owner and auditor record ciphertexts.
+                        let (optional_record, optional_auditor_record) = match optional_records {
+                            Some((record, auditor_record)) => (Some(record), Some(auditor_record)),
+                            None => (None, None),
+                        };
+                        // If the optional record exists, insert the record nonce.
+                        if let Some(record) = &optional_record {
+                            self.record_nonce_map().insert(*record.nonce(), commitment)?;
+                        }
+                        // Insert the record entry, which is spent as any other record.
+                        self.record_map().insert(commitment, (checksum, optional_record))?;
+                        // Insert the auditor record entry.
+                        self.auditor_record_map().insert(commitment, optional_auditor_record)?
+                    }
                 }
             }
 
@@ -214,6 +240,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 self.private_map().remove(&output_id)?;
                 self.record_map().remove(&output_id)?;
                 self.external_record_map().remove(&output_id)?;
+                self.auditor_record_map().remove(&output_id)?;
             }
 
             Ok(())
@@ -265,6 +292,23 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             let record = self.record_map().get_confirmed(&output_id)?;
             let external_record = self.external_record_map().get_confirmed(&output_id)?;
 
+            // If the output is an audited record, retrieve the auditor record ciphertext.
+            if let Some(auditor_record) = self.auditor_record_map().get_confirmed(&output_id)? {
+                // Retrieve the record entry.
+                let (checksum, optional_record) = match record {
+                    Some(record) => record.into_owned(),
+                    None => bail!("Missing audited record '{output_id}' in transition '{transition_id}'"),
+                };
+                // Return the audited record.
+                return match (optional_record, auditor_record.into_owned()) {
+                    (Some(record), Some(auditor_record)) => {
+                        Ok(Output::AuditedRecord(output_id, checksum, Some((record, auditor_record))))
+                    }
+                    (None, None) => Ok(Output::AuditedRecord(output_id, checksum, None)),
+                    _ => bail!("Found a partial audited record '{output_id}' in transition '{transition_id}'"),
+                };
+            }
+
             // Retrieve the output.
             let output = match (constant, public, private, record, external_record) {
                 (Some(constant), None, None, None, None) => into_output!(Output::Constant(output_id, constant)),
@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Block;
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+    program::{Ciphertext, Identifier, Plaintext, ProgramID, Record, Value},
+    types::Scalar,
+};
+
+use indexmap::IndexMap;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+
+/// The version of the test vectors, which is incremented whenever the format or the objects change.
+pub const TEST_VECTORS_VERSION: u16 = 1;
+
+/// The seed of the random number generator, which samples the keys and randomizers of the test vectors.
+const TEST_VECTORS_SEED: u64 = 1234567890;
+
+/// A test vector for an object, with its canonical string, bytes, and hashes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    /// The kind of the object, i.e. `identifier`.
+    kind: String,
+    /// The string representation of the object.
+    string: String,
+    /// The `0x`-prefixed hex string of the little-endian bytes of the object.
+    bytes: String,
+    /// The hashes and other derived values of the object, by name, i.e. `bhp1024`.
+    hashes: IndexMap<String, String>,
+}
+
+impl TestVector {
+    /// Initializes a new test vector for the given object, with the given hashes.
+    fn new<T: ToBytes + Display>(kind: &str, object: &T, hashes: &[(&str, String)]) -> Result<Self> {
+        Ok(Self {
+            kind: kind.to_string(),
+            string: object.to_string(),
+            bytes: ToHexSerializer::to_hex(object)?,
+            hashes: hashes.iter().map(|(name, hash)| (name.to_string(), hash.clone())).collect(),
+        })
+    }
+
+    /// Returns the kind of the object.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Returns the string representation of the object.
+    pub fn string(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns the `0x`-prefixed hex string of the little-endian bytes of the object.
+    pub fn bytes(&self) -> &str {
+        &self.bytes
+    }
+
+    /// Returns the hashes and other derived values of the object, by name.
+    pub const fn hashes(&self) -> &IndexMap<String, String> {
+        &self.hashes
+    }
+}
+
+/// A versioned set of test vectors, which implementations of snarkVM in other languages
+/// can use to check that they are byte-for-byte compatible with snarkVM.
+///
+/// The test vectors are deterministic for a given version and network.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectors {
+    /// The version of the test vectors.
+    version: u16,
+    /// The name of the network.
+    network: String,
+    /// The test vectors.
+    vectors: Vec<TestVector>,
+}
+
+impl TestVectors {
+    /// Returns the test vectors for identifiers, values, records, transitions, and blocks on the given network.
+    pub fn generate<N: Network>() -> Result<Self> {
+        let rng = &mut ChaChaRng::seed_from_u64(TEST_VECTORS_SEED);
+
+        let mut vectors = Vec::new();
+
+        // Add the identifiers.
+        for identifier in ["hello", "credits", "transfer_public", "a1_b2"] {
+            let identifier = Identifier::<N>::from_str(identifier)?;
+            vectors.push(TestVector::new("identifier", &identifier, &[
+                ("field", identifier.to_field()?.to_string()),
+                ("bhp1024", N::hash_bhp1024(&identifier.to_bits_le())?.to_string()),
+            ])?);
+        }
+
+        // Sample the record owner.
+        let private_key = PrivateKey::<N>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Add the values.
+        for value in [
+            "true".to_string(),
+            "-42i8".to_string(),
+            "340282366920938463463374607431768211455u128".to_string(),
+            "1field".to_string(),
+            "2group".to_string(),
+            "3scalar".to_string(),
+            address.to_string(),
+            "\"hello world\"".to_string(),
+            format!("{{ owner: {address}, balances: {{ public: 1u64, private: 2u64 }} }}"),
+        ] {
+            let value = Value::<N>::from_str(&value)?;
+            vectors.push(TestVector::new("value", &value, &[
+                ("bhp1024", N::hash_bhp1024(&value.to_bits_le())?.to_string()),
+                ("psd2", N::hash_psd2(&value.to_fields()?)?.to_string()),
+            ])?);
+        }
+
+        // Add a record, and its encryption.
+        let program_id = ProgramID::<N>::from_str("token.aleo")?;
+        let record_name = Identifier::<N>::from_str("token")?;
+        let randomizer = Scalar::<N>::rand(rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+        let record = Record::<N, Plaintext<N>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))?;
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+        vectors.push(TestVector::new("record", &record, &[
+            ("commitment", commitment.to_string()),
+            ("serial_number", Record::<N, Plaintext<N>>::serial_number(private_key, commitment)?.to_string()),
+        ])?);
+        let ciphertext: Record<N, Ciphertext<N>> = record.encrypt(randomizer)?;
+        vectors.push(TestVector::new("record_ciphertext", &ciphertext, &[(
+            "bhp1024",
+            N::hash_bhp1024(&ciphertext.to_bits_le())?.to_string(),
+        )])?);
+
+        // Add the transitions and the genesis block.
+        let block = Block::<N>::from_bytes_le(N::genesis_bytes())?;
+        for transition in block.transitions() {
+            vectors.push(TestVector::new("transition", transition, &[
+                ("id", transition.id().to_string()),
+                ("tcm", transition.tcm().to_string()),
+            ])?);
+        }
+        vectors.push(TestVector::new("block", &block, &[
+            ("hash", block.hash().to_string()),
+            ("previous_hash", block.previous_hash().to_string()),
+            ("transactions_root", block.header().transactions_root().to_string()),
+        ])?);
+
+        Ok(Self { version: TEST_VECTORS_VERSION, network: N::NAME.to_string(), vectors })
+    }
+
+    /// Returns the version of the test vectors.
+    pub const fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the name of the network.
+    pub fn network(&self) -> &str {
+        &self.network
+    }
+
+    /// Returns the test vectors.
+    pub fn vectors(&self) -> &[TestVector] {
+        &self.vectors
+    }
+
+    /// Checks the given test vector against the object, by parsing the object from the string and the bytes.
+    pub fn check<T: FromStr<Err = Error> + FromBytes + ToBytes + Display + PartialEq>(
+        vector: &TestVector,
+    ) -> Result<T> {
+        // Parse the object from the string.
+        let object = T::from_str(vector.string())?;
+        // Ensure the object is the same when parsed from the bytes.
+        ensure!(object == FromHexDeserializer::<T>::from_hex(vector.bytes())?, "Mismatching '{}' bytes", vector.kind());
+        // Ensure the object is printed and serialized the same.
+        ensure!(object.to_string() == vector.string(), "Mismatching '{}' string", vector.kind());
+        ensure!(ToHexSerializer::to_hex(&object)? == vector.bytes(), "Mismatching '{}' bytes", vector.kind());
+        Ok(object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Transition;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let vectors = TestVectors::generate::<CurrentNetwork>()?;
+        assert_eq!(vectors.version(), TEST_VECTORS_VERSION);
+        assert_eq!(vectors.network(), CurrentNetwork::NAME);
+
+        // Ensure the test vectors are deterministic.
+        assert_eq!(vectors, TestVectors::generate::<CurrentNetwork>()?);
+
+        // Ensure each test vector matches its object.
+        for vector in vectors.vectors() {
+            match vector.kind() {
+                "identifier" => {
+                    let identifier = TestVectors::check::<Identifier<CurrentNetwork>>(vector)?;
+                    assert_eq!(vector.hashes()["field"], identifier.to_field()?.to_string());
+                }
+                "value" => {
+                    TestVectors::check::<Value<CurrentNetwork>>(vector)?;
+                }
+                "record" => {
+                    TestVectors::check::<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>(vector)?;
+                }
+                "record_ciphertext" => {
+                    TestVectors::check::<Record<CurrentNetwork, Ciphertext<CurrentNetwork>>>(vector)?;
+                }
+                "transition" => {
+                    let transition = TestVectors::check::<Transition<CurrentNetwork>>(vector)?;
+                    assert_eq!(vector.hashes()["id"], transition.id().to_string());
+                }
+                "block" => {
+                    let block = TestVectors::check::<Block<CurrentNetwork>>(vector)?;
+                    assert_eq!(vector.hashes()["hash"], block.hash().to_string());
+                }
+                kind => bail!("Unknown test vector kind '{kind}'"),
+            }
+        }
+
+        // Ensure the test vectors round trip through JSON.
+        let json = serde_json::to_string_pretty(&vectors)?;
+        assert_eq!(vectors, serde_json::from_str(&json)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_testnet3_vectors() -> Result<()> {
+        // The path of the published test vectors, which is rewritten if `REWRITE_EXPECTATIONS` is set.
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/vectors/testnet3.json");
+
+        // Generate the test vectors.
+        let json = serde_json::to_string_pretty(&TestVectors::generate::<CurrentNetwork>()?)?;
+        if std::env::var("REWRITE_EXPECTATIONS").is_ok() {
+            std::fs::write(&path, &json)?;
+        }
+
+        // Ensure the test vectors match the published test vectors, as a change must increment the version.
+        assert_eq!(json, std::fs::read_to_string(&path)?, "The test vectors changed, update the version");
+        Ok(())
+    }
+}
@@ -24,6 +24,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_expiration(private_key, program_id, function_name, inputs, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, which expires after the given block height.
+    #[inline]
+    pub fn authorize_with_expiration<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
+        valid_until: Option<u32>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("VM::authorize");
 
@@ -55,8 +69,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let inputs = cast_ref!(inputs as Vec<Value<$network>>);
 
                 // Compute the authorization.
-                let authorization =
-                    $process.authorize::<$aleo, _>(private_key, program_id, function_name, inputs.iter(), rng)?;
+                let authorization = $process.authorize_with_expiration::<$aleo, _>(
+                    private_key,
+                    program_id,
+                    function_name,
+                    inputs.iter(),
+                    valid_until,
+                    rng,
+                )?;
                 lap!(timer, "Compute authorization");
 
                 finish!(timer);
@@ -27,30 +27,122 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
-        // Compute the authorization.
-        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        self.execute_with_expiration(private_key, (program_id, function_name), inputs, None, fee, query, rng)
+    }
+
+    /// Returns a new execute transaction, which may only be finalized up to the given block height, if given.
+    ///
+    /// An expired transaction is rejected, so it may not be replayed after its expiration height.
+    /// The expiration height is a public input of the root transition, so it can not be changed or removed.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    pub fn execute_with_expiration<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        valid_until: Option<u32>,
+        fee: Option<(Record<N, Plaintext<N>>, u64)>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the authorization, with the expiration height signed in the root request.
+        let authorization =
+            self.authorize_with_expiration(private_key, program_id, function_name, inputs, valid_until, rng)?;
+        // Retrieve the signed expiration height from the root request.
+        let valid_until = authorization.peek_next()?.valid_until();
         // Compute the execution.
         let (_response, execution) = self.execute_authorization_raw(authorization, query.clone(), rng)?;
+        // Set the expiration height.
+        let execution = execution.with_valid_until(valid_until);
         // Compute the fee.
         let fee = match fee {
             None => None,
-            Some((credits, priority_fee_in_microcredits)) => {
-                // Compute the minimum execution cost.
-                let (minimum_execution_cost, (_, _)) = Execution::cost(self, &execution)?;
-                // Determine the fee.
-                let fee_in_microcredits = minimum_execution_cost
-                    .checked_add(priority_fee_in_microcredits)
-                    .ok_or_else(|| anyhow!("Fee overflowed for an execution transaction"))?;
-                // Compute the execution ID.
-                let execution_id = execution.to_execution_id()?;
-                // Compute the fee.
-                Some(self.execute_fee_raw(private_key, credits, fee_in_microcredits, execution_id, query, rng)?.1)
-            }
+            Some((credits, priority_fee_in_microcredits)) => Some(self.execute_fee_for_execution(
+                private_key,
+                credits,
+                priority_fee_in_microcredits,
+                &execution,
+                query,
+                rng,
+            )?),
         };
         // Return the execute transaction.
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns a new execute transaction without a fee, whose fee may only be paid by the given sponsor.
+    ///
+    /// The signer of the execution endorses the sponsor, which binds the sponsor to the execution ID.
+    /// The returned transaction must be passed to `sponsor_execution` by the sponsor, to attach the fee.
+    pub fn execute_with_sponsor<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        sponsor: Address<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Endorse the sponsor, with the root request of the authorization.
+        let sponsorship = Sponsorship::new(&authorization.peek_next()?, sponsor, rng)?;
+        // Compute the execution.
+        let (_response, execution) = self.execute_authorization_raw(authorization, query, rng)?;
+        // Set the sponsorship.
+        let execution = execution.with_sponsorship(Some(sponsorship));
+        // Return the execute transaction.
+        Transaction::from_execution(execution, None)
+    }
+
+    /// Returns the given execute transaction, with its fee paid by the given sponsor.
+    ///
+    /// This allows an application to sponsor the fee of an execution authorized by a different account.
+    /// The given transaction must be an execute transaction without a fee, from `execute_with_sponsor`,
+    /// whose signer endorsed the given sponsor. The sponsor signs the fee, and the fee is bound to the execution ID,
+    /// which commits to the signed requests and the sponsorship, so the sponsor cannot alter the execution,
+    /// and the fee cannot be reused for a different execution.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    pub fn sponsor_execution<R: Rng + CryptoRng>(
+        &self,
+        sponsor_private_key: &PrivateKey<N>,
+        transaction: Transaction<N>,
+        (credits, priority_fee_in_microcredits): (Record<N, Plaintext<N>>, u64),
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Retrieve the execution.
+        let execution = match &transaction {
+            Transaction::Execute(_, execution, None) => execution.clone(),
+            Transaction::Execute(id, _, Some(_)) => bail!("Cannot sponsor transaction '{id}' - it already has a fee"),
+            _ => bail!("Cannot sponsor transaction '{}' - it is not an execute transaction", transaction.id()),
+        };
+        // Retrieve the sponsorship.
+        let Some(sponsorship) = execution.sponsorship().copied() else {
+            bail!("Cannot sponsor transaction '{}' - it does not designate a sponsor", transaction.id())
+        };
+        // Ensure the sponsor is the designated sponsor.
+        let sponsor = Address::try_from(sponsor_private_key)?;
+        if sponsorship.sponsor() != &sponsor {
+            bail!("Cannot sponsor transaction '{}' - '{sponsor}' is not the designated sponsor", transaction.id())
+        }
+        // Compute the fee.
+        let fee = self.execute_fee_for_execution(
+            sponsor_private_key,
+            credits,
+            priority_fee_in_microcredits,
+            &execution,
+            query,
+            rng,
+        )?;
+        // Sign the fee, as the sponsor.
+        let sponsorship = sponsorship.sign(sponsor_private_key, &fee, rng)?;
+        // Return the sponsored execute transaction.
+        Transaction::from_execution(execution.with_sponsorship(Some(sponsorship)), Some(fee))
+    }
+
     /// Returns a new execute transaction for the given authorization.
     pub fn execute_authorization<R: Rng + CryptoRng>(
         &self,
@@ -65,6 +157,28 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns the fee for the given execution, authorized by the given private key.
+    fn execute_fee_for_execution<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        credits: Record<N, Plaintext<N>>,
+        priority_fee_in_microcredits: u64,
+        execution: &Execution<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = Execution::cost(self, execution)?;
+        // Determine the fee.
+        let fee_in_microcredits = minimum_execution_cost
+            .checked_add(priority_fee_in_microcredits)
+            .ok_or_else(|| anyhow!("Fee overflowed for an execution transaction"))?;
+        // Compute the execution ID.
+        let execution_id = execution.to_execution_id()?;
+        // Compute the fee.
+        Ok(self.execute_fee_raw(private_key, credits, fee_in_microcredits, execution_id, query, rng)?.1)
+    }
+
     /// Executes a call to the program function for the given authorization.
     /// Returns the response and execution.
     #[inline]
@@ -196,6 +310,140 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sponsor_execution() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the sponsor, who owns the genesis records.
+        let sponsor_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let sponsor_view_key = ViewKey::try_from(&sponsor_private_key).unwrap();
+        // Initialize a new caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record of the sponsor.
+        let record = records.values().next().unwrap().decrypt(&sponsor_view_key).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Execute, without a fee, designating the sponsor.
+        let sponsor = Address::try_from(&sponsor_private_key).unwrap();
+        let transaction = vm
+            .execute_with_sponsor(&caller_private_key, ("credits.aleo", "mint"), inputs.clone(), sponsor, None, rng)
+            .unwrap();
+        let Transaction::Execute(_, execution, None) = &transaction else {
+            panic!("Expected an execute transaction without a fee");
+        };
+        let execution = execution.clone();
+        // Ensure the unsponsored transaction is invalid.
+        assert!(!vm.verify_transaction(&transaction, None));
+
+        // Ensure an account other than the designated sponsor may not sponsor the execution.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        assert!(vm.sponsor_execution(&other_private_key, transaction.clone(), (record.clone(), 1), None, rng).is_err());
+
+        // Sponsor the execution.
+        let sponsored = vm.sponsor_execution(&sponsor_private_key, transaction, (record, 1), None, rng).unwrap();
+        // Ensure the transitions are unchanged, and the fee is bound to the execution.
+        let Transaction::Execute(_, sponsored_execution, Some(fee)) = &sponsored else {
+            panic!("Expected an execute transaction with a fee");
+        };
+        assert!(execution.transitions().eq(sponsored_execution.transitions()));
+        assert!(vm.verify_fee(fee, sponsored_execution.to_execution_id().unwrap()));
+        // Ensure the sponsored transaction is valid.
+        assert!(vm.verify_transaction(&sponsored, None));
+
+        // Ensure a sponsored transaction may not be sponsored again.
+        let record = records.values().nth(1).unwrap().decrypt(&sponsor_view_key).unwrap();
+        assert!(vm.sponsor_execution(&sponsor_private_key, sponsored.clone(), (record, 1), None, rng).is_err());
+
+        // Ensure an execution without a designated sponsor may not be sponsored.
+        let unsponsored = vm.execute(&caller_private_key, ("credits.aleo", "mint"), inputs, None, None, rng).unwrap();
+        let record = records.values().nth(2).unwrap().decrypt(&sponsor_view_key).unwrap();
+        assert!(vm.sponsor_execution(&sponsor_private_key, unsponsored.clone(), (record, 1), None, rng).is_err());
+
+        // Ensure the fee of the sponsored transaction may not be attached to a different execution.
+        let Transaction::Execute(_, unsponsored_execution, None) = unsponsored else {
+            panic!("Expected an execute transaction without a fee");
+        };
+        let swapped = unsponsored_execution.with_sponsorship(sponsored_execution.sponsorship().copied());
+        let swapped = Transaction::from_execution(swapped, Some(fee.clone())).unwrap();
+        assert!(!vm.verify_transaction(&swapped, None));
+
+        // Ensure a sponsorship with a signature from a different sponsor is rejected.
+        let sponsorship = sponsored_execution.sponsorship().unwrap();
+        let forged =
+            Sponsorship::from(Address::try_from(&other_private_key).unwrap(), *sponsorship.endorsement(), None);
+        let forged = forged.sign(&other_private_key, fee, rng).unwrap();
+        let forged = sponsored_execution.clone().with_sponsorship(Some(forged));
+        let forged = Transaction::from_execution(forged, Some(fee.clone())).unwrap();
+        assert!(!vm.verify_transaction(&forged, None));
+    }
+
+    #[test]
+    fn test_execute_with_expiration() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the caller, who owns the genesis records.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record.
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Execute, with an expiration height.
+        let transaction = vm
+            .execute_with_expiration(
+                &caller_private_key,
+                ("credits.aleo", "mint"),
+                inputs,
+                Some(10),
+                Some((record, 1)),
+                None,
+                rng,
+            )
+            .unwrap();
+        let Transaction::Execute(_, execution, Some(fee)) = &transaction else {
+            panic!("Expected an execute transaction with a fee");
+        };
+        assert_eq!(Some(10), execution.valid_until());
+        // Ensure the transaction is valid.
+        assert!(vm.verify_transaction(&transaction, None));
+
+        // Ensure the execution remains valid without the fee, as a mint does not require one.
+        let unpaid = Transaction::from_execution(execution.clone(), None).unwrap();
+        assert!(vm.verify_transaction(&unpaid, None));
+
+        // Ensure a changed or stripped expiration height is rejected, as the proof is bound to it.
+        // Note: The fee is omitted, so the proof is the only binding of the expiration height.
+        for valid_until in [Some(11), None] {
+            let forged = execution.clone().with_valid_until(valid_until);
+            let forged = Transaction::from_execution(forged, None).unwrap();
+            assert!(!vm.verify_transaction(&forged, None));
+        }
+        // Ensure the fee does not verify against a stripped expiration height.
+        assert!(!vm.verify_fee(fee, execution.clone().with_valid_until(None).to_execution_id().unwrap()));
+    }
+
     #[test]
     fn test_transfer_transaction_size() {
         let rng = &mut TestRng::default();
@@ -13,7 +13,14 @@
 // limitations under the License.
 
 use super::*;
-use crate::{ConfirmedTransaction, Rejected, Transactions};
+use crate::{
+    block::{FinalizeOperation, RejectionCode, RejectionMetadata},
+    process::{FinalizeFailure, Stack},
+    store::FinalizeStorage,
+    ConfirmedTransaction,
+    Rejected,
+    Transactions,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FinalizeMode {
@@ -63,13 +70,56 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(confirmed_transactions.into_iter().collect())
     }
 
-    /// Finalizes the given transactions into the VM.
+    /// Speculates on the given list of transactions, followed by the given custom ratifications, in the VM.
+    /// Returns the confirmed transactions, and the finalize operations of the custom ratifications.
     #[inline]
-    pub fn finalize(&self, state: FinalizeGlobalState, transactions: &Transactions<N>) -> Result<()> {
+    pub fn speculate_with_ratifications<'a>(
+        &self,
+        state: FinalizeGlobalState,
+        transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
+        ratifications: &[Ratify<N>],
+    ) -> Result<(Transactions<N>, Vec<FinalizeOperation<N>>)> {
+        let timer = timer!("VM::speculate_with_ratifications");
+
+        // Performs a **dry-run** over the list of transactions and ratifications.
+        let (confirmed_transactions, ratify_operations) =
+            self.atomic_speculate_with_ratifications(state, transactions, ratifications)?;
+
+        finish!(timer, "Finished dry-run of the transactions");
+
+        // Return the transactions, and the finalize operations of the ratifications.
+        Ok((confirmed_transactions.into_iter().collect(), ratify_operations))
+    }
+
+    /// Performs a dry run of finalize for the given execution, and returns a trace of every command executed,
+    /// register written, and mapping read and write. If finalize aborts, the trace ends with the error.
+    #[inline]
+    pub fn trace_finalize(&self, state: FinalizeGlobalState, execution: &Execution<N>) -> Result<FinalizeTrace<N>> {
+        // Performs a **dry-run** of finalize over the execution.
+        atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            // Acquire the read lock on the process.
+            let process = self.process.read();
+            // Initialize the trace.
+            let mut trace = FinalizeTrace::new();
+            // Finalize the execution. Note: If finalize aborts, the error is recorded in the trace.
+            let _ = process.finalize_execution_with_trace(state, self.finalize_store(), execution, Some(&mut trace));
+            // Return the trace.
+            Ok(trace)
+        })
+    }
+
+    /// Finalizes the given transactions and ratifications into the VM.
+    #[inline]
+    pub fn finalize(
+        &self,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+        ratifications: &[Ratify<N>],
+    ) -> Result<()> {
         let timer = timer!("VM::finalize");
 
-        // Performs a **real-run** of finalize over the list of transactions.
-        self.atomic_finalize(state, transactions)?;
+        // Performs a **real-run** of finalize over the list of transactions and ratifications.
+        self.atomic_finalize(state, transactions, ratifications)?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(())
@@ -79,12 +129,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Performs atomic speculation over a list of transactions, and returns the confirmed transactions.
     #[inline]
-    #[rustfmt::skip]
     fn atomic_speculate<'a>(
         &self,
         state: FinalizeGlobalState,
         transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
     ) -> Result<Vec<ConfirmedTransaction<N>>> {
+        self.atomic_speculate_with_ratifications(state, transactions, &[]).map(|(confirmed, _)| confirmed)
+    }
+
+    /// Performs atomic speculation over a list of transactions, followed by the custom ratifications,
+    /// and returns the confirmed transactions and the finalize operations of the ratifications.
+    ///
+    /// The executions whose accessed mapping keys are statically known are finalized in parallel,
+    /// if they do not conflict, and the remaining transactions are finalized sequentially.
+    #[inline]
+    fn atomic_speculate_with_ratifications<'a>(
+        &self,
+        state: FinalizeGlobalState,
+        transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
+        ratifications: &[Ratify<N>],
+    ) -> Result<(Vec<ConfirmedTransaction<N>>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("VM::atomic_speculate");
 
         // Retrieve the number of transactions.
@@ -100,72 +164,67 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Retrieve the finalize store.
             let store = self.finalize_store();
 
+            // Extract the mapping keys that each transaction accesses in finalize, if they are statically known.
+            // Note: The expired executions are speculated on sequentially, as they are rejected without finalizing.
+            let transactions = transactions.collect::<Vec<_>>();
+            let accesses = transactions
+                .iter()
+                .map(|transaction| match transaction {
+                    Transaction::Execute(_, execution, _) if execution.is_expired(state.block_height()) => None,
+                    _ => parallel::finalize_accesses(&process, transaction),
+                })
+                .collect::<Vec<_>>();
+            lap!(timer, "Extract the accessed mapping keys");
+
             // Initialize a list of the confirmed transactions.
             let mut confirmed = Vec::with_capacity(num_transactions);
 
-            // Finalize the transactions.
-            for (index, transaction) in transactions.enumerate() {
-                // Convert the transaction index to a u32.
-                // Note: On failure, this will abort the entire atomic batch.
-                let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
-
-                // Process the transaction in an isolated atomic batch.
-                // - If the transaction succeeds, the finalize operations are stored.
-                // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
-                let outcome = match transaction {
-                    // The finalize operation here involves appending the 'stack',
-                    // and adding the program to the finalize tree.
-                    Transaction::Deploy(_, program_owner, deployment, fee) => match process.finalize_deployment(store, deployment) {
-                        // Construct the accepted deploy transaction.
-                        Ok((_, finalize)) => ConfirmedTransaction::accepted_deploy(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
-                        // Construct the rejected deploy transaction.
-                        Err(_error) => {
-                            // Construct the fee transaction.
-                            // Note: On failure, this will abort the entire atomic batch.
-                            let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
-                            // Construct the rejected deployment.
-                            let rejected = Rejected::new_deployment(*program_owner, *deployment.clone());
-                            // Construct the rejected deploy transaction.
-                            ConfirmedTransaction::rejected_deploy(index, fee_tx, rejected).map_err(|e| e.to_string())
-                        }
-                    }
-                    // The finalize operation here involves calling 'update_key_value',
-                    // and update the respective leaves of the finalize tree.
-                    Transaction::Execute(_, execution, fee) => match process.finalize_execution(state, store, execution) {
-                        // Construct the accepted execute transaction.
-                        Ok(finalize) => ConfirmedTransaction::accepted_execute(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
-                        // Construct the rejected execute transaction.
-                        Err(_error) => match fee {
-                            Some(fee) => {
-                                // Construct the fee transaction.
-                                // Note: On failure, this will abort the entire atomic batch.
-                                let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
-                                // Construct the rejected execution.
-                                let rejected = Rejected::new_execution(execution.clone());
-                                // Construct the rejected execute transaction.
-                                ConfirmedTransaction::rejected_execute(index, fee_tx, rejected).map_err(|e| e.to_string())
-                            },
-                            // This is a foundational bug - the caller is violating protocol rules.
+            let mut start = 0;
+            while start < num_transactions {
+                // Retrieve the run of transactions, from the start, whose accessed mapping keys are known.
+                let end = accesses[start..]
+                    .iter()
+                    .position(Option::is_none)
+                    .map_or(num_transactions, |offset| start + offset);
+
+                // If the accessed mapping keys are not known, speculate on the transaction sequentially.
+                if start == end {
+                    let transaction = transactions[start];
+                    match Self::speculate_transaction(&process, store, state, start, transaction) {
+                        // If the transaction succeeded, store it and continue to the next transaction.
+                        Ok(confirmed_transaction) => confirmed.push(confirmed_transaction),
+                        // If the transaction failed, abort the entire batch.
+                        Err(error) => {
+                            eprintln!("Critical bug in speculate: {error}\n\n{transaction}");
                             // Note: This will abort the entire atomic batch.
-                            None => Err("Rejected execute transaction has no fee".to_string()),
-                        },
+                            return Err(format!("Failed to speculate on transaction - {error}"));
+                        }
                     }
-                    // There are no finalize operations here.
-                    // Note: This will abort the entire atomic batch.
-                    Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
-                };
-                lap!(timer, "Speculated on transaction '{}'", transaction.id());
-
-                match outcome {
-                    // If the transaction succeeded, store it and continue to the next transaction.
-                    Ok(confirmed_transaction) => confirmed.push(confirmed_transaction),
-                    // If the transaction failed, abort the entire batch.
+                    lap!(timer, "Speculated on transaction '{}'", transaction.id());
+                    start += 1;
+                    continue;
+                }
+
+                // Otherwise, speculate on the run of transactions in parallel.
+                match Self::speculate_in_parallel(
+                    &process,
+                    store,
+                    state,
+                    start,
+                    &transactions[start..end],
+                    &accesses[start..end],
+                ) {
+                    // If the transactions succeeded, store them and continue to the next transaction.
+                    Ok(confirmed_transactions) => confirmed.extend(confirmed_transactions),
+                    // If the transactions failed, abort the entire batch.
                     Err(error) => {
-                        eprintln!("Critical bug in speculate: {error}\n\n{transaction}");
+                        eprintln!("Critical bug in speculate: {error}");
                         // Note: This will abort the entire atomic batch.
-                        return Err(format!("Failed to speculate on transaction - {error}"));
+                        return Err(format!("Failed to speculate on transactions - {error}"));
                     }
                 }
+                lap!(timer, "Speculated on {} transactions in parallel", end - start);
+                start = end;
             }
 
             // Ensure all transactions were processed.
@@ -174,16 +233,161 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 return Err("Not all transactions were processed in 'VM::atomic_speculate'".to_string());
             }
 
+            // Speculate on the custom ratifications, after the transactions.
+            let ratify_operations = self
+                .ratify_kinds
+                .read()
+                .finalize(store, state, ratifications)
+                .map_err(|error| format!("Failed to speculate on the ratifications - {error}"))?;
+
             finish!(timer);
 
-            // On return, 'atomic_finalize!' will abort the batch, and return the confirmed transactions.
-            Ok(confirmed)
+            // On return, 'atomic_finalize!' will abort the batch, and return the confirmed transactions
+            // and the finalize operations of the ratifications.
+            Ok((confirmed, ratify_operations))
         })
     }
 
-    /// Performs atomic finalization over a list of transactions.
+    /// Speculates on the given transaction, and returns the confirmed transaction.
+    #[rustfmt::skip]
+    fn speculate_transaction<P: FinalizeStorage<N>>(
+        process: &Process<N>,
+        store: &FinalizeStore<N, P>,
+        state: FinalizeGlobalState,
+        index: usize,
+        transaction: &Transaction<N>,
+    ) -> Result<ConfirmedTransaction<N>, String> {
+        // Convert the transaction index to a u32.
+        // Note: On failure, this will abort the entire atomic batch.
+        let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
+
+        // Process the transaction in an isolated atomic batch.
+        // - If the transaction succeeds, the finalize operations are stored.
+        // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
+        match transaction {
+            // The finalize operation here involves appending the 'stack',
+            // and adding the program to the finalize tree.
+            Transaction::Deploy(_, program_owner, deployment, fee) => match process.finalize_deployment(store, deployment, program_owner) {
+                // Construct the accepted deploy transaction.
+                Ok((_, finalize)) => ConfirmedTransaction::accepted_deploy(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
+                // Construct the rejected deploy transaction.
+                Err(_error) => {
+                    // Construct the rejection metadata.
+                    let metadata = RejectionMetadata::from_code(RejectionCode::DeploymentFailed);
+                    // Construct the fee transaction.
+                    // Note: On failure, this will abort the entire atomic batch.
+                    let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
+                    // Construct the rejected deployment.
+                    let rejected = Rejected::new_deployment(*program_owner, *deployment.clone());
+                    // Construct the rejected deploy transaction.
+                    ConfirmedTransaction::rejected_deploy(index, fee_tx, rejected, metadata).map_err(|e| e.to_string())
+                }
+            }
+            // If the execution has expired, reject it, without finalizing it.
+            Transaction::Execute(_, execution, fee) if execution.is_expired(state.block_height()) => {
+                // Construct the rejected execute transaction.
+                Self::reject_execution(index, execution, fee.as_ref(), RejectionMetadata::from_code(RejectionCode::Expired))
+            }
+            // The finalize operation here involves calling 'update_key_value',
+            // and update the respective leaves of the finalize tree.
+            Transaction::Execute(_, execution, fee) => match process.finalize_execution(state, store, execution) {
+                // Construct the accepted execute transaction.
+                Ok(finalize) => ConfirmedTransaction::accepted_execute(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
+                // Construct the rejected execute transaction.
+                Err(error) => Self::reject_execution(index, execution, fee.as_ref(), to_rejection_metadata(&error)),
+            }
+            // There are no finalize operations here.
+            // Note: This will abort the entire atomic batch.
+            Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
+        }
+    }
+
+    /// Speculates on the given executions, which start at the given transaction index, and whose accessed
+    /// mapping keys are given, and returns the confirmed transactions.
+    ///
+    /// The executions are finalized in parallel waves, and their mapping writes are merged in transaction order.
+    fn speculate_in_parallel<P: FinalizeStorage<N>>(
+        process: &Process<N>,
+        store: &FinalizeStore<N, P>,
+        state: FinalizeGlobalState,
+        start: usize,
+        transactions: &[&Transaction<N>],
+        accesses: &[Option<Vec<(parallel::MappingKey<N>, bool)>>],
+    ) -> Result<Vec<ConfirmedTransaction<N>>, String> {
+        // Retrieve the executions, with their accessed mapping keys.
+        let executions = transactions
+            .iter()
+            .zip_eq(accesses)
+            .map(|(transaction, accesses)| match (transaction, accesses) {
+                (Transaction::Execute(_, execution, _), Some(accesses)) => Ok((execution, accesses.as_slice())),
+                _ => Err(format!("Cannot speculate on transaction '{}' in parallel", transaction.id())),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        // Finalize the executions in parallel waves.
+        let outcomes = parallel::finalize_in_waves(process, store, state, &executions).map_err(|e| e.to_string())?;
+
+        // Merge the mapping writes of each execution, in transaction order.
+        transactions
+            .iter()
+            .zip_eq(executions)
+            .zip_eq(outcomes)
+            .enumerate()
+            .map(|(offset, ((transaction, (execution, _)), writes))| {
+                // Convert the transaction index to a u32.
+                // Note: On failure, this will abort the entire atomic batch.
+                let index =
+                    u32::try_from(start + offset).map_err(|_| "Failed to convert transaction index".to_string())?;
+                match writes {
+                    // Construct the accepted execute transaction.
+                    Ok(writes) => {
+                        let finalize = parallel::apply_writes(store, writes).map_err(|e| e.to_string())?;
+                        ConfirmedTransaction::accepted_execute(index, (*transaction).clone(), finalize)
+                            .map_err(|e| e.to_string())
+                    }
+                    // Construct the rejected execute transaction.
+                    Err(metadata) => {
+                        let Transaction::Execute(_, _, fee) = transaction else {
+                            return Err("Expected execute transaction".to_string());
+                        };
+                        Self::reject_execution(index, execution, fee.as_ref(), metadata)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the rejected execute transaction, for the given execution, fee, and rejection metadata.
+    fn reject_execution(
+        index: u32,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+        metadata: RejectionMetadata<N>,
+    ) -> Result<ConfirmedTransaction<N>, String> {
+        match fee {
+            Some(fee) => {
+                // Construct the fee transaction.
+                // Note: On failure, this will abort the entire atomic batch.
+                let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
+                // Construct the rejected execution.
+                let rejected = Rejected::new_execution(execution.clone());
+                // Construct the rejected execute transaction.
+                ConfirmedTransaction::rejected_execute(index, fee_tx, rejected, metadata).map_err(|e| e.to_string())
+            }
+            // This is a foundational bug - the caller is violating protocol rules.
+            // Note: This will abort the entire atomic batch.
+            None => Err("Rejected execute transaction has no fee".to_string()),
+        }
+    }
+
+    /// Performs atomic finalization over a list of transactions and ratifications.
     #[inline]
-    fn atomic_finalize(&self, state: FinalizeGlobalState, transactions: &Transactions<N>) -> Result<()> {
+    fn atomic_finalize(
+        &self,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+        ratifications: &[Ratify<N>],
+    ) -> Result<()> {
         let timer = timer!("VM::atomic_finalize");
 
         // Perform the finalize operation on the preset finalize mode.
@@ -196,145 +400,213 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Retrieve the finalize store.
             let store = self.finalize_store();
 
-            // Initialize a list for the deployed stacks.
-            let mut stacks = Vec::new();
+            // Finalize the transactions and ratifications, and retrieve the deployed stacks.
+            let ratify_kinds = self.ratify_kinds.read();
+            let (stacks, _) =
+                Self::finalize_transactions(&process, store, state, transactions, &ratify_kinds, ratifications)?;
 
-            // Finalize the transactions.
-            for (index, transaction) in transactions.iter().enumerate() {
-                // Convert the transaction index to a u32.
-                // Note: On failure, this will abort the entire atomic batch.
-                let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
-
-                // Process the transaction in an isolated atomic batch.
-                // - If the transaction succeeds, the finalize operations are stored.
-                // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
-                let outcome: Result<(), String> = match transaction {
-                    ConfirmedTransaction::AcceptedDeploy(idx, transaction, finalize) => {
-                        // Ensure the index matches the expected index.
-                        if index != *idx {
-                            // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in accepted deploy transaction index".to_string());
-                        }
-                        // Extract the deployment from the transaction.
-                        let deployment = match transaction {
-                            Transaction::Deploy(_, _, deployment, _) => deployment,
-                            // Note: This will abort the entire atomic batch.
-                            _ => return Err("Expected deploy transaction".to_string()),
-                        };
-                        // The finalize operation here involves appending the 'stack',
-                        // and adding the program to the finalize tree.
-                        match process.finalize_deployment(store, deployment) {
-                            // Ensure the finalize operations match the expected.
-                            Ok((stack, finalize_operations)) => match finalize == &finalize_operations {
-                                // Store the stack.
-                                true => stacks.push(stack),
-                                // Note: This will abort the entire atomic batch.
-                                false => {
-                                    return Err("Mismatch in finalize operations for an accepted deploy".to_string());
-                                }
-                            },
-                            // Note: This will abort the entire atomic batch.
-                            Err(error) => {
-                                return Err(format!("Failed to finalize an accepted deploy transaction - {error}"));
-                            }
-                        };
-                        Ok(())
+            /* Start the commit process. */
+
+            // Commit all of the stacks to the process.
+            if !stacks.is_empty() {
+                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+            }
+
+            finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
+
+            Ok(())
+        })
+    }
+
+    /// Finalizes the given confirmed transactions, followed by the given custom ratifications,
+    /// and returns the stacks of the accepted deployments and the finalize operations of the ratifications.
+    ///
+    /// Note: The stacks are not added to the process, as they must only be committed once the atomic batch succeeds.
+    pub(super) fn finalize_transactions<P: FinalizeStorage<N>>(
+        process: &Process<N>,
+        store: &FinalizeStore<N, P>,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+        ratify_kinds: &RatifyKinds<N, P>,
+        ratifications: &[Ratify<N>],
+    ) -> Result<(Vec<Stack<N>>, Vec<FinalizeOperation<N>>), String> {
+        let timer = timer!("VM::finalize_transactions");
+
+        // Initialize a list for the deployed stacks.
+        let mut stacks = Vec::new();
+
+        // Finalize the transactions.
+        for (index, transaction) in transactions.iter().enumerate() {
+            // Convert the transaction index to a u32.
+            // Note: On failure, this will abort the entire atomic batch.
+            let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
+
+            // Process the transaction in an isolated atomic batch.
+            // - If the transaction succeeds, the finalize operations are stored.
+            // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
+            let outcome: Result<(), String> = match transaction {
+                ConfirmedTransaction::AcceptedDeploy(idx, transaction, finalize) => {
+                    // Ensure the index matches the expected index.
+                    if index != *idx {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Mismatch in accepted deploy transaction index".to_string());
                     }
-                    ConfirmedTransaction::AcceptedExecute(idx, transaction, finalize) => {
-                        // Ensure the index matches the expected index.
-                        if index != *idx {
-                            // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in accepted execute transaction index".to_string());
-                        }
-                        // Extract the execution from the transaction.
-                        let execution = match transaction {
-                            Transaction::Execute(_, execution, _) => execution,
-                            // Note: This will abort the entire atomic batch.
-                            _ => return Err("Expected execute transaction".to_string()),
-                        };
-                        // The finalize operation here involves calling 'update_key_value',
-                        // and update the respective leaves of the finalize tree.
-                        match process.finalize_execution(state, store, execution) {
-                            // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
-                                if finalize != &finalize_operations {
-                                    // Note: This will abort the entire atomic batch.
-                                    return Err("Mismatch in finalize operations for an accepted execute".to_string());
-                                }
-                            }
+                    // Extract the program owner and deployment from the transaction.
+                    let (program_owner, deployment) = match transaction {
+                        Transaction::Deploy(_, program_owner, deployment, _) => (program_owner, deployment),
+                        // Note: This will abort the entire atomic batch.
+                        _ => return Err("Expected deploy transaction".to_string()),
+                    };
+                    // The finalize operation here involves appending the 'stack',
+                    // and adding the program to the finalize tree.
+                    match process.finalize_deployment(store, deployment, program_owner) {
+                        // Ensure the finalize operations match the expected.
+                        Ok((stack, finalize_operations)) => match finalize == &finalize_operations {
+                            // Store the stack.
+                            true => stacks.push(stack),
                             // Note: This will abort the entire atomic batch.
-                            Err(error) => {
-                                return Err(format!("Failed to finalize an accepted execute transaction - {error}"));
+                            false => {
+                                return Err("Mismatch in finalize operations for an accepted deploy".to_string());
                             }
+                        },
+                        // Note: This will abort the entire atomic batch.
+                        Err(error) => {
+                            return Err(format!("Failed to finalize an accepted deploy transaction - {error}"));
                         }
-                        Ok(())
+                    };
+                    Ok(())
+                }
+                ConfirmedTransaction::AcceptedExecute(idx, transaction, finalize) => {
+                    // Ensure the index matches the expected index.
+                    if index != *idx {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Mismatch in accepted execute transaction index".to_string());
                     }
-                    ConfirmedTransaction::RejectedDeploy(idx, _fee_transaction, rejected) => {
-                        // Ensure the index matches the expected index.
-                        if index != *idx {
-                            // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in rejected deploy transaction index".to_string());
+                    // Extract the execution from the transaction.
+                    let execution = match transaction {
+                        Transaction::Execute(_, execution, _) => execution,
+                        // Note: This will abort the entire atomic batch.
+                        _ => return Err("Expected execute transaction".to_string()),
+                    };
+                    // The finalize operation here involves calling 'update_key_value',
+                    // and update the respective leaves of the finalize tree.
+                    match process.finalize_execution(state, store, execution) {
+                        // Ensure the finalize operations match the expected.
+                        Ok(finalize_operations) => {
+                            if finalize != &finalize_operations {
+                                // Note: This will abort the entire atomic batch.
+                                return Err("Mismatch in finalize operations for an accepted execute".to_string());
+                            }
                         }
-                        // Extract the rejected deployment.
-                        let Some(deployment) = rejected.deployment() else {
-                            // Note: This will abort the entire atomic batch.
-                            return Err("Expected rejected deployment".to_string());
-                        };
-                        // TODO (howardwu): Ensure this fee corresponds to the deployment.
-                        // Attempt to finalize the deployment, which should fail.
-                        #[cfg(debug_assertions)]
-                        if let Ok(..) = process.finalize_deployment(store, deployment) {
-                            // Note: This will abort the entire atomic batch.
-                            return Err("Failed to reject a rejected deploy transaction".to_string());
+                        // Note: This will abort the entire atomic batch.
+                        Err(error) => {
+                            return Err(format!("Failed to finalize an accepted execute transaction - {error}"));
                         }
-                        Ok(())
                     }
-                    ConfirmedTransaction::RejectedExecute(idx, _fee_transaction, rejected) => {
-                        // Ensure the index matches the expected index.
-                        if index != *idx {
-                            // Note: This will abort the entire atomic batch.
-                            return Err("Mismatch in rejected execute transaction index".to_string());
-                        }
-                        // Extract the rejected execution.
-                        let Some(execution) = rejected.execution() else {
-                            // Note: This will abort the entire atomic batch.
-                            return Err("Expected rejected execution".to_string());
+                    Ok(())
+                }
+                ConfirmedTransaction::RejectedDeploy(idx, _fee_transaction, rejected, metadata) => {
+                    // Ensure the index matches the expected index.
+                    if index != *idx {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Mismatch in rejected deploy transaction index".to_string());
+                    }
+                    // Extract the rejected program owner and deployment.
+                    let (Some(program_owner), Some(deployment)) = (rejected.program_owner(), rejected.deployment())
+                    else {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Expected rejected deployment".to_string());
+                    };
+                    // TODO (howardwu): Ensure this fee corresponds to the deployment.
+                    // Attempt to finalize the deployment, which should fail.
+                    #[cfg(debug_assertions)]
+                    if let Ok(..) = process.finalize_deployment(store, deployment, program_owner) {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Failed to reject a rejected deploy transaction".to_string());
+                    }
+                    // Ensure the rejection metadata matches the expected, if it is recorded.
+                    if metadata.is_recorded()
+                        && metadata != &RejectionMetadata::from_code(RejectionCode::DeploymentFailed)
+                    {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Mismatch in rejection metadata for a rejected deploy".to_string());
+                    }
+                    Ok(())
+                }
+                ConfirmedTransaction::RejectedExecute(idx, _fee_transaction, rejected, metadata) => {
+                    // Ensure the index matches the expected index.
+                    if index != *idx {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Mismatch in rejected execute transaction index".to_string());
+                    }
+                    // Extract the rejected execution.
+                    let Some(execution) = rejected.execution() else {
+                        // Note: This will abort the entire atomic batch.
+                        return Err("Expected rejected execution".to_string());
+                    };
+                    // TODO (howardwu): Ensure this fee corresponds to the execution.
+                    // Recompute the rejection metadata of the execution.
+                    #[cfg(debug_assertions)]
+                    {
+                        let expected_metadata = match execution.is_expired(state.block_height()) {
+                            // If the execution has expired, it is rejected without being finalized.
+                            true => RejectionMetadata::from_code(RejectionCode::Expired),
+                            // Otherwise, attempt to finalize the execution, which should fail.
+                            false => match process.finalize_execution(state, store, execution) {
+                                // Note: This will abort the entire atomic batch.
+                                Ok(..) => return Err("Failed to reject a rejected execute transaction".to_string()),
+                                Err(error) => to_rejection_metadata(&error),
+                            },
                         };
-                        // TODO (howardwu): Ensure this fee corresponds to the execution.
-                        // Attempt to finalize the execution, which should fail.
-                        #[cfg(debug_assertions)]
-                        if let Ok(..) = process.finalize_execution(state, store, execution) {
+                        // Ensure the rejection metadata matches the expected, if it is recorded.
+                        if metadata.is_recorded() && metadata != &expected_metadata {
                             // Note: This will abort the entire atomic batch.
-                            return Err("Failed to reject a rejected execute transaction".to_string());
+                            return Err("Mismatch in rejection metadata for a rejected execute".to_string());
                         }
-                        Ok(())
-                    }
-                };
-                lap!(timer, "Finalizing transaction {}", transaction.id());
-
-                match outcome {
-                    // If the transaction succeeded to finalize, continue to the next transaction.
-                    Ok(()) => (),
-                    // If the transaction failed to finalize, abort and continue to the next transaction.
-                    Err(error) => {
-                        eprintln!("Critical bug in finalize: {error}\n\n{transaction}");
-                        // Note: This will abort the entire atomic batch.
-                        return Err(format!("Failed to finalize on transaction - {error}"));
                     }
+                    Ok(())
+                }
+            };
+            lap!(timer, "Finalizing transaction {}", transaction.id());
+
+            match outcome {
+                // If the transaction succeeded to finalize, continue to the next transaction.
+                Ok(()) => (),
+                // If the transaction failed to finalize, abort and continue to the next transaction.
+                Err(error) => {
+                    eprintln!("Critical bug in finalize: {error}\n\n{transaction}");
+                    // Note: This will abort the entire atomic batch.
+                    return Err(format!("Failed to finalize on transaction - {error}"));
                 }
             }
+        }
 
-            /* Start the commit process. */
+        // Finalize the custom ratifications.
+        let ratify_operations = ratify_kinds
+            .finalize(store, state, ratifications)
+            .map_err(|error| format!("Failed to finalize the ratifications - {error}"))?;
 
-            // Commit all of the stacks to the process.
-            if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
-            }
+        // Record the updated mapping values in the history, at this block height.
+        #[cfg(feature = "history")]
+        store
+            .record_history(state.block_height())
+            .map_err(|error| format!("Failed to record the history - {error}"))?;
 
-            finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
+        finish!(timer);
 
-            Ok(())
-        })
+        Ok((stacks, ratify_operations))
+    }
+}
+
+/// Returns the rejection metadata for the given error, from finalizing an execution.
+pub(super) fn to_rejection_metadata<N: Network>(error: &Error) -> RejectionMetadata<N> {
+    match error.downcast_ref::<FinalizeFailure<N>>() {
+        // If a command failed, record the failed command.
+        Some(failure) => {
+            failure.to_rejection_metadata().unwrap_or_else(|_| RejectionMetadata::from_code(failure.code()))
+        }
+        // Otherwise, the execution failed before a command was evaluated.
+        None => RejectionMetadata::from_code(RejectionCode::ExecutionFailed),
     }
 }
 
@@ -345,6 +617,8 @@ mod tests {
         store::helpers::memory::ConsensusMemory,
         vm::{test_helpers, test_helpers::sample_finalize_state},
         Block,
+        FinalizeEvent,
+        FinalizeOperation,
         Header,
         Metadata,
         Program,
@@ -588,18 +862,51 @@ finalize transfer_public:
         create_execution(vm, caller_private_key, program_id, "transfer_public", inputs, unspent_records, rng)
     }
 
-    /// A helper method to construct the rejected transaction format for `atomic_finalize`.
+    /// A helper method to construct the rejected transaction format for `atomic_finalize`,
+    /// for a `transfer_public` that halts on the `sub` command in its finalize scope.
     fn reject(index: u32, transaction: &Transaction<CurrentNetwork>) -> ConfirmedTransaction<CurrentNetwork> {
         match transaction {
-            Transaction::Execute(_, execution, fee) => ConfirmedTransaction::RejectedExecute(
-                index,
-                Transaction::from_fee(fee.clone().unwrap()).unwrap(),
-                Rejected::new_execution(execution.clone()),
-            ),
+            Transaction::Execute(_, execution, fee) => {
+                // Construct the rejection metadata of the halted `sub` command.
+                let transition = execution.peek().unwrap();
+                let command = Some((*transition.program_id(), *transition.function_name(), 2));
+                let metadata = RejectionMetadata::new(RejectionCode::CommandHalted, command, None).unwrap();
+
+                ConfirmedTransaction::RejectedExecute(
+                    index,
+                    Transaction::from_fee(fee.clone().unwrap()).unwrap(),
+                    Rejected::new_execution(execution.clone()),
+                    metadata,
+                )
+            }
             _ => panic!("only reject execution transactions"),
         }
     }
 
+    /// Speculates on the given transactions sequentially, which is the reference for the parallel speculation.
+    fn speculate_sequentially(
+        vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
+        state: FinalizeGlobalState,
+        transactions: &[Transaction<CurrentNetwork>],
+    ) -> Result<Vec<ConfirmedTransaction<CurrentNetwork>>> {
+        atomic_finalize!(vm.finalize_store(), FinalizeMode::DryRun, {
+            let process = vm.process.write();
+            transactions
+                .iter()
+                .enumerate()
+                .map(|(index, transaction)| {
+                    VM::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::speculate_transaction(
+                        &process,
+                        vm.finalize_store(),
+                        state,
+                        index,
+                        transaction,
+                    )
+                })
+                .collect()
+        })
+    }
+
     #[test]
     fn test_finalize_duplicate_deployment() {
         let rng = &mut TestRng::default();
@@ -620,13 +927,13 @@ finalize transfer_public:
         assert!(!vm.contains_program(&program_id));
 
         // Finalize the transaction.
-        assert!(vm.finalize(sample_finalize_state(1), &confirmed_transactions).is_ok());
+        assert!(vm.finalize(sample_finalize_state(1), &confirmed_transactions, &[]).is_ok());
 
         // Ensure the VM contains this program.
         assert!(vm.contains_program(&program_id));
 
         // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(sample_finalize_state(1), &confirmed_transactions).is_err());
+        assert!(vm.finalize(sample_finalize_state(1), &confirmed_transactions, &[]).is_err());
 
         // Ensure the VM contains this program.
         assert!(vm.contains_program(&program_id));
@@ -802,6 +1109,127 @@ finalize transfer_public:
         }
     }
 
+    #[test]
+    fn test_speculate_parallel_matches_sequential() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Sample a private key and address for the recipient.
+        let recipient_private_key = PrivateKey::new(rng).unwrap();
+        let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next blocks.
+        let mut previous_block = deployment_block;
+        for _ in 0..2 {
+            let splits_block =
+                generate_splits(&vm, &caller_private_key, &previous_block, &mut unspent_records, rng).unwrap();
+            vm.add_next_block(&splits_block).unwrap();
+            previous_block = splits_block;
+        }
+
+        // Construct the initial mint.
+        let initial_mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let initial_mint_block =
+            sample_next_block(&vm, &caller_private_key, &[initial_mint], &previous_block, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&initial_mint_block).unwrap();
+
+        // Construct the mints and transfers.
+        let mut mint = |recipient, amount, rng: &mut TestRng| {
+            sample_mint_public(&vm, caller_private_key, &program_id, recipient, amount, &mut unspent_records, rng)
+        };
+        let mint_10 = mint(caller_address, 10, rng);
+        let mint_5 = mint(recipient_address, 5, rng);
+        let mint_20 = mint(caller_address, 20, rng);
+        let mut transfer = |amount, rng: &mut TestRng| {
+            sample_transfer_public(
+                &vm,
+                caller_private_key,
+                &program_id,
+                recipient_address,
+                amount,
+                &mut unspent_records,
+                rng,
+            )
+        };
+        let transfer_20 = transfer(20, rng);
+        let transfer_30 = transfer(30, rng);
+        let transfer_10 = transfer(10, rng);
+        let transfer_20_again = transfer(20, rng);
+
+        // Construct a deployment, which is speculated on sequentially, and splits the executions into two runs.
+        let deployment = test_helpers::sample_deployment_transaction(rng);
+
+        // Starting Balance = 20
+        // Run 1:
+        //   Wave 0: Mint_10 -> Balance = 20 + 10 = 30, and Mint_5 to the recipient, which writes to a distinct key
+        //   Wave 1: Transfer_20 -> Balance = 30 - 20 = 10
+        //   Wave 2: Transfer_30 -> Balance = 10 - 30 = -20 (should be rejected)
+        //   Wave 3: Transfer_10 -> Balance = 10 - 10 = 0
+        // Deployment
+        // Run 2:
+        //   Wave 0: Mint_20 -> Balance = 0 + 20 = 20
+        //   Wave 1: Transfer_20 -> Balance = 20 - 20 = 0
+        let transactions = [
+            mint_10,
+            mint_5,
+            transfer_20,
+            transfer_30.clone(),
+            transfer_10,
+            deployment.clone(),
+            mint_20,
+            transfer_20_again,
+        ];
+
+        // Ensure the accessed mapping keys of the executions are known, so that they are speculated on in parallel.
+        for transaction in transactions.iter().filter(|transaction| transaction.is_execute()) {
+            assert!(parallel::finalize_accesses(&vm.process.read(), transaction).is_some());
+        }
+
+        // Speculate on the transactions, in parallel and sequentially.
+        let state = sample_finalize_state(1);
+        let parallel = vm.atomic_speculate(state, transactions.iter()).unwrap();
+        let sequential = speculate_sequentially(&vm, state, &transactions).unwrap();
+
+        // Ensure the confirmed transactions, including their finalize operations, are the same.
+        assert_eq!(parallel, sequential);
+
+        // Ensure the transfer in the middle wave is rejected, and the other transactions are accepted.
+        assert_eq!(parallel.len(), transactions.len());
+        for (index, confirmed) in parallel.iter().enumerate() {
+            match index {
+                3 => assert_eq!(confirmed, &reject(3, &transfer_30)),
+                _ => assert!(confirmed.is_accepted()),
+            }
+        }
+        assert!(matches!(parallel[5], ConfirmedTransaction::AcceptedDeploy(..)));
+        assert_eq!(parallel[5].transaction(), &deployment);
+    }
+
     #[test]
     fn test_finalize_catch_halt() {
         let rng = &mut TestRng::default();
@@ -885,16 +1313,230 @@ function ped_hash:
             assert_eq!(confirmed_transactions.len(), 1);
             assert!(transaction.is_execute());
             if let Transaction::Execute(_, execution, fee) = transaction {
-                let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
-                let expected_confirmed_transaction =
-                    ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution));
-
                 let confirmed_transaction = confirmed_transactions.iter().next().unwrap();
+                // Ensure the rejection metadata records the failed command.
+                let metadata = confirmed_transaction.rejection_metadata().unwrap().clone();
+                assert!(metadata.command().is_some());
+
+                let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
+                let expected_confirmed_transaction = ConfirmedTransaction::RejectedExecute(
+                    0,
+                    fee_transaction.clone(),
+                    Rejected::new_execution(execution.clone()),
+                    metadata,
+                );
                 assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
+
+                // Ensure finalize fails on a rejected transaction with mismatched rejection metadata.
+                #[cfg(debug_assertions)]
+                {
+                    let mismatched_transaction = ConfirmedTransaction::RejectedExecute(
+                        0,
+                        fee_transaction.clone(),
+                        Rejected::new_execution(execution.clone()),
+                        RejectionMetadata::from_code(RejectionCode::ExecutionFailed),
+                    );
+                    let mismatched_transactions = [mismatched_transaction].into_iter().collect();
+                    assert!(vm.finalize(sample_finalize_state(1), &mismatched_transactions, &[]).is_err());
+                }
+
+                // Ensure finalize skips the comparison on a rejected transaction without recorded rejection metadata.
+                let unrecorded_transaction = ConfirmedTransaction::RejectedExecute(
+                    0,
+                    fee_transaction,
+                    Rejected::new_execution(execution),
+                    RejectionMetadata::from_code(RejectionCode::Unknown),
+                );
+                let unrecorded_transactions = [unrecorded_transaction].into_iter().collect();
+                vm.finalize(sample_finalize_state(1), &unrecorded_transactions, &[]).unwrap();
             }
         }
     }
 
+    #[test]
+    fn test_trace_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, view key, and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Create a program whose finalize aborts after writing to a mapping.
+        let program_id = "testing.aleo";
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "
+program {program_id};
+
+mapping counts:
+    key owner as u128.public;
+    value count as u128.public;
+
+function divide:
+    input r0 as u128.public;
+    finalize r0;
+
+finalize divide:
+    input r0 as u128.public;
+    get.or_use counts[r0] 0u128 into r1;
+    add r1 1u128 into r2;
+    set r2 into counts[r0];
+    div r0 r1 into r3;"
+        ))
+        .unwrap();
+
+        let credits = unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap();
+        let additional_fee = (credits, 10);
+
+        // Deploy the program.
+        let deployment_transaction = vm.deploy(&caller_private_key, &program, additional_fee, None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &caller_private_key, &[deployment_transaction], &genesis, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Construct a transaction that divides by zero in the finalize execution.
+        let inputs = vec![Value::<CurrentNetwork>::from_str("1u128").unwrap()];
+        let transaction =
+            create_execution(&vm, caller_private_key, program_id, "divide", inputs, &mut unspent_records, rng);
+        let Transaction::Execute(_, execution, _) = transaction else { unreachable!() };
+
+        // Trace the finalize execution.
+        let trace = vm.trace_finalize(sample_finalize_state(1), &execution).unwrap();
+
+        // Ensure every command is traced, up to and including the aborting command.
+        let commands = trace.commands().map(|command| command.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "get.or_use counts[r0] 0u128 into r1;",
+                "add r1 1u128 into r2;",
+                "set r2 into counts[r0];",
+                "div r0 r1 into r3;"
+            ],
+            commands
+        );
+        // Ensure the mapping read and write, and the register writes, are traced.
+        let key = Plaintext::from_str("1u128").unwrap();
+        assert!(trace.events().iter().any(|event| matches!(event,
+            FinalizeEvent::MappingRead { key: candidate, value: None, .. } if *candidate == key)));
+        assert!(trace.events().iter().any(|event| matches!(event,
+            FinalizeEvent::MappingWrite { key: candidate, value: Some(value), .. }
+                if *candidate == key && *value == Value::from_str("1u128").unwrap())));
+        assert!(trace.events().iter().any(|event| matches!(event,
+            FinalizeEvent::RegisterWrite { value, .. } if *value == Value::from_str("1u128").unwrap())));
+        // Ensure the trace ends with the abort.
+        assert!(trace.abort_error().is_some());
+        // Ensure the steps are metered up to and including the aborting command.
+        assert_eq!(
+            trace.steps(),
+            CurrentNetwork::FINALIZE_READ_STEPS
+                + 2 * CurrentNetwork::FINALIZE_INSTRUCTION_STEPS
+                + CurrentNetwork::FINALIZE_WRITE_STEPS
+        );
+
+        // Ensure the dry run did not update storage.
+        let mapping_name = Identifier::from_str("counts").unwrap();
+        let program_id = ProgramID::from_str(program_id).unwrap();
+        assert!(vm.finalize_store().get_value_confirmed(&program_id, &mapping_name, &key).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_simulate_rejection_reason() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and view key for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Create a program whose finalize aborts when the key does not exist.
+        let program_id = "testing.aleo";
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "
+program {program_id};
+
+mapping counts:
+    key owner as u128.public;
+    value count as u128.public;
+
+function increment:
+    input r0 as u128.public;
+    finalize r0;
+
+finalize increment:
+    input r0 as u128.public;
+    get counts[r0] into r1;
+    add r1 1u128 into r2;
+    set r2 into counts[r0];"
+        ))
+        .unwrap();
+
+        // Deploy the program.
+        let credits = unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap();
+        let deployment_transaction = vm.deploy(&caller_private_key, &program, (credits, 10), None, rng).unwrap();
+        let outcome = vm.simulate(sample_finalize_state(1), &deployment_transaction).unwrap();
+        assert!(outcome.is_accepted());
+        assert_eq!(Some(0), outcome.steps());
+        let deployment_block =
+            sample_next_block(&vm, &caller_private_key, &[deployment_transaction], &genesis, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Construct a transaction that reads a missing key in the finalize execution.
+        let inputs = vec![Value::<CurrentNetwork>::from_str("1u128").unwrap()];
+        let transaction =
+            create_execution(&vm, caller_private_key, program_id, "increment", inputs, &mut unspent_records, rng);
+
+        // Ensure the simulation rejects the transaction, with the failed command and mapping key.
+        let outcome = vm.simulate(sample_finalize_state(1), &transaction).unwrap();
+        assert!(outcome.is_rejected());
+        let Some(RejectionReason::CommandFailed(failure)) = outcome.reason() else {
+            panic!("Expected a failed command, found '{outcome:?}'")
+        };
+        assert_eq!(&ProgramID::from_str(program_id).unwrap(), failure.program_id());
+        assert_eq!(&Identifier::from_str("increment").unwrap(), failure.function_name());
+        assert_eq!(0, failure.index());
+        assert_eq!("get counts[r0] into r1;", failure.command().to_string());
+        let (mapping_name, key) = failure.mapping_key().unwrap();
+        assert_eq!(&Identifier::from_str("counts").unwrap(), mapping_name);
+        assert_eq!(&Plaintext::from_str("1u128").unwrap(), key);
+
+        // Ensure the outcome matches speculation.
+        let confirmed_transactions = vm.speculate(sample_finalize_state(1), [transaction.clone()].iter()).unwrap();
+        assert!(confirmed_transactions.iter().next().unwrap().is_rejected());
+
+        // Ensure a fee transaction is aborted.
+        let Transaction::Execute(_, _, Some(fee)) = transaction else { unreachable!() };
+        let outcome = vm.simulate(sample_finalize_state(1), &Transaction::from_fee(fee).unwrap()).unwrap();
+        assert!(outcome.is_aborted());
+    }
+
     #[test]
     fn test_rejected_transaction_should_not_update_storage() {
         let rng = &mut TestRng::default();
@@ -994,11 +1636,10 @@ finalize compute:
             .get_value_speculative(&program_id, &mapping_name, &Plaintext::from(Literal::Address(address)))
             .unwrap();
         println!("{:?}", value);
-        assert!(
-            !vm.finalize_store()
-                .contains_key_confirmed(&program_id, &mapping_name, &Plaintext::from(Literal::Address(address)))
-                .unwrap()
-        );
+        assert!(!vm
+            .finalize_store()
+            .contains_key_confirmed(&program_id, &mapping_name, &Plaintext::from(Literal::Address(address)))
+            .unwrap());
 
         // Create an execution transaction, that will be rejected.
         let r0 = Value::<CurrentNetwork>::from_str("100u8").unwrap();
@@ -1027,4 +1668,136 @@ finalize compute:
         let expected = Value::<CurrentNetwork>::from_str("3u8").unwrap();
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn test_finalize_clear_rejects_non_admin() {
+        let rng = &mut TestRng::default();
+
+        // Sample the private key of the admin, and a private key for a non-admin.
+        let admin_private_key = test_helpers::sample_genesis_private_key(rng);
+        let admin = Address::try_from(&admin_private_key).unwrap();
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let other_view_key = ViewKey::try_from(&other_private_key).unwrap();
+        let other = Address::try_from(&other_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &admin_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Deploy a program that clears its mapping with the caller as the admin.
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+program clearable.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into account[r0];
+
+function reset:
+    finalize self.caller;
+
+finalize reset:
+    input r0 as address.public;
+    clear account r0;",
+        )
+        .unwrap();
+        let view_key = ViewKey::try_from(&admin_private_key).unwrap();
+        let credits = unspent_records.pop().unwrap().decrypt(&view_key).unwrap();
+        let transaction = vm.deploy(&admin_private_key, &program, (credits, 10), None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &admin_private_key, &[transaction], &splits_block, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Ensure the deployment registers the admin, as the program uses `clear`, and the finalize root commits to it.
+        let program_id = ProgramID::from_str("clearable.aleo").unwrap();
+        let program_id_field = CurrentNetwork::hash_bhp1024(&program_id.to_bits_le()).unwrap();
+        let register_admin = FinalizeOperation::RegisterAdmin(program_id_field, admin.to_field().unwrap());
+        let deployment = deployment_block.transactions().iter().next().unwrap();
+        assert_eq!(Some(&register_admin), deployment.finalize_operations().unwrap().last());
+
+        // Mint an entry, and transfer credits to the non-admin to pay for its fee.
+        let mint = sample_mint_public(&vm, admin_private_key, "clearable.aleo", admin, 10, &mut unspent_records, rng);
+        let record = unspent_records.pop().unwrap().decrypt(&view_key).unwrap();
+        let inputs = vec![
+            Value::Record(record),
+            Value::from_str(&other.to_string()).unwrap(),
+            Value::from_str("100000000u64").unwrap(),
+        ];
+        let transfer = create_execution(
+            &vm,
+            admin_private_key,
+            "credits.aleo",
+            "transfer_private",
+            inputs,
+            &mut unspent_records,
+            rng,
+        );
+        let next_block =
+            sample_next_block(&vm, &admin_private_key, &[mint, transfer], &deployment_block, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&next_block).unwrap();
+
+        // Move the record of the non-admin to the end of the unspent records.
+        let index = unspent_records.iter().position(|record| record.is_owner(&other_view_key)).unwrap();
+        let record = unspent_records.remove(index);
+        unspent_records.push(record);
+
+        // Ensure the non-admin cannot clear the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(admin));
+        let transaction =
+            create_execution(&vm, other_private_key, "clearable.aleo", "reset", vec![], &mut unspent_records, rng);
+        let next_block =
+            sample_next_block(&vm, &admin_private_key, &[transaction], &next_block, &mut unspent_records, rng).unwrap();
+        assert!(next_block.transactions().iter().next().unwrap().is_rejected());
+        vm.add_next_block(&next_block).unwrap();
+        assert!(vm.finalize_store().contains_key_confirmed(&program_id, &mapping_name, &key).unwrap());
+
+        // Ensure the admin can clear the mapping.
+        let transaction =
+            create_execution(&vm, admin_private_key, "clearable.aleo", "reset", vec![], &mut unspent_records, rng);
+        let next_block =
+            sample_next_block(&vm, &admin_private_key, &[transaction], &next_block, &mut unspent_records, rng).unwrap();
+        assert!(next_block.transactions().iter().next().unwrap().is_accepted());
+        vm.add_next_block(&next_block).unwrap();
+        assert!(!vm.finalize_store().contains_key_confirmed(&program_id, &mapping_name, &key).unwrap());
+        assert!(vm.finalize_store().contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+
+        // Ensure the finalize root of the block commits to the cleared mapping.
+        let mapping_id = CurrentNetwork::hash_bhp1024(&(program_id, mapping_name).to_bits_le()).unwrap();
+        let confirmed = next_block.transactions().iter().next().unwrap();
+        assert_eq!(Some(&vec![FinalizeOperation::ReplaceMapping(mapping_id)]), confirmed.finalize_operations());
+        assert_eq!(next_block.finalize_root(), next_block.transactions().to_finalize_root().unwrap());
+        let uncleared = Transactions::from_iter([ConfirmedTransaction::accepted_execute(
+            confirmed.index(),
+            confirmed.transaction().clone(),
+            vec![],
+        )
+        .unwrap()]);
+        assert_ne!(next_block.finalize_root(), uncleared.to_finalize_root().unwrap());
+    }
 }
@@ -19,24 +19,54 @@ mod deploy;
 mod execute;
 mod execute_fee;
 mod finalize;
+mod parallel;
+mod priority_fee;
+mod ratify;
+mod replay;
+mod simulate;
 mod verify;
 
 pub use finalize::FinalizeMode;
+pub use ratify::{RatifyKind, RatifyKinds};
+pub use replay::{BlockReplay, ReplayMismatch};
+pub use simulate::{RejectionReason, SimulationOutcome};
 
 use crate::{
     atomic_finalize,
-    block::{Block, ConfirmedTransaction, Deployment, Execution, Fee, Header, Transaction, Transactions},
+    block::{
+        Block,
+        ConfirmedTransaction,
+        Deployment,
+        Execution,
+        Fee,
+        Header,
+        Ratify,
+        Sponsorship,
+        Transaction,
+        Transactions,
+    },
     cast_mut_ref,
     cast_ref,
     process,
-    process::{Authorization, FinalizeGlobalState, Process, Program, Query, Trace},
+    process::{Authorization, FinalizeGlobalState, FinalizeTrace, Process, Program, Query, Trace},
     store::{BlockStore, ConsensusStorage, ConsensusStore, FinalizeStore, TransactionStore, TransitionStore},
-    TransactionStorage,
 };
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Entry, Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Response, Value},
+    program::{
+        Entry,
+        Identifier,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        ProgramOwner,
+        Record,
+        Response,
+        Value,
+        RATIFICATIONS_DEPTH,
+    },
     types::Field,
 };
 
@@ -50,6 +80,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The registered ratification kinds.
+    ratify_kinds: Arc<RwLock<RatifyKinds<N, C::FinalizeStorage>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -69,62 +101,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
-        // A helper function to load the program into the process, and recursively load all imports.
-        fn load_deployment_and_imports<N: Network, T: TransactionStorage<N>>(
-            process: &mut Process<N>,
-            transaction_store: &TransactionStore<N, T>,
-            transaction_id: N::TransactionID,
-        ) -> Result<()> {
-            // Retrieve the deployment from the transaction id.
-            let deployment = match transaction_store.get_deployment(&transaction_id)? {
-                Some(deployment) => deployment,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
-            };
-
-            // Fetch the program from the deployment.
-            let program = deployment.program();
-            let program_id = program.id();
-
-            // Return early if the program is already loaded.
-            if process.contains_program(program_id) {
-                return Ok(());
-            }
-
-            // Iterate through the program imports.
-            for import_program_id in program.imports().keys() {
-                // Add the imports to the process if does not exist yet.
-                if !process.contains_program(import_program_id) {
-                    // Fetch the deployment transaction id.
-                    let Some(transaction_id) = transaction_store
-                        .deployment_store()
-                        .find_transaction_id_from_program_id(import_program_id)?
-                    else {
-                        bail!("Transaction id for '{program_id}' is not found in storage.");
-                    };
-
-                    // Recursively load the deployment and its imports.
-                    load_deployment_and_imports(process, transaction_store, transaction_id)?
-                }
-            }
-
-            // Load the deployment if it does not exist in the process yet.
-            if !process.contains_program(program_id) {
-                process.load_deployment(&deployment)?;
-            }
-
-            Ok(())
-        }
-
         // Retrieve the transaction store.
         let transaction_store = store.transaction_store();
-        // Load the deployments from the store.
-        for transaction_id in transaction_store.deployment_transaction_ids() {
-            // Load the deployment and its imports.
-            load_deployment_and_imports(&mut process, transaction_store, *transaction_id)?;
-        }
+        // Retrieve the deployments from the store.
+        let deployments = transaction_store
+            .deployment_transaction_ids()
+            .map(|transaction_id| match transaction_store.get_deployment(&transaction_id)? {
+                Some(deployment) => Ok(deployment),
+                None => bail!("Deployment transaction '{}' is not found in storage.", *transaction_id),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Load the deployments, in the order of their imports.
+        process.load_deployments(&deployments)?;
 
         // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(Self { process: Arc::new(RwLock::new(process)), store, ratify_kinds: Default::default() })
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -138,6 +129,31 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn process(&self) -> Arc<RwLock<Process<N>>> {
         self.process.clone()
     }
+
+    /// Registers the given ratification kind, so blocks may include custom ratifications of this kind.
+    #[inline]
+    pub fn register_ratify_kind(&self, kind: impl RatifyKind<N, C::FinalizeStorage> + 'static) -> Result<()> {
+        self.ratify_kinds.write().register(kind)
+    }
+
+    /// Checks the custom ratifications in the given block are of a registered kind, and are valid.
+    #[inline]
+    pub fn check_ratifications(&self, block: &Block<N>) -> Result<()> {
+        self.ratify_kinds.read().check(block)
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given block height.
+    /// If the key did not exist at the given block height, `None` is returned.
+    #[cfg(feature = "history")]
+    pub fn get_mapping_value_at(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.finalize_store().get_value_at_height_confirmed(program_id, mapping_name, key, height)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -220,7 +236,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // First, insert the block.
         self.block_store().insert(block)?;
         // Next, finalize the transactions.
-        match self.finalize(state, block.transactions()) {
+        match self.finalize(state, block.transactions(), block.ratifications()) {
             Ok(_) => {
                 // TODO (howardwu): Check the accepted, rejected, and finalize operations match the block.
                 Ok(())
@@ -233,13 +249,136 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
     }
+
+    /// Adds the given contiguous blocks into the VM, in one atomic batch.
+    ///
+    /// Each block is checked before it is added, and its transactions are verified against the earlier blocks
+    /// in the batch, so the programs deployed in earlier blocks are available to the later blocks.
+    /// The finalize root of each block is checked once the block is finalized.
+    /// If any block fails to be checked, stored, or finalized, none of the blocks are added.
+    pub fn add_next_blocks(&self, blocks: &[Block<N>]) -> Result<()> {
+        let timer = timer!("VM::add_next_blocks");
+
+        // Ensure there is at least one block.
+        ensure!(!blocks.is_empty(), "Cannot add an empty list of blocks");
+        // Ensure the first block follows the latest block in storage.
+        let first = &blocks[0];
+        match self.block_store().current_block_height() {
+            Some(latest_height) => {
+                ensure!(
+                    latest_height.checked_add(1) == Some(first.height()),
+                    "Block {} does not follow the latest block {latest_height}",
+                    first.height()
+                );
+                let Some(previous_hash) = self.block_store().get_block_hash(latest_height)? else {
+                    bail!("Missing the block hash for the latest block {latest_height}")
+                };
+                ensure!(
+                    first.previous_hash() == previous_hash,
+                    "Block {} does not follow the latest block",
+                    first.height()
+                );
+            }
+            None => ensure!(first.height() == 0, "Block {} does not follow the empty block store", first.height()),
+        }
+        // Ensure the blocks are contiguous.
+        for (previous, block) in blocks.iter().tuple_windows() {
+            ensure!(
+                previous.height().checked_add(1) == Some(block.height()) && block.previous_hash() == previous.hash(),
+                "Block {} does not follow block {}",
+                block.height(),
+                previous.height()
+            );
+        }
+        // Ensure the blocks are well-formed, and their transactions are unique.
+        self.check_next_blocks(blocks)?;
+        // Ensure that there is no atomic batch write in progress.
+        ensure!(!self.store.is_atomic_in_progress(), "Cannot add blocks while another atomic batch is in progress");
+        lap!(timer, "Check the blocks");
+
+        // Note: The deployed stacks are added to the process after each block, so that the later blocks are verified
+        // against them, and the process is restored from this copy if the atomic batch is aborted.
+        let previous_process = self.process.read().clone();
+
+        // Start the atomic batch, for both the block store and the finalize store.
+        self.store.start_atomic();
+
+        // Verify, insert, and finalize each block.
+        let mut num_inserted = 0u32;
+        let outcome = blocks.iter().try_for_each(|block| {
+            // Verify the transactions, where the state roots of the earlier blocks in the batch are accepted.
+            for transaction in block.transactions().iter() {
+                // Construct the rejected ID.
+                let rejected_id = match transaction {
+                    ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => None,
+                    ConfirmedTransaction::RejectedDeploy(_, _, rejected, _) => Some(rejected.to_id()?),
+                    ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => Some(rejected.to_id()?),
+                };
+                self.check_transaction_speculative(transaction, rejected_id).map_err(|error| {
+                    anyhow!("Invalid transaction '{}' in block {}: {error}", transaction.id(), block.height())
+                })?;
+            }
+
+            // Construct the finalize state.
+            let state = FinalizeGlobalState::new::<N>(
+                block.round(),
+                block.height(),
+                block.cumulative_weight(),
+                block.cumulative_proof_target(),
+                block.previous_hash(),
+            )?;
+            // Next, insert the block.
+            self.block_store().insert(block)?;
+            num_inserted += 1;
+            // Next, finalize the transactions.
+            let (stacks, ratify_operations) = Self::finalize_transactions(
+                &self.process.read(),
+                self.finalize_store(),
+                state,
+                block.transactions(),
+                &self.ratify_kinds.read(),
+                block.ratifications(),
+            )
+            .map_err(|error| anyhow!("Failed to finalize transactions: {error}"))?;
+            // Ensure the finalize root matches the transactions, followed by the custom ratifications.
+            let expected_finalize_root = block.transactions().to_finalize_root_with_ratifications(&ratify_operations)?;
+            ensure!(
+                block.finalize_root() == expected_finalize_root,
+                "Invalid finalize root for block {}: expected '{expected_finalize_root}', got '{}'",
+                block.height(),
+                block.finalize_root()
+            );
+            // Lastly, add the deployed stacks, for the later blocks.
+            let mut process = self.process.write();
+            stacks.into_iter().for_each(|stack| process.add_stack(stack));
+            lap!(timer, "Add block {}", block.height());
+            Ok(())
+        });
+
+        // Commit the atomic batch, or abort it on failure.
+        match outcome.and_then(|_| self.store.finish_atomic()) {
+            Ok(()) => {
+                finish!(timer);
+                Ok(())
+            }
+            Err(error) => {
+                self.store.abort_atomic();
+                // Rollback the block tree, as the blocks are not committed.
+                self.block_store().rewind_tree(num_inserted)?;
+                // Restore the process, without the stacks deployed in the batch.
+                *self.process.write() = previous_process;
+                finish!(timer);
+                Err(error)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
     use crate::{
-        block::{Block, Fee, Header, Metadata, Transition},
+        block::{Block, Fee, Header, Metadata, Output, Transition},
         process::Program,
         store::helpers::memory::ConsensusMemory,
     };
@@ -886,4 +1025,170 @@ function multitransfer:
             .unwrap();
         vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[execution], rng).unwrap()).unwrap();
     }
+
+    #[test]
+    fn test_audited_program() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the auditor.
+        let auditor_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let auditor_view_key = ViewKey::try_from(&auditor_private_key).unwrap();
+        let auditor = Address::try_from(&auditor_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Fetch the unspent records.
+        let records =
+            genesis.transitions().cloned().flat_map(Transition::into_records).take(3).collect::<IndexMap<_, _>>();
+        let record_0 = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+        let record_1 = records.values().nth(1).unwrap().decrypt(&caller_view_key).unwrap();
+        let record_2 = records.values().nth(2).unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy a program with an auditor.
+        let program = Program::from_str(&format!(
+            r"
+program test_audited_program.aleo;
+
+auditor {auditor};
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;
+
+function spend:
+    input r0 as token.record;
+    cast r0.owner r0.amount into r1 as token.record;
+    output r1 as token.record;
+    "
+        ))
+        .unwrap();
+        let deployment = vm.deploy(&caller_private_key, &program, (record_0, 1), None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Mint a token.
+        let inputs = [Value::<CurrentNetwork>::from_str("100u64").unwrap()];
+        let execution = vm
+            .execute(
+                &caller_private_key,
+                ("test_audited_program.aleo", "mint"),
+                inputs.into_iter(),
+                Some((record_1, 1)),
+                None,
+                rng,
+            )
+            .unwrap();
+        assert!(vm.verify_transaction(&execution, None));
+
+        // Ensure the token is an audited record, which the owner and the auditor decrypt to the same record.
+        let output = execution.transitions().next().unwrap().outputs()[0].clone();
+        assert!(matches!(output, Output::AuditedRecord(_, _, Some(_))));
+        let token = output.record().unwrap().1.decrypt(&caller_view_key).unwrap();
+        assert_eq!(token, output.auditor_record().unwrap().decrypt(&auditor_view_key).unwrap());
+        vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[execution], rng).unwrap()).unwrap();
+
+        // Ensure the audited record is spent as any other record.
+        let inputs = [Value::<CurrentNetwork>::Record(token)];
+        let execution = vm
+            .execute(
+                &caller_private_key,
+                ("test_audited_program.aleo", "spend"),
+                inputs.into_iter(),
+                Some((record_2, 1)),
+                None,
+                rng,
+            )
+            .unwrap();
+        assert!(vm.verify_transaction(&execution, None));
+        vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[execution], rng).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_add_next_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Fetch the unspent records.
+        let records =
+            genesis.transitions().cloned().flat_map(Transition::into_records).take(2).collect::<IndexMap<_, _>>();
+        let record_0 = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+        let record_1 = records.values().nth(1).unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Initialize the VM, and add the blocks one at a time.
+        let vm = sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let program = Program::from_str(
+            r"
+program test_add_next_blocks.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;
+    ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&caller_private_key, &program, (record_0, 1), None, rng).unwrap();
+        let deployment_block = sample_next_block(&vm, &caller_private_key, &[deployment], rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Execute the program.
+        let inputs = [Value::<Testnet3>::from_str("10u64").unwrap()];
+        let execution = vm
+            .execute(
+                &caller_private_key,
+                ("test_add_next_blocks.aleo", "compute"),
+                inputs.into_iter(),
+                Some((record_1, 1)),
+                None,
+                rng,
+            )
+            .unwrap();
+        let execution_block = sample_next_block(&vm, &caller_private_key, &[execution], rng).unwrap();
+        vm.add_next_block(&execution_block).unwrap();
+
+        // Ensure non-contiguous blocks are rejected, and do not change the VM.
+        let candidate = sample_vm();
+        assert!(candidate.add_next_blocks(&[]).is_err());
+        assert!(candidate.add_next_blocks(&[genesis.clone(), execution_block.clone()]).is_err());
+        assert!(candidate.add_next_blocks(std::slice::from_ref(&deployment_block)).is_err());
+        assert!(candidate.block_store().heights().next().is_none());
+
+        // Ensure a block that skips a height after the latest block is rejected.
+        let skipped = sample_vm();
+        skipped.add_next_block(&genesis).unwrap();
+        assert!(skipped.add_next_blocks(std::slice::from_ref(&execution_block)).is_err());
+        assert_eq!(skipped.block_store().current_block_height(), Some(0));
+
+        // Ensure the blocks are added in one atomic batch, with the program deployed for the execution.
+        candidate.add_next_blocks(&[genesis, deployment_block.clone(), execution_block.clone()]).unwrap();
+        assert_eq!(vm.block_store().current_state_root(), candidate.block_store().current_state_root());
+        assert!(candidate.contains_program(program.id()));
+
+        // Ensure the blocks may not be added again.
+        assert!(candidate.add_next_blocks(&[deployment_block, execution_block]).is_err());
+        assert_eq!(vm.block_store().current_state_root(), candidate.block_store().current_state_root());
+    }
 }
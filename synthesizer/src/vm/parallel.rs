@@ -0,0 +1,297 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parallel speculation, based on the mapping keys accessed by each transaction.
+//!
+//! The mapping keys that an execution accesses in finalize are extracted statically, from the finalize inputs
+//! of its transitions. Each execution is scheduled in the earliest wave after the executions it conflicts with,
+//! where two executions conflict if they access the same key, and one of them writes to it. The executions in a wave
+//! are finalized in parallel, each on an isolated store that is seeded with the keys it accesses, and the writes
+//! of each execution are merged into the finalize store in transaction order, which yields the same finalize
+//! operations as finalizing the executions sequentially.
+
+use super::*;
+use crate::{
+    block::{FinalizeOperation, RejectionMetadata},
+    process::{FinalizeEvent, StackProgram},
+    program::CommandTrait,
+    store::{helpers::memory::FinalizeMemory, FinalizeStorage},
+};
+use console::program::Register;
+use snarkvm_synthesizer_program::Operand;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// A mapping key, as the program ID, mapping name, and key.
+pub(super) type MappingKey<N> = (ProgramID<N>, Identifier<N>, Plaintext<N>);
+
+/// A write to a mapping key, where the value is `None` if the key is removed.
+pub(super) type MappingWrite<N> = (MappingKey<N>, Option<Value<N>>);
+
+/// The identifier of a mapping key, which is hashable.
+type MappingKeyID<N> = (ProgramID<N>, Identifier<N>, Vec<bool>);
+
+/// Returns the identifier of the given mapping key.
+fn to_key_id<N: Network>((program_id, mapping_name, key): &MappingKey<N>) -> MappingKeyID<N> {
+    (*program_id, *mapping_name, key.to_bits_le())
+}
+
+/// Returns the mapping keys that the given transaction accesses in finalize, with `true` for each key it may write to,
+/// if the transaction is an execution whose accessed keys are statically known. Otherwise, returns `None`.
+pub(super) fn finalize_accesses<N: Network>(
+    process: &Process<N>,
+    transaction: &Transaction<N>,
+) -> Option<Vec<(MappingKey<N>, bool)>> {
+    let Transaction::Execute(_, execution, _) = transaction else {
+        return None;
+    };
+
+    let mut accesses = Vec::new();
+    for transition in execution.transitions() {
+        // Retrieve the finalize scope, if the function has one.
+        let function =
+            process.get_stack(transition.program_id()).ok()?.get_function(transition.function_name()).ok()?;
+        let Some((_, finalize)) = function.finalize() else {
+            continue;
+        };
+        // Retrieve the finalize inputs.
+        let inputs = transition.finalize()?;
+
+        for command in finalize.commands() {
+            // Note: The keys of a mapping that is accessed as a whole are not statically known.
+            if command.mapping_scan().is_some() {
+                return None;
+            }
+            if let Some((mapping_name, key)) = command.mapping_access() {
+                let key = resolve_key(key, inputs)?;
+                accesses.push(((*transition.program_id(), *mapping_name, key), command.is_write()));
+            }
+        }
+    }
+    Some(accesses)
+}
+
+/// Returns the key for the given key operand, if it is a literal or a finalize input.
+fn resolve_key<N: Network>(operand: &Operand<N>, inputs: &[Value<N>]) -> Option<Plaintext<N>> {
+    match operand {
+        Operand::Literal(literal) => Some(Plaintext::from(literal.clone())),
+        // Note: The finalize inputs are stored in the first registers, which the commands may not overwrite.
+        Operand::Register(register) => {
+            let Value::Plaintext(plaintext) = inputs.get(usize::try_from(register.locator()).ok()?)? else {
+                return None;
+            };
+            match register {
+                Register::Locator(..) => Some(plaintext.clone()),
+                Register::Member(_, path) => plaintext.find(path).ok(),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the wave of each of the given executions, which is the earliest wave after the executions it conflicts with.
+fn schedule_waves<N: Network>(accesses: &[&[(MappingKey<N>, bool)]]) -> Vec<usize> {
+    // The latest wave that reads, and the latest wave that writes, each key.
+    let mut latest: HashMap<MappingKeyID<N>, (Option<usize>, Option<usize>)> = HashMap::new();
+
+    accesses
+        .iter()
+        .map(|accesses| {
+            // Determine the wave, which follows every earlier write to the keys, and every earlier read of the keys it writes.
+            let wave = accesses
+                .iter()
+                .filter_map(|(key, is_write)| {
+                    let (read, write) = latest.get(&to_key_id(key)).copied().unwrap_or_default();
+                    match is_write {
+                        true => read.max(write),
+                        false => write,
+                    }
+                })
+                .map(|wave| wave + 1)
+                .max()
+                .unwrap_or(0);
+            // Update the latest waves of the keys.
+            for (key, is_write) in accesses.iter() {
+                let (read, write) = latest.entry(to_key_id(key)).or_default();
+                match is_write {
+                    true => *write = (*write).max(Some(wave)),
+                    false => *read = (*read).max(Some(wave)),
+                }
+            }
+            wave
+        })
+        .collect()
+}
+
+/// Finalizes the given executions, with the given accessed mapping keys, in parallel waves, and returns
+/// the mapping writes of each execution, or the rejection metadata if the execution fails to finalize.
+///
+/// Note: The finalize store is not updated, as the writes must be merged in transaction order.
+pub(super) fn finalize_in_waves<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    store: &FinalizeStore<N, P>,
+    state: FinalizeGlobalState,
+    executions: &[(&Execution<N>, &[(MappingKey<N>, bool)])],
+) -> Result<Vec<Result<Vec<MappingWrite<N>>, RejectionMetadata<N>>>> {
+    // Schedule the executions into waves.
+    let waves = schedule_waves(&executions.iter().map(|(_, accesses)| *accesses).collect::<Vec<_>>());
+    let num_waves = waves.iter().max().map_or(0, |wave| wave + 1);
+
+    // Initialize the outcomes, and the writes of the finalized waves.
+    let mut outcomes = (0..executions.len()).map(|_| None).collect::<Vec<_>>();
+    let mut overlay = HashMap::new();
+
+    for wave in 0..num_waves {
+        // Finalize the executions in the wave, in parallel.
+        let indices = (0..executions.len()).filter(|index| waves[*index] == wave).collect::<Vec<_>>();
+        let results = cfg_iter!(indices)
+            .map(|index| {
+                let (execution, accesses) = executions[*index];
+                finalize_isolated(process, store, state, &overlay, execution, accesses)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Record the writes of the wave, which the later waves observe.
+        // Note: The executions in a wave do not write to the same keys, so their order does not matter.
+        for (index, writes) in indices.into_iter().zip_eq(results) {
+            for (key, value) in writes.iter().flatten() {
+                overlay.insert(to_key_id(key), value.clone());
+            }
+            outcomes[index] = Some(writes);
+        }
+    }
+
+    outcomes.into_iter().map(|outcome| outcome.ok_or_else(|| anyhow!("An execution was not scheduled"))).collect()
+}
+
+/// Finalizes the given execution on an isolated store, which is seeded with the given accessed mapping keys, and
+/// returns the mapping writes of the execution, or the rejection metadata if the execution fails to finalize.
+fn finalize_isolated<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    store: &FinalizeStore<N, P>,
+    state: FinalizeGlobalState,
+    overlay: &HashMap<MappingKeyID<N>, Option<Value<N>>>,
+    execution: &Execution<N>,
+    accesses: &[(MappingKey<N>, bool)],
+) -> Result<Result<Vec<MappingWrite<N>>, RejectionMetadata<N>>> {
+    // Initialize the isolated store.
+    let isolated = FinalizeStore::<N, FinalizeMemory<N>>::open(store.dev())?;
+
+    // Initialize the mappings of the programs in the execution.
+    for transition in execution.transitions() {
+        let program_id = transition.program_id();
+        for mapping_name in process.get_stack(program_id)?.program().mappings().keys() {
+            if !isolated.contains_mapping_confirmed(program_id, mapping_name)? {
+                isolated.initialize_mapping(program_id, mapping_name)?;
+            }
+        }
+    }
+
+    // Seed the accessed keys with their values, as of the finalized waves.
+    for ((program_id, mapping_name, key), _) in accesses {
+        if isolated.contains_key_confirmed(program_id, mapping_name, key)? {
+            continue;
+        }
+        let value = match overlay.get(&to_key_id(&(*program_id, *mapping_name, key.clone()))) {
+            Some(value) => value.clone(),
+            None => store.get_value_speculative(program_id, mapping_name, key)?,
+        };
+        if let Some(value) = value {
+            isolated.insert_key_value(program_id, mapping_name, key.clone(), value)?;
+        }
+    }
+
+    // Finalize the execution, and extract the mapping writes from the trace.
+    let mut trace = FinalizeTrace::new();
+    if let Err(error) = process.finalize_execution_with_trace(state, &isolated, execution, Some(&mut trace)) {
+        return Ok(Err(super::finalize::to_rejection_metadata(&error)));
+    }
+    let writes = trace.events().iter().filter_map(|event| match event {
+        FinalizeEvent::MappingWrite { program_id, mapping_name, key, value } => {
+            Some(((*program_id, *mapping_name, key.clone()), value.clone()))
+        }
+        _ => None,
+    });
+    Ok(Ok(writes.collect()))
+}
+
+/// Applies the given mapping writes to the finalize store, and returns the finalize operations.
+pub(super) fn apply_writes<N: Network, P: FinalizeStorage<N>>(
+    store: &FinalizeStore<N, P>,
+    writes: Vec<MappingWrite<N>>,
+) -> Result<Vec<FinalizeOperation<N>>> {
+    writes
+        .into_iter()
+        .map(|((program_id, mapping_name, key), value)| match value {
+            Some(value) => store.update_key_value(&program_id, &mapping_name, key, value),
+            None => store.remove_key_value(&program_id, &mapping_name, &key),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a mapping key for the given mapping name and key.
+    fn sample_key(mapping_name: &str, key: &str) -> MappingKey<CurrentNetwork> {
+        (
+            ProgramID::from_str("testing.aleo").unwrap(),
+            Identifier::from_str(mapping_name).unwrap(),
+            Plaintext::from_str(key).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_schedule_waves() {
+        let accesses = [
+            // Writes to 'a[0u8]'.
+            vec![(sample_key("a", "0u8"), true)],
+            // Reads 'a[1u8]', which does not conflict.
+            vec![(sample_key("a", "1u8"), false)],
+            // Reads 'a[0u8]', which follows the first write.
+            vec![(sample_key("a", "0u8"), false), (sample_key("b", "0u8"), false)],
+            // Reads 'a[1u8]', which does not conflict with the earlier read.
+            vec![(sample_key("a", "1u8"), false)],
+            // Writes to 'b[0u8]', which follows the read in the third wave.
+            vec![(sample_key("b", "0u8"), true)],
+            // Accesses no keys.
+            vec![],
+        ];
+        let accesses = accesses.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        assert_eq!(vec![0, 0, 1, 0, 2, 0], schedule_waves(&accesses));
+    }
+
+    #[test]
+    fn test_resolve_key() {
+        let inputs = [
+            Value::<CurrentNetwork>::from_str("1u8").unwrap(),
+            Value::from_str("{ owner: 2u8, amount: 3u64 }").unwrap(),
+        ];
+        let resolve = |operand: &str| resolve_key(&Operand::from_str(operand).unwrap(), &inputs);
+
+        // Ensure literals and finalize inputs are resolved.
+        assert_eq!(Some(Plaintext::from_str("0field").unwrap()), resolve("0field"));
+        assert_eq!(Some(Plaintext::from_str("1u8").unwrap()), resolve("r0"));
+        assert_eq!(Some(Plaintext::from_str("2u8").unwrap()), resolve("r1.owner"));
+        // Ensure computed registers are not resolved.
+        assert_eq!(None, resolve("r2"));
+        assert_eq!(None, resolve("block.height"));
+    }
+}
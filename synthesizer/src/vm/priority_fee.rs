@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::cmp::Reverse;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the minimum cost in microcredits of the given transaction, which its fee must cover.
+    ///
+    /// A `mint` or `split` execution does not require a fee, so its minimum cost is zero. As the cost of
+    /// the rejected transaction of a fee transaction is not known, the entire fee is its minimum cost.
+    pub fn minimum_cost(&self, transaction: &Transaction<N>) -> Result<u64> {
+        match transaction {
+            // Compute the deployment cost.
+            Transaction::Deploy(_, _, deployment, _) => Ok(Deployment::cost(deployment)?.0),
+            // TODO (raychu86): Remove the split check when batch executions are integrated.
+            Transaction::Execute(_, execution, _)
+                if (transaction.is_mint() || transaction.is_split()) && execution.len() == 1 =>
+            {
+                Ok(0)
+            }
+            // Compute the execution cost.
+            Transaction::Execute(_, execution, _) => Ok(Execution::cost(self, execution)?.0),
+            // TODO (howardwu): Plug in the Rejected struct, to compute the cost.
+            Transaction::Fee(_, fee) => Ok(*fee.amount()?),
+        }
+    }
+
+    /// Returns the priority fee in microcredits of the given transaction,
+    /// which is the portion of its fee **on top** of its minimum cost.
+    ///
+    /// Returns an error if the fee does not cover the minimum cost of the transaction.
+    pub fn priority_fee(&self, transaction: &Transaction<N>) -> Result<u64> {
+        // Retrieve the transaction fee.
+        let fee = *transaction.fee()?;
+        // Retrieve the minimum cost of the transaction.
+        let cost = self.minimum_cost(transaction)?;
+        // Compute the priority fee.
+        match fee.checked_sub(cost) {
+            Some(priority_fee) => Ok(priority_fee),
+            None => bail!(
+                "Transaction '{}' has an insufficient fee - expected at least {cost} microcredits",
+                transaction.id()
+            ),
+        }
+    }
+
+    /// Returns the given transactions, ordered by priority fee in descending order, for a block producer.
+    ///
+    /// The transactions with the same priority fee retain their relative order. The transactions whose priority fee
+    /// cannot be computed, for example, as they execute a program that is not yet deployed, are ordered last.
+    pub fn order_by_priority_fee<'a>(
+        &self,
+        transactions: impl IntoIterator<Item = &'a Transaction<N>>,
+    ) -> Vec<&'a Transaction<N>> {
+        let mut transactions = transactions.into_iter().collect::<Vec<_>>();
+        // Note: `sort_by_cached_key` is stable, and `None` is ordered before `Some`, so it is last in reverse.
+        transactions.sort_by_cached_key(|transaction| Reverse(self.priority_fee(transaction).ok()));
+        transactions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Transition;
+    use console::account::ViewKey;
+
+    #[test]
+    fn test_priority_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Sample an execution with an explicit priority fee on top of its minimum cost.
+        const PRIORITY_FEE: u64 = 1_000;
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let mut records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record.decrypt(&caller_view_key).unwrap());
+        let inputs = [
+            Value::Record(records.next().unwrap()),
+            Value::from_str(&caller.to_string()).unwrap(),
+            Value::from_str("1u64").unwrap(),
+        ];
+        let fee = Some((records.next().unwrap(), PRIORITY_FEE));
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), fee, None, rng)
+            .unwrap();
+
+        // Ensure the priority fee of the execution is its fee on top of its minimum cost.
+        let cost = vm.minimum_cost(&transaction).unwrap();
+        assert!(*transaction.fee().unwrap() > cost);
+        assert_eq!(PRIORITY_FEE, vm.priority_fee(&transaction).unwrap());
+
+        // Ensure a fee transaction has no priority fee.
+        let fee_transaction = crate::vm::test_helpers::sample_fee_transaction(rng);
+        assert_eq!(0, vm.priority_fee(&fee_transaction).unwrap());
+
+        // Ensure an execution without a fee is rejected, as it does not cover its minimum cost.
+        let invalid_transaction = crate::vm::test_helpers::sample_execution_transaction_without_fee(rng);
+        assert!(vm.priority_fee(&invalid_transaction).is_err());
+
+        // Ensure the transactions are ordered by priority fee, with the invalid transaction last.
+        let ordered = vm.order_by_priority_fee([&invalid_transaction, &fee_transaction, &transaction]);
+        assert_eq!(vec![&transaction, &fee_transaction, &invalid_transaction], ordered);
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{block::FinalizeOperation, store::FinalizeStorage};
+
+use indexmap::IndexMap;
+
+/// A kind of ratification, which may be registered with the VM in addition to the built-in rewards.
+///
+/// A custom ratification carries the ID of its kind and an opaque payload. The payload is checked
+/// when a block is validated, and its effects are applied when the block is speculated on and finalized.
+pub trait RatifyKind<N: Network, P: FinalizeStorage<N>>: Send + Sync {
+    /// Returns the unique ID of the ratification kind.
+    fn id(&self) -> u16;
+
+    /// Checks the given payload is a valid ratification of this kind, in the given block.
+    fn check(&self, payload: &[u8], block: &Block<N>) -> Result<()>;
+
+    /// Applies the effects of the given payload to the finalize store, and returns the finalize operations.
+    ///
+    /// Note: This is called within the atomic batch of the block, so on failure, the entire block is aborted.
+    /// The finalize operations are included in the finalize root of the block, after those of the transactions.
+    fn finalize(
+        &self,
+        payload: &[u8],
+        store: &FinalizeStore<N, P>,
+        state: FinalizeGlobalState,
+    ) -> Result<Vec<FinalizeOperation<N>>>;
+}
+
+/// The ratification kinds registered with the VM.
+pub struct RatifyKinds<N: Network, P: FinalizeStorage<N>> {
+    /// The mapping of kind IDs to ratification kinds.
+    kinds: IndexMap<u16, Box<dyn RatifyKind<N, P>>>,
+}
+
+impl<N: Network, P: FinalizeStorage<N>> Default for RatifyKinds<N, P> {
+    /// Initializes an empty set of ratification kinds.
+    fn default() -> Self {
+        Self { kinds: IndexMap::new() }
+    }
+}
+
+impl<N: Network, P: FinalizeStorage<N>> RatifyKinds<N, P> {
+    /// Registers the given ratification kind.
+    pub fn register(&mut self, kind: impl RatifyKind<N, P> + 'static) -> Result<()> {
+        let id = kind.id();
+        // Ensure the kind ID is not already registered.
+        ensure!(!self.kinds.contains_key(&id), "Ratify kind '{id}' is already registered");
+        // Register the kind.
+        self.kinds.insert(id, Box::new(kind));
+        Ok(())
+    }
+
+    /// Returns `true` if a ratification kind with the given ID is registered.
+    pub fn contains(&self, id: u16) -> bool {
+        self.kinds.contains_key(&id)
+    }
+
+    /// Returns the ratification kind for the given ID.
+    fn get(&self, id: u16) -> Result<&dyn RatifyKind<N, P>> {
+        match self.kinds.get(&id) {
+            Some(kind) => Ok(kind.as_ref()),
+            None => bail!("Ratify kind '{id}' is not registered"),
+        }
+    }
+
+    /// Checks the custom ratifications in the given block.
+    pub fn check(&self, block: &Block<N>) -> Result<()> {
+        for ratify in block.ratifications() {
+            if let Ratify::Custom(id, payload) = ratify {
+                self.get(*id)?
+                    .check(payload, block)
+                    .map_err(|e| anyhow!("Invalid ratification of kind '{id}': {e}"))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finalizes the custom ratifications, in the given order, and returns their finalize operations.
+    pub fn finalize(
+        &self,
+        store: &FinalizeStore<N, P>,
+        state: FinalizeGlobalState,
+        ratifications: &[Ratify<N>],
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let mut operations = Vec::new();
+        for ratify in ratifications {
+            if let Ratify::Custom(id, payload) = ratify {
+                operations.extend(self.get(*id)?.finalize(payload, store, state)?);
+            }
+        }
+        Ok(operations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::memory::FinalizeMemory;
+    use console::network::Testnet3;
+
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    type CurrentNetwork = Testnet3;
+
+    /// A ratification kind that counts the payload bytes it finalizes, and stores the latest count in a mapping.
+    struct SampleKind(Arc<AtomicUsize>);
+
+    /// Returns the program ID and mapping name that the sample ratification kind writes to.
+    fn sample_mapping() -> (ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>) {
+        (ProgramID::from_str("ratify.aleo").unwrap(), Identifier::from_str("counts").unwrap())
+    }
+
+    impl RatifyKind<CurrentNetwork, FinalizeMemory<CurrentNetwork>> for SampleKind {
+        fn id(&self) -> u16 {
+            7
+        }
+
+        fn check(&self, _payload: &[u8], _block: &Block<CurrentNetwork>) -> Result<()> {
+            Ok(())
+        }
+
+        fn finalize(
+            &self,
+            payload: &[u8],
+            store: &FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>>,
+            _state: FinalizeGlobalState,
+        ) -> Result<Vec<FinalizeOperation<CurrentNetwork>>> {
+            ensure!(!payload.is_empty(), "Empty payload");
+            let count = self.0.fetch_add(payload.len(), Ordering::SeqCst) + payload.len();
+            // Store the latest count.
+            let (program_id, mapping_name) = sample_mapping();
+            let key = Plaintext::from(Literal::Boolean(console::types::Boolean::new(true)));
+            let value = Value::from(Literal::U64(console::types::U64::new(count as u64)));
+            Ok(vec![store.update_key_value(&program_id, &mapping_name, key, value)?])
+        }
+    }
+
+    #[test]
+    fn test_ratify_kinds() {
+        let rng = &mut TestRng::default();
+
+        let store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+        let (program_id, mapping_name) = sample_mapping();
+        store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        let state = crate::vm::test_helpers::sample_finalize_state(1);
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        // Register the ratification kind.
+        let mut kinds = RatifyKinds::default();
+        assert!(!kinds.contains(7));
+        kinds.register(SampleKind(counter.clone())).unwrap();
+        assert!(kinds.contains(7));
+        // Ensure the ratification kind may not be registered twice.
+        assert!(kinds.register(SampleKind(counter.clone())).is_err());
+
+        // Ensure the custom ratifications are finalized, and the built-in ratifications are skipped.
+        let ratifications = vec![
+            Ratify::ProvingReward(Address::new(rng.gen()), 100),
+            Ratify::Custom(7, vec![1, 2, 3]),
+            Ratify::Custom(7, vec![4]),
+        ];
+        let operations = kinds.finalize(&store, state, &ratifications).unwrap();
+        assert_eq!(4, counter.load(Ordering::SeqCst));
+        // Ensure a finalize operation is returned for each custom ratification.
+        assert_eq!(2, operations.len());
+        assert!(operations.iter().all(|operation| matches!(operation, FinalizeOperation::UpdateKeyValue(..))));
+
+        // Ensure an invalid payload is rejected.
+        assert!(kinds.finalize(&store, state, &[Ratify::Custom(7, vec![])]).is_err());
+        // Ensure an unregistered ratification kind is rejected.
+        assert!(kinds.finalize(&store, state, &[Ratify::Custom(8, vec![1])]).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{block::FinalizeOperation, store::helpers::memory::ConsensusMemory};
+
+/// A transaction whose replayed finalize outcome differs from the outcome stored in its block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayMismatch<N: Network> {
+    /// The index of the transaction in the block.
+    pub index: u32,
+    /// The ID of the stored transaction.
+    pub transaction_id: N::TransactionID,
+    /// The stored finalize operations, or `None` if the transaction was stored as rejected.
+    pub expected: Option<Vec<FinalizeOperation<N>>>,
+    /// The replayed finalize operations, or `None` if the transaction was rejected on replay.
+    pub candidate: Option<Vec<FinalizeOperation<N>>>,
+}
+
+/// The result of replaying the finalize of a block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockReplay<N: Network> {
+    /// The height of the replayed block.
+    height: u32,
+    /// The number of replayed transactions.
+    num_transactions: usize,
+    /// The transactions whose replayed outcome differs from the stored outcome.
+    mismatches: Vec<ReplayMismatch<N>>,
+}
+
+impl<N: Network> BlockReplay<N> {
+    /// Returns the height of the replayed block.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the number of replayed transactions.
+    pub const fn num_transactions(&self) -> usize {
+        self.num_transactions
+    }
+
+    /// Returns the transactions whose replayed outcome differs from the stored outcome.
+    pub fn mismatches(&self) -> &[ReplayMismatch<N>] {
+        &self.mismatches
+    }
+
+    /// Returns `true` if the replayed outcome of every transaction matches the stored outcome.
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Replays the finalize of the block at the given height against the state prior to the block,
+    /// and returns the transactions whose replayed outcome differs from the outcome stored in the block.
+    ///
+    /// The prior state is reconstructed by adding every earlier block to an in-memory VM,
+    /// so this method is intended for auditing, and scales with the height of the block.
+    pub fn replay_block(&self, height: u32) -> Result<BlockReplay<N>> {
+        let timer = timer!("VM::replay_block");
+
+        // Ensure the block is not the genesis block.
+        ensure!(height > 0, "Cannot replay the genesis block");
+
+        // A helper function to retrieve the block at the given height.
+        let get_block = |height: u32| -> Result<Block<N>> {
+            let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+                bail!("Block {height} does not exist in storage")
+            };
+            match self.block_store().get_block(&block_hash)? {
+                Some(block) => Ok(block),
+                None => bail!("Block {height} ('{block_hash}') does not exist in storage"),
+            }
+        };
+
+        // Retrieve the block.
+        let block = get_block(height)?;
+
+        // Reconstruct the state prior to the block, in an in-memory VM.
+        let replica = VM::<N, ConsensusMemory<N>>::from(ConsensusStore::open(None)?)?;
+        for previous_height in 0..height {
+            replica.add_next_block(&get_block(previous_height)?)?;
+        }
+        lap!(timer, "Reconstruct the prior state");
+
+        // Construct the finalize state.
+        let state = FinalizeGlobalState::new::<N>(
+            block.round(),
+            block.height(),
+            block.cumulative_weight(),
+            block.cumulative_proof_target(),
+            block.previous_hash(),
+        )?;
+
+        // Replay the transactions.
+        let mismatches = replica.atomic_replay(state, block.transactions())?;
+        finish!(timer, "Replayed the transactions");
+
+        Ok(BlockReplay { height, num_transactions: block.transactions().len(), mismatches })
+    }
+
+    /// Performs a dry run of finalize over the given confirmed transactions,
+    /// and returns the transactions whose replayed outcome differs from the stored outcome.
+    #[inline]
+    fn atomic_replay(
+        &self,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+    ) -> Result<Vec<ReplayMismatch<N>>> {
+        // Perform the finalize operation as a dry run.
+        atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            // Acquire the read lock on the process.
+            let process = self.process.read();
+
+            // Retrieve the finalize store.
+            let store = self.finalize_store();
+
+            // Initialize a list of the mismatched transactions.
+            let mut mismatches = Vec::new();
+
+            for transaction in transactions.iter() {
+                // Replay the transaction, and retrieve the stored and replayed finalize operations.
+                let (expected, candidate) = match transaction {
+                    ConfirmedTransaction::AcceptedDeploy(
+                        _,
+                        Transaction::Deploy(_, program_owner, deployment, _),
+                        finalize,
+                    ) => {
+                        let candidate = process
+                            .finalize_deployment(store, deployment, program_owner)
+                            .ok()
+                            .map(|(_, operations)| operations);
+                        (Some(finalize.clone()), candidate)
+                    }
+                    ConfirmedTransaction::AcceptedExecute(_, Transaction::Execute(_, execution, _), finalize) => {
+                        let candidate = process.finalize_execution(state, store, execution).ok();
+                        (Some(finalize.clone()), candidate)
+                    }
+                    ConfirmedTransaction::RejectedDeploy(_, _, rejected, _) => {
+                        match (rejected.program_owner(), rejected.deployment()) {
+                            (Some(program_owner), Some(deployment)) => (
+                                None,
+                                process
+                                    .finalize_deployment(store, deployment, program_owner)
+                                    .ok()
+                                    .map(|(_, operations)| operations),
+                            ),
+                            _ => return Err("Expected rejected deployment".to_string()),
+                        }
+                    }
+                    ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => match rejected.execution() {
+                        Some(execution) => (None, process.finalize_execution(state, store, execution).ok()),
+                        None => return Err("Expected rejected execution".to_string()),
+                    },
+                    _ => return Err(format!("Malformed confirmed transaction '{}'", transaction.id())),
+                };
+
+                // Record the transaction if the replayed outcome differs from the stored outcome.
+                if expected != candidate {
+                    mismatches.push(ReplayMismatch {
+                        index: transaction.index(),
+                        transaction_id: transaction.id(),
+                        expected,
+                        candidate,
+                    });
+                }
+            }
+
+            Ok(mismatches)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers;
+
+    #[test]
+    fn test_replay_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM with the genesis block.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Ensure the genesis block cannot be replayed.
+        assert!(vm.replay_block(0).is_err());
+
+        // Add a block with a deployment.
+        let deployment = test_helpers::sample_deployment_transaction(rng);
+        let block = test_helpers::sample_next_block(&vm, &caller_private_key, &[deployment], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Replay the block, and ensure it matches storage.
+        let replay = vm.replay_block(1).unwrap();
+        assert_eq!(1, replay.height());
+        assert_eq!(1, replay.num_transactions());
+        assert!(replay.is_consistent());
+
+        // Ensure a block that does not exist cannot be replayed.
+        assert!(vm.replay_block(2).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{block::FinalizeOperation, process::FinalizeFailure};
+
+/// The reason a transaction is rejected or aborted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RejectionReason<N: Network> {
+    /// The transaction failed verification.
+    InvalidTransaction { error: String },
+    /// The transaction is a fee transaction, which may not be speculated on.
+    FeeTransaction,
+    /// The execution does not include a fee, which is required to reject the execution.
+    MissingFee,
+    /// The deployment failed to finalize, for example, as the program already exists.
+    DeploymentFailed { program_id: ProgramID<N>, error: String },
+    /// A command in a finalize scope failed, which aborts finalize.
+    CommandFailed(Box<FinalizeFailure<N>>),
+    /// The execution failed to finalize, before a command was evaluated.
+    ExecutionFailed { error: String },
+}
+
+impl<N: Network> Display for RejectionReason<N> {
+    /// Prints the reason as an error message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidTransaction { error } => write!(f, "the transaction is invalid: {error}"),
+            Self::FeeTransaction => write!(f, "cannot speculate on a fee transaction"),
+            Self::MissingFee => write!(f, "the rejected execution has no fee"),
+            Self::DeploymentFailed { program_id, error } => {
+                write!(f, "the deployment of '{program_id}' failed: {error}")
+            }
+            Self::CommandFailed(failure) => match failure.mapping_key() {
+                Some((mapping_name, key)) => write!(
+                    f,
+                    "'{}/{}' failed on command {} ({}) at '{mapping_name}[{key}]': {}",
+                    failure.program_id(),
+                    failure.function_name(),
+                    failure.index(),
+                    failure.command(),
+                    failure.error()
+                ),
+                None => write!(
+                    f,
+                    "'{}/{}' failed on command {} ({}): {}",
+                    failure.program_id(),
+                    failure.function_name(),
+                    failure.index(),
+                    failure.command(),
+                    failure.error()
+                ),
+            },
+            Self::ExecutionFailed { error } => write!(f, "the execution failed to finalize: {error}"),
+        }
+    }
+}
+
+/// The outcome of simulating a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimulationOutcome<N: Network> {
+    /// The transaction is accepted, with the given finalize operations and number of metered finalize steps.
+    /// Note: A deployment is not metered, so its number of steps is zero.
+    Accepted { finalize: Vec<FinalizeOperation<N>>, steps: u64 },
+    /// The transaction is rejected, so it is included in a block with only its fee.
+    Rejected(RejectionReason<N>),
+    /// The transaction is aborted, so it may not be included in a block.
+    Aborted(RejectionReason<N>),
+}
+
+impl<N: Network> SimulationOutcome<N> {
+    /// Returns `true` if the transaction is accepted.
+    pub const fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted { .. })
+    }
+
+    /// Returns `true` if the transaction is rejected.
+    pub const fn is_rejected(&self) -> bool {
+        matches!(self, Self::Rejected(..))
+    }
+
+    /// Returns `true` if the transaction is aborted.
+    pub const fn is_aborted(&self) -> bool {
+        matches!(self, Self::Aborted(..))
+    }
+
+    /// Returns the number of metered finalize steps, if the transaction is accepted.
+    pub const fn steps(&self) -> Option<u64> {
+        match self {
+            Self::Accepted { steps, .. } => Some(*steps),
+            Self::Rejected(..) | Self::Aborted(..) => None,
+        }
+    }
+
+    /// Returns the reason the transaction is rejected or aborted, if it is not accepted.
+    pub const fn reason(&self) -> Option<&RejectionReason<N>> {
+        match self {
+            Self::Accepted { .. } => None,
+            Self::Rejected(reason) | Self::Aborted(reason) => Some(reason),
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Simulates the given transaction on the current state of the VM, and returns its outcome,
+    /// with the reason the transaction is rejected or aborted, if it is not accepted.
+    ///
+    /// The transaction is verified, and its finalize is performed as a **dry-run**, which does not update storage.
+    pub fn simulate(&self, state: FinalizeGlobalState, transaction: &Transaction<N>) -> Result<SimulationOutcome<N>> {
+        let timer = timer!("VM::simulate");
+
+        // Ensure the transaction is valid.
+        if let Err(error) = self.check_transaction(transaction, None) {
+            return Ok(SimulationOutcome::Aborted(RejectionReason::InvalidTransaction { error: error.to_string() }));
+        }
+        lap!(timer, "Verify the transaction");
+
+        // Performs a **dry-run** of finalize over the transaction.
+        let outcome = atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            // Acquire the read lock on the process.
+            let process = self.process.read();
+            // Retrieve the finalize store.
+            let store = self.finalize_store();
+
+            // Finalize the transaction, and determine its outcome.
+            let outcome = match transaction {
+                Transaction::Deploy(_, program_owner, deployment, _) => {
+                    match process.finalize_deployment(store, deployment, program_owner) {
+                        Ok((_, finalize)) => SimulationOutcome::Accepted { finalize, steps: 0 },
+                        Err(error) => SimulationOutcome::Rejected(RejectionReason::DeploymentFailed {
+                            program_id: *deployment.program_id(),
+                            error: error.to_string(),
+                        }),
+                    }
+                }
+                Transaction::Execute(_, execution, fee) => {
+                    match process.finalize_execution_with_trace(state, store, execution, None) {
+                        Ok((finalize, steps)) => SimulationOutcome::Accepted { finalize, steps },
+                        // Note: An execution without a fee may not be rejected, so it is aborted instead.
+                        Err(_) if fee.is_none() => SimulationOutcome::Aborted(RejectionReason::MissingFee),
+                        Err(error) => SimulationOutcome::Rejected(match error.downcast::<FinalizeFailure<N>>() {
+                            Ok(failure) => RejectionReason::CommandFailed(Box::new(failure)),
+                            Err(error) => RejectionReason::ExecutionFailed { error: error.to_string() },
+                        }),
+                    }
+                }
+                Transaction::Fee(..) => SimulationOutcome::Aborted(RejectionReason::FeeTransaction),
+            };
+            Ok(outcome)
+        })?;
+
+        finish!(timer, "Finished dry-run of the transaction");
+        Ok(outcome)
+    }
+}
@@ -39,7 +39,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Returns `true` if the execution is valid.
     pub fn verify_execution(&self, execution: &Execution<N>) -> bool {
-        match self.check_execution(execution) {
+        match self.check_execution(execution, false) {
             Ok(_) => true,
             Err(error) => {
                 warn!("{error}");
@@ -50,7 +50,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Returns `true` if the fee is valid.
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> bool {
-        match self.check_fee(fee, deployment_or_execution_id) {
+        match self.check_fee(fee, deployment_or_execution_id, false) {
             Ok(_) => true,
             Err(error) => {
                 warn!("{error}");
@@ -62,6 +62,28 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_transaction_internal(transaction, rejected_id, false)
+    }
+
+    /// Verifies the transaction in the VM, during an atomic batch of blocks.
+    /// The global state roots of the blocks in the pending batch are accepted. On failure, returns an error.
+    #[inline]
+    pub(crate) fn check_transaction_speculative(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+    ) -> Result<()> {
+        self.check_transaction_internal(transaction, rejected_id, true)
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    /// If `speculative` is `true`, the global state roots of the blocks in a pending atomic batch are accepted.
+    fn check_transaction_internal(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        speculative: bool,
+    ) -> Result<()> {
         let timer = timer!("VM::verify");
 
         // Compute the Merkle root of the transaction.
@@ -131,6 +153,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Check for duplicate elements");
 
+        /* Fee */
+
+        // Ensure the fee covers the minimum cost of the transaction.
+        // Note: The remainder of the fee is the priority fee of the transaction.
+        self.priority_fee(transaction)?;
+
+        lap!(timer, "Check the priority fee");
+
         match transaction {
             Transaction::Deploy(id, owner, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -142,7 +172,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
                 // Verify the fee.
-                self.check_fee(fee, deployment_id)?;
+                self.check_fee(fee, deployment_id, speculative)?;
                 // Verify the deployment.
                 self.check_deployment(deployment)?;
             }
@@ -158,12 +188,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if !((transaction.is_mint() || transaction.is_split()) && execution.len() == 1) && fee.is_none() {
                     bail!("Transaction is missing a fee (execution)");
                 }
+                // Ensure the fee is present, if the execution is sponsored, and that the sponsor signed the fee.
+                if let Some(sponsorship) = execution.sponsorship() {
+                    let Some(fee) = fee else { bail!("Transaction is missing a fee (sponsored execution)") };
+                    // Retrieve the root transition, whose signer endorsed the sponsor.
+                    let transition = execution.peek()?;
+                    // Verify the sponsorship.
+                    sponsorship.verify(transition.tpk(), fee)?;
+                }
                 // Verify the fee.
                 if let Some(fee) = fee {
-                    self.check_fee(fee, execution_id)?;
+                    self.check_fee(fee, execution_id, speculative)?;
                 }
                 // Verify the execution.
-                self.check_execution(execution)?;
+                self.check_execution(execution, speculative)?;
             }
             Transaction::Fee(_, fee) => {
                 // Ensure the fee is nonzero.
@@ -173,7 +211,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction is missing a rejected ID (fee)");
                 };
                 // Verify the fee.
-                self.check_fee(fee, rejected_id)?;
+                self.check_fee(fee, rejected_id, speculative)?;
             }
         };
 
@@ -184,6 +222,230 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(())
     }
 
+    /// Checks the given contiguous blocks are well-formed, and that their transactions are unique,
+    /// before the blocks are added to the VM in one atomic batch. On failure, returns an error.
+    ///
+    /// Note: The transactions are verified as each block is added, with `VM::check_transaction_speculative`,
+    /// as they may depend on the earlier blocks in the batch. The committee, the coinbase, and the staking
+    /// rewards of a block depend on the ledger state, and are checked by `Ledger::check_next_block`.
+    pub(crate) fn check_next_blocks(&self, blocks: &[Block<N>]) -> Result<()> {
+        let timer = timer!("VM::check_next_blocks");
+
+        for block in blocks {
+            let (height, hash) = (block.height(), block.hash());
+
+            /* Block Header */
+
+            // If the block is the genesis block, check that it is valid.
+            ensure!(height != 0 || block.is_genesis(), "Invalid genesis block");
+            // Ensure the block header is valid.
+            ensure!(block.header().is_valid(), "Invalid block header for block {height}: {:?}", block.header());
+
+            /* Block Hash */
+
+            // Compute the Merkle root of the block header.
+            let header_root = match block.header().to_root() {
+                Ok(root) => root,
+                Err(error) => bail!("Failed to compute the Merkle root of the block header: {error}"),
+            };
+            // Ensure the block hash matches the one in the block.
+            match N::hash_bhp1024(&[block.previous_hash().to_bits_le(), header_root.to_bits_le()].concat()) {
+                Ok(candidate_hash) => {
+                    ensure!(candidate_hash == *hash, "Block {height} ({hash}) has an incorrect block hash")
+                }
+                Err(error) => bail!("Unable to compute block hash for block {height} ({hash}): {error}"),
+            }
+            // Ensure the block hash does not already exist.
+            ensure!(!self.block_store().contains_block_hash(&hash)?, "Block hash '{hash}' already exists in the VM");
+
+            /* Transactions */
+
+            // Ensure the transactions root matches the one in the block header.
+            match block.transactions().to_transactions_root() {
+                Ok(root) => ensure!(
+                    root == block.header().transactions_root(),
+                    "Block {height} ({hash}) has an incorrect transactions root"
+                ),
+                Err(error) => bail!("Failed to compute the Merkle root of the block transactions: {error}"),
+            }
+            // Ensure the transactions list is not empty.
+            ensure!(!block.transactions().is_empty(), "Block {height} ({hash}) has an empty transactions list");
+            // Ensure the number of transactions is within the allowed range.
+            ensure!(
+                block.transactions().len() <= Transactions::<N>::MAX_TRANSACTIONS,
+                "Block {height} ({hash}) has more than {} transactions",
+                Transactions::<N>::MAX_TRANSACTIONS
+            );
+            // Ensure the executions have not expired, and the deployments are for the current edition.
+            for transaction in block.transactions().iter() {
+                if let Some(execution) = transaction.execution() {
+                    ensure!(
+                        !execution.is_expired(height),
+                        "Transaction '{}' expired at block height {:?}",
+                        transaction.id(),
+                        execution.valid_until()
+                    );
+                }
+                if let Transaction::Deploy(_, _, deployment, _) = transaction.transaction() {
+                    ensure!(
+                        deployment.edition() == N::EDITION,
+                        "Invalid program deployment: expected edition {}",
+                        N::EDITION
+                    );
+                }
+            }
+
+            /* Ratifications Root */
+
+            // Compute the ratifications root of the block.
+            let leaves =
+                block.ratifications().iter().map(|r| Ok(r.to_bytes_le()?.to_bits_le())).collect::<Result<Vec<_>>>()?;
+            let ratifications_root = *N::merkle_tree_bhp::<RATIFICATIONS_DEPTH>(&leaves)?.root();
+            // Ensure that the block's ratifications root matches the declared ratifications.
+            ensure!(
+                block.ratifications_root() == ratifications_root,
+                "Invalid ratifications root for block {height}: expected '{ratifications_root}', got '{}'",
+                block.ratifications_root()
+            );
+        }
+        lap!(timer, "Check the blocks are well-formed");
+
+        // Ensure there are no duplicate transactions or transitions across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.transaction_ids())),
+            "Found duplicate transactions in the blocks"
+        );
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.transition_ids())),
+            "Found duplicate transitions in the blocks"
+        );
+        // Ensure there are no duplicate deployed programs across the blocks.
+        ensure!(
+            !has_duplicates(
+                blocks
+                    .iter()
+                    .flat_map(|block| block.deployments().filter_map(|tx| tx.deployment()))
+                    .map(|deployment| *deployment.program_id())
+            ),
+            "Found duplicate program deployments in the blocks"
+        );
+
+        /* Input */
+
+        // Ensure there are no duplicate input IDs across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.input_ids())),
+            "Found duplicate input IDs in the blocks"
+        );
+        // Ensure there are no duplicate serial numbers across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.serial_numbers())),
+            "Found duplicate serial numbers in the blocks"
+        );
+        // Ensure there are no duplicate tags across the blocks.
+        ensure!(!has_duplicates(blocks.iter().flat_map(|block| block.tags())), "Found duplicate tags in the blocks");
+
+        /* Output */
+
+        // Ensure there are no duplicate output IDs across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.output_ids())),
+            "Found duplicate output IDs in the blocks"
+        );
+        // Ensure there are no duplicate commitments across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.commitments())),
+            "Found duplicate commitments in the blocks"
+        );
+        // Ensure there are no duplicate nonces across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.nonces())),
+            "Found duplicate nonces in the blocks"
+        );
+
+        /* Metadata */
+
+        // Ensure there are no duplicate transition public keys across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.transition_public_keys())),
+            "Found duplicate transition public keys in the blocks"
+        );
+        // Ensure there are no duplicate transition commitments across the blocks.
+        ensure!(
+            !has_duplicates(blocks.iter().flat_map(|block| block.transition_commitments())),
+            "Found duplicate transition commitments in the blocks"
+        );
+        lap!(timer, "Check for duplicate elements");
+
+        // Ensure the VM does not already contain the elements of the blocks.
+        for block in blocks {
+            for transaction_id in block.transaction_ids() {
+                ensure!(
+                    !self.transaction_store().contains_transaction_id(transaction_id)?,
+                    "Transaction '{transaction_id}' already exists in the VM"
+                );
+            }
+            for deployment in block.deployments().filter_map(|transaction| transaction.deployment()) {
+                ensure!(
+                    !self.transaction_store().contains_program_id(deployment.program_id())?,
+                    "Program ID '{}' already exists in the VM",
+                    deployment.program_id()
+                );
+            }
+            for transition_id in block.transition_ids() {
+                ensure!(
+                    !self.transition_store().contains_transition_id(transition_id)?,
+                    "Transition ID '{transition_id}' already exists in the VM"
+                );
+            }
+            for input_id in block.input_ids() {
+                ensure!(
+                    !self.transition_store().contains_input_id(input_id)?,
+                    "Input ID '{input_id}' already exists in the VM"
+                );
+            }
+            for serial_number in block.serial_numbers() {
+                ensure!(
+                    !self.transition_store().contains_serial_number(serial_number)?,
+                    "Serial number '{serial_number}' already exists in the VM"
+                );
+            }
+            for tag in block.tags() {
+                ensure!(!self.transition_store().contains_tag(tag)?, "Tag '{tag}' already exists in the VM");
+            }
+            for output_id in block.output_ids() {
+                ensure!(
+                    !self.transition_store().contains_output_id(output_id)?,
+                    "Output ID '{output_id}' already exists in the VM"
+                );
+            }
+            for commitment in block.commitments() {
+                ensure!(
+                    !self.transition_store().contains_commitment(commitment)?,
+                    "Commitment '{commitment}' already exists in the VM"
+                );
+            }
+            for nonce in block.nonces() {
+                ensure!(!self.transition_store().contains_nonce(nonce)?, "Nonce '{nonce}' already exists in the VM");
+            }
+            for tpk in block.transition_public_keys() {
+                ensure!(
+                    !self.transition_store().contains_tpk(tpk)?,
+                    "Transition public key '{tpk}' already exists in the VM"
+                );
+            }
+            for tcm in block.transition_commitments() {
+                ensure!(
+                    !self.transition_store().contains_tcm(tcm)?,
+                    "Transition commitment '{tcm}' already exists in the VM"
+                );
+            }
+        }
+        finish!(timer, "Check the elements are unique");
+
+        Ok(())
+    }
+
     /// Verifies the given deployment. On failure, returns an error.
     #[inline]
     fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
@@ -219,16 +481,39 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Verifies the given execution. On failure, returns an error.
     #[inline]
-    fn check_execution(&self, execution: &Execution<N>) -> Result<()> {
+    fn check_execution(&self, execution: &Execution<N>, speculative: bool) -> Result<()> {
         let timer = timer!("VM::verify_execution");
 
+        // If the execution expires, ensure the expiring circuit key of the root transition exists.
+        if execution.valid_until().is_some() {
+            // Retrieve the root transition.
+            let transition = execution.peek()?;
+            let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+
+            // Compute the core logic.
+            macro_rules! logic {
+                ($process:expr, $network:path, $aleo:path) => {{
+                    // Prepare the program ID and function name.
+                    let program_id = cast_ref!(program_id as ProgramID<$network>);
+                    let function_name = cast_ref!(function_name as Identifier<$network>);
+                    // Synthesize the expiring circuit key, if it does not exist.
+                    $process.synthesize_expiring_key::<$aleo, _>(program_id, function_name, &mut rand::thread_rng())
+                }};
+            }
+            // Process the logic.
+            if let Err(error) = process!(self, logic) {
+                bail!("Execution verification failed: {error}")
+            }
+            lap!(timer, "Synthesize the expiring circuit key");
+        }
+
         // Verify the execution.
         let verification = self.process.read().verify_execution(execution);
         finish!(timer);
 
         match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
+            Ok(()) => match self.contains_global_state_root(&execution.global_state_root(), speculative) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Execution verification failed: global state root not found"),
                 Err(error) => bail!("Execution verification failed: {error}"),
@@ -239,7 +524,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Verifies the given fee. On failure, returns an error.
     #[inline]
-    fn check_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>, speculative: bool) -> Result<()> {
         let timer = timer!("VM::verify_fee");
 
         // Ensure the fee does not exceed the limit.
@@ -251,7 +536,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
+            Ok(()) => match self.contains_global_state_root(&fee.global_state_root(), speculative) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Fee verification failed: global state root not found"),
                 Err(error) => bail!("Fee verification failed: {error}"),
@@ -259,6 +544,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Err(error) => bail!("Fee verification failed: {error}"),
         }
     }
+
+    /// Returns `true` if the given global state root exists in the block store.
+    /// If `speculative` is `true`, the state roots of the blocks in a pending atomic batch are included.
+    fn contains_global_state_root(&self, global_state_root: &N::StateRoot, speculative: bool) -> Result<bool> {
+        match speculative {
+            true => self.block_store().contains_state_root_speculative(global_state_root),
+            false => self.block_store().contains_state_root(global_state_root),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -326,14 +620,14 @@ mod tests {
                 // Ensure the proof exists.
                 assert!(execution.proof().is_some());
                 // Verify the execution.
-                assert!(vm.check_execution(&execution).is_ok());
+                assert!(vm.check_execution(&execution, false).is_ok());
                 assert!(vm.verify_execution(&execution));
 
                 // Ensure that deserialization doesn't break the transaction verification.
                 let serialized_execution = execution.to_string();
                 let recovered_execution: Execution<CurrentNetwork> =
                     serde_json::from_str(&serialized_execution).unwrap();
-                assert!(vm.check_execution(&recovered_execution).is_ok());
+                assert!(vm.check_execution(&recovered_execution, false).is_ok());
                 assert!(vm.verify_execution(&recovered_execution));
             }
             _ => panic!("Expected an execution transaction"),
@@ -355,13 +649,13 @@ mod tests {
                 // Ensure the proof exists.
                 assert!(fee.proof().is_some());
                 // Verify the fee.
-                assert!(vm.check_fee(&fee, execution_id).is_ok());
+                assert!(vm.check_fee(&fee, execution_id, false).is_ok());
                 assert!(vm.verify_fee(&fee, execution_id));
 
                 // Ensure that deserialization doesn't break the transaction verification.
                 let serialized_fee = fee.to_string();
                 let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                assert!(vm.check_fee(&recovered_fee, execution_id).is_ok());
+                assert!(vm.check_fee(&recovered_fee, execution_id, false).is_ok());
                 assert!(vm.verify_fee(&recovered_fee, execution_id));
             }
             _ => panic!("Expected an execution with a fee"),
@@ -176,11 +176,11 @@ fn test_vm_execute_and_finalize() {
                                     ConfirmedTransaction::AcceptedExecute(_, _, _) => {
                                         "the execution was accepted".to_string()
                                     }
-                                    ConfirmedTransaction::RejectedExecute(_, _, _) => {
+                                    ConfirmedTransaction::RejectedExecute(..) => {
                                         "the execution was rejected".to_string()
                                     }
                                     ConfirmedTransaction::AcceptedDeploy(_, _, _)
-                                    | ConfirmedTransaction::RejectedDeploy(_, _, _) => {
+                                    | ConfirmedTransaction::RejectedDeploy(..) => {
                                         unreachable!("unexpected deployment transaction")
                                     }
                                 }),
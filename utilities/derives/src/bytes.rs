@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::canonical_serialize::IdentOrIndex;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Index, LitInt};
+
+/// Returns the version in the `#[bytes(version = N)]` attribute, if one is given.
+fn parse_version(ast: &DeriveInput) -> syn::Result<Option<u8>> {
+    let mut version = None;
+    for attribute in ast.attrs.iter().filter(|attribute| attribute.path().is_ident("bytes")) {
+        attribute.parse_nested_meta(|meta| match meta.path.is_ident("version") {
+            true => {
+                version = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u8>()?);
+                Ok(())
+            }
+            false => Err(meta.error("unsupported attribute, expected `#[bytes(version = N)]`")),
+        })?;
+    }
+    Ok(version)
+}
+
+/// Returns the accessors of the given fields, in declaration order.
+fn field_accessors(fields: &Fields) -> Vec<IdentOrIndex> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match field.ident {
+            Some(ref ident) => IdentOrIndex::Ident(ident.clone()),
+            None => IdentOrIndex::Index(Index::from(i)),
+        })
+        .collect()
+}
+
+/// Returns the bindings of the given fields when matching on an enum variant, in declaration order.
+fn field_bindings(fields: &Fields) -> Vec<Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match field.ident {
+            Some(ref ident) => ident.clone(),
+            None => Ident::new(&format!("field_{i}"), Span::call_site()),
+        })
+        .collect()
+}
+
+/// Returns the constructor of `path` with the given values of its fields.
+fn fields_constructor(path: TokenStream, fields: &Fields, values: &[TokenStream]) -> TokenStream {
+    match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#idents: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#values),* ) },
+        Fields::Unit => path,
+    }
+}
+
+pub(super) fn impl_to_bytes(ast: &DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let version = match parse_version(ast) {
+        Ok(version) => version.map(|version| quote! { snarkvm_utilities::ToBytes::write_le(&#version, &mut writer)?; }),
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let body = match ast.data {
+        Data::Struct(ref data_struct) => {
+            let accessors = field_accessors(&data_struct.fields);
+            quote! { #(snarkvm_utilities::ToBytes::write_le(&self.#accessors, &mut writer)?;)* }
+        }
+        Data::Enum(ref data_enum) => {
+            if data_enum.variants.len() > 256 {
+                panic!("`ToBytes` can only be derived for enums with at most 256 variants, {name} has more");
+            }
+            let arms = data_enum.variants.iter().enumerate().map(|(index, variant)| {
+                let variant_name = &variant.ident;
+                let tag = index as u8;
+                let bindings = field_bindings(&variant.fields);
+                let pattern = match variant.fields {
+                    Fields::Named(_) => quote! { Self::#variant_name { #(#bindings),* } },
+                    Fields::Unnamed(_) => quote! { Self::#variant_name ( #(#bindings),* ) },
+                    Fields::Unit => quote! { Self::#variant_name },
+                };
+                quote! {
+                    #pattern => {
+                        snarkvm_utilities::ToBytes::write_le(&#tag, &mut writer)?;
+                        #(snarkvm_utilities::ToBytes::write_le(#bindings, &mut writer)?;)*
+                    }
+                }
+            });
+            quote! { match self { #(#arms)* } }
+        }
+        Data::Union(_) => panic!("`ToBytes` can only be derived for structs and enums, {name} is a union"),
+    };
+
+    quote! {
+        impl #impl_generics snarkvm_utilities::ToBytes for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn write_le<W: snarkvm_utilities::io::Write>(&self, mut writer: W) -> snarkvm_utilities::io::Result<()> {
+                #version
+                #body
+                Ok(())
+            }
+        }
+    }
+}
+
+pub(super) fn impl_from_bytes(ast: &DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let read = quote! { snarkvm_utilities::FromBytes::read_le(&mut reader)? };
+
+    let version = match parse_version(ast) {
+        Ok(version) => version.map(|version| {
+            quote! {
+                let version: u8 = #read;
+                if version != #version {
+                    return Err(snarkvm_utilities::error(concat!("Invalid ", stringify!(#name), " version")));
+                }
+            }
+        }),
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let body = match ast.data {
+        Data::Struct(ref data_struct) => {
+            let values = vec![read; data_struct.fields.len()];
+            let constructor = fields_constructor(quote! { Self }, &data_struct.fields, &values);
+            quote! { Ok(#constructor) }
+        }
+        Data::Enum(ref data_enum) => {
+            if data_enum.variants.len() > 256 {
+                panic!("`FromBytes` can only be derived for enums with at most 256 variants, {name} has more");
+            }
+            let arms = data_enum.variants.iter().enumerate().map(|(index, variant)| {
+                let variant_name = &variant.ident;
+                let tag = index as u8;
+                let values = vec![read.clone(); variant.fields.len()];
+                let constructor = fields_constructor(quote! { Self::#variant_name }, &variant.fields, &values);
+                quote! { #tag => Ok(#constructor), }
+            });
+            quote! {
+                let tag: u8 = #read;
+                match tag {
+                    #(#arms)*
+                    _ => Err(snarkvm_utilities::error(concat!("Invalid ", stringify!(#name), " variant"))),
+                }
+            }
+        }
+        Data::Union(_) => panic!("`FromBytes` can only be derived for structs and enums, {name} is a union"),
+    };
+
+    quote! {
+        impl #impl_generics snarkvm_utilities::FromBytes for #name #ty_generics #where_clause {
+            #[allow(unused_mut)]
+            fn read_le<R: snarkvm_utilities::io::Read>(mut reader: R) -> snarkvm_utilities::io::Result<Self> {
+                #version
+                #body
+            }
+        }
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
+
 mod canonical_deserialize;
 
 mod canonical_serialize;
@@ -29,3 +31,19 @@ pub fn derive_canonical_deserialize(input: proc_macro::TokenStream) -> proc_macr
     let ast = parse_macro_input!(input as DeriveInput);
     proc_macro::TokenStream::from(canonical_deserialize::impl_canonical_deserialize(&ast))
 }
+
+/// Derives `ToBytes`, which writes the fields in declaration order, so the encoding is stable as long as
+/// the fields are not reordered. Enum variants are prefixed with their index as a `u8`, so new variants
+/// must be appended. The container attribute `#[bytes(version = N)]` prefixes the encoding with a `u8` version.
+#[proc_macro_derive(ToBytes, attributes(bytes))]
+pub fn derive_to_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(bytes::impl_to_bytes(&ast))
+}
+
+/// Derives `FromBytes`, which reads the encoding of `#[derive(ToBytes)]`, and checks the version if one is given.
+#[proc_macro_derive(FromBytes, attributes(bytes))]
+pub fn derive_from_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(bytes::impl_from_bytes(&ast))
+}
@@ -77,6 +77,16 @@ pub trait FromBytes {
     }
 }
 
+/// Reads `T` from the given bytes, which must contain exactly one object.
+fn read_exact_le<T: FromBytes>(bytes: &[u8]) -> anyhow::Result<T> {
+    let mut reader = bytes;
+    let object = T::read_le(&mut reader)?;
+    match reader.is_empty() {
+        true => Ok(object),
+        false => anyhow::bail!("Failed to read the bytes: found {} trailing bytes", reader.len()),
+    }
+}
+
 pub struct ToBytesSerializer<T: ToBytes>(String, Option<usize>, PhantomData<T>);
 
 impl<T: ToBytes> ToBytesSerializer<T> {
@@ -102,11 +112,16 @@ pub struct FromBytesDeserializer<T: FromBytes>(String, Option<usize>, PhantomDat
 impl<'de, T: FromBytes> FromBytesDeserializer<T> {
     /// Deserializes a static-sized byte array (without length encoding).
     ///
-    /// This method fails if `deserializer` is given an insufficient `size`.
+    /// This method fails if `deserializer` is given an insufficient `size`, and, as binary formats such as
+    /// bincode do not encode the length of a tuple, it also fails if `T` does not read exactly `size` bytes,
+    /// instead of silently reading into (or leaving behind) the bytes of neighbouring values.
     pub fn deserialize<D: Deserializer<'de>>(deserializer: D, name: &str, size: usize) -> Result<T, D::Error> {
         let mut buffer = Vec::with_capacity(size);
         deserializer.deserialize_tuple(size, FromBytesVisitor::new(&mut buffer, name))?;
-        FromBytes::read_le(&*buffer).map_err(de::Error::custom)
+        if buffer.len() != size {
+            return Err(de::Error::invalid_length(buffer.len(), &format!("{size} bytes for {name}").as_str()));
+        }
+        read_exact_le::<T>(&buffer).map_err(de::Error::custom)
     }
 
     /// Deserializes a static-sized byte array, with a u8 length encoding at the start.
@@ -120,10 +135,12 @@ impl<'de, T: FromBytes> FromBytesDeserializer<T> {
     }
 
     /// Deserializes a dynamically-sized byte array.
+    ///
+    /// If the deserializer provides the bytes as a slice (i.e. from a byte buffer or a memory-mapped file),
+    /// the object is read directly from the slice, instead of first copying the bytes into a buffer.
+    /// This method fails if `T` does not read exactly the given bytes.
     pub fn deserialize_with_size_encoding<D: Deserializer<'de>>(deserializer: D, name: &str) -> Result<T, D::Error> {
-        let mut buffer = Vec::with_capacity(32);
-        deserializer.deserialize_bytes(FromBytesVisitor::new(&mut buffer, name))?;
-        FromBytes::read_le(&*buffer).map_err(de::Error::custom)
+        deserializer.deserialize_bytes(FromBytesSizeEncodedVisitor::<T>(name.to_string(), PhantomData))
     }
 
     /// Attempts to deserialize a byte array (without length encoding).
@@ -174,7 +191,7 @@ impl<'a, 'de> Visitor<'de> for FromBytesVisitor<'a> {
         formatter.write_str(&format!("a valid {} ", self.1))
     }
 
-    fn visit_borrowed_bytes<E: serde::de::Error>(self, bytes: &'de [u8]) -> Result<Self::Value, E> {
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
         self.0.extend_from_slice(bytes);
         Ok(())
     }
@@ -187,6 +204,28 @@ impl<'a, 'de> Visitor<'de> for FromBytesVisitor<'a> {
     }
 }
 
+struct FromBytesSizeEncodedVisitor<T: FromBytes>(String, PhantomData<T>);
+
+impl<'de, T: FromBytes> Visitor<'de> for FromBytesSizeEncodedVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(&format!("a valid {} ", self.0))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        read_exact_le::<T>(bytes).map_err(de::Error::custom)
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let mut buffer = Vec::with_capacity(seq.size_hint().unwrap_or(32));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            buffer.push(byte);
+        }
+        read_exact_le::<T>(&buffer).map_err(de::Error::custom)
+    }
+}
+
 struct FromBytesWithU8Visitor<T: FromBytes>(String, PhantomData<T>);
 
 impl<T: FromBytes> FromBytesWithU8Visitor<T> {
@@ -255,6 +294,90 @@ impl<'de, T: FromBytes> Visitor<'de> for FromBytesWithU16Visitor<T> {
     }
 }
 
+pub struct ToHexSerializer<T: ToBytes>(PhantomData<T>);
+
+impl<T: ToBytes> ToHexSerializer<T> {
+    /// Serializes an object as a `0x`-prefixed, lowercase hex string of its little-endian bytes.
+    pub fn serialize<S: Serializer>(object: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&Self::to_hex(object).map_err(ser::Error::custom)?)
+    }
+
+    /// Returns the `0x`-prefixed, lowercase hex string of the little-endian bytes of the given object.
+    pub fn to_hex(object: &T) -> anyhow::Result<String> {
+        const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+        let bytes = object.to_bytes_le()?;
+        let mut string = String::with_capacity(2 + 2 * bytes.len());
+        string.push_str("0x");
+        for byte in bytes {
+            string.push(HEX_CHARS[(byte >> 4) as usize] as char);
+            string.push(HEX_CHARS[(byte & 0xf) as usize] as char);
+        }
+        Ok(string)
+    }
+}
+
+pub struct FromHexDeserializer<T: FromBytes>(PhantomData<T>);
+
+impl<'de, T: FromBytes> FromHexDeserializer<T> {
+    /// Deserializes an object from a `0x`-prefixed, lowercase hex string of its little-endian bytes.
+    pub fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(FromHexVisitor::<T>(PhantomData))
+    }
+
+    /// Returns the object from the given `0x`-prefixed, lowercase hex string of its little-endian bytes.
+    ///
+    /// This method is strict, and fails if the string is not prefixed with `0x`, contains uppercase or
+    /// non-hex characters, has an odd number of characters, or does not encode exactly one object.
+    pub fn from_hex(string: &str) -> anyhow::Result<T> {
+        /// Returns the value of the given lowercase hex character.
+        fn to_nibble(character: u8) -> anyhow::Result<u8> {
+            match character {
+                b'0'..=b'9' => Ok(character - b'0'),
+                b'a'..=b'f' => Ok(character - b'a' + 10),
+                _ => anyhow::bail!("Invalid hex character '{}'", character as char),
+            }
+        }
+
+        // Ensure the string is prefixed with `0x`.
+        let Some(hex) = string.strip_prefix("0x") else {
+            anyhow::bail!("Hex string '{string}' is missing the '0x' prefix")
+        };
+        // Ensure the string has an even number of characters.
+        if hex.len() % 2 != 0 {
+            anyhow::bail!("Hex string '{string}' has an odd number of characters")
+        }
+        // Decode the bytes.
+        let bytes = hex
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| Ok((to_nibble(pair[0])? << 4) | to_nibble(pair[1])?))
+            .collect::<anyhow::Result<Vec<u8>>>()?;
+
+        // Read the object, and ensure all of the bytes were consumed.
+        let mut reader = &bytes[..];
+        let object = T::read_le(&mut reader)?;
+        match reader.is_empty() {
+            true => Ok(object),
+            false => anyhow::bail!("Hex string '{string}' has {} trailing bytes", reader.len()),
+        }
+    }
+}
+
+struct FromHexVisitor<T: FromBytes>(PhantomData<T>);
+
+impl<'de, T: FromBytes> Visitor<'de> for FromHexVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a 0x-prefixed lowercase hex string")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, string: &str) -> Result<Self::Value, E> {
+        FromHexDeserializer::<T>::from_hex(string).map_err(de::Error::custom)
+    }
+}
+
 impl ToBytes for () {
     #[inline]
     fn write_le<W: Write>(&self, _writer: W) -> IoResult<()> {
@@ -471,6 +594,119 @@ mod test {
         assert_eq!(bytes, actual_bytes);
     }
 
+    #[test]
+    fn test_deserialize_with_size_encoding() {
+        use serde::de::value::{BorrowedBytesDeserializer, Error as ValueError, SeqDeserializer};
+
+        let bytes = [1u8, 2, 3, 4];
+        // Ensure the object is read from borrowed bytes.
+        let deserializer = BorrowedBytesDeserializer::<ValueError>::new(&bytes);
+        let candidate = FromBytesDeserializer::<u32>::deserialize_with_size_encoding(deserializer, "integer");
+        assert_eq!(u32::from_le_bytes(bytes), candidate.unwrap());
+        // Ensure the object is read from a sequence of bytes.
+        let deserializer = SeqDeserializer::<_, ValueError>::new(bytes.into_iter());
+        let candidate = FromBytesDeserializer::<u32>::deserialize_with_size_encoding(deserializer, "integer");
+        assert_eq!(u32::from_le_bytes(bytes), candidate.unwrap());
+        // Ensure insufficient bytes fail.
+        let deserializer = BorrowedBytesDeserializer::<ValueError>::new(&bytes[..3]);
+        assert!(FromBytesDeserializer::<u32>::deserialize_with_size_encoding(deserializer, "integer").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_is_exact() {
+        use serde::de::value::{Error as ValueError, SeqDeserializer};
+
+        let bytes = [1u8, 2, 3, 4, 5];
+        // Ensure the object is read from exactly `size` bytes.
+        let deserializer = SeqDeserializer::<_, ValueError>::new(bytes[..4].iter().copied());
+        let candidate = FromBytesDeserializer::<u32>::deserialize(deserializer, "integer", 4);
+        assert_eq!(u32::from_le_bytes([1, 2, 3, 4]), candidate.unwrap());
+        // Ensure an object that is shorter than `size` fails.
+        let deserializer = SeqDeserializer::<_, ValueError>::new(bytes.iter().copied());
+        assert!(FromBytesDeserializer::<u32>::deserialize(deserializer, "integer", 5).is_err());
+        // Ensure an object that is longer than `size` fails.
+        let deserializer = SeqDeserializer::<_, ValueError>::new(bytes[..3].iter().copied());
+        assert!(FromBytesDeserializer::<u32>::deserialize(deserializer, "integer", 3).is_err());
+        // Ensure trailing bytes after a size-encoded object fail.
+        let deserializer = SeqDeserializer::<_, ValueError>::new(bytes.iter().copied());
+        assert!(FromBytesDeserializer::<u32>::deserialize_with_size_encoding(deserializer, "integer").is_err());
+    }
+
+    #[test]
+    fn test_binary_formats() {
+        /// An object that is serialized as a static-sized byte array.
+        #[derive(Debug, PartialEq)]
+        struct Static(u64);
+        /// An object that is serialized as a dynamically-sized byte array.
+        #[derive(Debug, PartialEq)]
+        struct Dynamic(Vec<u8>);
+
+        impl ToBytes for Static {
+            fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+                self.0.write_le(writer)
+            }
+        }
+        impl FromBytes for Static {
+            fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+                Ok(Self(u64::read_le(reader)?))
+            }
+        }
+        impl serde::Serialize for Static {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ToBytesSerializer::serialize(self, serializer)
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Static {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                FromBytesDeserializer::<Self>::deserialize(deserializer, "static", 8)
+            }
+        }
+
+        impl ToBytes for Dynamic {
+            fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+                (self.0.len() as u16).write_le(&mut writer)?;
+                self.0.write_le(&mut writer)
+            }
+        }
+        impl FromBytes for Dynamic {
+            fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+                let length = u16::read_le(&mut reader)?;
+                Ok(Self((0..length).map(|_| u8::read_le(&mut reader)).collect::<IoResult<_>>()?))
+            }
+        }
+        impl serde::Serialize for Dynamic {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Dynamic {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "dynamic")
+            }
+        }
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let length = rng.gen_range(0..64);
+            let expected = (Static(rng.gen()), Dynamic((0..length).map(|_| rng.gen()).collect()), Static(rng.gen()));
+
+            // Ensure the bincode encoding is the concatenation of the byte encodings, and round-trips.
+            let bytes = bincode::serialize(&expected).unwrap();
+            assert_eq!(8 + 8 + 2 + length + 8, bytes.len());
+            assert_eq!(expected, bincode::deserialize(&bytes).unwrap());
+
+            // Ensure the CBOR encoding round-trips.
+            let mut bytes = Vec::new();
+            ciborium::ser::into_writer(&expected, &mut bytes).unwrap();
+            assert_eq!(expected, ciborium::de::from_reader(&bytes[..]).unwrap());
+
+            // Ensure the JSON encoding round-trips.
+            let string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected, serde_json::from_str(&string).unwrap());
+        }
+    }
+
     #[test]
     fn test_bits_from_bytes_le() {
         assert_eq!(bits_from_bytes_le(&[204, 76]).collect::<Vec<bool>>(), [
@@ -488,6 +724,38 @@ mod test {
         assert_eq!(bytes_from_bits_le(&bits), [204, 76]);
     }
 
+    #[test]
+    fn test_hex() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: u64 = rng.gen();
+
+            // Ensure the hex string round-trips.
+            let hex = ToHexSerializer::to_hex(&expected).unwrap();
+            assert_eq!(
+                hex,
+                format!("0x{}", expected.to_le_bytes().iter().map(|b| format!("{b:02x}")).collect::<String>())
+            );
+            assert_eq!(expected, FromHexDeserializer::<u64>::from_hex(&hex).unwrap());
+
+            // Ensure the serde encoding round-trips.
+            let json = ToHexSerializer::serialize(&expected, serde_json::value::Serializer).unwrap();
+            assert_eq!(json, serde_json::Value::String(hex.clone()));
+            assert_eq!(expected, FromHexDeserializer::<u64>::deserialize(json).unwrap());
+        }
+
+        // Ensure the parsing is strict.
+        assert!(FromHexDeserializer::<u16>::from_hex("0x0102").is_ok());
+        assert!(FromHexDeserializer::<u16>::from_hex("0102").is_err());
+        assert!(FromHexDeserializer::<u16>::from_hex("0X0102").is_err());
+        assert!(FromHexDeserializer::<u16>::from_hex("0x0A02").is_err());
+        assert!(FromHexDeserializer::<u16>::from_hex("0x102").is_err());
+        assert!(FromHexDeserializer::<u16>::from_hex("0x01").is_err());
+        assert!(FromHexDeserializer::<u16>::from_hex("0x010203").is_err());
+        assert!(FromHexDeserializer::<u16>::from_hex("0x01 2").is_err());
+    }
+
     #[test]
     fn test_from_bits_le_to_bytes_le_roundtrip() {
         let mut rng = TestRng::default();
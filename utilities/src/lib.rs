@@ -61,6 +61,11 @@ pub use error::*;
 pub mod iterator;
 pub use iterator::*;
 
+#[cfg(feature = "std")]
+pub mod mmap;
+#[cfg(feature = "std")]
+pub use mmap::*;
+
 #[macro_use]
 pub mod parallel;
 pub use parallel::*;
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![deny(unsafe_code)]
+
+use std::{
+    fs::File,
+    io,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+/// The bytes of a file, which are memory-mapped from disk where the platform supports it.
+///
+/// The pages of a mapped file are read on demand and can be reclaimed by the OS, so a large file
+/// can be deserialized without first copying the whole file into the heap.
+///
+/// A mapped file must not be truncated or modified in place while it is mapped, as reading a truncated
+/// mapping raises `SIGBUS`. Files which may be mapped must therefore only be written with `replace_file`.
+pub struct MappedBytes(Inner);
+
+enum Inner {
+    /// A read-only mapping of a file.
+    #[cfg(unix)]
+    Mapped(memmap2::Mmap),
+    /// Bytes which are held in the heap, i.e. a downloaded file or a file on a platform without `mmap`.
+    Owned(Vec<u8>),
+}
+
+impl MappedBytes {
+    /// Maps the file at the given path into memory, or reads it into the heap if the platform does not support `mmap`.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // A mapping can not be empty.
+        match file.metadata()?.len() {
+            0 => Ok(Self(Inner::Owned(Vec::new()))),
+            _ => Self::map(&file),
+        }
+    }
+
+    /// Returns `true` if the bytes are memory-mapped from disk.
+    pub const fn is_mapped(&self) -> bool {
+        match self.0 {
+            #[cfg(unix)]
+            Inner::Mapped(_) => true,
+            Inner::Owned(_) => false,
+        }
+    }
+
+    /// Returns the bytes in the heap, copying them if they are memory-mapped.
+    pub fn into_vec(self) -> Vec<u8> {
+        match self.0 {
+            #[cfg(unix)]
+            Inner::Mapped(mapping) => mapping.to_vec(),
+            Inner::Owned(bytes) => bytes,
+        }
+    }
+
+    /// Maps the given file into memory.
+    ///
+    /// # Safety
+    /// The mapping aliases the file, so the file must not be truncated or modified in place until the mapping
+    /// is dropped. This holds as long as every writer of the file uses `replace_file`, which writes the bytes
+    /// to a new file and renames it into place, so the existing mappings keep the previous file.
+    #[cfg(unix)]
+    #[allow(unsafe_code)]
+    fn map(file: &File) -> io::Result<Self> {
+        // SAFETY: The mapping is read-only, and the file is only replaced by a rename, per the contract above.
+        let mapping = unsafe { memmap2::Mmap::map(file)? };
+        Ok(Self(Inner::Mapped(mapping)))
+    }
+
+    /// Reads the given file into the heap, as the platform does not support `mmap`.
+    #[cfg(not(unix))]
+    fn map(mut file: &File) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        io::Read::read_to_end(&mut file, &mut bytes)?;
+        Ok(Self(Inner::Owned(bytes)))
+    }
+}
+
+/// Writes the bytes to a temporary file next to the given path, and renames it into place.
+///
+/// The rename atomically replaces any existing file, so its existing mappings keep the previous contents,
+/// and a concurrent reader never observes a partially written file. This write path must remain rename-only,
+/// as `MappedBytes` relies on it for the safety of its mappings.
+pub fn replace_file(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    // Write the bytes to a temporary file, which is unique to this process.
+    let temporary_path = PathBuf::from(format!("{}.{}.tmp", path.display(), std::process::id()));
+    let result = std::fs::write(&temporary_path, bytes).and_then(|_| std::fs::rename(&temporary_path, path));
+    // Remove the temporary file, if it was not renamed.
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temporary_path);
+    }
+    result
+}
+
+impl From<Vec<u8>> for MappedBytes {
+    /// Initializes the bytes from bytes in the heap.
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(Inner::Owned(bytes))
+    }
+}
+
+impl Deref for MappedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match &self.0 {
+            #[cfg(unix)]
+            Inner::Mapped(mapping) => mapping,
+            Inner::Owned(bytes) => bytes,
+        }
+    }
+}
+
+impl AsRef<[u8]> for MappedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapped_bytes() {
+        let path = std::env::temp_dir().join(format!("snarkvm-utilities-mmap-{}", std::process::id()));
+        let expected = (0..10_000u32).flat_map(|i| i.to_le_bytes()).collect::<Vec<_>>();
+        std::fs::write(&path, &expected).unwrap();
+
+        // Ensure the mapped bytes match the file.
+        let bytes = MappedBytes::open(&path).unwrap();
+        assert_eq!(bytes.is_mapped(), cfg!(unix));
+        assert_eq!(&*bytes, expected.as_slice());
+
+        // Ensure the mapping outlives the file.
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(&*bytes, expected.as_slice());
+        assert_eq!(bytes.into_vec(), expected);
+
+        // Ensure replacing the file leaves an existing mapping intact.
+        std::fs::write(&path, &expected).unwrap();
+        let bytes = MappedBytes::open(&path).unwrap();
+        replace_file(&path, b"replaced").unwrap();
+        assert_eq!(&*bytes, expected.as_slice());
+        assert_eq!(&*MappedBytes::open(&path).unwrap(), b"replaced");
+
+        // Ensure an empty file can be opened.
+        std::fs::write(&path, []).unwrap();
+        assert!(MappedBytes::open(&path).unwrap().is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
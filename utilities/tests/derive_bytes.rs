@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct Named {
+    a: u8,
+    b: u32,
+    c: [u8; 2],
+}
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+#[bytes(version = 3)]
+struct Versioned(u16, bool);
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct Generic<T: ToBytes + FromBytes> {
+    inner: T,
+    unit: Unit,
+}
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct Unit;
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+enum Message {
+    Ping,
+    Pong(u64),
+    Data { id: u16, payload: Named },
+}
+
+#[test]
+fn test_struct_encoding() {
+    // Ensure the fields are encoded in declaration order.
+    let named = Named { a: 1, b: 2, c: [3, 4] };
+    let bytes = named.to_bytes_le().unwrap();
+    assert_eq!(vec![1, 2, 0, 0, 0, 3, 4], bytes);
+    assert_eq!(named, Named::from_bytes_le(&bytes).unwrap());
+
+    // Ensure generic and unit structs round trip.
+    let generic = Generic { inner: 5u64, unit: Unit };
+    let bytes = generic.to_bytes_le().unwrap();
+    assert_eq!(5u64.to_le_bytes().to_vec(), bytes);
+    assert_eq!(generic, Generic::from_bytes_le(&bytes).unwrap());
+}
+
+#[test]
+fn test_versioned_encoding() {
+    // Ensure the version is written first.
+    let versioned = Versioned(7, true);
+    let bytes = versioned.to_bytes_le().unwrap();
+    assert_eq!(vec![3, 7, 0, 1], bytes);
+    assert_eq!(versioned, Versioned::from_bytes_le(&bytes).unwrap());
+
+    // Ensure a different version fails.
+    assert!(Versioned::from_bytes_le(&[2, 7, 0, 1]).is_err());
+}
+
+#[test]
+fn test_enum_encoding() {
+    // Ensure each variant is prefixed with its index.
+    assert_eq!(vec![0], Message::Ping.to_bytes_le().unwrap());
+    assert_eq!(vec![1, 9, 0, 0, 0, 0, 0, 0, 0], Message::Pong(9).to_bytes_le().unwrap());
+
+    for message in
+        [Message::Ping, Message::Pong(9), Message::Data { id: 258, payload: Named { a: 1, b: 2, c: [3, 4] } }]
+    {
+        let bytes = message.to_bytes_le().unwrap();
+        assert_eq!(message, Message::from_bytes_le(&bytes).unwrap());
+    }
+
+    // Ensure an unknown variant fails.
+    assert!(Message::from_bytes_le(&[3]).is_err());
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, New, Run, Update};
+use crate::cli::commands::{Build, Clean, New, Run, Update, Vectors};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -48,6 +48,8 @@ pub enum Command {
     Run(Run),
     #[clap(name = "update")]
     Update(Update),
+    #[clap(name = "vectors")]
+    Vectors(Vectors),
 }
 
 impl Command {
@@ -59,6 +61,7 @@ impl Command {
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Update(command) => command.parse(),
+            Self::Vectors(command) => command.parse(),
         }
     }
 }
@@ -27,6 +27,9 @@ pub use run::*;
 pub mod update;
 pub use update::*;
 
+pub mod vectors;
+pub use vectors::*;
+
 use crate::{
     package::Package,
     prelude::{Identifier, Locator, ProgramID, Value},
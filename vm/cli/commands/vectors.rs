@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::prelude::TestVectors;
+
+use std::path::PathBuf;
+
+/// Emits the versioned test vectors, for implementations of snarkVM in other languages to check compatibility.
+#[derive(Debug, Parser)]
+pub struct Vectors {
+    /// The path of the file to write the test vectors to, instead of printing them.
+    #[clap(short, long)]
+    path: Option<PathBuf>,
+}
+
+impl Vectors {
+    /// Generates the test vectors, and writes them as JSON to the given path or the output.
+    pub fn parse(self) -> Result<String> {
+        // Generate the test vectors.
+        let vectors = TestVectors::generate::<CurrentNetwork>()?;
+        let json = serde_json::to_string_pretty(&vectors)?;
+
+        match self.path {
+            Some(path) => {
+                // Write the test vectors to the file.
+                std::fs::write(&path, json)?;
+
+                // Prepare the path string.
+                let path_string = format!("(in \"{}\")", path.display());
+
+                Ok(format!(
+                    "✅ Wrote {} test vectors (version {}) {}",
+                    vectors.vectors().len(),
+                    vectors.version(),
+                    path_string.dimmed()
+                ))
+            }
+            None => Ok(json),
+        }
+    }
+}
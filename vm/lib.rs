@@ -41,6 +41,8 @@ pub use snarkvm_ledger as ledger;
 pub use snarkvm_parameters as parameters;
 #[cfg(feature = "synthesizer")]
 pub use snarkvm_synthesizer as synthesizer;
+#[cfg(feature = "macros")]
+pub use snarkvm_synthesizer_macros::aleo_program;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
 #[cfg(feature = "wasm")]